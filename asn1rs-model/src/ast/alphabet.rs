@@ -0,0 +1,48 @@
+use crate::model::PermittedAlphabet;
+use syn::parse::{Parse, ParseStream};
+use syn::LitStr;
+use syn::Token;
+
+impl Parse for PermittedAlphabet {
+    fn parse<'a>(input: ParseStream) -> syn::Result<Self> {
+        let mut ranges = Vec::new();
+        let mut extensible = false;
+
+        while !input.is_empty() {
+            if input.peek(Token![.]) {
+                let _ = input.parse::<Token![.]>()?;
+                let _ = input.parse::<Token![.]>()?;
+                let _ = input.parse::<Token![.]>()?;
+                extensible = true;
+            } else {
+                let start = input.parse::<LitStr>()?;
+                if input.peek(Token![.]) {
+                    let _ = input.parse::<Token![.]>()?;
+                    let _ = input.parse::<Token![.]>()?;
+                    let end = input.parse::<LitStr>()?;
+                    ranges.push((single_char(&start)?, single_char(&end)?));
+                } else {
+                    ranges.extend(start.value().chars().map(|c| (c, c)));
+                }
+            }
+
+            if !input.is_empty() {
+                let _ = input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(PermittedAlphabet::from_ranges(ranges).with_extensible(extensible))
+    }
+}
+
+fn single_char(literal: &LitStr) -> syn::Result<char> {
+    let value = literal.value();
+    let mut chars = value.chars();
+    match (chars.next(), chars.next()) {
+        (Some(char), None) => Ok(char),
+        _ => Err(syn::Error::new(
+            literal.span(),
+            "Expected a single character as bound of the range",
+        )),
+    }
+}
@@ -2,10 +2,13 @@ use super::range::ident_or_literal_or_punct;
 use super::range::IntegerRange;
 use super::tag::AttrTag;
 use crate::ast::constants::ConstLit;
-use crate::model::LiteralValue;
 use crate::model::{
-    Charset, Choice, ChoiceVariant, OpenType,OpenTypeVariant,Enumerated, EnumeratedVariant, Range, Size, Tag, Type,
+    Charset, Choice, ChoiceVariant, Enumerated, EnumeratedVariant, OpenType, OpenTypeVariant,
+    PermittedAlphabet, Range, Size, Tag, TimeKind, Type,
 };
+use crate::model::{LiteralValue, ObjectIdentifier, ObjectIdentifierComponent};
+use crate::model::{PresenceConstraint, TagDefault, TagMode, ValueConstraint};
+use proc_macro2::Delimiter;
 use std::fmt::Debug;
 use std::fmt::Display;
 use std::marker::PhantomData;
@@ -15,15 +18,21 @@ use syn::parenthesized;
 use syn::parse::{Parse, ParseBuffer, ParseStream};
 use syn::token;
 use syn::Token;
-use proc_macro2::Delimiter;
 
 #[derive(Debug)]
 pub(crate) struct AsnAttribute<C: Context> {
     pub(crate) primary: C::Primary,
     pub(crate) tag: Option<Tag>,
+    pub(crate) tag_mode: Option<TagMode>,
+    pub(crate) tag_default: Option<TagDefault>,
     pub(crate) consts: Vec<ConstLit>,
     pub(crate) extensible_after: Option<String>,
     pub(crate) default_value: Option<LiteralValue>,
+    pub(crate) identifier: Option<String>,
+    pub(crate) key: Option<usize>,
+    pub(crate) key_object_identifier: Option<Vec<u64>>,
+    pub(crate) unknown_extensions: bool,
+    pub(crate) components: Vec<(String, Option<ValueConstraint>, Option<PresenceConstraint>)>,
     _c: PhantomData<C>,
 }
 
@@ -32,9 +41,16 @@ impl<C: Context> AsnAttribute<C> {
         Self {
             primary,
             tag: None,
+            tag_mode: None,
+            tag_default: None,
             consts: Vec::default(),
             extensible_after: None,
             default_value: None,
+            identifier: None,
+            key: None,
+            key_object_identifier: None,
+            unknown_extensions: false,
+            components: Vec::default(),
             _c: Default::default(),
         }
     }
@@ -42,8 +58,11 @@ impl<C: Context> AsnAttribute<C> {
 
 impl<C: Context> Parse for AsnAttribute<C> {
     fn parse<'a>(input: &'a ParseBuffer<'a>) -> syn::Result<Self> {
+        let cursor = input.cursor();
         let mut asn = Self::new(C::Primary::parse(input)?);
-        eof_or_comma(input, "Primary attribute must be separated by comma")?;
+        if input.cursor() != cursor {
+            eof_or_comma(input, "Primary attribute must be separated by comma")?;
+        }
         while !input.cursor().eof() {
             let lowercase_ident = input
                 .step(|c| {
@@ -57,6 +76,35 @@ impl<C: Context> Parse for AsnAttribute<C> {
                     let tag = AttrTag::parse(input)?;
                     asn.tag = Some(tag.0);
                 }
+                "explicit" if C::TAGGABLE && asn.tag_mode.is_none() => {
+                    asn.tag_mode = Some(TagMode::Explicit);
+                }
+                "implicit" if C::TAGGABLE && asn.tag_mode.is_none() => {
+                    asn.tag_mode = Some(TagMode::Implicit);
+                }
+                "tags" if C::TAG_DEFAULT => {
+                    let content;
+                    parenthesized!(content in input);
+                    let ident = parse_ident(&content, "Expected tagging environment")?;
+                    asn.tag_default = Some(TagDefault::try_from_text(&ident).ok_or_else(|| {
+                        content.error(format!("Invalid tagging environment: {}", ident))
+                    })?);
+                }
+                "unknown_extensions" if C::UNKNOWN_EXTENSIONS && !asn.unknown_extensions => {
+                    asn.unknown_extensions = true;
+                }
+                "components" if C::COMPONENTS && asn.components.is_empty() => {
+                    let content;
+                    parenthesized!(content in input);
+                    while !content.is_empty() {
+                        let name = parse_ident(&content, "Expected name of component")?;
+                        let constraints;
+                        parenthesized!(constraints in content);
+                        let (value, presence) = parse_component_constraints(&constraints)?;
+                        asn.components.push((name, value, presence));
+                        eof_or_comma(&content, "Components must be separated by comma")?;
+                    }
+                }
                 "extensible_after" if C::EXTENSIBLE_AFTER => {
                     let content;
                     parenthesized!(content in input);
@@ -64,6 +112,26 @@ impl<C: Context> Parse for AsnAttribute<C> {
                         .step(|s| s.ident().ok_or_else(|| content.error("Not a valid ident")))?;
                     asn.extensible_after = Some(ident.to_string());
                 }
+                "identifier" if C::IDENTIFIER => {
+                    let content;
+                    parenthesized!(content in input);
+                    asn.identifier = Some(content.parse::<syn::LitStr>()?.value());
+                }
+                "key" if C::KEY => {
+                    let content;
+                    parenthesized!(content in input);
+                    asn.key = Some(content.parse::<syn::LitInt>()?.base10_parse()?);
+                }
+                "key_object_identifier" if C::KEY && asn.key_object_identifier.is_none() => {
+                    let content;
+                    parenthesized!(content in input);
+                    let arcs = content.parse_terminated::<_, Token![,]>(syn::LitInt::parse)?;
+                    asn.key_object_identifier = Some(
+                        arcs.iter()
+                            .map(syn::LitInt::base10_parse)
+                            .collect::<syn::Result<_>>()?,
+                    );
+                }
                 "const" if C::CONSTS => {
                     let content;
                     parenthesized!(content in input);
@@ -93,6 +161,67 @@ impl<C: Context> Parse for AsnAttribute<C> {
     }
 }
 
+/// Parses the value constraint - a range like `1..10` or `min..10`, `true`, `false` or the
+/// identifier of an ENUMERATED value like `"var-b"` - and the presence constraint - `present`,
+/// `absent` or `optional` - of a component, in any order
+fn parse_component_constraints(
+    input: &ParseBuffer,
+) -> syn::Result<(Option<ValueConstraint>, Option<PresenceConstraint>)> {
+    let mut value = None;
+    let mut presence = None;
+    while !input.is_empty() {
+        if input.peek(syn::LitStr) {
+            value = Some(ValueConstraint::new(input.parse::<syn::LitStr>()?.value()));
+        } else if input.peek(Token![-]) || input.peek(syn::LitInt) {
+            value = Some(parse_component_range(input, None)?);
+        } else {
+            let ident = parse_ident(input, "Expected value or presence constraint")?;
+            match ident.to_lowercase().as_str() {
+                "present" => presence = Some(PresenceConstraint::Present),
+                "absent" => presence = Some(PresenceConstraint::Absent),
+                "optional" => presence = Some(PresenceConstraint::Optional),
+                "true" => value = Some(ValueConstraint::new("TRUE")),
+                "false" => value = Some(ValueConstraint::new("FALSE")),
+                "min" => value = Some(parse_component_range(input, Some("MIN"))?),
+                _ => {
+                    return Err(
+                        input.error(format!("Invalid value or presence constraint: {}", ident))
+                    )
+                }
+            }
+        }
+        eof_or_comma(input, "Constraints must be separated by comma")?;
+    }
+    Ok((value, presence))
+}
+
+fn parse_component_range(input: &ParseBuffer, min: Option<&str>) -> syn::Result<ValueConstraint> {
+    let parse_int = |input: &ParseBuffer| -> syn::Result<i64> {
+        let negative = input.parse::<Option<Token![-]>>()?.is_some();
+        let value = input.parse::<syn::LitInt>()?.base10_parse::<i64>()?;
+        Ok(if negative { -value } else { value })
+    };
+    let min = match min {
+        Some(min) => min.to_string(),
+        None => parse_int(input)?.to_string(),
+    };
+    if input.peek(Token![..]) {
+        let _ = input.parse::<Token![..]>()?;
+        let max = if input.peek(syn::Ident) {
+            let ident = parse_ident(input, "Expected upper bound")?;
+            if !ident.eq_ignore_ascii_case("max") {
+                return Err(input.error(format!("Invalid upper bound: {}", ident)));
+            }
+            "MAX".to_string()
+        } else {
+            parse_int(input)?.to_string()
+        };
+        Ok(ValueConstraint::new(format!("{}..{}", min, max)))
+    } else {
+        Ok(ValueConstraint::new(min))
+    }
+}
+
 fn parse_type<'a>(input: &'a ParseBuffer<'a>) -> syn::Result<Type> {
     let ident = parse_ident(input, "Expected ASN-Type")?.to_lowercase();
     parse_type_pre_stepped(&ident, input)
@@ -118,7 +247,8 @@ fn parse_type_pre_stepped<'a>(
             let charset = &string[..len - "string".chars().count()];
             let charset = Charset::from_str(charset)
                 .map_err(|_| input.error(format!("Unexpected charset '{}'", charset)))?;
-            parse_opt_size_or_any(input).map(|size| Type::String(size, charset))
+            parse_opt_size_and_alphabet(input)
+                .map(|(size, alphabet)| Type::String(size, charset, alphabet))
         }
         "integer" => {
             if input.is_empty() {
@@ -144,18 +274,25 @@ fn parse_type_pre_stepped<'a>(
             let content;
             parenthesized!(content in input);
             let ident: syn::Ident = content.parse()?;
+            // the lifetime of a type that borrows from the decoded data, like `Inner<'a>`
+            let ident = if content.peek(Token![<]) {
+                let generics: syn::AngleBracketedGenericArguments = content.parse()?;
+                quote::quote!(#ident #generics)
+            } else {
+                quote::quote!(#ident)
+            };
             let _ = content.parse::<Token![,]>()?;
             let tag_ident: syn::Ident = content.parse()?;
             if !"tag".eq_ignore_ascii_case(&tag_ident.to_string()) {
                 return Err(input.error("Expected identifier 'tag'"));
             }
             let tag = AttrTag::parse(&content)?;
-            let mut key_val=None;
-            if !content.is_empty(){
+            let mut key_val = None;
+            if !content.is_empty() {
                 let _ = content.parse::<Token![,]>()?;
                 let key_or_tag_ident: syn::Ident = content.parse()?;
                 if "key".eq_ignore_ascii_case(&key_or_tag_ident.to_string()) {
-                    key_val=content.step(|s|{
+                    key_val = content.step(|s| {
                         let (group, _, outer) = s
                             .group(Delimiter::Parenthesis)
                             .ok_or_else(|| input.error("Expected parenthesis"))?;
@@ -163,14 +300,19 @@ fn parse_type_pre_stepped<'a>(
                             let number = literal.to_string().parse::<usize>().map_err(|_| {
                                 syn::Error::new(literal.span(), "Literal is not a number")
                             })?;
-                            Ok((Some(number),outer))
-                        }else {
+                            Ok((Some(number), outer))
+                        } else {
                             Err(syn::Error::new(group.span(), "Expected ref_id variant"))
                         }
                     })?;
                 }
             }
-            Ok(Type::TypeReferenceId(ident.to_string(), Some(tag.0),None,key_val))
+            Ok(Type::TypeReferenceId(
+                ident.to_string(),
+                Some(tag.0),
+                None,
+                key_val,
+            ))
         }
         "open_type" => {
             let content;
@@ -181,13 +323,13 @@ fn parse_type_pre_stepped<'a>(
             if !"ref_id".eq_ignore_ascii_case(&ref_id_ident.to_string()) {
                 return Err(input.error("Expected identifier 'ref_id'"));
             }
-            let ref_id_val=content.step(|s| {
+            let ref_id_val = content.step(|s| {
                 let (group, _, outer) = s
                     .group(Delimiter::Parenthesis)
                     .ok_or_else(|| input.error("Expected parenthesis"))?;
                 if let Some((variant, _)) = group.ident() {
-                    Ok((variant.to_string(),outer))
-                }else {
+                    Ok((variant.to_string(), outer))
+                } else {
                     Err(syn::Error::new(group.span(), "Expected ref_id variant"))
                 }
             })?;
@@ -197,7 +339,12 @@ fn parse_type_pre_stepped<'a>(
                 return Err(input.error("Expected identifier 'tag'"));
             }
             let tag = AttrTag::parse(&content)?;
-            Ok(Type::TypeReferenceId(ident.to_string(), Some(tag.0),Some(ref_id_val),None))
+            Ok(Type::TypeReferenceId(
+                ident.to_string(),
+                Some(tag.0),
+                Some(ref_id_val),
+                None,
+            ))
         }
         "option" | "optional" => {
             let content;
@@ -249,6 +396,28 @@ fn parse_type_pre_stepped<'a>(
                             }
                         })
                     })
+                    .or_else(|| {
+                        let reference = content.parse::<syn::ExprReference>().ok()?;
+                        if let syn::Expr::Array(array) = *reference.expr {
+                            array
+                                .elems
+                                .iter()
+                                .map(|element| match element {
+                                    syn::Expr::Lit(syn::ExprLit {
+                                        lit: syn::Lit::Int(int),
+                                        ..
+                                    }) => int
+                                        .base10_parse()
+                                        .ok()
+                                        .map(ObjectIdentifierComponent::NumberForm),
+                                    _ => None,
+                                })
+                                .collect::<Option<Vec<_>>>()
+                                .map(|arcs| LiteralValue::ObjectIdentifier(ObjectIdentifier(arcs)))
+                        } else {
+                            None
+                        }
+                    })
                     .ok_or_else(|| {
                         syn::Error::new(span, format!("Invalid literal value: {}", content))
                     })?,
@@ -256,6 +425,14 @@ fn parse_type_pre_stepped<'a>(
         }
         "boolean" => Ok(Type::Boolean),
         "null" => Ok(Type::Null),
+        "real" => Ok(Type::Real),
+        "object_identifier" => Ok(Type::ObjectIdentifier),
+        "relative_oid" => Ok(Type::RelativeOid),
+        "generalized_time" => Ok(Type::Time(TimeKind::GeneralizedTime)),
+        "utc_time" => Ok(Type::Time(TimeKind::UtcTime)),
+        "date" => Ok(Type::Time(TimeKind::Date)),
+        "time_of_day" => Ok(Type::Time(TimeKind::TimeOfDay)),
+        "duration" => Ok(Type::Time(TimeKind::Duration)),
         "sequence_of" | "set_of" => {
             let content;
             parenthesized!(content in input);
@@ -313,6 +490,38 @@ fn parse_opt_size_or_any(input: ParseStream) -> syn::Result<Size> {
     }
 }
 
+fn parse_opt_size_and_alphabet(
+    input: ParseStream,
+) -> syn::Result<(Size, Option<PermittedAlphabet>)> {
+    let mut size = Size::Any;
+    let mut alphabet = None;
+
+    if !input.is_empty() && input.peek(token::Paren) {
+        let content;
+        parenthesized!(content in input);
+        while !content.is_empty() {
+            let ident = parse_ident(&content, "Expected size or from")?.to_lowercase();
+            let constraint_content;
+            parenthesized!(constraint_content in content);
+
+            match ident.as_str() {
+                "size" => size = Size::parse(&constraint_content)?,
+                "from" => alphabet = Some(PermittedAlphabet::parse(&constraint_content)?),
+                _ => {
+                    return Err(content.error(format!(
+                        "Invalid identifier, expected none, size or from but got: {}",
+                        ident
+                    )))
+                }
+            }
+
+            eof_or_comma(&content, "Expected comma or end of constraints")?;
+        }
+    }
+
+    Ok((size, alphabet))
+}
+
 fn eof_or_comma<T: Display>(input: &ParseBuffer, msg: T) -> syn::Result<()> {
     if !input.cursor().eof() && !input.peek(syn::token::Comma) {
         Err(input.error(msg))
@@ -345,6 +554,10 @@ impl PrimaryContext for Type {
 
 impl PrimaryContext for Option<usize> {
     fn parse(input: &ParseBuffer<'_>) -> syn::Result<Self> {
+        if input.peek(syn::Ident) {
+            // no number but the first attribute
+            return Ok(None);
+        }
         input
             .step(|c| {
                 ident_or_literal_or_punct(*c)
@@ -368,7 +581,12 @@ pub trait Context: Debug {
     type Primary: PrimaryContext + Debug;
     const EXTENSIBLE_AFTER: bool;
     const TAGGABLE: bool;
+    const TAG_DEFAULT: bool = false;
+    const UNKNOWN_EXTENSIONS: bool = false;
+    const COMPONENTS: bool = false;
     const CONSTS: bool;
+    const IDENTIFIER: bool;
+    const KEY: bool;
 }
 
 impl Context for Choice {
@@ -376,6 +594,8 @@ impl Context for Choice {
     const EXTENSIBLE_AFTER: bool = true;
     const TAGGABLE: bool = true;
     const CONSTS: bool = false;
+    const IDENTIFIER: bool = false;
+    const KEY: bool = false;
 }
 
 impl Context for ChoiceVariant {
@@ -383,6 +603,8 @@ impl Context for ChoiceVariant {
     const EXTENSIBLE_AFTER: bool = false;
     const TAGGABLE: bool = true;
     const CONSTS: bool = false;
+    const IDENTIFIER: bool = true;
+    const KEY: bool = false;
 }
 
 impl Context for OpenType {
@@ -390,6 +612,8 @@ impl Context for OpenType {
     const EXTENSIBLE_AFTER: bool = false;
     const TAGGABLE: bool = true;
     const CONSTS: bool = false;
+    const IDENTIFIER: bool = false;
+    const KEY: bool = false;
 }
 
 impl Context for OpenTypeVariant {
@@ -397,6 +621,8 @@ impl Context for OpenTypeVariant {
     const EXTENSIBLE_AFTER: bool = false;
     const TAGGABLE: bool = true;
     const CONSTS: bool = false;
+    const IDENTIFIER: bool = true;
+    const KEY: bool = true;
 }
 
 impl Context for Enumerated {
@@ -404,6 +630,8 @@ impl Context for Enumerated {
     const EXTENSIBLE_AFTER: bool = true;
     const TAGGABLE: bool = true;
     const CONSTS: bool = false;
+    const IDENTIFIER: bool = false;
+    const KEY: bool = false;
 }
 
 impl Context for EnumeratedVariant {
@@ -411,6 +639,8 @@ impl Context for EnumeratedVariant {
     const EXTENSIBLE_AFTER: bool = false;
     const TAGGABLE: bool = false;
     const CONSTS: bool = false;
+    const IDENTIFIER: bool = true;
+    const KEY: bool = false;
 }
 
 #[derive(Debug)]
@@ -420,6 +650,8 @@ impl Context for Transparent {
     const EXTENSIBLE_AFTER: bool = false;
     const TAGGABLE: bool = true;
     const CONSTS: bool = true;
+    const IDENTIFIER: bool = true;
+    const KEY: bool = false;
 }
 
 #[derive(Debug)]
@@ -428,7 +660,12 @@ impl Context for DefinitionHeader {
     type Primary = Self;
     const EXTENSIBLE_AFTER: bool = true;
     const TAGGABLE: bool = true;
+    const TAG_DEFAULT: bool = true;
+    const UNKNOWN_EXTENSIONS: bool = true;
+    const COMPONENTS: bool = true;
     const CONSTS: bool = false;
+    const IDENTIFIER: bool = false;
+    const KEY: bool = false;
 }
 
 impl Deref for DefinitionHeader {
@@ -1,3 +1,4 @@
+mod alphabet;
 mod attribute;
 mod constants;
 mod inline;
@@ -8,8 +9,11 @@ mod tag;
 use crate::ast::attribute::{Context, DefinitionHeader, Transparent};
 use crate::ast::constants::ConstLit;
 use crate::model::lor::Resolved;
-use crate::model::{Choice, ChoiceVariant,OpenType,OpenTypeVariant, Definition, Enumerated, Field, Model, Type};
-use crate::model::{ComponentTypeList, EnumeratedVariant, TagProperty, TagResolver};
+use crate::model::InnerTypeConstraints;
+use crate::model::{
+    Choice, ChoiceVariant, Definition, Enumerated, Field, Model, OpenType, OpenTypeVariant, Type,
+};
+use crate::model::{ComponentTypeList, EnumeratedVariant, TagDefault, TagProperty, TagResolver};
 use attribute::AsnAttribute;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -21,6 +25,13 @@ use syn::{Attribute, Item};
 pub use inline::asn_to_rust;
 pub type AsnModelType = crate::model::Asn<crate::model::lor::Resolved>;
 
+/// The field of a SEQUENCE or SET that carries the extension additions unknown to its schema
+const UNKNOWN_EXTENSIONS_FIELD: &str = "unknown_extensions";
+/// The variant of an ENUMERATED or CHOICE that carries the values unknown to its schema
+const UNKNOWN_EXTENSION_VARIANT: &str = "UnknownExtension";
+/// The variant of an open type that carries the keys unknown to its object set
+const UNKNOWN_KEY_VARIANT: &str = "Unknown";
+
 pub fn parse(attr: TokenStream, item: TokenStream) -> TokenStream {
     if cfg!(feature = "debug-proc-macro") {
         println!();
@@ -30,13 +41,14 @@ pub fn parse(attr: TokenStream, item: TokenStream) -> TokenStream {
         println!();
     }
 
-    let (definition, item) = match parse_asn_definition(attr, item) {
-        Ok(v) => v,
-        Err(e) => {
-            println!("Errör: {}", e);
-            return e;
-        }
-    };
+    let (definition, tag_default, unknown_extensions, borrowed, item) =
+        match parse_definition(attr, item) {
+            Ok(v) => v,
+            Err(e) => {
+                println!("Errör: {}", e);
+                return e;
+            }
+        };
     if cfg!(feature = "debug-proc-macro") {
         println!("---------- parsed definition begin ----------");
         println!("{:#?}", definition);
@@ -49,7 +61,7 @@ pub fn parse(attr: TokenStream, item: TokenStream) -> TokenStream {
         println!();
     }
 
-    let additional_impl = expand(definition);
+    let additional_impl = expand_definition(definition, tag_default, unknown_extensions, borrowed);
 
     let result = quote! {
         #item
@@ -66,9 +78,31 @@ pub fn parse(attr: TokenStream, item: TokenStream) -> TokenStream {
 }
 
 pub fn expand(definition: Option<Definition<AsnModelType>>) -> Vec<TokenStream> {
+    expand_with_tag_default(definition, TagDefault::Automatic)
+}
+
+/// Like [`expand`], but for a definition in the given tagging environment - as declared through
+/// the `tags(...)` attribute
+pub fn expand_with_tag_default(
+    definition: Option<Definition<AsnModelType>>,
+    tag_default: TagDefault,
+) -> Vec<TokenStream> {
+    expand_definition(definition, tag_default, false, false)
+}
+
+/// The definition carries the extension additions unknown to its schema, if `unknown_extensions`
+/// is declared through its attribute, and borrows from the data it is decoded from, if it is
+/// declared with the lifetime `'a`
+fn expand_definition(
+    definition: Option<Definition<AsnModelType>>,
+    tag_default: TagDefault,
+    unknown_extensions: bool,
+    borrowed: bool,
+) -> Vec<TokenStream> {
     let mut additional_impl: Vec<TokenStream> = Vec::default();
     let mut model: Model<AsnModelType> = Model {
         name: "__proc_macro".to_string(),
+        tag_default,
         ..Default::default()
     };
 
@@ -81,9 +115,30 @@ pub fn expand(definition: Option<Definition<AsnModelType>>) -> Vec<TokenStream>
             println!("---------- parsed definition to rust end ----------");
             println!();
         }
-        additional_impl.push(
-            TokenStream::from_str(&AsnDefWriter::stringify(&model.to_rust_keep_names())).unwrap(),
-        );
+        let mut rust = model.to_rust_keep_names();
+        rust.definitions = rust
+            .definitions
+            .into_iter()
+            .map(|Definition(name, rust)| {
+                // as declared by the attribute, not as generated by default
+                Definition(
+                    name,
+                    if unknown_extensions {
+                        rust.with_unknown_extensions()
+                    } else {
+                        rust.without_unknown_extensions()
+                    },
+                )
+            })
+            .collect();
+        if borrowed {
+            rust.definitions = rust
+                .definitions
+                .into_iter()
+                .map(|Definition(name, rust)| Definition(name, rust.with_borrowed()))
+                .collect();
+        }
+        additional_impl.push(TokenStream::from_str(&AsnDefWriter::stringify(&rust)).unwrap());
     }
 
     additional_impl
@@ -93,6 +148,40 @@ pub fn parse_asn_definition(
     attr: TokenStream,
     item: TokenStream,
 ) -> Result<(Option<Definition<AsnModelType>>, Item), TokenStream> {
+    parse_asn_definition_with_tag_default(attr, item)
+        .map(|(definition, _tag_default, item)| (definition, item))
+}
+
+/// Like [`parse_asn_definition`], but also returns the tagging environment of the definition,
+/// which is [`TagDefault::Automatic`] if not declared otherwise through `tags(...)`
+pub fn parse_asn_definition_with_tag_default(
+    attr: TokenStream,
+    item: TokenStream,
+) -> Result<(Option<Definition<AsnModelType>>, TagDefault, Item), TokenStream> {
+    parse_definition(attr, item).map(
+        |(definition, tag_default, _unknown_extensions, _borrowed, item)| {
+            (definition, tag_default, item)
+        },
+    )
+}
+
+/// Also returns whether the definition carries the extension additions unknown to its schema in
+/// the field `unknown_extensions` or the variant `UnknownExtension`, which are not part of the
+/// ASN.1 definition, and whether it borrows from the data it is decoded from
+#[allow(clippy::type_complexity)]
+fn parse_definition(
+    attr: TokenStream,
+    item: TokenStream,
+) -> Result<
+    (
+        Option<Definition<AsnModelType>>,
+        TagDefault,
+        bool,
+        bool,
+        Item,
+    ),
+    TokenStream,
+> {
     let item_span = item.span();
     let attr_span = attr.span();
 
@@ -113,7 +202,46 @@ pub fn parse_asn_definition(
         // println!("Matching item {:?}", item);
     }
 
-    match item {
+    if asn.unknown_extensions
+        && asn.extensible_after.is_none()
+        && !asn.primary.eq_ignore_ascii_case("open_type")
+    {
+        compile_err_ts(
+            attr_span,
+            "Only extensible types can carry unknown extensions",
+        )?;
+    }
+
+    if !asn.components.is_empty()
+        && !["transparent", "sequence", "set"]
+            .iter()
+            .any(|primary| asn.primary.eq_ignore_ascii_case(primary))
+    {
+        compile_err_ts(
+            attr_span,
+            "Only sequences, sets and transparent types can constrain their components",
+        )?;
+    }
+
+    let borrowed = match &item {
+        Item::Struct(strct) => is_borrowed(&strct.generics)?,
+        Item::Enum(enm) => is_borrowed(&enm.generics)?,
+        _ => false,
+    };
+
+    if borrowed
+        && !["transparent", "sequence", "set", "choice"]
+            .iter()
+            .any(|primary| asn.primary.eq_ignore_ascii_case(primary))
+    {
+        compile_err_ts(
+            attr_span,
+            "Only sequences, sets, choices and transparent types can borrow from the decoded data",
+        )?;
+    }
+
+    let tag_default = asn.tag_default.unwrap_or(TagDefault::Automatic);
+    let (definition, item) = match item {
         Item::Struct(strct) if asn.primary.eq_ignore_ascii_case("sequence") => {
             parse_sequence_or_set(strct, &asn, attr_span, Type::Sequence)
         }
@@ -133,6 +261,26 @@ pub fn parse_asn_definition(
             parse_open_type(enm, &asn, attr_span)
         }
         item => Ok((None, item)),
+    }?;
+    Ok((
+        definition,
+        tag_default,
+        asn.unknown_extensions,
+        borrowed,
+        item,
+    ))
+}
+
+/// A type borrows from the data it is decoded from, if it is declared with the lifetime `'a`,
+/// which is the only supported generic parameter
+fn is_borrowed(generics: &syn::Generics) -> Result<bool, TokenStream> {
+    match generics.params.iter().collect::<Vec<_>>()[..] {
+        [] => Ok(false),
+        [syn::GenericParam::Lifetime(param)] if param.lifetime.ident == "a" => Ok(true),
+        _ => Err(compile_error_ts(
+            generics.span(),
+            "The lifetime 'a is the only supported generic parameter",
+        )),
     }
 }
 
@@ -145,6 +293,7 @@ fn parse_sequence_or_set<F: Fn(ComponentTypeList<Resolved>) -> Type>(
     let fields = strct
         .fields
         .iter_mut()
+        .filter(|f| !(asn.unknown_extensions && is_ident(&f.ident, UNKNOWN_EXTENSIONS_FIELD)))
         .map(|field| {
             if field.ident.is_none() {
                 compile_err_ts(
@@ -158,8 +307,9 @@ fn parse_sequence_or_set<F: Fn(ComponentTypeList<Resolved>) -> Type>(
                 &field.ty,
                 &mut field.attrs,
             )
-            .map(|asn| Field {
+            .map(|(identifier, asn)| Field {
                 name: field.ident.as_ref().unwrap().to_string(),
+                identifier,
                 role: asn,
             })
         })
@@ -175,13 +325,22 @@ fn parse_sequence_or_set<F: Fn(ComponentTypeList<Resolved>) -> Type>(
                     fields.iter().map(|v| &v.name),
                 )?,
                 fields,
+                components_of: Vec::default(),
             })
-            .opt_tagged(asn.tag),
+            .opt_tagged(asn.tag)
+            .with_tag_mode(asn.tag_mode)
+            .with_components(components(asn)),
         )),
         Item::Struct(strct),
     ))
 }
 
+fn components(asn: &AsnAttribute<DefinitionHeader>) -> Option<InnerTypeConstraints> {
+    Some(asn.components.clone())
+        .filter(|c| !c.is_empty())
+        .map(InnerTypeConstraints::from_components)
+}
+
 fn parse_transparent(
     mut strct: syn::ItemStruct,
     asn: &AsnAttribute<DefinitionHeader>,
@@ -200,11 +359,14 @@ fn parse_transparent(
         &field.ty,
         &mut field.attrs,
     )
-    .map(|parsed| {
+    .map(|(_identifier, parsed)| {
         (
             Some(Definition(
                 strct.ident.to_string(),
-                parsed.with_tag_opt(asn.tag),
+                parsed
+                    .with_tag_opt(asn.tag)
+                    .with_tag_mode(asn.tag_mode)
+                    .with_components(components(asn)),
             )),
             Item::Struct(strct),
         )
@@ -218,6 +380,7 @@ fn parse_enumerated(
 ) -> Result<(Option<Definition<AsnModelType>>, Item), TokenStream> {
     enm.variants
         .iter()
+        .filter(|v| !(asn.unknown_extensions && v.ident == UNKNOWN_EXTENSION_VARIANT))
         .find(|v| !v.fields.is_empty())
         .map(|v| {
             compile_err_ts(
@@ -230,6 +393,7 @@ fn parse_enumerated(
     let variants = enm
         .variants
         .iter_mut()
+        .filter(|v| !(asn.unknown_extensions && v.ident == UNKNOWN_EXTENSION_VARIANT))
         .map(|v| {
             let variant = EnumeratedVariant::from_name(v.ident.to_string());
             let attributes = index_of_first_asn_attribute(&v.attrs).map(|_index| {
@@ -241,7 +405,9 @@ fn parse_enumerated(
                         compile_err_ts(v.span(), "ENUMERATED Variants must not have a Tag")?;
                     }
 
-                    Ok(variant.with_number_opt(attr.primary))
+                    Ok(variant
+                        .with_number_opt(attr.primary)
+                        .with_identifier_opt(attr.identifier))
                 })
             } else {
                 Ok(variant)
@@ -256,7 +422,9 @@ fn parse_enumerated(
     Ok((
         Some(Definition(
             enm.ident.to_string(),
-            Type::Enumerated(enumerated).opt_tagged(asn.tag),
+            Type::Enumerated(enumerated)
+                .opt_tagged(asn.tag)
+                .with_tag_mode(asn.tag_mode),
         )),
         Item::Enum(enm),
     ))
@@ -281,6 +449,7 @@ fn parse_choice(
     let variants = enm
         .variants
         .iter_mut()
+        .filter(|v| !(asn.unknown_extensions && v.ident == UNKNOWN_EXTENSION_VARIANT))
         .map(|v| {
             if v.fields.len() != 1 || v.fields.iter().next().unwrap().ident.is_some() {
                 compile_err_ts(
@@ -294,12 +463,13 @@ fn parse_choice(
                 &v.fields.iter().next().unwrap().ty,
                 &mut v.attrs,
             )
-            .map(|asn| {
+            .map(|(identifier, asn)| {
                 // TODO extensible
-                // TODO tags
                 ChoiceVariant {
                     name: v.ident.to_string(),
+                    identifier,
                     tag: asn.tag,
+                    tag_mode: asn.tag_mode,
                     r#type: asn.r#type,
                 }
             })
@@ -312,10 +482,16 @@ fn parse_choice(
         Choice::from_variants(variants.into_iter()).with_maybe_extension_after(extensible_after),
     );
 
-    let tag = asn.tag.or_else(|| TagResolver::resolve_default(&choice));
+    let tag_default = asn.tag_default.unwrap_or(TagDefault::Automatic);
+    let tag = asn
+        .tag
+        .or_else(|| TagResolver::resolve_default_in(&choice, tag_default));
 
     Ok((
-        Some(Definition(enm.ident.to_string(), choice.opt_tagged(tag))),
+        Some(Definition(
+            enm.ident.to_string(),
+            choice.opt_tagged(tag).with_tag_mode(asn.tag_mode),
+        )),
         Item::Enum(enm),
     ))
 }
@@ -328,6 +504,7 @@ fn parse_open_type(
 ) -> Result<(Option<Definition<AsnModelType>>, Item), TokenStream> {
     enm.variants
         .iter()
+        .filter(|v| !(asn.unknown_extensions && v.ident == UNKNOWN_KEY_VARIANT))
         .find(|v| v.fields.is_empty())
         .map(|v| {
             compile_err_ts(
@@ -340,6 +517,7 @@ fn parse_open_type(
     let variants = enm
         .variants
         .iter_mut()
+        .filter(|v| !(asn.unknown_extensions && v.ident == UNKNOWN_KEY_VARIANT))
         .map(|v| {
             if v.fields.len() != 1 || v.fields.iter().next().unwrap().ident.is_some() {
                 compile_err_ts(
@@ -348,21 +526,24 @@ fn parse_open_type(
                 )?;
             }
 
-            parse_and_remove_first_asn_attribute_type::<OpenTypeVariant>(
-                v.span(),
-                &v.fields.iter().next().unwrap().ty,
-                &mut v.attrs,
-            )
-                .map(|asn| {
+            parse_and_remove_first_asn_attribute::<OpenTypeVariant>(v.span(), &mut v.attrs).map(
+                |mut attribute| {
+                    let identifier = attribute.identifier.take();
+                    let key = attribute.key;
+                    let key_object_identifier = attribute.key_object_identifier.take();
+                    let asn = into_asn(&v.fields.iter().next().unwrap().ty, attribute);
                     // TODO extensible
                     // TODO tags
                     OpenTypeVariant {
                         name: v.ident.to_string(),
+                        identifier,
                         tag: asn.tag,
+                        key: key.or_else(|| asn.r#type.get_key()),
+                        key_object_identifier,
                         r#type: asn.r#type,
-                        key:None,
                     }
-                })
+                },
+            )
         })
         .vec_result()?;
     let extensible_after = find_extensible_index(asn, asn_span, variants.iter().map(|v| v.name()))?;
@@ -379,6 +560,10 @@ fn parse_open_type(
     ))
 }
 
+fn is_ident(ident: &Option<syn::Ident>, name: &str) -> bool {
+    ident.as_ref().is_some_and(|ident| ident == name)
+}
+
 fn find_extensible_index(
     asn: &AsnAttribute<DefinitionHeader>,
     asn_span: proc_macro2::Span,
@@ -403,12 +588,14 @@ fn find_extensible_index(
         .transpose()
 }
 
+/// Returns the ASN.1 identifier - if given - and the type of the attribute
 fn parse_and_remove_first_asn_attribute_type<C: Context<Primary = Type>>(
     span: proc_macro2::Span,
     ty: &syn::Type,
     attrs: &mut Vec<Attribute>,
-) -> Result<AsnModelType, TokenStream> {
-    parse_and_remove_first_asn_attribute::<C>(span, attrs).map(|asn| into_asn(ty, asn))
+) -> Result<(Option<String>, AsnModelType), TokenStream> {
+    parse_and_remove_first_asn_attribute::<C>(span, attrs)
+        .map(|mut asn| (asn.identifier.take(), into_asn(ty, asn)))
 }
 
 fn parse_and_remove_first_asn_attribute<C: Context>(
@@ -427,6 +614,7 @@ fn parse_and_remove_first_asn_attribute<C: Context>(
 fn into_asn<C: Context<Primary = Type>>(ty: &syn::Type, mut asn: AsnAttribute<C>) -> AsnModelType {
     AsnModelType {
         tag: asn.tag,
+        tag_mode: asn.tag_mode,
         r#type: if let Type::TypeReference(_, empty_tag) = asn.primary {
             Type::TypeReference(quote! { #ty }.to_string(), empty_tag.or(asn.tag))
         } else {
@@ -441,6 +629,7 @@ fn into_asn<C: Context<Primary = Type>>(ty: &syn::Type, mut asn: AsnAttribute<C>
             asn.primary
         },
         default: asn.default_value,
+        components: None,
     }
 }
 
@@ -483,12 +672,12 @@ trait VecResult<T, E> {
         Self: Sized;
 }
 
-impl<T, E, I: ExactSizeIterator<Item = Result<T, E>>> VecResult<T, E> for I {
+impl<T, E, I: Iterator<Item = Result<T, E>>> VecResult<T, E> for I {
     fn vec_result(self) -> Result<Vec<T>, E>
     where
         Self: Sized,
     {
-        let mut result = Vec::with_capacity(self.len());
+        let mut result = Vec::with_capacity(self.size_hint().0);
         for value in self {
             result.push(value?);
         }
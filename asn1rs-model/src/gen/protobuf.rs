@@ -94,8 +94,14 @@ impl ProtobufDefGenerator {
             }
             Protobuf::Message(fields) => {
                 writeln!(target, "message {} {{", name)?;
-                for (prev_tag, (field_name, field_type)) in fields.iter().enumerate() {
-                    Self::append_field(target, model, field_name, field_type, prev_tag + 1)?;
+                let mut tag = 1;
+                for (field_name, field_type) in fields {
+                    Self::append_field(target, model, field_name, field_type, tag)?;
+                    // the variants of a oneof occupy one tag each
+                    tag += match field_type {
+                        ProtobufType::OneOf(variants) => variants.len(),
+                        _ => 1,
+                    };
                 }
                 writeln!(target, "}}")?;
             }
@@ -124,7 +130,7 @@ impl ProtobufDefGenerator {
                         "      {} {} = {};",
                         Self::role_to_full_type(variant_type, model),
                         variant_name,
-                        index + 1
+                        tag + index
                     )?;
                 }
                 write!(&mut inner, "    }}")?;
@@ -262,6 +268,8 @@ impl ProtobufDefGenerator {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::model::protobuf::ToProtobufModel;
+    use crate::parser::Tokenizer;
 
     #[test]
     fn test_protobuf_variant_name() {
@@ -269,4 +277,44 @@ mod tests {
         assert_eq!("ABC_DEF", ProtobufDefGenerator::variant_name("AbcDef"));
         assert_eq!("ABC_DEF", ProtobufDefGenerator::variant_name("ABcDef"));
     }
+
+    #[test]
+    fn test_open_type_message_carries_key_and_oneof() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r"OpenTypes DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+            PROTOCOL-IES ::= CLASS {
+                &id     INTEGER UNIQUE,
+                &Value
+            }
+            WITH SYNTAX {
+                ID &id TYPE &Value
+            }
+
+            IEs PROTOCOL-IES ::= { { ID 1 TYPE BOOLEAN } | { ID 7 TYPE UTF8String } }
+
+            Field ::= SEQUENCE {
+                id      PROTOCOL-IES.&id ({IEs}),
+                value   PROTOCOL-IES.&Value ({IEs}{@id})
+            }
+
+            END",
+        ))
+        .unwrap()
+        .try_resolve()
+        .unwrap()
+        .to_rust()
+        .to_protobuf();
+
+        let (_file_name, content) = ProtobufDefGenerator::generate_file(&model).unwrap();
+        assert!(content.contains(
+            "message FieldValue {
+    uint64 key = 1;
+    oneof value {
+      bool value1 = 2;
+      string value7 = 3;
+    };
+}"
+        ));
+    }
 }
@@ -10,13 +10,17 @@ pub mod async_psql;
 pub(crate) mod shared_psql;
 
 use crate::gen::Generator;
-use crate::model::rust::{DataEnum, Field};
+use crate::model::rust::{ComponentConstraint, DataEnum, Field, PermittedValue};
 use crate::model::rust::{EncodingOrdering, PlainEnum};
 use crate::model::Model;
+use crate::model::ParameterizedDefinition;
+use crate::model::PermittedAlphabet;
+use crate::model::PresenceConstraint;
 use crate::model::Rust;
 use crate::model::RustType;
 use crate::model::TagProperty;
 use crate::model::{Definition, Tag, Type as AsnType, Type};
+use crate::model::{TagDefault, TagMode};
 use codegen::Block;
 use codegen::Enum;
 use codegen::Impl;
@@ -58,6 +62,8 @@ pub struct RustCodeGenerator {
     global_derives: Vec<String>,
     direct_field_access: bool,
     getter_and_setter: bool,
+    unknown_extensions: bool,
+    borrowed: bool,
 }
 
 impl From<Model<Rust>> for RustCodeGenerator {
@@ -75,6 +81,8 @@ impl Default for RustCodeGenerator {
             global_derives: Vec::default(),
             direct_field_access: true,
             getter_and_setter: false,
+            unknown_extensions: false,
+            borrowed: false,
         }
     }
 }
@@ -130,6 +138,29 @@ impl RustCodeGenerator {
         self.getter_and_setter = allow;
     }
 
+    pub const fn preserves_unknown_extensions(&self) -> bool {
+        self.unknown_extensions
+    }
+
+    /// Lets all extensible SEQUENCE, SET, ENUMERATED and CHOICE types carry the extension
+    /// additions unknown to their schema, see [`Rust::with_unknown_extensions`]. This is not
+    /// supported by the psql supplements.
+    pub fn set_preserve_unknown_extensions(&mut self, preserve: bool) {
+        self.unknown_extensions = preserve;
+    }
+
+    pub const fn borrows_decoded_values(&self) -> bool {
+        self.borrowed
+    }
+
+    /// Lets all types that contain OCTET STRING or UTF8String values borrow these from the data
+    /// they are decoded from, see [`Model::with_borrowed_types`]. These values are then
+    /// represented as `Cow<'a, [u8]>` and `Cow<'a, str>` and the types are declared with the
+    /// lifetime `'a`. This is not supported by the psql supplements.
+    pub fn set_borrow_decoded_values(&mut self, borrow: bool) {
+        self.borrowed = borrow;
+    }
+
     pub fn to_string_without_generators(&self) -> Vec<(String, String)> {
         self.to_string_with_generators(&[])
     }
@@ -156,6 +187,12 @@ impl RustCodeGenerator {
             string
         };
 
+        let model = &if self.borrowed {
+            Cow::Owned(model.clone().with_borrowed_types())
+        } else {
+            Cow::Borrowed(model)
+        };
+
         let mut scope = Scope::new();
         generators.iter().for_each(|g| g.add_imports(&mut scope));
 
@@ -177,7 +214,15 @@ impl RustCodeGenerator {
         }
 
         for definition in &model.definitions {
-            self.add_definition(&mut scope, definition);
+            let definition = &if self.unknown_extensions {
+                Cow::Owned(Definition(
+                    definition.0.clone(),
+                    definition.1.clone().with_unknown_extensions(),
+                ))
+            } else {
+                Cow::Borrowed(definition)
+            };
+            self.add_definition_with_tag_default(&mut scope, definition, model.tag_default);
             Self::impl_definition(&mut scope, definition, generators, self.getter_and_setter);
 
             generators
@@ -196,111 +241,253 @@ impl RustCodeGenerator {
             r#type.to_const_lit_string(),
             if let RustType::Complex(..) = r#type {
                 format!("{}::new({})", r#type.to_const_lit_string(), value)
+            } else if let RustType::ObjectIdentifier | RustType::RelativeOid = r#type {
+                format!("{}::from_static({})", r#type.to_const_lit_string(), value)
             } else {
                 value.to_string()
             }
         )
     }
 
-    pub fn add_definition(&self, scope: &mut Scope, Definition(name, rust): &Definition<Rust>) {
+    pub fn add_definition(&self, scope: &mut Scope, definition: &Definition<Rust>) {
+        self.add_definition_with_tag_default(scope, definition, TagDefault::Automatic)
+    }
+
+    /// Like [`RustCodeGenerator::add_definition`], but for a definition of a module with the
+    /// given tagging environment, which is declared through the `tags(...)` attribute
+    pub fn add_definition_with_tag_default(
+        &self,
+        scope: &mut Scope,
+        Definition(name, rust): &Definition<Rust>,
+        tag_default: TagDefault,
+    ) {
+        let tags = Some(tag_default).filter(|t| *t != TagDefault::Automatic);
         match rust {
             Rust::Struct {
                 fields,
                 tag,
                 extension_after,
                 ordering,
+                unknown_extensions,
+                components,
+                borrowed,
             } => {
                 scope.raw(&Self::asn_attribute(
-                    match ordering {
-                        EncodingOrdering::Keep => "sequence",
-                        EncodingOrdering::Sort => "set",
-                    },
+                    Self::with_components_keyword(
+                        &Self::with_unknown_extensions_keyword(
+                            match ordering {
+                                EncodingOrdering::Keep => "sequence",
+                                EncodingOrdering::Sort => "set",
+                            },
+                            *unknown_extensions,
+                        ),
+                        components,
+                    ),
                     *tag,
+                    None,
+                    tags,
                     extension_after.map(|index| fields[index].name().to_string()),
                     &[],
+                    None,
                 ));
+                let str_ct = self.new_struct(scope, name, *borrowed);
                 Self::add_struct(
-                    self.new_struct(scope, name),
+                    str_ct,
                     name,
                     fields,
+                    components,
                     self.direct_field_access,
-                )
+                    tag_default,
+                    *borrowed,
+                );
+                if *unknown_extensions {
+                    Self::add_unknown_extensions_field(str_ct, self.direct_field_access);
+                }
             }
             Rust::Enum(plain) => {
                 scope.raw(&Self::asn_attribute(
-                    "enumerated",
+                    Self::with_unknown_extensions_keyword(
+                        "enumerated",
+                        plain.has_unknown_extensions(),
+                    ),
                     plain.tag(),
+                    None,
+                    None,
                     plain.extension_after_variant().cloned(),
                     &[],
+                    None,
                 ));
-                Self::add_enum(self.new_enum(scope, name, true), name, plain)
+                Self::add_enum(self.new_enum(scope, name, true, false), name, plain)
             }
             Rust::DataEnum(data) => {
                 scope.raw(&Self::asn_attribute(
-                    "choice",
+                    Self::with_unknown_extensions_keyword(
+                        if data.is_open_type() {
+                            "open_type"
+                        } else {
+                            "choice"
+                        },
+                        data.has_unknown_extensions(),
+                    ),
                     data.tag(),
+                    None,
+                    tags.filter(|_| !data.is_open_type()),
                     data.extension_after_variant().map(|v| v.name().to_string()),
                     &[],
+                    None,
                 ));
-                Self::add_data_enum(self.new_enum(scope, name, false), name, data)
+                Self::add_data_enum(
+                    self.new_enum(scope, name, false, data.is_borrowed()),
+                    name,
+                    data,
+                    tag_default,
+                )
             }
             Rust::TupleStruct {
                 r#type,
                 tag,
                 constants,
+                components,
+                borrowed,
             } => {
-                scope.raw(&Self::asn_attribute("transparent", *tag, None, &[]));
+                scope.raw(&Self::asn_attribute(
+                    Self::with_components_keyword("transparent", components),
+                    *tag,
+                    None,
+                    None,
+                    None,
+                    &[],
+                    None,
+                ));
                 Self::add_tuple_struct(
-                    self.new_struct(scope, name),
+                    self.new_struct(scope, name, *borrowed),
                     name,
                     r#type,
                     self.direct_field_access,
                     None,
                     &constants[..],
+                    *borrowed,
                 )
             }
         }
     }
 
-    fn add_struct(str_ct: &mut Struct, _name: &str, fields: &[Field], pub_access: bool) {
+    fn add_struct(
+        str_ct: &mut Struct,
+        _name: &str,
+        fields: &[Field],
+        components: &[ComponentConstraint],
+        pub_access: bool,
+        tag_default: TagDefault,
+        borrowed: bool,
+    ) {
         for field in fields {
+            let field_name = Self::rust_field_name(field.name(), true);
+            let r#type = match (field.ref_id(), field.r#type()) {
+                (Some(ref_id), RustType::Complex(inner, Some(tag))) => format!(
+                    "open_type({}, ref_id({}), {})",
+                    inner,
+                    ref_id,
+                    Self::asn_attribute_tag(*tag)
+                ),
+                // OPTIONAL in ASN.1, but constrained to be PRESENT
+                (_, r#type)
+                    if components
+                        .iter()
+                        .any(|c| c.is_present() && c.field == field.name()) =>
+                {
+                    Self::asn_attribute_type(&AsnType::Optional(Box::new(
+                        r#type.clone().into_asn(),
+                    )))
+                }
+                _ => Self::asn_attribute_type(&field.r#type().clone().into_asn()),
+            };
             str_ct.field(
                 &format!(
                     "{} {}{}",
                     Self::asn_attribute(
-                        &Self::asn_attribute_type(&field.r#type().clone().into_asn()),
+                        &r#type,
                         field.tag(),
+                        Self::tag_mode(field.tag(), field.is_explicit(), tag_default),
+                        None,
                         None,
                         field.constants(),
+                        Some(field.identifier()).filter(|i| *i != field_name),
                     ),
                     if pub_access { "pub " } else { "" },
-                    Self::rust_field_name(field.name(), true),
+                    field_name,
                 ),
-                field.r#type().to_string(),
+                Self::field_type_string(field.r#type(), borrowed),
             );
         }
     }
 
+    fn add_unknown_extensions_field(str_ct: &mut Struct, pub_access: bool) {
+        str_ct.field(
+            &format!("{}unknown_extensions", if pub_access { "pub " } else { "" }),
+            "UnknownExtensions",
+        );
+    }
+
     fn add_enum(en_m: &mut Enum, _name: &str, rust_enum: &PlainEnum) {
-        for variant in rust_enum.variants() {
-            en_m.new_variant(&Self::rust_variant_name(variant));
+        for (index, variant) in rust_enum.variants().enumerate() {
+            let variant_name = Self::rust_variant_name(variant);
+            match rust_enum.identifier(index).filter(|i| *i != variant_name) {
+                Some(identifier) => en_m.new_variant(&format!(
+                    "#[asn({})] {}",
+                    Self::asn_attribute_identifier(identifier),
+                    variant_name
+                )),
+                None => en_m.new_variant(&variant_name),
+            };
+        }
+        if rust_enum.has_unknown_extensions() {
+            en_m.new_variant("UnknownExtension(u64)");
         }
     }
 
-    fn add_data_enum(en_m: &mut Enum, _name: &str, enumeration: &DataEnum) {
+    fn add_data_enum(
+        en_m: &mut Enum,
+        _name: &str,
+        enumeration: &DataEnum,
+        tag_default: TagDefault,
+    ) {
         for variant in enumeration.variants() {
+            let variant_name = Self::rust_variant_name(variant.name());
+            let r#type = Self::asn_attribute_type(&variant.r#type().clone().into_asn());
             en_m.new_variant(&format!(
                 "{} {}({})",
                 Self::asn_attribute(
-                    Self::asn_attribute_type(&variant.r#type().clone().into_asn()),
+                    match (variant.key(), variant.key_object_identifier()) {
+                        (Some(key), Some(arcs)) => format!(
+                            "{}, {}, {}",
+                            r#type,
+                            Self::asn_attribute_key(key),
+                            Self::asn_attribute_key_object_identifier(arcs)
+                        ),
+                        (Some(key), None) => {
+                            format!("{}, {}", r#type, Self::asn_attribute_key(key))
+                        }
+                        (None, _) => r#type,
+                    },
                     variant.tag(),
+                    Self::tag_mode(variant.tag(), variant.is_explicit(), tag_default),
+                    None,
                     None,
                     &[],
+                    Some(variant.identifier()).filter(|i| *i != variant_name),
                 ),
-                Self::rust_variant_name(variant.name()),
-                variant.r#type().to_string(),
+                variant_name,
+                Self::field_type_string(variant.r#type(), enumeration.is_borrowed()),
             ));
         }
+        if enumeration.has_unknown_extensions() {
+            en_m.new_variant(if enumeration.is_open_type() {
+                "Unknown { key: usize, bytes: Vec<u8> }"
+            } else {
+                "UnknownExtension(UnknownExtension)"
+            });
+        }
     }
 
     fn add_tuple_struct(
@@ -310,6 +497,7 @@ impl RustCodeGenerator {
         pub_access: bool,
         tag: Option<Tag>,
         constants: &[(String, String)],
+        borrowed: bool,
     ) {
         str_ct.tuple_field(format!(
             "{} {}{}",
@@ -317,25 +505,55 @@ impl RustCodeGenerator {
                 Self::asn_attribute_type(&inner.clone().into_asn()),
                 tag,
                 None,
+                None,
+                None,
                 constants,
+                None,
             ),
             if pub_access { "pub " } else { "" },
-            inner.to_string(),
+            Self::field_type_string(inner, borrowed),
         ));
     }
 
+    /// The `EXPLICIT` or `IMPLICIT` keyword of a tag, if it differs from what the tagging
+    /// environment implies
+    fn tag_mode(tag: Option<Tag>, explicit: bool, tag_default: TagDefault) -> Option<TagMode> {
+        let implied_explicit = tag_default == TagDefault::Explicit;
+        if tag.is_none() || explicit == implied_explicit {
+            None
+        } else if explicit {
+            Some(TagMode::Explicit)
+        } else {
+            Some(TagMode::Implicit)
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
     fn asn_attribute<T: ToString>(
         r#type: T,
         tag: Option<Tag>,
+        tag_mode: Option<TagMode>,
+        tag_default: Option<TagDefault>,
         extensible_after: Option<String>,
         constants: &[(String, String)],
+        identifier: Option<&str>,
     ) -> String {
         format!(
             "#[asn({})]",
             vec![
                 Some(r#type.to_string()),
                 tag.map(Self::asn_attribute_tag),
+                tag_mode.map(|mode| match mode {
+                    TagMode::Explicit => "explicit".to_string(),
+                    TagMode::Implicit => "implicit".to_string(),
+                }),
+                tag_default.map(|tags| match tags {
+                    TagDefault::Explicit => "tags(explicit)".to_string(),
+                    TagDefault::Implicit => "tags(implicit)".to_string(),
+                    TagDefault::Automatic => "tags(automatic)".to_string(),
+                }),
                 extensible_after.map(Self::asn_attribute_extensible_after),
+                identifier.map(Self::asn_attribute_identifier),
                 if constants.is_empty() {
                     None
                 } else {
@@ -382,12 +600,17 @@ impl RustCodeGenerator {
                     }
                 )],
             ),
-            Type::String(size, charset) => (
+            Type::String(size, charset, alphabet) => (
                 Cow::Owned(format!("{:?}string", charset).to_lowercase()),
-                vec![size.to_constraint_string()]
-                    .into_iter()
-                    .flatten()
-                    .collect(),
+                vec![
+                    size.to_constraint_string(),
+                    alphabet
+                        .as_ref()
+                        .map(PermittedAlphabet::to_constraint_string),
+                ]
+                .into_iter()
+                .flatten()
+                .collect(),
             ),
             Type::OctetString(size) => (
                 Cow::Borrowed("octet_string"),
@@ -404,6 +627,10 @@ impl RustCodeGenerator {
                     .collect()],
             ),
             Type::Null => (Cow::Borrowed("null"), Vec::default()),
+            Type::Real => (Cow::Borrowed("real"), Vec::default()),
+            Type::ObjectIdentifier => (Cow::Borrowed("object_identifier"), Vec::default()),
+            Type::RelativeOid => (Cow::Borrowed("relative_oid"), Vec::default()),
+            Type::Time(kind) => (Cow::Borrowed(kind.attribute_name()), Vec::default()),
             Type::Optional(inner) => (
                 Cow::Borrowed("optional"),
                 vec![Self::asn_attribute_type(inner)],
@@ -448,7 +675,17 @@ impl RustCodeGenerator {
                     .flatten()
                     .collect(),
             ),
-            Type::TypeReferenceId(inner, tag,_id,_key) => (
+            Type::ParameterizedReference(name, arguments) => (
+                Cow::Borrowed("complex"),
+                vec![ParameterizedDefinition::instance_name(name, arguments)],
+            ),
+            Type::ObjectClassField(field) => {
+                unreachable!(
+                    "{}.{} is replaced while resolving",
+                    field.class, field.field
+                )
+            }
+            Type::TypeReferenceId(inner, tag, _id, _key) => (
                 Cow::Borrowed("complex"),
                 vec![Some(inner.clone()), (*tag).map(Self::asn_attribute_tag)]
                     .into_iter()
@@ -472,10 +709,76 @@ impl RustCodeGenerator {
         }
     }
 
+    fn with_unknown_extensions_keyword(primary: &str, unknown_extensions: bool) -> String {
+        if unknown_extensions {
+            format!("{}, unknown_extensions", primary)
+        } else {
+            primary.to_string()
+        }
+    }
+
+    fn with_components_keyword(primary: &str, components: &[ComponentConstraint]) -> String {
+        if components.is_empty() {
+            primary.to_string()
+        } else {
+            format!(
+                "{}, components({})",
+                primary,
+                components
+                    .iter()
+                    .map(|component| format!(
+                        "{}({})",
+                        component.field,
+                        component
+                            .value
+                            .iter()
+                            .map(|value| match value {
+                                PermittedValue::Integer(min, max) => format!(
+                                    "{}..{}",
+                                    min.map_or_else(|| "min".to_string(), |v| v.to_string()),
+                                    max.map_or_else(|| "max".to_string(), |v| v.to_string())
+                                ),
+                                PermittedValue::Boolean(value) => value.to_string(),
+                                PermittedValue::Enumerated(identifier) => {
+                                    format!("{:?}", identifier)
+                                }
+                            })
+                            .chain(component.presence.iter().map(|presence| match presence {
+                                PresenceConstraint::Present => "present".to_string(),
+                                PresenceConstraint::Absent => "absent".to_string(),
+                                PresenceConstraint::Optional => "optional".to_string(),
+                            }))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        }
+    }
+
+    fn asn_attribute_key(key: usize) -> String {
+        format!("key({})", key)
+    }
+
+    fn asn_attribute_key_object_identifier(arcs: &[u64]) -> String {
+        format!(
+            "key_object_identifier({})",
+            arcs.iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+
     fn asn_attribute_extensible_after(variant: String) -> String {
         format!("extensible_after({})", variant)
     }
 
+    fn asn_attribute_identifier(identifier: &str) -> String {
+        format!("identifier({:?})", identifier)
+    }
+
     fn impl_definition(
         scope: &mut Scope,
         Definition(name, rust): &Definition<Rust>,
@@ -487,7 +790,10 @@ impl RustCodeGenerator {
                 fields,
                 tag: _,
                 extension_after: _,
+                unknown_extensions: _,
                 ordering: _,
+                components: _,
+                borrowed,
             } => {
                 Self::impl_consts(
                     scope,
@@ -495,8 +801,10 @@ impl RustCodeGenerator {
                     fields
                         .iter()
                         .map(|f| (f.name_type.0.as_str(), &f.name_type.1, &f.constants[..])),
+                    *borrowed,
                 );
-                let implementation = Self::impl_struct(scope, name, fields, getter_and_setter);
+                let implementation =
+                    Self::impl_struct(scope, name, fields, getter_and_setter, *borrowed);
                 for g in generators {
                     g.extend_impl_of_struct(name, implementation, fields);
                 }
@@ -513,76 +821,91 @@ impl RustCodeGenerator {
                 for g in generators {
                     g.extend_impl_of_data_enum(name, implementation, enumeration);
                 }
-                Self::impl_data_enum_default(scope, name, enumeration);
+                Self::impl_data_enum_default(scope, name, enumeration, enumeration.is_borrowed());
             }
             Rust::TupleStruct {
                 r#type: inner,
                 tag: _,
                 constants,
+                components: _,
+                borrowed,
             } => {
-                Self::impl_consts(scope, name, Some(("", inner, &constants[..])).into_iter());
-                let implementation = Self::impl_tuple_struct(scope, name, inner);
+                let borrowed = *borrowed;
+                Self::impl_consts(
+                    scope,
+                    name,
+                    Some(("", inner, &constants[..])).into_iter(),
+                    borrowed,
+                );
+                let implementation = Self::impl_tuple_struct(scope, name, inner, borrowed);
                 for g in generators {
                     g.extend_impl_of_tuple(name, implementation, inner);
                 }
-                Self::impl_tuple_struct_const_new(scope, name, inner);
-                Self::impl_tuple_struct_deref(scope, name, inner);
-                Self::impl_tuple_struct_deref_mut(scope, name, inner);
-                Self::impl_tuple_struct_from(scope, name, inner);
+                Self::impl_tuple_struct_const_new(scope, name, inner, borrowed);
+                Self::impl_tuple_struct_deref(scope, name, inner, borrowed);
+                Self::impl_tuple_struct_deref_mut(scope, name, inner, borrowed);
+                Self::impl_tuple_struct_from(scope, name, inner, borrowed);
             }
         }
     }
 
-    fn impl_tuple_struct_const_new(scope: &mut Scope, name: &str, rust: &RustType) {
-        scope
-            .new_impl(name)
+    fn impl_tuple_struct_const_new(scope: &mut Scope, name: &str, rust: &RustType, borrowed: bool) {
+        Self::new_impl(scope, name, borrowed)
             .new_fn("new")
             .vis("pub const")
-            .arg("value", rust.to_string())
+            .arg("value", Self::field_type_string(rust, borrowed))
             .ret("Self")
             .line("Self(value)");
     }
 
-    fn impl_tuple_struct_deref(scope: &mut Scope, name: &str, rust: &RustType) {
-        scope
-            .new_impl(name)
+    fn impl_tuple_struct_deref(scope: &mut Scope, name: &str, rust: &RustType, borrowed: bool) {
+        let rust = Self::field_type_string(rust, borrowed);
+        Self::new_impl(scope, name, borrowed)
             .impl_trait("::core::ops::Deref")
-            .associate_type("Target", rust.to_string())
+            .associate_type("Target", &rust)
             .new_fn("deref")
             .arg_ref_self()
-            .ret(&format!("&{}", rust.to_string()))
+            .ret(&format!("&{}", rust))
             .line("&self.0".to_string());
     }
 
-    fn impl_tuple_struct_deref_mut(scope: &mut Scope, name: &str, rust: &RustType) {
-        scope
-            .new_impl(name)
+    fn impl_tuple_struct_deref_mut(scope: &mut Scope, name: &str, rust: &RustType, borrowed: bool) {
+        Self::new_impl(scope, name, borrowed)
             .impl_trait("::core::ops::DerefMut")
             .new_fn("deref_mut")
             .arg_mut_self()
-            .ret(&format!("&mut {}", rust.to_string()))
+            .ret(&format!("&mut {}", Self::field_type_string(rust, borrowed)))
             .line("&mut self.0".to_string());
     }
 
-    fn impl_tuple_struct_from(scope: &mut Scope, name: &str, rust: &RustType) {
-        scope
-            .new_impl(name)
-            .impl_trait(format!("::core::convert::From<{}>", rust.to_string()))
+    fn impl_tuple_struct_from(scope: &mut Scope, name: &str, rust: &RustType, borrowed: bool) {
+        let rust = Self::field_type_string(rust, borrowed);
+        Self::new_impl(scope, name, borrowed)
+            .impl_trait(format!("::core::convert::From<{}>", rust))
             .new_fn("from")
-            .arg("value", &rust.to_string())
+            .arg("value", &rust)
             .ret("Self")
             .line("Self(value)");
-        scope
-            .new_impl(&rust.to_string())
+        let name = Self::definition_type_name(name, borrowed);
+        let implementation = scope.new_impl(&rust);
+        if borrowed {
+            implementation.generic("'a");
+        }
+        implementation
             .impl_trait(format!("::core::convert::From<{}>", name))
             .new_fn("from")
-            .arg("value", name)
+            .arg("value", name.as_ref())
             .ret("Self")
             .line("value.0");
     }
 
-    fn impl_tuple_struct<'a>(scope: &'a mut Scope, name: &str, rust: &RustType) -> &'a mut Impl {
-        let implementation = scope.new_impl(name);
+    fn impl_tuple_struct<'a>(
+        scope: &'a mut Scope,
+        name: &str,
+        rust: &RustType,
+        borrowed: bool,
+    ) -> &'a mut Impl {
+        let implementation = Self::new_impl(scope, name, borrowed);
         Self::add_min_max_fn_if_applicable(implementation, None, rust);
         implementation
     }
@@ -592,14 +915,16 @@ impl RustCodeGenerator {
         name: &str,
         fields: &[Field],
         getter_and_setter: bool,
+        borrowed: bool,
     ) -> &'a mut Impl {
-        let implementation = scope.new_impl(name);
+        let implementation = Self::new_impl(scope, name, borrowed);
 
         for field in fields {
             if getter_and_setter {
-                Self::impl_struct_field_get(implementation, field.name(), field.r#type());
-                Self::impl_struct_field_get_mut(implementation, field.name(), field.r#type());
-                Self::impl_struct_field_set(implementation, field.name(), field.r#type());
+                let field_type = Self::field_type_string(field.r#type(), borrowed);
+                Self::impl_struct_field_get(implementation, field.name(), &field_type);
+                Self::impl_struct_field_get_mut(implementation, field.name(), &field_type);
+                Self::impl_struct_field_set(implementation, field.name(), &field_type);
             }
 
             Self::add_min_max_fn_if_applicable(implementation, Some(field.name()), field.r#type());
@@ -611,11 +936,16 @@ impl RustCodeGenerator {
         scope: &mut Scope,
         name: &str,
         fields: impl Iterator<Item = (&'a str, &'a RustType, &'a [(String, String)])>,
+        borrowed: bool,
     ) {
         let mut found_consts = false;
         for (field, r#type, constants) in fields {
             if !found_consts && !constants.is_empty() {
-                scope.raw(&format!("impl {} {{", name));
+                scope.raw(&format!(
+                    "impl{} {} {{",
+                    if borrowed { "<'a>" } else { "" },
+                    Self::definition_type_name(name, borrowed)
+                ));
                 found_consts = true;
             }
             for (name, value) in constants {
@@ -636,37 +966,33 @@ impl RustCodeGenerator {
         }
     }
 
-    fn impl_struct_field_get(implementation: &mut Impl, field_name: &str, field_type: &RustType) {
+    fn impl_struct_field_get(implementation: &mut Impl, field_name: &str, field_type: &str) {
         implementation
             .new_fn(&Self::rust_field_name(field_name, true))
             .vis("pub")
             .arg_ref_self()
-            .ret(format!("&{}", field_type.to_string()))
+            .ret(format!("&{}", field_type))
             .line(format!("&self.{}", Self::rust_field_name(field_name, true)));
     }
 
-    fn impl_struct_field_get_mut(
-        implementation: &mut Impl,
-        field_name: &str,
-        field_type: &RustType,
-    ) {
+    fn impl_struct_field_get_mut(implementation: &mut Impl, field_name: &str, field_type: &str) {
         implementation
             .new_fn(&format!("{}_mut", field_name))
             .vis("pub")
             .arg_mut_self()
-            .ret(format!("&mut {}", field_type.to_string()))
+            .ret(format!("&mut {}", field_type))
             .line(format!(
                 "&mut self.{}",
                 Self::rust_field_name(field_name, true)
             ));
     }
 
-    fn impl_struct_field_set(implementation: &mut Impl, field_name: &str, field_type: &RustType) {
+    fn impl_struct_field_set(implementation: &mut Impl, field_name: &str, field_type: &str) {
         implementation
             .new_fn(&format!("set_{}", field_name))
             .vis("pub")
             .arg_mut_self()
-            .arg("value", field_type.to_string())
+            .arg("value", field_type)
             .line(format!(
                 "self.{} = value;",
                 Self::rust_field_name(field_name, true)
@@ -748,6 +1074,12 @@ impl RustCodeGenerator {
                     ordinal
                 ));
             });
+        if r_enum.has_unknown_extensions() {
+            block.line(format!(
+                "{}::UnknownExtension(index) => index as usize,",
+                name
+            ));
+        }
 
         ordinal_fn.push_block(block);
     }
@@ -757,7 +1089,7 @@ impl RustCodeGenerator {
         name: &str,
         enumeration: &DataEnum,
     ) -> &'a mut Impl {
-        let implementation = scope.new_impl(name);
+        let implementation = Self::new_impl(scope, name, enumeration.is_borrowed());
 
         Self::impl_data_enum_values_fn(implementation, name, enumeration);
         Self::impl_data_enum_value_index_fn(implementation, name, enumeration);
@@ -810,16 +1142,33 @@ impl RustCodeGenerator {
                     ordinal
                 ));
             });
+        if enumeration.has_unknown_extensions() && enumeration.is_open_type() {
+            // after all known variants
+            block.line(format!(
+                "{}::Unknown {{ .. }} => {},",
+                name,
+                enumeration.len()
+            ));
+        } else if enumeration.has_unknown_extensions() {
+            block.line(format!(
+                "{}::UnknownExtension(unknown) => unknown.index() as usize,",
+                name
+            ));
+        }
 
         ordinal_fn.push_block(block);
     }
 
-    fn impl_data_enum_default(scope: &mut Scope, name: &str, enumeration: &DataEnum) {
-        scope
-            .new_impl(name)
+    fn impl_data_enum_default(
+        scope: &mut Scope,
+        name: &str,
+        enumeration: &DataEnum,
+        borrowed: bool,
+    ) {
+        Self::new_impl(scope, name, borrowed)
             .impl_trait("Default")
             .new_fn("default")
-            .ret(name as &str)
+            .ret(Self::definition_type_name(name, borrowed).as_ref())
             .line(format!(
                 "{}::{}(Default::default())",
                 name,
@@ -827,6 +1176,32 @@ impl RustCodeGenerator {
             ));
     }
 
+    /// The type of a field or variant of a definition, see [`Rust::is_borrowed`]
+    fn field_type_string(r#type: &RustType, borrowed: bool) -> String {
+        if borrowed {
+            r#type.to_borrowed_string()
+        } else {
+            r#type.to_string()
+        }
+    }
+
+    /// The name of a definition - with its lifetime, if it is borrowed
+    fn definition_type_name(name: &str, borrowed: bool) -> Cow<'_, str> {
+        if borrowed {
+            Cow::Owned(format!("{}<'a>", name))
+        } else {
+            Cow::Borrowed(name)
+        }
+    }
+
+    fn new_impl<'a>(scope: &'a mut Scope, name: &str, borrowed: bool) -> &'a mut Impl {
+        let implementation = scope.new_impl(name);
+        if borrowed {
+            implementation.generic("'a").target_generic("'a");
+        }
+        implementation
+    }
+
     fn add_min_max_fn_if_applicable(
         implementation: &mut Impl,
         field_name: Option<&str>,
@@ -923,32 +1298,86 @@ impl RustCodeGenerator {
         out
     }
 
-    fn new_struct<'a>(&self, scope: &'a mut Scope, name: &str) -> &'a mut Struct {
+    /// Whether the definition with the given name contains - directly or through other
+    /// definitions - no REAL value, because `f64` does not implement `Hash`
+    fn is_hashable(&self, name: &str) -> bool {
+        self.is_hashable_definition(name, &mut Vec::default())
+    }
+
+    fn is_hashable_definition<'a>(&'a self, name: &'a str, visited: &mut Vec<&'a str>) -> bool {
+        if visited.contains(&name) {
+            return true;
+        }
+        visited.push(name);
+        let definition = self
+            .models
+            .iter()
+            .flat_map(|model| model.definitions.iter())
+            .find(|Definition(other, _)| name == other);
+        match definition {
+            Some(Definition(_, Rust::Struct { fields, .. })) => fields
+                .iter()
+                .all(|field| self.is_hashable_type(field.r#type(), visited)),
+            Some(Definition(_, Rust::DataEnum(data))) => data
+                .variants()
+                .all(|variant| self.is_hashable_type(variant.r#type(), visited)),
+            Some(Definition(_, Rust::TupleStruct { r#type, .. })) => {
+                self.is_hashable_type(r#type, visited)
+            }
+            Some(Definition(_, Rust::Enum(_))) | None => true,
+        }
+    }
+
+    fn is_hashable_type<'a>(&'a self, r#type: &'a RustType, visited: &mut Vec<&'a str>) -> bool {
+        match r#type.as_inner_type() {
+            RustType::F64 => false,
+            RustType::Complex(name, _) => self.is_hashable_definition(name, visited),
+            _ => true,
+        }
+    }
+
+    fn new_struct<'a>(&self, scope: &'a mut Scope, name: &str, borrowed: bool) -> &'a mut Struct {
         let str_ct = scope
             .new_struct(name)
             .vis("pub")
             .derive("Default")
             .derive("Debug")
             .derive("Clone")
-            .derive("PartialEq")
-            .derive("Hash");
+            .derive("PartialEq");
+        if self.is_hashable(name) {
+            str_ct.derive("Hash");
+        }
+        if borrowed {
+            str_ct.generic("'a");
+        }
         self.global_derives.iter().for_each(|derive| {
             str_ct.derive(derive);
         });
         str_ct
     }
 
-    fn new_enum<'a>(&self, scope: &'a mut Scope, name: &str, c_enum: bool) -> &'a mut Enum {
+    fn new_enum<'a>(
+        &self,
+        scope: &'a mut Scope,
+        name: &str,
+        c_enum: bool,
+        borrowed: bool,
+    ) -> &'a mut Enum {
         let en_m = scope
             .new_enum(name)
             .vis("pub")
             .derive("Debug")
             .derive("Clone")
-            .derive("PartialEq")
-            .derive("Hash");
+            .derive("PartialEq");
+        if self.is_hashable(name) {
+            en_m.derive("Hash");
+        }
         if c_enum {
             en_m.derive("Copy").derive("PartialOrd").derive("Eq");
         }
+        if borrowed {
+            en_m.generic("'a");
+        }
         self.global_derives.iter().for_each(|derive| {
             en_m.derive(derive);
         });
@@ -1047,4 +1476,124 @@ pub(crate) mod tests {
             &file_content,
         );
     }
+    #[test]
+    pub fn test_real_is_not_hashable() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r#"BasicReal DEFINITIONS AUTOMATIC TAGS ::=
+            BEGIN
+
+            Outer ::= SEQUENCE {
+                inner Inner
+            }
+
+            Inner ::= SEQUENCE {
+                value REAL
+            }
+
+            END
+        "#,
+        ))
+        .unwrap()
+        .try_resolve()
+        .unwrap()
+        .to_rust();
+
+        let (_file_name, file_content) = RustCodeGenerator::from(model)
+            .without_additional_global_derives()
+            .to_string_without_generators()
+            .into_iter()
+            .next()
+            .unwrap();
+
+        assert_starts_with_lines(
+            r#"
+            use asn1rs::prelude::*;
+
+            #[asn(sequence)]
+            #[derive(Default, Debug, Clone, PartialEq)]
+            pub struct Outer {
+                #[asn(complex(Inner, tag(UNIVERSAL(16))))] pub inner: Inner,
+            }
+
+            impl Outer {
+            }
+
+            #[asn(sequence)]
+            #[derive(Default, Debug, Clone, PartialEq)]
+            pub struct Inner {
+                #[asn(real)] pub value: f64,
+            }
+
+            impl Inner {
+            }
+        "#,
+            &file_content,
+        );
+    }
+
+    #[test]
+    pub fn test_borrow_decoded_values() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r#"BasicBorrowed DEFINITIONS AUTOMATIC TAGS ::=
+            BEGIN
+
+            Outer ::= SEQUENCE {
+                inner Inner,
+                id INTEGER (0..255)
+            }
+
+            Inner ::= SEQUENCE {
+                name UTF8String,
+                data OCTET STRING OPTIONAL
+            }
+
+            END
+        "#,
+        ))
+        .unwrap()
+        .try_resolve()
+        .unwrap()
+        .to_rust();
+
+        let mut generator = RustCodeGenerator::from(model);
+        generator.set_borrow_decoded_values(true);
+        let (_file_name, file_content) = generator
+            .without_additional_global_derives()
+            .to_string_without_generators()
+            .into_iter()
+            .next()
+            .unwrap();
+
+        assert_starts_with_lines(
+            r#"
+            use asn1rs::prelude::*;
+
+            #[asn(sequence)]
+            #[derive(Default, Debug, Clone, PartialEq, Hash)]
+            pub struct Outer<'a> {
+                #[asn(complex(Inner<'a>, tag(UNIVERSAL(16))))] pub inner: Inner<'a>,
+                #[asn(integer(0..255))] pub id: u8,
+            }
+
+            impl<'a> Outer<'a> {
+                pub const fn id_min() -> u8 {
+                    0
+                }
+
+                pub const fn id_max() -> u8 {
+                    255
+                }
+            }
+
+            #[asn(sequence)]
+            #[derive(Default, Debug, Clone, PartialEq, Hash)]
+            pub struct Inner<'a> {
+                #[asn(utf8string)] pub name: Cow<'a, str>,
+                #[asn(optional(octet_string))] pub data: Option<Cow<'a, [u8]>>,
+            }
+
+        "#,
+            &file_content,
+        );
+    }
 }
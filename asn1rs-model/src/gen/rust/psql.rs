@@ -79,7 +79,10 @@ impl PsqlInserter {
                 fields,
                 tag: _,
                 extension_after: _,
+                unknown_extensions: _,
                 ordering: _,
+                components: _,
+                borrowed: _,
             } => {
                 Self::impl_struct_insert_statement(
                     Self::new_insert_statement_fn(implementation),
@@ -412,7 +415,10 @@ impl PsqlInserter {
                 fields,
                 tag: _,
                 extension_after: _,
+                unknown_extensions: _,
                 ordering: _,
+                components: _,
+                borrowed: _,
             } => {
                 Self::impl_query_statement(Self::new_query_statement_fn(implementation), name);
                 Self::impl_struct_query_fn(Self::new_query_fn(implementation, true), name);
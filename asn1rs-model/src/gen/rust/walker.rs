@@ -1,9 +1,11 @@
 use crate::gen::RustCodeGenerator;
-use crate::model::rust::{DataEnum, EncodingOrdering, Field, PlainEnum};
+use crate::model::rust::{ComponentConstraint, DataEnum, EncodingOrdering, Field};
+use crate::model::rust::{PermittedValue, PlainEnum};
 use crate::model::{
-    Charset, Definition, LiteralValue, Model, Range, Rust, RustType, Size, Tag, TagProperty,
+    Charset, Definition, LiteralValue, Model, PermittedAlphabet, Range, Rust, RustType, Size, Tag,
+    TagDefault, TagProperty,
 };
-use codegen::{Block, Impl, Scope};
+use codegen::{Block, Function, Impl, Scope};
 use std::fmt::Display;
 
 pub const CRATE_SYN_PREFIX: &str = "::asn1rs::syn::";
@@ -17,25 +19,39 @@ impl AsnDefWriter {
         scope: &mut Scope,
         Definition(name, r#type): &Definition<Rust>,
     ) {
+        let borrowed = r#type.is_borrowed();
+        let lifetime = Self::lifetime(borrowed);
         match r#type {
             Rust::Struct {
                 fields,
                 tag: _,
                 extension_after: _,
+                unknown_extensions: _,
                 ordering,
+                components: _,
+                borrowed: _,
             } => {
                 scope.raw(&format!(
-                    "type AsnDef{} = {}{}<{}>;",
+                    "type AsnDef{}{} = {}{}<{}{}>;",
                     name,
+                    lifetime,
                     CRATE_SYN_PREFIX,
                     match ordering {
                         EncodingOrdering::Keep => "Sequence",
                         EncodingOrdering::Sort => "Set",
                     },
-                    name
+                    name,
+                    lifetime
                 ));
                 for field in fields {
-                    self.write_type_declaration(scope, name, field.name(), field.r#type());
+                    self.write_type_declaration(
+                        scope,
+                        name,
+                        field.name(),
+                        field.r#type(),
+                        field.is_explicit(),
+                        borrowed,
+                    );
                 }
             }
             Rust::Enum(_enm) => {
@@ -45,19 +61,26 @@ impl AsnDefWriter {
                 ));
             }
             Rust::DataEnum(enm) => {
-                if enm.is_open_type(){
+                if enm.is_open_type() {
                     scope.raw(&format!(
                         "type AsnDef{} = {}OpenType<{}>;",
                         name, CRATE_SYN_PREFIX, name
                     ));
-                }else{
+                } else {
                     scope.raw(&format!(
-                        "type AsnDef{} = {}Choice<{}>;",
-                        name, CRATE_SYN_PREFIX, name
+                        "type AsnDef{}{} = {}Choice<{}{}>;",
+                        name, lifetime, CRATE_SYN_PREFIX, name, lifetime
                     ));
                 }
                 for variant in enm.variants() {
-                    self.write_type_declaration(scope, name, variant.name(), variant.r#type());
+                    self.write_type_declaration(
+                        scope,
+                        name,
+                        variant.name(),
+                        variant.r#type(),
+                        variant.is_explicit(),
+                        borrowed,
+                    );
                 }
             }
             // Rust::OpenDataEnum(enm) => {
@@ -73,20 +96,40 @@ impl AsnDefWriter {
                 r#type: field,
                 tag: _,
                 constants: _,
+                components: _,
+                borrowed: _,
             } => {
                 scope.raw(&format!(
-                    "type AsnDef{} = {}Sequence<{}>;",
-                    name, CRATE_SYN_PREFIX, name
+                    "type AsnDef{}{} = {}Sequence<{}{}>;",
+                    name, lifetime, CRATE_SYN_PREFIX, name, lifetime
                 ));
-                self.write_type_declaration(scope, name, "0", field);
+                self.write_type_declaration(scope, name, "0", field, false, borrowed);
             }
         }
     }
 
     #[must_use]
     pub fn type_declaration(r#type: &RustType, name: &str) -> String {
+        Self::type_declaration_of(r#type, name, false)
+    }
+
+    /// Like [`AsnDefWriter::type_declaration`], but for a value that borrows from the data it is
+    /// decoded from, if `borrowed` and the value supports this (see [`RustType::is_borrowed`])
+    fn type_declaration_of(r#type: &RustType, name: &str, borrowed: bool) -> String {
         match r#type {
-            RustType::Bool => format!("{}Boolean", CRATE_SYN_PREFIX),
+            RustType::VecU8(_) if borrowed => format!(
+                "{}borrowed::OctetString<'a, {}Constraint>",
+                CRATE_SYN_PREFIX, name
+            ),
+            RustType::String(_, Charset::Utf8, _) if borrowed => format!(
+                "{}borrowed::Utf8String<'a, {}Constraint>",
+                CRATE_SYN_PREFIX, name
+            ),
+            RustType::Complex(inner, _tag) if borrowed && r#type.is_borrowed() => format!(
+                "{}borrowed::Complex<{}, {}Constraint>",
+                CRATE_SYN_PREFIX, inner, name
+            ),
+            RustType::Bool => format!("{}Boolean<{}Constraint>", CRATE_SYN_PREFIX, name),
             RustType::I8(_) => format!("{}Integer<i8, {}Constraint>", CRATE_SYN_PREFIX, name),
             RustType::U8(_) => format!("{}Integer<u8, {}Constraint>", CRATE_SYN_PREFIX, name),
             RustType::I16(_) => format!("{}Integer<i16, {}Constraint>", CRATE_SYN_PREFIX, name),
@@ -95,13 +138,26 @@ impl AsnDefWriter {
             RustType::U32(_) => format!("{}Integer<u32, {}Constraint>", CRATE_SYN_PREFIX, name),
             RustType::I64(_) => format!("{}Integer<i64, {}Constraint>", CRATE_SYN_PREFIX, name),
             RustType::U64(_) => format!("{}Integer<u64, {}Constraint>", CRATE_SYN_PREFIX, name),
-            RustType::String(_, charset) => format!(
+            RustType::F64 => format!("{}Real<{}Constraint>", CRATE_SYN_PREFIX, name),
+            RustType::String(_, charset, _) => format!(
                 "{}{:?}String<{}Constraint>",
                 CRATE_SYN_PREFIX, charset, name
             ),
             RustType::VecU8(_) => format!("{}OctetString<{}Constraint>", CRATE_SYN_PREFIX, name),
             RustType::BitVec(_) => format!("{}BitString<{}Constraint>", CRATE_SYN_PREFIX, name),
-            RustType::Null => format!("{}NullT", CRATE_SYN_PREFIX),
+            RustType::Null => format!("{}NullT<{}Constraint>", CRATE_SYN_PREFIX, name),
+            RustType::ObjectIdentifier => {
+                format!("{}ObjectIdentifierT<{}Constraint>", CRATE_SYN_PREFIX, name)
+            }
+            RustType::RelativeOid => {
+                format!("{}RelativeOidT<{}Constraint>", CRATE_SYN_PREFIX, name)
+            }
+            RustType::Time(kind) => format!(
+                "{}{}T<{}Constraint>",
+                CRATE_SYN_PREFIX,
+                kind.rust_type_name(),
+                name
+            ),
             RustType::Vec(inner, _, ordering) => {
                 let virtual_field = Self::vec_virtual_field_name(name);
                 format!(
@@ -111,11 +167,14 @@ impl AsnDefWriter {
                         EncodingOrdering::Keep => "SequenceOf",
                         EncodingOrdering::Sort => "SetOf",
                     },
-                    Self::type_declaration(inner, &virtual_field),
+                    Self::type_declaration_of(inner, &virtual_field, borrowed),
                     name
                 )
             }
-            RustType::Option(inner) => format!("Option<{}>", Self::type_declaration(inner, name)),
+            RustType::Option(inner) => format!(
+                "Option<{}>",
+                Self::type_declaration_of(inner, name, borrowed)
+            ),
             RustType::Default(inner, _default) => {
                 let virtual_field = Self::default_virtual_field_name(name);
                 format!(
@@ -131,10 +190,87 @@ impl AsnDefWriter {
         }
     }
 
-    fn write_type_declaration(&self, scope: &mut Scope, base: &str, name: &str, r#type: &RustType) {
+    /// Like [`AsnDefWriter::type_declaration`], but the value - within `Option` or `DefaultValue`
+    /// - is wrapped in an `ExplicitTag`, whose constraint carries the tag of the field
+    #[must_use]
+    pub fn explicit_type_declaration(r#type: &RustType, name: &str) -> String {
+        Self::explicit_type_declaration_of(r#type, name, false)
+    }
+
+    fn explicit_type_declaration_of(r#type: &RustType, name: &str, borrowed: bool) -> String {
+        match r#type {
+            RustType::Option(inner) => format!(
+                "Option<{}>",
+                Self::explicit_type_declaration_of(inner, name, borrowed)
+            ),
+            RustType::Default(inner, _default) => {
+                let virtual_field = Self::default_virtual_field_name(name);
+                format!(
+                    "{}DefaultValue<{}, {}Constraint>",
+                    CRATE_SYN_PREFIX,
+                    Self::explicit_type_declaration(inner, &virtual_field),
+                    name
+                )
+            }
+            r#type => format!(
+                "{}ExplicitTag<{}, {}Constraint>",
+                CRATE_SYN_PREFIX,
+                Self::type_declaration_of(r#type, name, borrowed),
+                Self::explicit_virtual_field_name(name)
+            ),
+        }
+    }
+
+    fn write_type_declaration(
+        &self,
+        scope: &mut Scope,
+        base: &str,
+        name: &str,
+        r#type: &RustType,
+        explicit: bool,
+        borrowed: bool,
+    ) {
         let combined = Self::combined_field_type_name(base, name);
-        let type_dec = Self::type_declaration(r#type, &Self::constraint_impl_name(&combined));
-        scope.raw(&format!("type AsnDef{} = {};", combined, type_dec));
+        let constraint_name = Self::constraint_impl_name(&combined);
+        let borrowed = borrowed && r#type.is_borrowed();
+        let type_dec = if explicit {
+            Self::explicit_type_declaration_of(r#type, &constraint_name, borrowed)
+        } else {
+            Self::type_declaration_of(r#type, &constraint_name, borrowed)
+        };
+        scope.raw(&format!(
+            "type AsnDef{}{} = {};",
+            combined,
+            Self::lifetime(borrowed),
+            type_dec
+        ));
+    }
+
+    /// The lifetime parameter of a type that borrows from the data it is decoded from
+    fn lifetime(borrowed: bool) -> &'static str {
+        if borrowed {
+            "<'a>"
+        } else {
+            ""
+        }
+    }
+
+    /// The impl block for a definition, which carries the lifetime `'a` if it is borrowed
+    fn definition_impl(name: &str, borrowed: bool) -> Impl {
+        let mut imp = Impl::new(name);
+        if borrowed {
+            imp.generic("'a").target_generic("'a");
+        }
+        imp
+    }
+
+    /// Like [`AsnDefWriter::definition_impl`], but pushed to the scope
+    fn new_definition_impl<'a>(scope: &'a mut Scope, name: &str, borrowed: bool) -> &'a mut Impl {
+        let imp = scope.new_impl(name);
+        if borrowed {
+            imp.generic("'a").target_generic("'a");
+        }
+        imp
     }
 
     fn constraint_impl_name(combined: &str) -> String {
@@ -150,17 +286,25 @@ impl AsnDefWriter {
         )
     }
 
-    fn write_constraints(&self, scope: &mut Scope, Definition(name, r#type): &Definition<Rust>) {
+    fn write_constraints(
+        &self,
+        scope: &mut Scope,
+        Definition(name, r#type): &Definition<Rust>,
+        tag_default: TagDefault,
+    ) {
         match r#type {
             Rust::Struct {
                 fields,
                 tag,
                 extension_after,
                 ordering,
+                unknown_extensions,
+                components,
+                borrowed,
             } => {
                 // ITU-T X.680 | ISO/IEC 8824-1, G.2.12.3 (SEQUENCE and SET)
-                let fields = Self::assign_implicit_tags(fields);
-                self.write_field_constraints(scope, name, &fields);
+                let fields = Self::assign_implicit_tags(fields, tag_default);
+                self.write_field_constraints(scope, name, &fields, true);
                 self.write_sequence_or_set_constraint(
                     scope,
                     name,
@@ -168,6 +312,10 @@ impl AsnDefWriter {
                     &fields,
                     *extension_after,
                     *ordering,
+                    false,
+                    *unknown_extensions,
+                    components,
+                    *borrowed,
                 );
             }
             Rust::Enum(plain) => {
@@ -176,24 +324,24 @@ impl AsnDefWriter {
             Rust::DataEnum(data) => {
                 let fields = data
                     .variants()
-                    .map(|variant| {
-                        Field {
-                            name_type: (variant.name().to_string(), variant.r#type().clone()),
-                            tag: variant.tag(),
-                            constants: Vec::default(),
-                            ref_id: None,
-                            key: variant.key(),
-                        }
+                    .map(|variant| Field {
+                        name_type: (variant.name().to_string(), variant.r#type().clone()),
+                        identifier: Some(variant.identifier().to_string()),
+                        tag: variant.tag(),
+                        constants: Vec::default(),
+                        ref_id: None,
+                        key: variant.key(),
+                        explicit: variant.is_explicit(),
                     })
                     .collect::<Vec<_>>();
 
                 // ITU-T X.680 | ISO/IEC 8824-1, G.2.12.3 (CHOICE)
-                let fields = Self::assign_implicit_tags(&fields);
-                self.write_field_constraints(scope, name, &fields);
-                if data.is_open_type(){
+                let fields = Self::assign_implicit_tags(&fields, tag_default);
+                self.write_field_constraints(scope, name, &fields, true);
+                if data.is_open_type() {
                     self.write_open_type_constraint(scope, name, data)
-                }else{
-                    self.write_choice_constraint(scope, name, data)
+                } else {
+                    self.write_choice_constraint(scope, name, data, &fields)
                 }
             }
             //TODO opendata enum
@@ -210,22 +358,26 @@ impl AsnDefWriter {
             //     // ITU-T X.680 | ISO/IEC 8824-1, G.2.12.3 (CHOICE)
             //     let fields = Self::assign_implicit_tags(&fields);
             //
-            //     self.write_field_constraints(scope, name, &fields);
+            //     self.write_field_constraints(scope, name, &fields, true);
             //     self.write_choice_constraint(scope, name, data)
             // }
             Rust::TupleStruct {
                 r#type,
                 tag,
                 constants,
+                components,
+                borrowed,
             } => {
                 let fields = [Field {
                     name_type: ("0".to_string(), r#type.clone()),
+                    identifier: None,
                     tag: *tag,
                     constants: constants.to_vec(),
-                    ref_id:None,
-                    key:None,
+                    ref_id: None,
+                    key: None,
+                    explicit: false,
                 }];
-                self.write_field_constraints(scope, name, &fields[..]);
+                self.write_field_constraints(scope, name, &fields[..], false);
                 self.write_sequence_or_set_constraint(
                     scope,
                     name,
@@ -233,38 +385,124 @@ impl AsnDefWriter {
                     &fields[..],
                     None,
                     EncodingOrdering::Keep,
+                    true,
+                    false,
+                    components,
+                    *borrowed,
                 );
+                if Self::is_key_type(r#type) {
+                    Self::write_key_impl(scope, name);
+                }
             }
         }
     }
 
-    fn write_field_constraints(&self, scope: &mut Scope, name: &str, fields: &[Field]) {
+    /// Whether values of the type can select the variant of an open type, which ENUMERATED
+    /// types can as well
+    fn is_key_type(r#type: &RustType) -> bool {
+        matches!(
+            r#type,
+            RustType::I8(_)
+                | RustType::U8(_)
+                | RustType::I16(_)
+                | RustType::U16(_)
+                | RustType::I32(_)
+                | RustType::U32(_)
+                | RustType::I64(_)
+                | RustType::U64(_)
+                | RustType::ObjectIdentifier
+        )
+    }
+
+    fn write_key_impl(scope: &mut Scope, name: &str) {
+        Self::new_definition_impl(scope, name, false)
+            .impl_trait(format!("{}opentype::Key", CRATE_SYN_PREFIX))
+            .new_fn("to_key")
+            .attr("inline")
+            .generic(&format!("C: {}opentype::Constraint", CRATE_SYN_PREFIX))
+            .arg_ref_self()
+            .ret("Option<usize>")
+            .line(format!(
+                "{}opentype::Key::to_key::<C>(&self.0)",
+                CRATE_SYN_PREFIX
+            ));
+    }
+
+    fn write_field_constraints(
+        &self,
+        scope: &mut Scope,
+        name: &str,
+        fields: &[Field],
+        named: bool,
+    ) {
         for field in fields {
             let constraint_name = Self::constraint_type_name(name, field.name());
             Self::write_constraint_type_decl(scope, &constraint_name);
-            self.write_field_constraint(scope, name, field, &constraint_name)
+            self.write_field_constraint(
+                scope,
+                name,
+                field,
+                &constraint_name,
+                Some(field.identifier()).filter(|_| named),
+            )
         }
     }
+
     fn write_field_constraint(
         &self,
         scope: &mut Scope,
         name: &str,
         field: &Field,
         constraint_type_name: &str,
+        identifier: Option<&str>,
     ) {
+        if field.explicit && !matches!(field.r#type(), RustType::Option(_) | RustType::Default(..))
+        {
+            // ITU-T X.690 | ISO/IEC 8825-1, 8.14.3, the tag is written around the value, which
+            // itself is encoded as if there were no tag
+            let explicit_field_name = Self::explicit_virtual_field_name(field.name());
+            let explicit_type_name = Self::constraint_type_name(name, &explicit_field_name);
+            Self::write_constraint_type_decl(scope, &explicit_type_name);
+            Self::write_common_constraint_type(
+                scope,
+                &explicit_type_name,
+                field.tag.expect("explicit tagging requires a tag"),
+                None,
+            );
+            scope
+                .new_impl(&explicit_type_name)
+                .impl_trait(format!("{}explicit::Constraint", CRATE_SYN_PREFIX));
+
+            let mut field = field.clone();
+            field.tag = None;
+            field.explicit = false;
+            return self.write_field_constraint(
+                scope,
+                name,
+                &field,
+                constraint_type_name,
+                identifier,
+            );
+        }
+
         match field.r#type() {
             RustType::Bool => {
                 Self::write_common_constraint_type(
                     scope,
                     constraint_type_name,
                     field.tag.unwrap_or(Tag::DEFAULT_BOOLEAN),
+                    identifier,
                 );
+                scope
+                    .new_impl(constraint_type_name)
+                    .impl_trait(format!("{}boolean::Constraint", CRATE_SYN_PREFIX));
             }
             RustType::I8(range) => {
                 Self::write_common_constraint_type(
                     scope,
                     constraint_type_name,
                     field.tag.unwrap_or(Tag::DEFAULT_INTEGER),
+                    identifier,
                 );
                 Self::write_integer_constraint_type(
                     scope,
@@ -278,6 +516,7 @@ impl AsnDefWriter {
                     scope,
                     constraint_type_name,
                     field.tag.unwrap_or(Tag::DEFAULT_INTEGER),
+                    identifier,
                 );
                 Self::write_integer_constraint_type(
                     scope,
@@ -291,6 +530,7 @@ impl AsnDefWriter {
                     scope,
                     constraint_type_name,
                     field.tag.unwrap_or(Tag::DEFAULT_INTEGER),
+                    identifier,
                 );
                 Self::write_integer_constraint_type(
                     scope,
@@ -304,6 +544,7 @@ impl AsnDefWriter {
                     scope,
                     constraint_type_name,
                     field.tag.unwrap_or(Tag::DEFAULT_INTEGER),
+                    identifier,
                 );
                 Self::write_integer_constraint_type(
                     scope,
@@ -317,6 +558,7 @@ impl AsnDefWriter {
                     scope,
                     constraint_type_name,
                     field.tag.unwrap_or(Tag::DEFAULT_INTEGER),
+                    identifier,
                 );
                 Self::write_integer_constraint_type(
                     scope,
@@ -330,6 +572,7 @@ impl AsnDefWriter {
                     scope,
                     constraint_type_name,
                     field.tag.unwrap_or(Tag::DEFAULT_INTEGER),
+                    identifier,
                 );
                 Self::write_integer_constraint_type(
                     scope,
@@ -343,6 +586,7 @@ impl AsnDefWriter {
                     scope,
                     constraint_type_name,
                     field.tag.unwrap_or(Tag::DEFAULT_INTEGER),
+                    identifier,
                 );
                 Self::write_integer_constraint_type(
                     scope,
@@ -356,6 +600,7 @@ impl AsnDefWriter {
                     scope,
                     constraint_type_name,
                     field.tag.unwrap_or(Tag::DEFAULT_INTEGER),
+                    identifier,
                 );
                 Self::write_integer_constraint_type(
                     scope,
@@ -364,23 +609,30 @@ impl AsnDefWriter {
                     range,
                 )
             }
-            RustType::String(size, charset) => {
+            RustType::String(size, charset, alphabet) => {
                 Self::write_common_constraint_type(
                     scope,
                     constraint_type_name,
                     field.tag.unwrap_or_else(|| charset.default_tag()),
+                    identifier,
                 );
-                Self::write_size_constraint(
+                Self::write_string_constraint(
                     match charset {
                         Charset::Utf8 => "utf8string",
                         Charset::Ia5 => "ia5string",
                         Charset::Numeric => "numericstring",
                         Charset::Printable => "printablestring",
                         Charset::Visible => "visiblestring",
+                        Charset::Teletex => "teletexstring",
+                        Charset::Graphic => "graphicstring",
+                        Charset::General => "generalstring",
+                        Charset::Universal => "universalstring",
+                        Charset::Bmp => "bmpstring",
                     },
                     scope,
                     constraint_type_name,
                     size,
+                    alphabet.as_ref(),
                 )
             }
             RustType::VecU8(size) => {
@@ -388,6 +640,7 @@ impl AsnDefWriter {
                     scope,
                     constraint_type_name,
                     field.tag.unwrap_or(Tag::DEFAULT_OCTET_STRING),
+                    identifier,
                 );
                 Self::write_size_constraint("octetstring", scope, constraint_type_name, size)
             }
@@ -396,6 +649,7 @@ impl AsnDefWriter {
                     scope,
                     constraint_type_name,
                     field.tag.unwrap_or(Tag::DEFAULT_BIT_STRING),
+                    identifier,
                 );
                 Self::write_size_constraint("bitstring", scope, constraint_type_name, size)
             }
@@ -403,7 +657,11 @@ impl AsnDefWriter {
                 Self::write_common_constraint_type(
                     scope,
                     constraint_type_name,
-                    field.tag.unwrap_or(Tag::DEFAULT_SEQUENCE_OF),
+                    field.tag.unwrap_or(match ordering {
+                        EncodingOrdering::Keep => Tag::DEFAULT_SEQUENCE_OF,
+                        EncodingOrdering::Sort => Tag::DEFAULT_SET_OF,
+                    }),
+                    identifier,
                 );
                 Self::write_size_constraint(
                     match ordering {
@@ -424,12 +682,15 @@ impl AsnDefWriter {
                     name,
                     &Field {
                         name_type: (virtual_field_name, *inner.clone()),
+                        identifier: None,
                         tag: None,
                         constants: field.constants().to_vec(),
                         ref_id: None,
-                        key:None,
+                        key: None,
+                        explicit: false,
                     },
                     &constraint_type_name,
+                    None,
                 )
             }
             RustType::Null => {
@@ -437,25 +698,77 @@ impl AsnDefWriter {
                     scope,
                     constraint_type_name,
                     field.tag.unwrap_or(Tag::DEFAULT_NULL),
+                    identifier,
+                );
+                scope
+                    .new_impl(constraint_type_name)
+                    .impl_trait(format!("{}null::Constraint", CRATE_SYN_PREFIX));
+            }
+            RustType::F64 => {
+                Self::write_common_constraint_type(
+                    scope,
+                    constraint_type_name,
+                    field.tag.unwrap_or(Tag::DEFAULT_REAL),
+                    identifier,
+                );
+                scope
+                    .new_impl(constraint_type_name)
+                    .impl_trait(format!("{}real::Constraint", CRATE_SYN_PREFIX));
+            }
+            RustType::ObjectIdentifier => {
+                Self::write_common_constraint_type(
+                    scope,
+                    constraint_type_name,
+                    field.tag.unwrap_or(Tag::DEFAULT_OBJECT_IDENTIFIER),
+                    identifier,
                 );
+                scope
+                    .new_impl(constraint_type_name)
+                    .impl_trait(format!("{}objectidentifier::Constraint", CRATE_SYN_PREFIX));
+            }
+            RustType::RelativeOid => {
+                Self::write_common_constraint_type(
+                    scope,
+                    constraint_type_name,
+                    field.tag.unwrap_or(Tag::DEFAULT_RELATIVE_OID),
+                    identifier,
+                );
+                scope
+                    .new_impl(constraint_type_name)
+                    .impl_trait(format!("{}relativeoid::Constraint", CRATE_SYN_PREFIX));
+            }
+            RustType::Time(kind) => {
+                Self::write_common_constraint_type(
+                    scope,
+                    constraint_type_name,
+                    field.tag.unwrap_or_else(|| kind.default_tag()),
+                    identifier,
+                );
+                scope
+                    .new_impl(constraint_type_name)
+                    .impl_trait(format!("{}time::Constraint", CRATE_SYN_PREFIX));
             }
             RustType::Option(inner) => self.write_field_constraint(
                 scope,
                 name,
                 &Field {
                     name_type: (field.name().to_string(), *inner.clone()),
+                    identifier: None,
                     tag: field.tag(),
                     constants: field.constants().to_vec(),
                     ref_id: None,
-                    key:None,
+                    key: None,
+                    explicit: field.explicit,
                 },
                 constraint_type_name,
+                identifier,
             ),
             RustType::Default(inner, default) => {
                 Self::write_common_constraint_type(
                     scope,
                     constraint_type_name,
                     field.tag.unwrap_or(Tag::DEFAULT_SEQUENCE_OF),
+                    identifier,
                 );
                 Self::write_default_constraint(scope, constraint_type_name, inner, default);
 
@@ -468,12 +781,15 @@ impl AsnDefWriter {
                     name,
                     &Field {
                         name_type: (virtual_field_name, *inner.clone()),
+                        identifier: None,
                         tag: field.tag,
                         constants: field.constants().to_vec(),
                         ref_id: None,
-                        key:None,
+                        key: None,
+                        explicit: field.explicit,
                     },
                     &constraint_type_name,
+                    identifier,
                 )
             }
             RustType::Complex(_, tag) => {
@@ -488,16 +804,29 @@ impl AsnDefWriter {
                             constraint_type_name
                         )
                     }),
+                    field.tag.is_some(),
+                    identifier,
                 );
             }
         }
     }
 
-    fn write_complex_constraint(&self, scope: &mut Scope, name: &str, tag: Tag) {
-        Self::write_common_constraint_type(scope, name, tag);
-        scope
-            .new_impl(name)
-            .impl_trait(format!("{}complex::Constraint", CRATE_SYN_PREFIX));
+    fn write_complex_constraint(
+        &self,
+        scope: &mut Scope,
+        name: &str,
+        tag: Tag,
+        tagged: bool,
+        identifier: Option<&str>,
+    ) {
+        Self::write_common_constraint_type(scope, name, tag, identifier);
+        let mut imp = Impl::new(name);
+        imp.impl_trait(format!("{}complex::Constraint", CRATE_SYN_PREFIX));
+        Self::insert_consts(
+            scope,
+            imp,
+            tagged.then(|| "const TAGGED: bool = true;".to_string()),
+        );
     }
 
     fn vec_virtual_field_name(field_name: &str) -> String {
@@ -508,6 +837,12 @@ impl AsnDefWriter {
         field_name.to_string() + "Value"
     }
 
+    fn explicit_virtual_field_name(field_name: &str) -> String {
+        field_name.to_string() + "Explicit"
+    }
+
+    #[allow(clippy::too_many_arguments)] // for now this is fine-ish
+    #[allow(clippy::too_many_arguments)]
     fn write_sequence_or_set_constraint(
         &self,
         scope: &mut Scope,
@@ -516,8 +851,21 @@ impl AsnDefWriter {
         fields: &[Field],
         extension_after_field: Option<usize>,
         ordering: EncodingOrdering,
+        transparent: bool,
+        unknown_extensions: bool,
+        components: &[ComponentConstraint],
+        borrowed: bool,
     ) {
-        Self::write_common_constraint_type(scope, name, tag.unwrap_or(Tag::DEFAULT_SEQUENCE));
+        let default_tag = match ordering {
+            EncodingOrdering::Keep => Tag::DEFAULT_SEQUENCE,
+            EncodingOrdering::Sort => Tag::DEFAULT_SET,
+        };
+        Self::write_definition_common_constraint_type(
+            scope,
+            name,
+            tag.unwrap_or(default_tag),
+            borrowed,
+        );
 
         let sorted;
         let (fields, module) = match ordering {
@@ -528,24 +876,102 @@ impl AsnDefWriter {
             }
         };
 
-        let mut imp = Impl::new(name);
+        let mut imp = Self::definition_impl(name, borrowed);
         imp.impl_trait(format!("{}{}::Constraint", CRATE_SYN_PREFIX, module));
 
-        self.write_sequence_or_set_constraint_read_fn(&mut imp, name, fields);
-        self.write_sequence_or_set_constraint_write_fn(&mut imp, name, fields);
+        self.write_sequence_or_set_constraint_read_fn(
+            &mut imp,
+            name,
+            fields,
+            unknown_extensions,
+            components,
+        );
+        self.write_sequence_or_set_constraint_write_fn(
+            &mut imp,
+            name,
+            fields,
+            transparent,
+            unknown_extensions,
+            components,
+        );
 
         Self::write_sequence_constraint_insert_consts(
             scope,
             name,
             fields,
             extension_after_field,
+            transparent,
+            components,
             imp,
         );
+
+        if borrowed {
+            Self::impl_borrowed_readable_sequence_or_set(
+                scope,
+                name,
+                fields,
+                module,
+                unknown_extensions,
+                components,
+            );
+        }
+    }
+
+    /// Reads the fields like `read_seq`, but lets these borrow from the data they are decoded from
+    fn impl_borrowed_readable_sequence_or_set(
+        scope: &mut Scope,
+        name: &str,
+        fields: &[Field],
+        module: &str,
+        unknown_extensions: bool,
+        components: &[ComponentConstraint],
+    ) {
+        let mut block = Block::new("Ok(Self");
+        for field in fields {
+            let combined = Self::combined_field_type_name(name, field.name());
+            block.line(format!(
+                "{}: {}?,",
+                field.name(),
+                if Self::is_present_field(field, components) {
+                    format!(
+                        "reader.read_present_borrowed::<AsnDef{}>({:?})",
+                        combined,
+                        field.name()
+                    )
+                } else {
+                    format!("AsnDef{}::read_value_borrowed(reader)", combined)
+                }
+            ));
+        }
+        if unknown_extensions {
+            // after all known fields
+            block.line("unknown_extensions: reader.read_unknown_extensions()?,");
+        }
+        block.after(")");
+
+        let mut closure = Block::new(&format!("reader.read_{}::<Self, Self, _>(|reader|", module));
+        closure.push_block(block).after(")");
+        Self::new_read_borrowed_fn(scope, name).push_block(closure);
+    }
+
+    fn new_read_borrowed_fn<'a>(scope: &'a mut Scope, name: &str) -> &'a mut Function {
+        Self::new_definition_impl(scope, name, true)
+            .impl_trait(format!(
+                "{}borrowed::BorrowedReadable<'a>",
+                CRATE_SYN_PREFIX
+            ))
+            .new_fn("read_borrowed")
+            .attr("inline")
+            .generic(&format!(
+                "R: {}borrowed::BorrowedReader<'a>",
+                CRATE_SYN_PREFIX
+            ))
+            .arg("reader", "&mut R")
+            .ret("Result<Self, R::Error>")
     }
 
     fn impl_readable(&self, scope: &mut Scope, Definition(name, r#type): &Definition<Rust>) {
-        let imp = scope
-            .new_impl(name)
+        let imp = Self::new_definition_impl(scope, name, r#type.is_borrowed())
             .impl_trait(format!("{}Readable", CRATE_SYN_PREFIX));
 
         imp.new_fn("read")
@@ -555,8 +981,8 @@ impl AsnDefWriter {
             .ret("Result<Self, R::Error>")
             .line(format!("AsnDef{}::read_value(reader)", name));
 
-        if let Rust::DataEnum(data)=r#type {
-            if data.is_open_type(){
+        if let Rust::DataEnum(data) = r#type {
+            if data.is_open_type() {
                 imp.new_fn("read_by_key")
                     .attr("inline")
                     .generic(&format!("R: {}Reader", CRATE_SYN_PREFIX))
@@ -566,12 +992,10 @@ impl AsnDefWriter {
                     .line(format!("AsnDef{}::read_value_by_key(reader,key)", name));
             }
         }
-
     }
 
-    fn impl_writable(&self, scope: &mut Scope, Definition(name, _): &Definition<Rust>) {
-        let imp = scope
-            .new_impl(name)
+    fn impl_writable(&self, scope: &mut Scope, Definition(name, r#type): &Definition<Rust>) {
+        let imp = Self::new_definition_impl(scope, name, r#type.is_borrowed())
             .impl_trait(format!("{}Writable", CRATE_SYN_PREFIX));
 
         imp.new_fn("write")
@@ -583,11 +1007,25 @@ impl AsnDefWriter {
             .line(format!("AsnDef{}::write_value(writer, self)", name));
     }
 
+    fn impl_validate(&self, scope: &mut Scope, Definition(name, r#type): &Definition<Rust>) {
+        Self::new_definition_impl(scope, name, r#type.is_borrowed())
+            .impl_trait(format!("{}Validate", CRATE_SYN_PREFIX))
+            .new_fn("validate")
+            .attr("inline")
+            .arg_ref_self()
+            .ret(format!("Result<(), {}Violations>", CRATE_SYN_PREFIX))
+            .line(format!(
+                "{}io::ValidationWriter::validate(self)",
+                CRATE_SYN_PREFIX
+            ));
+    }
+
     fn write_enumerated_constraint(&self, scope: &mut Scope, name: &str, enumerated: &PlainEnum) {
         Self::write_common_constraint_type(
             scope,
             name,
             enumerated.tag().unwrap_or(Tag::DEFAULT_ENUMERATED),
+            None,
         );
         let mut imp = Impl::new(name);
         imp.impl_trait(format!("{}enumerated::Constraint", CRATE_SYN_PREFIX));
@@ -601,6 +1039,9 @@ impl AsnDefWriter {
                 for (index, variant) in enumerated.variants().enumerate() {
                     match_block.line(format!("Self::{} => {},", variant, index));
                 }
+                if enumerated.has_unknown_extensions() {
+                    match_block.line("Self::UnknownExtension(index) => *index,");
+                }
                 match_block
             });
 
@@ -613,7 +1054,11 @@ impl AsnDefWriter {
                 for (index, variant) in enumerated.variants().enumerate() {
                     match_block.line(format!("{} => Some(Self::{}),", index, variant));
                 }
-                match_block.line("_ => None,");
+                if enumerated.has_unknown_extensions() {
+                    match_block.line("_ => Some(Self::UnknownExtension(index)),");
+                } else {
+                    match_block.line("_ => None,");
+                }
                 match_block
             });
 
@@ -622,6 +1067,12 @@ impl AsnDefWriter {
             imp,
             &[
                 format!("const NAME: &'static str = \"{}\";", name),
+                format!(
+                    "const IDENTIFIERS: &'static [&'static str] = &{:?};",
+                    (0..enumerated.len())
+                        .filter_map(|index| enumerated.identifier(index))
+                        .collect::<Vec<_>>()
+                ),
                 format!("const VARIANT_COUNT: u64 = {};", enumerated.len()),
                 format!(
                     "const STD_VARIANT_COUNT: u64 = {};",
@@ -635,30 +1086,42 @@ impl AsnDefWriter {
         );
     }
 
-    fn write_choice_constraint(&self, scope: &mut Scope, name: &str, choice: &DataEnum) {
-        Self::write_common_constraint_type(
+    fn write_choice_constraint(
+        &self,
+        scope: &mut Scope,
+        name: &str,
+        choice: &DataEnum,
+        fields: &[Field],
+    ) {
+        Self::write_definition_common_constraint_type(
             scope,
             name,
             choice.tag().unwrap_or_else(|| {
                 panic!("For at least one entry in {} the Tag is not assigned", name)
             }),
+            choice.is_borrowed(),
         );
-        let mut imp = Impl::new(name);
-        if choice.is_open_type(){
+        let mut imp = Self::definition_impl(name, choice.is_borrowed());
+        if choice.is_open_type() {
             imp.impl_trait(format!("{}opentype::Constraint", CRATE_SYN_PREFIX));
-        }else{
+        } else {
             imp.impl_trait(format!("{}choice::Constraint", CRATE_SYN_PREFIX));
         }
 
+        let indices = Self::choice_indices_canonically(fields, choice.extension_after_index());
+
         imp.new_fn("to_choice_index")
             .attr("inline")
             .arg_ref_self()
             .ret("u64")
             .push_block({
                 let mut match_block = Block::new("match self");
-                for (index, variant) in choice.variants().enumerate() {
+                for (variant, index) in choice.variants().zip(&indices) {
                     match_block.line(format!("Self::{}(_) => {},", variant.name(), index));
                 }
+                if choice.has_unknown_extensions() {
+                    match_block.line("Self::UnknownExtension(unknown) => unknown.index(),");
+                }
                 match_block
             });
 
@@ -678,6 +1141,10 @@ impl AsnDefWriter {
                         combined
                     ));
                 }
+                if choice.has_unknown_extensions() {
+                    // only encodings that can carry it re-emit the content
+                    match_block.line("Self::UnknownExtension(_) => Ok(()),");
+                }
                 match_block
             });
 
@@ -689,7 +1156,7 @@ impl AsnDefWriter {
             .ret("Result<Option<Self>, R::Error>")
             .push_block({
                 let mut match_block = Block::new("match index");
-                for (index, variant) in choice.variants().enumerate() {
+                for (variant, index) in choice.variants().zip(&indices) {
                     let combined = Self::combined_field_type_name(name, variant.name());
                     match_block.line(format!(
                         "{} => Ok(Some(Self::{}(AsnDef{}::read_value(reader)?))),",
@@ -702,6 +1169,25 @@ impl AsnDefWriter {
                 match_block
             });
 
+        if choice.has_unknown_extensions() {
+            imp.new_fn("from_unknown_extension")
+                .attr("inline")
+                .arg("unknown", format!("{}UnknownExtension", CRATE_SYN_PREFIX))
+                .ret("Option<Self>")
+                .line("Some(Self::UnknownExtension(unknown))");
+
+            imp.new_fn("unknown_extension")
+                .attr("inline")
+                .arg_ref_self()
+                .ret(format!("Option<&{}UnknownExtension>", CRATE_SYN_PREFIX))
+                .push_block({
+                    let mut match_block = Block::new("match self");
+                    match_block.line("Self::UnknownExtension(unknown) => Some(unknown),");
+                    match_block.line("_ => None,");
+                    match_block
+                });
+        }
+
         Self::insert_consts(
             scope,
             imp,
@@ -718,7 +1204,26 @@ impl AsnDefWriter {
                 format!("const EXTENSIBLE: bool = {};", choice.is_extensible()),
             ],
         );
+
+        if choice.is_borrowed() {
+            // like `read_content`, but lets the variants borrow from the data they are decoded from
+            let mut closure =
+                Block::new("reader.read_choice_borrowed::<Self, _>(|index, reader| match index");
+            for (variant, index) in choice.variants().zip(&indices) {
+                let combined = Self::combined_field_type_name(name, variant.name());
+                closure.line(format!(
+                    "{} => Ok(Some(Self::{}(AsnDef{}::read_value_borrowed(reader)?))),",
+                    index,
+                    variant.name(),
+                    combined
+                ));
+            }
+            closure.line("_ => Ok(None),");
+            closure.after(")");
+            Self::new_read_borrowed_fn(scope, name).push_block(closure);
+        }
     }
+
     fn write_open_type_constraint(&self, scope: &mut Scope, name: &str, choice: &DataEnum) {
         Self::write_common_constraint_type(
             scope,
@@ -726,11 +1231,12 @@ impl AsnDefWriter {
             choice.tag().unwrap_or_else(|| {
                 panic!("For at least one entry in {} the Tag is not assigned", name)
             }),
+            None,
         );
         let mut imp = Impl::new(name);
-        if choice.is_open_type(){
+        if choice.is_open_type() {
             imp.impl_trait(format!("{}opentype::Constraint", CRATE_SYN_PREFIX));
-        }else{
+        } else {
             imp.impl_trait(format!("{}choice::Constraint", CRATE_SYN_PREFIX));
         }
 
@@ -741,7 +1247,14 @@ impl AsnDefWriter {
             .push_block({
                 let mut match_block = Block::new("match self");
                 for (_, variant) in choice.variants().enumerate() {
-                    match_block.line(format!("Self::{}(_) => {},", variant.name(), variant.key().unwrap()));
+                    match_block.line(format!(
+                        "Self::{}(_) => {},",
+                        variant.name(),
+                        variant.key().unwrap()
+                    ));
+                }
+                if choice.has_unknown_extensions() {
+                    match_block.line("Self::Unknown { key, .. } => *key,");
                 }
                 match_block
             });
@@ -762,6 +1275,10 @@ impl AsnDefWriter {
                         combined
                     ));
                 }
+                if choice.has_unknown_extensions() {
+                    // only encodings that can carry it re-emit the content
+                    match_block.line("Self::Unknown { .. } => Ok(()),");
+                }
                 match_block
             });
 
@@ -786,6 +1303,26 @@ impl AsnDefWriter {
                 match_block
             });
 
+        if choice.has_unknown_extensions() {
+            imp.new_fn("from_unknown_key")
+                .attr("inline")
+                .arg("key", "usize")
+                .arg("bytes", "Vec<u8>")
+                .ret("Option<Self>")
+                .line("Some(Self::Unknown { key, bytes })");
+
+            imp.new_fn("unknown_bytes")
+                .attr("inline")
+                .arg_ref_self()
+                .ret("Option<&[u8]>")
+                .push_block({
+                    let mut match_block = Block::new("match self");
+                    match_block.line("Self::Unknown { bytes, .. } => Some(bytes),");
+                    match_block.line("_ => None,");
+                    match_block
+                });
+        }
+
         Self::insert_consts(
             scope,
             imp,
@@ -800,19 +1337,73 @@ impl AsnDefWriter {
                         .unwrap_or_else(|| choice.len())
                 ),
                 format!("const EXTENSIBLE: bool = {};", choice.is_extensible()),
+                format!(
+                    "const KEYS: &'static [usize] = &[{}];",
+                    choice
+                        .variants()
+                        .map(|variant| variant.key().unwrap().to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+                format!(
+                    "const OBJECT_IDENTIFIERS: &'static [&'static [u64]] = &[{}];",
+                    choice
+                        .variants()
+                        .filter_map(|variant| variant.key_object_identifier())
+                        .map(|arcs| format!("&{:?}", arcs))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
             ],
         );
     }
 
-    fn write_common_constraint_type(scope: &mut Scope, constraint_type_name: &str, tag: Tag) {
+    fn write_definition_common_constraint_type(
+        scope: &mut Scope,
+        name: &str,
+        tag: Tag,
+        borrowed: bool,
+    ) {
+        let lifetime = Self::lifetime(borrowed);
+        Self::write_common_constraint_impl(
+            scope,
+            lifetime,
+            &format!("{}{}", name, lifetime),
+            tag,
+            None,
+        );
+    }
+
+    fn write_common_constraint_type(
+        scope: &mut Scope,
+        constraint_type_name: &str,
+        tag: Tag,
+        identifier: Option<&str>,
+    ) {
+        Self::write_common_constraint_impl(scope, "", constraint_type_name, tag, identifier);
+    }
+
+    fn write_common_constraint_impl(
+        scope: &mut Scope,
+        generics: &str,
+        target: &str,
+        tag: Tag,
+        identifier: Option<&str>,
+    ) {
         scope.raw(&format!(
-            "impl {}common::Constraint for {} {{",
-            CRATE_SYN_PREFIX, constraint_type_name
+            "impl{} {}common::Constraint for {} {{",
+            generics, CRATE_SYN_PREFIX, target
         ));
         scope.raw(&format!(
             "const TAG: {}Tag = {}Tag::{:?};",
             CRATE_MODEL_PREFIX, CRATE_MODEL_PREFIX, tag
         ));
+        if let Some(identifier) = identifier {
+            scope.raw(&format!(
+                "const IDENTIFIER: Option<&'static str> = Some({:?});",
+                identifier
+            ));
+        }
         scope.raw("}");
     }
 
@@ -861,6 +1452,11 @@ impl AsnDefWriter {
             "impl {}{}::Constraint for {} {{",
             CRATE_SYN_PREFIX, module, constraint_type_name
         ));
+        Self::write_size_constraint_consts(scope, size);
+        scope.raw("}");
+    }
+
+    fn write_size_constraint_consts(scope: &mut Scope, size: &Size) {
         if let Some(min) = size.min() {
             scope.raw(&format!("const MIN: Option<u64> = Some({});", min));
         }
@@ -868,6 +1464,32 @@ impl AsnDefWriter {
             scope.raw(&format!("const MAX: Option<u64> = Some({});", max));
         }
         scope.raw(&format!("const EXTENSIBLE: bool = {};", size.extensible()));
+    }
+
+    fn write_string_constraint(
+        module: &str,
+        scope: &mut Scope,
+        constraint_type_name: &str,
+        size: &Size,
+        alphabet: Option<&PermittedAlphabet>,
+    ) {
+        scope.raw(&format!(
+            "impl {}{}::Constraint for {} {{",
+            CRATE_SYN_PREFIX, module, constraint_type_name
+        ));
+        Self::write_size_constraint_consts(scope, size);
+        // an extensible permitted alphabet is not PER-visible
+        if let Some(alphabet) = alphabet.filter(|alphabet| !alphabet.extensible()) {
+            scope.raw(&format!(
+                "const PERMITTED_ALPHABET: Option<&'static [(char, char)]> = Some(&[{}]);",
+                alphabet
+                    .ranges()
+                    .iter()
+                    .map(|(start, end)| format!("({:?}, {:?})", start, end))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
         scope.raw("}");
     }
 
@@ -922,6 +1544,17 @@ impl AsnDefWriter {
                         .to_string(),
                 ),
             ),
+            t @ RustType::ObjectIdentifier | t @ RustType::RelativeOid => (
+                t.to_const_lit_string(),
+                t.to_const_lit_string(),
+                Cow::Owned(format!(
+                    "{}::from_static({})",
+                    t.to_const_lit_string(),
+                    default.as_rust_const_literal_expect(false, |l| {
+                        matches!(l, LiteralValue::ObjectIdentifier(..))
+                    })
+                )),
+            ),
             t => (
                 Cow::Owned(t.to_string()),
                 t.to_const_lit_string(),
@@ -942,31 +1575,33 @@ impl AsnDefWriter {
         name: &str,
         fields: &[Field],
         extension_after_field: Option<usize>,
+        transparent: bool,
+        components: &[ComponentConstraint],
         imp: Impl,
     ) {
-        Self::insert_consts(
-            scope,
-            imp,
-            &[
-                format!(
-                    "const EXTENDED_AFTER_FIELD: Option<u64> = {:?};",
-                    extension_after_field
-                ),
-                format!("const FIELD_COUNT: u64 = {};", fields.len()),
-                format!(
-                    "const STD_OPTIONAL_FIELDS: u64 = {};",
-                    fields
-                        .iter()
-                        .enumerate()
-                        .take_while(
-                            |(index, _f)| *index <= extension_after_field.unwrap_or(usize::MAX)
-                        )
-                        .filter(|(_index, f)| f.r#type().is_optional())
-                        .count()
-                ),
-                format!("const NAME: &'static str = \"{}\";", name),
-            ],
-        );
+        let mut consts = vec![
+            format!(
+                "const EXTENDED_AFTER_FIELD: Option<u64> = {:?};",
+                extension_after_field
+            ),
+            format!("const FIELD_COUNT: u64 = {};", fields.len()),
+            format!(
+                "const STD_OPTIONAL_FIELDS: u64 = {};",
+                fields
+                    .iter()
+                    .enumerate()
+                    .take_while(|(index, _f)| *index <= extension_after_field.unwrap_or(usize::MAX))
+                    .filter(|(_index, f)| {
+                        f.r#type().is_optional() || Self::is_present_field(f, components)
+                    })
+                    .count()
+            ),
+            format!("const NAME: &'static str = \"{}\";", name),
+        ];
+        if transparent {
+            consts.push("const TRANSPARENT: bool = true;".to_string());
+        }
+        Self::insert_consts(scope, imp, consts);
     }
 
     fn insert_consts<S: ToString, I: IntoIterator<Item = S>>(
@@ -989,67 +1624,106 @@ impl AsnDefWriter {
         imp: &mut Impl,
         name: &str,
         fields: &[Field],
+        unknown_extensions: bool,
+        components: &[ComponentConstraint],
     ) {
-        let mut open_key=false;
-        fields.iter().for_each(|field|{
-            if let Some(_)=&field.ref_id{
-                open_key=true;
+        let mut open_key = false;
+        fields.iter().for_each(|field| {
+            if let Some(_) = &field.ref_id {
+                open_key = true;
             }
         });
-        let block=imp.new_fn("read_seq")
+        let block = imp
+            .new_fn("read_seq")
             .attr("inline")
             .generic(&format!("R: {}Reader", CRATE_SYN_PREFIX))
             .arg("reader", "&mut R")
             .ret("Result<Self, R::Error>")
             .bound("Self", "Sized");
-        if open_key{
+        if open_key {
             for field in fields {
-                if let Some(key)=&field.ref_id{
+                if let Some(key) = &field.ref_id {
+                    // the earlier sibling selects the variant of the open type, without a key
+                    // of the object set the open type fails to read
+                    let combined = Self::combined_field_type_name(name, field.name());
                     block.line(format!(
-                        "let {} = AsnDef{}::read_value_by_key(reader,*(&{}.0) as usize)?;",
+                        "let {} = match {}opentype::Key::to_key::<{}>(&{}) {{ Some(key) => AsnDef{}::read_value_by_key(reader, key)?, None => AsnDef{}::read_value(reader)?, }};",
                         field.name(),
-                        Self::combined_field_type_name(name, field.name()),
-                        key
+                        CRATE_SYN_PREFIX,
+                        field.r#type().as_inner_type().to_string(),
+                        key,
+                        combined,
+                        combined,
                     ));
-                }else{
+                } else {
                     block.line(format!(
-                        "let {}:{}= AsnDef{}::read_value(reader)?;",
+                        "let {}:{}= {}?;",
                         field.name(),
                         field.r#type().to_string(),
-                        Self::combined_field_type_name(name, field.name())
+                        Self::read_field_value(name, field, components)
                     ));
                 }
             }
             let mut rtn_block = Block::new("Ok(Self");
             for field in fields {
-                rtn_block.line(format!(
-                    "{},",
-                    field.name(),
-                ));
+                rtn_block.line(format!("{},", field.name(),));
+            }
+            if unknown_extensions {
+                // after all known fields
+                rtn_block.line("unknown_extensions: reader.read_unknown_extensions()?,");
             }
             rtn_block.after(")");
             block.push_block(rtn_block);
-        }else{
+        } else {
             block.push_block({
                 let mut block = Block::new("Ok(Self");
                 for field in fields {
                     block.line(format!(
-                        "{}: AsnDef{}::read_value(reader)?,",
+                        "{}: {}?,",
                         field.name(),
-                        Self::combined_field_type_name(name, field.name())
+                        Self::read_field_value(name, field, components)
                     ));
                 }
+                if unknown_extensions {
+                    // after all known fields
+                    block.line("unknown_extensions: reader.read_unknown_extensions()?,");
+                }
                 block.after(")");
                 block
             });
         }
     }
 
+    /// Whether the field is `OPTIONAL` in ASN.1, but constrained to be `PRESENT` and therefore
+    /// not optional in Rust
+    fn is_present_field(field: &Field, components: &[ComponentConstraint]) -> bool {
+        !matches!(field.r#type(), RustType::Option(_))
+            && components
+                .iter()
+                .any(|c| c.is_present() && c.field == field.name())
+    }
+
+    fn read_field_value(name: &str, field: &Field, components: &[ComponentConstraint]) -> String {
+        let combined = Self::combined_field_type_name(name, field.name());
+        if Self::is_present_field(field, components) {
+            format!(
+                "reader.read_present::<AsnDef{}>({:?})",
+                combined,
+                field.name()
+            )
+        } else {
+            format!("AsnDef{}::read_value(reader)", combined)
+        }
+    }
+
     fn write_sequence_or_set_constraint_write_fn(
         &self,
         imp: &mut Impl,
         name: &str,
         fields: &[Field],
+        transparent: bool,
+        unknown_extensions: bool,
+        components: &[ComponentConstraint],
     ) {
         let body = imp
             .new_fn("write_seq")
@@ -1059,12 +1733,64 @@ impl AsnDefWriter {
             .arg("writer", "&mut W")
             .ret("Result<(), W::Error>");
 
+        if unknown_extensions {
+            // before any field, so that these can be announced in the extension header
+            body.line("writer.write_unknown_extensions(&self.unknown_extensions)?;");
+        }
+
+        // the fields of the referenced struct for tuple structs
+        let prefix = if transparent { "self.0." } else { "self." };
+        for component in components {
+            let present_field = fields
+                .iter()
+                .any(|f| f.name() == component.field && Self::is_present_field(f, components));
+            match component.presence {
+                Some(presence) if !present_field => body.line(format!(
+                    "writer.check_presence({:?}, {}PresenceConstraint::{:?}, {}{}.is_some())?;",
+                    component.field, CRATE_MODEL_PREFIX, presence, prefix, component.field,
+                )),
+                _ => body,
+            };
+            if let Some(value) = &component.value {
+                body.line(format!(
+                    "writer.check_value({:?}, {}validate::{})?;",
+                    component.field,
+                    CRATE_SYN_PREFIX,
+                    match value {
+                        PermittedValue::Integer(min, max) => format!(
+                            "is_in_range(&{}{}, {}, {})",
+                            prefix,
+                            component.field,
+                            min.map_or_else(|| "i64::MIN".to_string(), |v| v.to_string()),
+                            max.map_or_else(|| "i64::MAX".to_string(), |v| v.to_string()),
+                        ),
+                        PermittedValue::Boolean(value) => {
+                            format!("is_boolean(&{}{}, {})", prefix, component.field, value)
+                        }
+                        PermittedValue::Enumerated(identifier) => format!(
+                            "is_variant(&{}{}, {:?})",
+                            prefix, component.field, identifier
+                        ),
+                    }
+                ));
+            }
+        }
+
         for field in fields {
-            body.line(format!(
-                "AsnDef{}::write_value(writer, &self.{})?;",
-                Self::combined_field_type_name(name, field.name()),
-                field.name(),
-            ));
+            let combined = Self::combined_field_type_name(name, field.name());
+            if Self::is_present_field(field, components) {
+                body.line(format!(
+                    "writer.write_opt::<AsnDef{}>(Some(&self.{}))?;",
+                    combined,
+                    field.name(),
+                ));
+            } else {
+                body.line(format!(
+                    "AsnDef{}::write_value(writer, &self.{})?;",
+                    combined,
+                    field.name(),
+                ));
+            }
         }
 
         body.line("Ok(())");
@@ -1075,18 +1801,19 @@ impl AsnDefWriter {
 
         for definition in &model.definitions {
             Self.write_type_definitions(&mut scope, definition);
-            Self.write_constraints(&mut scope, definition);
+            Self.write_constraints(&mut scope, definition, model.tag_default);
             Self.impl_readable(&mut scope, &definition);
             Self.impl_writable(&mut scope, &definition);
+            Self.impl_validate(&mut scope, definition);
         }
 
         scope.to_string()
     }
 
-    /// ITU-T X.680 | ISO/IEC 8824-1, G.2.12.3
-    fn assign_implicit_tags(fields: &[Field]) -> Vec<Field> {
+    /// ITU-T X.680 | ISO/IEC 8824-1, G.2.12.3, only in an `AUTOMATIC TAGS` environment
+    fn assign_implicit_tags(fields: &[Field], tag_default: TagDefault) -> Vec<Field> {
         let any_explicit = fields.iter().any(|f| f.tag.is_some());
-        if any_explicit {
+        if any_explicit || tag_default != TagDefault::Automatic {
             fields.to_vec()
         } else {
             fields
@@ -1101,6 +1828,29 @@ impl AsnDefWriter {
         }
     }
 
+    /// ITU-T X.691 | ISO/IEC 8825-2, 23.3, the index of each alternative - in textual order - if
+    /// the alternatives of the root and of the extension are each sorted by their tags. Without a
+    /// tag for every alternative, the textual order is kept.
+    fn choice_indices_canonically(
+        fields: &[Field],
+        extended_after_index: Option<usize>,
+    ) -> Vec<usize> {
+        let mut order = (0..fields.len()).collect::<Vec<_>>();
+        if let Some(tags) = fields
+            .iter()
+            .map(|field| field.tag.or_else(|| field.r#type().tag()))
+            .collect::<Option<Vec<Tag>>>()
+        {
+            let extended_field = |index: usize| extended_after_index.is_some_and(|a| index > a);
+            order.sort_by_key(|index| (extended_field(*index), tags[*index]));
+        }
+        let mut indices = vec![0; fields.len()];
+        for (canonical, textual) in order.into_iter().enumerate() {
+            indices[textual] = canonical;
+        }
+        indices
+    }
+
     fn sort_fields_canonically(
         fields: &[Field],
         extended_after_index: Option<usize>,
@@ -1129,21 +1879,21 @@ impl AsnDefWriter {
 pub(crate) mod tests {
     use crate::gen::rust::walker::AsnDefWriter;
     use crate::model::rust::{EncodingOrdering, Field};
-    use crate::model::{Charset, Definition, Rust, RustType, Size};
+    use crate::model::{Charset, Definition, Rust, RustType, Size, TagDefault};
     use codegen::Scope;
 
     fn simple_whatever_sequence() -> Definition<Rust> {
         Definition(
             String::from("Whatever"),
             Rust::struct_from_fields(vec![
-                Field::from_name_type("name", RustType::String(Size::Any, Charset::Utf8)),
+                Field::from_name_type("name", RustType::String(Size::Any, Charset::Utf8, None)),
                 Field::from_name_type(
                     "opt",
-                    RustType::Option(Box::new(RustType::String(Size::Any, Charset::Utf8))),
+                    RustType::Option(Box::new(RustType::String(Size::Any, Charset::Utf8, None))),
                 ),
                 Field::from_name_type(
                     "some",
-                    RustType::Option(Box::new(RustType::String(Size::Any, Charset::Utf8))),
+                    RustType::Option(Box::new(RustType::String(Size::Any, Charset::Utf8, None))),
                 ),
             ]),
         )
@@ -1155,18 +1905,29 @@ pub(crate) mod tests {
             Rust::Struct {
                 ordering: EncodingOrdering::Keep,
                 fields: vec![
-                    Field::from_name_type("name", RustType::String(Size::Any, Charset::Utf8)),
+                    Field::from_name_type("name", RustType::String(Size::Any, Charset::Utf8, None)),
                     Field::from_name_type(
                         "opt",
-                        RustType::Option(Box::new(RustType::String(Size::Any, Charset::Utf8))),
+                        RustType::Option(Box::new(RustType::String(
+                            Size::Any,
+                            Charset::Utf8,
+                            None,
+                        ))),
                     ),
                     Field::from_name_type(
                         "some",
-                        RustType::Option(Box::new(RustType::String(Size::Any, Charset::Utf8))),
+                        RustType::Option(Box::new(RustType::String(
+                            Size::Any,
+                            Charset::Utf8,
+                            None,
+                        ))),
                     ),
                 ],
                 tag: None,
                 extension_after: Some(1),
+                unknown_extensions: false,
+                components: Vec::default(),
+                borrowed: false,
             },
         )
     }
@@ -1229,9 +1990,9 @@ pub(crate) mod tests {
     pub fn test_whatever_struct_constraint_and_read_write_impl() {
         let def = simple_whatever_sequence();
         let mut scope = Scope::new();
-        AsnDefWriter.write_constraints(&mut scope, &def);
-        AsnDefWriter.impl_readable(&mut scope, &def.0);
-        AsnDefWriter.impl_writable(&mut scope, &def.0);
+        AsnDefWriter.write_constraints(&mut scope, &def, TagDefault::Automatic);
+        AsnDefWriter.impl_readable(&mut scope, &def);
+        AsnDefWriter.impl_writable(&mut scope, &def);
         let string = scope.to_string();
         println!("{}", string);
 
@@ -1241,6 +2002,7 @@ pub(crate) mod tests {
             struct ___asn1rs_WhateverFieldNameConstraint;
             impl ::asn1rs::syn::common::Constraint for ___asn1rs_WhateverFieldNameConstraint {
                 const TAG: ::asn1rs::model::Tag = ::asn1rs::model::Tag::ContextSpecific(0);
+                const IDENTIFIER: Option<&'static str> = Some("name");
             }
             impl ::asn1rs::syn::utf8string::Constraint for ___asn1rs_WhateverFieldNameConstraint {
                 const EXTENSIBLE: bool = false;
@@ -1251,6 +2013,7 @@ pub(crate) mod tests {
             struct ___asn1rs_WhateverFieldOptConstraint;
             impl ::asn1rs::syn::common::Constraint for ___asn1rs_WhateverFieldOptConstraint {
                 const TAG: ::asn1rs::model::Tag = ::asn1rs::model::Tag::ContextSpecific(1);
+                const IDENTIFIER: Option<&'static str> = Some("opt");
             }
             impl ::asn1rs::syn::utf8string::Constraint for ___asn1rs_WhateverFieldOptConstraint {
                 const EXTENSIBLE: bool = false;
@@ -1260,6 +2023,7 @@ pub(crate) mod tests {
             struct ___asn1rs_WhateverFieldSomeConstraint;
             impl ::asn1rs::syn::common::Constraint for ___asn1rs_WhateverFieldSomeConstraint {
                 const TAG: ::asn1rs::model::Tag = ::asn1rs::model::Tag::ContextSpecific(2);
+                const IDENTIFIER: Option<&'static str> = Some("some");
             }
             impl ::asn1rs::syn::utf8string::Constraint for ___asn1rs_WhateverFieldSomeConstraint {
                 const EXTENSIBLE: bool = false;
@@ -1317,7 +2081,7 @@ pub(crate) mod tests {
     pub fn test_potatoe_struct_has_correct_extensible_constraints() {
         let def = extensible_potato_sequence();
         let mut scope = Scope::new();
-        AsnDefWriter.write_constraints(&mut scope, &def);
+        AsnDefWriter.write_constraints(&mut scope, &def, TagDefault::Automatic);
         let string = scope.to_string();
         println!("{}", string);
 
@@ -1327,6 +2091,7 @@ pub(crate) mod tests {
             struct ___asn1rs_PotatoFieldNameConstraint;
             impl ::asn1rs::syn::common::Constraint for ___asn1rs_PotatoFieldNameConstraint {
                 const TAG: ::asn1rs::model::Tag = ::asn1rs::model::Tag::ContextSpecific(0);
+                const IDENTIFIER: Option<&'static str> = Some("name");
             }
             impl ::asn1rs::syn::utf8string::Constraint for ___asn1rs_PotatoFieldNameConstraint {
                 const EXTENSIBLE: bool = false;
@@ -1336,6 +2101,7 @@ pub(crate) mod tests {
             struct ___asn1rs_PotatoFieldOptConstraint;
             impl ::asn1rs::syn::common::Constraint for ___asn1rs_PotatoFieldOptConstraint {
                 const TAG: ::asn1rs::model::Tag = ::asn1rs::model::Tag::ContextSpecific(1);
+                const IDENTIFIER: Option<&'static str> = Some("opt");
             }
             impl ::asn1rs::syn::utf8string::Constraint for ___asn1rs_PotatoFieldOptConstraint {
                 const EXTENSIBLE: bool = false;
@@ -1345,6 +2111,7 @@ pub(crate) mod tests {
             struct ___asn1rs_PotatoFieldSomeConstraint;
             impl ::asn1rs::syn::common::Constraint for ___asn1rs_PotatoFieldSomeConstraint {
                 const TAG: ::asn1rs::model::Tag = ::asn1rs::model::Tag::ContextSpecific(2);
+                const IDENTIFIER: Option<&'static str> = Some("some");
             }
             impl ::asn1rs::syn::utf8string::Constraint for ___asn1rs_PotatoFieldSomeConstraint {
                 const EXTENSIBLE: bool = false;
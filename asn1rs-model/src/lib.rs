@@ -1,7 +1,30 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "std")]
 #[macro_use]
 extern crate strum_macros;
 
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[cfg(feature = "std")]
 pub mod ast;
+#[cfg(feature = "std")]
 pub mod gen;
+#[cfg(feature = "std")]
 pub mod model;
+#[cfg(feature = "std")]
 pub mod parser;
+
+/// Without `std`, only the types of the model that the runtime of `asn1rs` depends on are
+/// available, but neither the parser nor the code generators
+#[cfg(not(feature = "std"))]
+pub mod model {
+    mod charset;
+    mod presence;
+    mod tag;
+
+    pub use charset::Charset;
+    pub use presence::PresenceConstraint;
+    pub use tag::{Tag, TagDefault, TagMode, TagProperty};
+}
@@ -0,0 +1,138 @@
+use crate::model::lor::Unresolved;
+use crate::model::{Asn, Error, ErrorKind, Model, PeekableTokens};
+use crate::parser::Token;
+use std::convert::TryFrom;
+use std::iter::Peekable;
+
+/// The characters permitted by a `FROM` constraint (ITU-T X.680 | ISO/IEC 8824-1, 51.7) as
+/// sorted, non-overlapping and inclusive ranges
+#[derive(Debug, Default, Clone, PartialOrd, PartialEq, Eq)]
+pub struct PermittedAlphabet {
+    ranges: Vec<(char, char)>,
+    extensible: bool,
+}
+
+impl PermittedAlphabet {
+    pub fn from_ranges<I: IntoIterator<Item = (char, char)>>(ranges: I) -> Self {
+        let mut sorted = ranges
+            .into_iter()
+            .map(|(a, b)| if a <= b { (a, b) } else { (b, a) })
+            .collect::<Vec<_>>();
+        sorted.sort_unstable();
+
+        let mut ranges: Vec<(char, char)> = Vec::with_capacity(sorted.len());
+        for (start, end) in sorted {
+            match ranges.last_mut() {
+                Some((_, last)) if u32::from(start) <= u32::from(*last) + 1 => {
+                    *last = (*last).max(end);
+                }
+                _ => ranges.push((start, end)),
+            }
+        }
+
+        Self {
+            ranges,
+            extensible: false,
+        }
+    }
+
+    pub fn with_extensible(mut self, extensible: bool) -> Self {
+        self.extensible = extensible;
+        self
+    }
+
+    pub fn ranges(&self) -> &[(char, char)] {
+        &self.ranges
+    }
+
+    /// An extensible permitted alphabet is not PER-visible (ITU-T X.691 | ISO/IEC 8825-2, 9.3.10)
+    pub const fn extensible(&self) -> bool {
+        self.extensible
+    }
+
+    pub fn contains(&self, char: char) -> bool {
+        self.ranges
+            .iter()
+            .any(|(start, end)| *start <= char && char <= *end)
+    }
+
+    pub fn to_constraint_string(&self) -> String {
+        let mut ranges = self
+            .ranges
+            .iter()
+            .map(|(start, end)| {
+                if start == end {
+                    format!("{:?}", start.to_string())
+                } else {
+                    format!("{:?}..{:?}", start.to_string(), end.to_string())
+                }
+            })
+            .collect::<Vec<_>>();
+        if self.extensible {
+            ranges.push("...".to_string());
+        }
+        format!("from({})", ranges.join(", "))
+    }
+}
+
+impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for PermittedAlphabet {
+    type Error = Error;
+
+    fn try_from(iter: &mut Peekable<T>) -> Result<Self, Self::Error> {
+        iter.next_text_eq_ignore_case_or_err("FROM")?;
+        iter.next_separator_eq_or_err('(')?;
+
+        let mut ranges = Vec::new();
+        loop {
+            let location = iter.peek_or_err()?.location();
+            let start = read_characters(iter)?;
+            if iter.next_is_separator_and_eq('.') {
+                iter.next_separator_eq_or_err('.')?;
+                let end = read_characters(iter)?;
+                match (single_char(&start), single_char(&end)) {
+                    (Some(start), Some(end)) => ranges.push((start, end)),
+                    _ => {
+                        return Err(ErrorKind::InvalidLiteral(Token::Text(
+                            location,
+                            format!("\"{}\"..\"{}\"", start, end),
+                        ))
+                        .into())
+                    }
+                }
+            } else {
+                ranges.extend(start.chars().map(|c| (c, c)));
+            }
+
+            if !iter.next_is_text_and_eq_ignore_case("|")
+                && !iter.next_is_text_and_eq_ignore_case("UNION")
+            {
+                break;
+            }
+        }
+
+        let extensible = if iter.next_is_separator_and_eq(',') {
+            iter.next_separator_eq_or_err('.')?;
+            iter.next_separator_eq_or_err('.')?;
+            iter.next_separator_eq_or_err('.')?;
+            true
+        } else {
+            false
+        };
+
+        iter.next_separator_eq_or_err(')')?;
+        Ok(PermittedAlphabet::from_ranges(ranges).with_extensible(extensible))
+    }
+}
+
+fn read_characters<T: Iterator<Item = Token>>(iter: &mut Peekable<T>) -> Result<String, Error> {
+    let literal = Model::<Asn<Unresolved>>::read_string_literal(iter, '"')?;
+    Ok(literal[1..literal.len() - 1].to_string())
+}
+
+fn single_char(string: &str) -> Option<char> {
+    let mut chars = string.chars();
+    match (chars.next(), chars.next()) {
+        (Some(char), None) => Some(char),
+        _ => None,
+    }
+}
@@ -1,13 +1,24 @@
 use crate::model::lor::{Error as ResolveError, TryResolve, Unresolved};
 use crate::model::lor::{ResolveState, Resolved, Resolver};
-use crate::model::{BitString, Charset, Choice, ChoiceVariant, ComponentTypeList, Enumerated, Field, Integer, LitOrRef, LiteralValue, Range, Size, Tag, TagProperty, Target, OpenType};
+use crate::model::{
+    BitString, Charset, Choice, ChoiceVariant, ComponentTypeList, Enumerated, Field,
+    InnerTypeConstraints, Integer, LitOrRef, LiteralValue, ObjectClassFieldType, OpenType,
+    ParameterizedDefinition, PermittedAlphabet, Range, Size, Tag, TagMode, TagProperty, Target,
+    TimeKind,
+};
+use crate::parser::Token;
 use std::fmt::Debug;
 
 #[derive(Debug, Clone, PartialOrd, PartialEq)]
 pub struct Asn<RS: ResolveState = Resolved> {
     pub tag: Option<Tag>,
+    /// Whether the `tag` is marked as `IMPLICIT` or `EXPLICIT` instead of following the
+    /// tagging environment of the module
+    pub tag_mode: Option<TagMode>,
     pub r#type: Type<RS>,
     pub default: Option<RS::ConstType>,
+    /// The `WITH COMPONENTS` constraint on the referenced type, ITU-T X.680 | ISO/IEC 8824-1, 51.8
+    pub components: Option<InnerTypeConstraints>,
 }
 
 impl<RS: ResolveState> Target for Asn<RS> {
@@ -28,11 +39,23 @@ impl<RS: ResolveState> Asn<RS> {
     pub fn opt_tagged(tag: Option<Tag>, r#type: Type<RS>) -> Self {
         Self {
             tag,
+            tag_mode: None,
             r#type,
             default: None,
+            components: None,
         }
     }
 
+    pub fn with_components(mut self, components: Option<InnerTypeConstraints>) -> Self {
+        self.components = components;
+        self
+    }
+
+    pub fn with_tag_mode(mut self, tag_mode: Option<TagMode>) -> Self {
+        self.tag_mode = tag_mode;
+        self
+    }
+
     pub fn untagged(r#type: Type<RS>) -> Self {
         Self::opt_tagged(None, r#type)
     }
@@ -75,6 +98,7 @@ impl Asn<Unresolved> {
         let r#type = self.r#type.try_resolve(resolver)?;
         Ok(Asn {
             tag: self.tag,
+            tag_mode: self.tag_mode,
             default: self
                 .default
                 .as_ref()
@@ -107,6 +131,7 @@ impl Asn<Unresolved> {
                 })
                 .transpose()?,
             r#type,
+            components: self.components.clone(),
         })
     }
 }
@@ -117,13 +142,20 @@ pub enum Type<RS: ResolveState = Resolved> {
     Boolean,
     /// ITU-T X.680 | ISO/IEC 8824-1, 19
     Integer(Integer<RS::RangeType>),
-    String(Size<RS::SizeType>, Charset),
+    /// ITU-T X.680 | ISO/IEC 8824-1, 21
+    Real,
+    String(Size<RS::SizeType>, Charset, Option<PermittedAlphabet>),
     /// ITU-T X.680 | ISO/IEC 8824-1, 23
     OctetString(Size<RS::SizeType>),
     /// ITU-T X.680 | ISO/IEC 8824-1, 22
     BitString(BitString<RS::SizeType>),
     /// ITU-T X.680 | ISO/IEC 8824-1, 24
     Null,
+    /// ITU-T X.680 | ISO/IEC 8824-1, 32
+    ObjectIdentifier,
+    /// ITU-T X.680 | ISO/IEC 8824-1, 33
+    RelativeOid,
+    Time(TimeKind),
 
     Optional(Box<Type<RS>>),
     Default(Box<Type<RS>>, LiteralValue),
@@ -144,9 +176,14 @@ pub enum Type<RS: ResolveState = Resolved> {
     /// ITU-T X.680 | ISO/IEC 8824-1, 16
     TypeReference(String, Option<Tag>),
     /// ITU-T X.681
-    TypeReferenceId(String, Option<Tag>,Option<String>,Option<usize>),
+    TypeReferenceId(String, Option<Tag>, Option<String>, Option<usize>),
     OpenType(OpenType<RS>),
-
+    /// ITU-T X.683 | ISO/IEC 8824-4, 9: replaced by a [`Type::TypeReference`] to the
+    /// instantiation while resolving
+    ParameterizedReference(String, Vec<Vec<Token>>),
+    /// ITU-T X.681 | ISO/IEC 8824-2, 14.1: replaced by the type of the field - or by an
+    /// [`Type::OpenType`] of the objects of the table constraint - while resolving
+    ObjectClassField(ObjectClassFieldType),
 }
 
 impl Type {
@@ -158,21 +195,22 @@ impl Type {
         Self::Sequence(ComponentTypeList {
             fields,
             extension_after: None,
+            components_of: Vec::new(),
         })
     }
 
     pub fn get_ref_id(&self) -> Option<String> {
-        if let Self::TypeReferenceId(_,_,ref_id,_) = self {
+        if let Self::TypeReferenceId(_, _, ref_id, _) = self {
             ref_id.clone()
-        }else{
+        } else {
             None
         }
     }
 
     pub fn get_key(&self) -> Option<usize> {
-        if let Self::TypeReferenceId(_,_,_,key) = self{
+        if let Self::TypeReferenceId(_, _, _, key) = self {
             key.clone()
-        }else{
+        } else {
             None
         }
     }
@@ -180,7 +218,7 @@ impl Type {
 
 impl<RS: ResolveState> Type<RS> {
     pub fn unconstrained_utf8string() -> Self {
-        Self::String(Size::Any, Charset::Utf8)
+        Self::String(Size::Any, Charset::Utf8, None)
     }
 
     pub fn unconstrained_octetstring() -> Self {
@@ -238,6 +276,20 @@ impl<RS: ResolveState> Type<RS> {
 }
 
 impl Type<Unresolved> {
+    /// ITU-T X.680 | ISO/IEC 8824-1, 13.4, `EXTENSIBILITY IMPLIED`
+    pub fn imply_extensibility(&mut self) {
+        match self {
+            Type::Sequence(list) | Type::Set(list) => list.imply_extensibility(),
+            Type::Choice(choice) => choice.imply_extensibility(),
+            Type::Enumerated(enumerated) => enumerated.imply_extensibility(),
+            Type::Optional(inner)
+            | Type::Default(inner, _)
+            | Type::SequenceOf(inner, _)
+            | Type::SetOf(inner, _) => inner.imply_extensibility(),
+            _ => {}
+        }
+    }
+
     pub fn try_resolve<
         R: Resolver<<Resolved as ResolveState>::SizeType>
             + Resolver<<Resolved as ResolveState>::RangeType>
@@ -250,10 +302,16 @@ impl Type<Unresolved> {
         Ok(match self {
             Type::Boolean => Type::Boolean,
             Type::Integer(integer) => Type::Integer(integer.try_resolve(resolver)?),
-            Type::String(size, charset) => Type::String(size.try_resolve(resolver)?, *charset),
+            Type::Real => Type::Real,
+            Type::String(size, charset, alphabet) => {
+                Type::String(size.try_resolve(resolver)?, *charset, alphabet.clone())
+            }
             Type::OctetString(size) => Type::OctetString(size.try_resolve(resolver)?),
             Type::BitString(string) => Type::BitString(string.try_resolve(resolver)?),
             Type::Null => Type::Null,
+            Type::ObjectIdentifier => Type::ObjectIdentifier,
+            Type::RelativeOid => Type::RelativeOid,
+            Type::Time(kind) => Type::Time(*kind),
             Type::Optional(inner) => Type::Optional(Box::new(inner.try_resolve(resolver)?)),
             Type::Default(inner, default) => {
                 Type::Default(Box::new(inner.try_resolve(resolver)?), default.clone())
@@ -272,7 +330,19 @@ impl Type<Unresolved> {
             Type::Choice(c) => Type::Choice(c.try_resolve(resolver)?),
             Type::OpenType(c) => Type::OpenType(c.try_resolve(resolver)?),
             Type::TypeReference(name, tag) => Type::TypeReference(name.clone(), *tag),
-            Type::TypeReferenceId(name, tag,id,key) => Type::TypeReferenceId(name.clone(), *tag,id.clone(),key.clone()),
+            Type::ParameterizedReference(name, arguments) => Type::TypeReference(
+                ParameterizedDefinition::instance_name(name, arguments),
+                None,
+            ),
+            Type::ObjectClassField(field) => {
+                return Err(ResolveError::FailedToResolveInformationObject(format!(
+                    "{}.{}",
+                    field.class, field.field
+                )))
+            }
+            Type::TypeReferenceId(name, tag, id, key) => {
+                Type::TypeReferenceId(name.clone(), *tag, id.clone(), key.clone())
+            }
         })
     }
 }
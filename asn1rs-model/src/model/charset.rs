@@ -1,24 +1,37 @@
 use crate::model::Tag;
 
-#[derive(Debug, Clone, Copy, PartialOrd, PartialEq, Eq, EnumString)]
-#[strum(serialize_all = "lowercase")]
+#[derive(Debug, Clone, Copy, PartialOrd, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "std",
+    derive(EnumString),
+    strum(serialize_all = "lowercase")
+)]
 pub enum Charset {
     Utf8,
     /// ITU-T X.680 | ISO/IEC 8824-1, 43.3
     Numeric,
     /// ITU-T X.680 | ISO/IEC 8824-1, 43.3
     Printable,
-
-    // /// (Also T61String)
-    // Teletext,
-    // Videotext,
+    /// ITU-T X.680 | ISO/IEC 8824-1, 41.4 (Also T61String), restricted to the characters of
+    /// ISO/IEC 8859-1 that are transferred as a single octet each without escape sequences
+    Teletex,
     /// Encoding as in ISO/IEC 646 (??)
     Ia5,
-
-    // GraphicsString,
+    /// ITU-T X.680 | ISO/IEC 8824-1, 41.4, restricted to the graphic characters of
+    /// ISO/IEC 8859-1 that are transferred as a single octet each without escape sequences
+    Graphic,
     /// ITU-T X.680 | ISO/IEC 8824-1, 43.3
     /// (Also ISO646String)
     Visible,
+    /// ITU-T X.680 | ISO/IEC 8824-1, 41.4, restricted to the graphic and control characters
+    /// of ISO/IEC 8859-1 that are transferred as a single octet each without escape sequences
+    General,
+    /// ITU-T X.680 | ISO/IEC 8824-1, 41.2: any character of ISO/IEC 10646, transferred in
+    /// four octets each
+    Universal,
+    /// ITU-T X.680 | ISO/IEC 8824-1, 41.2: the Basic Multilingual Plane of ISO/IEC 10646,
+    /// transferred in two octets each
+    Bmp,
 }
 
 impl Charset {
@@ -74,6 +87,11 @@ impl Charset {
             Charset::Printable => Tag::DEFAULT_PRINTABLE_STRING,
             Charset::Ia5 => Tag::DEFAULT_IA5_STRING,
             Charset::Visible => Tag::DEFAULT_VISIBLE_STRING,
+            Charset::Teletex => Tag::DEFAULT_TELETEXT_STRING,
+            Charset::Graphic => Tag::DEFAULT_GRAPHIC_STRING,
+            Charset::General => Tag::DEFAULT_GENERAL_STRING,
+            Charset::Universal => Tag::DEFAULT_UNIVERSAL_STRING,
+            Charset::Bmp => Tag::DEFAULT_BMP_STRING,
         }
     }
 
@@ -92,6 +110,10 @@ impl Charset {
             }
             Charset::Ia5 => matches!(char as u32, 0_u32..=127),
             Charset::Visible => matches!(char as u32, 32_u32..=126),
+            Charset::Teletex | Charset::General => matches!(char as u32, 0_u32..=255),
+            Charset::Graphic => matches!(char as u32, 32_u32..=126 | 160_u32..=255),
+            Charset::Universal => true,
+            Charset::Bmp => matches!(char as u32, 0_u32..=0xFFFF),
         }
     }
 }
@@ -1,5 +1,5 @@
 use crate::model::lor::{Error as ResolveError, ResolveState, Resolved, Resolver, Unresolved};
-use crate::model::{Asn, Error, Model, PeekableTokens, Tag, TagProperty, Type};
+use crate::model::{Asn, Error, Model, PeekableTokens, Tag, TagMode, TagProperty, Type};
 use crate::parser::Token;
 use std::convert::TryFrom;
 
@@ -82,12 +82,18 @@ impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for Choice<Unresolved>
                 }
             } else {
                 let name = iter.next_text_or_err()?;
-                let (token, tag) = Model::<Asn<Unresolved>>::next_with_opt_tag(iter)?;
+                let (token, tag, tag_mode) = Model::<Asn<Unresolved>>::next_with_opt_tag(iter)?;
                 let r#type = Model::<Asn<Unresolved>>::read_role_given_text(
                     iter,
                     token.into_text_or_else(Error::no_text)?,
                 )?;
-                choice.variants.push(ChoiceVariant { name, tag, r#type });
+                choice.variants.push(ChoiceVariant {
+                    name,
+                    identifier: None,
+                    tag,
+                    tag_mode,
+                    r#type,
+                });
             }
 
             loop_ctrl_separator!(iter.next_or_err()?);
@@ -98,6 +104,15 @@ impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for Choice<Unresolved>
 }
 
 impl Choice<Unresolved> {
+    pub fn imply_extensibility(&mut self) {
+        if self.extension_after.is_none() && !self.variants.is_empty() {
+            self.extension_after = Some(self.variants.len() - 1);
+        }
+        for variant in &mut self.variants {
+            variant.r#type.imply_extensibility();
+        }
+    }
+
     pub fn try_resolve<
         R: Resolver<<Resolved as ResolveState>::SizeType>
             + Resolver<<Resolved as ResolveState>::RangeType>
@@ -121,7 +136,11 @@ impl Choice<Unresolved> {
 #[derive(Debug, Clone, PartialOrd, PartialEq)]
 pub struct ChoiceVariant<RS: ResolveState = Resolved> {
     pub name: String,
+    /// The ASN.1 identifier, if it differs from the `name`
+    pub identifier: Option<String>,
     pub tag: Option<Tag>,
+    /// Whether the `tag` is marked as `IMPLICIT` or `EXPLICIT`
+    pub tag_mode: Option<TagMode>,
     pub r#type: Type<RS>,
 }
 
@@ -130,7 +149,9 @@ impl<RS: ResolveState> ChoiceVariant<RS> {
     pub fn name_type<I: ToString>(name: I, r#type: Type<RS>) -> Self {
         ChoiceVariant {
             name: name.to_string(),
+            identifier: None,
             tag: None,
+            tag_mode: None,
             r#type,
         }
     }
@@ -139,6 +160,11 @@ impl<RS: ResolveState> ChoiceVariant<RS> {
         &self.name
     }
 
+    /// The ASN.1 identifier of this variant
+    pub fn identifier(&self) -> &str {
+        self.identifier.as_deref().unwrap_or(&self.name)
+    }
+
     pub fn r#type(&self) -> &Type<RS> {
         &self.r#type
     }
@@ -170,7 +196,9 @@ impl ChoiceVariant<Unresolved> {
     ) -> Result<ChoiceVariant<Resolved>, ResolveError> {
         Ok(ChoiceVariant {
             name: self.name.clone(),
+            identifier: self.identifier.clone(),
             tag: self.tag,
+            tag_mode: self.tag_mode,
             r#type: self.r#type.try_resolve(resolver)?,
         })
     }
@@ -5,11 +5,26 @@ use crate::parser::Token;
 use std::convert::TryFrom;
 use std::iter::Peekable;
 
+/// The name of the field an extension addition group is read as, followed by its number
+const EXTENSION_GROUP_PREFIX: &str = "extension-group-";
+
 /// ITU-T X.680 | ISO/IEC 8824-1:2015, Annex L
 #[derive(Debug, Clone, PartialOrd, PartialEq)]
 pub struct ComponentTypeList<RS: ResolveState = Unresolved> {
     pub fields: Vec<Field<Asn<RS>>>,
     pub extension_after: Option<usize>,
+    /// The `COMPONENTS OF` notations that still need to be replaced by the components they
+    /// refer to, always empty once resolved
+    pub components_of: Vec<ComponentsOf>,
+}
+
+/// ITU-T X.680 | ISO/IEC 8824-1, 25.5, `COMPONENTS OF Type` includes the root components of the
+/// referenced SEQUENCE or SET type in front of the field at `index`
+#[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
+pub struct ComponentsOf {
+    pub index: usize,
+    pub r#type: String,
+    pub extension_addition: bool,
 }
 
 impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for ComponentTypeList<Unresolved> {
@@ -20,7 +35,9 @@ impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for ComponentTypeList<
         let mut sequence = Self {
             fields: Vec::default(),
             extension_after: None,
+            components_of: Vec::default(),
         };
+        let mut extension_groups = 0;
 
         loop {
             let continues = if iter.next_is_separator_and_eq('}') {
@@ -31,11 +48,22 @@ impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for ComponentTypeList<
                 let field_len = sequence.fields.len();
                 sequence.extension_after = Some(field_len.saturating_sub(1));
 
-                match iter.next_or_err()? {
-                    token if token.eq_separator(',') => true,
-                    token if token.eq_separator('}') => false,
-                    token => return Err(Error::unexpected_token(token)),
-                }
+                Self::next_continues_or_err(iter)?
+            } else if iter.next_is_text_and_eq_ignore_case("COMPONENTS") {
+                iter.next_text_eq_ignore_case_or_err("OF")?;
+                sequence.components_of.push(ComponentsOf {
+                    index: sequence.fields.len(),
+                    r#type: iter.next_text_or_err()?,
+                    extension_addition: sequence.extension_after.is_some(),
+                });
+                Self::next_continues_or_err(iter)?
+            } else if iter.next_is_separator_and_eq('[') {
+                iter.next_separator_eq_or_err('[')?;
+                extension_groups += 1;
+                sequence
+                    .fields
+                    .push(Self::read_extension_group(iter, extension_groups)?);
+                Self::next_continues_or_err(iter)?
             } else {
                 let (field, continues) = Model::<Asn<Unresolved>>::read_field(iter)?;
                 sequence.fields.push(field);
@@ -52,6 +80,67 @@ impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for ComponentTypeList<
 }
 
 impl ComponentTypeList<Unresolved> {
+    fn next_continues_or_err<T: Iterator<Item = Token>>(
+        iter: &mut Peekable<T>,
+    ) -> Result<bool, Error> {
+        match iter.next_or_err()? {
+            token if token.eq_separator(',') => Ok(true),
+            token if token.eq_separator('}') => Ok(false),
+            token => Err(Error::unexpected_token(token)),
+        }
+    }
+
+    /// ITU-T X.680 | ISO/IEC 8824-1, 25.1, an `ExtensionAdditionGroup` is read as a field of an
+    /// inline SEQUENCE type of its components. This is how ITU-T X.691 | ISO/IEC 8825-2, 19.9
+    /// encodes it and how the components are represented in the generated code.
+    fn read_extension_group<T: Iterator<Item = Token>>(
+        iter: &mut Peekable<T>,
+        number: usize,
+    ) -> Result<Field<Asn<Unresolved>>, Error> {
+        // the optional VersionNumber is not relevant for the encoding
+        if iter.peek_is_text_and_satisfies(|text| text.chars().all(|c| c.is_ascii_digit())) {
+            iter.next_or_err()?;
+            iter.next_separator_eq_or_err(':')?;
+        }
+
+        let mut fields = Vec::new();
+        loop {
+            let (field, continues) = Model::<Asn<Unresolved>>::read_field_until(iter, ']')?;
+            fields.push(field);
+            if !continues {
+                break;
+            }
+        }
+        iter.next_separator_eq_or_err(']')?;
+
+        Ok(Field {
+            name: format!("{}{}", EXTENSION_GROUP_PREFIX, number),
+            identifier: None,
+            role: Type::Sequence(ComponentTypeList {
+                fields,
+                extension_after: None,
+                components_of: Vec::default(),
+            })
+            .untagged(),
+        })
+    }
+
+    pub fn imply_extensibility(&mut self) {
+        if self.extension_after.is_none() && !self.fields.is_empty() {
+            self.extension_after = Some(self.fields.len() - 1);
+        }
+        for field in &mut self.fields {
+            match &mut field.role.r#type {
+                // the SEQUENCE of an extension addition group is not a type on its own
+                Type::Sequence(group) if field.name.starts_with(EXTENSION_GROUP_PREFIX) => group
+                    .fields
+                    .iter_mut()
+                    .for_each(|f| f.role.r#type.imply_extensibility()),
+                other => other.imply_extensibility(),
+            }
+        }
+    }
+
     pub fn try_resolve<
         R: Resolver<<Resolved as ResolveState>::SizeType>
             + Resolver<<Resolved as ResolveState>::RangeType>
@@ -68,6 +157,51 @@ impl ComponentTypeList<Unresolved> {
                 .map(|f| f.try_resolve(resolver))
                 .collect::<Result<Vec<_>, _>>()?,
             extension_after: self.extension_after,
+            components_of: Vec::default(),
         })
     }
+
+    /// Includes the given root components for the `COMPONENTS OF` notation at `index` of
+    /// [`ComponentTypeList::components_of`]
+    pub fn include_components_of(&mut self, index: usize, components: &[Field<Asn<Unresolved>>]) {
+        let ComponentsOf {
+            index: position,
+            extension_addition,
+            ..
+        } = self.components_of.remove(index);
+        self.fields
+            .splice(position..position, components.iter().cloned());
+        if let Some(extension_after) = self
+            .extension_after
+            .as_mut()
+            .filter(|_| !extension_addition)
+        {
+            *extension_after += components.len();
+        }
+        for components_of in &mut self.components_of {
+            if components_of.index >= position {
+                components_of.index += components.len();
+            }
+        }
+    }
+}
+
+impl Type<Unresolved> {
+    /// Whether this type or one of its inner types has a `COMPONENTS OF` notation
+    pub fn has_components_of(&self) -> bool {
+        match self {
+            Type::Optional(inner)
+            | Type::Default(inner, _)
+            | Type::SequenceOf(inner, _)
+            | Type::SetOf(inner, _) => inner.has_components_of(),
+            Type::Sequence(list) | Type::Set(list) => {
+                !list.components_of.is_empty()
+                    || list
+                        .fields
+                        .iter()
+                        .any(|f| f.role.r#type.has_components_of())
+            }
+            _ => false,
+        }
+    }
 }
@@ -62,6 +62,12 @@ impl Enumerated {
     pub fn extension_after_index(&self) -> Option<usize> {
         self.extension_after
     }
+
+    pub fn imply_extensibility(&mut self) {
+        if self.extension_after.is_none() && !self.variants.is_empty() {
+            self.extension_after = Some(self.variants.len() - 1);
+        }
+    }
 }
 
 impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for Enumerated {
@@ -119,6 +125,7 @@ impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for Enumerated {
 #[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
 pub struct EnumeratedVariant {
     pub(crate) name: String,
+    pub(crate) identifier: Option<String>,
     pub(crate) number: Option<usize>,
 }
 
@@ -133,6 +140,7 @@ impl EnumeratedVariant {
     pub fn from_name<I: ToString>(name: I) -> Self {
         Self {
             name: name.to_string(),
+            identifier: None,
             number: None,
         }
     }
@@ -140,6 +148,7 @@ impl EnumeratedVariant {
     pub fn from_name_number<I: ToString>(name: I, number: usize) -> Self {
         Self {
             name: name.to_string(),
+            identifier: None,
             number: Some(number),
         }
     }
@@ -153,10 +162,21 @@ impl EnumeratedVariant {
         self
     }
 
+    /// Sets the ASN.1 identifier, if it differs from the name
+    pub fn with_identifier_opt(mut self, identifier: Option<String>) -> Self {
+        self.identifier = identifier;
+        self
+    }
+
     pub fn name(&self) -> &str {
         &self.name
     }
 
+    /// The ASN.1 identifier of this variant
+    pub fn identifier(&self) -> &str {
+        self.identifier.as_deref().unwrap_or(&self.name)
+    }
+
     pub fn number(&self) -> Option<usize> {
         self.number
     }
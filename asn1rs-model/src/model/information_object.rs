@@ -0,0 +1,626 @@
+use crate::model::lor::Unresolved;
+use crate::model::{Asn, Type};
+use crate::model::{Definition, Error, ErrorKind, LitOrRef, LiteralValue, Model, PeekableTokens};
+use crate::parser::Token;
+use std::iter::Peekable;
+use std::vec::IntoIter;
+
+/// An information object class (ITU-T X.681 | ISO/IEC 8824-2, 9)
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub struct ObjectClass {
+    pub fields: Vec<FieldSpec>,
+    /// The user-defined syntax given by `WITH SYNTAX` (ITU-T X.681 | ISO/IEC 8824-2, 10), objects
+    /// are defined in the default syntax if there is none
+    pub syntax: Option<Vec<SyntaxItem>>,
+}
+
+/// ITU-T X.681 | ISO/IEC 8824-2, 9.4
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub struct FieldSpec {
+    /// The name of the field, including the leading `&`
+    pub name: String,
+    /// The type of a (fixed-type) value or value set field, [`None`] for a type field
+    pub r#type: Option<Type<Unresolved>>,
+    pub unique: bool,
+    /// Whether the field is `OPTIONAL` or has a `DEFAULT` setting
+    pub optional: bool,
+}
+
+impl FieldSpec {
+    /// ITU-T X.681 | ISO/IEC 8824-2, 9.5
+    pub fn is_type_field(&self) -> bool {
+        self.r#type.is_none()
+            && self
+                .name
+                .trim_start_matches('&')
+                .starts_with(|c: char| c.is_ascii_uppercase())
+    }
+}
+
+/// ITU-T X.681 | ISO/IEC 8824-2, 10.5
+#[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
+pub enum SyntaxItem {
+    /// A word or comma that has to appear as is
+    Literal(String),
+    /// The setting of the field with the given name
+    Field(String),
+    /// `[ ... ]`
+    Optional(Vec<SyntaxItem>),
+}
+
+/// An information object (ITU-T X.681 | ISO/IEC 8824-2, 11). The definition is kept as tokens,
+/// because it can only be parsed with the syntax of its class, which might be defined in another
+/// module.
+#[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
+pub struct InformationObject {
+    pub class: String,
+    /// The tokens of the object definition, including the braces
+    pub tokens: Vec<Token>,
+}
+
+/// An information object set (ITU-T X.681 | ISO/IEC 8824-2, 12)
+#[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
+pub struct ObjectSet {
+    pub class: String,
+    pub elements: Vec<ObjectSetElement>,
+    pub extensible: bool,
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
+pub enum ObjectSetElement {
+    /// A reference to an information object or to another information object set
+    Reference(String),
+    /// The tokens of an information object that is defined in place, including the braces
+    Object(Vec<Token>),
+}
+
+/// The setting of a field of an information object (ITU-T X.681 | ISO/IEC 8824-2, 11.7)
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum Setting {
+    Type(Type<Unresolved>),
+    Value(LitOrRef<LiteralValue>),
+    /// Value sets, objects and object sets are not interpreted
+    Other(Vec<Token>),
+}
+
+/// `CLASS.&field`, optionally restricted by a table constraint (ITU-T X.681 | ISO/IEC 8824-2, 14.1)
+#[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
+pub struct ObjectClassFieldType {
+    pub class: String,
+    /// The name of the field, including the leading `&`
+    pub field: String,
+    pub constraint: Option<TableConstraint>,
+}
+
+/// ITU-T X.682 | ISO/IEC 8824-3, 10
+#[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
+pub struct TableConstraint {
+    pub object_set: String,
+    /// The name of the component that is referenced by the component relation constraint
+    /// `{@component}` (ITU-T X.682 | ISO/IEC 8824-3, 10.7)
+    pub component: Option<String>,
+}
+
+impl ObjectClass {
+    pub fn field(&self, name: &str) -> Option<&FieldSpec> {
+        self.fields.iter().find(|field| field.name.eq(name))
+    }
+
+    /// Parses the settings of the given information object, which is defined in the syntax of
+    /// this class
+    pub fn read_object(&self, tokens: &[Token]) -> Result<Vec<(String, Setting)>, Error> {
+        let mut iter = tokens.iter().cloned().peekable();
+        let mut settings = Vec::new();
+        iter.next_separator_eq_or_err('{')?;
+
+        if let Some(syntax) = &self.syntax {
+            self.read_syntax_settings(syntax, &mut iter, &mut settings)?;
+        } else if !iter.peek_is_separator_eq('}') {
+            // ITU-T X.681 | ISO/IEC 8824-2, 11.5
+            loop {
+                let name = iter.next_text_or_err()?;
+                let setting = self.read_setting(&name, &mut iter)?;
+                settings.push((name, setting));
+                if !iter.next_is_separator_and_eq(',') {
+                    break;
+                }
+            }
+        }
+
+        iter.next_separator_eq_or_err('}')?;
+        if let Some(token) = iter.next() {
+            Err(Error::unexpected_token(token))
+        } else {
+            Ok(settings)
+        }
+    }
+
+    fn read_syntax_settings<T: Iterator<Item = Token>>(
+        &self,
+        syntax: &[SyntaxItem],
+        iter: &mut Peekable<T>,
+        settings: &mut Vec<(String, Setting)>,
+    ) -> Result<(), Error> {
+        for item in syntax {
+            match item {
+                SyntaxItem::Literal(literal) if literal == "," => {
+                    iter.next_separator_eq_or_err(',')?;
+                }
+                SyntaxItem::Literal(literal) => {
+                    let token = iter.next_or_err()?;
+                    if !token.eq_text(literal) {
+                        return Err(Error::expected_text(literal.clone(), token));
+                    }
+                }
+                SyntaxItem::Field(name) => {
+                    let setting = self.read_setting(name, iter)?;
+                    settings.push((name.clone(), setting));
+                }
+                SyntaxItem::Optional(group) => {
+                    let present = match group.first() {
+                        Some(SyntaxItem::Literal(literal)) if literal == "," => {
+                            iter.peek_is_separator_eq(',')
+                        }
+                        Some(SyntaxItem::Literal(literal)) => iter.peek_is_text_eq(literal),
+                        _ => false,
+                    };
+                    if present {
+                        self.read_syntax_settings(group, iter, settings)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn read_setting<T: Iterator<Item = Token>>(
+        &self,
+        name: &str,
+        iter: &mut Peekable<T>,
+    ) -> Result<Setting, Error> {
+        if self.field(name).is_some_and(FieldSpec::is_type_field) {
+            Ok(Setting::Type(Model::<Asn<Unresolved>>::read_role(iter)?))
+        } else if name
+            .trim_start_matches('&')
+            .starts_with(|c: char| c.is_ascii_lowercase())
+        {
+            match Model::<Asn<Unresolved>>::read_literal(iter) {
+                Ok(value) => Ok(Setting::Value(LitOrRef::Lit(value))),
+                Err(ErrorKind::UnsupportedLiteral(token)) if token.is_text() => {
+                    Ok(Setting::Value(LitOrRef::Ref(iter.next_text_or_err()?)))
+                }
+                Err(e) => Err(e.into()),
+            }
+        } else {
+            Ok(Setting::Other(Model::<Asn<Unresolved>>::read_group(iter)?))
+        }
+    }
+}
+
+impl Model<Asn<Unresolved>> {
+    /// Whether the upcoming tokens are the remainder of `Name ::= CLASS`
+    pub(crate) fn peek_is_object_class_assignment(iter: &Peekable<IntoIter<Token>>) -> bool {
+        let mut iter = iter.clone();
+        iter.next_is_separator_and_eq(':')
+            && iter.next_is_separator_and_eq(':')
+            && iter.next_is_separator_and_eq('=')
+            && iter.peek_is_text_eq("CLASS")
+    }
+
+    /// Whether the upcoming tokens are the remainder of `name CLASS-NAME ::= {`
+    pub(crate) fn peek_is_object_or_object_set_assignment(
+        iter: &Peekable<IntoIter<Token>>,
+    ) -> bool {
+        let mut iter = iter.clone();
+        iter.next_text_or_err()
+            .is_ok_and(|governor| Self::is_object_class_reference(&governor))
+            && iter.next_is_separator_and_eq(':')
+            && iter.next_is_separator_and_eq(':')
+            && iter.next_is_separator_and_eq('=')
+            && iter.peek_is_separator_eq('{')
+    }
+
+    /// ITU-T X.681 | ISO/IEC 8824-2, 7.1
+    fn is_object_class_reference(text: &str) -> bool {
+        // reserved words (ITU-T X.680 | ISO/IEC 8824-1, 12.38) of types with a value notation
+        // in braces
+        const RESERVED: &[&str] = &["EXTERNAL", "OID-IRI", "RELATIVE-OID", "RELATIVE-OID-IRI"];
+        !RESERVED.contains(&text)
+            && text.chars().any(|c| c.is_ascii_alphabetic())
+            && text
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '-')
+    }
+
+    pub(crate) fn read_object_class(
+        iter: &mut Peekable<IntoIter<Token>>,
+        name: String,
+    ) -> Result<Definition<ObjectClass>, Error> {
+        iter.next_separator_eq_or_err(':')?;
+        iter.next_separator_eq_or_err(':')?;
+        iter.next_separator_eq_or_err('=')?;
+        iter.next_text_eq_ignore_case_or_err("CLASS")?;
+        iter.next_separator_eq_or_err('{')?;
+
+        let mut fields = Vec::new();
+        loop {
+            let mut field = FieldSpec {
+                name: iter.next_text_or_err()?,
+                r#type: None,
+                unique: false,
+                optional: false,
+            };
+
+            if !iter.peek_is_separator_eq(',')
+                && !iter.peek_is_separator_eq('}')
+                && !iter.peek_is_text_eq("UNIQUE")
+                && !iter.peek_is_text_eq("OPTIONAL")
+                && !iter.peek_is_text_eq("DEFAULT")
+            {
+                field.r#type = Some(Self::read_role(iter)?);
+            }
+
+            loop {
+                if iter.next_is_text_and_eq_ignore_case("UNIQUE") {
+                    field.unique = true;
+                } else if iter.next_is_text_and_eq_ignore_case("OPTIONAL") {
+                    field.optional = true;
+                } else if iter.next_is_text_and_eq_ignore_case("DEFAULT") {
+                    // the default setting is only relevant for encoding values of the class
+                    let _ = Self::read_group(iter)?;
+                    field.optional = true;
+                } else {
+                    break;
+                }
+            }
+
+            fields.push(field);
+            loop_ctrl_separator!(iter.next_or_err()?);
+        }
+
+        let syntax = if iter.next_is_text_and_eq_ignore_case("WITH") {
+            iter.next_text_eq_ignore_case_or_err("SYNTAX")?;
+            iter.next_separator_eq_or_err('{')?;
+            Some(Self::read_syntax_items(iter, '}')?)
+        } else {
+            None
+        };
+
+        Ok(Definition(name, ObjectClass { fields, syntax }))
+    }
+
+    fn read_syntax_items<T: Iterator<Item = Token>>(
+        iter: &mut Peekable<T>,
+        end: char,
+    ) -> Result<Vec<SyntaxItem>, Error> {
+        let mut items = Vec::new();
+        loop {
+            match iter.next_or_err()? {
+                token if token.eq_separator(end) => return Ok(items),
+                token if token.eq_separator('[') => {
+                    items.push(SyntaxItem::Optional(Self::read_syntax_items(iter, ']')?))
+                }
+                token if token.eq_separator(',') => items.push(SyntaxItem::Literal(",".into())),
+                Token::Text(_, text) if text.starts_with('&') => {
+                    items.push(SyntaxItem::Field(text))
+                }
+                Token::Text(_, text) => items.push(SyntaxItem::Literal(text)),
+                token => return Err(Error::unexpected_token(token)),
+            }
+        }
+    }
+
+    pub(crate) fn read_object(
+        iter: &mut Peekable<IntoIter<Token>>,
+        name: String,
+    ) -> Result<Definition<InformationObject>, Error> {
+        let class = iter.next_text_or_err()?;
+        iter.next_separator_eq_or_err(':')?;
+        iter.next_separator_eq_or_err(':')?;
+        iter.next_separator_eq_or_err('=')?;
+        Ok(Definition(
+            name,
+            InformationObject {
+                class,
+                tokens: Self::read_group(iter)?,
+            },
+        ))
+    }
+
+    pub(crate) fn read_object_set(
+        iter: &mut Peekable<IntoIter<Token>>,
+        name: String,
+    ) -> Result<Definition<ObjectSet>, Error> {
+        let class = iter.next_text_or_err()?;
+        iter.next_separator_eq_or_err(':')?;
+        iter.next_separator_eq_or_err(':')?;
+        iter.next_separator_eq_or_err('=')?;
+        iter.next_separator_eq_or_err('{')?;
+
+        let mut set = ObjectSet {
+            class,
+            elements: Vec::new(),
+            extensible: false,
+        };
+
+        if iter.next_is_separator_and_eq('}') {
+            return Ok(Definition(name, set));
+        }
+
+        loop {
+            if iter.next_is_separator_and_eq('.') {
+                iter.next_separator_eq_or_err('.')?;
+                iter.next_separator_eq_or_err('.')?;
+                set.extensible = true;
+            } else if iter.peek_is_separator_eq('{') {
+                set.elements
+                    .push(ObjectSetElement::Object(Self::read_group(iter)?));
+            } else {
+                set.elements
+                    .push(ObjectSetElement::Reference(iter.next_text_or_err()?));
+            }
+
+            match iter.next_or_err()? {
+                t if t.eq_separator(',') || t.eq_text("|") || t.eq_text("UNION") => continue,
+                t if t.eq_separator('}') => break,
+                t => return Err(Error::unexpected_token(t)),
+            }
+        }
+
+        Ok(Definition(name, set))
+    }
+
+    /// Reads `.&field` and an optional table constraint of an [`ObjectClassFieldType`]
+    pub(crate) fn read_object_class_field_type<T: Iterator<Item = Token>>(
+        iter: &mut Peekable<T>,
+        class: String,
+    ) -> Result<ObjectClassFieldType, Error> {
+        iter.next_separator_eq_or_err('.')?;
+        let field = iter.next_text_or_err()?;
+
+        let constraint = if iter.next_is_separator_and_eq('(') {
+            iter.next_separator_eq_or_err('{')?;
+            let object_set = iter.next_text_or_err()?;
+            iter.next_separator_eq_or_err('}')?;
+
+            let component = if iter.next_is_separator_and_eq('{') {
+                let at = iter.next_text_or_err()?;
+                let component = if at == "@" {
+                    // the level of the referenced component is given by leading dots
+                    while iter.next_is_separator_and_eq('.') {}
+                    iter.next_text_or_err()?
+                } else {
+                    at.trim_start_matches('@').to_string()
+                };
+                iter.next_separator_eq_or_err('}')?;
+                Some(component)
+            } else {
+                None
+            };
+
+            iter.next_separator_eq_or_err(')')?;
+            Some(TableConstraint {
+                object_set,
+                component,
+            })
+        } else {
+            None
+        };
+
+        Ok(ObjectClassFieldType {
+            class,
+            field,
+            constraint,
+        })
+    }
+
+    /// Reads a single token or - if it opens a block - all tokens until the block is closed
+    fn read_group<T: Iterator<Item = Token>>(iter: &mut Peekable<T>) -> Result<Vec<Token>, Error> {
+        let mut tokens = Vec::new();
+        let mut depth = 0_usize;
+        loop {
+            let token = iter.next_or_err()?;
+            if token.eq_separator('{') || token.eq_separator('(') {
+                depth += 1;
+            } else if token.eq_separator('}') || token.eq_separator(')') {
+                depth = depth.saturating_sub(1);
+            }
+            tokens.push(token);
+            if depth == 0 {
+                return Ok(tokens);
+            }
+        }
+    }
+}
+
+impl Type<Unresolved> {
+    /// Whether this type or one of its inner types is an [`ObjectClassFieldType`]
+    pub fn has_object_class_fields(&self) -> bool {
+        match self {
+            Type::ObjectClassField(_) => true,
+            Type::Optional(inner)
+            | Type::Default(inner, _)
+            | Type::SequenceOf(inner, _)
+            | Type::SetOf(inner, _) => inner.has_object_class_fields(),
+            Type::Sequence(list) | Type::Set(list) => list
+                .fields
+                .iter()
+                .any(|f| f.role.r#type.has_object_class_fields()),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::Tokenizer;
+
+    const PROTOCOL_IES: &str = r"Classes DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+        PROTOCOL-IES ::= CLASS {
+            &id         INTEGER UNIQUE,
+            &criticality Criticality DEFAULT ignore,
+            &Value
+        }
+        WITH SYNTAX {
+            ID &id [CRITICALITY &criticality] TYPE &Value
+        }
+
+        first-ie PROTOCOL-IES ::= { ID 1 CRITICALITY reject TYPE BOOLEAN }
+
+        IEs PROTOCOL-IES ::= { first-ie | { ID id-second TYPE Second }, ... }
+
+        Field ::= SEQUENCE {
+            id      PROTOCOL-IES.&id ({IEs}),
+            value   PROTOCOL-IES.&Value ({IEs}{@.id})
+        }
+
+        END";
+
+    #[test]
+    fn test_read_object_class_with_syntax() {
+        let model = Model::try_from(Tokenizer::default().parse(PROTOCOL_IES)).unwrap();
+
+        assert_eq!(1, model.object_classes.len());
+        let Definition(name, class) = &model.object_classes[0];
+        assert_eq!("PROTOCOL-IES", name);
+        assert_eq!(
+            vec!["&id", "&criticality", "&Value"],
+            class
+                .fields
+                .iter()
+                .map(|f| f.name.as_str())
+                .collect::<Vec<_>>()
+        );
+        assert!(class.fields[0].unique);
+        assert!(class.fields[1].optional);
+        assert!(class.fields[2].is_type_field());
+        assert_eq!(
+            Some(vec![
+                SyntaxItem::Literal("ID".to_string()),
+                SyntaxItem::Field("&id".to_string()),
+                SyntaxItem::Optional(vec![
+                    SyntaxItem::Literal("CRITICALITY".to_string()),
+                    SyntaxItem::Field("&criticality".to_string()),
+                ]),
+                SyntaxItem::Literal("TYPE".to_string()),
+                SyntaxItem::Field("&Value".to_string()),
+            ]),
+            class.syntax
+        );
+    }
+
+    #[test]
+    fn test_read_objects_and_object_sets() {
+        let model = Model::try_from(Tokenizer::default().parse(PROTOCOL_IES)).unwrap();
+        let class = &model.object_classes[0].1;
+
+        assert_eq!(1, model.objects.len());
+        assert_eq!(
+            vec![
+                (
+                    "&id".to_string(),
+                    Setting::Value(LitOrRef::Lit(LiteralValue::Integer(1)))
+                ),
+                (
+                    "&criticality".to_string(),
+                    Setting::Value(LitOrRef::Ref("reject".to_string()))
+                ),
+                ("&Value".to_string(), Setting::Type(Type::Boolean)),
+            ],
+            class.read_object(&model.objects[0].1.tokens).unwrap()
+        );
+
+        assert_eq!(1, model.object_sets.len());
+        let set = &model.object_sets[0].1;
+        assert_eq!("PROTOCOL-IES", set.class);
+        assert!(set.extensible);
+        assert_eq!(
+            ObjectSetElement::Reference("first-ie".to_string()),
+            set.elements[0]
+        );
+        match &set.elements[1] {
+            ObjectSetElement::Object(tokens) => assert_eq!(
+                vec![
+                    (
+                        "&id".to_string(),
+                        Setting::Value(LitOrRef::Ref("id-second".to_string()))
+                    ),
+                    (
+                        "&Value".to_string(),
+                        Setting::Type(Type::TypeReference("Second".to_string(), None))
+                    ),
+                ],
+                class.read_object(tokens).unwrap()
+            ),
+            other => panic!("Unexpected element {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_read_object_class_field_types() {
+        let model = Model::try_from(Tokenizer::default().parse(PROTOCOL_IES)).unwrap();
+
+        assert_eq!(1, model.definitions.len());
+        match &model.definitions[0].1.r#type {
+            Type::Sequence(list) => {
+                assert_eq!(
+                    Type::ObjectClassField(ObjectClassFieldType {
+                        class: "PROTOCOL-IES".to_string(),
+                        field: "&id".to_string(),
+                        constraint: Some(TableConstraint {
+                            object_set: "IEs".to_string(),
+                            component: None,
+                        }),
+                    }),
+                    list.fields[0].role.r#type
+                );
+                assert_eq!(
+                    Type::ObjectClassField(ObjectClassFieldType {
+                        class: "PROTOCOL-IES".to_string(),
+                        field: "&Value".to_string(),
+                        constraint: Some(TableConstraint {
+                            object_set: "IEs".to_string(),
+                            component: Some("id".to_string()),
+                        }),
+                    }),
+                    list.fields[1].role.r#type
+                );
+            }
+            other => panic!("Unexpected type {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_negative_key_is_rejected() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r"Classes DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+            PROTOCOL-IES ::= CLASS {
+                &id     INTEGER UNIQUE,
+                &Value
+            }
+            WITH SYNTAX {
+                ID &id TYPE &Value
+            }
+
+            IEs PROTOCOL-IES ::= { { ID -1 TYPE BOOLEAN } }
+
+            Field ::= SEQUENCE {
+                id      PROTOCOL-IES.&id ({IEs}),
+                value   PROTOCOL-IES.&Value ({IEs}{@id})
+            }
+
+            END",
+        ))
+        .unwrap();
+
+        assert_eq!(
+            Some(crate::model::lor::Error::FailedToResolveInformationObject(
+                "PROTOCOL-IES.&Value".to_string()
+            )),
+            model.try_resolve().err()
+        );
+    }
+}
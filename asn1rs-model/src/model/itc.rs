@@ -1,4 +1,4 @@
-use crate::model::{Error, PeekableTokens};
+use crate::model::{Error, PeekableTokens, PresenceConstraint};
 use crate::parser::Token;
 use std::convert::TryFrom;
 use std::iter::Peekable;
@@ -9,6 +9,35 @@ pub struct InnerTypeConstraints {
     entries: Vec<(String, Option<ValueConstraint>, Option<PresenceConstraint>)>,
 }
 
+impl InnerTypeConstraints {
+    /// A partial specification of just the given value and presence constraints
+    pub fn from_components(
+        components: impl IntoIterator<
+            Item = (String, Option<ValueConstraint>, Option<PresenceConstraint>),
+        >,
+    ) -> Self {
+        Self {
+            implicit_all_present: true,
+            entries: components.into_iter().collect(),
+        }
+    }
+
+    /// Whether this is a partial specification (`WITH COMPONENTS { ..., }`), where components
+    /// that are not listed are not constrained (ITU-T X.680 | ISO/IEC 8824-1, 51.8)
+    pub fn is_partial_specification(&self) -> bool {
+        self.implicit_all_present
+    }
+
+    /// The identifiers of the listed components with their value and presence constraint
+    pub fn components(
+        &self,
+    ) -> impl Iterator<Item = (&str, Option<&ValueConstraint>, Option<PresenceConstraint>)> {
+        self.entries
+            .iter()
+            .map(|(name, value, presence)| (name.as_str(), value.as_ref(), *presence))
+    }
+}
+
 impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for InnerTypeConstraints {
     type Error = Error;
 
@@ -69,6 +98,57 @@ impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for InnerTypeConstrain
 #[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
 pub struct ValueConstraint(String);
 
+impl ValueConstraint {
+    pub fn new(value: impl ToString) -> Self {
+        Self(value.to_string())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The bounds of a single value (`5`) or of a value range (`1..10`), where `MIN` and `MAX`
+    /// are unbounded
+    pub fn integer_range(&self) -> Option<(Option<i64>, Option<i64>)> {
+        let bound = |value: &str, unbounded: &str| {
+            if value.eq_ignore_ascii_case(unbounded) {
+                Some(None)
+            } else {
+                value.parse::<i64>().ok().map(Some)
+            }
+        };
+        match self.0.split_once("..") {
+            Some((min, max)) => Some((bound(min, "MIN")?, bound(max, "MAX")?)),
+            None => self
+                .0
+                .parse::<i64>()
+                .ok()
+                .map(|value| (Some(value), Some(value))),
+        }
+    }
+
+    /// The single value `TRUE` or `FALSE`
+    pub fn boolean(&self) -> Option<bool> {
+        match self.0.as_str() {
+            "TRUE" => Some(true),
+            "FALSE" => Some(false),
+            _ => None,
+        }
+    }
+
+    /// The single identifier of a named value, for example of an ENUMERATED variant
+    pub fn identifier(&self) -> Option<&str> {
+        Some(self.0.as_str()).filter(|value| {
+            value.starts_with(|c: char| c.is_ascii_lowercase() || c.is_ascii_uppercase())
+                && value
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+                && self.boolean().is_none()
+                && self.integer_range().is_none()
+        })
+    }
+}
+
 impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for ValueConstraint {
     type Error = Error;
 
@@ -94,23 +174,3 @@ impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for ValueConstraint {
         Ok(Self(string))
     }
 }
-
-#[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
-pub enum PresenceConstraint {
-    Present,
-    Absent,
-    Optional,
-}
-
-impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for PresenceConstraint {
-    type Error = Error;
-
-    fn try_from(iter: &mut Peekable<T>) -> Result<Self, Self::Error> {
-        Ok(match iter.next_or_err()? {
-            t if t.eq_text_ignore_ascii_case("PRESENT") => PresenceConstraint::Present,
-            t if t.eq_text_ignore_ascii_case("ABSENT") => PresenceConstraint::Absent,
-            t if t.eq_text_ignore_ascii_case("OPTIONAL") => PresenceConstraint::Optional,
-            t => return Err(Error::unexpected_token(t)),
-        })
-    }
-}
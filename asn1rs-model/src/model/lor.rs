@@ -56,6 +56,8 @@ pub enum Error {
     FailedToResolveType(String),
     FailedToResolveReference(String),
     FailedToParseLiteral(String),
+    FailedToInstantiateType(String),
+    FailedToResolveInformationObject(String),
 }
 
 impl std::error::Error for Error {}
@@ -71,6 +73,12 @@ impl std::fmt::Display for Error {
             Error::FailedToParseLiteral(literal) => {
                 write!(f, "Failed to parse literal: {}", literal)
             }
+            Error::FailedToInstantiateType(name) => {
+                write!(f, "Failed to instantiate parameterized type: {}", name)
+            }
+            Error::FailedToResolveInformationObject(name) => {
+                write!(f, "Failed to resolve information object: {}", name)
+            }
         }
     }
 }
@@ -24,6 +24,7 @@ macro_rules! loop_ctrl_separator {
     };
 }
 
+mod alphabet;
 mod asn;
 mod bit_string;
 mod charset;
@@ -32,45 +33,59 @@ mod components;
 mod definition;
 mod enumerated;
 mod err;
+mod information_object;
 mod int;
 mod itc;
 pub mod lor;
 mod oid;
+mod opentype;
+mod parameterized;
 mod parse;
+mod presence;
 mod range;
 mod rs;
 mod size;
 mod tag;
 mod tag_resolver;
-mod opentype;
+mod time;
 
-use crate::model::itc::InnerTypeConstraints;
 use crate::model::lor::{ResolveState, Resolved, Resolver, Unresolved};
+pub use alphabet::PermittedAlphabet;
 pub use asn::Asn;
 pub use asn::Type;
 pub use bit_string::BitString;
 pub use charset::Charset;
 pub use choice::Choice;
 pub use choice::ChoiceVariant;
-pub use opentype::OpenType;
-pub use opentype::OpenTypeVariant;
-pub use components::ComponentTypeList;
+pub use components::{ComponentTypeList, ComponentsOf};
 pub use definition::Definition;
 pub use enumerated::Enumerated;
 pub use enumerated::EnumeratedVariant;
 pub use err::Error;
 pub use err::ErrorKind;
+pub use information_object::{
+    FieldSpec, InformationObject, ObjectClass, ObjectClassFieldType, ObjectSet, ObjectSetElement,
+    Setting, SyntaxItem, TableConstraint,
+};
 pub use int::Integer;
+pub use itc::{InnerTypeConstraints, ValueConstraint};
 pub use lor::Error as ResolveError;
 pub use lor::LitOrRef;
 pub use oid::{ObjectIdentifier, ObjectIdentifierComponent};
+pub use opentype::OpenType;
+pub use opentype::OpenTypeVariant;
+pub use parameterized::{Parameter, ParameterizedDefinition};
 pub use parse::PeekableTokens;
+pub use presence::PresenceConstraint;
 pub use range::Range;
 pub use rs::MultiModuleResolver;
 pub use size::Size;
 pub use tag::Tag;
+pub use tag::TagDefault;
+pub use tag::TagMode;
 pub use tag::TagProperty;
 pub use tag_resolver::TagResolver;
+pub use time::TimeKind;
 
 #[derive(Debug, Clone)]
 pub struct Model<T: Target> {
@@ -79,6 +94,18 @@ pub struct Model<T: Target> {
     pub imports: Vec<Import>,
     pub definitions: Vec<Definition<T::DefinitionType>>,
     pub value_references: Vec<ValueReference<T::ValueReferenceType>>,
+    /// ITU-T X.683 | ISO/IEC 8824-4, 8.2
+    pub parameterized_definitions: Vec<ParameterizedDefinition>,
+    /// ITU-T X.681 | ISO/IEC 8824-2, 9.1
+    pub object_classes: Vec<Definition<ObjectClass>>,
+    /// ITU-T X.681 | ISO/IEC 8824-2, 11.1
+    pub objects: Vec<Definition<InformationObject>>,
+    /// ITU-T X.681 | ISO/IEC 8824-2, 12.1
+    pub object_sets: Vec<Definition<ObjectSet>>,
+    /// ITU-T X.680 | ISO/IEC 8824-1, 13.1
+    pub tag_default: TagDefault,
+    /// ITU-T X.680 | ISO/IEC 8824-1, 13.4 (`EXTENSIBILITY IMPLIED`)
+    pub extensibility_implied: bool,
 }
 
 pub trait Target {
@@ -94,6 +121,13 @@ impl<T: Target> Default for Model<T> {
             imports: Default::default(),
             definitions: Default::default(),
             value_references: Vec::default(),
+            parameterized_definitions: Vec::default(),
+            object_classes: Vec::default(),
+            objects: Vec::default(),
+            object_sets: Vec::default(),
+            // what the `#[asn(...)]` attributes assume if no tagging environment is given
+            tag_default: TagDefault::Automatic,
+            extensibility_implied: false,
         }
     }
 }
@@ -105,10 +139,13 @@ impl Model<Asn<Unresolved>> {
 
         model.name = Self::read_name(&mut iter)?;
         model.oid = Self::maybe_read_oid(&mut iter)?;
-        Self::skip_until_after_text_ignore_ascii_case(&mut iter, "BEGIN")?;
+        Self::read_module_header(&mut iter, &mut model)?;
 
         while let Some(token) = iter.next() {
             if token.eq_text_ignore_ascii_case("END") {
+                if model.extensibility_implied {
+                    model.imply_extensibility();
+                }
                 model.make_names_nice();
                 return Ok(model);
             } else if token.eq_text_ignore_ascii_case("IMPORTS") {
@@ -116,10 +153,32 @@ impl Model<Asn<Unresolved>> {
                     .into_iter()
                     .for_each(|i| model.imports.push(i));
             } else if iter.peek_is_separator_eq(':') {
-                model.definitions.push(Self::read_definition(
-                    &mut iter,
-                    token.into_text_or_else(Error::unexpected_token)?,
-                )?);
+                let name = token.into_text_or_else(Error::unexpected_token)?;
+                if Self::peek_is_object_class_assignment(&iter) {
+                    model
+                        .object_classes
+                        .push(Self::read_object_class(&mut iter, name)?);
+                } else {
+                    model
+                        .definitions
+                        .push(Self::read_definition(&mut iter, name)?);
+                }
+            } else if iter.peek_is_separator_eq('{') {
+                model
+                    .parameterized_definitions
+                    .push(Self::read_parameterized_definition(
+                        &mut iter,
+                        token.into_text_or_else(Error::unexpected_token)?,
+                    )?);
+            } else if Self::peek_is_object_or_object_set_assignment(&iter) {
+                let name = token.into_text_or_else(Error::unexpected_token)?;
+                if name.starts_with(|c: char| c.is_ascii_lowercase()) {
+                    model.objects.push(Self::read_object(&mut iter, name)?);
+                } else {
+                    model
+                        .object_sets
+                        .push(Self::read_object_set(&mut iter, name)?);
+                }
             } else {
                 model.value_references.push(Self::read_value_reference(
                     &mut iter,
@@ -146,7 +205,9 @@ impl Model<Asn<Unresolved>> {
         }
     }
 
-    fn read_oid(iter: &mut Peekable<IntoIter<Token>>) -> Result<ObjectIdentifier, Error> {
+    fn read_oid<T: Iterator<Item = Token>>(
+        iter: &mut Peekable<T>,
+    ) -> Result<ObjectIdentifier, ErrorKind> {
         let mut vec = Vec::default();
         while let Some(token) = iter.next() {
             if token.eq_separator('}') {
@@ -156,12 +217,12 @@ impl Model<Asn<Unresolved>> {
                     vec.push(ObjectIdentifierComponent::NumberForm(
                         identifier
                             .parse()
-                            .map_err(|_| Error::invalid_int_value(token))?,
+                            .map_err(|_| ErrorKind::InvalidIntText(token))?,
                     ));
                 } else if iter.next_is_separator_and_eq('(') {
                     let number = match iter.next_text_or_err()?.parse::<u64>() {
                         Ok(number) => number,
-                        Err(_) => return Err(Error::invalid_int_value(token)),
+                        Err(_) => return Err(ErrorKind::InvalidIntText(token)),
                     };
                     iter.next_separator_eq_or_err(')')?;
                     vec.push(ObjectIdentifierComponent::NameAndNumberForm(
@@ -172,24 +233,51 @@ impl Model<Asn<Unresolved>> {
                     vec.push(ObjectIdentifierComponent::NameForm(identifier.to_string()));
                 }
             } else {
-                return Err(Error::unexpected_token(token));
+                return Err(ErrorKind::UnexpectedToken(token));
             }
         }
         Ok(ObjectIdentifier(vec))
     }
 
-    fn skip_until_after_text_ignore_ascii_case(
+    /// ITU-T X.680 | ISO/IEC 8824-1, 13.1, reads everything up to and including `BEGIN`. Without
+    /// a `TagDefault`, the module has `EXPLICIT TAGS` (ITU-T X.680 | ISO/IEC 8824-1, 13.3).
+    fn read_module_header(
         iter: &mut Peekable<IntoIter<Token>>,
-        text: &str,
+        model: &mut Self,
     ) -> Result<(), Error> {
-        for t in iter {
-            if t.eq_text_ignore_ascii_case(text) {
+        model.tag_default = TagDefault::Explicit;
+        let mut previous: Option<Token> = None;
+        for token in iter {
+            if token.eq_text_ignore_ascii_case("BEGIN") {
                 return Ok(());
+            } else if token.eq_text_ignore_ascii_case("TAGS") {
+                if let Some(tag_default) = previous
+                    .as_ref()
+                    .and_then(Token::text)
+                    .and_then(TagDefault::try_from_text)
+                {
+                    model.tag_default = tag_default;
+                }
+            } else if token.eq_text_ignore_ascii_case("IMPLIED")
+                && previous
+                    .as_ref()
+                    .is_some_and(|t| t.eq_text_ignore_ascii_case("EXTENSIBILITY"))
+            {
+                model.extensibility_implied = true;
             }
+            previous = Some(token);
         }
         Err(Error::unexpected_end_of_stream())
     }
 
+    /// ITU-T X.680 | ISO/IEC 8824-1, 13.4, adds an extension marker to every SEQUENCE, SET,
+    /// CHOICE and ENUMERATED type that does not have one
+    fn imply_extensibility(&mut self) {
+        for Definition(_name, asn) in &mut self.definitions {
+            asn.r#type.imply_extensibility();
+        }
+    }
+
     fn read_imports(iter: &mut Peekable<IntoIter<Token>>) -> Result<Vec<Import>, Error> {
         let mut imports = Vec::new();
         let mut import = Import::default();
@@ -199,6 +287,10 @@ impl Model<Asn<Unresolved>> {
             } else {
                 let text = token.into_text_or_else(Error::unexpected_token)?;
                 import.what.push(text);
+                if iter.next_is_separator_and_eq('{') {
+                    // parameterized reference (ITU-T X.683 | ISO/IEC 8824-4, 9.1)
+                    iter.next_separator_eq_or_err('}')?;
+                }
                 let token = iter.next_or_err()?;
                 if token.eq_separator(',') {
                     // ignore separator
@@ -220,32 +312,35 @@ impl Model<Asn<Unresolved>> {
         iter.next_separator_eq_or_err(':')?;
         iter.next_separator_eq_or_err('=')?;
 
-        let (token, tag) = Self::next_with_opt_tag(iter)?;
+        let (token, tag, tag_mode) = Self::next_with_opt_tag(iter)?;
+        let opt_tagged = |r#type: Type<Unresolved>| r#type.opt_tagged(tag).with_tag_mode(tag_mode);
 
         if token.eq_text_ignore_ascii_case("SEQUENCE") {
             Ok(Definition(
                 name,
-                Self::read_sequence_or_sequence_of(iter)?.opt_tagged(tag),
+                opt_tagged(Self::read_sequence_or_sequence_of(iter)?),
             ))
         } else if token.eq_text_ignore_ascii_case("SET") {
             Ok(Definition(
                 name,
-                Self::read_set_or_set_of(iter)?.opt_tagged(tag),
+                opt_tagged(Self::read_set_or_set_of(iter)?),
             ))
         } else if token.eq_text_ignore_ascii_case("ENUMERATED") {
             Ok(Definition(
                 name,
-                Type::Enumerated(Enumerated::try_from(iter)?).opt_tagged(tag),
+                opt_tagged(Type::Enumerated(Enumerated::try_from(iter)?)),
             ))
         } else if token.eq_text_ignore_ascii_case("CHOICE") {
             Ok(Definition(
                 name,
-                Type::Choice(Choice::try_from(iter)?).opt_tagged(tag),
+                opt_tagged(Type::Choice(Choice::try_from(iter)?)),
             ))
         } else if let Some(text) = token.text() {
+            let (r#type, components) =
+                Self::read_role_given_text_with_components(iter, text.to_string())?;
             Ok(Definition(
                 name,
-                Self::read_role_given_text(iter, text.to_string())?.opt_tagged(tag),
+                opt_tagged(r#type).with_components(components),
             ))
         } else {
             Err(Error::unexpected_token(token))
@@ -267,8 +362,10 @@ impl Model<Asn<Unresolved>> {
             },
             role: Asn {
                 tag: None,
+                tag_mode: None,
                 r#type,
                 default: None,
+                components: None,
             },
         })
     }
@@ -276,6 +373,9 @@ impl Model<Asn<Unresolved>> {
     fn read_literal<T: Iterator<Item = Token>>(
         iter: &mut Peekable<T>,
     ) -> Result<LiteralValue, ErrorKind> {
+        if iter.next_is_separator_and_eq('{') {
+            return Ok(LiteralValue::ObjectIdentifier(Self::read_oid(iter)?));
+        }
         let location = iter.peek_or_err()?.location();
         let string = {
             // boolean or integer
@@ -353,17 +453,22 @@ impl Model<Asn<Unresolved>> {
         Ok(string)
     }
 
+    /// ITU-T X.680 | ISO/IEC 8824-1, 31.1, reads the next token after the optional tag and the
+    /// optional `IMPLICIT` or `EXPLICIT` keyword
     fn next_with_opt_tag<T: Iterator<Item = Token>>(
         iter: &mut Peekable<T>,
-    ) -> Result<(Token, Option<Tag>), Error> {
+    ) -> Result<(Token, Option<Tag>, Option<TagMode>), Error> {
         let token = iter.next_or_err()?;
         if token.eq_separator('[') {
             let tag = Tag::try_from(&mut *iter)?;
             iter.next_separator_eq_or_err(']')?;
             let token = iter.next_or_err()?;
-            Ok((token, Some(tag)))
+            match token.text().and_then(TagMode::try_from_text) {
+                Some(tag_mode) => Ok((iter.next_or_err()?, Some(tag), Some(tag_mode))),
+                None => Ok((token, Some(tag), None)),
+            }
         } else {
-            Ok((token, None))
+            Ok((token, None, None))
         }
     }
 
@@ -378,15 +483,43 @@ impl Model<Asn<Unresolved>> {
         iter: &mut Peekable<T>,
         text: String,
     ) -> Result<Type<Unresolved>, Error> {
-        Ok(match text.to_ascii_lowercase().as_ref() {
+        // TODO keep the InnerTypeConstraints of components as well
+        Self::read_role_given_text_with_components(iter, text).map(|(r#type, _)| r#type)
+    }
+
+    /// Reads the type and - for a type reference - its `WITH COMPONENTS` constraint
+    fn read_role_given_text_with_components<T: Iterator<Item = Token>>(
+        iter: &mut Peekable<T>,
+        text: String,
+    ) -> Result<(Type<Unresolved>, Option<InnerTypeConstraints>), Error> {
+        let mut components = None;
+        let r#type = match text.to_ascii_lowercase().as_ref() {
             "integer" => Type::Integer(Integer::try_from(iter)?),
+            "real" => Type::Real,
             "boolean" => Type::Boolean,
             "null" => Type::Null,
-            "utf8string" => Type::String(Self::maybe_read_size(iter)?, Charset::Utf8),
-            "ia5string" => Type::String(Self::maybe_read_size(iter)?, Charset::Ia5),
-            "numericstring" => Type::String(Self::maybe_read_size(iter)?, Charset::Numeric),
-            "printablestring" => Type::String(Self::maybe_read_size(iter)?, Charset::Printable),
-            "visiblestring" => Type::String(Self::maybe_read_size(iter)?, Charset::Visible),
+            "object" => {
+                iter.next_text_eq_ignore_case_or_err("IDENTIFIER")?;
+                Type::ObjectIdentifier
+            }
+            "relative-oid" => Type::RelativeOid,
+            "generalizedtime" => Type::Time(TimeKind::GeneralizedTime),
+            "utctime" => Type::Time(TimeKind::UtcTime),
+            // reserved words of ITU-T X.680 | ISO/IEC 8824-1, 12.38 that might otherwise
+            // collide with type references of the same name
+            "date" if text == "DATE" => Type::Time(TimeKind::Date),
+            "time-of-day" if text == "TIME-OF-DAY" => Type::Time(TimeKind::TimeOfDay),
+            "duration" if text == "DURATION" => Type::Time(TimeKind::Duration),
+            "utf8string" => Self::read_string(iter, Charset::Utf8)?,
+            "ia5string" => Self::read_string(iter, Charset::Ia5)?,
+            "numericstring" => Self::read_string(iter, Charset::Numeric)?,
+            "printablestring" => Self::read_string(iter, Charset::Printable)?,
+            "visiblestring" => Self::read_string(iter, Charset::Visible)?,
+            "teletexstring" | "t61string" => Self::read_string(iter, Charset::Teletex)?,
+            "graphicstring" => Self::read_string(iter, Charset::Graphic)?,
+            "generalstring" => Self::read_string(iter, Charset::General)?,
+            "universalstring" => Self::read_string(iter, Charset::Universal)?,
+            "bmpstring" => Self::read_string(iter, Charset::Bmp)?,
             "octet" => {
                 iter.next_text_eq_ignore_case_or_err("STRING")?;
                 Type::OctetString(Self::maybe_read_size(iter)?)
@@ -399,14 +532,20 @@ impl Model<Asn<Unresolved>> {
             "choice" => Type::Choice(Choice::try_from(iter)?),
             "sequence" => Self::read_sequence_or_sequence_of(iter)?,
             "set" => Self::read_set_or_set_of(iter)?,
+            _ if iter.peek_is_separator_eq('{') => {
+                Type::ParameterizedReference(text, Self::read_actual_parameters(iter)?)
+            }
+            _ if iter.peek_is_separator_eq('.') => {
+                Type::ObjectClassField(Self::read_object_class_field_type(iter, text)?)
+            }
             _ => {
-                // TODO use InnerTypeConstraints to flatten TypeReference to an actual type and
-                //      prevent tuple-type nesting in the generated rust and other code by copying
-                //      over the fields and adding these additional constraints
-                let _ = Self::maybe_read_with_components_constraint(iter)?;
+                // the Rust model only copies over the fields of the referenced type, if an
+                // OPTIONAL field is constrained to be PRESENT
+                components = Self::maybe_read_with_components_constraint(iter)?;
                 Type::TypeReference(text, None)
             }
-        })
+        };
+        Ok((r#type, components))
     }
 
     fn maybe_read_with_components_constraint<T: Iterator<Item = Token>>(
@@ -435,6 +574,40 @@ impl Model<Asn<Unresolved>> {
         }
     }
 
+    /// Reads the `SIZE` and `FROM` constraints of a character string type, that are either given
+    /// as serial constraints or as an intersection
+    fn read_string<T: Iterator<Item = Token>>(
+        iter: &mut Peekable<T>,
+        charset: Charset,
+    ) -> Result<Type<Unresolved>, Error> {
+        let mut size = Size::Any;
+        let mut alphabet = None;
+
+        loop {
+            if iter.peek_is_text_eq_ignore_case("SIZE") {
+                size = Size::try_from(&mut *iter)?;
+            } else if iter.next_is_separator_and_eq('(') {
+                loop {
+                    if iter.peek_is_text_eq_ignore_case("FROM") {
+                        alphabet = Some(PermittedAlphabet::try_from(&mut *iter)?);
+                    } else {
+                        size = Size::try_from(&mut *iter)?;
+                    }
+                    if !iter.next_is_text_and_eq_ignore_case("^")
+                        && !iter.next_is_text_and_eq_ignore_case("INTERSECTION")
+                    {
+                        break;
+                    }
+                }
+                iter.next_separator_eq_or_err(')')?;
+            } else {
+                break;
+            }
+        }
+
+        Ok(Type::String(size, charset, alphabet))
+    }
+
     fn read_sequence_or_sequence_of<T: Iterator<Item = Token>>(
         iter: &mut Peekable<T>,
     ) -> Result<Type<Unresolved>, Error> {
@@ -461,13 +634,23 @@ impl Model<Asn<Unresolved>> {
 
     fn read_field<T: Iterator<Item = Token>>(
         iter: &mut Peekable<T>,
+    ) -> Result<(Field<Asn<Unresolved>>, bool), Error> {
+        Self::read_field_until(iter, '}')
+    }
+
+    /// Reads a field that is either followed by a `,` or the given closing separator
+    fn read_field_until<T: Iterator<Item = Token>>(
+        iter: &mut Peekable<T>,
+        closing: char,
     ) -> Result<(Field<Asn<Unresolved>>, bool), Error> {
         let name = iter.next_text_or_err()?;
-        let (token, tag) = Self::next_with_opt_tag(iter)?;
+        let (token, tag, tag_mode) = Self::next_with_opt_tag(iter)?;
         let mut field = Field {
             name,
+            identifier: None,
             role: Self::read_role_given_text(iter, token.into_text_or_else(Error::no_text)?)?
-                .opt_tagged(tag),
+                .opt_tagged(tag)
+                .with_tag_mode(tag_mode),
         };
 
         let token = {
@@ -497,7 +680,7 @@ impl Model<Asn<Unresolved>> {
 
         let (continues, ends) = token
             .separator()
-            .map_or((false, false), |s| (s == ',', s == '}'));
+            .map_or((false, false), |s| (s == ',', s == closing));
 
         if continues || ends {
             Ok((field, continues))
@@ -595,6 +778,7 @@ pub enum LiteralValue {
     Integer(i64),
     OctetString(Vec<u8>),
     EnumeratedVariant(String, String),
+    ObjectIdentifier(ObjectIdentifier),
 }
 
 impl LiteralValue {
@@ -617,9 +801,19 @@ pub struct Import {
 #[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
 pub struct Field<T> {
     pub name: String,
+    /// The ASN.1 identifier, if it differs from the `name` - which is the case if the model
+    /// was re-parsed from the Rust representation
+    pub identifier: Option<String>,
     pub role: T,
 }
 
+impl<T> Field<T> {
+    /// The ASN.1 identifier of this field
+    pub fn identifier(&self) -> &str {
+        self.identifier.as_deref().unwrap_or(&self.name)
+    }
+}
+
 impl<T: TagProperty> TagProperty for Field<T> {
     fn tag(&self) -> Option<Tag> {
         self.role.tag()
@@ -646,6 +840,7 @@ impl Field<Asn<Unresolved>> {
     ) -> Result<Field<Asn<Resolved>>, ResolveError> {
         Ok(Field {
             name: self.name.clone(),
+            identifier: self.identifier.clone(),
             role: self.role.try_resolve(resolver)?,
         })
     }
@@ -686,21 +881,25 @@ pub(crate) mod tests {
                 Type::sequence_from_fields(vec![
                     Field {
                         name: "small".into(),
+                        identifier: None,
                         role: Type::integer_with_range(Range::inclusive(Some(0), Some(255)))
                             .untagged(),
                     },
                     Field {
                         name: "bigger".into(),
+                        identifier: None,
                         role: Type::integer_with_range(Range::inclusive(Some(0), Some(65535)))
                             .untagged(),
                     },
                     Field {
                         name: "negative".into(),
+                        identifier: None,
                         role: Type::integer_with_range(Range::inclusive(Some(-1), Some(255)))
                             .untagged(),
                     },
                     Field {
                         name: "unlimited".into(),
+                        identifier: None,
                         role: Type::unconstrained_integer().optional().untagged(),
                     }
                 ])
@@ -741,6 +940,7 @@ pub(crate) mod tests {
                 "Woah".into(),
                 Type::sequence_from_fields(vec![Field {
                     name: "decision".into(),
+                    identifier: None,
                     role: Type::Enumerated(Enumerated::from_names(
                         ["ABORT", "RETURN", "CONFIRM", "MAYDAY", "THE_CAKE_IS_A_LIE",].iter()
                     ))
@@ -810,6 +1010,7 @@ pub(crate) mod tests {
                 Type::sequence_from_fields(vec![
                     Field {
                         name: "also-ones".into(),
+                        identifier: None,
                         role: Type::SequenceOf(
                             Box::new(Type::integer_with_range(Range::inclusive(Some(0), Some(1)))),
                             Size::Any,
@@ -818,6 +1019,7 @@ pub(crate) mod tests {
                     },
                     Field {
                         name: "nesteds".into(),
+                        identifier: None,
                         role: Type::SequenceOf(
                             Box::new(Type::SequenceOf(
                                 Box::new(Type::integer_with_range(Range::inclusive(
@@ -832,6 +1034,7 @@ pub(crate) mod tests {
                     },
                     Field {
                         name: "optionals".into(),
+                        identifier: None,
                         role: Type::SequenceOf(
                             Box::new(Type::SequenceOf(
                                 Box::new(Type::unconstrained_integer()),
@@ -919,6 +1122,7 @@ pub(crate) mod tests {
                 "Woah".into(),
                 Type::sequence_from_fields(vec![Field {
                     name: "decision".into(),
+                    identifier: None,
                     role: Type::choice_from_variants(vec![
                         ChoiceVariant::name_type("this", Type::TypeReference("This".into(), None)),
                         ChoiceVariant::name_type("that", Type::TypeReference("That".into(), None)),
@@ -964,14 +1168,17 @@ pub(crate) mod tests {
                 "Woah".into(),
                 Type::sequence_from_fields(vec![Field {
                     name: "complex".into(),
+                    identifier: None,
                     role: Type::sequence_from_fields(vec![
                         Field {
                             name: "ones".into(),
+                            identifier: None,
                             role: Type::integer_with_range(Range::inclusive(Some(0), Some(1)))
                                 .untagged(),
                         },
                         Field {
                             name: "list-ones".into(),
+                            identifier: None,
                             role: Type::SequenceOf(
                                 Box::new(Type::integer_with_range(Range::inclusive(
                                     Some(0),
@@ -983,6 +1190,7 @@ pub(crate) mod tests {
                         },
                         Field {
                             name: "optional-ones".into(),
+                            identifier: None,
                             role: Type::SequenceOf(
                                 Box::new(Type::integer_with_range(Range::inclusive(
                                     Some(0),
@@ -1251,10 +1459,12 @@ pub(crate) mod tests {
                     Type::sequence_from_fields(vec![
                         Field {
                             name: "abc".to_string(),
+                            identifier: None,
                             role: Type::unconstrained_integer().tagged(Tag::ContextSpecific(1)),
                         },
                         Field {
                             name: "def".to_string(),
+                            identifier: None,
                             role: Type::integer_with_range(Range::inclusive(Some(0), Some(255)))
                                 .tagged(Tag::ContextSpecific(2)),
                         }
@@ -1489,6 +1699,7 @@ pub(crate) mod tests {
                     Type::sequence_from_fields(vec![
                         Field {
                             name: "inline".to_string(),
+                            identifier: None,
                             role: Type::Integer(Integer {
                                 range: Range::none(),
                                 constants: vec![
@@ -1501,6 +1712,7 @@ pub(crate) mod tests {
                         },
                         Field {
                             name: "eff-u8".to_string(),
+                            identifier: None,
                             role: Type::Integer(Integer {
                                 range: Range::inclusive(Some(0), Some(255)),
                                 constants: vec![
@@ -1513,6 +1725,7 @@ pub(crate) mod tests {
                         },
                         Field {
                             name: "tagged".to_string(),
+                            identifier: None,
                             role: Type::Integer(Integer {
                                 range: Range::inclusive(Some(0), Some(255)),
                                 constants: vec![
@@ -1569,6 +1782,7 @@ pub(crate) mod tests {
                 "RangedOptional".to_string(),
                 Type::sequence_from_fields(vec![Field {
                     name: "value".to_string(),
+                    identifier: None,
                     role: Type::Integer(Integer {
                         range: Range::inclusive(Some(0), Some(255)).with_extensible(true),
                         constants: vec![
@@ -1635,6 +1849,9 @@ pub(crate) mod tests {
             fields,
             tag,
             extension_after: _,
+            unknown_extensions: _,
+            components: _,
+            borrowed: _,
         } = rust.definitions[0].value()
         {
             assert_eq!("Implicit", rust.definitions[0].0.as_str());
@@ -1797,12 +2014,12 @@ pub(crate) mod tests {
             &[
                 ValueReference {
                     name: "utf8".to_string(),
-                    role: Type::String(Size::Any, Charset::Utf8).untagged(),
+                    role: Type::String(Size::Any, Charset::Utf8, None).untagged(),
                     value: LiteralValue::String("häw äre yöu .. .. doing".to_string())
                 },
                 ValueReference {
                     name: "ia5".to_string(),
-                    role: Type::String(Size::Any, Charset::Ia5).untagged(),
+                    role: Type::String(Size::Any, Charset::Ia5, None).untagged(),
                     value: LiteralValue::String("how are you".to_string())
                 }
             ],
@@ -1932,4 +2149,129 @@ pub(crate) mod tests {
             &model.definitions[..]
         );
     }
+
+    #[test]
+    fn test_module_header_tag_default_and_extensibility_implied() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r"Header DEFINITIONS IMPLICIT TAGS EXTENSIBILITY IMPLIED ::=
+            BEGIN
+            Seq ::= SEQUENCE { a [0] EXPLICIT INTEGER, b [1] BOOLEAN }
+            Choice ::= CHOICE { a INTEGER, b BOOLEAN, ..., c NULL }
+            END",
+        ))
+        .unwrap();
+
+        assert_eq!(TagDefault::Implicit, model.tag_default);
+        assert!(model.extensibility_implied);
+
+        match &model.definitions[0].1.r#type {
+            Type::Sequence(seq) => {
+                assert_eq!(Some(1), seq.extension_after);
+                assert_eq!(Some(TagMode::Explicit), seq.fields[0].role.tag_mode);
+                assert_eq!(None, seq.fields[1].role.tag_mode);
+            }
+            other => panic!("Unexpected type: {:?}", other),
+        }
+        match &model.definitions[1].1.r#type {
+            Type::Choice(choice) => assert_eq!(Some(1), choice.extension_after_index()),
+            other => panic!("Unexpected type: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_module_header_without_tag_default_is_explicit() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r"Header DEFINITIONS ::=
+            BEGIN
+            END",
+        ))
+        .unwrap();
+
+        assert_eq!(TagDefault::Explicit, model.tag_default);
+        assert!(!model.extensibility_implied);
+    }
+
+    #[test]
+    fn test_components_of_and_extension_addition_groups() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r"Groups DEFINITIONS AUTOMATIC TAGS ::=
+            BEGIN
+
+            Base ::= SEQUENCE {
+                a BOOLEAN,
+                ...,
+                b BOOLEAN
+            }
+
+            Extended ::= SEQUENCE {
+                COMPONENTS OF Base,
+                c BOOLEAN,
+                ...,
+                [[ 2: d BOOLEAN, e BOOLEAN OPTIONAL ]],
+                f BOOLEAN
+            }
+
+            END",
+        ))
+        .expect("Failed to parse");
+
+        match &model.definitions[1].1.r#type {
+            Type::Sequence(seq) => assert_eq!(
+                &[ComponentsOf {
+                    index: 0,
+                    r#type: "Base".to_string(),
+                    extension_addition: false,
+                }][..],
+                &seq.components_of[..]
+            ),
+            other => panic!("Unexpected type: {:?}", other),
+        }
+
+        let model = model.try_resolve().expect("Failed to resolve");
+        assert_eq!(
+            Definition::new(
+                "Extended",
+                Type::Sequence(ComponentTypeList {
+                    fields: vec![
+                        Field {
+                            name: "a".to_string(),
+                            identifier: None,
+                            role: Type::Boolean.untagged(),
+                        },
+                        Field {
+                            name: "c".to_string(),
+                            identifier: None,
+                            role: Type::Boolean.untagged(),
+                        },
+                        Field {
+                            name: "extension-group-1".to_string(),
+                            identifier: None,
+                            role: Type::sequence_from_fields(vec![
+                                Field {
+                                    name: "d".to_string(),
+                                    identifier: None,
+                                    role: Type::Boolean.untagged(),
+                                },
+                                Field {
+                                    name: "e".to_string(),
+                                    identifier: None,
+                                    role: Type::Boolean.optional().untagged(),
+                                },
+                            ])
+                            .untagged(),
+                        },
+                        Field {
+                            name: "f".to_string(),
+                            identifier: None,
+                            role: Type::Boolean.untagged(),
+                        },
+                    ],
+                    extension_after: Some(1),
+                    components_of: Vec::default(),
+                })
+                .untagged(),
+            ),
+            model.definitions[1]
+        );
+    }
 }
@@ -82,12 +82,19 @@ impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for OpenType<Unresolve
                 }
             } else {
                 let name = iter.next_text_or_err()?;
-                let (token, tag) = Model::<Asn<Unresolved>>::next_with_opt_tag(iter)?;
+                let (token, tag, _tag_mode) = Model::<Asn<Unresolved>>::next_with_opt_tag(iter)?;
                 let r#type = Model::<Asn<Unresolved>>::read_role_given_text(
                     iter,
                     token.into_text_or_else(Error::no_text)?,
                 )?;
-                open_type.variants.push(OpenTypeVariant { name, tag, r#type,key: None });
+                open_type.variants.push(OpenTypeVariant {
+                    name,
+                    identifier: None,
+                    tag,
+                    r#type,
+                    key: None,
+                    key_object_identifier: None,
+                });
             }
 
             loop_ctrl_separator!(iter.next_or_err()?);
@@ -121,9 +128,14 @@ impl OpenType<Unresolved> {
 #[derive(Debug, Clone, PartialOrd, PartialEq)]
 pub struct OpenTypeVariant<RS: ResolveState = Resolved> {
     pub name: String,
+    /// The ASN.1 identifier, if it differs from the `name`
+    pub identifier: Option<String>,
     pub tag: Option<Tag>,
     pub r#type: Type<RS>,
     pub key: Option<usize>,
+    /// The arcs of the OBJECT IDENTIFIER that the `key` stands for, if the variants are keyed by
+    /// OBJECT IDENTIFIER values instead of integers
+    pub key_object_identifier: Option<Vec<u64>>,
 }
 
 impl<RS: ResolveState> OpenTypeVariant<RS> {
@@ -131,9 +143,11 @@ impl<RS: ResolveState> OpenTypeVariant<RS> {
     pub fn name_type<I: ToString>(name: I, r#type: Type<RS>) -> Self {
         OpenTypeVariant {
             name: name.to_string(),
+            identifier: None,
             tag: None,
             r#type,
-            key:None,
+            key: None,
+            key_object_identifier: None,
         }
     }
 
@@ -141,6 +155,11 @@ impl<RS: ResolveState> OpenTypeVariant<RS> {
         &self.name
     }
 
+    /// The ASN.1 identifier of this variant
+    pub fn identifier(&self) -> &str {
+        self.identifier.as_deref().unwrap_or(&self.name)
+    }
+
     pub fn r#type(&self) -> &Type<RS> {
         &self.r#type
     }
@@ -172,9 +191,11 @@ impl OpenTypeVariant<Unresolved> {
     ) -> Result<OpenTypeVariant<Resolved>, ResolveError> {
         Ok(OpenTypeVariant {
             name: self.name.clone(),
+            identifier: self.identifier.clone(),
             tag: self.tag,
             r#type: self.r#type.try_resolve(resolver)?,
-            key: None
+            key: self.key,
+            key_object_identifier: self.key_object_identifier.clone(),
         })
     }
 }
@@ -0,0 +1,395 @@
+use crate::model::lor::Unresolved;
+use crate::model::{Asn, Definition, Error, Model, PeekableTokens, Type};
+use crate::parser::Token;
+use std::iter::Peekable;
+use std::vec::IntoIter;
+
+/// A parameterized type assignment (ITU-T X.683 | ISO/IEC 8824-4, 8.2). The body is kept as
+/// tokens, because it can only be parsed into a [`Definition`] once the dummy references have
+/// been replaced by the actual parameters of an instantiation.
+#[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
+pub struct ParameterizedDefinition {
+    pub name: String,
+    pub parameters: Vec<Parameter>,
+    /// The tokens of the assignment, starting at `::=`
+    pub tokens: Vec<Token>,
+}
+
+/// ITU-T X.683 | ISO/IEC 8824-4, 8.3
+#[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
+pub enum Parameter {
+    /// `{ Dummy }`
+    Type(String),
+    /// `{ Governor : dummy }`
+    Value(String),
+    /// `{ Governor : Dummy }`
+    ValueSet(String),
+}
+
+impl Parameter {
+    pub fn dummy_reference(&self) -> &str {
+        match self {
+            Parameter::Type(dummy) | Parameter::Value(dummy) | Parameter::ValueSet(dummy) => dummy,
+        }
+    }
+}
+
+impl ParameterizedDefinition {
+    /// Replaces the dummy references with the given actual parameters (ITU-T X.683 |
+    /// ISO/IEC 8824-4, 9.3) and parses the result as [`Definition`] of the given name
+    pub fn instantiate(
+        &self,
+        name: String,
+        arguments: &[Vec<Token>],
+    ) -> Result<Definition<Asn<Unresolved>>, Error> {
+        let mut iter = self
+            .substitute(|parameter| {
+                let index = self.parameters.iter().position(|p| p == parameter)?;
+                let argument = arguments.get(index)?;
+                Some(match parameter {
+                    // the braces of a value set are not part of the constraint it is used in
+                    Parameter::ValueSet(_)
+                        if argument.len() >= 2
+                            && argument[0].eq_separator('{')
+                            && argument[argument.len() - 1].eq_separator('}') =>
+                    {
+                        argument[1..argument.len() - 1].to_vec()
+                    }
+                    _ => argument.clone(),
+                })
+            })
+            .into_iter()
+            .peekable();
+
+        let definition = Model::<Asn<Unresolved>>::read_definition(&mut iter, name)?;
+        if let Some(token) = iter.next() {
+            Err(Error::unexpected_token(token))
+        } else {
+            Ok(definition)
+        }
+    }
+
+    /// The name of the [`Definition`] that is emitted for the instantiation of the parameterized
+    /// type with the given actual parameters
+    pub fn instance_name(name: &str, arguments: &[Vec<Token>]) -> String {
+        let mut instance = name.to_string();
+        for text in arguments.iter().flatten().filter_map(Token::text) {
+            instance.push('-');
+            instance.extend(text.chars().filter(|c| c.is_alphanumeric() || *c == '-'));
+        }
+        instance
+    }
+
+    fn substitute(&self, mut f: impl FnMut(&Parameter) -> Option<Vec<Token>>) -> Vec<Token> {
+        let mut tokens = Vec::with_capacity(self.tokens.len());
+        for token in &self.tokens {
+            match self
+                .parameters
+                .iter()
+                .find(|p| token.eq_text(p.dummy_reference()))
+                .and_then(&mut f)
+            {
+                Some(replacement) => tokens.extend(replacement),
+                None => tokens.push(token.clone()),
+            }
+        }
+        tokens
+    }
+}
+
+impl Model<Asn<Unresolved>> {
+    pub(crate) fn read_parameterized_definition(
+        iter: &mut Peekable<IntoIter<Token>>,
+        name: String,
+    ) -> Result<ParameterizedDefinition, Error> {
+        let parameters = Self::read_parameters(iter)?;
+
+        // Parse the body once to find where the assignment ends. The dummy references of value
+        // sets are replaced by a placeholder, because they are not valid in a constraint
+        // before being substituted. Object sets in table constraints are enclosed in braces and
+        // can be parsed as they are.
+        let mut origins = Vec::new();
+        let mut tokens: Vec<Token> = Vec::new();
+        for (index, token) in iter.clone().enumerate() {
+            if tokens
+                .last()
+                .is_some_and(|previous| previous.eq_separator('('))
+                && parameters
+                    .iter()
+                    .any(|p| matches!(p, Parameter::ValueSet(dummy) if token.eq_text(dummy)))
+            {
+                for placeholder in [
+                    Token::from("MIN".to_string()),
+                    Token::from('.'),
+                    Token::from('.'),
+                    Token::from("MAX".to_string()),
+                ] {
+                    origins.push(index);
+                    tokens.push(placeholder);
+                }
+            } else {
+                origins.push(index);
+                tokens.push(token);
+            }
+        }
+
+        let mut dry_run = tokens.into_iter().peekable();
+        let len = dry_run.len();
+        let _ = Self::read_definition(&mut dry_run, name.clone())?;
+        let consumed = origins[len - dry_run.len() - 1] + 1;
+
+        Ok(ParameterizedDefinition {
+            name,
+            parameters,
+            tokens: iter.take(consumed).collect(),
+        })
+    }
+
+    fn read_parameters<T: Iterator<Item = Token>>(
+        iter: &mut Peekable<T>,
+    ) -> Result<Vec<Parameter>, Error> {
+        iter.next_separator_eq_or_err('{')?;
+        let mut parameters = Vec::new();
+        loop {
+            let mut words = vec![iter.next_text_or_err()?];
+            while iter.peek_is_text_and_satisfies(|_| true) {
+                words.push(iter.next_text_or_err()?);
+            }
+            let parameter = if iter.next_is_separator_and_eq(':') {
+                // the governor is not needed, the dummy is only substituted
+                let dummy = iter.next_text_or_err()?;
+                if dummy.starts_with(char::is_uppercase) {
+                    Parameter::ValueSet(dummy)
+                } else {
+                    Parameter::Value(dummy)
+                }
+            } else if words.len() == 1 {
+                Parameter::Type(words.remove(0))
+            } else {
+                return Err(Error::unexpected_token(iter.next_or_err()?));
+            };
+            parameters.push(parameter);
+            loop_ctrl_separator!(iter.next_or_err()?);
+        }
+        Ok(parameters)
+    }
+
+    /// Reads the actual parameters of a parameterized type reference as tokens
+    /// (ITU-T X.683 | ISO/IEC 8824-4, 9.5)
+    pub(crate) fn read_actual_parameters<T: Iterator<Item = Token>>(
+        iter: &mut Peekable<T>,
+    ) -> Result<Vec<Vec<Token>>, Error> {
+        iter.next_separator_eq_or_err('{')?;
+        let mut arguments = Vec::new();
+        let mut argument = Vec::new();
+        let mut depth = 0_usize;
+        loop {
+            let token = iter.next_or_err()?;
+            if depth == 0 && (token.eq_separator(',') || token.eq_separator('}')) {
+                arguments.push(core::mem::take(&mut argument));
+                if token.eq_separator('}') {
+                    return Ok(arguments);
+                }
+            } else {
+                if token.eq_separator('{') || token.eq_separator('(') {
+                    depth += 1;
+                } else if token.eq_separator('}') || token.eq_separator(')') {
+                    depth = depth.saturating_sub(1);
+                }
+                argument.push(token);
+            }
+        }
+    }
+}
+
+impl Type<Unresolved> {
+    /// Collects the parameterized type references (name and actual parameters) of this type and
+    /// its inner types
+    pub fn parameterized_references(&self) -> Vec<(&str, &[Vec<Token>])> {
+        let mut references = Vec::new();
+        self.collect_parameterized_references(&mut references);
+        references
+    }
+
+    fn collect_parameterized_references<'a>(
+        &'a self,
+        references: &mut Vec<(&'a str, &'a [Vec<Token>])>,
+    ) {
+        match self {
+            Type::ParameterizedReference(name, arguments) => {
+                references.push((name.as_str(), &arguments[..]))
+            }
+            Type::Optional(inner)
+            | Type::Default(inner, _)
+            | Type::SequenceOf(inner, _)
+            | Type::SetOf(inner, _) => inner.collect_parameterized_references(references),
+            Type::Sequence(list) | Type::Set(list) => list
+                .fields
+                .iter()
+                .for_each(|f| f.role.r#type.collect_parameterized_references(references)),
+            Type::Choice(choice) => choice
+                .variants()
+                .for_each(|v| v.r#type().collect_parameterized_references(references)),
+            Type::OpenType(open_type) => open_type
+                .variants()
+                .for_each(|v| v.r#type().collect_parameterized_references(references)),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Size;
+    use crate::parser::Tokenizer;
+
+    #[test]
+    fn test_parameterized_definition_is_not_parsed_eagerly() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r"Params DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+            Container{Payload, INTEGER : maxLen} ::= SEQUENCE {
+                payload Payload,
+                list SEQUENCE (SIZE(1..maxLen)) OF Payload
+            }
+
+            Plain ::= BOOLEAN
+
+            END",
+        ))
+        .unwrap();
+
+        assert_eq!(1, model.definitions.len());
+        assert_eq!("Plain", model.definitions[0].name());
+        assert_eq!(1, model.parameterized_definitions.len());
+        assert_eq!("Container", model.parameterized_definitions[0].name);
+        assert_eq!(
+            vec![
+                Parameter::Type("Payload".to_string()),
+                Parameter::Value("maxLen".to_string())
+            ],
+            model.parameterized_definitions[0].parameters
+        );
+    }
+
+    #[test]
+    fn test_instantiate_with_value_set() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r"Params DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+            Bounded{INTEGER : Bounds} ::= SEQUENCE OF INTEGER (Bounds)
+
+            END",
+        ))
+        .unwrap();
+
+        let arguments = vec![Tokenizer::default().parse("{ 1..10 }")];
+        let name = ParameterizedDefinition::instance_name("Bounded", &arguments);
+        assert_eq!("Bounded-1-10", name);
+
+        let definition = model.parameterized_definitions[0]
+            .instantiate(name.clone(), &arguments)
+            .unwrap();
+        assert_eq!(
+            Definition(
+                name,
+                Type::<Unresolved>::SequenceOf(
+                    Box::new(Type::integer_with_range(crate::model::Range::inclusive(
+                        Some(crate::model::LitOrRef::Lit(1)),
+                        Some(crate::model::LitOrRef::Lit(10)),
+                    ))),
+                    Size::Any,
+                )
+                .untagged()
+            ),
+            definition
+        );
+    }
+
+    #[test]
+    fn test_read_nested_actual_parameters() {
+        let mut iter = Tokenizer::default()
+            .parse("{ Inner{A, B}, { 1..2 }, SEQUENCE OF C }")
+            .into_iter()
+            .peekable();
+        let arguments = Model::<Asn<Unresolved>>::read_actual_parameters(&mut iter).unwrap();
+        assert_eq!(3, arguments.len());
+        assert_eq!(6, arguments[0].len());
+        assert_eq!(6, arguments[1].len());
+        assert_eq!(3, arguments[2].len());
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_instantiate_imported_parameterized_type() {
+        let mut resolver = crate::model::MultiModuleResolver::default();
+        resolver.push(
+            Model::try_from(Tokenizer::default().parse(
+                r"Common DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+                Header ::= INTEGER (0..255)
+
+                Container{Payload} ::= SEQUENCE {
+                    header Header,
+                    payload Payload
+                }
+
+                END",
+            ))
+            .unwrap(),
+        );
+        resolver.push(
+            Model::try_from(Tokenizer::default().parse(
+                r"Specific DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+                IMPORTS Container{} FROM Common;
+
+                Message ::= Container{BOOLEAN}
+
+                END",
+            ))
+            .unwrap(),
+        );
+
+        let models = resolver.try_resolve_all().unwrap();
+        assert!(models[0]
+            .definitions
+            .iter()
+            .all(|d| d.name() != "Container"));
+        assert_eq!(
+            vec![crate::model::Import {
+                what: vec!["Header".to_string()],
+                from: "Common".to_string(),
+                from_oid: None,
+            }],
+            models[1].imports
+        );
+        assert_eq!(
+            Definition(
+                "Message".to_string(),
+                Type::TypeReference("Container-BOOLEAN".to_string(), None).untagged()
+            ),
+            models[1].definitions[0]
+        );
+        assert_eq!(
+            Definition(
+                "Container-BOOLEAN".to_string(),
+                Type::sequence_from_fields(vec![
+                    crate::model::Field {
+                        name: "header".to_string(),
+                        identifier: None,
+                        role: Type::TypeReference("Header".to_string(), None).untagged(),
+                    },
+                    crate::model::Field {
+                        name: "payload".to_string(),
+                        identifier: None,
+                        role: Type::Boolean.untagged(),
+                    },
+                ])
+                .untagged()
+            ),
+            models[1].definitions[1]
+        );
+    }
+}
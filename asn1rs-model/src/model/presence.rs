@@ -0,0 +1,30 @@
+#[cfg(feature = "std")]
+use crate::model::{Error, PeekableTokens};
+#[cfg(feature = "std")]
+use crate::parser::Token;
+#[cfg(feature = "std")]
+use std::convert::TryFrom;
+#[cfg(feature = "std")]
+use std::iter::Peekable;
+
+/// ITU-T X.680 | ISO/IEC 8824-1, 51.8.10, the presence of a component in `WITH COMPONENTS`
+#[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Eq, Hash)]
+pub enum PresenceConstraint {
+    Present,
+    Absent,
+    Optional,
+}
+
+#[cfg(feature = "std")]
+impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for PresenceConstraint {
+    type Error = Error;
+
+    fn try_from(iter: &mut Peekable<T>) -> Result<Self, Self::Error> {
+        Ok(match iter.next_or_err()? {
+            t if t.eq_text_ignore_ascii_case("PRESENT") => PresenceConstraint::Present,
+            t if t.eq_text_ignore_ascii_case("ABSENT") => PresenceConstraint::Absent,
+            t if t.eq_text_ignore_ascii_case("OPTIONAL") => PresenceConstraint::Optional,
+            t => return Err(Error::unexpected_token(t)),
+        })
+    }
+}
@@ -4,6 +4,7 @@ use std::convert::Infallible;
 
 const TUPLE_VARIABLE_NAME_REPLACEMENT: &str = "value";
 const DATAENUM_VARIABLE_NAME_REPLACEMENT: &str = "value";
+const OPENTYPE_KEY_NAME_REPLACEMENT: &str = "key";
 
 #[allow(clippy::module_name_repetitions)]
 #[derive(Debug, Clone, PartialOrd, PartialEq)]
@@ -17,9 +18,13 @@ pub enum ProtobufType {
     UInt64,
     SInt32,
     SInt64,
+    Double,
     String,
     Bytes,
     BitsReprByBytesAndBitsLen,
+    ObjectIdentifierReprByString,
+    RelativeOidReprByString,
+    TimeReprByString(TimeKind),
     Repeated(Box<ProtobufType>),
     OneOf(Vec<(String, ProtobufType)>),
     /// Indicates a complex, custom type that is
@@ -42,9 +47,13 @@ impl ProtobufType {
             ProtobufType::UInt64 => RustType::U64(Range::none()),
             ProtobufType::SInt32 => RustType::I32(Range::inclusive(0, i32::MAX)),
             ProtobufType::SInt64 => RustType::I64(Range::inclusive(0, i64::MAX)),
-            ProtobufType::String => RustType::String(Size::Any, Charset::Utf8),
+            ProtobufType::Double => RustType::F64,
+            ProtobufType::String => RustType::String(Size::Any, Charset::Utf8, None),
             ProtobufType::Bytes => RustType::VecU8(Size::Any),
             ProtobufType::BitsReprByBytesAndBitsLen => RustType::BitVec(Size::Any),
+            ProtobufType::ObjectIdentifierReprByString => RustType::ObjectIdentifier,
+            ProtobufType::RelativeOidReprByString => RustType::RelativeOid,
+            ProtobufType::TimeReprByString(kind) => RustType::Time(*kind),
             ProtobufType::Repeated(inner) => {
                 RustType::Vec(Box::new(inner.to_rust()), Size::Any, EncodingOrdering::Keep)
             }
@@ -63,8 +72,12 @@ impl ProtobufType {
             ProtobufType::UInt64 => true,
             ProtobufType::SInt32 => true,
             ProtobufType::SInt64 => true,
+            ProtobufType::Double => true,
             ProtobufType::String => true,
             ProtobufType::Bytes | ProtobufType::BitsReprByBytesAndBitsLen => true,
+            ProtobufType::ObjectIdentifierReprByString
+            | ProtobufType::RelativeOidReprByString
+            | ProtobufType::TimeReprByString(_) => true,
             ProtobufType::OneOf(_) => false,
             ProtobufType::Complex(_) => false,
             ProtobufType::Repeated(_) => false,
@@ -82,9 +95,13 @@ impl ToString for ProtobufType {
             ProtobufType::UInt64 => "uint64",
             ProtobufType::SInt32 => "sint32",
             ProtobufType::SInt64 => "sint64",
+            ProtobufType::Double => "double",
             ProtobufType::String => "string",
             ProtobufType::Bytes => "bytes",
             ProtobufType::BitsReprByBytesAndBitsLen => "bytes",
+            ProtobufType::ObjectIdentifierReprByString => "string",
+            ProtobufType::RelativeOidReprByString => "string",
+            ProtobufType::TimeReprByString(_) => "string",
             ProtobufType::OneOf(_) => "oneof",
             ProtobufType::Complex(name) => return name.clone(),
             ProtobufType::Repeated(name) => return format!("repeated {}", name.to_string()),
@@ -122,6 +139,12 @@ impl Model<Protobuf> {
             imports: rust_model.imports.clone(),
             definitions: Vec::with_capacity(rust_model.definitions.len()),
             value_references: Vec::default(),
+            parameterized_definitions: Vec::default(),
+            object_classes: Vec::default(),
+            objects: Vec::default(),
+            object_sets: Vec::default(),
+            tag_default: rust_model.tag_default,
+            extensibility_implied: rust_model.extensibility_implied,
         };
         for Definition(name, rust) in &rust_model.definitions {
             let proto = Self::definition_to_protobuf(rust);
@@ -138,7 +161,10 @@ impl Model<Protobuf> {
                 fields,
                 tag: _,
                 extension_after: _,
+                unknown_extensions: _,
                 ordering: _,
+                components: _,
+                borrowed: _,
             } => {
                 let mut proto_fields = Vec::with_capacity(fields.len());
                 for field in fields.iter() {
@@ -161,10 +187,19 @@ impl Model<Protobuf> {
                         Self::definition_type_to_protobuf_type(variant.r#type()),
                     ))
                 }
-                Protobuf::Message(vec![(
+                let value = (
                     DATAENUM_VARIABLE_NAME_REPLACEMENT.into(),
                     ProtobufType::OneOf(proto_enum),
-                )])
+                );
+                if enumeration.is_open_type() {
+                    // the key precedes the payload, see ProtobufWriter::write_open_type
+                    Protobuf::Message(vec![
+                        (OPENTYPE_KEY_NAME_REPLACEMENT.into(), ProtobufType::UInt64),
+                        value,
+                    ])
+                } else {
+                    Protobuf::Message(vec![value])
+                }
             }
             Rust::TupleStruct { r#type: inner, .. } => Protobuf::Message(vec![(
                 TUPLE_VARIABLE_NAME_REPLACEMENT.into(),
@@ -185,10 +220,14 @@ impl Model<Protobuf> {
             RustType::I32(_) => ProtobufType::SInt32,
             RustType::U64(_) => ProtobufType::UInt64,
             RustType::I64(_) => ProtobufType::SInt64,
+            RustType::F64 => ProtobufType::Double,
             RustType::String(..) => ProtobufType::String,
             RustType::VecU8(_) => ProtobufType::Bytes,
             RustType::BitVec(_) => ProtobufType::BitsReprByBytesAndBitsLen,
             RustType::Null => ProtobufType::Bytes,
+            RustType::ObjectIdentifier => ProtobufType::ObjectIdentifierReprByString,
+            RustType::RelativeOid => ProtobufType::RelativeOidReprByString,
+            RustType::Time(kind) => ProtobufType::TimeReprByString(*kind),
 
             RustType::Complex(complex, _) => ProtobufType::Complex(complex.clone()),
 
@@ -304,7 +343,7 @@ mod tests {
                 "SuchStruct".into(),
                 Rust::struct_from_fields(vec![Field::from_name_type(
                     "very_optional",
-                    RustType::Option(Box::new(RustType::String(Size::Any, Charset::Utf8))),
+                    RustType::Option(Box::new(RustType::String(Size::Any, Charset::Utf8, None))),
                 )]),
             )],
             &[Definition(
@@ -322,7 +361,7 @@ mod tests {
                 Rust::DataEnum(
                     vec![DataVariant::from_name_type(
                         "MuchVariant",
-                        RustType::String(Size::Any, Charset::Utf8),
+                        RustType::String(Size::Any, Charset::Utf8, None),
                     )]
                     .into(),
                 ),
@@ -1,5 +1,11 @@
 use crate::model::lor::{Error, Resolved, Resolver, Unresolved};
-use crate::model::{Asn, Definition, LitOrRef, LiteralValue, Model, Target, Type, ValueReference};
+use crate::model::{
+    Asn, ComponentTypeList, Definition, Field, Import, InformationObject, LitOrRef, LiteralValue,
+    Model, ObjectClass, ObjectClassFieldType, ObjectIdentifier, ObjectIdentifierComponent,
+    ObjectSet, ObjectSetElement, OpenType, OpenTypeVariant, ParameterizedDefinition, Setting,
+    TableConstraint, Target, Type, ValueReference,
+};
+use crate::parser::Token;
 
 #[derive(Default)]
 pub struct MultiModuleResolver {
@@ -41,12 +47,65 @@ impl<'a> From<&'a Model<Asn<Unresolved>>> for ResolveScope<'a> {
 
 impl<'a> ResolveScope<'a> {
     pub(crate) fn try_resolve(&self) -> Result<Model<Asn<Resolved>>, Error> {
+        if let Some(model) = self.instantiate_parameterized_references()? {
+            return ResolveScope {
+                model: &model,
+                scope: self.scope,
+            }
+            .try_resolve();
+        }
+
+        if let Some(model) = self.include_components_of()? {
+            return ResolveScope {
+                model: &model,
+                scope: self.scope,
+            }
+            .try_resolve();
+        }
+
+        if let Some(model) = self.resolve_object_class_fields()? {
+            return ResolveScope {
+                model: &model,
+                scope: self.scope,
+            }
+            .try_resolve();
+        }
+
         let mut result = Model::<Asn<Resolved>> {
             name: self.model.name.clone(),
             oid: self.model.oid.clone(),
-            imports: self.model.imports.clone(),
+            imports: self
+                .model
+                .imports
+                .iter()
+                .map(|import| Import {
+                    // there is no counterpart for a parameterized definition, only for its
+                    // instantiations
+                    what: import
+                        .what
+                        .iter()
+                        .filter(|what| self.parameterized_definition(what).is_none())
+                        // information object classes, objects and object sets are only used
+                        // while resolving
+                        .filter(|what| {
+                            self.object_class(what).is_none()
+                                && self.object(what).is_none()
+                                && self.object_set(what).is_none()
+                        })
+                        .cloned()
+                        .collect(),
+                    ..import.clone()
+                })
+                .filter(|import| !import.what.is_empty())
+                .collect(),
             definitions: Vec::with_capacity(self.model.definitions.len()),
             value_references: Vec::with_capacity(self.model.value_references.len()),
+            parameterized_definitions: self.model.parameterized_definitions.clone(),
+            object_classes: self.model.object_classes.clone(),
+            objects: self.model.objects.clone(),
+            object_sets: self.model.object_sets.clone(),
+            tag_default: self.model.tag_default,
+            extensibility_implied: self.model.extensibility_implied,
         };
 
         // copy over all value references
@@ -54,7 +113,7 @@ impl<'a> ResolveScope<'a> {
             result.value_references.push(ValueReference {
                 name: vr.name.clone(),
                 role: vr.role.try_resolve(self)?,
-                value: vr.value.clone(),
+                value: self.resolve_literal(&vr.value)?,
             })
         }
 
@@ -67,6 +126,494 @@ impl<'a> ResolveScope<'a> {
         Ok(result)
     }
 
+    /// Instantiates all parameterized types that are referenced in this model but not yet
+    /// defined. Returns [`None`] if there is nothing to instantiate, otherwise a copy of the model
+    /// with the concrete definitions - and the imports these require - added to it.
+    fn instantiate_parameterized_references(
+        &self,
+    ) -> Result<Option<Model<Asn<Unresolved>>>, Error> {
+        let mut instances: Vec<Definition<Asn<Unresolved>>> = Vec::new();
+        let mut imports: Vec<Import> = Vec::new();
+        let mut pending = self
+            .model
+            .definitions
+            .iter()
+            .flat_map(|Definition(_, asn)| asn.r#type.parameterized_references())
+            .map(|(name, arguments)| (name.to_string(), arguments.to_vec()))
+            .collect::<Vec<_>>();
+
+        while let Some((name, arguments)) = pending.pop() {
+            let instance = ParameterizedDefinition::instance_name(&name, &arguments);
+            if self.definition(&instance).is_some()
+                || instances.iter().any(|def| def.name().eq(&instance))
+            {
+                continue;
+            }
+
+            let (origin, parameterized) = self
+                .parameterized_definition(&name)
+                .ok_or_else(|| Error::FailedToResolveType(name.clone()))?;
+
+            if parameterized.parameters.len() != arguments.len() {
+                return Err(Error::FailedToInstantiateType(instance));
+            }
+
+            let mut definition = parameterized
+                .instantiate(instance.clone(), &arguments)
+                .map_err(|_| Error::FailedToInstantiateType(instance))?;
+
+            if origin.extensibility_implied {
+                definition.1.r#type.imply_extensibility();
+            }
+
+            pending.extend(
+                definition
+                    .1
+                    .r#type
+                    .parameterized_references()
+                    .into_iter()
+                    .map(|(name, arguments)| (name.to_string(), arguments.to_vec())),
+            );
+
+            if !core::ptr::eq(origin, self.model) {
+                // the body might refer to other items of the module it is defined in
+                imports.push(Import {
+                    what: parameterized
+                        .tokens
+                        .iter()
+                        .filter_map(|token| token.text())
+                        .filter(|text| {
+                            origin.definitions.iter().any(|def| def.name().eq(*text))
+                                || origin.value_references.iter().any(|vr| vr.name.eq(*text))
+                                || origin
+                                    .parameterized_definitions
+                                    .iter()
+                                    .any(|def| def.name.eq(*text))
+                                || origin.object_classes.iter().any(|def| def.name().eq(*text))
+                                || origin.object_sets.iter().any(|def| def.name().eq(*text))
+                        })
+                        .filter(|text| self.definition(text).is_none())
+                        .map(ToString::to_string)
+                        .collect(),
+                    from: origin.name.clone(),
+                    from_oid: origin.oid.clone(),
+                });
+            }
+
+            instances.push(definition);
+        }
+
+        if instances.is_empty() {
+            Ok(None)
+        } else {
+            let mut model = self.model.clone();
+            model.definitions.extend(instances);
+            model
+                .imports
+                .extend(imports.into_iter().filter(|import| !import.what.is_empty()));
+            Ok(Some(model))
+        }
+    }
+
+    /// Replaces the `COMPONENTS OF` notations (ITU-T X.680 | ISO/IEC 8824-1, 25.5) by the root
+    /// components of the referenced SEQUENCE or SET types. Returns [`None`] if there is nothing
+    /// to replace.
+    fn include_components_of(&self) -> Result<Option<Model<Asn<Unresolved>>>, Error> {
+        if !self
+            .model
+            .definitions
+            .iter()
+            .any(|Definition(_, asn)| asn.r#type.has_components_of())
+        {
+            return Ok(None);
+        }
+
+        let mut model = self.model.clone();
+        for Definition(_, asn) in &mut model.definitions {
+            self.include_components_of_in(&mut asn.r#type)?;
+        }
+        Ok(Some(model))
+    }
+
+    fn include_components_of_in(&self, r#type: &mut Type<Unresolved>) -> Result<(), Error> {
+        match r#type {
+            Type::Optional(inner)
+            | Type::Default(inner, _)
+            | Type::SequenceOf(inner, _)
+            | Type::SetOf(inner, _) => self.include_components_of_in(inner),
+            Type::Sequence(list) | Type::Set(list) => self.include_components_of_in_list(list),
+            _ => Ok(()),
+        }
+    }
+
+    fn include_components_of_in_list(
+        &self,
+        list: &mut ComponentTypeList<Unresolved>,
+    ) -> Result<(), Error> {
+        for field in &mut list.fields {
+            self.include_components_of_in(&mut field.role.r#type)?;
+        }
+        while let Some(index) = list.components_of.len().checked_sub(1) {
+            let name = &list.components_of[index].r#type;
+            let mut included = match self.definition(name).map(|def| &def.1.r#type) {
+                Some(Type::Sequence(other)) | Some(Type::Set(other)) => other.clone(),
+                _ => return Err(Error::FailedToResolveType(name.clone())),
+            };
+            // the included type might use the notation as well
+            self.include_components_of_in_list(&mut included)?;
+            let root = included
+                .extension_after
+                .map_or(included.fields.len(), |index| index + 1);
+            list.include_components_of(index, &included.fields[..root]);
+        }
+        Ok(())
+    }
+
+    /// Replaces the types that refer to a field of an information object class
+    /// (ITU-T X.681 | ISO/IEC 8824-2, 14) by the type of the field. A type field that is
+    /// constrained by a component relation constraint (ITU-T X.682 | ISO/IEC 8824-3, 10.7) is
+    /// replaced by a reference to an [`OpenType`] of all the types in the object set, keyed by
+    /// the values the objects have for the referenced component. Returns [`None`] if there is
+    /// nothing to replace.
+    fn resolve_object_class_fields(&self) -> Result<Option<Model<Asn<Unresolved>>>, Error> {
+        if !self
+            .model
+            .definitions
+            .iter()
+            .any(|Definition(_, asn)| asn.r#type.has_object_class_fields())
+        {
+            return Ok(None);
+        }
+
+        let mut model = self.model.clone();
+        let mut open_types = Vec::new();
+        let mut imports = Vec::new();
+
+        for Definition(name, asn) in &mut model.definitions {
+            asn.r#type = self.resolve_object_class_fields_of(
+                name,
+                &asn.r#type,
+                &[],
+                &mut open_types,
+                &mut imports,
+            )?;
+        }
+
+        model.definitions.extend(open_types);
+        model.imports.extend(imports);
+        Ok(Some(model))
+    }
+
+    fn resolve_object_class_fields_of(
+        &self,
+        name: &str,
+        r#type: &Type<Unresolved>,
+        siblings: &[Field<Asn<Unresolved>>],
+        open_types: &mut Vec<Definition<Asn<Unresolved>>>,
+        imports: &mut Vec<Import>,
+    ) -> Result<Type<Unresolved>, Error> {
+        let mut resolve = |inner: &Type<Unresolved>, siblings: &[Field<Asn<Unresolved>>]| {
+            self.resolve_object_class_fields_of(name, inner, siblings, open_types, imports)
+                .map(Box::new)
+        };
+        Ok(match r#type {
+            Type::ObjectClassField(field) => {
+                self.resolve_object_class_field(name, field, siblings, open_types, imports)?
+            }
+            Type::Optional(inner) => Type::Optional(resolve(inner, siblings)?),
+            Type::Default(inner, default) => {
+                Type::Default(resolve(inner, siblings)?, default.clone())
+            }
+            Type::SequenceOf(inner, size) => Type::SequenceOf(resolve(inner, &[])?, size.clone()),
+            Type::SetOf(inner, size) => Type::SetOf(resolve(inner, &[])?, size.clone()),
+            Type::Sequence(list) => Type::Sequence(
+                self.resolve_object_class_fields_of_components(name, list, open_types, imports)?,
+            ),
+            Type::Set(list) => Type::Set(
+                self.resolve_object_class_fields_of_components(name, list, open_types, imports)?,
+            ),
+            other => other.clone(),
+        })
+    }
+
+    fn resolve_object_class_fields_of_components(
+        &self,
+        name: &str,
+        list: &ComponentTypeList<Unresolved>,
+        open_types: &mut Vec<Definition<Asn<Unresolved>>>,
+        imports: &mut Vec<Import>,
+    ) -> Result<ComponentTypeList<Unresolved>, Error> {
+        let mut fields = Vec::with_capacity(list.fields.len());
+        for field in &list.fields {
+            let mut field = field.clone();
+            field.role.r#type = self.resolve_object_class_fields_of(
+                &format!("{}-{}", name, field.name),
+                &field.role.r#type,
+                &list.fields,
+                open_types,
+                imports,
+            )?;
+            fields.push(field);
+        }
+        Ok(ComponentTypeList {
+            fields,
+            extension_after: list.extension_after,
+            components_of: list.components_of.clone(),
+        })
+    }
+
+    fn resolve_object_class_field(
+        &self,
+        name: &str,
+        field: &ObjectClassFieldType,
+        siblings: &[Field<Asn<Unresolved>>],
+        open_types: &mut Vec<Definition<Asn<Unresolved>>>,
+        imports: &mut Vec<Import>,
+    ) -> Result<Type<Unresolved>, Error> {
+        let failed =
+            || Error::FailedToResolveInformationObject(format!("{}.{}", field.class, field.field));
+        let (class_origin, class) = self
+            .object_class(&field.class)
+            .ok_or_else(|| Error::FailedToResolveType(field.class.clone()))?;
+        let spec = class.field(&field.field).ok_or_else(failed)?;
+
+        if !spec.is_type_field() {
+            return spec.r#type.clone().ok_or_else(failed);
+        }
+
+        let (object_set, component) = match &field.constraint {
+            Some(TableConstraint {
+                object_set,
+                component: Some(component),
+            }) => (object_set, component),
+            _ => return Err(failed()),
+        };
+
+        // the field of the class that is referenced by the component relation constraint
+        let key_field = siblings
+            .iter()
+            .find(|sibling| sibling.name.eq(component))
+            .map(|sibling| match &sibling.role.r#type {
+                Type::Optional(inner) => &**inner,
+                other => other,
+            })
+            .and_then(|r#type| match r#type {
+                Type::ObjectClassField(key) if key.class.eq(&field.class) => Some(&key.field),
+                _ => None,
+            })
+            .ok_or_else(failed)?;
+
+        // the identifiers of an ENUMERATED key field are keyed by their index
+        let enumerated = match class.field(key_field).and_then(|spec| spec.r#type.as_ref()) {
+            Some(Type::Enumerated(enumerated)) => Some(enumerated),
+            Some(Type::TypeReference(reference, _)) => ResolveScope {
+                model: class_origin,
+                scope: self.scope,
+            }
+            .definition(reference)
+            .and_then(|Definition(_, asn)| match &asn.r#type {
+                Type::Enumerated(enumerated) => Some(enumerated),
+                _ => None,
+            }),
+            _ => None,
+        };
+
+        let (objects, extensible) = self.information_objects(object_set)?;
+        let mut variants: Vec<OpenTypeVariant<Unresolved>> = Vec::with_capacity(objects.len());
+
+        for (origin, settings) in objects {
+            let setting = |name: &str| {
+                settings
+                    .iter()
+                    .find(|(field, _)| field.eq(name))
+                    .map(|(_, setting)| setting)
+            };
+            let origin = ResolveScope {
+                model: origin,
+                scope: self.scope,
+            };
+
+            let (key, key_object_identifier) = match (setting(key_field), enumerated) {
+                (Some(Setting::Value(LitOrRef::Ref(identifier))), Some(enumerated)) => {
+                    let index = enumerated.variants().position(|v| v.name().eq(identifier));
+                    (index.ok_or_else(failed)?, None)
+                }
+                (Some(Setting::Value(value)), _) => {
+                    match Resolver::<LiteralValue>::resolve(&origin, value)? {
+                        LiteralValue::Integer(value) if value >= 0 => (value as usize, None),
+                        // OBJECT IDENTIFIER values are keyed by the index of the variant
+                        LiteralValue::ObjectIdentifier(oid) => {
+                            let arcs = oid
+                                .iter()
+                                .map(|component| match component {
+                                    ObjectIdentifierComponent::NumberForm(number) => Some(*number),
+                                    _ => None,
+                                })
+                                .collect::<Option<Vec<_>>>();
+                            (variants.len(), Some(arcs.ok_or_else(failed)?))
+                        }
+                        _ => return Err(failed()),
+                    }
+                }
+                _ => return Err(failed()),
+            };
+
+            let r#type = match setting(&field.field) {
+                Some(Setting::Type(r#type)) => r#type.clone(),
+                None if spec.optional => continue,
+                _ => return Err(failed()),
+            };
+
+            if let Type::TypeReference(reference, _) = &r#type {
+                if !core::ptr::eq(origin.model, self.model) && self.definition(reference).is_none()
+                {
+                    imports.push(Import {
+                        what: vec![reference.clone()],
+                        from: origin.model.name.clone(),
+                        from_oid: origin.model.oid.clone(),
+                    });
+                }
+            }
+
+            let mut variant_name = match &r#type {
+                Type::TypeReference(reference, _) => reference.clone(),
+                _ => format!("{}-{}", field.field.trim_start_matches('&'), key),
+            };
+            if variants.iter().any(|v| v.name.eq(&variant_name)) {
+                variant_name = format!("{}-{}", variant_name, key);
+            }
+
+            variants.push(OpenTypeVariant {
+                name: variant_name,
+                identifier: None,
+                tag: None,
+                r#type,
+                key: Some(key),
+                key_object_identifier,
+            });
+        }
+
+        let extension_after = Some(variants.len())
+            .filter(|len| extensible && *len > 0)
+            .map(|len| len - 1);
+        open_types.push(Definition(
+            name.to_string(),
+            Type::OpenType(OpenType::from(variants).with_maybe_extension_after(extension_after))
+                .untagged(),
+        ));
+
+        Ok(Type::TypeReferenceId(
+            name.to_string(),
+            None,
+            Some(component.clone()),
+            None,
+        ))
+    }
+
+    /// Collects the settings of all the information objects in the given object set, together
+    /// with the module each object is defined in, and whether the object set is extensible
+    #[allow(clippy::type_complexity)]
+    fn information_objects(
+        &self,
+        object_set: &str,
+    ) -> Result<
+        (
+            Vec<(&'a Model<Asn<Unresolved>>, Vec<(String, Setting)>)>,
+            bool,
+        ),
+        Error,
+    > {
+        let (origin, set) = self
+            .object_set(object_set)
+            .ok_or_else(|| Error::FailedToResolveReference(object_set.to_string()))?;
+        let origin = ResolveScope {
+            model: origin,
+            scope: self.scope,
+        };
+        let (_, class) = origin
+            .object_class(&set.class)
+            .ok_or_else(|| Error::FailedToResolveType(set.class.clone()))?;
+        let read_object = |tokens: &[Token]| {
+            class.read_object(tokens).map_err(|e| {
+                Error::FailedToResolveInformationObject(format!("{}: {}", object_set, e))
+            })
+        };
+
+        let mut objects = Vec::new();
+        let mut extensible = set.extensible;
+
+        for element in &set.elements {
+            match element {
+                ObjectSetElement::Object(tokens) => {
+                    objects.push((origin.model, read_object(tokens)?));
+                }
+                ObjectSetElement::Reference(name) => {
+                    if let Some((model, object)) = origin.object(name) {
+                        objects.push((model, read_object(&object.tokens)?));
+                    } else {
+                        let (nested, nested_extensible) = origin.information_objects(name)?;
+                        objects.extend(nested);
+                        extensible |= nested_extensible;
+                    }
+                }
+            }
+        }
+
+        Ok((objects, extensible))
+    }
+
+    fn object_class(&self, name: &str) -> Option<(&'a Model<Asn<Unresolved>>, &'a ObjectClass)> {
+        self.find_with_origin(name, |model| &model.object_classes[..])
+    }
+
+    fn object(&self, name: &str) -> Option<(&'a Model<Asn<Unresolved>>, &'a InformationObject)> {
+        self.find_with_origin(name, |model| &model.objects[..])
+    }
+
+    fn object_set(&self, name: &str) -> Option<(&'a Model<Asn<Unresolved>>, &'a ObjectSet)> {
+        self.find_with_origin(name, |model| &model.object_sets[..])
+    }
+
+    fn find_with_origin<T>(
+        &self,
+        name: &str,
+        items: fn(&'a Model<Asn<Unresolved>>) -> &'a [Definition<T>],
+    ) -> Option<(&'a Model<Asn<Unresolved>>, &'a T)> {
+        items(self.model)
+            .iter()
+            .find(|def| def.name().eq(name))
+            .map(|def| (self.model, def.value()))
+            .or_else(|| {
+                self.model_with_imported_item(name).and_then(|model| {
+                    ResolveScope {
+                        model,
+                        scope: self.scope,
+                    }
+                    .find_with_origin(name, items)
+                })
+            })
+    }
+
+    fn parameterized_definition(
+        &self,
+        name: &str,
+    ) -> Option<(&'a Model<Asn<Unresolved>>, &'a ParameterizedDefinition)> {
+        self.model
+            .parameterized_definitions
+            .iter()
+            .find(|def| def.name.eq(name))
+            .map(|def| (self.model, def))
+            .or_else(|| {
+                self.model_with_imported_item(name).and_then(|model| {
+                    ResolveScope {
+                        model,
+                        scope: self.scope,
+                    }
+                    .parameterized_definition(name)
+                })
+            })
+    }
+
     fn model_with_imported_item(&self, item: &str) -> Option<&'a Model<Asn<Unresolved>>> {
         self.model
             .imports
@@ -98,6 +645,50 @@ impl<'a> ResolveScope<'a> {
             })
     }
 
+    fn resolve_literal(&self, literal: &LiteralValue) -> Result<LiteralValue, Error> {
+        match literal {
+            LiteralValue::ObjectIdentifier(oid) => {
+                Ok(LiteralValue::ObjectIdentifier(self.resolve_oid(oid)?))
+            }
+            other => Ok(other.clone()),
+        }
+    }
+
+    /// Flattens the given [`ObjectIdentifier`] into its `NumberForm`. A leading `NameForm` is
+    /// either one of the well-known root arcs (ITU-T X.660, A.2) or refers to another object
+    /// identifier value, whose arcs are spliced in.
+    fn resolve_oid(&self, oid: &ObjectIdentifier) -> Result<ObjectIdentifier, Error> {
+        let mut arcs = Vec::with_capacity(oid.0.len());
+        for (index, component) in oid.iter().enumerate() {
+            match component {
+                ObjectIdentifierComponent::NumberForm(number)
+                | ObjectIdentifierComponent::NameAndNumberForm(_, number) => {
+                    arcs.push(ObjectIdentifierComponent::NumberForm(*number))
+                }
+                ObjectIdentifierComponent::NameForm(name) if index == 0 => match name.as_str() {
+                    "itu-t" | "ccitt" => arcs.push(ObjectIdentifierComponent::NumberForm(0)),
+                    "iso" => arcs.push(ObjectIdentifierComponent::NumberForm(1)),
+                    "joint-iso-itu-t" | "joint-iso-ccitt" => {
+                        arcs.push(ObjectIdentifierComponent::NumberForm(2))
+                    }
+                    _ => match self.value_reference(name).map(|vr| &vr.value) {
+                        Some(LiteralValue::ObjectIdentifier(referenced)) => {
+                            arcs.extend(self.resolve_oid(referenced)?.0)
+                        }
+                        Some(_) => {
+                            return Err(Error::FailedToParseLiteral(format!("name: {}", name)))
+                        }
+                        None => return Err(Error::FailedToResolveReference(name.clone())),
+                    },
+                },
+                ObjectIdentifierComponent::NameForm(name) => {
+                    return Err(Error::FailedToResolveReference(name.clone()))
+                }
+            }
+        }
+        Ok(ObjectIdentifier(arcs))
+    }
+
     fn definition(&self, name: &str) -> Option<&'a Definition<Asn<Unresolved>>> {
         self.model
             .definitions
@@ -147,11 +738,11 @@ impl Resolver<i64> for ResolveScope<'_> {
 impl Resolver<LiteralValue> for ResolveScope<'_> {
     fn resolve(&self, lor: &LitOrRef<LiteralValue>) -> Result<LiteralValue, Error> {
         match lor {
-            LitOrRef::Lit(lit) => Ok(lit.clone()),
+            LitOrRef::Lit(lit) => self.resolve_literal(lit),
             LitOrRef::Ref(name) => self
                 .value_reference(name)
-                .map(|vr| vr.value.clone())
-                .ok_or_else(|| Error::FailedToResolveReference(name.clone())),
+                .ok_or_else(|| Error::FailedToResolveReference(name.clone()))
+                .and_then(|vr| self.resolve_literal(&vr.value)),
         }
     }
 }
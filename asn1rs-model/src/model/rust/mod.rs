@@ -1,13 +1,16 @@
 use crate::model::lor::{ResolveState, Resolved};
 use crate::model::rust::Field as RustField;
-use crate::model::{Asn, ChoiceVariant,OpenTypeVariant, Integer, LiteralValue, Target};
-use crate::model::{Charset, Range};
+use crate::model::ValueConstraint;
+use crate::model::{Asn, ChoiceVariant, Integer, LiteralValue, OpenTypeVariant, Target};
+use crate::model::{Charset, PermittedAlphabet, Range, TimeKind};
 use crate::model::{ComponentTypeList, ValueReference};
 use crate::model::{Definition, Type};
-use crate::model::{Import, Tag, TagProperty};
-use crate::model::{Model, Size};
+use crate::model::{EnumeratedVariant, ObjectIdentifierComponent};
+use crate::model::{Import, InnerTypeConstraints, PresenceConstraint, Tag, TagProperty};
+use crate::model::{Model, ParameterizedDefinition, Size};
 use crate::model::{TagResolver, Type as AsnType};
 use std::borrow::Cow;
+use std::collections::HashSet;
 
 const I8_MAX: i64 = i8::MAX as i64;
 const I16_MAX: i64 = i16::MAX as i64;
@@ -39,11 +42,15 @@ pub enum RustType {
     U32(Range<u32>),
     I64(Range<i64>),
     U64(Range<Option<u64>>),
-    String(Size, Charset),
+    F64,
+    String(Size, Charset, Option<PermittedAlphabet>),
     VecU8(Size),
     BitVec(Size),
     Vec(Box<RustType>, Size, EncodingOrdering),
     Null,
+    ObjectIdentifier,
+    RelativeOid,
+    Time(TimeKind),
 
     Option(Box<RustType>),
     Default(Box<RustType>, LiteralValue),
@@ -121,10 +128,36 @@ impl RustType {
                 | RustType::U32(_)
                 | RustType::I32(_)
                 | RustType::U64(_)
-                | RustType::I64(_),
+                | RustType::I64(_)
+                | RustType::F64,
         ) || matches!(self, RustType::Default(inner, ..) if inner.is_primitive())
     }
 
+    /// Whether the value borrows from the data it is decoded from, if it is part of a borrowed
+    /// type (see [`Rust::with_borrowed`]). This applies to OCTET STRING and UTF8String values -
+    /// except for those with a DEFAULT value - and to references of borrowed types, which are
+    /// named with the lifetime `'a` (like `Inner<'a>`).
+    pub fn is_borrowed(&self) -> bool {
+        match self {
+            RustType::VecU8(_) | RustType::String(_, Charset::Utf8, _) => true,
+            RustType::Vec(inner, ..) | RustType::Option(inner) => inner.is_borrowed(),
+            RustType::Complex(name, _) => name.contains('\''),
+            _ => false,
+        }
+    }
+
+    /// Like [`ToString::to_string`], but for a value that is part of a borrowed type, see
+    /// [`RustType::is_borrowed`]
+    pub fn to_borrowed_string(&self) -> String {
+        match self {
+            RustType::VecU8(_) => "Cow<'a, [u8]>".to_string(),
+            RustType::String(_, Charset::Utf8, _) => "Cow<'a, str>".to_string(),
+            RustType::Vec(inner, ..) => format!("Vec<{}>", inner.to_borrowed_string()),
+            RustType::Option(inner) => format!("Option<{}>", inner.to_borrowed_string()),
+            other => other.to_string(),
+        }
+    }
+
     pub fn integer_range_str(&self) -> Option<Range<String>> {
         #[allow(clippy::match_same_arms)] // to have the same order as the original enum
         match self {
@@ -155,11 +188,15 @@ impl RustType {
             RustType::I64(Range(min, max, extensible)) => {
                 Some(Range(min.to_string(), max.to_string(), *extensible))
             }
+            RustType::F64 => None,
             RustType::String(..) => None,
             RustType::VecU8(_) => None,
             RustType::BitVec(_) => None,
             RustType::Vec(inner, _size, _ordering) => inner.integer_range_str(),
             RustType::Null => None,
+            RustType::ObjectIdentifier => None,
+            RustType::RelativeOid => None,
+            RustType::Time(_) => None,
             RustType::Option(inner) => inner.integer_range_str(),
             RustType::Default(inner, ..) => inner.integer_range_str(),
             RustType::Complex(_, _) => None,
@@ -207,7 +244,8 @@ impl RustType {
                 range.max().map(|v| v as i64),
                 range.extensible(),
             )),
-            RustType::String(size, charset) => AsnType::String(size, charset),
+            RustType::F64 => AsnType::Real,
+            RustType::String(size, charset, alphabet) => AsnType::String(size, charset, alphabet),
             RustType::VecU8(size) => AsnType::OctetString(size),
             RustType::BitVec(size) => AsnType::bit_vec_with_size(size),
             RustType::Vec(inner, size, EncodingOrdering::Keep) => {
@@ -217,6 +255,9 @@ impl RustType {
                 AsnType::SetOf(Box::new(inner.into_asn()), size)
             }
             RustType::Null => AsnType::Null,
+            RustType::ObjectIdentifier => AsnType::ObjectIdentifier,
+            RustType::RelativeOid => AsnType::RelativeOid,
+            RustType::Time(kind) => AsnType::Time(kind),
             RustType::Option(value) => AsnType::Optional(Box::new(value.into_asn())),
             RustType::Default(value, default) => {
                 AsnType::Default(Box::new(value.into_asn()), default)
@@ -236,6 +277,7 @@ impl RustType {
             RustType::I32(_) => matches!(other, RustType::I32(_)),
             RustType::U64(_) => matches!(other, RustType::U64(_)),
             RustType::I64(_) => matches!(other, RustType::I64(_)),
+            RustType::F64 => RustType::F64 == *other,
             RustType::String(..) => matches!(other, RustType::String(..)),
             RustType::VecU8(_) => matches!(other, RustType::VecU8(_)),
             RustType::BitVec(_) => matches!(other, RustType::BitVec(_)),
@@ -247,6 +289,9 @@ impl RustType {
                 }
             }
             RustType::Null => RustType::Null == *other,
+            RustType::ObjectIdentifier => RustType::ObjectIdentifier == *other,
+            RustType::RelativeOid => RustType::RelativeOid == *other,
+            RustType::Time(kind) => RustType::Time(*kind) == *other,
             RustType::Option(inner) => {
                 matches!(other, RustType::Option(o) if o.similar(inner))
                     || matches!(other, RustType::Default(o, ..) if o.similar(inner))
@@ -278,12 +323,16 @@ impl RustType {
             | RustType::U32(_)
             | RustType::I64(_)
             | RustType::U64(_) => Tag::DEFAULT_INTEGER,
+            RustType::F64 => Tag::DEFAULT_REAL,
             RustType::BitVec(_) => Tag::DEFAULT_BIT_STRING,
             RustType::VecU8(_) => Tag::DEFAULT_OCTET_STRING,
-            RustType::String(_, charset) => charset.default_tag(),
+            RustType::String(_, charset, _) => charset.default_tag(),
             RustType::Vec(_, _, EncodingOrdering::Keep) => Tag::DEFAULT_SEQUENCE_OF,
             RustType::Vec(_, _, EncodingOrdering::Sort) => Tag::DEFAULT_SET_OF,
             RustType::Null => Tag::DEFAULT_NULL,
+            RustType::ObjectIdentifier => Tag::DEFAULT_OBJECT_IDENTIFIER,
+            RustType::RelativeOid => Tag::DEFAULT_RELATIVE_OID,
+            RustType::Time(kind) => kind.default_tag(),
             RustType::Option(inner) => return inner.tag(),
             RustType::Default(inner, ..) => return inner.tag(),
             // TODO this is wrong. This should resolve the tag from the referenced type instead, but atm the infrastructure is missing to do such a thing, see github#13
@@ -300,6 +349,87 @@ pub enum EncodingOrdering {
     Keep,
 }
 
+/// A `WITH COMPONENTS` constraint on a field of a struct (ITU-T X.680 | ISO/IEC 8824-1, 51.8)
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub struct ComponentConstraint {
+    /// The name of the constrained field
+    pub field: String,
+    /// `PRESENT` or `ABSENT`, `OPTIONAL` does not constrain the field
+    pub presence: Option<PresenceConstraint>,
+    pub value: Option<PermittedValue>,
+}
+
+impl ComponentConstraint {
+    pub fn is_present(&self) -> bool {
+        self.presence == Some(PresenceConstraint::Present)
+    }
+}
+
+/// The value constraint of a [`ComponentConstraint`], as far as it is supported
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum PermittedValue {
+    /// The inclusive bounds of an INTEGER value, if any
+    Integer(Option<i64>, Option<i64>),
+    Boolean(bool),
+    /// The ASN.1 identifier of an ENUMERATED value
+    Enumerated(String),
+}
+
+impl PermittedValue {
+    /// Maps the value constraint to the type of the field, if known. Otherwise the kind of value
+    /// is guessed from the notation.
+    fn from_constraint(
+        value: &ValueConstraint,
+        r#type: Option<&RustType>,
+        definitions: &[Definition<Rust>],
+    ) -> Option<Self> {
+        let r#type = match r#type {
+            Some(RustType::Option(inner)) | Some(RustType::Default(inner, _)) => inner,
+            Some(r#type) => r#type,
+            None => {
+                return if let Some((min, max)) = value.integer_range() {
+                    Some(PermittedValue::Integer(min, max))
+                } else if let Some(boolean) = value.boolean() {
+                    Some(PermittedValue::Boolean(boolean))
+                } else {
+                    value
+                        .identifier()
+                        .map(|identifier| PermittedValue::Enumerated(identifier.to_string()))
+                }
+            }
+        };
+        match r#type {
+            RustType::Bool => value.boolean().map(PermittedValue::Boolean),
+            RustType::I8(_)
+            | RustType::U8(_)
+            | RustType::I16(_)
+            | RustType::U16(_)
+            | RustType::I32(_)
+            | RustType::U32(_)
+            | RustType::I64(_)
+            | RustType::U64(_) => value
+                .integer_range()
+                .map(|(min, max)| PermittedValue::Integer(min, max)),
+            RustType::Complex(name, _) => {
+                let identifier = value.identifier()?;
+                definitions
+                    .iter()
+                    .find_map(|Definition(n, rust)| match rust {
+                        Rust::Enum(plain)
+                            if n == name
+                                && (0..plain.len())
+                                    .any(|i| plain.identifier(i) == Some(identifier)) =>
+                        {
+                            Some(PermittedValue::Enumerated(identifier.to_string()))
+                        }
+                        _ => None,
+                    })
+            }
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialOrd, PartialEq)]
 pub enum Rust {
     Struct {
@@ -307,6 +437,14 @@ pub enum Rust {
         fields: Vec<Field>,
         tag: Option<Tag>,
         extension_after: Option<usize>,
+        /// Whether the struct carries the extension additions unknown to its schema
+        unknown_extensions: bool,
+        /// The `WITH COMPONENTS` constraints on the fields. A field that is constrained to be
+        /// `PRESENT` is `OPTIONAL` in ASN.1 but not optional in Rust.
+        components: Vec<ComponentConstraint>,
+        /// Whether the struct borrows values from the data it is decoded from, see
+        /// [`Rust::with_borrowed`]
+        borrowed: bool,
     },
     Enum(PlainEnum),
     DataEnum(DataEnum),
@@ -317,6 +455,11 @@ pub enum Rust {
         r#type: RustType,
         tag: Option<Tag>,
         constants: Vec<(String, String)>,
+        /// The `WITH COMPONENTS` constraints on the fields of the referenced struct
+        components: Vec<ComponentConstraint>,
+        /// Whether the struct borrows values from the data it is decoded from, see
+        /// [`Rust::with_borrowed`]
+        borrowed: bool,
     },
 }
 
@@ -328,6 +471,85 @@ impl Rust {
             fields,
             tag: None,
             extension_after: None,
+            unknown_extensions: false,
+            components: Vec::default(),
+            borrowed: false,
+        }
+    }
+
+    /// Whether the type carries the extension additions unknown to its schema, see
+    /// [`Rust::with_unknown_extensions`]
+    pub fn has_unknown_extensions(&self) -> bool {
+        match self {
+            Rust::Struct {
+                unknown_extensions, ..
+            } => *unknown_extensions,
+            Rust::Enum(e) => e.has_unknown_extensions(),
+            Rust::DataEnum(c) => c.has_unknown_extensions(),
+            Rust::TupleStruct { .. } => false,
+        }
+    }
+
+    /// Lets an extensible SEQUENCE, SET, ENUMERATED or CHOICE carry the extension additions
+    /// unknown to its schema and an open type the keys unknown to its object set, so that these
+    /// can be re-encoded verbatim. All other types are returned unchanged.
+    pub fn with_unknown_extensions(mut self) -> Self {
+        match &mut self {
+            Rust::Struct {
+                extension_after,
+                unknown_extensions,
+                ..
+            } => *unknown_extensions = extension_after.is_some(),
+            Rust::Enum(e) => e.unknown_extensions = e.is_extensible(),
+            Rust::DataEnum(c) => c.unknown_extensions = c.is_extensible() || c.is_open_type(),
+            Rust::TupleStruct { .. } => {}
+        }
+        self
+    }
+
+    /// Reverts [`Rust::with_unknown_extensions`], which open types are generated with
+    pub fn without_unknown_extensions(mut self) -> Self {
+        match &mut self {
+            Rust::Struct {
+                unknown_extensions, ..
+            } => *unknown_extensions = false,
+            Rust::Enum(e) => e.unknown_extensions = false,
+            Rust::DataEnum(c) => c.unknown_extensions = false,
+            Rust::TupleStruct { .. } => {}
+        }
+        self
+    }
+
+    /// Whether the type borrows values from the data it is decoded from and is therefore
+    /// declared with the lifetime `'a`, see [`Rust::with_borrowed`]
+    pub fn is_borrowed(&self) -> bool {
+        match self {
+            Rust::Struct { borrowed, .. } | Rust::TupleStruct { borrowed, .. } => *borrowed,
+            Rust::Enum(_) => false,
+            Rust::DataEnum(c) => c.is_borrowed(),
+        }
+    }
+
+    /// Lets the values of a SEQUENCE, SET, CHOICE or transparent type borrow from the data they
+    /// are decoded from, if the decoder supports this, which requires the type to be declared with
+    /// the lifetime `'a`. See [`RustType::is_borrowed`] for the values that borrow. ENUMERATED
+    /// types are returned unchanged.
+    pub fn with_borrowed(mut self) -> Self {
+        match &mut self {
+            Rust::Struct { borrowed, .. } | Rust::TupleStruct { borrowed, .. } => *borrowed = true,
+            Rust::Enum(_) => {}
+            Rust::DataEnum(c) => c.borrowed = true,
+        }
+        self
+    }
+
+    /// The types of all fields or variants
+    fn field_types_mut(&mut self) -> Vec<&mut RustType> {
+        match self {
+            Rust::Struct { fields, .. } => fields.iter_mut().map(|f| &mut f.name_type.1).collect(),
+            Rust::Enum(_) => Vec::default(),
+            Rust::DataEnum(c) => c.variants.iter_mut().map(|v| &mut v.name_type.1).collect(),
+            Rust::TupleStruct { r#type, .. } => vec![r#type],
         }
     }
 
@@ -336,6 +558,8 @@ impl Rust {
             r#type,
             tag: None,
             constants: Vec::default(),
+            components: Vec::default(),
+            borrowed: false,
         }
     }
 }
@@ -389,6 +613,7 @@ impl RustType {
             RustType::I32(_) => "i32",
             RustType::U64(_) => "u64",
             RustType::I64(_) => "i64",
+            RustType::F64 => "f64",
             RustType::String(..) => "&'static str",
             RustType::VecU8(_) => "&'static [u8]",
             RustType::BitVec(_) => "u64",
@@ -396,6 +621,9 @@ impl RustType {
                 return Cow::Owned(format!("&'static [{}]", inner.to_const_lit_string()))
             }
             RustType::Null => "Null",
+            RustType::ObjectIdentifier => "ObjectIdentifier",
+            RustType::RelativeOid => "RelativeOid",
+            RustType::Time(kind) => kind.rust_type_name(),
             RustType::Option(inner) => {
                 return Cow::Owned(format!("Option<{}>", inner.to_const_lit_string()))
             }
@@ -417,11 +645,15 @@ impl ToString for RustType {
             RustType::I32(_) => "i32",
             RustType::U64(_) => "u64",
             RustType::I64(_) => "i64",
+            RustType::F64 => "f64",
             RustType::String(..) => "String",
             RustType::VecU8(_) => "Vec<u8>",
             RustType::BitVec(_) => "BitVec",
             RustType::Vec(inner, _size, _ordering) => return format!("Vec<{}>", inner.to_string()),
             RustType::Null => "Null",
+            RustType::ObjectIdentifier => "ObjectIdentifier",
+            RustType::RelativeOid => "RelativeOid",
+            RustType::Time(kind) => kind.rust_type_name(),
             RustType::Option(inner) => return format!("Option<{}>", inner.to_string()),
             RustType::Default(inner, ..) => return inner.to_string(),
             RustType::Complex(name, _) => return name.clone(),
@@ -433,21 +665,24 @@ impl ToString for RustType {
 #[derive(Debug, Clone, PartialOrd, PartialEq)]
 pub struct Field {
     pub(crate) name_type: (String, RustType),
+    pub(crate) identifier: Option<String>,
     pub(crate) tag: Option<Tag>,
     pub(crate) constants: Vec<(String, String)>,
     pub(crate) ref_id: Option<String>,
     pub(crate) key: Option<usize>,
-
+    pub(crate) explicit: bool,
 }
 
 impl Field {
     pub fn from_name_type<T: ToString>(name: T, r#type: RustType) -> Self {
         Self {
             name_type: (name.to_string(), r#type),
+            identifier: None,
             tag: None,
             constants: Vec::default(),
             ref_id: None,
-            key:None,
+            key: None,
+            explicit: false,
         }
     }
 
@@ -459,6 +694,19 @@ impl Field {
         &self.name_type.0
     }
 
+    /// The ASN.1 identifier of this field
+    pub fn identifier(&self) -> &str {
+        self.identifier.as_deref().unwrap_or_else(|| self.name())
+    }
+
+    /// Sets the ASN.1 identifier, which is only kept if it differs from the name
+    pub fn with_identifier(mut self, identifier: &str) -> Self {
+        self.identifier = Some(identifier)
+            .filter(|identifier| *identifier != self.name())
+            .map(ToString::to_string);
+        self
+    }
+
     pub fn r#type(&self) -> &RustType {
         &self.name_type.1
     }
@@ -472,9 +720,24 @@ impl Field {
         self
     }
 
-    pub fn with_ref_id_and_key(mut self, ref_id: Option<String>,key: Option<usize>,) -> Self {
+    /// The name of the field whose value selects the type of this open type field
+    pub fn ref_id(&self) -> Option<&str> {
+        self.ref_id.as_deref()
+    }
+
+    pub fn with_ref_id_and_key(mut self, ref_id: Option<String>, key: Option<usize>) -> Self {
         self.ref_id = ref_id;
-        self.key=key;
+        self.key = key;
+        self
+    }
+
+    /// Whether the tag of this field is explicit (ITU-T X.680 | ISO/IEC 8824-1, 31.2.7)
+    pub fn is_explicit(&self) -> bool {
+        self.explicit
+    }
+
+    pub fn with_explicit(mut self, explicit: bool) -> Self {
+        self.explicit = explicit;
         self
     }
 }
@@ -496,18 +759,25 @@ impl TagProperty for Field {
 #[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
 pub struct Enumeration<T> {
     variants: Vec<T>,
+    /// The ASN.1 identifiers of the plain variants that differ from their names
+    identifiers: Vec<Option<String>>,
     tag: Option<Tag>,
     extended_after_index: Option<usize>,
     open_type: Option<bool>,
+    unknown_extensions: bool,
+    borrowed: bool,
 }
 
 impl<T> From<Vec<T>> for Enumeration<T> {
     fn from(variants: Vec<T>) -> Self {
         Enumeration {
             variants,
+            identifiers: Vec::default(),
             tag: None,
             extended_after_index: None,
             open_type: None,
+            unknown_extensions: false,
+            borrowed: false,
         }
     }
 }
@@ -547,6 +817,17 @@ impl<T> Enumeration<T> {
         self.open_type.is_some()
     }
 
+    /// Whether an additional variant carries the values unknown to the schema, which for open
+    /// types are the keys unknown to the object set
+    pub fn has_unknown_extensions(&self) -> bool {
+        self.unknown_extensions
+    }
+
+    /// Whether the variants borrow values from the data they are decoded from, see
+    /// [`Rust::with_borrowed`]
+    pub fn is_borrowed(&self) -> bool {
+        self.borrowed
+    }
 }
 
 impl<T> TagProperty for Enumeration<T> {
@@ -567,21 +848,50 @@ impl PlainEnum {
     pub fn from_names(names: impl Iterator<Item = impl ToString>) -> Self {
         Self::from(names.map(|n| n.to_string()).collect::<Vec<_>>())
     }
+
+    /// Sets the ASN.1 identifiers of the variants, which are only kept if they differ from the
+    /// names of the variants
+    pub fn with_identifiers(mut self, identifiers: impl Iterator<Item = impl ToString>) -> Self {
+        self.identifiers = self
+            .variants
+            .iter()
+            .zip(identifiers)
+            .map(|(name, identifier)| Some(identifier.to_string()).filter(|i| i != name))
+            .collect();
+        if self.identifiers.iter().all(Option::is_none) {
+            self.identifiers.clear();
+        }
+        self
+    }
+
+    /// The ASN.1 identifier of the variant at the given index
+    pub fn identifier(&self, index: usize) -> Option<&str> {
+        self.identifiers
+            .get(index)
+            .and_then(Option::as_deref)
+            .or_else(|| self.variants.get(index).map(String::as_str))
+    }
 }
 
 #[derive(Debug, Clone, PartialOrd, PartialEq)]
 pub struct DataVariant {
     name_type: (String, RustType),
+    identifier: Option<String>,
     tag: Option<Tag>,
     key: Option<usize>,
+    key_object_identifier: Option<Vec<u64>>,
+    explicit: bool,
 }
 
 impl DataVariant {
     pub fn from_name_type<T: ToString>(name: T, r#type: RustType) -> Self {
         Self {
             name_type: (name.to_string(), r#type),
+            identifier: None,
             tag: None,
-            key:None,
+            key: None,
+            key_object_identifier: None,
+            explicit: false,
         }
     }
 
@@ -593,16 +903,50 @@ impl DataVariant {
         &self.name_type.0
     }
 
+    /// The ASN.1 identifier of this variant
+    pub fn identifier(&self) -> &str {
+        self.identifier.as_deref().unwrap_or_else(|| self.name())
+    }
+
+    /// Sets the ASN.1 identifier, which is only kept if it differs from the name
+    pub fn with_identifier(mut self, identifier: &str) -> Self {
+        self.identifier = Some(identifier)
+            .filter(|identifier| *identifier != self.name())
+            .map(ToString::to_string);
+        self
+    }
+
     pub fn key(&self) -> Option<usize> {
         self.key
     }
-    pub fn set_key(&mut self,key: Option<usize>)  {
-        self.key=key
+    pub fn set_key(&mut self, key: Option<usize>) {
+        self.key = key
+    }
+
+    /// The arcs of the OBJECT IDENTIFIER that the key stands for, if the variants of the open
+    /// type are keyed by OBJECT IDENTIFIER values
+    pub fn key_object_identifier(&self) -> Option<&[u64]> {
+        self.key_object_identifier.as_deref()
+    }
+
+    pub fn with_key_object_identifier(mut self, arcs: Option<Vec<u64>>) -> Self {
+        self.key_object_identifier = arcs;
+        self
     }
 
     pub fn r#type(&self) -> &RustType {
         &self.name_type.1
     }
+
+    /// Whether the tag of this variant is explicit (ITU-T X.680 | ISO/IEC 8824-1, 31.2.7)
+    pub fn is_explicit(&self) -> bool {
+        self.explicit
+    }
+
+    pub fn with_explicit(mut self, explicit: bool) -> Self {
+        self.explicit = explicit;
+        self
+    }
 }
 
 impl TagProperty for DataVariant {
@@ -620,6 +964,65 @@ impl TagProperty for DataVariant {
 }
 
 impl Model<Rust> {
+    /// Lets all types that contain OCTET STRING or UTF8String values - directly or through
+    /// references of other types of this model - borrow these from the data they are decoded
+    /// from, see [`Rust::with_borrowed`]. References of these types are renamed to carry the
+    /// lifetime `'a`.
+    pub fn with_borrowed_types(mut self) -> Self {
+        let mut borrowed = HashSet::new();
+        loop {
+            let before = borrowed.len();
+            for Definition(name, rust) in &mut self.definitions {
+                if !borrowed.contains(name)
+                    && rust
+                        .field_types_mut()
+                        .into_iter()
+                        .any(|r#type| Self::borrows(r#type, &borrowed))
+                {
+                    borrowed.insert(name.clone());
+                }
+            }
+            if borrowed.len() == before {
+                break;
+            }
+        }
+
+        self.definitions = self
+            .definitions
+            .into_iter()
+            .map(|Definition(name, mut rust)| {
+                for r#type in rust.field_types_mut() {
+                    Self::rename_borrowed_references(r#type, &borrowed);
+                }
+                if borrowed.contains(&name) {
+                    rust = rust.with_borrowed();
+                }
+                Definition(name, rust)
+            })
+            .collect();
+        self
+    }
+
+    fn borrows(r#type: &RustType, borrowed: &HashSet<String>) -> bool {
+        match r#type {
+            RustType::Vec(inner, ..) | RustType::Option(inner) => Self::borrows(inner, borrowed),
+            RustType::Complex(name, _) => borrowed.contains(name),
+            other => other.is_borrowed(),
+        }
+    }
+
+    fn rename_borrowed_references(r#type: &mut RustType, borrowed: &HashSet<String>) {
+        match r#type {
+            RustType::Vec(inner, ..) | RustType::Option(inner) => {
+                Self::rename_borrowed_references(inner, borrowed)
+            }
+            RustType::Complex(name, _) if borrowed.contains(name.as_str()) => {
+                *name = format!("{}<'a>", name)
+            }
+            _ => {}
+        }
+    }
+
     pub fn convert_asn_to_rust(
         asn_model: &Model<Asn>,
         scope: &[&Model<Asn>],
@@ -645,10 +1048,24 @@ impl Model<Rust> {
                 .collect(),
             definitions: Vec::default(),
             value_references: Vec::with_capacity(asn_model.value_references.len()),
+            parameterized_definitions: Vec::default(),
+            object_classes: Vec::default(),
+            objects: Vec::default(),
+            object_sets: Vec::default(),
+            tag_default: asn_model.tag_default,
+            extensibility_implied: asn_model.extensibility_implied,
         };
+        let mut components = Vec::new();
         for Definition(name, asn) in &asn_model.definitions {
             let rust_name = ctxt.struct_or_enum_name(name);
             Self::definition_to_rust(&rust_name, &asn.r#type, asn.tag, &mut ctxt);
+            if let Some(constraints) = &asn.components {
+                components.push((rust_name, constraints));
+            }
+        }
+        // only after all definitions are converted, because these can refer to any of them
+        for (name, constraints) in components {
+            ctxt.add_component_constraints(&name, constraints);
         }
         for vref in &asn_model.value_references {
             if let Some(rust_type) = Self::map_asn_type_to_rust_type_flat(&vref.role.r#type) {
@@ -673,10 +1090,17 @@ impl Model<Rust> {
                 Self::asn_extensible_integer_to_rust(int)
             }
             Type::Integer(int) => Self::asn_fixed_integer_to_rust_type(int),
-            Type::String(size, charset) => RustType::String(size.clone(), *charset),
+            Type::Real => RustType::F64,
+            Type::String(size, charset, alphabet) => {
+                RustType::String(size.clone(), *charset, alphabet.clone())
+            }
             Type::OctetString(size) => RustType::VecU8(size.clone()),
             Type::BitString(bs) => RustType::BitVec(bs.size.clone()),
             Type::Null => RustType::Null,
+            Type::ObjectIdentifier => RustType::ObjectIdentifier,
+            Type::RelativeOid => RustType::RelativeOid,
+            // the textual value notation is not yet mapped to the rust types
+            Type::Time(_) => return None,
             Type::Optional(opt) => {
                 RustType::Option(Box::new(Self::map_asn_type_to_rust_type_flat(&**opt)?))
             }
@@ -685,14 +1109,19 @@ impl Model<Rust> {
                 default.clone(),
             ),
             Type::TypeReference(name, tag) => RustType::Complex(name.clone(), *tag),
-            Type::TypeReferenceId(_,_,_,_)
+            Type::ParameterizedReference(name, arguments) => RustType::Complex(
+                ParameterizedDefinition::instance_name(name, arguments),
+                None,
+            ),
+            Type::TypeReferenceId(_, _, _, _)
+            | Type::ObjectClassField(_)
             | Type::Sequence(_)
             | Type::SequenceOf(_, _)
             | Type::Set(_)
             | Type::SetOf(_, _)
             | Type::Enumerated(_)
             | Type::Choice(_)
-            | Type::OpenType(_)=> return None,
+            | Type::OpenType(_) => return None,
         })
     }
 
@@ -707,6 +1136,10 @@ impl Model<Rust> {
         match asn {
             AsnType::Boolean
             | AsnType::Null
+            | AsnType::Real
+            | AsnType::ObjectIdentifier
+            | AsnType::RelativeOid
+            | AsnType::Time(_)
             | AsnType::String(..)
             | AsnType::OctetString(_)
             | AsnType::BitString(_) => {
@@ -723,7 +1156,20 @@ impl Model<Rust> {
                     Rust::tuple_struct_from_type(rust_type).with_tag_opt(*tag),
                 ));
             }
-            AsnType::TypeReferenceId(_, tag,_id,_key) => {
+            AsnType::ObjectClassField(field) => {
+                unreachable!(
+                    "{}.{} is replaced while resolving",
+                    field.class, field.field
+                )
+            }
+            AsnType::ParameterizedReference(..) => {
+                let rust_type = Self::definition_type_to_rust_type(name, asn, None, ctxt);
+                ctxt.add_definition(Definition(
+                    name.to_string(),
+                    Rust::tuple_struct_from_type(rust_type),
+                ));
+            }
+            AsnType::TypeReferenceId(_, tag, _id, _key) => {
                 let rust_type = Self::definition_type_to_rust_type(name, asn, *tag, ctxt);
                 ctxt.add_definition(Definition(
                     name.to_string(),
@@ -740,6 +1186,8 @@ impl Model<Rust> {
                         r#type: rust_type,
                         tag,
                         constants,
+                        components: Vec::default(),
+                        borrowed: false,
                     },
                 ));
             }
@@ -768,6 +1216,7 @@ impl Model<Rust> {
             AsnType::Sequence(ComponentTypeList {
                 fields,
                 extension_after,
+                ..
             }) => {
                 let fields = Self::asn_fields_to_rust_fields(name, fields, *extension_after, ctxt);
                 ctxt.add_definition(Definition(
@@ -777,6 +1226,9 @@ impl Model<Rust> {
                         fields,
                         tag,
                         extension_after: *extension_after,
+                        unknown_extensions: false,
+                        components: Vec::default(),
+                        borrowed: false,
                     },
                 ));
             }
@@ -784,6 +1236,7 @@ impl Model<Rust> {
             AsnType::Set(ComponentTypeList {
                 fields,
                 extension_after,
+                ..
             }) => {
                 let fields = Self::asn_fields_to_rust_fields(name, fields, *extension_after, ctxt);
                 ctxt.add_definition(Definition(
@@ -793,6 +1246,9 @@ impl Model<Rust> {
                         fields,
                         tag,
                         extension_after: *extension_after,
+                        unknown_extensions: false,
+                        components: Vec::default(),
+                        borrowed: false,
                     },
                 ));
             }
@@ -821,15 +1277,20 @@ impl Model<Rust> {
             AsnType::Choice(choice) => {
                 let mut enumeration = Enumeration {
                     variants: Vec::with_capacity(choice.len()),
+                    identifiers: Vec::default(),
                     tag,
                     extended_after_index: choice.extension_after_index(),
-                    open_type:None,
+                    open_type: None,
+                    unknown_extensions: false,
+                    borrowed: false,
                 };
 
-                for ChoiceVariant {
+                for variant @ ChoiceVariant {
                     name: variant_name,
                     r#type,
                     tag,
+                    tag_mode,
+                    ..
                 } in choice.variants()
                 {
                     let rust_name = format!("{}{}", name, ctxt.struct_or_enum_name(variant_name));
@@ -837,7 +1298,10 @@ impl Model<Rust> {
                         Self::definition_type_to_rust_type(&rust_name, r#type, *tag, ctxt);
                     let rust_field_name = ctxt.variant_name(variant_name);
                     enumeration.variants.push(
-                        DataVariant::from_name_type(rust_field_name, rust_role).with_tag_opt(*tag),
+                        DataVariant::from_name_type(rust_field_name, rust_role)
+                            .with_identifier(variant.identifier())
+                            .with_tag_opt(*tag)
+                            .with_explicit(ctxt.resolver().is_explicit(*tag, *tag_mode)),
                     );
                 }
 
@@ -846,22 +1310,31 @@ impl Model<Rust> {
             AsnType::OpenType(open_type) => {
                 let mut enumeration = Enumeration {
                     variants: Vec::with_capacity(open_type.len()),
+                    identifiers: Vec::default(),
                     tag,
                     extended_after_index: open_type.extension_after_index(),
-                    open_type:Some(true)
+                    open_type: Some(true),
+                    // decoding continues for keys unknown to the object set
+                    unknown_extensions: true,
+                    borrowed: false,
                 };
 
-                for OpenTypeVariant {
+                for variant @ OpenTypeVariant {
                     name: variant_name,
                     r#type,
-                    tag, ..
-                } in open_type.variants(){
+                    tag,
+                    ..
+                } in open_type.variants()
+                {
                     let rust_name = format!("{}{}", name, ctxt.struct_or_enum_name(variant_name));
                     let rust_role =
                         Self::definition_type_to_rust_type(&rust_name, r#type, *tag, ctxt);
                     let rust_field_name = ctxt.variant_name(variant_name);
-                    let mut x=DataVariant::from_name_type(rust_field_name, rust_role).with_tag_opt(*tag);
-                    x.set_key(r#type.get_key());
+                    let mut x = DataVariant::from_name_type(rust_field_name, rust_role)
+                        .with_identifier(variant.identifier())
+                        .with_tag_opt(*tag)
+                        .with_key_object_identifier(variant.key_object_identifier.clone());
+                    x.set_key(variant.key.or_else(|| r#type.get_key()));
                     enumeration.variants.push(x);
                 }
                 ctxt.add_definition(Definition(name.into(), Rust::DataEnum(enumeration)));
@@ -869,14 +1342,19 @@ impl Model<Rust> {
             AsnType::Enumerated(enumerated) => {
                 let mut rust_enum = Enumeration {
                     variants: Vec::with_capacity(enumerated.len()),
+                    identifiers: Vec::default(),
                     tag,
                     extended_after_index: enumerated.extension_after_index(),
                     open_type: None,
+                    unknown_extensions: false,
+                    borrowed: false,
                 };
 
                 for variant in enumerated.variants() {
                     rust_enum.variants.push(ctxt.variant_name(variant.name()));
                 }
+                let rust_enum = rust_enum
+                    .with_identifiers(enumerated.variants().map(EnumeratedVariant::identifier));
 
                 ctxt.add_definition(Definition(name.into(), Rust::Enum(rust_enum)));
             }
@@ -910,8 +1388,18 @@ impl Model<Rust> {
             let constants = ctxt.to_rust_constants(&field.role.r#type);
             rust_fields.push(
                 RustField::from_name_type(rust_field_name, rust_role)
+                    .with_identifier(field.identifier())
                     .with_constants(constants)
-                    .with_tag_opt(tag).with_ref_id_and_key(field.role.r#type.get_ref_id(),field.role.r#type.get_key())
+                    .with_tag_opt(tag)
+                    .with_explicit(ctxt.resolver().is_explicit(tag, field.role.tag_mode))
+                    .with_ref_id_and_key(
+                        field
+                            .role
+                            .r#type
+                            .get_ref_id()
+                            .map(|id| ctxt.field_name(&id)),
+                        field.role.r#type.get_key(),
+                    ),
             );
         }
 
@@ -927,12 +1415,18 @@ impl Model<Rust> {
         match asn {
             AsnType::Boolean => RustType::Bool,
             AsnType::Null => RustType::Null,
+            AsnType::Real => RustType::F64,
+            AsnType::ObjectIdentifier => RustType::ObjectIdentifier,
+            AsnType::RelativeOid => RustType::RelativeOid,
+            AsnType::Time(kind) => RustType::Time(*kind),
             AsnType::Integer(int) if int.range.extensible() => {
                 Self::asn_extensible_integer_to_rust(int)
             }
             AsnType::Integer(int) => Self::asn_fixed_integer_to_rust_type(int),
 
-            AsnType::String(size, charset) => RustType::String(size.clone(), *charset),
+            AsnType::String(size, charset, alphabet) => {
+                RustType::String(size.clone(), *charset, alphabet.clone())
+            }
             AsnType::OctetString(size) => RustType::VecU8(size.clone()),
             AsnType::BitString(bitstring) => RustType::BitVec(bitstring.size.clone()),
             Type::Optional(inner) => {
@@ -976,7 +1470,7 @@ impl Model<Rust> {
             | ty @ AsnType::Set(_)
             | ty @ AsnType::Enumerated(_)
             | ty @ AsnType::Choice(_)
-            | ty @ AsnType::OpenType(_)=> {
+            | ty @ AsnType::OpenType(_) => {
                 let name = ctxt.struct_or_enum_name(name);
                 Self::definition_to_rust(&name, asn, tag, ctxt);
                 RustType::Complex(name, tag.or_else(|| ctxt.resolver().resolve_type_tag(ty)))
@@ -985,7 +1479,20 @@ impl Model<Rust> {
                 ctxt.struct_or_enum_name(name),
                 (*tag).or_else(|| ctxt.resolver().resolve_tag(name)),
             ),
-            AsnType::TypeReferenceId(name, tag,_id,_key) => RustType::Complex(
+            AsnType::ParameterizedReference(name, arguments) => {
+                let name = ParameterizedDefinition::instance_name(name, arguments);
+                RustType::Complex(
+                    ctxt.struct_or_enum_name(&name),
+                    ctxt.resolver().resolve_tag(&name),
+                )
+            }
+            AsnType::ObjectClassField(field) => {
+                unreachable!(
+                    "{}.{} is replaced while resolving",
+                    field.class, field.field
+                )
+            }
+            AsnType::TypeReferenceId(name, tag, _id, _key) => RustType::Complex(
                 ctxt.struct_or_enum_name(name),
                 (*tag).or_else(|| ctxt.resolver().resolve_tag(name)),
             ),
@@ -1068,6 +1575,10 @@ impl Context<'_> {
 
             Type::Boolean
             | Type::Null
+            | Type::Real
+            | Type::ObjectIdentifier
+            | Type::RelativeOid
+            | Type::Time(_)
             | Type::String(..)
             | Type::OctetString(_)
             | Type::Optional(_)
@@ -1080,7 +1591,9 @@ impl Context<'_> {
             | Type::Choice(_)
             | Type::OpenType(_)
             | Type::TypeReference(_, _)
-            | Type::TypeReferenceId(_, _,_,_) => Vec::default(),
+            | Type::ParameterizedReference(..)
+            | Type::ObjectClassField(_)
+            | Type::TypeReferenceId(_, _, _, _) => Vec::default(),
         }
     }
 
@@ -1128,6 +1641,113 @@ impl Context<'_> {
         self.target.push(def)
     }
 
+    /// Adds the constraints of a `WITH COMPONENTS` constraint to the struct `name`, or to the
+    /// tuple struct `name` that was added as the representation of a type reference. Value
+    /// constraints that do not suit the type of the field are ignored. If an `OPTIONAL` field
+    /// of the referenced struct is constrained to be `PRESENT`, the tuple struct is replaced
+    /// by a copy of the referenced struct, in which the field is not optional.
+    pub fn add_component_constraints(&mut self, name: &str, constraints: &InnerTypeConstraints) {
+        let index = match self.target.iter().rposition(|d| d.0 == name) {
+            Some(index) => index,
+            None => return,
+        };
+        let referenced = match &self.target[index].1 {
+            Rust::Struct { .. } => Some(self.target[index].clone()),
+            Rust::TupleStruct {
+                r#type: RustType::Complex(reference, _),
+                ..
+            } => self
+                .target
+                .iter()
+                .find(|d| d.0 == *reference && matches!(d.1, Rust::Struct { .. }))
+                .cloned(),
+            Rust::TupleStruct { .. } | Rust::Enum(_) | Rust::DataEnum(_) => None,
+        };
+        let fields = match &referenced {
+            Some(Definition(_, Rust::Struct { fields, .. })) => Some(&fields[..]),
+            _ => None,
+        };
+
+        let mut new_constraints = Vec::new();
+        for (identifier, value, presence) in constraints.components() {
+            let field = self.field_name(identifier);
+            let r#type = match fields {
+                Some(fields) => match fields.iter().find(|f| f.name() == field) {
+                    Some(f) => Some(f.r#type()),
+                    None => continue,
+                },
+                None => None,
+            };
+            let optional = r#type.map_or(true, |t| matches!(t, RustType::Option(_)));
+            let constraint = ComponentConstraint {
+                presence: presence
+                    .filter(|p| *p != PresenceConstraint::Optional)
+                    .filter(|_| optional),
+                value: value
+                    .and_then(|v| PermittedValue::from_constraint(v, r#type, &self.target[..])),
+                field,
+            };
+            if constraint.presence.is_some() || constraint.value.is_some() {
+                new_constraints.push(constraint);
+            }
+        }
+
+        let flatten =
+            fields.is_some() && new_constraints.iter().any(ComponentConstraint::is_present);
+        let definition = &mut self.target[index].1;
+        match (definition, referenced) {
+            (
+                definition @ Rust::TupleStruct { .. },
+                Some(Definition(
+                    _,
+                    Rust::Struct {
+                        mut fields,
+                        tag: referenced_tag,
+                        extension_after,
+                        ordering,
+                        unknown_extensions,
+                        ..
+                    },
+                )),
+            ) if flatten => {
+                Self::flatten_present_fields(&mut fields, &new_constraints);
+                *definition = Rust::Struct {
+                    ordering,
+                    fields,
+                    tag: definition.tag().or(referenced_tag),
+                    extension_after,
+                    unknown_extensions,
+                    components: new_constraints,
+                    borrowed: false,
+                };
+            }
+            (
+                Rust::Struct {
+                    fields, components, ..
+                },
+                _,
+            ) => {
+                Self::flatten_present_fields(fields, &new_constraints);
+                *components = new_constraints;
+            }
+            (Rust::TupleStruct { components, .. }, _) => *components = new_constraints,
+            (Rust::Enum(_), _) | (Rust::DataEnum(_), _) => {}
+        }
+    }
+
+    fn flatten_present_fields(fields: &mut [Field], constraints: &[ComponentConstraint]) {
+        for field in fields {
+            if constraints
+                .iter()
+                .any(|c| c.is_present() && c.field == field.name())
+            {
+                if let RustType::Option(inner) = &field.name_type.1 {
+                    field.name_type.1 = (**inner).clone();
+                }
+            }
+        }
+    }
+
     pub fn resolver(&self) -> &TagResolver<'_> {
         &self.resolver
     }
@@ -1259,6 +1879,28 @@ impl LiteralValue {
                             }
                         )
                     }
+                    LiteralValue::ObjectIdentifier(oid) => {
+                        write!(f, "&[")?;
+                        for component in oid.iter() {
+                            match component {
+                                ObjectIdentifierComponent::NumberForm(number)
+                                | ObjectIdentifierComponent::NameAndNumberForm(_, number) => {
+                                    write!(f, "{}, ", number)?
+                                }
+                                // only present if the literal was not resolved
+                                ObjectIdentifierComponent::NameForm(name) => write!(
+                                    f,
+                                    "{}, ",
+                                    if self.1 {
+                                        Cow::Owned(rust_constant_name(name))
+                                    } else {
+                                        Cow::Borrowed(name)
+                                    }
+                                )?,
+                            }
+                        }
+                        write!(f, "]")
+                    }
                 }
             }
         }
@@ -1273,7 +1915,7 @@ mod tests {
     use crate::gen::RustCodeGenerator;
     use crate::model::tag::tests::test_property;
     use crate::model::tests::*;
-    use crate::model::{Choice, Enumerated, EnumeratedVariant, Field, Tag, Type};
+    use crate::model::{Choice, Enumerated, EnumeratedVariant, Field, Tag, TagDefault, Type};
     use crate::parser::Tokenizer;
 
     #[test]
@@ -1379,14 +2021,12 @@ mod tests {
             Definition(
                 "WoahDecision".into(),
                 Rust::Enum(
-                    vec![
-                        "Abort".into(),
-                        "Return".into(),
-                        "Confirm".into(),
-                        "Mayday".into(),
-                        "TheCakeIsALie".into()
-                    ]
-                    .into()
+                    PlainEnum::from_names(
+                        ["Abort", "Return", "Confirm", "Mayday", "TheCakeIsALie"].iter()
+                    )
+                    .with_identifiers(
+                        ["ABORT", "RETURN", "CONFIRM", "MAYDAY", "THE_CAKE_IS_A_LIE"].iter()
+                    )
                 ),
             ),
             model_rust.definitions[0]
@@ -1454,7 +2094,8 @@ mod tests {
                             Size::Any,
                             EncodingOrdering::Keep
                         ),
-                    ),
+                    )
+                    .with_identifier("also-ones"),
                     RustField::from_name_type(
                         "nesteds",
                         RustType::Vec(
@@ -1525,7 +2166,10 @@ mod tests {
         assert_eq!(
             Definition(
                 "Neither".into(),
-                Rust::Enum(vec!["Abc".into(), "Def".into(),].into()),
+                Rust::Enum(
+                    PlainEnum::from_names(["Abc", "Def"].iter())
+                        .with_identifiers(["ABC", "DEF"].iter())
+                ),
             ),
             model_rust.definitions[2]
         );
@@ -1537,15 +2181,18 @@ mod tests {
                         DataVariant::from_name_type(
                             "This",
                             RustType::Complex("This".into(), Some(Tag::DEFAULT_SEQUENCE_OF))
-                        ),
+                        )
+                        .with_identifier("this"),
                         DataVariant::from_name_type(
                             "That",
                             RustType::Complex("That".into(), Some(Tag::DEFAULT_SEQUENCE_OF))
-                        ),
+                        )
+                        .with_identifier("that"),
                         DataVariant::from_name_type(
                             "Neither",
                             RustType::Complex("Neither".into(), Some(Tag::DEFAULT_ENUMERATED))
-                        ),
+                        )
+                        .with_identifier("neither"),
                     ]
                     .into()
                 )
@@ -1557,7 +2204,7 @@ mod tests {
                 "Woah".into(),
                 Rust::struct_from_fields(vec![RustField::from_name_type(
                     "decision",
-                    RustType::Complex("WoahDecision".into(), Some(Tag::DEFAULT_ENUMERATED)),
+                    RustType::Complex("WoahDecision".into(), Some(Tag::ContextSpecific(0))),
                 )])
             ),
             model_rust.definitions[4]
@@ -1587,7 +2234,8 @@ mod tests {
                             Size::Any,
                             EncodingOrdering::Keep
                         ),
-                    ),
+                    )
+                    .with_identifier("list-ones"),
                     RustField::from_name_type(
                         "optional_ones",
                         RustType::Option(Box::new(RustType::Vec(
@@ -1595,7 +2243,8 @@ mod tests {
                             Size::Any,
                             EncodingOrdering::Keep
                         ))),
-                    ),
+                    )
+                    .with_identifier("optional-ones"),
                 ]),
             ),
             model_rust.definitions[0]
@@ -1632,7 +2281,10 @@ mod tests {
         assert_eq!(
             Definition(
                 "SimpleEnumTest".into(),
-                Rust::Enum(vec!["Bernd".into(), "DasVerdammte".into(), "Brooot".into(),].into()),
+                Rust::Enum(
+                    PlainEnum::from_names(["Bernd", "DasVerdammte", "Brooot"].iter())
+                        .with_identifiers(["Bernd", "Das-Verdammte", "Brooot"].iter())
+                ),
             ),
             model_rust.definitions[0]
         );
@@ -1660,9 +2312,11 @@ mod tests {
                     vec![
                         DataVariant::from_name_type(
                             "BerndDasBrot",
-                            RustType::String(Size::Any, Charset::Utf8),
-                        ),
-                        DataVariant::from_name_type("NochSoEinBrot", RustType::VecU8(Size::Any)),
+                            RustType::String(Size::Any, Charset::Utf8, None),
+                        )
+                        .with_identifier("bernd-das-brot"),
+                        DataVariant::from_name_type("NochSoEinBrot", RustType::VecU8(Size::Any))
+                            .with_identifier("nochSoEinBrot"),
                     ]
                     .into()
                 ),
@@ -1706,11 +2360,12 @@ mod tests {
                         DataVariant::from_name_type(
                             "NormalList",
                             RustType::Vec(
-                                Box::new(RustType::String(Size::Any, Charset::Utf8)),
+                                Box::new(RustType::String(Size::Any, Charset::Utf8, None)),
                                 Size::Any,
                                 EncodingOrdering::Keep
                             ),
-                        ),
+                        )
+                        .with_identifier("normal-List"),
                         DataVariant::from_name_type(
                             "NestedList",
                             RustType::Vec(
@@ -1722,7 +2377,8 @@ mod tests {
                                 Size::Any,
                                 EncodingOrdering::Keep
                             ),
-                        ),
+                        )
+                        .with_identifier("NESTED-List"),
                     ]
                     .into()
                 ),
@@ -1748,7 +2404,7 @@ mod tests {
             Definition(
                 "TupleTest".into(),
                 Rust::tuple_struct_from_type(RustType::Vec(
-                    Box::new(RustType::String(Size::Any, Charset::Utf8)),
+                    Box::new(RustType::String(Size::Any, Charset::Utf8, None)),
                     Size::Any,
                     EncodingOrdering::Keep
                 )),
@@ -1781,7 +2437,7 @@ mod tests {
                 "NestedTupleTest".into(),
                 Rust::tuple_struct_from_type(RustType::Vec(
                     Box::new(RustType::Vec(
-                        Box::new(RustType::String(Size::Any, Charset::Utf8)),
+                        Box::new(RustType::String(Size::Any, Charset::Utf8, None)),
                         Size::Any,
                         EncodingOrdering::Keep
                     )),
@@ -1801,6 +2457,7 @@ mod tests {
             "OptionalStructListTest".into(),
             AsnType::sequence_from_fields(vec![Field {
                 name: "strings".into(),
+                identifier: None,
                 role: AsnType::SequenceOf(Box::new(AsnType::unconstrained_utf8string()), Size::Any)
                     .optional()
                     .untagged(),
@@ -1817,7 +2474,7 @@ mod tests {
                 Rust::struct_from_fields(vec![RustField::from_name_type(
                     "strings",
                     RustType::Option(Box::new(RustType::Vec(
-                        Box::new(RustType::String(Size::Any, Charset::Utf8)),
+                        Box::new(RustType::String(Size::Any, Charset::Utf8, None)),
                         Size::Any,
                         EncodingOrdering::Keep
                     ))),
@@ -1835,6 +2492,7 @@ mod tests {
             "StructListTest".into(),
             AsnType::sequence_from_fields(vec![Field {
                 name: "strings".into(),
+                identifier: None,
                 role: AsnType::SequenceOf(Box::new(AsnType::unconstrained_utf8string()), Size::Any)
                     .untagged(),
             }])
@@ -1850,7 +2508,7 @@ mod tests {
                 Rust::struct_from_fields(vec![RustField::from_name_type(
                     "strings",
                     RustType::Vec(
-                        Box::new(RustType::String(Size::Any, Charset::Utf8)),
+                        Box::new(RustType::String(Size::Any, Charset::Utf8, None)),
                         Size::Any,
                         EncodingOrdering::Keep
                     ),
@@ -1868,6 +2526,7 @@ mod tests {
             "NestedStructListTest".into(),
             AsnType::sequence_from_fields(vec![Field {
                 name: "strings".into(),
+                identifier: None,
                 role: AsnType::SequenceOf(
                     Box::new(AsnType::SequenceOf(
                         Box::new(AsnType::unconstrained_utf8string()),
@@ -1890,7 +2549,7 @@ mod tests {
                     "strings",
                     RustType::Vec(
                         Box::new(RustType::Vec(
-                            Box::new(RustType::String(Size::Any, Charset::Utf8)),
+                            Box::new(RustType::String(Size::Any, Charset::Utf8, None)),
                             Size::Any,
                             EncodingOrdering::Keep
                         )),
@@ -1927,6 +2586,7 @@ mod tests {
                 "Extensible".into(),
                 Rust::Enum(
                     PlainEnum::from_names(["Abc", "Def", "Ghi"].iter())
+                        .with_identifiers(["abc", "def", "ghi"].iter())
                         .with_extension_after(Some(2))
                 ),
             )],
@@ -1946,7 +2606,9 @@ mod tests {
                     ChoiceVariant::name_type("def", Type::unconstrained_integer()),
                     ChoiceVariant {
                         name: "ghi".to_string(),
+                        identifier: None,
                         tag: Some(Tag::Universal(4)),
+                        tag_mode: None,
                         r#type: Type::Boolean,
                     },
                 ])
@@ -1963,12 +2625,15 @@ mod tests {
                 "Extensible".into(),
                 Rust::DataEnum(
                     DataEnum::from(vec![
-                        DataVariant::from_name_type("Abc".to_string(), RustType::VecU8(Size::Any)),
+                        DataVariant::from_name_type("Abc".to_string(), RustType::VecU8(Size::Any))
+                            .with_identifier("abc"),
                         DataVariant::from_name_type(
                             "Def".to_string(),
                             RustType::U64(Range::none()),
-                        ),
+                        )
+                        .with_identifier("def"),
                         DataVariant::from_name_type("Ghi".to_string(), RustType::Bool)
+                            .with_identifier("ghi")
                             .with_tag(Tag::Universal(4)),
                     ])
                     .with_extension_after(Some(2))
@@ -1985,6 +2650,9 @@ mod tests {
             fields: Vec::default(),
             tag: None,
             extension_after: None,
+            unknown_extensions: false,
+            components: Vec::default(),
+            borrowed: false,
         });
     }
 
@@ -2000,7 +2668,7 @@ mod tests {
         test_property(Rust::DataEnum(DataEnum::from(vec![
             DataVariant::from_name_type(
                 "SomeName".to_string(),
-                RustType::String(Size::Any, Charset::Visible),
+                RustType::String(Size::Any, Charset::Visible, None),
             ),
         ])));
     }
@@ -2011,6 +2679,8 @@ mod tests {
             r#type: RustType::VecU8(Size::Any),
             tag: None,
             constants: Vec::default(),
+            components: Vec::default(),
+            borrowed: false,
         });
     }
 
@@ -2058,6 +2728,12 @@ mod tests {
                     value: LiteralValue::Boolean(true),
                 },
             ],
+            parameterized_definitions: Vec::default(),
+            object_classes: Vec::default(),
+            objects: Vec::default(),
+            object_sets: Vec::default(),
+            tag_default: TagDefault::Automatic,
+            extensibility_implied: false,
         };
 
         assert_starts_with_lines(
@@ -2091,20 +2767,29 @@ mod tests {
                         fields: vec![
                             Field {
                                 name: "some-internal".to_string(),
+                                identifier: None,
                                 role: Type::Boolean.untagged(),
                             },
                             Field {
                                 name: "id".to_string(),
+                                identifier: None,
                                 role: Type::TypeReference("Some-Name-WithID".to_string(), None)
                                     .untagged(),
                             },
                         ],
                         extension_after: None,
+                        components_of: Vec::default(),
                     })
                     .untagged(),
                 ),
             ],
             value_references: vec![],
+            parameterized_definitions: Vec::default(),
+            object_classes: Vec::default(),
+            objects: Vec::default(),
+            object_sets: Vec::default(),
+            tag_default: TagDefault::Automatic,
+            extensibility_implied: false,
         };
         assert_eq!(
             vec![
@@ -2113,7 +2798,9 @@ mod tests {
                     Rust::TupleStruct {
                         r#type: RustType::Bool,
                         tag: None,
-                        constants: vec![]
+                        constants: vec![],
+                        components: vec![],
+                        borrowed: false,
                     }
                 ),
                 Definition(
@@ -2124,7 +2811,8 @@ mod tests {
                             crate::model::rust::Field::from_name_type(
                                 "some_internal".to_string(),
                                 RustType::Bool
-                            ),
+                            )
+                            .with_identifier("some-internal"),
                             crate::model::rust::Field::from_name_type(
                                 "id".to_string(),
                                 RustType::Complex(
@@ -2134,11 +2822,30 @@ mod tests {
                             ),
                         ],
                         tag: None,
-                        extension_after: None
+                        extension_after: None,
+                        unknown_extensions: false,
+                        components: Vec::default(),
+                        borrowed: false,
                     }
                 ),
             ],
             asn.to_rust().definitions
         );
     }
+
+    #[test]
+    fn test_unresolved_object_identifier_literal_prints_the_name() {
+        let literal = LiteralValue::ObjectIdentifier(crate::model::ObjectIdentifier(vec![
+            ObjectIdentifierComponent::NameForm("some-oid".to_string()),
+            ObjectIdentifierComponent::NumberForm(3),
+        ]));
+        assert_eq!(
+            "&[some-oid, 3, ]",
+            literal.as_rust_const_literal(false).to_string()
+        );
+        assert_eq!(
+            "&[SOME_OID, 3, ]",
+            literal.as_rust_const_literal(true).to_string()
+        );
+    }
 }
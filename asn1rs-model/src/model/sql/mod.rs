@@ -5,7 +5,7 @@ use crate::model::Rust;
 use crate::model::RustType;
 use crate::model::{Charset, Model};
 use crate::model::{Definition, Size};
-use crate::model::{Range, Target};
+use crate::model::{Range, Target, TimeKind};
 use std::collections::HashMap;
 use std::convert::Infallible;
 
@@ -24,6 +24,7 @@ pub enum SqlType {
     Integer,  // 4byte
     BigInt,   // 8byte
     Serial,   // 4byte
+    Double,   // 8byte
     Boolean,
     Text,
     Array(Box<SqlType>),
@@ -31,6 +32,9 @@ pub enum SqlType {
     ByteArray,
     NullByteArray,
     BitsReprByByteArrayAndBitsLen,
+    ObjectIdentifierReprByText,
+    RelativeOidReprByText,
+    TimeReprByText(TimeKind),
     References(String, String, Option<Action>, Option<Action>),
 }
 
@@ -76,8 +80,9 @@ impl SqlType {
             SqlType::Integer => RustType::I32(Range::inclusive(0, i32::MAX)),
             SqlType::BigInt => RustType::I64(Range::inclusive(0, i64::MAX)),
             SqlType::Serial => RustType::I32(Range::inclusive(0, i32::MAX)),
+            SqlType::Double => RustType::F64,
             SqlType::Boolean => RustType::Bool,
-            SqlType::Text => RustType::String(Size::Any, Charset::Utf8),
+            SqlType::Text => RustType::String(Size::Any, Charset::Utf8, None),
             SqlType::Array(inner) => {
                 RustType::Vec(Box::new(inner.to_rust()), Size::Any, EncodingOrdering::Keep)
             }
@@ -85,6 +90,9 @@ impl SqlType {
             SqlType::ByteArray => RustType::VecU8(Size::Any),
             SqlType::NullByteArray => return RustType::Null,
             SqlType::BitsReprByByteArrayAndBitsLen => RustType::BitVec(Size::Any),
+            SqlType::ObjectIdentifierReprByText => RustType::ObjectIdentifier,
+            SqlType::RelativeOidReprByText => RustType::RelativeOid,
+            SqlType::TimeReprByText(kind) => RustType::Time(*kind),
             SqlType::References(name, _, _, _) => RustType::Complex(name.clone(), None),
         }))
     }
@@ -97,6 +105,7 @@ impl ToString for SqlType {
             SqlType::Integer => "INTEGER".into(),
             SqlType::BigInt => "BIGINT".into(),
             SqlType::Serial => "SERIAL".into(),
+            SqlType::Double => "DOUBLE PRECISION".into(),
             SqlType::Boolean => "BOOLEAN".into(),
             SqlType::Text => "TEXT".into(),
             SqlType::Array(inner) => format!("{}[]", inner.to_string()),
@@ -104,6 +113,9 @@ impl ToString for SqlType {
             SqlType::ByteArray
             | SqlType::NullByteArray
             | SqlType::BitsReprByByteArrayAndBitsLen => "BYTEA".into(),
+            SqlType::ObjectIdentifierReprByText
+            | SqlType::RelativeOidReprByText
+            | SqlType::TimeReprByText(_) => "TEXT".into(),
             SqlType::References(table, column, on_delete, on_update) => format!(
                 "INTEGER REFERENCES {}({}){}{}",
                 Model::<Sql>::sql_definition_name(table),
@@ -159,6 +171,12 @@ impl Model<Sql> {
             imports: Default::default(), // ignored in SQL
             definitions: Vec::with_capacity(rust_model.definitions.len()),
             value_references: Vec::default(),
+            parameterized_definitions: Vec::default(),
+            object_classes: Vec::default(),
+            objects: Vec::default(),
+            object_sets: Vec::default(),
+            tag_default: rust_model.tag_default,
+            extensibility_implied: rust_model.extensibility_implied,
         };
         for Definition(name, rust) in &rust_model.definitions {
             let name = Self::sql_definition_name(name);
@@ -221,7 +239,10 @@ impl Model<Sql> {
                 fields,
                 tag: _,
                 extension_after: _,
+                unknown_extensions: _,
                 ordering: _,
+                components: _,
+                borrowed: _,
             } => Self::rust_struct_to_sql_table(name, fields, definitions),
             Rust::Enum(rust_enum) => Self::rust_enum_to_sql_enum(name, rust_enum, definitions),
             Rust::DataEnum(enumeration) => {
@@ -508,6 +529,9 @@ impl Model<Sql> {
             RustType::VecU8(_) => true,
             RustType::BitVec(_) => true,
             RustType::Null => true,
+            RustType::ObjectIdentifier => true,
+            RustType::RelativeOid => true,
+            RustType::Time(_) => true,
             r => r.is_primitive(),
         }
     }
@@ -547,9 +571,13 @@ impl ToSql for RustType {
             RustType::U32(Range(_, upper, _)) if *upper <= i32::MAX as u32 => SqlType::Integer,
             RustType::U16(_) | RustType::I32(_) => SqlType::Integer,
             RustType::U32(_) | RustType::U64(_) | RustType::I64(_) => SqlType::BigInt,
-            RustType::String(_size, _charset) => SqlType::Text,
+            RustType::F64 => SqlType::Double,
+            RustType::String(..) => SqlType::Text,
             RustType::VecU8(_) => SqlType::ByteArray,
             RustType::BitVec(_) => SqlType::BitsReprByByteArrayAndBitsLen,
+            RustType::ObjectIdentifier => SqlType::ObjectIdentifierReprByText,
+            RustType::RelativeOid => SqlType::RelativeOidReprByText,
+            RustType::Time(kind) => SqlType::TimeReprByText(*kind),
             RustType::Vec(inner, _size, _ordering) => SqlType::Array(inner.to_sql().into()),
             RustType::Option(inner) => return inner.to_sql().nullable(),
             RustType::Default(inner, ..) => return inner.to_sql(),
@@ -605,7 +633,7 @@ mod tests {
             definitions: vec![Definition(
                 "Person".into(),
                 Rust::struct_from_fields(vec![
-                    Field::from_name_type("name", RustType::String(Size::Any, Charset::Utf8)),
+                    Field::from_name_type("name", RustType::String(Size::Any, Charset::Utf8, None)),
                     Field::from_name_type("birth", RustType::Complex("City".into(), None)),
                 ]),
             )],
@@ -678,7 +706,7 @@ mod tests {
                     vec![
                         DataVariant::from_name_type(
                             "DeadSince",
-                            RustType::String(Size::Any, Charset::Utf8),
+                            RustType::String(Size::Any, Charset::Utf8, None),
                         ),
                         DataVariant::from_name_type(
                             "Alive",
@@ -786,7 +814,7 @@ mod tests {
                     Field::from_name_type(
                         "list_of_primitive",
                         RustType::Vec(
-                            Box::new(RustType::String(Size::Any, Charset::Utf8)),
+                            Box::new(RustType::String(Size::Any, Charset::Utf8, None)),
                             Size::Any,
                             EncodingOrdering::Keep,
                         ),
@@ -922,7 +950,7 @@ mod tests {
             definitions: vec![
                 Definition(
                     "Whatever".into(),
-                    Rust::tuple_struct_from_type(RustType::String(Size::Any, Charset::Utf8)),
+                    Rust::tuple_struct_from_type(RustType::String(Size::Any, Charset::Utf8, None)),
                 ),
                 Definition(
                     "Whatelse".into(),
@@ -1084,7 +1112,7 @@ mod tests {
                 "City".into(),
                 Rust::struct_from_fields(vec![Field::from_name_type(
                     "id",
-                    RustType::String(Size::Any, Charset::Utf8),
+                    RustType::String(Size::Any, Charset::Utf8, None),
                 )]),
             )],
             ..Default::default()
@@ -1118,6 +1146,7 @@ mod tests {
     #[test]
     fn test_rust_to_sql_to_rust() {
         assert_eq!(RustType::Bool.to_sql().to_rust(), RustType::Bool);
+        assert_eq!(RustType::F64.to_sql().to_rust(), RustType::F64);
         assert_eq!(
             RustType::I8(Range::inclusive(0, i8::MAX))
                 .to_sql()
@@ -1184,10 +1213,10 @@ mod tests {
         );
 
         assert_eq!(
-            RustType::String(Size::Any, Charset::Utf8)
+            RustType::String(Size::Any, Charset::Utf8, None)
                 .to_sql()
                 .to_rust(),
-            RustType::String(Size::Any, Charset::Utf8),
+            RustType::String(Size::Any, Charset::Utf8, None),
         );
         assert_eq!(
             RustType::VecU8(Size::Any).to_sql().to_rust(),
@@ -1195,14 +1224,14 @@ mod tests {
         );
         assert_eq!(
             RustType::Vec(
-                Box::new(RustType::String(Size::Any, Charset::Utf8)),
+                Box::new(RustType::String(Size::Any, Charset::Utf8, None)),
                 Size::Any,
                 EncodingOrdering::Keep
             )
             .to_sql()
             .to_rust(),
             RustType::Vec(
-                Box::new(RustType::String(Size::Any, Charset::Utf8)),
+                Box::new(RustType::String(Size::Any, Charset::Utf8, None)),
                 Size::Any,
                 EncodingOrdering::Keep
             ),
@@ -1246,6 +1275,7 @@ mod tests {
         assert_eq!("BIGINT", &SqlType::BigInt.to_string());
         assert_eq!("SERIAL", &SqlType::Serial.to_string());
         assert_eq!("BOOLEAN", &SqlType::Boolean.to_string());
+        assert_eq!("DOUBLE PRECISION", &SqlType::Double.to_string());
         assert_eq!("TEXT", &SqlType::Text.to_string());
         assert_eq!(
             "SMALLINT[]",
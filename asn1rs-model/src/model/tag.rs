@@ -1,6 +1,10 @@
+#[cfg(feature = "std")]
 use crate::model::{Error, PeekableTokens};
+#[cfg(feature = "std")]
 use crate::parser::Token;
+#[cfg(feature = "std")]
 use std::convert::TryFrom;
+#[cfg(feature = "std")]
 use std::iter::Peekable;
 
 ///ITU-T X.680 | ISO/IEC 8824-1, chapter 8
@@ -44,12 +48,25 @@ impl Tag {
     pub const DEFAULT_BIT_STRING: Tag = Tag::Universal(3);
     pub const DEFAULT_OCTET_STRING: Tag = Tag::Universal(4);
     pub const DEFAULT_NULL: Tag = Tag::Universal(5);
+    pub const DEFAULT_OBJECT_IDENTIFIER: Tag = Tag::Universal(6);
+    pub const DEFAULT_REAL: Tag = Tag::Universal(9);
     pub const DEFAULT_ENUMERATED: Tag = Tag::Universal(10);
     pub const DEFAULT_UTF8_STRING: Tag = Tag::Universal(12);
+    pub const DEFAULT_RELATIVE_OID: Tag = Tag::Universal(13);
     pub const DEFAULT_SEQUENCE: Tag = Tag::Universal(16);
     pub const DEFAULT_SEQUENCE_OF: Tag = Tag::Universal(16);
     pub const DEFAULT_SET: Tag = Tag::Universal(17);
     pub const DEFAULT_SET_OF: Tag = Tag::Universal(17);
+    /// ITU-T Rec. X.680, 47
+    pub const DEFAULT_UTC_TIME: Tag = Tag::Universal(23);
+    /// ITU-T Rec. X.680, 46
+    pub const DEFAULT_GENERALIZED_TIME: Tag = Tag::Universal(24);
+    /// ITU-T Rec. X.680, 38.4.1
+    pub const DEFAULT_DATE: Tag = Tag::Universal(31);
+    /// ITU-T Rec. X.680, 38.4.2
+    pub const DEFAULT_TIME_OF_DAY: Tag = Tag::Universal(32);
+    /// ITU-T Rec. X.680, 38.4.4
+    pub const DEFAULT_DURATION: Tag = Tag::Universal(34);
 
     /// ITU-T Rec. X.680, 41
     pub const DEFAULT_NUMERIC_STRING: Tag = Tag::Universal(18);
@@ -73,6 +90,7 @@ impl Tag {
     pub const DEFAULT_BMP_STRING: Tag = Tag::Universal(30);
 }
 
+#[cfg(feature = "std")]
 impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for Tag {
     type Error = Error;
 
@@ -101,6 +119,43 @@ impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for Tag {
     }
 }
 
+/// ITU-T X.680 | ISO/IEC 8824-1, 13.1, the tagging environment of a module
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TagDefault {
+    Explicit,
+    Implicit,
+    Automatic,
+}
+
+impl TagDefault {
+    /// ITU-T X.680 | ISO/IEC 8824-1, 13.3, an empty `TagDefault` means `EXPLICIT TAGS`
+    pub fn try_from_text(text: &str) -> Option<Self> {
+        Some(match text.to_ascii_uppercase().as_str() {
+            "EXPLICIT" => TagDefault::Explicit,
+            "IMPLICIT" => TagDefault::Implicit,
+            "AUTOMATIC" => TagDefault::Automatic,
+            _ => return None,
+        })
+    }
+}
+
+/// ITU-T X.680 | ISO/IEC 8824-1, 31.1, the keyword after the tag of a tagged type
+#[derive(Debug, Clone, Copy, PartialOrd, PartialEq, Eq, Hash)]
+pub enum TagMode {
+    Explicit,
+    Implicit,
+}
+
+impl TagMode {
+    pub fn try_from_text(text: &str) -> Option<Self> {
+        match text.to_ascii_uppercase().as_str() {
+            "EXPLICIT" => Some(TagMode::Explicit),
+            "IMPLICIT" => Some(TagMode::Implicit),
+            _ => None,
+        }
+    }
+}
+
 pub trait TagProperty {
     fn tag(&self) -> Option<Tag>;
 
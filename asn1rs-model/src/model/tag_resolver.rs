@@ -1,5 +1,6 @@
 use crate::model::charset::Charset;
-use crate::model::{Asn, Definition, Model, Tag, TagProperty, Type};
+use crate::model::{Asn, Definition, Model, ParameterizedDefinition, Tag, TagProperty, Type};
+use crate::model::{TagDefault, TagMode};
 
 pub struct TagResolver<'a> {
     model: &'a Model<Asn>,
@@ -12,7 +13,15 @@ impl TagResolver<'_> {
     }
 
     pub fn resolve_default(ty: &Type) -> Option<Tag> {
-        let model = Model::<Asn>::default();
+        Self::resolve_default_in(ty, TagDefault::Automatic)
+    }
+
+    /// Like [`TagResolver::resolve_default`], but within the given tagging environment
+    pub fn resolve_default_in(ty: &Type, tag_default: TagDefault) -> Option<Tag> {
+        let model = Model::<Asn> {
+            tag_default,
+            ..Default::default()
+        };
         TagResolver {
             model: &model,
             scope: &[],
@@ -46,32 +55,63 @@ impl TagResolver<'_> {
     /// ITU-T X.680 | ISO/IEC 8824-1, 8.6
     /// ITU-T X.680 | ISO/IEC 8824-1, 41, table 8
     pub fn resolve_no_default(&self, ty: &Type) -> Option<Tag> {
-        let default = Self::resolve_default(ty);
+        let default = Self::resolve_default_in(ty, self.model.tag_default);
         let resolved = self.resolve_type_tag(ty);
         resolved.filter(|r| default.ne(&Some(*r)))
     }
 
+    /// ITU-T X.680 | ISO/IEC 8824-1, 25.3 and 29.2, the components of a SEQUENCE, SET or
+    /// CHOICE are tagged automatically in an `AUTOMATIC TAGS` environment, unless any of them
+    /// has a tag already. The automatic tags are the context specific tags in textual order.
+    pub fn is_automatically_tagged(&self, mut tags: impl Iterator<Item = Option<Tag>>) -> bool {
+        self.model.tag_default == TagDefault::Automatic && tags.all(|tag| tag.is_none())
+    }
+
+    /// ITU-T X.680 | ISO/IEC 8824-1, 31.2.7, whether a tag is explicit. Without an `EXPLICIT`
+    /// or `IMPLICIT` keyword, this is determined by the tagging environment of the module.
+    pub fn is_explicit(&self, tag: Option<Tag>, tag_mode: Option<TagMode>) -> bool {
+        tag.is_some()
+            && match tag_mode {
+                Some(mode) => mode == TagMode::Explicit,
+                None => self.model.tag_default == TagDefault::Explicit,
+            }
+    }
+
     /// ITU-T X.680 | ISO/IEC 8824-1, 8.6
     /// ITU-T X.680 | ISO/IEC 8824-1, 41, table 8
     pub fn resolve_type_tag(&self, ty: &Type) -> Option<Tag> {
         match ty {
             Type::Boolean => Some(Tag::DEFAULT_BOOLEAN),
             Type::Integer(_) => Some(Tag::DEFAULT_INTEGER),
+            Type::Real => Some(Tag::DEFAULT_REAL),
             Type::BitString(_) => Some(Tag::DEFAULT_BIT_STRING),
             Type::OctetString(_) => Some(Tag::DEFAULT_OCTET_STRING),
             Type::Enumerated(_) => Some(Tag::DEFAULT_ENUMERATED),
-            Type::String(_, Charset::Numeric) => Some(Tag::DEFAULT_NUMERIC_STRING),
-            Type::String(_, Charset::Printable) => Some(Tag::DEFAULT_PRINTABLE_STRING),
-            Type::String(_, Charset::Visible) => Some(Tag::DEFAULT_VISIBLE_STRING),
-            Type::String(_, Charset::Utf8) => Some(Tag::DEFAULT_UTF8_STRING),
-            Type::String(_, Charset::Ia5) => Some(Tag::DEFAULT_IA5_STRING),
+            Type::String(_, Charset::Numeric, _) => Some(Tag::DEFAULT_NUMERIC_STRING),
+            Type::String(_, Charset::Printable, _) => Some(Tag::DEFAULT_PRINTABLE_STRING),
+            Type::String(_, Charset::Visible, _) => Some(Tag::DEFAULT_VISIBLE_STRING),
+            Type::String(_, Charset::Utf8, _) => Some(Tag::DEFAULT_UTF8_STRING),
+            Type::String(_, Charset::Ia5, _) => Some(Tag::DEFAULT_IA5_STRING),
+            Type::String(_, Charset::Teletex, _) => Some(Tag::DEFAULT_TELETEXT_STRING),
+            Type::String(_, Charset::Graphic, _) => Some(Tag::DEFAULT_GRAPHIC_STRING),
+            Type::String(_, Charset::General, _) => Some(Tag::DEFAULT_GENERAL_STRING),
+            Type::String(_, Charset::Universal, _) => Some(Tag::DEFAULT_UNIVERSAL_STRING),
+            Type::String(_, Charset::Bmp, _) => Some(Tag::DEFAULT_BMP_STRING),
             Type::Null => Some(Tag::DEFAULT_NULL),
+            Type::ObjectIdentifier => Some(Tag::DEFAULT_OBJECT_IDENTIFIER),
+            Type::RelativeOid => Some(Tag::DEFAULT_RELATIVE_OID),
+            Type::Time(kind) => Some(kind.default_tag()),
             Type::Optional(inner) => self.resolve_type_tag(&**inner),
             Type::Default(inner, ..) => self.resolve_type_tag(&**inner),
             Type::Sequence(_) => Some(Tag::DEFAULT_SEQUENCE),
             Type::SequenceOf(_, _) => Some(Tag::DEFAULT_SEQUENCE_OF),
             Type::Set(_) => Some(Tag::DEFAULT_SET),
             Type::SetOf(_, _) => Some(Tag::DEFAULT_SET_OF),
+            Type::Choice(choice)
+                if self.is_automatically_tagged(choice.variants().map(|v| v.tag())) =>
+            {
+                Some(Tag::ContextSpecific(0))
+            }
             Type::Choice(choice) => {
                 let mut tags = choice
                     .variants()
@@ -113,7 +153,11 @@ impl TagResolver<'_> {
                 }
                 tag
             }
-            Type::TypeReferenceId(inner, tag,_id,_key) => {
+            Type::ParameterizedReference(name, arguments) => {
+                self.resolve_tag(&ParameterizedDefinition::instance_name(name, arguments))
+            }
+            Type::ObjectClassField(_) => None,
+            Type::TypeReferenceId(inner, tag, _id, _key) => {
                 let tag = (*tag).or_else(|| self.resolve_tag(inner.as_str()));
                 if cfg!(feature = "debug-proc-macro") {
                     println!("resolved :: {}::Tag = {:?}", inner, tag);
@@ -0,0 +1,51 @@
+use crate::model::Tag;
+
+/// The time types of ITU-T X.680 | ISO/IEC 8824-1, which are all represented by a subset of
+/// the ISO 8601 notation
+#[derive(Debug, Clone, Copy, PartialOrd, PartialEq, Eq)]
+pub enum TimeKind {
+    /// ITU-T X.680 | ISO/IEC 8824-1, 46
+    GeneralizedTime,
+    /// ITU-T X.680 | ISO/IEC 8824-1, 47
+    UtcTime,
+    /// ITU-T X.680 | ISO/IEC 8824-1, 38.4.1
+    Date,
+    /// ITU-T X.680 | ISO/IEC 8824-1, 38.4.2
+    TimeOfDay,
+    /// ITU-T X.680 | ISO/IEC 8824-1, 38.4.4
+    Duration,
+}
+
+impl TimeKind {
+    pub fn default_tag(self) -> Tag {
+        match self {
+            TimeKind::GeneralizedTime => Tag::DEFAULT_GENERALIZED_TIME,
+            TimeKind::UtcTime => Tag::DEFAULT_UTC_TIME,
+            TimeKind::Date => Tag::DEFAULT_DATE,
+            TimeKind::TimeOfDay => Tag::DEFAULT_TIME_OF_DAY,
+            TimeKind::Duration => Tag::DEFAULT_DURATION,
+        }
+    }
+
+    /// The name of the value type in `asn1rs::syn::time`
+    pub fn rust_type_name(self) -> &'static str {
+        match self {
+            TimeKind::GeneralizedTime => "GeneralizedTime",
+            TimeKind::UtcTime => "UtcTime",
+            TimeKind::Date => "Date",
+            TimeKind::TimeOfDay => "TimeOfDay",
+            TimeKind::Duration => "Duration",
+        }
+    }
+
+    /// The name of the type in the `#[asn(...)]` attribute
+    pub fn attribute_name(self) -> &'static str {
+        match self {
+            TimeKind::GeneralizedTime => "generalized_time",
+            TimeKind::UtcTime => "utc_time",
+            TimeKind::Date => "date",
+            TimeKind::TimeOfDay => "time_of_day",
+            TimeKind::Duration => "duration",
+        }
+    }
+}
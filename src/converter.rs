@@ -7,7 +7,7 @@ use crate::gen::Generator;
 use crate::model::lor::Error as ResolveError;
 use crate::model::protobuf::ToProtobufModel;
 use crate::model::sql::ToSqlModel;
-use crate::model::Model;
+use crate::model::{Asn, Model};
 use crate::model::{Error as ModelError, MultiModuleResolver};
 use crate::parser::Tokenizer;
 use std::collections::HashMap;
@@ -68,6 +68,12 @@ impl Converter {
         Ok(())
     }
 
+    /// The loaded modules with all references resolved, which the
+    /// [`crate::dynamic::Interpreter`] reads and writes values of without generating code
+    pub fn to_models(&self) -> Result<Vec<Model<Asn>>, Error> {
+        Ok(self.models.try_resolve_all()?)
+    }
+
     pub fn to_rust<D: AsRef<Path>, A: Fn(&mut RustGenerator)>(
         &self,
         directory: D,
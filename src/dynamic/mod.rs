@@ -0,0 +1,178 @@
+//! Encoding and decoding without generated types, driven by a schema that is only loaded at
+//! runtime. The [`Interpreter`] walks the resolved [`Model`]s and reads and writes the values
+//! as [`DynamicValue`] trees.
+//!
+//! ```text
+//! crate::dynamic                  The value tree and the interpreter of the schema
+//!      ::dynamic::uper            UNALIGNED PER impls of the interpreter
+//! ```
+
+mod uper;
+
+use crate::io::per::err::{Error, ErrorKind};
+use crate::model::lor::Resolved;
+use crate::model::{Asn, Definition, LiteralValue, Model, ObjectIdentifierComponent, Type};
+use crate::model::{OpenType, OpenTypeVariant};
+use crate::syn::{BitVec, ObjectIdentifier, RelativeOid};
+
+/// The value of any ASN.1 type, as read and written by the [`Interpreter`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum DynamicValue {
+    Boolean(bool),
+    Integer(i64),
+    Real(f64),
+    /// The value of any character string type or time type
+    String(String),
+    OctetString(Vec<u8>),
+    BitString(BitVec),
+    Null,
+    ObjectIdentifier(ObjectIdentifier),
+    RelativeOid(RelativeOid),
+    /// The identifier of the ENUMERATED value
+    Enumerated(String),
+    /// The present components of a SEQUENCE or SET by their identifier, in the order of their
+    /// definition
+    Sequence(Vec<(String, DynamicValue)>),
+    /// The values of a SEQUENCE OF or SET OF
+    SequenceOf(Vec<DynamicValue>),
+    /// The identifier of the chosen alternative of a CHOICE and its value. The variants of an
+    /// open type are chosen by the name of the type they contain.
+    Choice(String, Box<DynamicValue>),
+    /// The content of an open type whose key is not in the object set of the schema
+    Unknown(Vec<u8>),
+}
+
+impl DynamicValue {
+    /// The value of the component with the given identifier, if this is a SEQUENCE or SET
+    pub fn component(&self, identifier: &str) -> Option<&DynamicValue> {
+        match self {
+            DynamicValue::Sequence(components) => components
+                .iter()
+                .find(|(name, _)| name.eq(identifier))
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    /// The value of a `DEFAULT` component that is absent from the encoding
+    fn from_literal(literal: &LiteralValue) -> Option<Self> {
+        Some(match literal {
+            LiteralValue::Boolean(value) => DynamicValue::Boolean(*value),
+            LiteralValue::String(value) => DynamicValue::String(value.clone()),
+            LiteralValue::Integer(value) => DynamicValue::Integer(*value),
+            LiteralValue::OctetString(value) => DynamicValue::OctetString(value.clone()),
+            LiteralValue::EnumeratedVariant(_, variant) => {
+                DynamicValue::Enumerated(variant.clone())
+            }
+            LiteralValue::ObjectIdentifier(oid) => {
+                DynamicValue::ObjectIdentifier(ObjectIdentifier::new(
+                    oid.iter()
+                        .map(|component| match component {
+                            ObjectIdentifierComponent::NumberForm(number)
+                            | ObjectIdentifierComponent::NameAndNumberForm(_, number) => {
+                                Some(*number)
+                            }
+                            ObjectIdentifierComponent::NameForm(_) => None,
+                        })
+                        .collect::<Option<Vec<_>>>()?,
+                ))
+            }
+        })
+    }
+}
+
+/// Reads and writes [`DynamicValue`]s as the types defined in a set of resolved modules, so
+/// that arbitrary values can be decoded and encoded without generating code first.
+///
+/// ```rust
+/// use asn1rs::dynamic::{DynamicValue, Interpreter};
+/// use asn1rs::model::Model;
+/// use asn1rs::parser::Tokenizer;
+/// use asn1rs::prelude::*;
+///
+/// let tokens = Tokenizer::default().parse(
+///     r"Basic DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+///     Pair ::= SEQUENCE { key INTEGER (0..255), value UTF8String OPTIONAL }
+///     END",
+/// );
+/// let model = Model::try_from(tokens).unwrap().try_resolve().unwrap();
+/// let interpreter = Interpreter::new(&[&model]);
+///
+/// let pair = DynamicValue::Sequence(vec![("key".to_string(), DynamicValue::Integer(42))]);
+/// let mut writer = UperWriter::default();
+/// interpreter.write_uper(&mut writer, "Pair", &pair).unwrap();
+/// assert_eq!(&[0x15, 0x00], writer.byte_content());
+///
+/// let mut reader = writer.as_reader();
+/// assert_eq!(pair, interpreter.read_uper(&mut reader, "Pair").unwrap());
+/// ```
+pub struct Interpreter<'a> {
+    scope: Vec<&'a Model<Asn<Resolved>>>,
+}
+
+impl<'a> Interpreter<'a> {
+    /// The types of all given modules can be read and written, and refer to each other
+    pub fn new(scope: &[&'a Model<Asn<Resolved>>]) -> Self {
+        Self {
+            scope: scope.to_vec(),
+        }
+    }
+
+    /// The type of the definition with the given name
+    pub fn definition(&self, name: &str) -> Result<&'a Type, Error> {
+        self.scope
+            .iter()
+            .flat_map(|model| model.definitions.iter())
+            .find(|Definition(definition, _)| definition.eq(name))
+            .map(|Definition(_, asn)| &asn.r#type)
+            .ok_or_else(|| ErrorKind::UnknownType(name.to_string()).into())
+    }
+
+    /// Follows references to other definitions until the actual type is reached
+    fn resolve<'t>(&self, r#type: &'t Type) -> Result<&'t Type, Error>
+    where
+        'a: 't,
+    {
+        match r#type {
+            Type::TypeReference(name, _) | Type::TypeReferenceId(name, ..) => {
+                self.resolve(self.definition(name)?)
+            }
+            Type::Optional(inner) | Type::Default(inner, _) => self.resolve(inner),
+            other => Ok(other),
+        }
+    }
+
+    /// The variant of the open type that is selected by the value of the sibling component
+    /// the open type refers to. ENUMERATED values select their index and OBJECT IDENTIFIER
+    /// values the variant defined for the same value (see [`crate::syn::opentype::Key`]).
+    fn open_type_variant<'o>(
+        &self,
+        open_type: &'o OpenType,
+        key_type: &Type,
+        key_value: &DynamicValue,
+    ) -> Result<Option<&'o OpenTypeVariant>, Error> {
+        let key = match (self.resolve(key_type)?, key_value) {
+            (Type::Enumerated(enumerated), DynamicValue::Enumerated(identifier)) => enumerated
+                .variants()
+                .position(|variant| variant.identifier().eq(identifier)),
+            (_, DynamicValue::Integer(value)) => Some(*value as usize),
+            (_, DynamicValue::ObjectIdentifier(oid)) => {
+                return Ok(open_type
+                    .variants()
+                    .find(|variant| variant.key_object_identifier.as_deref() == Some(oid.arcs())))
+            }
+            (_, other) => return Err(mismatch("a key of an open type", other)),
+        };
+        Ok(key.and_then(|key| {
+            open_type
+                .variants()
+                .find(|variant| variant.key == Some(key))
+        }))
+    }
+}
+
+#[cold]
+#[inline(never)]
+fn mismatch(expected: &str, value: &DynamicValue) -> Error {
+    ErrorKind::DynamicValueMismatch(format!("expected {}, but got {:?}", expected, value)).into()
+}
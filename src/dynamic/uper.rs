@@ -0,0 +1,699 @@
+use super::{mismatch, DynamicValue, Interpreter};
+use crate::io::per::err::{Error, ErrorKind};
+use crate::io::per::unaligned::{ScopedBitRead, ScopedBitWrite};
+use crate::io::per::{PackedRead, PackedWrite};
+use crate::model::lor::Resolved;
+use crate::model::{
+    Asn, Charset, Choice, ComponentTypeList, Enumerated, Field, Integer, LiteralValue, OpenType,
+    PermittedAlphabet, Size, Tag, Type,
+};
+use crate::syn::io::{Bits, UperReader, UperWriter};
+use crate::syn::{BitVec, ObjectIdentifier, RelativeOid};
+
+impl Interpreter<'_> {
+    /// Writes the value as the type with the given name, like [`crate::syn::Writer::write`]
+    /// writes generated types
+    pub fn write_uper<B: ScopedBitWrite>(
+        &self,
+        writer: &mut UperWriter<B>,
+        type_name: &str,
+        value: &DynamicValue,
+    ) -> Result<(), Error> {
+        let r#type = self.definition(type_name)?;
+        writer.scope_stashed(|w| self.write_uper_type(w, r#type, value))
+    }
+
+    /// Reads a value of the type with the given name, like [`crate::syn::Reader::read`] reads
+    /// generated types
+    pub fn read_uper<B: ScopedBitRead>(
+        &self,
+        reader: &mut UperReader<B>,
+        type_name: &str,
+    ) -> Result<DynamicValue, Error> {
+        let r#type = self.definition(type_name)?;
+        reader.scope_stashed(|r| self.read_uper_type(r, r#type))
+    }
+
+    fn write_uper_type<B: ScopedBitWrite>(
+        &self,
+        w: &mut UperWriter<B>,
+        r#type: &Type,
+        value: &DynamicValue,
+    ) -> Result<(), Error> {
+        match (self.resolve(r#type)?, value) {
+            (Type::Boolean, DynamicValue::Boolean(value)) => w.bits.write_boolean(*value),
+            (Type::Integer(integer), DynamicValue::Integer(value)) => {
+                let (min, max, extensible) = integer_bounds(integer);
+                let unconstrained = if extensible {
+                    let out_of_range =
+                        *value < min.unwrap_or(0) || *value > max.unwrap_or(i64::MAX);
+                    w.bits.write_bit(out_of_range)?;
+                    out_of_range
+                } else {
+                    min.is_none() && max.is_none()
+                };
+                if unconstrained {
+                    w.bits.write_unconstrained_whole_number(*value)
+                } else {
+                    w.bits.write_constrained_whole_number(
+                        min.unwrap_or(0),
+                        max.unwrap_or(i64::MAX),
+                        *value,
+                    )
+                }
+            }
+            (Type::Real, DynamicValue::Real(value)) => {
+                let mut content = Vec::new();
+                crate::io::ber::write_real(&mut content, *value);
+                w.bits.write_octetstring(None, None, false, &content)
+            }
+            (Type::String(size, charset, alphabet), DynamicValue::String(value)) => {
+                Self::write_uper_string(w, size, *charset, alphabet.as_ref(), value)
+            }
+            (Type::Time(_), DynamicValue::String(value)) => {
+                // encoded as if they were an unconstrained VisibleString
+                Self::write_uper_string(w, &Size::Any, Charset::Visible, None, value)
+            }
+            (Type::OctetString(size), DynamicValue::OctetString(value)) => {
+                let (min, max, extensible) = size_bounds(size);
+                w.bits.write_octetstring(min, max, extensible, value)
+            }
+            (Type::BitString(bit_string), DynamicValue::BitString(value)) => {
+                let (min, max, extensible) = size_bounds(&bit_string.size);
+                w.bits.write_bitstring(
+                    min,
+                    max,
+                    extensible,
+                    value.as_byte_slice(),
+                    0,
+                    value.bit_len(),
+                )
+            }
+            (Type::Null, DynamicValue::Null) => Ok(()),
+            (Type::ObjectIdentifier, DynamicValue::ObjectIdentifier(value)) => {
+                let mut content = Vec::new();
+                crate::io::ber::write_object_identifier(
+                    &mut content,
+                    Tag::DEFAULT_OBJECT_IDENTIFIER,
+                    value.arcs(),
+                )
+                .map_err(Error::invalid_object_identifier)?;
+                w.bits.write_octetstring(None, None, false, &content)
+            }
+            (Type::RelativeOid, DynamicValue::RelativeOid(value)) => {
+                let mut content = Vec::new();
+                crate::io::ber::write_relative_oid(&mut content, value.arcs());
+                w.bits.write_octetstring(None, None, false, &content)
+            }
+            (Type::Sequence(components) | Type::Set(components), value) => {
+                self.write_uper_components(w, components, value)
+            }
+            (
+                Type::SequenceOf(inner, size) | Type::SetOf(inner, size),
+                DynamicValue::SequenceOf(values),
+            ) => {
+                let (min, max, extensible) = size_bounds(size);
+                w.write_extensible_bit_and_length_or_err(
+                    extensible,
+                    min,
+                    max,
+                    i64::MAX as u64,
+                    values.len() as u64,
+                )?;
+                values
+                    .iter()
+                    .try_for_each(|value| self.write_uper_type(w, inner, value))
+            }
+            (Type::Enumerated(enumerated), DynamicValue::Enumerated(identifier)) => {
+                let index = enumerated
+                    .variants()
+                    .position(|variant| variant.identifier().eq(identifier))
+                    .ok_or_else(|| mismatch("a variant of the ENUMERATED", value))?;
+                let (std_variants, extensible) = enumerated_bounds(enumerated);
+                w.bits
+                    .write_enumeration_index(std_variants, extensible, index as u64)
+            }
+            (Type::Choice(choice), DynamicValue::Choice(identifier, content)) => {
+                let (index, variant) = choice
+                    .variants()
+                    .enumerate()
+                    .find(|(_, variant)| variant.identifier().eq(identifier))
+                    .ok_or_else(|| mismatch("an alternative of the CHOICE", value))?;
+                let (std_variants, extensible) = choice_bounds(choice);
+                w.bits
+                    .write_choice_index(std_variants, extensible, index as u64)?;
+                if index as u64 >= std_variants {
+                    w.write_octets_with(true, |w| {
+                        self.write_uper_type(w, variant.r#type(), content)
+                    })
+                } else {
+                    self.write_uper_type(w, variant.r#type(), content)
+                }
+            }
+            (Type::OpenType(open_type), DynamicValue::Choice(name, content)) => {
+                let variant = open_type
+                    .variants()
+                    .find(|variant| variant.name().eq(name))
+                    .ok_or_else(|| mismatch("a variant of the open type", value))?;
+                w.write_octets_with(false, |w| self.write_uper_type(w, &variant.r#type, content))
+            }
+            (Type::OpenType(_), DynamicValue::Unknown(content)) => {
+                w.bits.write_octetstring(None, None, false, content)
+            }
+            (r#type, value) => Err(mismatch_type(r#type, value)),
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 30, like the writers of the generated
+    /// character string types
+    fn write_uper_string<B: ScopedBitWrite>(
+        w: &mut UperWriter<B>,
+        size: &Size,
+        charset: Charset,
+        alphabet: Option<&PermittedAlphabet>,
+        value: &str,
+    ) -> Result<(), Error> {
+        let (min, max, extensible) = size_bounds(size);
+        // an extensible permitted alphabet is not PER-visible
+        let alphabet = alphabet.filter(|alphabet| !alphabet.extensible());
+        match charset {
+            Charset::Utf8 => {
+                let chars = value.chars().count() as u64;
+                let (min, max) = (min.unwrap_or(0), max.unwrap_or(u64::MAX));
+                if !extensible && (chars < min || chars > max) {
+                    return Err(ErrorKind::SizeNotInRange(chars, min, max).into());
+                }
+                w.bits
+                    .write_octetstring(None, None, false, value.as_bytes())
+            }
+            Charset::Ia5 | Charset::Numeric | Charset::Printable | Charset::Visible
+                if alphabet.is_none() =>
+            {
+                Error::ensure_string_valid(charset, value)?;
+                w.write_extensible_bit_and_length_or_err(
+                    extensible,
+                    min,
+                    max,
+                    u64::MAX,
+                    value.chars().count() as u64,
+                )?;
+                for char in value.chars().map(|c| c as u8) {
+                    if charset == Charset::Numeric {
+                        let char = match char - 32 {
+                            0 => 0,
+                            c => c - 15,
+                        };
+                        w.bits.write_bits_with_offset(&[char], 4)?;
+                    } else {
+                        // 7 bits
+                        w.bits.write_bits_with_offset(&[char], 1)?;
+                    }
+                }
+                Ok(())
+            }
+            Charset::Ia5
+            | Charset::Numeric
+            | Charset::Printable
+            | Charset::Visible
+            | Charset::Universal
+            | Charset::Bmp
+                if alphabet.is_some() =>
+            {
+                Error::ensure_string_valid(charset, value)?;
+                let ranges = alphabet.map(PermittedAlphabet::ranges).unwrap_or_default();
+                w.write_permitted_alphabet_string(charset, ranges, extensible, min, max, value)
+            }
+            _ => w.write_charset_string(charset, extensible, min, max, value),
+        }
+    }
+
+    /// Writes the components of a SEQUENCE or SET, see [`crate::syn::io::Scope`]
+    fn write_uper_components<B: ScopedBitWrite>(
+        &self,
+        w: &mut UperWriter<B>,
+        components: &ComponentTypeList<Resolved>,
+        value: &DynamicValue,
+    ) -> Result<(), Error> {
+        let values = match value {
+            DynamicValue::Sequence(values) => values,
+            other => return Err(mismatch("a SEQUENCE or SET", other)),
+        };
+        if let Some((name, _)) = values.iter().find(|(name, _)| {
+            !components
+                .fields
+                .iter()
+                .any(|field| field.identifier().eq(name))
+        }) {
+            return Err(mismatch(&format!("no component named {}", name), value));
+        }
+
+        let present = components
+            .fields
+            .iter()
+            .map(
+                |field| match (default_of(field), value.component(field.identifier())) {
+                    // the default value is not encoded
+                    (Some(default), Some(value)) => {
+                        DynamicValue::from_literal(default).as_ref() != Some(value)
+                    }
+                    (_, present) => present.is_some(),
+                },
+            )
+            .collect::<Vec<_>>();
+
+        let (root, additions) = components.fields.split_at(root_len(components));
+        let any_addition_present = present[root.len()..].iter().any(|present| *present);
+
+        if components.extension_after.is_some() {
+            w.bits.write_bit(any_addition_present)?;
+        }
+        for (field, present) in root.iter().zip(&present) {
+            if is_optional(field) {
+                w.bits.write_bit(*present)?;
+            } else if !present {
+                return Err(mismatch(
+                    &format!("the component {}", field.identifier()),
+                    value,
+                ));
+            }
+        }
+        for (field, _) in root.iter().zip(&present).filter(|(_, present)| **present) {
+            self.write_uper_component(w, field, value)?;
+        }
+
+        if any_addition_present {
+            // ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 19.7 and 19.8
+            w.bits
+                .write_normally_small_non_negative_whole_number(additions.len() as u64 - 1)?;
+            for present in &present[root.len()..] {
+                w.bits.write_bit(*present)?;
+            }
+            for (field, _) in additions
+                .iter()
+                .zip(&present[root.len()..])
+                .filter(|(_, present)| **present)
+            {
+                w.write_octets_with(true, |w| self.write_uper_component(w, field, value))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_uper_component<B: ScopedBitWrite>(
+        &self,
+        w: &mut UperWriter<B>,
+        field: &Field<Asn>,
+        sequence: &DynamicValue,
+    ) -> Result<(), Error> {
+        let value = sequence
+            .component(field.identifier())
+            .ok_or_else(|| mismatch(&format!("the component {}", field.identifier()), sequence))?;
+        self.write_uper_type(w, &field.role.r#type, value)
+    }
+
+    fn read_uper_type<B: ScopedBitRead>(
+        &self,
+        r: &mut UperReader<B>,
+        r#type: &Type,
+    ) -> Result<DynamicValue, Error> {
+        Ok(match self.resolve(r#type)? {
+            Type::Boolean => DynamicValue::Boolean(r.bits.read_boolean()?),
+            Type::Integer(integer) => {
+                let (min, max, extensible) = integer_bounds(integer);
+                let unconstrained = if extensible {
+                    r.bits.read_bit()?
+                } else {
+                    min.is_none() && max.is_none()
+                };
+                DynamicValue::Integer(if unconstrained {
+                    r.bits.read_unconstrained_whole_number()?
+                } else {
+                    r.bits
+                        .read_constrained_whole_number(min.unwrap_or(0), max.unwrap_or(i64::MAX))?
+                })
+            }
+            Type::Real => {
+                let content = r.bits.read_octetstring(None, None, false)?;
+                DynamicValue::Real(
+                    crate::io::ber::read_real(Tag::DEFAULT_REAL, &content)
+                        .map_err(Error::invalid_real)?,
+                )
+            }
+            Type::String(size, charset, alphabet) => DynamicValue::String(Self::read_uper_string(
+                r,
+                size,
+                *charset,
+                alphabet.as_ref(),
+            )?),
+            Type::Time(_) => DynamicValue::String(Self::read_uper_string(
+                r,
+                &Size::Any,
+                Charset::Visible,
+                None,
+            )?),
+            Type::OctetString(size) => {
+                let (min, max, extensible) = size_bounds(size);
+                DynamicValue::OctetString(r.bits.read_octetstring(min, max, extensible)?)
+            }
+            Type::BitString(bit_string) => {
+                let (min, max, extensible) = size_bounds(&bit_string.size);
+                let (bytes, bit_len) = r.bits.read_bitstring(min, max, extensible)?;
+                DynamicValue::BitString(BitVec::from_bytes(bytes, bit_len))
+            }
+            Type::Null => DynamicValue::Null,
+            Type::ObjectIdentifier => {
+                let content = r.bits.read_octetstring(None, None, false)?;
+                DynamicValue::ObjectIdentifier(ObjectIdentifier::new(
+                    crate::io::ber::read_object_identifier(
+                        Tag::DEFAULT_OBJECT_IDENTIFIER,
+                        &content,
+                    )
+                    .map_err(Error::invalid_object_identifier)?,
+                ))
+            }
+            Type::RelativeOid => {
+                let content = r.bits.read_octetstring(None, None, false)?;
+                DynamicValue::RelativeOid(RelativeOid::new(
+                    crate::io::ber::read_relative_oid(Tag::DEFAULT_RELATIVE_OID, &content)
+                        .map_err(Error::invalid_object_identifier)?,
+                ))
+            }
+            Type::Sequence(components) | Type::Set(components) => {
+                self.read_uper_components(r, components)?
+            }
+            Type::SequenceOf(inner, size) | Type::SetOf(inner, size) => {
+                let (min, max, extensible) = size_bounds(size);
+                let len = if extensible && r.bits.read_bit()? {
+                    r.read_length_determinant(None, None)?
+                } else {
+                    r.read_length_determinant(min, max)?
+                };
+                DynamicValue::SequenceOf(
+                    (0..len)
+                        .map(|_| self.read_uper_type(r, inner))
+                        .collect::<Result<_, _>>()?,
+                )
+            }
+            Type::Enumerated(enumerated) => {
+                let (std_variants, extensible) = enumerated_bounds(enumerated);
+                let index = r.read_enumeration_index(std_variants, extensible)?;
+                let variant = enumerated.variants().nth(index as usize).ok_or_else(|| {
+                    Error::from(ErrorKind::InvalidChoiceIndex(
+                        index,
+                        enumerated.len() as u64,
+                    ))
+                })?;
+                DynamicValue::Enumerated(variant.identifier().to_string())
+            }
+            Type::Choice(choice) => {
+                let (std_variants, extensible) = choice_bounds(choice);
+                let index = r.bits.read_choice_index(std_variants, extensible)?;
+                let variant = choice.variants().nth(index as usize).ok_or_else(|| {
+                    Error::from(ErrorKind::InvalidChoiceIndex(index, choice.len() as u64))
+                })?;
+                let content = if index >= std_variants {
+                    read_fragmented(r, |r| self.read_uper_type(r, variant.r#type()))?
+                } else {
+                    self.read_uper_type(r, variant.r#type())?
+                };
+                DynamicValue::Choice(variant.identifier().to_string(), Box::new(content))
+            }
+            // without the value of the component that selects the variant
+            Type::OpenType(_) => {
+                return Err(ErrorKind::UnsupportedOperation(
+                    "Reading an open type without a preceding key".to_string(),
+                )
+                .into())
+            }
+            other => {
+                return Err(ErrorKind::UnsupportedOperation(format!(
+                    "Reading a value of the type {:?}",
+                    other
+                ))
+                .into())
+            }
+        })
+    }
+
+    /// See [`Self::write_uper_string`]
+    fn read_uper_string<B: ScopedBitRead>(
+        r: &mut UperReader<B>,
+        size: &Size,
+        charset: Charset,
+        alphabet: Option<&PermittedAlphabet>,
+    ) -> Result<String, Error> {
+        let (min, max, extensible) = size_bounds(size);
+        let alphabet = alphabet.filter(|alphabet| !alphabet.extensible());
+        match charset {
+            Charset::Utf8 => {
+                let octets = r.bits.read_octetstring(None, None, false)?;
+                String::from_utf8(octets).map_err(|e| ErrorKind::FromUtf8Error(e).into())
+            }
+            Charset::Ia5 | Charset::Numeric | Charset::Printable | Charset::Visible
+                if alphabet.is_none() =>
+            {
+                let len = if extensible && r.bits.read_bit()? {
+                    r.read_length_determinant(None, None)?
+                } else {
+                    r.read_length_determinant(min, max)?
+                };
+                let mut buffer = vec![0u8; len as usize];
+                for char in buffer.chunks_exact_mut(1) {
+                    if charset == Charset::Numeric {
+                        r.bits.read_bits_with_offset(char, 4)?;
+                        char[0] = match char[0] {
+                            0 => b' ',
+                            c => c + 32 + 15,
+                        };
+                    } else {
+                        r.bits.read_bits_with_offset(char, 1)?;
+                    }
+                }
+                let string = String::from_utf8(buffer)
+                    .map_err(|e| Error::from(ErrorKind::FromUtf8Error(e)))?;
+                Error::ensure_string_valid(charset, &string)?;
+                Ok(string)
+            }
+            Charset::Ia5
+            | Charset::Numeric
+            | Charset::Printable
+            | Charset::Visible
+            | Charset::Universal
+            | Charset::Bmp
+                if alphabet.is_some() =>
+            {
+                let ranges = alphabet.map(PermittedAlphabet::ranges).unwrap_or_default();
+                r.read_permitted_alphabet_string(charset, ranges, extensible, min, max)
+            }
+            _ => r.read_charset_string(charset, extensible, min, max),
+        }
+    }
+
+    /// Reads the components of a SEQUENCE or SET. The absent `DEFAULT` components are set to
+    /// their default value, and extension additions unknown to the schema are skipped.
+    fn read_uper_components<B: ScopedBitRead>(
+        &self,
+        r: &mut UperReader<B>,
+        components: &ComponentTypeList<Resolved>,
+    ) -> Result<DynamicValue, Error> {
+        let (root, additions) = components.fields.split_at(root_len(components));
+        let extended = components.extension_after.is_some() && r.bits.read_bit()?;
+        let present = root
+            .iter()
+            .map(|field| {
+                if is_optional(field) {
+                    r.bits.read_bit()
+                } else {
+                    Ok(true)
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut values = Vec::with_capacity(components.fields.len());
+        for (field, present) in root.iter().zip(present) {
+            if present {
+                let value = self.read_uper_component(r, components, field, &values)?;
+                values.push((field.identifier().to_string(), value));
+            } else {
+                Self::push_default(&mut values, field);
+            }
+        }
+
+        let mut present = Vec::new();
+        if extended {
+            // ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 19.7 and 19.8
+            let len = r.bits.read_normally_small_length()? + 1;
+            for _ in 0..len {
+                present.push(r.bits.read_bit()?);
+            }
+        }
+        for (index, field) in additions.iter().enumerate() {
+            if present.get(index).copied().unwrap_or(false) {
+                let value = read_fragmented(r, |r| {
+                    self.read_uper_component(r, components, field, &values)
+                })?;
+                values.push((field.identifier().to_string(), value));
+            } else {
+                Self::push_default(&mut values, field);
+            }
+        }
+        for _ in present
+            .iter()
+            .skip(additions.len())
+            .filter(|present| **present)
+        {
+            // the extension additions unknown to the schema
+            r.bits.read_octetstring(None, None, false)?;
+        }
+
+        Ok(DynamicValue::Sequence(values))
+    }
+
+    /// Reads the value of a component. The variant of an open type is selected by the value
+    /// of the earlier component it refers to (ITU-T X.682 | ISO/IEC 8824-3, 10.7).
+    fn read_uper_component<B: ScopedBitRead>(
+        &self,
+        r: &mut UperReader<B>,
+        components: &ComponentTypeList<Resolved>,
+        field: &Field<Asn>,
+        values: &[(String, DynamicValue)],
+    ) -> Result<DynamicValue, Error> {
+        let r#type = &field.role.r#type;
+        if let (Some(key), Type::OpenType(open_type)) = (r#type.get_ref_id(), self.resolve(r#type)?)
+        {
+            let key = key.trim_start_matches('@');
+            let key_type = components
+                .fields
+                .iter()
+                .find(|field| field.identifier().eq(key))
+                .map(|field| &field.role.r#type);
+            let key_value = values
+                .iter()
+                .find(|(name, _)| name.eq(key))
+                .map(|(_, value)| value);
+            if let (Some(key_type), Some(key_value)) = (key_type, key_value) {
+                return self.read_uper_open_type(r, open_type, key_type, key_value);
+            }
+        }
+        self.read_uper_type(r, r#type)
+    }
+
+    fn read_uper_open_type<B: ScopedBitRead>(
+        &self,
+        r: &mut UperReader<B>,
+        open_type: &OpenType,
+        key_type: &Type,
+        key_value: &DynamicValue,
+    ) -> Result<DynamicValue, Error> {
+        match self.open_type_variant(open_type, key_type, key_value)? {
+            Some(variant) => {
+                let length = r.read_length_determinant(None, None)?;
+                // the content is padded to whole octets
+                let content = r.read_whole_sub_slice(length as usize, |r| {
+                    self.read_uper_type(r, &variant.r#type)
+                })?;
+                Ok(DynamicValue::Choice(
+                    variant.name().to_string(),
+                    Box::new(content),
+                ))
+            }
+            None => Ok(DynamicValue::Unknown(
+                r.bits.read_octetstring(None, None, false)?,
+            )),
+        }
+    }
+
+    fn push_default(values: &mut Vec<(String, DynamicValue)>, field: &Field<Asn>) {
+        if let Some(value) = default_of(field).and_then(DynamicValue::from_literal) {
+            values.push((field.identifier().to_string(), value));
+        }
+    }
+}
+
+/// The number of components in the extension root
+/// Reads the content of an open type field which the writer splits into fragments from 16K
+/// octets on (ITU-T X.691 | ISO/IEC 8825-2:2015, 11.9.3.8), by reassembling its octets first
+fn read_fragmented<B: ScopedBitRead, T>(
+    r: &mut UperReader<B>,
+    f: impl FnOnce(&mut UperReader<Bits>) -> Result<T, Error>,
+) -> Result<T, Error> {
+    let octets = r.bits.read_octetstring(None, None, false)?;
+    f(&mut UperReader::from(&octets[..]))
+}
+
+fn root_len(components: &ComponentTypeList<Resolved>) -> usize {
+    components
+        .extension_after
+        .map(|index| index + 1)
+        .unwrap_or(components.fields.len())
+}
+
+/// Whether the presence of the component is announced in the preamble of the SEQUENCE or SET
+fn is_optional(field: &Field<Asn>) -> bool {
+    matches!(field.role.r#type, Type::Optional(_)) || default_of(field).is_some()
+}
+
+fn default_of(field: &Field<Asn>) -> Option<&LiteralValue> {
+    match &field.role.r#type {
+        Type::Default(_, default) => Some(default),
+        _ => field.role.default.as_ref(),
+    }
+}
+
+/// The PER-visible bounds of an INTEGER, like the constraints of the generated types
+fn integer_bounds(integer: &Integer) -> (Option<i64>, Option<i64>, bool) {
+    let extensible = integer.range.extensible();
+    match (*integer.range.min(), *integer.range.max()) {
+        (None, None) | (Some(0), None) | (Some(0), Some(i64::MAX)) | (None, Some(i64::MAX)) => {
+            (None, None, extensible)
+        }
+        (min, max) if !extensible => (
+            Some(min.unwrap_or_default()),
+            Some(max.unwrap_or(i64::MAX)),
+            false,
+        ),
+        (min, max) if min.unwrap_or_default() >= 0 && max.unwrap_or_default() >= 0 => {
+            (min, max, true)
+        }
+        (min, max) => (
+            Some(min.unwrap_or(i64::MIN)),
+            Some(max.unwrap_or(i64::MAX)),
+            true,
+        ),
+    }
+}
+
+fn size_bounds(size: &Size) -> (Option<u64>, Option<u64>, bool) {
+    (
+        size.min().map(|min| *min as u64),
+        size.max().map(|max| *max as u64),
+        size.extensible(),
+    )
+}
+
+fn enumerated_bounds(enumerated: &Enumerated) -> (u64, bool) {
+    let std_variants = enumerated
+        .extension_after_index()
+        .map(|index| index + 1)
+        .unwrap_or_else(|| enumerated.len());
+    (std_variants as u64, enumerated.is_extensible())
+}
+
+fn choice_bounds(choice: &Choice) -> (u64, bool) {
+    let std_variants = choice
+        .extension_after_index()
+        .map(|index| index + 1)
+        .unwrap_or_else(|| choice.len());
+    (std_variants as u64, choice.is_extensible())
+}
+
+#[cold]
+#[inline(never)]
+fn mismatch_type(r#type: &Type, value: &DynamicValue) -> Error {
+    ErrorKind::DynamicValueMismatch(format!(
+        "expected a value of {:?}, but got {:?}",
+        r#type, value
+    ))
+    .into()
+}
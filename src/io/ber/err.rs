@@ -0,0 +1,239 @@
+use crate::io::Backtrace;
+use crate::model::{Charset, Tag};
+use alloc::boxed::Box;
+use alloc::string::FromUtf8Error;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Error(pub(crate) Box<ErrorKind>);
+
+impl Error {
+    #[inline]
+    pub fn kind(&self) -> &ErrorKind {
+        &self.0
+    }
+
+    /// Whether the tag of the element at the given offset did not match the expected tag
+    #[inline]
+    pub(crate) fn is_unexpected_tag_at(&self, position: usize) -> bool {
+        matches!(&*self.0, ErrorKind::UnexpectedTag { offset, .. } if *offset == position)
+            || matches!(&*self.0, ErrorKind::UnexpectedEndOfContents { offset, .. } if *offset == position)
+    }
+}
+
+impl From<ErrorKind> for Error {
+    #[cold]
+    #[inline(never)]
+    fn from(kind: ErrorKind) -> Self {
+        Self(Box::new(kind))
+    }
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl core::error::Error for Error {
+    fn description(&self) -> &str {
+        "encoding or decoding BER failed"
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum ErrorKind {
+    FromUtf8Error(FromUtf8Error),
+    InvalidString(Charset, char, usize),
+    InsufficientDataInSourceBuffer(Backtrace),
+    UnsupportedLength(usize),
+    UnexpectedTag {
+        expected: Tag,
+        found: Tag,
+        offset: usize,
+    },
+    UnexpectedEndOfContents {
+        expected: Tag,
+        offset: usize,
+    },
+    UnexpectedConstructedFlag(Tag, bool),
+    UnexpectedIndefiniteLength(Tag),
+    ContentNotFullyConsumed(Tag, usize),
+    InvalidContent(Tag, &'static str),
+    InvalidChoiceIndex(u64, u64),
+    /// The open type of the given name is not selected by a key of its object set
+    InvalidOpenTypeKey(&'static str),
+    ValueNotInRange(i64, i64, i64),
+    ValueExceedsMaxInt,
+    SizeNotInRange(u64, u64, u64),
+    /// The component stored in the given field violates its inner type constraint
+    ComponentConstraintViolated(&'static str, crate::syn::validate::ViolationKind),
+}
+
+impl Error {
+    #[cold]
+    #[inline(never)]
+    pub fn ensure_string_valid(charset: Charset, str: &str) -> Result<(), Self> {
+        match charset.find_invalid(str) {
+            None => Ok(()),
+            Some((index, char)) => Err(ErrorKind::InvalidString(charset, char, index).into()),
+        }
+    }
+
+    #[cold]
+    #[inline(never)]
+    pub fn insufficient_data_in_source_buffer() -> Self {
+        ErrorKind::InsufficientDataInSourceBuffer(Backtrace::new_unresolved()).into()
+    }
+}
+
+impl core::fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::FromUtf8Error(err) => {
+                write!(f, "Failed to call String::from_utf8: ")?;
+                err.fmt(f)
+            }
+            Self::InvalidString(charset, char, index) => {
+                write!(
+                    f,
+                    "Invalid character for a string with the charset {:?} at index {}: {}",
+                    charset, index, char
+                )
+            }
+            Self::InsufficientDataInSourceBuffer(backtrace) => write!(
+                f,
+                "There is insufficient data in the source buffer for this operation:\n{:?}",
+                {
+                    let mut b = backtrace.clone();
+                    b.resolve();
+                    b
+                }
+            ),
+            Self::UnsupportedLength(octets) => write!(
+                f,
+                "A length consisting of {} octets is not supported",
+                octets
+            ),
+            Self::UnexpectedTag {
+                expected,
+                found,
+                offset,
+            } => write!(
+                f,
+                "Expected the tag {:?} but found {:?} at offset {}",
+                expected, found, offset
+            ),
+            Self::UnexpectedEndOfContents { expected, offset } => write!(
+                f,
+                "Expected the tag {:?} but reached the end of the contents at offset {}",
+                expected, offset
+            ),
+            Self::UnexpectedConstructedFlag(tag, constructed) => write!(
+                f,
+                "The element with the tag {:?} is unexpectedly {}",
+                tag,
+                if *constructed {
+                    "constructed"
+                } else {
+                    "primitive"
+                }
+            ),
+            Self::UnexpectedIndefiniteLength(tag) => write!(
+                f,
+                "The primitive element with the tag {:?} must not have an indefinite length",
+                tag
+            ),
+            Self::ContentNotFullyConsumed(tag, remaining) => write!(
+                f,
+                "The contents of the element with the tag {:?} has {} unexpected trailing octets",
+                tag, remaining
+            ),
+            Self::InvalidContent(tag, reason) => write!(
+                f,
+                "The contents of the element with the tag {:?} is invalid: {}",
+                tag, reason
+            ),
+            Self::InvalidChoiceIndex(index, variant_count) => write!(
+                f,
+                "Unexpected choice-index {} with variant count {}",
+                index, variant_count
+            ),
+            Self::InvalidOpenTypeKey(name) => write!(
+                f,
+                "The open type {} is not selected by a key of its object set",
+                name
+            ),
+            Self::ValueNotInRange(value, min, max) => write!(
+                f,
+                "The value {} is not within the inclusive range of {} and {}",
+                value, min, max
+            ),
+            Self::ValueExceedsMaxInt => {
+                write!(f, "The value exceeds the maximum supported integer size",)
+            }
+            Self::SizeNotInRange(size, min, max) => write!(
+                f,
+                "The size {} is not within the inclusive range of {} and {}",
+                size, min, max
+            ),
+            Self::ComponentConstraintViolated(field, kind) => {
+                write!(
+                    f,
+                    "The component {} violates its constraint: {}",
+                    field, kind
+                )
+            }
+        }
+    }
+}
+
+impl PartialEq for ErrorKind {
+    fn eq(&self, other: &Self) -> bool {
+        match self {
+            Self::FromUtf8Error(a) => matches!(other, Self::FromUtf8Error(oa) if a == oa),
+            Self::InvalidString(a, b, c) => {
+                matches!(other, Self::InvalidString(oa, ob, oc) if (a, b, c) == (oa, ob, oc))
+            }
+            Self::InsufficientDataInSourceBuffer(_) => {
+                matches!(other, Self::InsufficientDataInSourceBuffer(_))
+            }
+            Self::UnsupportedLength(a) => matches!(other, Self::UnsupportedLength(oa) if a == oa),
+            Self::UnexpectedTag {
+                expected,
+                found,
+                offset,
+            } => {
+                matches!(other, Self::UnexpectedTag { expected: oe, found: of, offset: oo } if (expected, found, offset) == (oe, of, oo))
+            }
+            Self::UnexpectedEndOfContents { expected, offset } => {
+                matches!(other, Self::UnexpectedEndOfContents { expected: oe, offset: oo } if (expected, offset) == (oe, oo))
+            }
+            Self::UnexpectedConstructedFlag(a, b) => {
+                matches!(other, Self::UnexpectedConstructedFlag(oa, ob) if (a, b) == (oa, ob))
+            }
+            Self::UnexpectedIndefiniteLength(a) => {
+                matches!(other, Self::UnexpectedIndefiniteLength(oa) if a == oa)
+            }
+            Self::ContentNotFullyConsumed(a, b) => {
+                matches!(other, Self::ContentNotFullyConsumed(oa, ob) if (a, b) == (oa, ob))
+            }
+            Self::InvalidContent(a, b) => {
+                matches!(other, Self::InvalidContent(oa, ob) if (a, b) == (oa, ob))
+            }
+            Self::InvalidChoiceIndex(a, b) => {
+                matches!(other, Self::InvalidChoiceIndex(oa, ob) if (a, b) == (oa, ob))
+            }
+            Self::InvalidOpenTypeKey(a) => matches!(other, Self::InvalidOpenTypeKey(oa) if a == oa),
+            Self::ValueNotInRange(a, b, c) => {
+                matches!(other, Self::ValueNotInRange(oa, ob, oc) if (a, b, c) == (oa, ob, oc))
+            }
+            Self::ValueExceedsMaxInt => matches!(other, Self::ValueExceedsMaxInt),
+            Self::SizeNotInRange(a, b, c) => {
+                matches!(other, Self::SizeNotInRange(oa, ob, oc) if (a, b, c) == (oa, ob, oc))
+            }
+            Self::ComponentConstraintViolated(a, b) => {
+                matches!(other, Self::ComponentConstraintViolated(oa, ob) if (a, b) == (oa, ob))
+            }
+        }
+    }
+}
@@ -0,0 +1,673 @@
+//! This module contains the building blocks of the Basic Encoding Rules (BER) and its canonical
+//! subset, the Distinguished Encoding Rules (DER), according to ITU-T X.690 | ISO/IEC 8825-1.
+//! Every value is encoded as identifier octets, length octets and the contents octets.
+
+pub mod err;
+
+pub use err::Error;
+pub use err::ErrorKind;
+
+use crate::model::Charset;
+use crate::model::Tag;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// According to ITU-T X.690 | ISO/IEC 8825-1, 8.1.2.2, bit 6 of the first identifier octet
+const CONSTRUCTED: u8 = 0x20;
+/// According to ITU-T X.690 | ISO/IEC 8825-1, 8.1.2.4, tag numbers above 30 are encoded in
+/// subsequent octets
+const HIGH_TAG_NUMBER: u8 = 0x1F;
+/// According to ITU-T X.690 | ISO/IEC 8825-1, 8.1.3.6, the indefinite form of the length octets
+const INDEFINITE_LENGTH: u8 = 0x80;
+/// According to ITU-T X.690 | ISO/IEC 8825-1, 8.1.5, the end-of-contents octets
+pub const END_OF_CONTENTS: [u8; 2] = [0x00, 0x00];
+
+/// The identifier octets of an element
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Identifier {
+    pub tag: Tag,
+    pub constructed: bool,
+}
+
+/// According to ITU-T X.690 | ISO/IEC 8825-1, 8.1.3, the length octets of an element
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Length {
+    Definite(usize),
+    Indefinite,
+}
+
+/// According to ITU-T X.690 | ISO/IEC 8825-1, 8.1.2, writes the identifier octets
+pub fn write_identifier(buffer: &mut Vec<u8>, tag: Tag, constructed: bool) {
+    let (class, number) = match tag {
+        Tag::Universal(number) => (0x00, number),
+        Tag::Application(number) => (0x40, number),
+        Tag::ContextSpecific(number) => (0x80, number),
+        Tag::Private(number) => (0xC0, number),
+    };
+    let class = class | if constructed { CONSTRUCTED } else { 0x00 };
+
+    if number < usize::from(HIGH_TAG_NUMBER) {
+        buffer.push(class | number as u8);
+    } else {
+        buffer.push(class | HIGH_TAG_NUMBER);
+        let septets = (usize::BITS - number.leading_zeros()).div_ceil(7);
+        for i in (0..septets).rev() {
+            let septet = ((number >> (i * 7)) & 0x7F) as u8;
+            buffer.push(if i > 0 { septet | 0x80 } else { septet });
+        }
+    }
+}
+
+/// According to ITU-T X.690 | ISO/IEC 8825-1, 8.1.2, reads the identifier octets and returns
+/// them together with the amount of octets read
+pub fn read_identifier(bytes: &[u8]) -> Result<(Identifier, usize), Error> {
+    let first = *bytes
+        .first()
+        .ok_or_else(Error::insufficient_data_in_source_buffer)?;
+    let constructed = first & CONSTRUCTED != 0;
+    let mut number = usize::from(first & HIGH_TAG_NUMBER);
+    let mut consumed = 1;
+
+    if number == usize::from(HIGH_TAG_NUMBER) {
+        number = 0;
+        loop {
+            let octet = *bytes
+                .get(consumed)
+                .ok_or_else(Error::insufficient_data_in_source_buffer)?;
+            consumed += 1;
+            if number.leading_zeros() < 7 {
+                return Err(ErrorKind::ValueExceedsMaxInt.into());
+            }
+            number = (number << 7) | usize::from(octet & 0x7F);
+            if octet & 0x80 == 0 {
+                break;
+            }
+        }
+    }
+
+    let tag = match first >> 6 {
+        0 => Tag::Universal(number),
+        1 => Tag::Application(number),
+        2 => Tag::ContextSpecific(number),
+        _ => Tag::Private(number),
+    };
+
+    Ok((Identifier { tag, constructed }, consumed))
+}
+
+/// According to ITU-T X.690 | ISO/IEC 8825-1, 8.1.3 and 10.1, writes the length octets in the
+/// definite form and with the minimum number of octets
+pub fn write_length(buffer: &mut Vec<u8>, length: usize) {
+    if length < usize::from(INDEFINITE_LENGTH) {
+        buffer.push(length as u8);
+    } else {
+        let bytes = length.to_be_bytes();
+        let skip = (length.leading_zeros() / 8) as usize;
+        buffer.push(INDEFINITE_LENGTH | (bytes.len() - skip) as u8);
+        buffer.extend_from_slice(&bytes[skip..]);
+    }
+}
+
+/// According to ITU-T X.690 | ISO/IEC 8825-1, 8.1.3, reads the length octets and returns them
+/// together with the amount of octets read
+pub fn read_length(bytes: &[u8]) -> Result<(Length, usize), Error> {
+    let first = *bytes
+        .first()
+        .ok_or_else(Error::insufficient_data_in_source_buffer)?;
+
+    if first < INDEFINITE_LENGTH {
+        Ok((Length::Definite(usize::from(first)), 1))
+    } else if first == INDEFINITE_LENGTH {
+        Ok((Length::Indefinite, 1))
+    } else {
+        let octets = usize::from(first & !INDEFINITE_LENGTH);
+        if octets > core::mem::size_of::<usize>() {
+            return Err(ErrorKind::UnsupportedLength(octets).into());
+        }
+        let length = bytes
+            .get(1..1 + octets)
+            .ok_or_else(Error::insufficient_data_in_source_buffer)?
+            .iter()
+            .fold(0_usize, |length, octet| (length << 8) | usize::from(*octet));
+        Ok((Length::Definite(length), 1 + octets))
+    }
+}
+
+/// According to ITU-T X.690 | ISO/IEC 8825-1, 8.3, writes the contents octets of an integer
+/// value as two's complement binary number in the minimum number of octets
+pub fn write_integer(buffer: &mut impl Extend<u8>, value: i64) {
+    let bytes = value.to_be_bytes();
+    buffer.extend(bytes[redundant_integer_octets(&bytes)..].iter().copied());
+}
+
+/// According to ITU-T X.690 | ISO/IEC 8825-1, 8.3.2, the number of leading octets of a two's
+/// complement binary number that are redundant
+fn redundant_integer_octets(bytes: &[u8]) -> usize {
+    let mut skip = 0;
+    while skip < bytes.len() - 1
+        && ((bytes[skip] == 0x00 && bytes[skip + 1] & 0x80 == 0)
+            || (bytes[skip] == 0xFF && bytes[skip + 1] & 0x80 != 0))
+    {
+        skip += 1;
+    }
+    skip
+}
+
+/// According to ITU-T X.690 | ISO/IEC 8825-1, 8.3, reads the contents octets of an integer value
+pub fn read_integer(tag: Tag, content: &[u8]) -> Result<i64, Error> {
+    if content.is_empty() {
+        Err(ErrorKind::InvalidContent(tag, "an integer consists of at least one octet").into())
+    } else if content.len() > core::mem::size_of::<i64>() {
+        Err(ErrorKind::ValueExceedsMaxInt.into())
+    } else {
+        let sign = if content[0] & 0x80 != 0 { -1_i64 } else { 0 };
+        Ok(content
+            .iter()
+            .fold(sign, |value, octet| (value << 8) | i64::from(*octet)))
+    }
+}
+
+/// According to ITU-T X.690 | ISO/IEC 8825-1, 8.5.9, the special real value PLUS-INFINITY
+const REAL_PLUS_INFINITY: u8 = 0x40;
+/// According to ITU-T X.690 | ISO/IEC 8825-1, 8.5.9, the special real value MINUS-INFINITY
+const REAL_MINUS_INFINITY: u8 = 0x41;
+/// According to ITU-T X.690 | ISO/IEC 8825-1, 8.5.9, the special real value NOT-A-NUMBER
+const REAL_NOT_A_NUMBER: u8 = 0x42;
+/// According to ITU-T X.690 | ISO/IEC 8825-1, 8.5.9, the special real value minus zero
+const REAL_MINUS_ZERO: u8 = 0x43;
+
+/// According to ITU-T X.690 | ISO/IEC 8825-1, 8.5 and 11.3.1, writes the contents octets of a
+/// real value in the canonical form of CER and DER: in base 2 with a mantissa that is either
+/// zero or odd and with the exponent in the minimum number of octets
+pub fn write_real(buffer: &mut impl Extend<u8>, value: f64) {
+    if value.is_nan() {
+        buffer.extend(Some(REAL_NOT_A_NUMBER));
+    } else if value.is_infinite() {
+        buffer.extend(Some(if value.is_sign_positive() {
+            REAL_PLUS_INFINITY
+        } else {
+            REAL_MINUS_INFINITY
+        }));
+    } else if value == 0.0 {
+        // plus zero has no contents octets at all (8.5.2)
+        if value.is_sign_negative() {
+            buffer.extend(Some(REAL_MINUS_ZERO));
+        }
+    } else {
+        let bits = value.to_bits();
+        let biased_exponent = ((bits >> 52) & 0x7FF) as i64;
+        let fraction = bits & 0x000F_FFFF_FFFF_FFFF;
+        let (mantissa, exponent) = if biased_exponent == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | (1 << 52), biased_exponent - 1075)
+        };
+        let shift = mantissa.trailing_zeros();
+        let (mantissa, exponent) = (mantissa >> shift, exponent + i64::from(shift));
+
+        let exponent_octets = exponent.to_be_bytes();
+        let exponent_octets = &exponent_octets[redundant_integer_octets(&exponent_octets)..];
+        let sign = if value.is_sign_negative() { 0x40 } else { 0x00 };
+        buffer.extend(Some(0x80 | sign | (exponent_octets.len() as u8 - 1)));
+        buffer.extend(exponent_octets.iter().copied());
+        let mantissa_octets = mantissa.to_be_bytes();
+        let skip = (mantissa.leading_zeros() / 8) as usize;
+        buffer.extend(mantissa_octets[skip..].iter().copied());
+    }
+}
+
+/// According to ITU-T X.690 | ISO/IEC 8825-1, 8.5, reads the contents octets of a real value in
+/// the binary, decimal or special value form
+pub fn read_real(tag: Tag, content: &[u8]) -> Result<f64, Error> {
+    let first = match content.first() {
+        None => return Ok(0.0),
+        Some(first) => *first,
+    };
+
+    if first & 0x80 != 0 {
+        let sign = if first & 0x40 != 0 { -1.0 } else { 1.0 };
+        let base_bits = match (first >> 4) & 0x03 {
+            0 => 1,
+            1 => 3,
+            2 => 4,
+            _ => return Err(ErrorKind::InvalidContent(tag, "reserved base of a real").into()),
+        };
+        let scale = i64::from((first >> 2) & 0x03);
+        let (exponent_len, exponent_start) = match first & 0x03 {
+            3 => (usize::from(*content.get(1).unwrap_or(&0)), 2),
+            len => (usize::from(len) + 1, 1),
+        };
+        let exponent = content
+            .get(exponent_start..exponent_start + exponent_len)
+            .filter(|octets| !octets.is_empty())
+            .ok_or(ErrorKind::InvalidContent(tag, "missing exponent of a real"))?;
+        let exponent = read_integer(tag, exponent)?;
+        let mantissa = content[exponent_start + exponent_len..]
+            .iter()
+            .fold(0.0_f64, |mantissa, octet| {
+                mantissa * 256.0 + f64::from(*octet)
+            });
+        let exponent = exponent
+            .saturating_mul(base_bits)
+            .saturating_add(scale)
+            .clamp(i64::from(i32::MIN), i64::from(i32::MAX)) as i32;
+        Ok(sign * scale_by_power_of_two(mantissa, exponent))
+    } else if first & 0x40 != 0 {
+        match (first, content.len()) {
+            (REAL_PLUS_INFINITY, 1) => Ok(f64::INFINITY),
+            (REAL_MINUS_INFINITY, 1) => Ok(f64::NEG_INFINITY),
+            (REAL_NOT_A_NUMBER, 1) => Ok(f64::NAN),
+            (REAL_MINUS_ZERO, 1) => Ok(-0.0),
+            _ => Err(ErrorKind::InvalidContent(tag, "unknown special real value").into()),
+        }
+    } else {
+        // ISO 6093 NR1, NR2 or NR3 form (8.5.8)
+        core::str::from_utf8(&content[1..])
+            .ok()
+            .map(|text| text.trim().replace(',', "."))
+            .and_then(|text| text.parse::<f64>().ok())
+            .ok_or_else(|| ErrorKind::InvalidContent(tag, "invalid decimal real").into())
+    }
+}
+
+/// Computes `value * 2^exponent` in steps, so that a large mantissa does not overflow
+/// prematurely and without relying on the floating point functions of `std`
+fn scale_by_power_of_two(mut value: f64, mut exponent: i32) -> f64 {
+    const STEP: i32 = 1000;
+    // exact for all exponents of normal numbers, which are biased by 1023
+    let power_of_two = |exponent: i32| f64::from_bits(((exponent + 1023) as u64) << 52);
+    while exponent > STEP && value.is_finite() && value != 0.0 {
+        value *= power_of_two(STEP);
+        exponent -= STEP;
+    }
+    while exponent < -STEP && value != 0.0 {
+        value *= power_of_two(-STEP);
+        exponent += STEP;
+    }
+    value * power_of_two(exponent.clamp(-STEP, STEP))
+}
+
+/// According to ITU-T X.690 | ISO/IEC 8825-1, 8.19.2, writes a subidentifier as a series of
+/// 7-bit groups, where all but the last octet have bit 8 set
+fn write_subidentifier(buffer: &mut impl Extend<u8>, value: u64) {
+    let groups = ((64 - value.leading_zeros()).max(1)).div_ceil(7);
+    buffer.extend((0..groups).rev().map(|group| {
+        let more = if group > 0 { 0x80 } else { 0x00 };
+        more | ((value >> (group * 7)) & 0x7F) as u8
+    }));
+}
+
+/// According to ITU-T X.690 | ISO/IEC 8825-1, 8.19.2, reads all subidentifiers of the given
+/// contents octets
+fn read_subidentifiers(tag: Tag, content: &[u8]) -> Result<Vec<u64>, Error> {
+    let mut subidentifiers = Vec::with_capacity(content.len());
+    let mut value = 0_u64;
+    let mut first_octet = true;
+    for octet in content {
+        if first_octet && *octet == 0x80 {
+            return Err(ErrorKind::InvalidContent(tag, "subidentifier is not minimal").into());
+        }
+        if value.leading_zeros() < 7 {
+            return Err(ErrorKind::InvalidContent(tag, "subidentifier exceeds 64 bits").into());
+        }
+        value = (value << 7) | u64::from(octet & 0x7F);
+        first_octet = octet & 0x80 == 0;
+        if first_octet {
+            subidentifiers.push(value);
+            value = 0;
+        }
+    }
+    if first_octet {
+        Ok(subidentifiers)
+    } else {
+        Err(ErrorKind::InvalidContent(tag, "truncated subidentifier").into())
+    }
+}
+
+/// According to ITU-T X.690 | ISO/IEC 8825-1, 8.19, writes the contents octets of an object
+/// identifier value, where the first two arcs are combined into one subidentifier
+pub fn write_object_identifier(
+    buffer: &mut impl Extend<u8>,
+    tag: Tag,
+    arcs: &[u64],
+) -> Result<(), Error> {
+    match arcs {
+        [first @ 0..=1, second @ 0..=39, ..] | [first @ 2, second, ..] => {
+            let combined = second
+                .checked_add(first * 40)
+                .ok_or(ErrorKind::InvalidContent(tag, "second arc exceeds 64 bits"))?;
+            write_subidentifier(buffer, combined);
+            write_relative_oid(buffer, &arcs[2..]);
+            Ok(())
+        }
+        [_, _, ..] => Err(ErrorKind::InvalidContent(tag, "invalid first or second arc").into()),
+        _ => Err(ErrorKind::InvalidContent(tag, "less than two arcs").into()),
+    }
+}
+
+/// According to ITU-T X.690 | ISO/IEC 8825-1, 8.19, reads the contents octets of an object
+/// identifier value
+pub fn read_object_identifier(tag: Tag, content: &[u8]) -> Result<Vec<u64>, Error> {
+    let mut arcs = read_subidentifiers(tag, content)?;
+    let combined = match arcs.first() {
+        Some(combined) => *combined,
+        None => return Err(ErrorKind::InvalidContent(tag, "no subidentifier").into()),
+    };
+    let (first, second) = match combined {
+        0..=39 => (0, combined),
+        40..=79 => (1, combined - 40),
+        _ => (2, combined - 80),
+    };
+    arcs[0] = second;
+    arcs.insert(0, first);
+    Ok(arcs)
+}
+
+/// According to ITU-T X.690 | ISO/IEC 8825-1, 8.20, writes the contents octets of a relative
+/// object identifier value
+pub fn write_relative_oid(buffer: &mut impl Extend<u8>, arcs: &[u64]) {
+    for arc in arcs {
+        write_subidentifier(buffer, *arc);
+    }
+}
+
+/// According to ITU-T X.690 | ISO/IEC 8825-1, 8.20, reads the contents octets of a relative
+/// object identifier value
+pub fn read_relative_oid(tag: Tag, content: &[u8]) -> Result<Vec<u64>, Error> {
+    read_subidentifiers(tag, content)
+}
+
+/// According to ITU-T X.690 | ISO/IEC 8825-1, 8.23, writes the contents octets of a character
+/// string, where a BMPString has two octets and a UniversalString has four octets per character
+/// and the ISO/IEC 2022 based strings have a single octet per character. The characters must
+/// be valid for the given [`Charset`].
+pub fn write_string(buffer: &mut impl Extend<u8>, charset: Charset, value: &str) {
+    match charset {
+        Charset::Bmp => buffer.extend(value.chars().flat_map(|c| (c as u16).to_be_bytes())),
+        Charset::Universal => buffer.extend(value.chars().flat_map(|c| (c as u32).to_be_bytes())),
+        Charset::Teletex | Charset::Graphic | Charset::General => {
+            buffer.extend(value.chars().map(|c| c as u8))
+        }
+        _ => buffer.extend(value.bytes()),
+    }
+}
+
+/// According to ITU-T X.690 | ISO/IEC 8825-1, 8.23, reads the contents octets of a character
+/// string, see [`write_string`]. The characters are not checked against the [`Charset`].
+pub fn read_string(tag: Tag, charset: Charset, content: &[u8]) -> Result<String, Error> {
+    let octets_per_char = match charset {
+        Charset::Bmp => 2,
+        Charset::Universal => 4,
+        Charset::Teletex | Charset::Graphic | Charset::General => {
+            return Ok(content.iter().copied().map(char::from).collect())
+        }
+        _ => return Ok(String::from_utf8(content.to_vec()).map_err(ErrorKind::FromUtf8Error)?),
+    };
+    if !content.len().is_multiple_of(octets_per_char) {
+        return Err(ErrorKind::InvalidContent(tag, "truncated character").into());
+    }
+    content
+        .chunks_exact(octets_per_char)
+        .map(|chunk| {
+            let code = chunk
+                .iter()
+                .fold(0_u32, |code, octet| (code << 8) | u32::from(*octet));
+            char::from_u32(code).ok_or_else(|| {
+                ErrorKind::InvalidContent(tag, "invalid character of ISO/IEC 10646").into()
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    fn identifier(tag: Tag, constructed: bool) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        write_identifier(&mut buffer, tag, constructed);
+        buffer
+    }
+
+    #[test]
+    fn test_identifier_low_tag_number() {
+        assert_eq!(&[0x02], &identifier(Tag::DEFAULT_INTEGER, false)[..]);
+        assert_eq!(&[0x30], &identifier(Tag::DEFAULT_SEQUENCE, true)[..]);
+        assert_eq!(&[0x41], &identifier(Tag::Application(1), false)[..]);
+        assert_eq!(&[0xA3], &identifier(Tag::ContextSpecific(3), true)[..]);
+        assert_eq!(&[0xDE], &identifier(Tag::Private(30), false)[..]);
+    }
+
+    #[test]
+    fn test_identifier_high_tag_number() {
+        assert_eq!(
+            &[0x9F, 0x1F],
+            &identifier(Tag::ContextSpecific(31), false)[..]
+        );
+        assert_eq!(
+            &[0x9F, 0x7F],
+            &identifier(Tag::ContextSpecific(127), false)[..]
+        );
+        assert_eq!(
+            &[0xBF, 0x81, 0x00],
+            &identifier(Tag::ContextSpecific(128), true)[..]
+        );
+        assert_eq!(
+            &[0x5F, 0x83, 0xFF, 0x7F],
+            &identifier(Tag::Application(65535), false)[..]
+        );
+    }
+
+    #[test]
+    fn test_identifier_round_trip() {
+        for tag in [
+            Tag::Universal(0),
+            Tag::Application(30),
+            Tag::ContextSpecific(31),
+            Tag::Private(1_000_000),
+        ] {
+            for constructed in [false, true] {
+                let bytes = identifier(tag, constructed);
+                assert_eq!(
+                    (Identifier { tag, constructed }, bytes.len()),
+                    read_identifier(&bytes).unwrap()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_length() {
+        for (length, expected) in [
+            (0_usize, &[0x00][..]),
+            (127, &[0x7F][..]),
+            (128, &[0x81, 0x80][..]),
+            (255, &[0x81, 0xFF][..]),
+            (256, &[0x82, 0x01, 0x00][..]),
+            (65536, &[0x83, 0x01, 0x00, 0x00][..]),
+        ] {
+            let mut buffer = Vec::new();
+            write_length(&mut buffer, length);
+            assert_eq!(expected, &buffer[..]);
+            assert_eq!(
+                (Length::Definite(length), expected.len()),
+                read_length(&buffer).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_length_indefinite_and_insufficient() {
+        assert_eq!((Length::Indefinite, 1), read_length(&[0x80]).unwrap());
+        assert_eq!(
+            &ErrorKind::InsufficientDataInSourceBuffer(crate::io::Backtrace::new_unresolved()),
+            read_length(&[0x82, 0x01]).unwrap_err().kind()
+        );
+    }
+
+    #[test]
+    fn test_integer() {
+        for (value, expected) in [
+            (0_i64, &[0x00][..]),
+            (127, &[0x7F][..]),
+            (128, &[0x00, 0x80][..]),
+            (256, &[0x01, 0x00][..]),
+            (-1, &[0xFF][..]),
+            (-128, &[0x80][..]),
+            (-129, &[0xFF, 0x7F][..]),
+            (
+                i64::MAX,
+                &[0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF][..],
+            ),
+            (
+                i64::MIN,
+                &[0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00][..],
+            ),
+        ] {
+            let mut buffer = Vec::new();
+            write_integer(&mut buffer, value);
+            assert_eq!(expected, &buffer[..]);
+            assert_eq!(value, read_integer(Tag::DEFAULT_INTEGER, &buffer).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_real() {
+        for (value, expected) in [
+            (0.0_f64, &[][..]),
+            (-0.0, &[0x43][..]),
+            (1.0, &[0x80, 0x00, 0x01][..]),
+            (-1.0, &[0xC0, 0x00, 0x01][..]),
+            (0.5, &[0x80, 0xFF, 0x01][..]),
+            (3.0, &[0x80, 0x00, 0x03][..]),
+            (1024.0, &[0x80, 0x0A, 0x01][..]),
+            (f64::INFINITY, &[0x40][..]),
+            (f64::NEG_INFINITY, &[0x41][..]),
+            (f64::MIN_POSITIVE / 4.0, &[0x81, 0xFC, 0x00, 0x01][..]),
+        ] {
+            let mut buffer = Vec::new();
+            write_real(&mut buffer, value);
+            assert_eq!(expected, &buffer[..]);
+            let read = read_real(Tag::DEFAULT_REAL, &buffer).unwrap();
+            assert_eq!(value, read);
+            assert_eq!(value.is_sign_negative(), read.is_sign_negative());
+        }
+
+        let mut buffer = Vec::new();
+        write_real(&mut buffer, f64::NAN);
+        assert_eq!(&[0x42], &buffer[..]);
+        assert!(read_real(Tag::DEFAULT_REAL, &buffer).unwrap().is_nan());
+
+        for value in [
+            core::f64::consts::PI,
+            -1.0e300,
+            123.456e-300,
+            f64::MAX,
+            f64::MIN_POSITIVE,
+        ] {
+            let mut buffer = Vec::new();
+            write_real(&mut buffer, value);
+            assert_eq!(value, read_real(Tag::DEFAULT_REAL, &buffer).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_real_other_forms() {
+        // base 16 with a scaling factor of 1: 3 * 2^1 * 16^1
+        assert_eq!(
+            96.0,
+            read_real(Tag::DEFAULT_REAL, &[0xA4, 0x01, 0x03]).unwrap()
+        );
+        // base 8 with a mantissa that is not odd: 4 * 8^-1
+        assert_eq!(
+            0.5,
+            read_real(Tag::DEFAULT_REAL, &[0x90, 0xFF, 0x04]).unwrap()
+        );
+        // NR3 form
+        assert_eq!(-1.5e3, read_real(Tag::DEFAULT_REAL, b"\x03-1,5E3").unwrap());
+        // exponents beyond the range of f64
+        let huge = read_real(Tag::DEFAULT_REAL, &[0x81, 0x7F, 0xFF, 0x01]).unwrap();
+        assert_eq!(f64::INFINITY, huge);
+        let tiny = read_real(Tag::DEFAULT_REAL, &[0xC1, 0x80, 0x00, 0x01]).unwrap();
+        assert!(tiny == 0.0 && tiny.is_sign_negative());
+        assert!(read_real(Tag::DEFAULT_REAL, &[0xB0, 0x00, 0x01]).is_err());
+        assert!(read_real(Tag::DEFAULT_REAL, &[0x44]).is_err());
+    }
+
+    #[test]
+    fn test_object_identifier() {
+        let tag = Tag::DEFAULT_OBJECT_IDENTIFIER;
+        for (arcs, bytes) in &[
+            (
+                &[1_u64, 2, 840, 113_549][..],
+                &[0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D][..],
+            ),
+            (&[2, 999, 3], &[0x88, 0x37, 0x03]),
+            (&[0, 0], &[0x00]),
+            (&[2, 100], &[0x81, 0x34]),
+        ] {
+            let mut buffer = Vec::new();
+            write_object_identifier(&mut buffer, tag, arcs).unwrap();
+            assert_eq!(*bytes, &buffer[..]);
+            assert_eq!(*arcs, &read_object_identifier(tag, bytes).unwrap()[..]);
+        }
+
+        assert!(write_object_identifier(&mut Vec::new(), tag, &[1]).is_err());
+        assert!(write_object_identifier(&mut Vec::new(), tag, &[1, 40]).is_err());
+        assert!(write_object_identifier(&mut Vec::new(), tag, &[3, 1]).is_err());
+        assert!(read_object_identifier(tag, &[]).is_err());
+        assert!(read_object_identifier(tag, &[0x2A, 0x86]).is_err());
+        assert!(read_object_identifier(tag, &[0x2A, 0x80, 0x01]).is_err());
+    }
+
+    #[test]
+    fn test_string() {
+        let value = "A\u{E9}\u{20AC}";
+        for (charset, bytes) in &[
+            (Charset::Bmp, &[0x00, 0x41, 0x00, 0xE9, 0x20, 0xAC][..]),
+            (
+                Charset::Universal,
+                &[0, 0, 0, 0x41, 0, 0, 0, 0xE9, 0, 0, 0x20, 0xAC][..],
+            ),
+        ] {
+            let mut buffer = Vec::new();
+            write_string(&mut buffer, *charset, value);
+            assert_eq!(*bytes, &buffer[..]);
+            assert_eq!(
+                value,
+                read_string(Tag::DEFAULT_BMP_STRING, *charset, bytes).unwrap()
+            );
+        }
+
+        let mut buffer = Vec::new();
+        write_string(&mut buffer, Charset::Teletex, "A\u{E9}");
+        assert_eq!(&[0x41, 0xE9], &buffer[..]);
+        assert_eq!(
+            "A\u{E9}",
+            read_string(Tag::DEFAULT_TELETEXT_STRING, Charset::Teletex, &buffer).unwrap()
+        );
+
+        assert!(read_string(Tag::DEFAULT_BMP_STRING, Charset::Bmp, &[0x00]).is_err());
+        assert!(read_string(Tag::DEFAULT_BMP_STRING, Charset::Bmp, &[0xD8, 0x00]).is_err());
+        assert!(read_string(
+            Tag::DEFAULT_BMP_STRING,
+            Charset::Universal,
+            &[0, 0x11, 0, 0]
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_relative_oid() {
+        let mut buffer = Vec::new();
+        write_relative_oid(&mut buffer, &[8571, 3, 2]);
+        assert_eq!(&[0xC2, 0x7B, 0x03, 0x02], &buffer[..]);
+        assert_eq!(
+            vec![8571, 3, 2],
+            read_relative_oid(Tag::DEFAULT_RELATIVE_OID, &buffer).unwrap()
+        );
+        assert!(read_relative_oid(Tag::DEFAULT_RELATIVE_OID, &[])
+            .unwrap()
+            .is_empty());
+    }
+}
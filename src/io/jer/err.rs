@@ -0,0 +1,132 @@
+use crate::model::Charset;
+use alloc::boxed::Box;
+use alloc::string::String;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Error(pub(crate) Box<ErrorKind>);
+
+impl Error {
+    #[inline]
+    pub fn kind(&self) -> &ErrorKind {
+        &self.0
+    }
+}
+
+impl From<ErrorKind> for Error {
+    #[cold]
+    #[inline(never)]
+    fn from(kind: ErrorKind) -> Self {
+        Self(Box::new(kind))
+    }
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl core::error::Error for Error {
+    fn description(&self) -> &str {
+        "encoding or decoding JER failed"
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ErrorKind {
+    InvalidString(Charset, char, usize),
+    InvalidJson {
+        offset: usize,
+        reason: &'static str,
+    },
+    UnexpectedValue {
+        expected: &'static str,
+        found: &'static str,
+    },
+    MissingIdentifier,
+    MissingMember(&'static str),
+    NoMoreValues,
+    UnknownIdentifier(String),
+    InvalidContent(&'static str),
+    InvalidChoiceIndex(u64, u64),
+    /// The open type of the given name is not selected by a key of its object set
+    InvalidOpenTypeKey(&'static str),
+    ValueNotInRange(i64, i64, i64),
+    ValueExceedsMaxInt,
+    SizeNotInRange(u64, u64, u64),
+    /// The component stored in the given field violates its inner type constraint
+    ComponentConstraintViolated(&'static str, crate::syn::validate::ViolationKind),
+}
+
+impl Error {
+    #[cold]
+    #[inline(never)]
+    pub fn ensure_string_valid(charset: Charset, str: &str) -> Result<(), Self> {
+        match charset.find_invalid(str) {
+            None => Ok(()),
+            Some((index, char)) => Err(ErrorKind::InvalidString(charset, char, index).into()),
+        }
+    }
+}
+
+impl core::fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidString(charset, char, index) => {
+                write!(
+                    f,
+                    "Invalid character for a string with the charset {:?} at index {}: {}",
+                    charset, index, char
+                )
+            }
+            Self::InvalidJson { offset, reason } => {
+                write!(f, "Invalid JSON text at offset {}: {}", offset, reason)
+            }
+            Self::UnexpectedValue { expected, found } => {
+                write!(f, "Expected a JSON {} but found a JSON {}", expected, found)
+            }
+            Self::MissingIdentifier => write!(
+                f,
+                "The value is a member of an object but its constraint has no identifier"
+            ),
+            Self::MissingMember(identifier) => {
+                write!(f, "The object has no member named '{}'", identifier)
+            }
+            Self::NoMoreValues => write!(f, "There are no more values to read"),
+            Self::UnknownIdentifier(identifier) => {
+                write!(f, "The identifier '{}' is unknown", identifier)
+            }
+            Self::InvalidContent(reason) => write!(f, "The value is invalid: {}", reason),
+            Self::InvalidChoiceIndex(index, variant_count) => write!(
+                f,
+                "Unexpected choice-index {} with variant count {}",
+                index, variant_count
+            ),
+            Self::InvalidOpenTypeKey(name) => write!(
+                f,
+                "The open type {} is not selected by a key of its object set",
+                name
+            ),
+            Self::ValueNotInRange(value, min, max) => write!(
+                f,
+                "The value {} is not within the inclusive range of {} and {}",
+                value, min, max
+            ),
+            Self::ValueExceedsMaxInt => {
+                write!(f, "The value exceeds the maximum supported integer size",)
+            }
+            Self::SizeNotInRange(size, min, max) => write!(
+                f,
+                "The size {} is not within the inclusive range of {} and {}",
+                size, min, max
+            ),
+            Self::ComponentConstraintViolated(field, kind) => {
+                write!(
+                    f,
+                    "The component {} violates its constraint: {}",
+                    field, kind
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,389 @@
+//! This module contains the building blocks of the JSON Encoding Rules (JER) according to
+//! ITU-T X.697 | ISO/IEC 8825-8. Every value is represented as JSON value (IETF RFC 8259).
+
+pub mod err;
+
+pub use err::Error;
+pub use err::ErrorKind;
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// A parsed JSON value. Numbers keep their textual representation and the members of an object
+/// keep their order of appearance.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    /// The name of the kind of this JSON value
+    pub const fn kind(&self) -> &'static str {
+        match self {
+            Value::Null => "null",
+            Value::Bool(_) => "boolean",
+            Value::Number(_) => "number",
+            Value::String(_) => "string",
+            Value::Array(_) => "array",
+            Value::Object(_) => "object",
+        }
+    }
+}
+
+/// Parses a JSON text, which consists of exactly one value surrounded by optional whitespace
+pub fn parse(text: &str) -> Result<Value, Error> {
+    let mut parser = Parser {
+        bytes: text.as_bytes(),
+        position: 0,
+    };
+    let value = parser.parse_value()?;
+    parser.skip_whitespace();
+    if parser.position < parser.bytes.len() {
+        Err(parser.error("unexpected trailing characters"))
+    } else {
+        Ok(value)
+    }
+}
+
+struct Parser<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl Parser<'_> {
+    #[cold]
+    fn error(&self, reason: &'static str) -> Error {
+        ErrorKind::InvalidJson {
+            offset: self.position,
+            reason,
+        }
+        .into()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.bytes.get(self.position) {
+            self.position += 1;
+        }
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_whitespace();
+        self.bytes.get(self.position).copied()
+    }
+
+    fn expect(&mut self, byte: u8, reason: &'static str) -> Result<(), Error> {
+        if self.peek() == Some(byte) {
+            self.position += 1;
+            Ok(())
+        } else {
+            Err(self.error(reason))
+        }
+    }
+
+    fn expect_literal(&mut self, literal: &str, value: Value) -> Result<Value, Error> {
+        if self.bytes[self.position..].starts_with(literal.as_bytes()) {
+            self.position += literal.len();
+            Ok(value)
+        } else {
+            Err(self.error("invalid literal"))
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<Value, Error> {
+        match self.peek() {
+            Some(b'{') => self.parse_object(),
+            Some(b'[') => self.parse_array(),
+            Some(b'"') => self.parse_string().map(Value::String),
+            Some(b't') => self.expect_literal("true", Value::Bool(true)),
+            Some(b'f') => self.expect_literal("false", Value::Bool(false)),
+            Some(b'n') => self.expect_literal("null", Value::Null),
+            Some(b'-' | b'0'..=b'9') => self.parse_number(),
+            Some(_) => Err(self.error("unexpected character")),
+            None => Err(self.error("unexpected end of text")),
+        }
+    }
+
+    fn parse_object(&mut self) -> Result<Value, Error> {
+        self.expect(b'{', "expected begin of object")?;
+        let mut members = Vec::new();
+        if self.peek() == Some(b'}') {
+            self.position += 1;
+            return Ok(Value::Object(members));
+        }
+        loop {
+            if self.peek() != Some(b'"') {
+                return Err(self.error("expected name of member"));
+            }
+            let name = self.parse_string()?;
+            self.expect(b':', "expected name separator")?;
+            members.push((name, self.parse_value()?));
+            match self.peek() {
+                Some(b',') => self.position += 1,
+                Some(b'}') => {
+                    self.position += 1;
+                    return Ok(Value::Object(members));
+                }
+                _ => return Err(self.error("expected value separator or end of object")),
+            }
+        }
+    }
+
+    fn parse_array(&mut self) -> Result<Value, Error> {
+        self.expect(b'[', "expected begin of array")?;
+        let mut values = Vec::new();
+        if self.peek() == Some(b']') {
+            self.position += 1;
+            return Ok(Value::Array(values));
+        }
+        loop {
+            values.push(self.parse_value()?);
+            match self.peek() {
+                Some(b',') => self.position += 1,
+                Some(b']') => {
+                    self.position += 1;
+                    return Ok(Value::Array(values));
+                }
+                _ => return Err(self.error("expected value separator or end of array")),
+            }
+        }
+    }
+
+    fn parse_number(&mut self) -> Result<Value, Error> {
+        let start = self.position;
+        let digits = |parser: &mut Self| {
+            let start = parser.position;
+            while let Some(b'0'..=b'9') = parser.bytes.get(parser.position) {
+                parser.position += 1;
+            }
+            parser.position - start
+        };
+
+        if self.bytes.get(self.position) == Some(&b'-') {
+            self.position += 1;
+        }
+        let leading_zero = self.bytes.get(self.position) == Some(&b'0');
+        match digits(self) {
+            0 => return Err(self.error("expected digit")),
+            1 => {}
+            _ if leading_zero => return Err(self.error("leading zeros are not allowed")),
+            _ => {}
+        }
+        if self.bytes.get(self.position) == Some(&b'.') {
+            self.position += 1;
+            if digits(self) == 0 {
+                return Err(self.error("expected digit of fraction"));
+            }
+        }
+        if let Some(b'e' | b'E') = self.bytes.get(self.position) {
+            self.position += 1;
+            if let Some(b'+' | b'-') = self.bytes.get(self.position) {
+                self.position += 1;
+            }
+            if digits(self) == 0 {
+                return Err(self.error("expected digit of exponent"));
+            }
+        }
+
+        // the number consists of ASCII characters only
+        let number = String::from_utf8_lossy(&self.bytes[start..self.position]).into_owned();
+        Ok(Value::Number(number))
+    }
+
+    fn parse_string(&mut self) -> Result<String, Error> {
+        self.expect(b'"', "expected begin of string")?;
+        let mut bytes = Vec::new();
+        loop {
+            match self.bytes.get(self.position).copied() {
+                None => return Err(self.error("unterminated string")),
+                Some(b'"') => {
+                    self.position += 1;
+                    break;
+                }
+                Some(b'\\') => {
+                    self.position += 1;
+                    let escaped = self
+                        .bytes
+                        .get(self.position)
+                        .copied()
+                        .ok_or_else(|| self.error("unterminated escape sequence"))?;
+                    self.position += 1;
+                    let char = match escaped {
+                        b'"' => '"',
+                        b'\\' => '\\',
+                        b'/' => '/',
+                        b'b' => '\u{08}',
+                        b'f' => '\u{0C}',
+                        b'n' => '\n',
+                        b'r' => '\r',
+                        b't' => '\t',
+                        b'u' => self.parse_unicode_escape()?,
+                        _ => return Err(self.error("invalid escape sequence")),
+                    };
+                    let mut buffer = [0_u8; 4];
+                    bytes.extend_from_slice(char.encode_utf8(&mut buffer).as_bytes());
+                }
+                Some(byte) if byte < 0x20 => {
+                    return Err(self.error("unescaped control character in string"))
+                }
+                Some(byte) => {
+                    bytes.push(byte);
+                    self.position += 1;
+                }
+            }
+        }
+        // the input is a valid str and escape sequences are appended as valid UTF-8
+        String::from_utf8(bytes).map_err(|_| self.error("invalid UTF-8 in string"))
+    }
+
+    fn parse_hex4(&mut self) -> Result<u32, Error> {
+        let hex = self
+            .bytes
+            .get(self.position..self.position + 4)
+            .and_then(|hex| core::str::from_utf8(hex).ok())
+            .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+            .ok_or_else(|| self.error("invalid unicode escape sequence"))?;
+        self.position += 4;
+        Ok(hex)
+    }
+
+    fn parse_unicode_escape(&mut self) -> Result<char, Error> {
+        let high = self.parse_hex4()?;
+        let code = if (0xD800..0xDC00).contains(&high) {
+            if !self.bytes[self.position..].starts_with(b"\\u") {
+                return Err(self.error("expected low surrogate"));
+            }
+            self.position += 2;
+            let low = self.parse_hex4()?;
+            if !(0xDC00..0xE000).contains(&low) {
+                return Err(self.error("expected low surrogate"));
+            }
+            0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+        } else {
+            high
+        };
+        char::from_u32(code).ok_or_else(|| self.error("invalid unicode code point"))
+    }
+}
+
+/// Writes the given string as JSON string, escaping quotation marks, reverse solidi and control
+/// characters
+pub fn write_string(buffer: &mut String, value: &str) {
+    buffer.push('"');
+    for char in value.chars() {
+        match char {
+            '"' => buffer.push_str("\\\""),
+            '\\' => buffer.push_str("\\\\"),
+            '\n' => buffer.push_str("\\n"),
+            '\r' => buffer.push_str("\\r"),
+            '\t' => buffer.push_str("\\t"),
+            c if (c as u32) < 0x20 => buffer.push_str(&format!("\\u{:04X}", c as u32)),
+            c => buffer.push(c),
+        }
+    }
+    buffer.push('"');
+}
+
+/// According to ITU-T X.697 | ISO/IEC 8825-8, 23 and 24, octets are represented by two
+/// hexadecimal digits each
+pub fn write_hex(buffer: &mut String, octets: &[u8]) {
+    const DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+    buffer.push('"');
+    for octet in octets {
+        buffer.push(char::from(DIGITS[usize::from(octet >> 4)]));
+        buffer.push(char::from(DIGITS[usize::from(octet & 0x0F)]));
+    }
+    buffer.push('"');
+}
+
+/// Reads the octets of a string of hexadecimal digits, which are accepted in upper and lower case
+pub fn read_hex(hex: &str) -> Result<Vec<u8>, Error> {
+    if !hex.len().is_multiple_of(2) {
+        return Err(ErrorKind::InvalidContent("odd number of hexadecimal digits").into());
+    }
+    hex.as_bytes()
+        .chunks(2)
+        .map(|pair| {
+            core::str::from_utf8(pair)
+                .ok()
+                .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+                .ok_or_else(|| ErrorKind::InvalidContent("invalid hexadecimal digit").into())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_values() {
+        assert_eq!(
+            Value::Object(vec![
+                ("a".to_string(), Value::Number("-12".to_string())),
+                (
+                    "b".to_string(),
+                    Value::Array(vec![
+                        Value::Bool(true),
+                        Value::Bool(false),
+                        Value::Null,
+                        Value::Number("1.5e3".to_string())
+                    ])
+                ),
+                ("c".to_string(), Value::Object(Vec::new())),
+            ]),
+            parse(" { \"a\" : -12 , \"b\":[true,false,null,1.5e3], \"c\": {} }\n").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_string_escapes() {
+        assert_eq!(
+            Value::String("\"\\/\u{08}\u{0C}\n\r\tä😀".to_string()),
+            parse(r#""\"\\\/\b\f\n\r\tä😀""#).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        for (text, offset) in [
+            ("", 0),
+            ("[1,]", 3),
+            ("{\"a\" 1}", 5),
+            ("01", 2),
+            ("\"abc", 4),
+            ("tru", 0),
+            ("1 2", 2),
+        ] {
+            assert!(
+                matches!(parse(text).unwrap_err().kind(), ErrorKind::InvalidJson { offset: o, .. } if *o == offset),
+                "{}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn test_write_string_round_trip() {
+        let value = "quote\" backslash\\ newline\n bell\u{07} ä";
+        let mut buffer = String::new();
+        write_string(&mut buffer, value);
+        assert_eq!(r#""quote\" backslash\\ newline\n bell\u0007 ä""#, buffer);
+        assert_eq!(Value::String(value.to_string()), parse(&buffer).unwrap());
+    }
+
+    #[test]
+    fn test_hex() {
+        let mut buffer = String::new();
+        write_hex(&mut buffer, &[0x00, 0x0F, 0xA0, 0xFF]);
+        assert_eq!("\"000FA0FF\"", buffer);
+        assert_eq!(vec![0x00, 0x0F, 0xA0, 0xFF], read_hex("000fa0FF").unwrap());
+        assert!(read_hex("ABC").is_err());
+        assert!(read_hex("GG").is_err());
+    }
+}
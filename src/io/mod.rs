@@ -3,7 +3,11 @@
 //!      ::io::per                  Generic Packed Encoding impls and traits
 //!      ::io::per::unaligned       UNALIGNED PER specialization
 //!      ::io::per::aligned         ALIGNED PER specialization
-//!      ::io::...                  Other ASN.1 representations (e.g xer, ber, ...)
+//!      ::io::ber                  Basic and Distinguished Encoding Rules
+//!      ::io::jer                  JSON Encoding Rules
+//!      ::io::xer                  XML Encoding Rules
+//!      ::io::oer                  Octet Encoding Rules
+//!      ::io::...                  Other ASN.1 representations
 //!
 //!      ::io::async_psql           Async PSQL io-utils
 //!      ::io::protobuf             Protocol Buffer io-utils
@@ -12,8 +16,13 @@
 //!      ::io::uper                 Deprecated UNALIGNED PER decoder/encoder
 //! ```
 
+pub mod ber;
+pub mod jer;
+pub mod oer;
 pub mod per;
+#[cfg(feature = "std")]
 pub mod protobuf;
+pub mod xer;
 
 #[cfg(feature = "psql")]
 pub mod psql;
@@ -23,3 +32,22 @@ pub mod async_psql;
 
 #[cfg(any(feature = "psql", feature = "async-psql"))]
 pub mod psql_shared;
+
+#[cfg(feature = "std")]
+pub use backtrace::Backtrace;
+
+/// Backtraces cannot be captured without `std`, so errors carry this placeholder instead
+#[cfg(not(feature = "std"))]
+#[derive(Debug, Default, Clone)]
+pub struct Backtrace;
+
+#[cfg(not(feature = "std"))]
+impl Backtrace {
+    #[inline]
+    pub fn new_unresolved() -> Self {
+        Self
+    }
+
+    #[inline]
+    pub fn resolve(&mut self) {}
+}
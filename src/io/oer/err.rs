@@ -0,0 +1,155 @@
+use crate::model::{Charset, Tag};
+use alloc::boxed::Box;
+use alloc::string::FromUtf8Error;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Error(pub(crate) Box<ErrorKind>);
+
+impl Error {
+    #[inline]
+    pub fn kind(&self) -> &ErrorKind {
+        &self.0
+    }
+}
+
+impl From<ErrorKind> for Error {
+    #[cold]
+    #[inline(never)]
+    fn from(kind: ErrorKind) -> Self {
+        Self(Box::new(kind))
+    }
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl core::error::Error for Error {
+    fn description(&self) -> &str {
+        "encoding or decoding OER failed"
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ErrorKind {
+    FromUtf8Error(FromUtf8Error),
+    InvalidString(Charset, char, usize),
+    InsufficientDataInSourceBuffer {
+        offset: usize,
+    },
+    UnsupportedLength(usize),
+    UnexpectedChoiceTag {
+        found: Tag,
+        offset: usize,
+    },
+    ContentNotFullyConsumed(usize),
+    MissingExtensionAddition(&'static str),
+    InvalidContent(&'static str),
+    InvalidChoiceIndex(u64, u64),
+    /// The open type of the given name is not selected by a key of its object set
+    InvalidOpenTypeKey(&'static str),
+    ValueNotInRange(i64, i64, i64),
+    ValueExceedsMaxInt,
+    SizeNotInRange(u64, u64, u64),
+    /// The component stored in the given field violates its inner type constraint
+    ComponentConstraintViolated(&'static str, crate::syn::validate::ViolationKind),
+}
+
+impl Error {
+    #[cold]
+    #[inline(never)]
+    pub fn ensure_string_valid(charset: Charset, str: &str) -> Result<(), Self> {
+        match charset.find_invalid(str) {
+            None => Ok(()),
+            Some((index, char)) => Err(ErrorKind::InvalidString(charset, char, index).into()),
+        }
+    }
+
+    #[cold]
+    #[inline(never)]
+    pub fn insufficient_data_in_source_buffer(offset: usize) -> Self {
+        ErrorKind::InsufficientDataInSourceBuffer { offset }.into()
+    }
+}
+
+impl From<FromUtf8Error> for ErrorKind {
+    fn from(e: FromUtf8Error) -> Self {
+        ErrorKind::FromUtf8Error(e)
+    }
+}
+
+impl core::fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::FromUtf8Error(err) => {
+                write!(f, "Failed to call String::from_utf8: ")?;
+                err.fmt(f)
+            }
+            Self::InvalidString(charset, char, index) => {
+                write!(
+                    f,
+                    "Invalid character for a string with the charset {:?} at index {}: {}",
+                    charset, index, char
+                )
+            }
+            Self::InsufficientDataInSourceBuffer { offset } => write!(
+                f,
+                "There is insufficient data in the source buffer at offset {}",
+                offset
+            ),
+            Self::UnsupportedLength(octets) => write!(
+                f,
+                "A length that is encoded in {} octets is not supported",
+                octets
+            ),
+            Self::UnexpectedChoiceTag { found, offset } => write!(
+                f,
+                "No alternative of the choice has the tag {:?} found at offset {}",
+                found, offset
+            ),
+            Self::ContentNotFullyConsumed(remaining) => write!(
+                f,
+                "The open type was not fully consumed, {} octets remain",
+                remaining
+            ),
+            Self::MissingExtensionAddition(name) => write!(
+                f,
+                "A mandatory extension addition of {} is not present",
+                name
+            ),
+            Self::InvalidContent(reason) => write!(f, "The encoding is invalid: {}", reason),
+            Self::InvalidChoiceIndex(index, variant_count) => write!(
+                f,
+                "Unexpected choice-index {} with variant count {}",
+                index, variant_count
+            ),
+            Self::InvalidOpenTypeKey(name) => write!(
+                f,
+                "The open type {} is not selected by a key of its object set",
+                name
+            ),
+            Self::ValueNotInRange(value, min, max) => write!(
+                f,
+                "The value {} is not within the inclusive range of {} and {}",
+                value, min, max
+            ),
+            Self::ValueExceedsMaxInt => {
+                write!(f, "The value exceeds the maximum supported integer size",)
+            }
+            Self::SizeNotInRange(size, min, max) => write!(
+                f,
+                "The size {} is not within the inclusive range of {} and {}",
+                size, min, max
+            ),
+            Self::ComponentConstraintViolated(field, kind) => {
+                write!(
+                    f,
+                    "The component {} violates its constraint: {}",
+                    field, kind
+                )
+            }
+        }
+    }
+}
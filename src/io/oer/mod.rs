@@ -0,0 +1,282 @@
+//! This module contains the building blocks of the Octet Encoding Rules (OER) and its canonical
+//! variant (COER) according to ITU-T X.696 | ISO/IEC 8825-7. Every value is encoded in a whole
+//! number of octets, fixed size values are encoded without any length determinant.
+
+pub mod err;
+
+pub use err::Error;
+pub use err::ErrorKind;
+
+use crate::model::Tag;
+use alloc::vec::Vec;
+
+/// According to ITU-T X.696 | ISO/IEC 8825-7, 8.6.4, the long form of the length determinant
+const LONG_FORM: u8 = 0x80;
+/// According to ITU-T X.696 | ISO/IEC 8825-7, 8.7.2, tag numbers from 63 upwards are encoded in
+/// subsequent octets
+const HIGH_TAG_NUMBER: u8 = 0x3F;
+
+/// According to ITU-T X.696 | ISO/IEC 8825-7, 10.3 and 10.4, the number of octets of an integer
+/// with the given bounds - if it has a fixed size - and whether it is signed
+pub fn fixed_integer_size(min: Option<i64>, max: Option<i64>) -> Option<(usize, bool)> {
+    let (min, max) = (min?, max?);
+    let size = if min >= 0 {
+        match max {
+            0..=0xFF => 1,
+            0x100..=0xFFFF => 2,
+            0x1_0000..=0xFFFF_FFFF => 4,
+            _ => 8,
+        }
+    } else if min >= i64::from(i8::MIN) && max <= i64::from(i8::MAX) {
+        1
+    } else if min >= i64::from(i16::MIN) && max <= i64::from(i16::MAX) {
+        2
+    } else if min >= i64::from(i32::MIN) && max <= i64::from(i32::MAX) {
+        4
+    } else {
+        8
+    };
+    Some((size, min < 0))
+}
+
+/// According to ITU-T X.696 | ISO/IEC 8825-7, 8.6, writes the length determinant in the short
+/// form for lengths up to 127 and in the long form with the minimum number of octets otherwise
+pub fn write_length(buffer: &mut Vec<u8>, length: usize) {
+    if length < usize::from(LONG_FORM) {
+        buffer.push(length as u8);
+    } else {
+        let bytes = length.to_be_bytes();
+        let skip = (length.leading_zeros() / 8) as usize;
+        buffer.push(LONG_FORM | (bytes.len() - skip) as u8);
+        buffer.extend_from_slice(&bytes[skip..]);
+    }
+}
+
+/// According to ITU-T X.696 | ISO/IEC 8825-7, 8.6, reads the length determinant and returns it
+/// together with the amount of octets read
+pub fn read_length(bytes: &[u8]) -> Result<(usize, usize), Error> {
+    let first = *bytes
+        .first()
+        .ok_or_else(|| Error::insufficient_data_in_source_buffer(0))?;
+    if first < LONG_FORM {
+        Ok((usize::from(first), 1))
+    } else {
+        let octets = usize::from(first & !LONG_FORM);
+        if octets == 0 || octets > core::mem::size_of::<usize>() {
+            return Err(ErrorKind::UnsupportedLength(octets).into());
+        }
+        let length = bytes
+            .get(1..1 + octets)
+            .ok_or_else(|| Error::insufficient_data_in_source_buffer(1))?
+            .iter()
+            .fold(0_usize, |length, octet| (length << 8) | usize::from(*octet));
+        Ok((length, 1 + octets))
+    }
+}
+
+/// According to ITU-T X.696 | ISO/IEC 8825-7, 8.7, writes the tag of the chosen alternative of
+/// a CHOICE value
+pub fn write_tag(buffer: &mut Vec<u8>, tag: Tag) {
+    let (class, number) = match tag {
+        Tag::Universal(number) => (0x00, number),
+        Tag::Application(number) => (0x40, number),
+        Tag::ContextSpecific(number) => (0x80, number),
+        Tag::Private(number) => (0xC0, number),
+    };
+
+    if number < usize::from(HIGH_TAG_NUMBER) {
+        buffer.push(class | number as u8);
+    } else {
+        buffer.push(class | HIGH_TAG_NUMBER);
+        let septets = (usize::BITS - number.leading_zeros()).div_ceil(7);
+        for i in (0..septets).rev() {
+            let septet = ((number >> (i * 7)) & 0x7F) as u8;
+            buffer.push(if i > 0 { septet | 0x80 } else { septet });
+        }
+    }
+}
+
+/// According to ITU-T X.696 | ISO/IEC 8825-7, 8.7, reads the tag of the chosen alternative of a
+/// CHOICE value and returns it together with the amount of octets read
+pub fn read_tag(bytes: &[u8]) -> Result<(Tag, usize), Error> {
+    let first = *bytes
+        .first()
+        .ok_or_else(|| Error::insufficient_data_in_source_buffer(0))?;
+    let mut number = usize::from(first & HIGH_TAG_NUMBER);
+    let mut consumed = 1;
+
+    if number == usize::from(HIGH_TAG_NUMBER) {
+        number = 0;
+        loop {
+            let octet = *bytes
+                .get(consumed)
+                .ok_or_else(|| Error::insufficient_data_in_source_buffer(consumed))?;
+            consumed += 1;
+            if number.leading_zeros() < 7 {
+                return Err(ErrorKind::ValueExceedsMaxInt.into());
+            }
+            number = (number << 7) | usize::from(octet & 0x7F);
+            if octet & 0x80 == 0 {
+                break;
+            }
+        }
+    }
+
+    let tag = match first >> 6 {
+        0 => Tag::Universal(number),
+        1 => Tag::Application(number),
+        2 => Tag::ContextSpecific(number),
+        _ => Tag::Private(number),
+    };
+    Ok((tag, consumed))
+}
+
+/// According to ITU-T X.696 | ISO/IEC 8825-7, 10.3 and 10.4, writes the integer in exactly
+/// `size` octets
+pub fn write_fixed_integer(buffer: &mut Vec<u8>, value: i64, size: usize) {
+    buffer.extend_from_slice(&value.to_be_bytes()[core::mem::size_of::<i64>() - size..]);
+}
+
+/// Reads an integer from exactly the given octets, as two's complement binary number if `signed`
+pub fn read_fixed_integer(octets: &[u8], signed: bool) -> Result<i64, Error> {
+    if octets.len() > core::mem::size_of::<i64>()
+        || (!signed && octets.len() == core::mem::size_of::<i64>() && octets[0] & 0x80 != 0)
+    {
+        return Err(ErrorKind::ValueExceedsMaxInt.into());
+    }
+    let sign = if signed && octets.first().is_some_and(|octet| octet & 0x80 != 0) {
+        -1_i64
+    } else {
+        0
+    };
+    Ok(octets
+        .iter()
+        .fold(sign, |value, octet| (value << 8) | i64::from(*octet)))
+}
+
+/// According to ITU-T X.696 | ISO/IEC 8825-7, 10.6 and 10.8, writes the integer in the minimum
+/// number of octets, as two's complement binary number if `signed`
+pub fn write_variable_integer(buffer: &mut Vec<u8>, value: i64, signed: bool) {
+    let bytes = value.to_be_bytes();
+    let mut skip = 0;
+    while skip < bytes.len() - 1
+        && ((bytes[skip] == 0x00 && (!signed || bytes[skip + 1] & 0x80 == 0))
+            || (signed && bytes[skip] == 0xFF && bytes[skip + 1] & 0x80 != 0))
+    {
+        skip += 1;
+    }
+    buffer.extend_from_slice(&bytes[skip..]);
+}
+
+/// Writes the given bits into whole octets, the unused bits of the final octet are zero
+pub fn write_bitmap<I: IntoIterator<Item = bool>>(buffer: &mut Vec<u8>, bits: I) {
+    for (index, bit) in bits.into_iter().enumerate() {
+        if index % 8 == 0 {
+            buffer.push(0x00);
+        }
+        if bit {
+            *buffer.last_mut().unwrap() |= 0x80 >> (index % 8);
+        }
+    }
+}
+
+/// Reads the given amount of bits from whole octets
+pub fn read_bitmap(octets: &[u8], bits: usize) -> Vec<bool> {
+    (0..bits)
+        .map(|index| octets[index / 8] & (0x80 >> (index % 8)) != 0)
+        .collect()
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn test_length() {
+        for (length, expected) in [
+            (0_usize, &[0x00][..]),
+            (127, &[0x7F][..]),
+            (128, &[0x81, 0x80][..]),
+            (256, &[0x82, 0x01, 0x00][..]),
+        ] {
+            let mut buffer = Vec::new();
+            write_length(&mut buffer, length);
+            assert_eq!(expected, &buffer[..]);
+            assert_eq!((length, expected.len()), read_length(&buffer).unwrap());
+        }
+        assert!(read_length(&[0x80]).is_err());
+        assert!(read_length(&[0x82, 0x01]).is_err());
+    }
+
+    #[test]
+    fn test_tag() {
+        for (tag, expected) in [
+            (Tag::ContextSpecific(0), &[0x80][..]),
+            (Tag::Universal(2), &[0x02][..]),
+            (Tag::Application(62), &[0x7E][..]),
+            (Tag::ContextSpecific(63), &[0xBF, 0x3F][..]),
+            (Tag::Private(128), &[0xFF, 0x81, 0x00][..]),
+        ] {
+            let mut buffer = Vec::new();
+            write_tag(&mut buffer, tag);
+            assert_eq!(expected, &buffer[..]);
+            assert_eq!((tag, expected.len()), read_tag(&buffer).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_fixed_integer_size() {
+        assert_eq!(Some((1, false)), fixed_integer_size(Some(0), Some(255)));
+        assert_eq!(Some((2, false)), fixed_integer_size(Some(0), Some(256)));
+        assert_eq!(Some((4, false)), fixed_integer_size(Some(1), Some(70_000)));
+        assert_eq!(
+            Some((8, false)),
+            fixed_integer_size(Some(0), Some(i64::MAX))
+        );
+        assert_eq!(Some((1, true)), fixed_integer_size(Some(-128), Some(127)));
+        assert_eq!(Some((2, true)), fixed_integer_size(Some(-1), Some(128)));
+        assert_eq!(Some((4, true)), fixed_integer_size(Some(-40_000), Some(0)));
+        assert_eq!(Some((8, true)), fixed_integer_size(Some(i64::MIN), Some(0)));
+        assert_eq!(None, fixed_integer_size(Some(0), None));
+        assert_eq!(None, fixed_integer_size(None, Some(0)));
+    }
+
+    #[test]
+    fn test_bitmap() {
+        let mut buffer = Vec::new();
+        write_bitmap(
+            &mut buffer,
+            [true, false, true, true, false, false, false, false, true],
+        );
+        assert_eq!(&[0xB0, 0x80], &buffer[..]);
+        assert_eq!(vec![true, false, true, true], read_bitmap(&buffer, 4));
+        buffer.clear();
+        write_bitmap(&mut buffer, []);
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn test_integers() {
+        for (value, signed, expected) in [
+            (0_i64, false, &[0x00][..]),
+            (255, false, &[0xFF][..]),
+            (256, false, &[0x01, 0x00][..]),
+            (0, true, &[0x00][..]),
+            (128, true, &[0x00, 0x80][..]),
+            (-1, true, &[0xFF][..]),
+            (-129, true, &[0xFF, 0x7F][..]),
+        ] {
+            let mut buffer = Vec::new();
+            write_variable_integer(&mut buffer, value, signed);
+            assert_eq!(expected, &buffer[..]);
+            assert_eq!(value, read_fixed_integer(&buffer, signed).unwrap());
+        }
+
+        let mut buffer = Vec::new();
+        write_fixed_integer(&mut buffer, -2, 2);
+        write_fixed_integer(&mut buffer, 0x0102, 4);
+        assert_eq!(&[0xFF, 0xFE, 0x00, 0x00, 0x01, 0x02], &buffer[..]);
+        assert_eq!(-2, read_fixed_integer(&buffer[..2], true).unwrap());
+        assert_eq!(0xFFFE, read_fixed_integer(&buffer[..2], false).unwrap());
+    }
+}
@@ -0,0 +1,890 @@
+//! The ALIGNED variant of the Packed Encoding Rules shares most of its encoding with the
+//! UNALIGNED variant. The difference is, that some fields are preceded by padding bits so that
+//! they start at an octet boundary. Therefore, [`Aligned`] wraps the bit buffers of the
+//! [`crate::io::per::unaligned`] module and only needs to know where to insert the padding.
+
+use crate::io::per::unaligned::buffer::BitBuffer;
+use crate::io::per::unaligned::BitWrite;
+use crate::io::per::unaligned::ScopedBitRead;
+use crate::io::per::unaligned::BYTE_LEN;
+use crate::io::per::{Error, ErrorKind};
+use crate::io::per::{PackedRead, PackedWrite};
+use alloc::vec;
+use alloc::vec::Vec;
+
+const MAX_FRAGMENTS: u8 = 4  /* 11.9.3.8, NOTE */ ;
+
+const LENGTH_127: u64 = 127;
+const LENGTH_16K: u64 = 16 * 1024;
+const LENGTH_64K: u64 = 64 * 1024;
+
+const SMALL_NON_NEGATIVE_NUMBER: u64 = 64;
+
+/// Wraps a [`BitBuffer`] (for writing) or a [`ScopedBitRead`] (for reading) and implements
+/// [`PackedWrite`] / [`PackedRead`] according to the ALIGNED variant.
+#[derive(Debug, Default)]
+pub struct Aligned<T>(pub(crate) T);
+
+impl<T> Aligned<T> {
+    pub const fn new(inner: T) -> Self {
+        Self(inner)
+    }
+
+    pub const fn inner(&self) -> &T {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for Aligned<T> {
+    fn from(inner: T) -> Self {
+        Self(inner)
+    }
+}
+
+/// The minimal number of octets required to represent the given value, but at least one
+#[inline]
+const fn octets_needed(value: u64) -> u64 {
+    let octets = core::mem::size_of::<u64>() as u64 - value.leading_zeros() as u64 / 8;
+    if octets == 0 {
+        1
+    } else {
+        octets
+    }
+}
+
+impl Aligned<BitBuffer> {
+    /// Writes padding bits until the write position is on an octet boundary
+    #[inline]
+    pub fn align(&mut self) -> Result<(), Error> {
+        let padding = (BYTE_LEN - self.0.write_position % BYTE_LEN) % BYTE_LEN;
+        if padding > 0 {
+            self.0.write_bits_with_len(&[0x00], padding)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.5.7, where `range` is `ub - lb` and `value`
+    /// is the offset to `lb`
+    #[inline]
+    fn write_constrained_offset(&mut self, range: u64, value: u64) -> Result<(), Error> {
+        if range == 0 {
+            Ok(())
+        } else if range < 255 {
+            // 11.5.7.1: bit-field, not octet-aligned
+            self.0
+                .write_non_negative_binary_integer(None, Some(range), value)
+        } else if range == 255 {
+            // 11.5.7.2: one-octet, octet-aligned
+            self.align()?;
+            self.0.write_bits(&[value as u8])
+        } else if range < LENGTH_64K {
+            // 11.5.7.3: two-octets, octet-aligned
+            self.align()?;
+            self.0.write_bits(&(value as u16).to_be_bytes())
+        } else {
+            // 11.5.7.4: indefinite length case
+            let octets = octets_needed(value);
+            self.write_constrained_offset(octets_needed(range) - 1, octets - 1)?;
+            self.align()?;
+            let bytes = value.to_be_bytes();
+            self.0.write_bits(&bytes[bytes.len() - octets as usize..])
+        }
+    }
+
+    /// Writes the content in fragments, each prefixed with an unconstrained length determinant
+    /// (ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.9.3.8)
+    #[inline]
+    fn write_fragmented<F: FnMut(&mut Self, u64, u64) -> Result<(), Error>>(
+        &mut self,
+        length: u64,
+        mut write: F,
+    ) -> Result<(), Error> {
+        let mut written = 0;
+        loop {
+            let remaining = length - written;
+            let fragment = self
+                .write_length_determinant(None, None, remaining)?
+                .unwrap_or(remaining);
+            write(self, written, fragment)?;
+            written += fragment;
+
+            if fragment < LENGTH_16K {
+                return Ok(());
+            }
+        }
+    }
+}
+
+impl PackedWrite for Aligned<BitBuffer> {
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 12
+    #[inline]
+    fn write_boolean(&mut self, boolean: bool) -> Result<(), Error> {
+        self.0.write_bit(boolean)
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.4
+    #[inline]
+    fn write_2s_compliment_binary_integer(
+        &mut self,
+        bit_len: u64,
+        value: i64,
+    ) -> Result<(), Error> {
+        self.0.write_2s_compliment_binary_integer(bit_len, value)
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.5
+    #[inline]
+    fn write_constrained_whole_number(
+        &mut self,
+        lower_bound: i64,
+        upper_bound: i64,
+        value: i64,
+    ) -> Result<(), Error> {
+        if upper_bound > lower_bound {
+            if value < lower_bound || value > upper_bound {
+                Err(ErrorKind::ValueNotInRange(value, lower_bound, upper_bound).into())
+            } else {
+                self.write_constrained_offset(
+                    upper_bound.wrapping_sub(lower_bound) as u64,
+                    value.wrapping_sub(lower_bound) as u64,
+                )
+            }
+        } else {
+            Ok(())
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.9.4
+    #[inline]
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    fn write_length_determinant(
+        &mut self,
+        lower_bound: Option<u64>,
+        upper_bound: Option<u64>,
+        length: u64,
+    ) -> Result<Option<u64>, Error> {
+        match upper_bound {
+            Some(upper_bound) if upper_bound < LENGTH_64K => {
+                // 11.9.4.1 -> 11.9.3.3
+                let lower_bound = const_unwrap_or!(lower_bound, 0);
+                if length < lower_bound || length > upper_bound {
+                    Err(ErrorKind::SizeNotInRange(length, lower_bound, upper_bound).into())
+                } else {
+                    self.write_constrained_offset(
+                        upper_bound.saturating_sub(lower_bound),
+                        length - lower_bound,
+                    )?;
+                    Ok(None)
+                }
+            }
+            _ => {
+                // 11.9.4.2 -> 11.9.3.5, octet-aligned in the ALIGNED variant
+                self.align()?;
+                if length <= LENGTH_127 {
+                    // 11.9.3.6: less than or equal to 127
+                    self.0.write_bits(&[length as u8])?;
+                    Ok(None)
+                } else if length < LENGTH_16K {
+                    // 11.9.3.7: greater than 127 and less than 16K
+                    self.0.write_bits(&(0x8000 | length as u16).to_be_bytes())?;
+                    Ok(None)
+                } else {
+                    // 11.9.3.8: chunks of 16k multiples
+                    let multiple = (length / LENGTH_16K).min(u64::from(MAX_FRAGMENTS)) as u8;
+                    self.0.write_bits(&[0xC0 | multiple])?;
+                    Ok(Some(u64::from(multiple) * LENGTH_16K))
+                }
+            }
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.9.3.4
+    #[inline]
+    fn write_normally_small_length(&mut self, value: u64) -> Result<(), Error> {
+        self.write_normally_small_non_negative_whole_number(value)
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.6
+    #[inline]
+    fn write_normally_small_non_negative_whole_number(&mut self, value: u64) -> Result<(), Error> {
+        let greater_or_equal_to_64 = value >= SMALL_NON_NEGATIVE_NUMBER;
+        self.0.write_bit(greater_or_equal_to_64)?;
+        if greater_or_equal_to_64 {
+            // 11.6.2 -> 11.7
+            self.write_non_negative_binary_integer(None, None, value)
+        } else {
+            // 11.6.1: bit-field, not octet-aligned
+            self.write_constrained_offset(SMALL_NON_NEGATIVE_NUMBER - 1, value)
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.3
+    #[inline]
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    fn write_non_negative_binary_integer(
+        &mut self,
+        lower_bound: Option<u64>,
+        upper_bound: Option<u64>,
+        value: u64,
+    ) -> Result<(), Error> {
+        if const_is_none!(lower_bound) && const_is_none!(upper_bound) {
+            let octets = octets_needed(value);
+            let bytes = value.to_be_bytes();
+            self.write_length_determinant(None, None, octets)?;
+            self.0.write_bits(&bytes[bytes.len() - octets as usize..])
+        } else {
+            let lower = const_unwrap_or!(lower_bound, 0);
+            let upper = const_unwrap_or!(upper_bound, i64::MAX as u64);
+            self.write_constrained_offset(upper - lower, value - lower)
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.7
+    #[inline]
+    fn write_semi_constrained_whole_number(
+        &mut self,
+        lower_bound: i64,
+        value: i64,
+    ) -> Result<(), Error> {
+        if value < lower_bound {
+            Err(ErrorKind::ValueNotInRange(value, lower_bound, i64::MAX).into())
+        } else {
+            self.write_non_negative_binary_integer(
+                None,
+                None,
+                value.wrapping_sub(lower_bound) as u64,
+            )
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.8
+    #[inline]
+    fn write_unconstrained_whole_number(&mut self, value: i64) -> Result<(), Error> {
+        let prefix_len = if value.is_negative() {
+            value.leading_ones().saturating_sub(1)
+        } else {
+            value.leading_zeros().saturating_sub(1)
+        } as u64
+            / 8;
+        let octet_len = core::mem::size_of::<i64>() as u64 - prefix_len;
+        self.write_length_determinant(None, None, octet_len)?;
+        self.0
+            .write_2s_compliment_binary_integer(octet_len * BYTE_LEN as u64, value)
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 16
+    #[inline]
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    fn write_bitstring(
+        &mut self,
+        lower_bound_size: Option<u64>,
+        upper_bound_size: Option<u64>,
+        extensible: bool,
+        src: &[u8],
+        offset: u64,
+        len: u64,
+    ) -> Result<(), Error> {
+        let lower_bound = const_unwrap_or!(lower_bound_size, 0);
+        let upper_bound = const_unwrap_or!(upper_bound_size, i64::MAX as u64);
+        let out_of_range = len < lower_bound || len > upper_bound;
+
+        if extensible {
+            self.0.write_bit(out_of_range)?;
+        }
+
+        if out_of_range && !extensible {
+            Err(ErrorKind::SizeNotInRange(len, lower_bound, upper_bound).into())
+        } else if !out_of_range
+            && const_is_some!(lower_bound_size)
+            && lower_bound_size == upper_bound_size
+            && upper_bound < LENGTH_64K
+        {
+            // 16.9: up to 16 bits are not octet-aligned
+            // 16.10: octet-aligned in the ALIGNED variant
+            if upper_bound > 16 {
+                self.align()?;
+            }
+            self.0
+                .write_bits_with_offset_len(src, offset as usize, len as usize)
+        } else if !out_of_range && upper_bound < LENGTH_64K {
+            // 16.11
+            self.write_length_determinant(lower_bound_size, upper_bound_size, len)?;
+            if len > 0 {
+                self.align()?;
+            }
+            self.0
+                .write_bits_with_offset_len(src, offset as usize, len as usize)
+        } else {
+            // 16.6, 16.11 -> 11.9.3.8
+            self.write_fragmented(len, |w, written, fragment| {
+                w.0.write_bits_with_offset_len(src, (offset + written) as usize, fragment as usize)
+            })
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 17
+    #[inline]
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    fn write_octetstring(
+        &mut self,
+        lower_bound_size: Option<u64>,
+        upper_bound_size: Option<u64>,
+        extensible: bool,
+        src: &[u8],
+    ) -> Result<(), Error> {
+        let lower_bound = const_unwrap_or!(lower_bound_size, 0);
+        let upper_bound = const_unwrap_or!(upper_bound_size, i64::MAX as u64);
+        let length = src.len() as u64;
+        let out_of_range = length < lower_bound || length > upper_bound;
+
+        if extensible {
+            self.0.write_bit(out_of_range)?;
+        }
+
+        if out_of_range && !extensible {
+            Err(ErrorKind::SizeNotInRange(length, lower_bound, upper_bound).into())
+        } else if !out_of_range && upper_bound == 0 {
+            // 17.5
+            Ok(())
+        } else if !out_of_range
+            && const_is_some!(lower_bound_size)
+            && lower_bound_size == upper_bound_size
+            && upper_bound < LENGTH_64K
+        {
+            // 17.6: up to two octets are not octet-aligned
+            // 17.7: octet-aligned in the ALIGNED variant
+            if upper_bound > 2 {
+                self.align()?;
+            }
+            self.0.write_bits(src)
+        } else if !out_of_range && upper_bound < LENGTH_64K {
+            // 17.8
+            self.write_length_determinant(lower_bound_size, upper_bound_size, length)?;
+            if length > 0 {
+                self.align()?;
+            }
+            self.0.write_bits(src)
+        } else {
+            // 17.3, 17.8 -> 11.9.3.8
+            self.write_fragmented(length, |w, written, fragment| {
+                w.0.write_bits(&src[written as usize..(written + fragment) as usize])
+            })
+        }
+    }
+
+    #[inline]
+    fn write_choice_index(
+        &mut self,
+        std_variants: u64,
+        extensible: bool,
+        index: u64,
+    ) -> Result<(), Error> {
+        self.write_enumeration_index(std_variants, extensible, index)
+    }
+
+    #[inline]
+    fn write_enumeration_index(
+        &mut self,
+        std_variants: u64,
+        extensible: bool,
+        index: u64,
+    ) -> Result<(), Error> {
+        let out_of_range = index >= std_variants;
+        if extensible {
+            self.0.write_bit(out_of_range)?;
+        }
+
+        if out_of_range {
+            if extensible {
+                self.write_normally_small_length(index - std_variants)
+            } else {
+                Err(ErrorKind::InvalidChoiceIndex(index, std_variants).into())
+            }
+        } else {
+            self.write_constrained_offset(std_variants - 1, index)
+        }
+    }
+}
+
+impl<B: ScopedBitRead> Aligned<B> {
+    /// Skips the padding bits until the read position is on an octet boundary
+    #[inline]
+    pub fn align(&mut self) -> Result<(), Error> {
+        let padding = (BYTE_LEN - self.0.pos() % BYTE_LEN) % BYTE_LEN;
+        if padding > self.0.remaining() {
+            Err(Error::insufficient_data_in_source_buffer())
+        } else {
+            self.0.set_pos(self.0.pos() + padding);
+            Ok(())
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.5.7, where `range` is `ub - lb` and the
+    /// returned value is the offset to `lb`
+    #[inline]
+    fn read_constrained_offset(&mut self, range: u64) -> Result<u64, Error> {
+        if range == 0 {
+            Ok(0)
+        } else if range < 255 {
+            // 11.5.7.1: bit-field, not octet-aligned
+            self.0.read_non_negative_binary_integer(None, Some(range))
+        } else if range == 255 {
+            // 11.5.7.2: one-octet, octet-aligned
+            self.align()?;
+            let mut bytes = [0u8; 1];
+            self.0.read_bits(&mut bytes)?;
+            Ok(u64::from(bytes[0]))
+        } else if range < LENGTH_64K {
+            // 11.5.7.3: two-octets, octet-aligned
+            self.align()?;
+            let mut bytes = [0u8; 2];
+            self.0.read_bits(&mut bytes)?;
+            Ok(u64::from(u16::from_be_bytes(bytes)))
+        } else {
+            // 11.5.7.4: indefinite length case
+            let octets = self.read_constrained_offset(octets_needed(range) - 1)? as usize + 1;
+            self.align()?;
+            self.read_octets_as_u64(octets)
+        }
+    }
+
+    #[inline]
+    fn read_octets_as_u64(&mut self, octets: usize) -> Result<u64, Error> {
+        let mut bytes = [0u8; core::mem::size_of::<u64>()];
+        if let Some(offset) = bytes.len().checked_sub(octets) {
+            self.0.read_bits(&mut bytes[offset..])?;
+            Ok(u64::from_be_bytes(bytes))
+        } else {
+            Err(Error::length_determinant_exceeds_limit(octets, bytes.len()))
+        }
+    }
+
+    /// Reads the content in fragments, each prefixed with an unconstrained length determinant
+    /// (ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.9.3.8)
+    #[inline]
+    fn read_fragmented<F: FnMut(&mut Self, u64) -> Result<(), Error>>(
+        &mut self,
+        mut read: F,
+    ) -> Result<(), Error> {
+        loop {
+            let fragment = self.read_length_determinant(None, None)?;
+            read(self, fragment)?;
+
+            if fragment < LENGTH_16K {
+                return Ok(());
+            }
+        }
+    }
+}
+
+impl<B: ScopedBitRead> PackedRead for Aligned<B> {
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 12
+    #[inline]
+    fn read_boolean(&mut self) -> Result<bool, Error> {
+        self.0.read_bit()
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.4
+    #[inline]
+    fn read_2s_compliment_binary_integer(&mut self, bit_len: u64) -> Result<i64, Error> {
+        self.0.read_2s_compliment_binary_integer(bit_len)
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.5
+    #[inline]
+    fn read_constrained_whole_number(
+        &mut self,
+        lower_bound: i64,
+        upper_bound: i64,
+    ) -> Result<i64, Error> {
+        if upper_bound > lower_bound {
+            let offset =
+                self.read_constrained_offset(upper_bound.wrapping_sub(lower_bound) as u64)?;
+            Ok(lower_bound.wrapping_add(offset as i64))
+        } else {
+            Ok(lower_bound)
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.9.4
+    #[inline]
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    fn read_length_determinant(
+        &mut self,
+        lower_bound: Option<u64>,
+        upper_bound: Option<u64>,
+    ) -> Result<u64, Error> {
+        match upper_bound {
+            Some(upper_bound) if upper_bound < LENGTH_64K => {
+                // 11.9.4.1 -> 11.9.3.3
+                let lower_bound = const_unwrap_or!(lower_bound, 0);
+                Ok(lower_bound
+                    + self.read_constrained_offset(upper_bound.saturating_sub(lower_bound))?)
+            }
+            _ => {
+                // 11.9.4.2 -> 11.9.3.5, octet-aligned in the ALIGNED variant
+                self.align()?;
+                let mut bytes = [0u8; 2];
+                self.0.read_bits(&mut bytes[..1])?;
+                if bytes[0] & 0x80 == 0 {
+                    // 11.9.3.6: less than or equal to 127
+                    Ok(u64::from(bytes[0]))
+                } else if bytes[0] & 0x40 == 0 {
+                    // 11.9.3.7: greater than 127 and less than 16K
+                    self.0.read_bits(&mut bytes[1..])?;
+                    Ok(u64::from(u16::from_be_bytes(bytes) & 0x3FFF))
+                } else {
+                    // 11.9.3.8: chunks of 16k multiples
+                    Ok(LENGTH_16K * u64::from((bytes[0] & 0x3F).min(MAX_FRAGMENTS)))
+                }
+            }
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.9.3.4
+    #[inline]
+    fn read_normally_small_length(&mut self) -> Result<u64, Error> {
+        self.read_normally_small_non_negative_whole_number()
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.6
+    #[inline]
+    fn read_normally_small_non_negative_whole_number(&mut self) -> Result<u64, Error> {
+        if self.0.read_bit()? {
+            // 11.6.2 -> 11.7
+            self.read_non_negative_binary_integer(None, None)
+        } else {
+            // 11.6.1: bit-field, not octet-aligned
+            self.read_constrained_offset(SMALL_NON_NEGATIVE_NUMBER - 1)
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.3
+    #[inline]
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    fn read_non_negative_binary_integer(
+        &mut self,
+        lower_bound: Option<u64>,
+        upper_bound: Option<u64>,
+    ) -> Result<u64, Error> {
+        if const_is_none!(lower_bound) && const_is_none!(upper_bound) {
+            let octets = self.read_length_determinant(None, None)? as usize;
+            self.read_octets_as_u64(octets)
+        } else {
+            let lower = const_unwrap_or!(lower_bound, 0);
+            let upper = const_unwrap_or!(upper_bound, i64::MAX as u64);
+            Ok(lower + self.read_constrained_offset(upper.saturating_sub(lower))?)
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.7
+    #[inline]
+    fn read_semi_constrained_whole_number(&mut self, lower_bound: i64) -> Result<i64, Error> {
+        let n = self.read_non_negative_binary_integer(None, None)?;
+        Ok(lower_bound.wrapping_add(n as i64))
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.8
+    #[inline]
+    fn read_unconstrained_whole_number(&mut self) -> Result<i64, Error> {
+        let octet_len = self.read_length_determinant(None, None)?;
+        self.0
+            .read_2s_compliment_binary_integer(octet_len * BYTE_LEN as u64)
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 16
+    #[inline]
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    fn read_bitstring(
+        &mut self,
+        lower_bound_size: Option<u64>,
+        upper_bound_size: Option<u64>,
+        extensible: bool,
+    ) -> Result<(Vec<u8>, u64), Error> {
+        let upper_bound = const_unwrap_or!(upper_bound_size, i64::MAX as u64);
+        let out_of_range = extensible && self.0.read_bit()?;
+
+        let bit_len = if !out_of_range
+            && const_is_some!(lower_bound_size)
+            && lower_bound_size == upper_bound_size
+            && upper_bound < LENGTH_64K
+        {
+            // 16.9: up to 16 bits are not octet-aligned
+            // 16.10: octet-aligned in the ALIGNED variant
+            if upper_bound > 16 {
+                self.align()?;
+            }
+            upper_bound
+        } else if !out_of_range && upper_bound < LENGTH_64K {
+            // 16.11
+            let bit_len = self.read_length_determinant(lower_bound_size, upper_bound_size)?;
+            if bit_len > 0 {
+                self.align()?;
+            }
+            bit_len
+        } else {
+            // 16.6, 16.11 -> 11.9.3.8
+            let mut buffer = Vec::new();
+            let mut bit_len = 0_u64;
+            self.read_fragmented(|r, fragment| {
+                buffer.resize((bit_len + fragment).div_ceil(8) as usize, 0x00);
+                r.0.read_bits_with_offset_len(
+                    &mut buffer[..],
+                    bit_len as usize,
+                    fragment as usize,
+                )?;
+                bit_len += fragment;
+                Ok(())
+            })?;
+            return Ok((buffer, bit_len));
+        };
+
+        let mut buffer = vec![0u8; bit_len.div_ceil(8) as usize];
+        self.0
+            .read_bits_with_len(&mut buffer[..], bit_len as usize)?;
+        Ok((buffer, bit_len))
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 17
+    #[inline]
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    fn read_octetstring(
+        &mut self,
+        lower_bound_size: Option<u64>,
+        upper_bound_size: Option<u64>,
+        extensible: bool,
+    ) -> Result<Vec<u8>, Error> {
+        let upper_bound = const_unwrap_or!(upper_bound_size, i64::MAX as u64);
+        let out_of_range = extensible && self.0.read_bit()?;
+
+        let byte_len = if !out_of_range && upper_bound == 0 {
+            // 17.5
+            return Ok(Vec::default());
+        } else if !out_of_range
+            && const_is_some!(lower_bound_size)
+            && lower_bound_size == upper_bound_size
+            && upper_bound < LENGTH_64K
+        {
+            // 17.6: up to two octets are not octet-aligned
+            // 17.7: octet-aligned in the ALIGNED variant
+            if upper_bound > 2 {
+                self.align()?;
+            }
+            upper_bound
+        } else if !out_of_range && upper_bound < LENGTH_64K {
+            // 17.8
+            let byte_len = self.read_length_determinant(lower_bound_size, upper_bound_size)?;
+            if byte_len > 0 {
+                self.align()?;
+            }
+            byte_len
+        } else {
+            // 17.3, 17.8 -> 11.9.3.8
+            let mut buffer = Vec::new();
+            self.read_fragmented(|r, fragment| {
+                let offset = buffer.len();
+                buffer.resize(offset + fragment as usize, 0x00);
+                r.0.read_bits(&mut buffer[offset..])
+            })?;
+            return Ok(buffer);
+        };
+
+        let mut buffer = vec![0u8; byte_len as usize];
+        self.0.read_bits(&mut buffer[..])?;
+        Ok(buffer)
+    }
+
+    #[inline]
+    fn read_choice_index(&mut self, std_variants: u64, extensible: bool) -> Result<u64, Error> {
+        self.read_enumeration_index(std_variants, extensible)
+    }
+
+    #[inline]
+    fn read_enumeration_index(
+        &mut self,
+        std_variants: u64,
+        extensible: bool,
+    ) -> Result<u64, Error> {
+        if extensible && self.0.read_bit()? {
+            Ok(self.read_normally_small_length()? + std_variants)
+        } else {
+            self.read_constrained_offset(std_variants.saturating_sub(1))
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::io::per::unaligned::buffer::Bits;
+
+    fn write<F: FnOnce(&mut Aligned<BitBuffer>) -> Result<(), Error>>(f: F) -> (Vec<u8>, usize) {
+        let mut aligned = Aligned::<BitBuffer>::default();
+        f(&mut aligned).unwrap();
+        let bit_len = aligned.inner().bit_len();
+        (aligned.into_inner().into(), bit_len)
+    }
+
+    fn read_from(bytes: &[u8], bit_len: usize) -> Aligned<Bits> {
+        Aligned::new(Bits::from((bytes, bit_len)))
+    }
+
+    #[test]
+    pub fn constrained_whole_number_small_range_is_not_aligned() {
+        let (bytes, bit_len) = write(|w| {
+            w.write_boolean(true)?;
+            w.write_constrained_whole_number(0, 7, 5)
+        });
+        assert_eq!((vec![0b1101_0000], 4), (bytes.clone(), bit_len));
+
+        let mut reader = read_from(&bytes, bit_len);
+        assert!(reader.read_boolean().unwrap());
+        assert_eq!(5, reader.read_constrained_whole_number(0, 7).unwrap());
+    }
+
+    #[test]
+    pub fn constrained_whole_number_one_octet_is_aligned() {
+        let (bytes, bit_len) = write(|w| {
+            w.write_boolean(true)?;
+            w.write_constrained_whole_number(0, 255, 0x42)
+        });
+        assert_eq!((vec![0x80, 0x42], 16), (bytes.clone(), bit_len));
+
+        let mut reader = read_from(&bytes, bit_len);
+        assert!(reader.read_boolean().unwrap());
+        assert_eq!(0x42, reader.read_constrained_whole_number(0, 255).unwrap());
+    }
+
+    #[test]
+    pub fn constrained_whole_number_two_octets_is_aligned() {
+        let (bytes, bit_len) = write(|w| {
+            w.write_boolean(true)?;
+            w.write_constrained_whole_number(0, 65535, 0x1234)
+        });
+        assert_eq!((vec![0x80, 0x12, 0x34], 24), (bytes.clone(), bit_len));
+
+        let mut reader = read_from(&bytes, bit_len);
+        assert!(reader.read_boolean().unwrap());
+        assert_eq!(
+            0x1234,
+            reader.read_constrained_whole_number(0, 65535).unwrap()
+        );
+    }
+
+    #[test]
+    pub fn constrained_whole_number_indefinite_length() {
+        // range of 2^32 requires up to 4 octets, so the length is encoded in 2 bits
+        let (bytes, bit_len) = write(|w| {
+            w.write_boolean(true)?;
+            w.write_constrained_whole_number(0, u32::MAX as i64, 0x0102)
+        });
+        assert_eq!(
+            (vec![0b1010_0000, 0x01, 0x02], 24),
+            (bytes.clone(), bit_len)
+        );
+
+        let mut reader = read_from(&bytes, bit_len);
+        assert!(reader.read_boolean().unwrap());
+        assert_eq!(
+            0x0102,
+            reader
+                .read_constrained_whole_number(0, u32::MAX as i64)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    pub fn unconstrained_whole_number() {
+        let (bytes, bit_len) = write(|w| {
+            w.write_boolean(false)?;
+            w.write_unconstrained_whole_number(-128)
+        });
+        assert_eq!((vec![0x00, 0x01, 0x80], 24), (bytes.clone(), bit_len));
+
+        let mut reader = read_from(&bytes, bit_len);
+        assert!(!reader.read_boolean().unwrap());
+        assert_eq!(-128, reader.read_unconstrained_whole_number().unwrap());
+    }
+
+    #[test]
+    pub fn length_determinant_unconstrained() {
+        for (length, expected) in [
+            (0_u64, vec![0x80, 0x00]),
+            (127, vec![0x80, 0x7F]),
+            (128, vec![0x80, 0x80, 0x80]),
+            (16383, vec![0x80, 0xBF, 0xFF]),
+        ] {
+            let (bytes, bit_len) = write(|w| {
+                w.write_boolean(true)?;
+                w.write_length_determinant(None, None, length).map(drop)
+            });
+            assert_eq!(expected, bytes);
+
+            let mut reader = read_from(&bytes, bit_len);
+            assert!(reader.read_boolean().unwrap());
+            assert_eq!(length, reader.read_length_determinant(None, None).unwrap());
+        }
+    }
+
+    #[test]
+    pub fn octetstring_fixed_size() {
+        // up to two octets are not aligned
+        let (bytes, bit_len) = write(|w| {
+            w.write_boolean(true)?;
+            w.write_octetstring(Some(2), Some(2), false, &[0xAB, 0xCD])
+        });
+        assert_eq!((vec![0xD5, 0xE6, 0x80], 17), (bytes.clone(), bit_len));
+        let mut reader = read_from(&bytes, bit_len);
+        assert!(reader.read_boolean().unwrap());
+        assert_eq!(
+            vec![0xAB, 0xCD],
+            reader.read_octetstring(Some(2), Some(2), false).unwrap()
+        );
+
+        // more than two octets are aligned
+        let (bytes, bit_len) = write(|w| {
+            w.write_boolean(true)?;
+            w.write_octetstring(Some(3), Some(3), false, &[0x01, 0x02, 0x03])
+        });
+        assert_eq!((vec![0x80, 0x01, 0x02, 0x03], 32), (bytes.clone(), bit_len));
+        let mut reader = read_from(&bytes, bit_len);
+        assert!(reader.read_boolean().unwrap());
+        assert_eq!(
+            vec![0x01, 0x02, 0x03],
+            reader.read_octetstring(Some(3), Some(3), false).unwrap()
+        );
+    }
+
+    #[test]
+    pub fn octetstring_fragmented() {
+        let src = (0..LENGTH_16K + 3).map(|v| v as u8).collect::<Vec<_>>();
+        let (bytes, bit_len) = write(|w| w.write_octetstring(None, None, false, &src));
+        assert_eq!(0xC1, bytes[0]);
+        assert_eq!(&[0x03], &bytes[1 + LENGTH_16K as usize..][..1]);
+        assert_eq!(
+            src,
+            read_from(&bytes, bit_len)
+                .read_octetstring(None, None, false)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    pub fn bitstring_variable_size() {
+        let (bytes, bit_len) =
+            write(|w| w.write_bitstring(Some(0), Some(32), false, &[0xFF, 0x80], 0, 9));
+        // 6 bits length (9), 2 bits padding, 9 bits content
+        assert_eq!((vec![0x24, 0xFF, 0x80], 17), (bytes.clone(), bit_len));
+        assert_eq!(
+            (vec![0xFF, 0x80], 9),
+            read_from(&bytes, bit_len)
+                .read_bitstring(Some(0), Some(32), false)
+                .unwrap()
+        );
+    }
+}
@@ -1,6 +1,9 @@
+use crate::io::Backtrace;
 use crate::model::Charset;
-use backtrace::Backtrace;
-use std::string::FromUtf8Error;
+use alloc::boxed::Box;
+use alloc::string::FromUtf8Error;
+use alloc::string::String;
+use core::str::Utf8Error;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Error(pub(crate) Box<Inner>);
@@ -29,8 +32,8 @@ impl From<ErrorKind> for Error {
     }
 }
 
-impl std::fmt::Display for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}", self.0.kind)?;
         #[cfg(feature = "descriptive-deserialize-errors")]
         {
@@ -43,7 +46,7 @@ impl std::fmt::Display for Error {
     }
 }
 
-impl std::error::Error for Error {
+impl core::error::Error for Error {
     fn description(&self) -> &str {
         "encoding or decoding UPER failed"
     }
@@ -59,6 +62,7 @@ pub(crate) struct Inner {
 #[derive(Debug, Clone)]
 pub enum ErrorKind {
     FromUtf8Error(FromUtf8Error),
+    Utf8Error(Utf8Error),
     InvalidString(Charset, char, usize),
     UnsupportedOperation(String),
     InsufficientSpaceInDestinationBuffer(Backtrace),
@@ -69,6 +73,8 @@ pub enum ErrorKind {
         backtrace: Backtrace,
     },
     InvalidChoiceIndex(u64, u64),
+    /// The open type of the given name is not selected by a key of its object set
+    InvalidOpenTypeKey(&'static str),
     ExtensionFieldsInconsistent(String),
     ValueNotInRange(i64, i64, i64),
     ValueExceedsMaxInt,
@@ -76,7 +82,16 @@ pub enum ErrorKind {
     SizeNotInRange(u64, u64, u64),
     BitLenNotInRange(u64, u64, u64),
     OptFlagsExhausted,
+    InvalidReal(&'static str),
+    InvalidObjectIdentifier(&'static str),
+    InvalidTime(crate::syn::time::TimeError),
     EndOfStream,
+    /// The component stored in the given field violates its inner type constraint
+    ComponentConstraintViolated(&'static str, crate::syn::validate::ViolationKind),
+    /// The schema of the dynamic interpreter lacks the referenced type
+    UnknownType(String),
+    /// The dynamic value does not match the type it is written as
+    DynamicValueMismatch(String),
 }
 
 impl Error {
@@ -101,6 +116,31 @@ impl Error {
         ErrorKind::InsufficientDataInSourceBuffer(Backtrace::new_unresolved()).into()
     }
 
+    /// Maps the error of reading the X.690 contents octets of a real value
+    #[cold]
+    #[inline(never)]
+    pub fn invalid_real(e: crate::io::ber::Error) -> Self {
+        match e.kind() {
+            crate::io::ber::ErrorKind::InvalidContent(_, reason) => {
+                ErrorKind::InvalidReal(reason).into()
+            }
+            _ => ErrorKind::InvalidReal("the exponent exceeds the supported size").into(),
+        }
+    }
+
+    /// Maps the error of encoding or decoding the contents octets of an object identifier or
+    /// relative object identifier, see [`crate::io::ber::read_object_identifier`]
+    #[cold]
+    #[inline(never)]
+    pub fn invalid_object_identifier(e: crate::io::ber::Error) -> Self {
+        match e.kind() {
+            crate::io::ber::ErrorKind::InvalidContent(_, reason) => {
+                ErrorKind::InvalidObjectIdentifier(reason).into()
+            }
+            _ => ErrorKind::InvalidObjectIdentifier("unexpected encoding").into(),
+        }
+    }
+
     #[cold]
     #[inline(never)]
     pub fn length_determinant_exceeds_limit(length: usize, limit: usize) -> Self {
@@ -113,13 +153,17 @@ impl Error {
     }
 }
 
-impl std::fmt::Display for ErrorKind {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::FromUtf8Error(err) => {
                 write!(f, "Failed to call String::from_utf8: ")?;
                 err.fmt(f)
             }
+            Self::Utf8Error(err) => {
+                write!(f, "Failed to call str::from_utf8: ")?;
+                err.fmt(f)
+            }
             Self::InvalidString(charset, char, index) => {
                 write!(
                     f,
@@ -167,6 +211,11 @@ impl std::fmt::Display for ErrorKind {
                 "Unexpected choice-index {} with variant count {}",
                 index, variant_count
             ),
+            Self::InvalidOpenTypeKey(name) => write!(
+                f,
+                "The open type {} is not selected by a key of its object set",
+                name
+            ),
             Self::ExtensionFieldsInconsistent(name) => {
                 write!(
                     f,
@@ -198,10 +247,28 @@ impl std::fmt::Display for ErrorKind {
                 size, min, max
             ),
             Self::OptFlagsExhausted => write!(f, "All optional flags have already been exhausted"),
+            Self::InvalidReal(reason) => {
+                write!(f, "The encoding of the real is invalid: {}", reason)
+            }
+            Self::InvalidObjectIdentifier(reason) => {
+                write!(f, "The object identifier is invalid: {}", reason)
+            }
+            Self::InvalidTime(e) => e.fmt(f),
             Self::EndOfStream => write!(
                 f,
                 "Can no longer read or write any bytes from the underlying dataset"
             ),
+            Self::ComponentConstraintViolated(field, kind) => {
+                write!(
+                    f,
+                    "The component {} violates its constraint: {}",
+                    field, kind
+                )
+            }
+            Self::UnknownType(name) => write!(f, "The type {} is not defined in the schema", name),
+            Self::DynamicValueMismatch(reason) => {
+                write!(f, "The value does not match its type: {}", reason)
+            }
         }
     }
 }
@@ -210,6 +277,7 @@ impl PartialEq for ErrorKind {
     fn eq(&self, other: &Self) -> bool {
         match self {
             Self::FromUtf8Error(a) => matches!(other, Self::FromUtf8Error(oa) if a == oa),
+            Self::Utf8Error(a) => matches!(other, Self::Utf8Error(oa) if a == oa),
             Self::InvalidString(a, b, c) => {
                 matches!(other, Self::InvalidString(oa, ob, oc) if (a, b, c) == (oa, ob, oc))
             }
@@ -228,6 +296,7 @@ impl PartialEq for ErrorKind {
             Self::InvalidChoiceIndex(a, b) => {
                 matches!(other, Self::InvalidChoiceIndex(oa, ob) if (a, b) == (oa, ob))
             }
+            Self::InvalidOpenTypeKey(a) => matches!(other, Self::InvalidOpenTypeKey(oa) if a == oa),
             Self::ExtensionFieldsInconsistent(a) => {
                 matches!(other, Self::ExtensionFieldsInconsistent(oa) if a == oa)
             }
@@ -245,7 +314,19 @@ impl PartialEq for ErrorKind {
                 matches!(other, Self::BitLenNotInRange(oa, ob, oc) if (a,b ,c) == (oa, ob,oc))
             }
             Self::OptFlagsExhausted => matches!(other, Self::OptFlagsExhausted),
+            Self::InvalidReal(a) => matches!(other, Self::InvalidReal(oa) if a == oa),
+            Self::InvalidObjectIdentifier(a) => {
+                matches!(other, Self::InvalidObjectIdentifier(oa) if a == oa)
+            }
+            Self::InvalidTime(a) => matches!(other, Self::InvalidTime(oa) if a == oa),
             Self::EndOfStream => matches!(other, Self::EndOfStream),
+            Self::ComponentConstraintViolated(a, b) => {
+                matches!(other, Self::ComponentConstraintViolated(oa, ob) if (a, b) == (oa, ob))
+            }
+            Self::UnknownType(a) => matches!(other, Self::UnknownType(oa) if a == oa),
+            Self::DynamicValueMismatch(a) => {
+                matches!(other, Self::DynamicValueMismatch(oa) if a == oa)
+            }
         }
     }
 }
@@ -3,12 +3,17 @@
 //! The idea is to provide all building blocks to composite the more complex types on top of the
 //! traits without caring about the representation being ALIGNED or UNALIGNED.
 
+pub mod aligned;
 pub mod err;
 pub mod unaligned;
 
 pub use err::Error;
 pub use err::ErrorKind;
 
+use crate::model::Charset;
+use alloc::string::String;
+use alloc::vec::Vec;
+
 /// According to ITU-T X.691 | ISO/IEC 8825-2:2015
 pub trait PackedRead {
     /// According to ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 12, the boolean type is represented
@@ -169,3 +174,100 @@ pub trait PackedWrite {
         index: u64,
     ) -> Result<(), Error>;
 }
+
+/// Decodes the characters of a BMPString or UniversalString with 16 or 32 bits per character
+/// (ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 30.5.4) and of the ISO/IEC 2022 based strings
+/// with a single octet per character
+pub(crate) fn decode_chars(charset: Charset, content: &[u8]) -> Result<String, Error> {
+    let octets_per_char = match charset {
+        Charset::Bmp => 2,
+        Charset::Universal => 4,
+        _ => return Ok(content.iter().copied().map(char::from).collect()),
+    };
+    content
+        .chunks_exact(octets_per_char)
+        .enumerate()
+        .map(|(index, chunk)| {
+            let code = chunk
+                .iter()
+                .fold(0_u32, |code, octet| (code << 8) | u32::from(*octet));
+            char::from_u32(code).ok_or_else(|| {
+                ErrorKind::InvalidString(charset, char::REPLACEMENT_CHARACTER, index).into()
+            })
+        })
+        .collect()
+}
+
+/// The effective permitted alphabet of a known-multiplier character string type with a
+/// PER-visible `FROM` constraint (ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 30.5). Each
+/// character is encoded in `bits_per_char` bits, either as its value or - if the greatest value
+/// does not fit - as its index in the sorted alphabet.
+pub(crate) struct EffectiveAlphabet<'a> {
+    ranges: &'a [(char, char)],
+    bits_per_char: usize,
+    indexed: bool,
+}
+
+impl<'a> EffectiveAlphabet<'a> {
+    /// The ranges are expected to be sorted and non-overlapping
+    pub fn new(ranges: &'a [(char, char)], aligned: bool) -> Self {
+        let len = ranges
+            .iter()
+            .map(|(start, end)| u64::from(*end as u32 - *start as u32) + 1)
+            .sum::<u64>();
+        let bits = (u64::BITS - len.saturating_sub(1).leading_zeros()) as usize;
+        let bits_per_char = if aligned {
+            bits.next_power_of_two()
+        } else {
+            bits
+        };
+        let greatest = ranges.last().map(|(_, end)| u64::from(*end as u32));
+        Self {
+            ranges,
+            bits_per_char,
+            indexed: matches!(greatest, Some(greatest) if greatest >= 1_u64 << bits_per_char),
+        }
+    }
+
+    #[inline]
+    pub const fn bits_per_char(&self) -> usize {
+        self.bits_per_char
+    }
+
+    pub fn encode(&self, charset: Charset, index: usize, char: char) -> Result<u32, Error> {
+        let mut offset = 0;
+        for (start, end) in self.ranges {
+            if *start <= char && char <= *end {
+                return Ok(if self.indexed {
+                    offset + (char as u32 - *start as u32)
+                } else {
+                    char as u32
+                });
+            }
+            offset += *end as u32 - *start as u32 + 1;
+        }
+        Err(ErrorKind::InvalidString(charset, char, index).into())
+    }
+
+    pub fn decode(&self, charset: Charset, index: usize, code: u32) -> Result<char, Error> {
+        let mut offset = 0;
+        for (start, end) in self.ranges {
+            let len = *end as u32 - *start as u32 + 1;
+            let value = if self.indexed {
+                (code >= offset && code - offset < len).then(|| *start as u32 + (code - offset))
+            } else {
+                (*start as u32 <= code && code <= *end as u32).then_some(code)
+            };
+            if let Some(char) = value.and_then(char::from_u32) {
+                return Ok(char);
+            }
+            offset += len;
+        }
+        Err(ErrorKind::InvalidString(
+            charset,
+            char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER),
+            index,
+        )
+        .into())
+    }
+}
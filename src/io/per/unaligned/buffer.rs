@@ -1,6 +1,10 @@
 use super::*;
 use crate::io::per::Error;
 use crate::io::per::ErrorKind;
+use alloc::borrow::Cow;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
 
 #[derive(Debug, Default)]
 pub struct BitBuffer {
@@ -230,6 +234,41 @@ impl BitWrite for BitBuffer {
     }
 }
 
+impl ScopedBitWrite for BitBuffer {
+    #[inline]
+    fn bit_len(&self) -> usize {
+        self.write_position
+    }
+
+    #[inline]
+    fn content(&self) -> &[u8] {
+        &self.buffer
+    }
+
+    #[inline]
+    fn set_bit_len(&mut self, bit_len: usize) {
+        debug_assert!(bit_len <= self.buffer.len() * BYTE_LEN);
+        self.write_position = bit_len;
+        self.buffer.truncate(bit_len.div_ceil(BYTE_LEN));
+        slice::clear_trailing_bits(&mut self.buffer, bit_len);
+    }
+
+    #[inline]
+    fn with_write_position_at<T, F: Fn(&mut Self) -> T>(&mut self, position: usize, f: F) -> T {
+        BitBuffer::with_write_position_at(self, position, f)
+    }
+
+    #[inline]
+    fn copy_octets_within(&mut self, src: Range<usize>, dst: usize) -> Result<(), Error> {
+        let end = dst + src.len();
+        if end > self.write_position {
+            self.ensure_can_write_additional_bits(end - self.write_position);
+        }
+        slice::copy_octets_within(&mut self.buffer, src, dst);
+        Ok(())
+    }
+}
+
 pub struct Bits<'a> {
     slice: &'a [u8],
     pos: usize,
@@ -263,6 +302,37 @@ impl<'a> From<&'a BitBuffer> for Bits<'a> {
     }
 }
 
+impl<'a> Bits<'a> {
+    /// Reads an octet string like [`crate::io::per::PackedRead::read_octetstring`], but borrows
+    /// the octets from the underlying slice instead of copying them, if these are octet-aligned
+    /// and not fragmented.
+    pub fn read_octetstring_borrowed(
+        &mut self,
+        lower_bound_size: Option<u64>,
+        upper_bound_size: Option<u64>,
+        extensible: bool,
+    ) -> Result<Cow<'a, [u8]>, Error> {
+        let (byte_len, fragmentation_possible) =
+            read_octetstring_length(self, lower_bound_size, upper_bound_size, extensible)?;
+        let byte_len = byte_len as usize;
+
+        let fragmented = fragmentation_possible && byte_len as u64 >= LENGTH_16K;
+        if self.pos.is_multiple_of(BYTE_LEN) && !fragmented {
+            let end = self.pos + byte_len * BYTE_LEN;
+            if end > self.len {
+                return Err(ErrorKind::EndOfStream.into());
+            }
+            let octets = &self.slice[self.pos / BYTE_LEN..end / BYTE_LEN];
+            self.pos = end;
+            Ok(Cow::Borrowed(octets))
+        } else {
+            let mut buffer = vec![0u8; byte_len];
+            self.read_bits(&mut buffer[..])?;
+            read_octetstring_fragments(self, buffer, fragmentation_possible).map(Cow::Owned)
+        }
+    }
+}
+
 impl BitRead for Bits<'_> {
     #[inline]
     fn read_bit(&mut self) -> Result<bool, Error> {
@@ -339,6 +409,129 @@ impl ScopedBitRead for Bits<'_> {
     }
 }
 
+/// Bits written to a caller-provided slice, for example a pre-allocated network frame. Unlike
+/// the [`BitBuffer`], it never grows and fails with
+/// [`ErrorKind::InsufficientSpaceInDestinationBuffer`] instead.
+pub struct BitsMut<'a> {
+    slice: &'a mut [u8],
+    pos: usize,
+    /// The number of leading octets that were cleared before being written to
+    cleared: usize,
+}
+
+impl<'a> From<&'a mut [u8]> for BitsMut<'a> {
+    fn from(slice: &'a mut [u8]) -> Self {
+        Self {
+            slice,
+            pos: 0,
+            cleared: 0,
+        }
+    }
+}
+
+impl BitsMut<'_> {
+    pub const fn bit_len(&self) -> usize {
+        self.pos
+    }
+
+    #[inline]
+    fn ensure_can_write_additional_bits(&mut self, bit_len: usize) -> Result<(), Error> {
+        let required_len = (self.pos + bit_len).div_ceil(BYTE_LEN);
+        if required_len > self.slice.len() {
+            return Err(Error::insufficient_space_in_destination_buffer());
+        }
+        if required_len > self.cleared {
+            self.slice[self.cleared..required_len].fill(0);
+            self.cleared = required_len;
+        }
+        Ok(())
+    }
+}
+
+impl BitWrite for BitsMut<'_> {
+    #[inline]
+    fn write_bit(&mut self, bit: bool) -> Result<(), Error> {
+        self.ensure_can_write_additional_bits(1)?;
+        BitWrite::write_bit(&mut (&mut self.slice[..], &mut self.pos), bit)
+    }
+
+    #[inline]
+    fn write_bits(&mut self, src: &[u8]) -> Result<(), Error> {
+        self.ensure_can_write_additional_bits(src.len() * BYTE_LEN)?;
+        BitWrite::write_bits(&mut (&mut self.slice[..], &mut self.pos), src)
+    }
+
+    #[inline]
+    fn write_bits_with_offset(&mut self, src: &[u8], src_bit_offset: usize) -> Result<(), Error> {
+        self.ensure_can_write_additional_bits(src.len() * BYTE_LEN - src_bit_offset)?;
+        BitWrite::write_bits_with_offset(
+            &mut (&mut self.slice[..], &mut self.pos),
+            src,
+            src_bit_offset,
+        )
+    }
+
+    #[inline]
+    fn write_bits_with_len(&mut self, src: &[u8], bit_len: usize) -> Result<(), Error> {
+        self.ensure_can_write_additional_bits(bit_len)?;
+        BitWrite::write_bits_with_len(&mut (&mut self.slice[..], &mut self.pos), src, bit_len)
+    }
+
+    #[inline]
+    fn write_bits_with_offset_len(
+        &mut self,
+        src: &[u8],
+        src_bit_offset: usize,
+        src_bit_len: usize,
+    ) -> Result<(), Error> {
+        self.ensure_can_write_additional_bits(src_bit_len)?;
+        BitWrite::write_bits_with_offset_len(
+            &mut (&mut self.slice[..], &mut self.pos),
+            src,
+            src_bit_offset,
+            src_bit_len,
+        )
+    }
+}
+
+impl ScopedBitWrite for BitsMut<'_> {
+    #[inline]
+    fn bit_len(&self) -> usize {
+        self.pos
+    }
+
+    #[inline]
+    fn content(&self) -> &[u8] {
+        &self.slice[..self.pos.div_ceil(BYTE_LEN)]
+    }
+
+    #[inline]
+    fn set_bit_len(&mut self, bit_len: usize) {
+        debug_assert!(bit_len.div_ceil(BYTE_LEN) <= self.cleared);
+        self.pos = bit_len;
+        self.cleared = bit_len.div_ceil(BYTE_LEN);
+        slice::clear_trailing_bits(self.slice, bit_len);
+    }
+
+    #[inline]
+    fn with_write_position_at<T, F: Fn(&mut Self) -> T>(&mut self, position: usize, f: F) -> T {
+        let before = core::mem::replace(&mut self.pos, position);
+        let result = f(self);
+        self.pos = before;
+        result
+    }
+
+    #[inline]
+    fn copy_octets_within(&mut self, src: Range<usize>, dst: usize) -> Result<(), Error> {
+        let end = dst + src.len();
+        if end > self.pos {
+            self.ensure_can_write_additional_bits(end - self.pos)?;
+        }
+        slice::copy_octets_within(self.slice, src, dst);
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::identity_op, clippy::inconsistent_digit_grouping)] // this makes various examples easier to understand
 pub mod tests {
@@ -347,6 +540,30 @@ pub mod tests {
     use crate::io::per::unaligned::BitWrite;
     use crate::io::per::unaligned::PackedRead;
     use crate::io::per::unaligned::PackedWrite;
+    use crate::io::per::unaligned::ScopedBitWrite;
+
+    #[test]
+    pub fn bits_mut_write_into_slice_and_fail_if_full() -> Result<(), Error> {
+        let mut slice = [0xFF_u8; 2];
+        let mut bits = BitsMut::from(&mut slice[..]);
+
+        bits.write_bit(true)?;
+        bits.write_bits_with_len(&[0b0100_0000], 3)?;
+        assert_eq!(bits.content(), &[0b1010_0000]);
+
+        bits.write_bits(&[0xAB])?;
+        assert_eq!(bits.content(), &[0b1010_1010, 0b1011_0000]);
+        assert_eq!(12, bits.bit_len());
+
+        assert_eq!(
+            bits.write_bits(&[0x00]),
+            Err(Error::insufficient_space_in_destination_buffer())
+        );
+        bits.write_bits_with_len(&[0x00], 4)?;
+        assert!(bits.write_bit(false).is_err());
+        assert_eq!(&slice, &[0b1010_1010, 0b1011_0000]);
+        Ok(())
+    }
 
     #[test]
     pub fn bit_buffer_write_bit_keeps_correct_order() -> Result<(), Error> {
@@ -1,5 +1,8 @@
 use crate::io::per::{Error, ErrorKind};
 use crate::io::per::{PackedRead, PackedWrite};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
 
 pub mod buffer;
 pub mod slice;
@@ -94,11 +97,11 @@ impl<T: BitRead> PackedRead for T {
         if let Some((lower, upper)) = range {
             let range = upper.saturating_sub(lower);
             let offset_bits = range.leading_zeros() as usize;
-            let mut bytes = [0u8; std::mem::size_of::<u64>()];
+            let mut bytes = [0u8; core::mem::size_of::<u64>()];
             self.read_bits_with_offset(&mut bytes, offset_bits)?;
             Ok(lower + u64::from_be_bytes(bytes))
         } else {
-            let mut bytes = [0u8; std::mem::size_of::<u64>()];
+            let mut bytes = [0u8; core::mem::size_of::<u64>()];
             let length = self.read_length_determinant(None, None)? as usize;
 
             if let Some(offset) = bytes.len().checked_sub(length) {
@@ -113,7 +116,7 @@ impl<T: BitRead> PackedRead for T {
     /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.4
     #[inline]
     fn read_2s_compliment_binary_integer(&mut self, bit_len: u64) -> Result<i64, Error> {
-        let mut bytes = [0u8; std::mem::size_of::<i64>()];
+        let mut bytes = [0u8; core::mem::size_of::<i64>()];
 
         if bit_len == 0 || bit_len as usize > bytes.len() * BYTE_LEN {
             return Err(ErrorKind::BitLenNotInRange(
@@ -302,65 +305,17 @@ impl<T: BitRead> PackedRead for T {
 
     /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 17
     #[inline]
-    #[allow(clippy::suspicious_else_formatting)] // for 17.6 else-if comment block
-    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
     fn read_octetstring(
         &mut self,
         lower_bound_size: Option<u64>,
         upper_bound_size: Option<u64>,
         extensible: bool,
     ) -> Result<Vec<u8>, Error> {
-        // let lower_bound = const_unwrap_or!(lower_bound_size, 0);
-        let upper_bound = const_unwrap_or!(upper_bound_size, i64::MAX as u64);
-
-        let (mut byte_len, fragmentation_possible) = if extensible && self.read_bit()? {
-            // 17.3
-            // self.read_semi_constrained_whole_number(0)
-            // self.read_non_negative_binary_integer(0, MAX) + lb  | lb=0=>MIN for unsigned
-            (self.read_length_determinant(None, None)?, true)
-        } else if upper_bound == 0 {
-            // 17.5
-            return Ok(Vec::default());
-        }
-        /* else if const_is_some!(lower_bound_size)
-            && lower_bound_size == upper_bound_size
-            && upper_bound <= 2
-        {
-            // 17.6
-            (upper_bound, false)
-        }*/
-        else if const_is_some!(lower_bound_size)
-            && lower_bound_size == upper_bound_size
-            && upper_bound < LENGTH_64K
-        {
-            // 17.7
-            (upper_bound, false)
-        } else {
-            // 17.8
-            (
-                self.read_length_determinant(lower_bound_size, upper_bound_size)?,
-                true,
-            )
-        };
-
+        let (byte_len, fragmentation_possible) =
+            read_octetstring_length(self, lower_bound_size, upper_bound_size, extensible)?;
         let mut buffer = vec![0u8; byte_len as usize];
         self.read_bits(&mut buffer[..])?;
-
-        // fragmentation?
-        if fragmentation_possible && byte_len >= LENGTH_16K {
-            loop {
-                let ext_byte_len = self.read_length_determinant(None, None)?;
-                buffer.extend(core::iter::repeat(0u8).take(ext_byte_len as usize));
-                self.read_bits(&mut buffer[byte_len as usize..])?;
-                byte_len += ext_byte_len;
-
-                if ext_byte_len < LENGTH_16K {
-                    break;
-                }
-            }
-        }
-
-        Ok(buffer)
+        read_octetstring_fragments(self, buffer, fragmentation_possible)
     }
 
     #[inline]
@@ -382,6 +337,73 @@ impl<T: BitRead> PackedRead for T {
     }
 }
 
+/// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 17, reads the length of the octet string and
+/// whether further fragments might follow the first one
+#[inline]
+#[allow(clippy::suspicious_else_formatting)] // for 17.6 else-if comment block
+#[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+pub(crate) fn read_octetstring_length<T: BitRead>(
+    bits: &mut T,
+    lower_bound_size: Option<u64>,
+    upper_bound_size: Option<u64>,
+    extensible: bool,
+) -> Result<(u64, bool), Error> {
+    // let lower_bound = const_unwrap_or!(lower_bound_size, 0);
+    let upper_bound = const_unwrap_or!(upper_bound_size, i64::MAX as u64);
+
+    Ok(if extensible && bits.read_bit()? {
+        // 17.3
+        // self.read_semi_constrained_whole_number(0)
+        // self.read_non_negative_binary_integer(0, MAX) + lb  | lb=0=>MIN for unsigned
+        (bits.read_length_determinant(None, None)?, true)
+    } else if upper_bound == 0 {
+        // 17.5
+        (0, false)
+    }
+    /* else if const_is_some!(lower_bound_size)
+        && lower_bound_size == upper_bound_size
+        && upper_bound <= 2
+    {
+        // 17.6
+        (upper_bound, false)
+    }*/
+    else if const_is_some!(lower_bound_size)
+        && lower_bound_size == upper_bound_size
+        && upper_bound < LENGTH_64K
+    {
+        // 17.7
+        (upper_bound, false)
+    } else {
+        // 17.8
+        (
+            bits.read_length_determinant(lower_bound_size, upper_bound_size)?,
+            true,
+        )
+    })
+}
+
+/// Appends the remaining fragments of an octet string to the already read first fragment
+#[inline]
+pub(crate) fn read_octetstring_fragments<T: BitRead>(
+    bits: &mut T,
+    mut buffer: Vec<u8>,
+    fragmentation_possible: bool,
+) -> Result<Vec<u8>, Error> {
+    if fragmentation_possible && buffer.len() as u64 >= LENGTH_16K {
+        loop {
+            let byte_len = buffer.len();
+            let ext_byte_len = bits.read_length_determinant(None, None)?;
+            buffer.extend(core::iter::repeat(0u8).take(ext_byte_len as usize));
+            bits.read_bits(&mut buffer[byte_len..])?;
+
+            if ext_byte_len < LENGTH_16K {
+                break;
+            }
+        }
+    }
+    Ok(buffer)
+}
+
 pub trait BitWrite {
     fn write_bit(&mut self, bit: bool) -> Result<(), Error>;
 
@@ -399,6 +421,29 @@ pub trait BitWrite {
     ) -> Result<(), Error>;
 }
 
+pub trait ScopedBitWrite: BitWrite {
+    /// The number of bits written so far, which is also the position the next bit is written to
+    fn bit_len(&self) -> usize;
+
+    /// The octets containing all bits written so far
+    fn content(&self) -> &[u8];
+
+    /// Sets the number of bits written, all bits from this position on are discarded and the
+    /// remaining bits of the last octet are cleared. Growing requires the octets to be written
+    /// before, for example by [`ScopedBitWrite::copy_octets_within`].
+    fn set_bit_len(&mut self, bit_len: usize);
+
+    /// Changes the write-position to the given position for the closure call.
+    /// Restores the original write-position after the call.
+    fn with_write_position_at<T, F: Fn(&mut Self) -> T>(&mut self, position: usize, f: F) -> T;
+
+    /// Copies the octets containing the bits of `src`, so that the first bit of `src` is at the
+    /// position `dst` afterwards. Because whole octets are copied, the distance must be a
+    /// multiple of [`BYTE_LEN`] and the bits sharing the first and last octet with `src` are
+    /// copied as well. The write-position is not changed.
+    fn copy_octets_within(&mut self, src: Range<usize>, dst: usize) -> Result<(), Error>;
+}
+
 impl<T: BitWrite> PackedWrite for T {
     /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 12
     #[inline]
@@ -430,7 +475,7 @@ impl<T: BitWrite> PackedWrite for T {
             Ok(())
         } else {
             let offset = value.leading_zeros() as u64 / 8;
-            let len = std::mem::size_of::<u64>() as u64 - offset;
+            let len = core::mem::size_of::<u64>() as u64 - offset;
             let bytes = value.to_be_bytes();
             self.write_length_determinant(None, None, len)?;
             self.write_bits(&bytes[offset as usize..])
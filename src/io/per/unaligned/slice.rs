@@ -2,6 +2,7 @@ use super::BitRead;
 use crate::io::per::unaligned::BitWrite;
 use crate::io::per::unaligned::BYTE_LEN;
 use crate::io::per::{Error, ErrorKind};
+use core::ops::Range;
 
 impl BitRead for (&[u8], &mut usize) {
     #[inline]
@@ -89,6 +90,24 @@ impl<'a> BitWrite for (&'a mut [u8], &mut usize) {
     }
 }
 
+/// Copies the octets of the buffer that contain the bits of `src`, so that the first bit of `src`
+/// is at the position `dst` afterwards, see
+/// [`super::ScopedBitWrite::copy_octets_within`]
+#[inline]
+pub(crate) fn copy_octets_within(buffer: &mut [u8], src: Range<usize>, dst: usize) {
+    debug_assert_eq!(src.start % BYTE_LEN, dst % BYTE_LEN);
+    let octets = src.start / BYTE_LEN..src.end.div_ceil(BYTE_LEN);
+    buffer.copy_within(octets, dst / BYTE_LEN);
+}
+
+/// Clears the bits of the last octet that follow the given number of bits
+#[inline]
+pub(crate) fn clear_trailing_bits(buffer: &mut [u8], bit_len: usize) {
+    if !bit_len.is_multiple_of(BYTE_LEN) {
+        buffer[bit_len / BYTE_LEN] &= 0xFF << (BYTE_LEN - bit_len % BYTE_LEN);
+    }
+}
+
 #[inline]
 fn bit_string_copy(
     src: &[u8],
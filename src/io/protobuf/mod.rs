@@ -21,8 +21,12 @@ pub enum Error {
     InvalidTagReceived(Backtrace, u32),
     InvalidFormat(Backtrace, u32),
     InvalidVariant(Backtrace, u64),
+    InvalidOpenTypeKey(&'static str),
     UnexpectedFormat(Backtrace, Format),
     UnexpectedTag(Backtrace, (u32, Format)),
+    InvalidObjectIdentifier(Backtrace, String),
+    InvalidTime(Backtrace, String),
+    ComponentConstraintViolated(&'static str, crate::syn::validate::ViolationKind),
 }
 
 impl Error {
@@ -50,6 +54,16 @@ impl Error {
     pub fn unexpected_tag(tag: (u32, Format)) -> Self {
         Error::UnexpectedTag(Backtrace::new(), tag)
     }
+
+    #[allow(unused)]
+    pub fn invalid_object_identifier(value: String) -> Self {
+        Error::InvalidObjectIdentifier(Backtrace::new(), value)
+    }
+
+    #[allow(unused)]
+    pub fn invalid_time(value: String) -> Self {
+        Error::InvalidTime(Backtrace::new(), value)
+    }
 }
 
 impl std::fmt::Display for Error {
@@ -63,12 +77,28 @@ impl std::fmt::Display for Error {
             Error::InvalidTagReceived(b, tag) => write!(f, "Tag({}) is unknown\n{:?}", tag, b),
             Error::InvalidFormat(b, tag) => write!(f, "Format({}) is invalid\n{:?}", tag, b),
             Error::InvalidVariant(b, var) => write!(f, "Variant({}) is invalid\n{:?}", var, b),
+            Error::InvalidOpenTypeKey(name) => write!(
+                f,
+                "The open type {} is not selected by a key of its object set",
+                name
+            ),
             Error::UnexpectedFormat(b, format) => {
                 write!(f, "Format({:?}) is unexpected\n{:?}", format, b)
             }
             Error::UnexpectedTag(b, (tag, format)) => {
                 write!(f, "Tag({}/{:?}) is unexpected\n{:?}", tag, format, b)
             }
+            Error::InvalidObjectIdentifier(b, value) => {
+                write!(f, "ObjectIdentifier({}) is invalid\n{:?}", value, b)
+            }
+            Error::InvalidTime(b, value) => write!(f, "Time({}) is invalid\n{:?}", value, b),
+            Error::ComponentConstraintViolated(field, kind) => {
+                write!(
+                    f,
+                    "The component {} violates its constraint: {}",
+                    field, kind
+                )
+            }
         }
     }
 }
@@ -126,6 +156,8 @@ pub trait ProtoWrite {
 
     fn write_sfixed32(&mut self, value: i32) -> Result<(), Error>;
 
+    fn write_double(&mut self, value: f64) -> Result<(), Error>;
+
     fn write_uint32(&mut self, value: u32) -> Result<(), Error> {
         self.write_varint(u64::from(value))
     }
@@ -168,6 +200,11 @@ pub trait ProtoWrite {
         self.write_sfixed32(value)
     }
 
+    fn write_tagged_double(&mut self, field: u32, value: f64) -> Result<(), Error> {
+        self.write_tag(field, Format::Fixed64)?;
+        self.write_double(value)
+    }
+
     fn write_tagged_uint32(&mut self, field: u32, value: u32) -> Result<(), Error> {
         self.write_tag(field, Format::VarInt)?;
         self.write_uint32(value)
@@ -224,6 +261,11 @@ impl<W: Write> ProtoWrite for W {
         Ok(())
     }
 
+    fn write_double(&mut self, value: f64) -> Result<(), Error> {
+        self.write_f64::<E>(value)?;
+        Ok(())
+    }
+
     fn write_string(&mut self, value: &str) -> Result<(), Error> {
         self.write_bytes(value.as_bytes())?;
         Ok(())
@@ -258,6 +300,8 @@ pub trait ProtoRead {
 
     fn read_sfixed32(&mut self) -> Result<i32, Error>;
 
+    fn read_double(&mut self) -> Result<f64, Error>;
+
     fn read_uint32(&mut self) -> Result<u32, Error> {
         Ok(self.read_varint()? as u32)
     }
@@ -308,6 +352,10 @@ impl<R: Read> ProtoRead for R {
         Ok(self.read_i32::<E>()?)
     }
 
+    fn read_double(&mut self) -> Result<f64, Error> {
+        Ok(self.read_f64::<E>()?)
+    }
+
     fn read_string(&mut self) -> Result<String, Error> {
         let bytes = self.read_bytes()?;
         if let Ok(string) = String::from_utf8(bytes) {
@@ -1,2 +1,4 @@
 pub mod bit_vec_impl;
+pub mod oid_impl;
+pub mod time_impl;
 pub mod unit_impl;
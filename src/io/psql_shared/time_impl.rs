@@ -0,0 +1,55 @@
+use crate::syn::time::{Date, Duration, GeneralizedTime, TimeOfDay, UtcTime};
+use bytes::BytesMut;
+use std::error::Error;
+
+#[cfg(feature = "psql")]
+use postgres::types::{FromSql, IsNull, ToSql, Type};
+
+#[cfg(all(feature = "async-psql", not(feature = "psql")))]
+use tokio_postgres::types::{FromSql, IsNull, ToSql, Type};
+
+/// The time types are stored in their textual representation, see [`crate::syn::time`]
+macro_rules! impl_text_sql {
+    ($($name:ty),*) => {$(
+        impl<'a> FromSql<'a> for $name {
+            fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+                let text = <&str as FromSql>::from_sql(ty, raw)?;
+                Ok(text.parse()?)
+            }
+
+            fn accepts(ty: &Type) -> bool {
+                <&str as FromSql>::accepts(ty)
+            }
+        }
+
+        impl ToSql for $name {
+            fn to_sql(
+                &self,
+                ty: &Type,
+                out: &mut BytesMut,
+            ) -> Result<IsNull, Box<dyn Error + Sync + Send>>
+            where
+                Self: Sized,
+            {
+                <String as ToSql>::to_sql(&self.to_string(), ty, out)
+            }
+
+            fn accepts(ty: &Type) -> bool
+            where
+                Self: Sized,
+            {
+                <String as ToSql>::accepts(ty)
+            }
+
+            fn to_sql_checked(
+                &self,
+                ty: &Type,
+                out: &mut BytesMut,
+            ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+                <String as ToSql>::to_sql_checked(&self.to_string(), ty, out)
+            }
+        }
+    )*};
+}
+
+impl_text_sql!(GeneralizedTime, UtcTime, Date, TimeOfDay, Duration);
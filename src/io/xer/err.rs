@@ -0,0 +1,121 @@
+use crate::model::Charset;
+use alloc::boxed::Box;
+use alloc::string::String;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Error(pub(crate) Box<ErrorKind>);
+
+impl Error {
+    #[inline]
+    pub fn kind(&self) -> &ErrorKind {
+        &self.0
+    }
+}
+
+impl From<ErrorKind> for Error {
+    #[cold]
+    #[inline(never)]
+    fn from(kind: ErrorKind) -> Self {
+        Self(Box::new(kind))
+    }
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl core::error::Error for Error {
+    fn description(&self) -> &str {
+        "encoding or decoding XER failed"
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ErrorKind {
+    InvalidString(Charset, char, usize),
+    InvalidXml {
+        offset: usize,
+        reason: &'static str,
+    },
+    MissingIdentifier,
+    MissingElement(&'static str),
+    NoMoreElements,
+    UnknownElement(String),
+    InvalidContent(&'static str),
+    InvalidChoiceIndex(u64, u64),
+    /// The open type of the given name is not selected by a key of its object set
+    InvalidOpenTypeKey(&'static str),
+    ValueNotInRange(i64, i64, i64),
+    ValueExceedsMaxInt,
+    SizeNotInRange(u64, u64, u64),
+    /// The component stored in the given field violates its inner type constraint
+    ComponentConstraintViolated(&'static str, crate::syn::validate::ViolationKind),
+}
+
+impl Error {
+    #[cold]
+    #[inline(never)]
+    pub fn ensure_string_valid(charset: Charset, str: &str) -> Result<(), Self> {
+        match charset.find_invalid(str) {
+            None => Ok(()),
+            Some((index, char)) => Err(ErrorKind::InvalidString(charset, char, index).into()),
+        }
+    }
+}
+
+impl core::fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidString(charset, char, index) => {
+                write!(
+                    f,
+                    "Invalid character for a string with the charset {:?} at index {}: {}",
+                    charset, index, char
+                )
+            }
+            Self::InvalidXml { offset, reason } => {
+                write!(f, "Invalid XML document at offset {}: {}", offset, reason)
+            }
+            Self::MissingIdentifier => write!(
+                f,
+                "The value is a component of a constructed value but its constraint has no identifier"
+            ),
+            Self::MissingElement(identifier) => {
+                write!(f, "There is no element named '{}'", identifier)
+            }
+            Self::NoMoreElements => write!(f, "There are no more elements to read"),
+            Self::UnknownElement(name) => {
+                write!(f, "The element '{}' is unknown", name)
+            }
+            Self::InvalidContent(reason) => write!(f, "The value is invalid: {}", reason),
+            Self::InvalidChoiceIndex(index, variant_count) => write!(
+                f,
+                "Unexpected choice-index {} with variant count {}",
+                index, variant_count
+            ),
+            Self::InvalidOpenTypeKey(name) => write!(
+                f,
+                "The open type {} is not selected by a key of its object set",
+                name
+            ),
+            Self::ValueNotInRange(value, min, max) => write!(
+                f,
+                "The value {} is not within the inclusive range of {} and {}",
+                value, min, max
+            ),
+            Self::ValueExceedsMaxInt => {
+                write!(f, "The value exceeds the maximum supported integer size",)
+            }
+            Self::SizeNotInRange(size, min, max) => write!(
+                f,
+                "The size {} is not within the inclusive range of {} and {}",
+                size, min, max
+            ),
+            Self::ComponentConstraintViolated(field, kind) => {
+                write!(f, "The component {} violates its constraint: {}", field, kind)
+            }
+        }
+    }
+}
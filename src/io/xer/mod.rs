@@ -0,0 +1,428 @@
+//! This module contains the building blocks of the basic XML Encoding Rules (XER) according to
+//! ITU-T X.693 | ISO/IEC 8825-4. Every value is represented as XML element whose name is either
+//! the identifier of the component, the type reference or the name of the built-in type.
+
+pub mod err;
+
+pub use err::Error;
+pub use err::ErrorKind;
+
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+/// According to ITU-T X.680 | ISO/IEC 8824-1, Table 3, the names of the empty elements that
+/// represent the control characters, indexed by their code point
+pub const CONTROL_CHARACTERS: [&str; 32] = [
+    "nul", "soh", "stx", "etx", "eot", "enq", "ack", "bel", "bs", "ht", "lf", "vt", "ff", "cr",
+    "so", "si", "dle", "dc1", "dc2", "dc3", "dc4", "nak", "syn", "etb", "can", "em", "sub", "esc",
+    "is4", "is3", "is2", "is1",
+];
+
+/// A parsed XML element. Attributes, comments and processing instructions are not retained.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Element {
+    pub name: String,
+    pub content: Vec<Content>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Content {
+    Element(Element),
+    Text(String),
+}
+
+impl Element {
+    /// The child elements, the text between them must consist of whitespace only
+    pub fn into_elements(self) -> Result<Vec<Element>, Error> {
+        self.content
+            .into_iter()
+            .filter_map(|content| match content {
+                Content::Element(element) => Some(Ok(element)),
+                Content::Text(text) if text.trim().is_empty() => None,
+                Content::Text(_) => Some(Err(ErrorKind::InvalidContent(
+                    "unexpected text between elements",
+                )
+                .into())),
+            })
+            .collect()
+    }
+
+    /// The text of a character string, in which control characters might be represented as
+    /// empty elements according to [`CONTROL_CHARACTERS`]
+    pub fn into_text(self) -> Result<String, Error> {
+        let mut text = String::new();
+        for content in self.content {
+            match content {
+                Content::Text(t) => text.push_str(&t),
+                Content::Element(element) if element.content.is_empty() => {
+                    let index = CONTROL_CHARACTERS
+                        .iter()
+                        .position(|name| *name == element.name)
+                        .ok_or(ErrorKind::UnknownElement(element.name))?;
+                    text.push(char::from(index as u8));
+                }
+                Content::Element(element) => {
+                    return Err(ErrorKind::UnknownElement(element.name).into())
+                }
+            }
+        }
+        Ok(text)
+    }
+}
+
+/// Parses an XML document, which consists of exactly one root element, optionally preceded by
+/// an XML declaration
+pub fn parse(text: &str) -> Result<Element, Error> {
+    let mut parser = Parser { text, position: 0 };
+    parser.skip_misc()?;
+    let root = parser.parse_element()?;
+    parser.skip_misc()?;
+    if parser.position < parser.text.len() {
+        Err(parser.error("unexpected content after the root element"))
+    } else {
+        Ok(root)
+    }
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    position: usize,
+}
+
+impl Parser<'_> {
+    #[cold]
+    fn error(&self, reason: &'static str) -> Error {
+        ErrorKind::InvalidXml {
+            offset: self.position,
+            reason,
+        }
+        .into()
+    }
+
+    fn remaining(&self) -> &str {
+        &self.text[self.position..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let remaining = self.remaining();
+        self.position += remaining.len() - remaining.trim_start().len();
+    }
+
+    fn skip_until(&mut self, end: &str, reason: &'static str) -> Result<&str, Error> {
+        match self.remaining().find(end) {
+            Some(index) => {
+                let skipped = &self.text[self.position..self.position + index];
+                self.position += index + end.len();
+                Ok(skipped)
+            }
+            None => Err(self.error(reason)),
+        }
+    }
+
+    /// Skips whitespace, comments and processing instructions - including the XML declaration
+    fn skip_misc(&mut self) -> Result<(), Error> {
+        loop {
+            self.skip_whitespace();
+            if self.remaining().starts_with("<?") {
+                self.skip_until("?>", "unterminated processing instruction")?;
+            } else if self.remaining().starts_with("<!--") {
+                self.skip_until("-->", "unterminated comment")?;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn parse_name(&mut self) -> Result<String, Error> {
+        let length = self
+            .remaining()
+            .find(|c: char| c.is_whitespace() || matches!(c, '/' | '>' | '<' | '=' | '"' | '\''))
+            .unwrap_or_else(|| self.remaining().len());
+        if length == 0 {
+            return Err(self.error("expected name"));
+        }
+        let name = self.remaining()[..length].to_string();
+        self.position += length;
+        Ok(name)
+    }
+
+    fn parse_element(&mut self) -> Result<Element, Error> {
+        if !self.remaining().starts_with('<') {
+            return Err(self.error("expected start tag"));
+        }
+        self.position += 1;
+        let name = self.parse_name()?;
+
+        // attributes - for example namespace declarations - are skipped
+        loop {
+            self.skip_whitespace();
+            if self.remaining().starts_with("/>") {
+                self.position += 2;
+                return Ok(Element {
+                    name,
+                    content: Vec::default(),
+                });
+            } else if self.remaining().starts_with('>') {
+                self.position += 1;
+                break;
+            }
+            self.parse_name()?;
+            self.skip_whitespace();
+            if !self.remaining().starts_with('=') {
+                return Err(self.error("expected '=' after name of attribute"));
+            }
+            self.position += 1;
+            self.skip_whitespace();
+            match self.remaining().chars().next() {
+                Some(quote @ ('"' | '\'')) => {
+                    self.position += 1;
+                    let mut end = [0_u8; 1];
+                    self.skip_until(
+                        quote.encode_utf8(&mut end),
+                        "unterminated value of attribute",
+                    )?;
+                }
+                _ => return Err(self.error("expected quoted value of attribute")),
+            }
+        }
+
+        let mut content = Vec::new();
+        loop {
+            let remaining = self.remaining();
+            if remaining.starts_with("</") {
+                self.position += 2;
+                let start = self.position;
+                if self.parse_name()? != name {
+                    self.position = start;
+                    return Err(self.error("end tag does not match start tag"));
+                }
+                self.skip_whitespace();
+                if !self.remaining().starts_with('>') {
+                    return Err(self.error("expected end of end tag"));
+                }
+                self.position += 1;
+                return Ok(Element { name, content });
+            } else if remaining.starts_with("<!--") {
+                self.skip_until("-->", "unterminated comment")?;
+            } else if remaining.starts_with("<![CDATA[") {
+                self.position += "<![CDATA[".len();
+                let text = self.skip_until("]]>", "unterminated CDATA section")?;
+                push_text(&mut content, text);
+            } else if remaining.starts_with("<?") {
+                self.skip_until("?>", "unterminated processing instruction")?;
+            } else if remaining.starts_with('<') {
+                content.push(Content::Element(self.parse_element()?));
+            } else if remaining.is_empty() {
+                return Err(self.error("unexpected end of document"));
+            } else {
+                let text = self.parse_text()?;
+                push_text(&mut content, &text);
+            }
+        }
+    }
+
+    fn parse_text(&mut self) -> Result<String, Error> {
+        let mut text = String::new();
+        while let Some(char) = self.remaining().chars().next() {
+            match char {
+                '<' => break,
+                '&' => {
+                    self.position += 1;
+                    let reference = self.skip_until(";", "unterminated reference")?;
+                    let char = match reference {
+                        "lt" => '<',
+                        "gt" => '>',
+                        "amp" => '&',
+                        "quot" => '"',
+                        "apos" => '\'',
+                        _ => reference
+                            .strip_prefix("#x")
+                            .map(|hex| u32::from_str_radix(hex, 16))
+                            .or_else(|| reference.strip_prefix('#').map(str::parse))
+                            .and_then(Result::ok)
+                            .and_then(char::from_u32)
+                            .ok_or_else(|| self.error("invalid reference"))?,
+                    };
+                    text.push(char);
+                }
+                char => {
+                    text.push(char);
+                    self.position += char.len_utf8();
+                }
+            }
+        }
+        Ok(text)
+    }
+}
+
+fn push_text(content: &mut Vec<Content>, text: &str) {
+    if let Some(Content::Text(previous)) = content.last_mut() {
+        previous.push_str(text);
+    } else {
+        content.push(Content::Text(text.to_string()));
+    }
+}
+
+/// Writes the given string as character data. The characters `&`, `<` and `>` are escaped and
+/// control characters - except for horizontal tab and line feed - are written as empty elements
+/// according to ITU-T X.693 | ISO/IEC 8825-4, 8.2.4.
+pub fn write_text(buffer: &mut String, value: &str) {
+    for char in value.chars() {
+        match char {
+            '&' => buffer.push_str("&amp;"),
+            '<' => buffer.push_str("&lt;"),
+            '>' => buffer.push_str("&gt;"),
+            '\t' | '\n' => buffer.push(char),
+            c if (c as u32) < 0x20 => {
+                buffer.push('<');
+                buffer.push_str(CONTROL_CHARACTERS[c as usize]);
+                buffer.push_str("/>");
+            }
+            c => buffer.push(c),
+        }
+    }
+}
+
+/// According to ITU-T X.693 | ISO/IEC 8825-4, 8.3.10, octets are represented by two hexadecimal
+/// digits each
+pub fn write_hex(buffer: &mut String, octets: &[u8]) {
+    const DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+    for octet in octets {
+        buffer.push(char::from(DIGITS[usize::from(octet >> 4)]));
+        buffer.push(char::from(DIGITS[usize::from(octet & 0x0F)]));
+    }
+}
+
+/// Reads the octets of a string of hexadecimal digits, which are accepted in upper and lower case
+/// and might be separated by whitespace
+pub fn read_hex(hex: &str) -> Result<Vec<u8>, Error> {
+    let digits = hex
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| {
+            c.to_digit(16)
+                .map(|digit| digit as u8)
+                .ok_or_else(|| ErrorKind::InvalidContent("invalid hexadecimal digit").into())
+        })
+        .collect::<Result<Vec<u8>, Error>>()?;
+    if !digits.len().is_multiple_of(2) {
+        return Err(ErrorKind::InvalidContent("odd number of hexadecimal digits").into());
+    }
+    Ok(digits
+        .chunks(2)
+        .map(|pair| (pair[0] << 4) | pair[1])
+        .collect())
+}
+
+/// According to ITU-T X.693 | ISO/IEC 8825-4, 8.3.9, each bit is represented by the character
+/// `0` or `1`
+pub fn write_bits(buffer: &mut String, octets: &[u8], bit_len: u64) {
+    for bit in 0..bit_len {
+        let octet = octets[(bit / 8) as usize];
+        buffer.push(if octet & (0x80 >> (bit % 8)) != 0 {
+            '1'
+        } else {
+            '0'
+        });
+    }
+}
+
+/// Reads the bits of a string of the characters `0` and `1`, which might be separated by
+/// whitespace, and returns them together with the amount of bits read
+pub fn read_bits(bits: &str) -> Result<(Vec<u8>, u64), Error> {
+    let mut octets = Vec::new();
+    let mut bit_len = 0_u64;
+    for char in bits.chars().filter(|c| !c.is_whitespace()) {
+        if bit_len.is_multiple_of(8) {
+            octets.push(0x00);
+        }
+        match char {
+            '0' => {}
+            '1' => *octets.last_mut().unwrap() |= 0x80 >> (bit_len % 8),
+            _ => return Err(ErrorKind::InvalidContent("invalid binary digit").into()),
+        }
+        bit_len += 1;
+    }
+    Ok((octets, bit_len))
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    fn element(name: &str, content: Vec<Content>) -> Element {
+        Element {
+            name: name.to_string(),
+            content,
+        }
+    }
+
+    #[test]
+    fn test_parse_document() {
+        assert_eq!(
+            element(
+                "Pdu",
+                vec![
+                    Content::Text("\n  ".to_string()),
+                    Content::Element(element(
+                        "name",
+                        vec![Content::Text("a<b & c>d\u{e4}".to_string())]
+                    )),
+                    Content::Element(element("flag", vec![Content::Element(element("true", vec![]))])),
+                    Content::Text("\n".to_string()),
+                ]
+            ),
+            parse(
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<!-- comment -->\n<Pdu xmlns:asn1='urn:x'>\n  <name>a&lt;b &amp; <![CDATA[c>]]>d&#xe4;</name><flag><true /></flag>\n</Pdu>\n"
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        for (text, offset) in [
+            ("", 0),
+            ("<a>", 3),
+            ("<a></b>", 5),
+            ("<a>&unknown;</a>", 12),
+            ("<a x=1/>", 5),
+            ("<a/><b/>", 4),
+        ] {
+            assert!(
+                matches!(parse(text).unwrap_err().kind(), ErrorKind::InvalidXml { offset: o, .. } if *o == offset),
+                "{}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn test_text_round_trip() {
+        let value = "a<b>&c\u{07}\r\n\td";
+        let mut buffer = String::from("<a>");
+        write_text(&mut buffer, value);
+        buffer.push_str("</a>");
+        assert_eq!("<a>a&lt;b&gt;&amp;c<bel/><cr/>\n\td</a>", buffer);
+        assert_eq!(value, parse(&buffer).unwrap().into_text().unwrap());
+    }
+
+    #[test]
+    fn test_hex_and_bits() {
+        let mut buffer = String::new();
+        write_hex(&mut buffer, &[0x00, 0x0F, 0xA0, 0xFF]);
+        assert_eq!("000FA0FF", buffer);
+        assert_eq!(
+            vec![0x00, 0x0F, 0xA0, 0xFF],
+            read_hex("00 0f\nA0FF").unwrap()
+        );
+        assert!(read_hex("ABC").is_err());
+
+        let mut buffer = String::new();
+        write_bits(&mut buffer, &[0xA5, 0x80], 9);
+        assert_eq!("101001011", buffer);
+        assert_eq!((vec![0xA5, 0x80], 9), read_bits("1010 0101 1").unwrap());
+        assert!(read_bits("012").is_err());
+    }
+}
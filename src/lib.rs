@@ -1,6 +1,9 @@
+#![cfg_attr(not(feature = "std"), no_std)]
 #![deny(rustdoc::broken_intra_doc_links)]
 #![warn(unused_extern_crates)]
 
+extern crate alloc;
+
 #[cfg(feature = "macros")]
 pub extern crate asn1rs_macros as macros;
 
@@ -21,10 +24,11 @@ pub mod syn;
 #[cfg(feature = "model")]
 pub mod converter;
 #[cfg(feature = "model")]
+pub mod dynamic;
+#[cfg(feature = "model")]
 pub use asn1rs_model::ast;
 #[cfg(feature = "model")]
 pub use asn1rs_model::gen;
-#[cfg(feature = "model")]
 pub use asn1rs_model::model;
 #[cfg(feature = "model")]
 pub use asn1rs_model::parser;
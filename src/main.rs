@@ -1,6 +1,8 @@
 #![allow(dead_code)]
 #![warn(unused_extern_crates)]
 
+extern crate alloc;
+
 #[cfg(feature = "macros")]
 pub extern crate asn1rs_macros as macros;
 
@@ -1,5 +1,16 @@
+#[cfg(feature = "std")]
 pub use crate::io::protobuf::ProtobufEq;
 #[cfg(feature = "macros")]
 pub use crate::macros::*;
 pub use crate::syn::io::*;
 pub use crate::syn::prelude::*;
+pub use alloc::borrow::Cow;
+/// Generated code refers to these types, which are not in the prelude of `no_std` crates
+#[cfg(not(feature = "std"))]
+pub use alloc::{
+    borrow::ToOwned,
+    boxed::Box,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
@@ -1,8 +1,9 @@
 use crate::io::per::unaligned::BYTE_LEN;
 use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use alloc::vec::Vec;
 use asn1rs_model::model::Tag;
-use std::cmp::Ordering;
-use std::marker::PhantomData;
+use core::cmp::Ordering;
+use core::marker::PhantomData;
 
 pub struct BitString<C: Constraint = NoConstraint>(PhantomData<C>);
 
@@ -76,7 +77,7 @@ impl BitVec {
     ///
     /// If the given `Vec<u8>` is not at least 4 bytes large
     pub fn from_vec_with_trailing_bit_len(mut bytes: Vec<u8>) -> Self {
-        const U64_SIZE: usize = std::mem::size_of::<u64>();
+        const U64_SIZE: usize = core::mem::size_of::<u64>();
         let bytes_position = bytes.len() - U64_SIZE;
         let mut bit_len_buffer = [0u8; U64_SIZE];
         bit_len_buffer.copy_from_slice(&bytes[bytes_position..]);
@@ -0,0 +1,236 @@
+//! Decoding of values that borrow their `OCTET STRING` and `UTF8String` content from the data
+//! they are decoded from, instead of copying it into owned buffers.
+//!
+//! A type declared with the lifetime `'a` (or generated with
+//! `RustCodeGenerator::set_borrow_decoded_values`) stores these values as [`Cow`]s and
+//! implements [`BorrowedReadable`]. Readers that are able to lend their input implement
+//! [`BorrowedReader`]. Whether a value is actually borrowed depends on the encoding, the
+//! UPER reader for example borrows octet-aligned content only.
+
+use crate::syn::*;
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+pub trait BorrowedReader<'a>: Reader {
+    #[inline]
+    fn read_borrowed<T: BorrowedReadable<'a>>(&mut self) -> Result<T, Self::Error>
+    where
+        Self: Sized,
+    {
+        T::read_borrowed(self)
+    }
+
+    fn read_sequence_of_borrowed<C: sequenceof::Constraint, T: BorrowedReadableType<'a>>(
+        &mut self,
+    ) -> Result<Vec<T::Type>, Self::Error>;
+
+    fn read_set_of_borrowed<C: setof::Constraint, T: BorrowedReadableType<'a>>(
+        &mut self,
+    ) -> Result<Vec<T::Type>, Self::Error>;
+
+    /// Reads the index of a CHOICE and lets `f` read the content of the alternative with this
+    /// index, which returns `None` for an unknown index
+    fn read_choice_borrowed<
+        C: choice::Constraint,
+        F: FnOnce(u64, &mut Self) -> Result<Option<C>, Self::Error>,
+    >(
+        &mut self,
+        f: F,
+    ) -> Result<C, Self::Error>;
+
+    fn read_opt_borrowed<T: BorrowedReadableType<'a>>(
+        &mut self,
+    ) -> Result<Option<T::Type>, Self::Error>;
+
+    /// Like [`Reader::read_present`], but for values that borrow from the data
+    fn read_present_borrowed<T: BorrowedReadableType<'a>>(
+        &mut self,
+        field: &'static str,
+    ) -> Result<T::Type, Self::Error>;
+
+    fn read_utf8string_borrowed<C: utf8string::Constraint>(
+        &mut self,
+    ) -> Result<Cow<'a, str>, Self::Error>;
+
+    fn read_octet_string_borrowed<C: octetstring::Constraint>(
+        &mut self,
+    ) -> Result<Cow<'a, [u8]>, Self::Error>;
+}
+
+pub trait BorrowedReadable<'a>: Sized {
+    fn read_borrowed<R: BorrowedReader<'a>>(reader: &mut R) -> Result<Self, R::Error>;
+}
+
+pub trait BorrowedReadableType<'a>: ReadableType {
+    fn read_value_borrowed<R: BorrowedReader<'a>>(reader: &mut R) -> Result<Self::Type, R::Error>;
+}
+
+/// An `OCTET STRING` that borrows its content from the data it is decoded from
+pub struct OctetString<'a, C: octetstring::Constraint = octetstring::NoConstraint>(
+    PhantomData<&'a ()>,
+    PhantomData<C>,
+);
+
+impl<'a, C: octetstring::Constraint> WritableType for OctetString<'a, C> {
+    type Type = Cow<'a, [u8]>;
+
+    #[inline]
+    fn write_value<W: Writer>(writer: &mut W, value: &Self::Type) -> Result<(), W::Error> {
+        writer.write_octet_string::<C>(value.as_ref())
+    }
+}
+
+impl<'a, C: octetstring::Constraint> ReadableType for OctetString<'a, C> {
+    type Type = Cow<'a, [u8]>;
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        reader.read_octet_string::<C>().map(Cow::Owned)
+    }
+}
+
+impl<'a, C: octetstring::Constraint> BorrowedReadableType<'a> for OctetString<'a, C> {
+    #[inline]
+    fn read_value_borrowed<R: BorrowedReader<'a>>(reader: &mut R) -> Result<Self::Type, R::Error> {
+        reader.read_octet_string_borrowed::<C>()
+    }
+}
+
+/// An `UTF8String` that borrows its content from the data it is decoded from
+pub struct Utf8String<'a, C: utf8string::Constraint = utf8string::NoConstraint>(
+    PhantomData<&'a ()>,
+    PhantomData<C>,
+);
+
+impl<'a, C: utf8string::Constraint> WritableType for Utf8String<'a, C> {
+    type Type = Cow<'a, str>;
+
+    #[inline]
+    fn write_value<W: Writer>(writer: &mut W, value: &Self::Type) -> Result<(), W::Error> {
+        writer.write_utf8string::<C>(value.as_ref())
+    }
+}
+
+impl<'a, C: utf8string::Constraint> ReadableType for Utf8String<'a, C> {
+    type Type = Cow<'a, str>;
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        reader.read_utf8string::<C>().map(Cow::Owned)
+    }
+}
+
+impl<'a, C: utf8string::Constraint> BorrowedReadableType<'a> for Utf8String<'a, C> {
+    #[inline]
+    fn read_value_borrowed<R: BorrowedReader<'a>>(reader: &mut R) -> Result<Self::Type, R::Error> {
+        reader.read_utf8string_borrowed::<C>()
+    }
+}
+
+/// A field that refers to another type, which borrows from the data it is decoded from
+pub struct Complex<V, C: complex::Constraint>(PhantomData<V>, PhantomData<C>);
+
+impl<V: Writable, C: complex::Constraint> WritableType for Complex<V, C> {
+    type Type = V;
+
+    #[inline]
+    fn write_value<W: Writer>(writer: &mut W, value: &Self::Type) -> Result<(), W::Error> {
+        writer.write_complex::<C, _>(|w| value.write(w))
+    }
+}
+
+impl<V: Readable, C: complex::Constraint> ReadableType for Complex<V, C> {
+    type Type = V;
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        reader.read_complex::<C, _, _>(V::read)
+    }
+}
+
+impl<'a, V: Readable + BorrowedReadable<'a>, C: complex::Constraint> BorrowedReadableType<'a>
+    for Complex<V, C>
+{
+    #[inline]
+    fn read_value_borrowed<R: BorrowedReader<'a>>(reader: &mut R) -> Result<Self::Type, R::Error> {
+        reader.read_complex::<C, _, _>(V::read_borrowed)
+    }
+}
+
+impl<'a, T: BorrowedReadableType<'a>> BorrowedReadableType<'a> for Option<T> {
+    #[inline]
+    fn read_value_borrowed<R: BorrowedReader<'a>>(reader: &mut R) -> Result<Self::Type, R::Error> {
+        reader.read_opt_borrowed::<T>()
+    }
+}
+
+impl<'a, T: BorrowedReadableType<'a>, C: sequenceof::Constraint> BorrowedReadableType<'a>
+    for SequenceOf<T, C>
+{
+    #[inline]
+    fn read_value_borrowed<R: BorrowedReader<'a>>(reader: &mut R) -> Result<Self::Type, R::Error> {
+        reader.read_sequence_of_borrowed::<C, T>()
+    }
+}
+
+impl<'a, T: BorrowedReadableType<'a>, C: setof::Constraint> BorrowedReadableType<'a>
+    for SetOf<T, C>
+{
+    #[inline]
+    fn read_value_borrowed<R: BorrowedReader<'a>>(reader: &mut R) -> Result<Self::Type, R::Error> {
+        reader.read_set_of_borrowed::<C, T>()
+    }
+}
+
+impl<'a, T: BorrowedReadableType<'a>, C: explicit::Constraint> BorrowedReadableType<'a>
+    for ExplicitTag<T, C>
+{
+    #[inline]
+    fn read_value_borrowed<R: BorrowedReader<'a>>(reader: &mut R) -> Result<Self::Type, R::Error> {
+        reader.read_explicit::<C, _, _>(T::read_value_borrowed)
+    }
+}
+
+/// Types that never borrow from the data they are decoded from are read as usual
+macro_rules! read_owned {
+    ($($marker:ty => [$($generics:tt)*]),* $(,)?) => {
+        $(
+            impl<'a, $($generics)*> BorrowedReadableType<'a> for $marker {
+                #[inline]
+                fn read_value_borrowed<R: BorrowedReader<'a>>(
+                    reader: &mut R,
+                ) -> Result<Self::Type, R::Error> {
+                    Self::read_value(reader)
+                }
+            }
+        )*
+    };
+}
+
+read_owned!(
+    BitString<C> => [C: bitstring::Constraint],
+    BmpString<C> => [C: bmpstring::Constraint],
+    Boolean<C> => [C: boolean::Constraint],
+    Choice<C> => [C: choice::Constraint],
+    complex::Complex<V, C> => [V: Readable, C: complex::Constraint],
+    DefaultValue<T, C> => [T: ReadableType, C: default::Constraint<Owned = T::Type>],
+    Enumerated<C> => [C: enumerated::Constraint],
+    GeneralString<C> => [C: generalstring::Constraint],
+    GraphicString<C> => [C: graphicstring::Constraint],
+    Ia5String<C> => [C: ia5string::Constraint],
+    NullT<C> => [C: null::Constraint],
+    Integer<T, C> => [T: numbers::Number, C: numbers::Constraint<T>],
+    NumericString<C> => [C: numericstring::Constraint],
+    ObjectIdentifierT<C> => [C: objectidentifier::Constraint],
+    octetstring::OctetString<C> => [C: octetstring::Constraint],
+    OpenType<C> => [C: opentype::Constraint],
+    PrintableString<C> => [C: printablestring::Constraint],
+    Real<C> => [C: real::Constraint],
+    RelativeOidT<C> => [C: relativeoid::Constraint],
+    TeletexString<C> => [C: teletexstring::Constraint],
+    time::TimeT<T, C> => [T: time::TimeType, C: time::Constraint],
+    UniversalString<C> => [C: universalstring::Constraint],
+    utf8string::Utf8String<C> => [C: utf8string::Constraint],
+    VisibleString<C> => [C: visiblestring::Constraint],
+);
@@ -1,4 +1,4 @@
-use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use crate::syn::{ReadableType, Reader, UnknownExtension, WritableType, Writer};
 use core::marker::PhantomData;
 
 pub struct Choice<C: Constraint>(PhantomData<C>);
@@ -14,6 +14,18 @@ pub trait Constraint: super::common::Constraint + Sized {
     fn write_content<W: Writer>(&self, writer: &mut W) -> Result<(), W::Error>;
 
     fn read_content<R: Reader>(index: u64, reader: &mut R) -> Result<Option<Self>, R::Error>;
+
+    /// The value for an alternative that is unknown to the schema, if this type preserves these
+    #[inline]
+    fn from_unknown_extension(_unknown: UnknownExtension) -> Option<Self> {
+        None
+    }
+
+    /// The alternative that is unknown to the schema, if this is one
+    #[inline]
+    fn unknown_extension(&self) -> Option<&UnknownExtension> {
+        None
+    }
 }
 
 impl<C: Constraint> WritableType for Choice<C> {
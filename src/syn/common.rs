@@ -2,4 +2,6 @@ use crate::model::Tag;
 
 pub trait Constraint {
     const TAG: Tag;
+    /// The ASN.1 identifier of the field or variant this constraint belongs to, if any
+    const IDENTIFIER: Option<&'static str> = None;
 }
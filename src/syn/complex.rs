@@ -1,10 +1,11 @@
 use crate::syn::{Readable, ReadableType, Reader, Writable, WritableType, Writer};
 use core::marker::PhantomData;
 
-pub struct Complex<V, T: Constraint>(PhantomData<T>, PhantomData<V>,Option<usize>);
+pub struct Complex<V, T: Constraint>(PhantomData<T>, PhantomData<V>, Option<usize>);
 
 pub trait Constraint: super::common::Constraint {
-
+    /// Whether `TAG` is the tag of the referencing field rather than the tag of the referenced type
+    const TAGGED: bool = false;
 }
 
 impl<V: Writable, C: Constraint> WritableType for Complex<V, C> {
@@ -15,19 +16,22 @@ impl<V: Writable, C: Constraint> WritableType for Complex<V, C> {
         writer: &mut W,
         value: &Self::Type,
     ) -> Result<(), <W as Writer>::Error> {
-        value.write(writer)
+        writer.write_complex::<C, _>(|w| value.write(w))
     }
 }
 
 impl<V: Readable, C: Constraint> ReadableType for Complex<V, C> {
     type Type = V;
 
-    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, R::Error>{
-        V::read(reader)
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, R::Error> {
+        reader.read_complex::<C, _, _>(V::read)
     }
 
     #[inline]
-    fn read_value_by_key<R: Reader>(reader: &mut R, key: usize) -> Result<Self::Type, <R as Reader>::Error> {
-        V::read_by_key(reader,key)
+    fn read_value_by_key<R: Reader>(
+        reader: &mut R,
+        key: usize,
+    ) -> Result<Self::Type, <R as Reader>::Error> {
+        reader.read_complex::<C, _, _>(|r| V::read_by_key(r, key))
     }
 }
@@ -1,6 +1,7 @@
 use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use alloc::borrow::ToOwned;
+use core::fmt::Debug;
 use core::marker::PhantomData;
-use std::fmt::Debug;
 
 pub struct DefaultValue<T, C: Constraint>(PhantomData<T>, PhantomData<C>);
 
@@ -5,6 +5,8 @@ pub struct Enumerated<C: Constraint>(PhantomData<C>);
 
 pub trait Constraint: super::common::Constraint + Sized {
     const NAME: &'static str;
+    /// The ASN.1 identifiers of the variants, in the order of their choice index
+    const IDENTIFIERS: &'static [&'static str];
     const VARIANT_COUNT: u64;
     const STD_VARIANT_COUNT: u64;
     const EXTENSIBLE: bool = false;
@@ -0,0 +1,34 @@
+use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use core::marker::PhantomData;
+
+/// A value with an explicit tag (ITU-T X.680 | ISO/IEC 8824-1, 31.2.7), where `C::TAG` is the
+/// tag written around the encoding of the value
+pub struct ExplicitTag<T, C: Constraint>(PhantomData<T>, PhantomData<C>);
+
+pub trait Constraint: super::common::Constraint {}
+
+impl<T: WritableType, C: Constraint> WritableType for ExplicitTag<T, C> {
+    type Type = T::Type;
+
+    #[inline]
+    fn write_value<W: Writer>(writer: &mut W, value: &Self::Type) -> Result<(), W::Error> {
+        writer.write_explicit::<C, _>(|w| T::write_value(w, value))
+    }
+}
+
+impl<T: ReadableType, C: Constraint> ReadableType for ExplicitTag<T, C> {
+    type Type = T::Type;
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        reader.read_explicit::<C, _, _>(T::read_value)
+    }
+
+    #[inline]
+    fn read_value_by_key<R: Reader>(
+        reader: &mut R,
+        key: usize,
+    ) -> Result<Self::Type, <R as Reader>::Error> {
+        reader.read_explicit::<C, _, _>(|r| T::read_value_by_key(r, key))
+    }
+}
@@ -0,0 +1,38 @@
+use crate::model::Tag;
+use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use alloc::string::String;
+use core::marker::PhantomData;
+
+pub struct GeneralString<C: Constraint = NoConstraint>(PhantomData<C>);
+
+pub trait Constraint: super::common::Constraint {
+    const MIN: Option<u64> = None;
+    const MAX: Option<u64> = None;
+    const EXTENSIBLE: bool = false;
+    const PERMITTED_ALPHABET: Option<&'static [(char, char)]> = None;
+}
+
+#[derive(Default)]
+pub struct NoConstraint;
+impl super::common::Constraint for NoConstraint {
+    const TAG: Tag = Tag::DEFAULT_GENERAL_STRING;
+}
+impl Constraint for NoConstraint {}
+
+impl<C: Constraint> WritableType for GeneralString<C> {
+    type Type = String;
+
+    #[inline]
+    fn write_value<W: Writer>(writer: &mut W, value: &Self::Type) -> Result<(), W::Error> {
+        writer.write_general_string::<C>(value.as_str())
+    }
+}
+
+impl<C: Constraint> ReadableType for GeneralString<C> {
+    type Type = String;
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        reader.read_general_string::<C>()
+    }
+}
@@ -1,4 +1,5 @@
 use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use alloc::string::String;
 use asn1rs_model::model::Tag;
 use core::marker::PhantomData;
 
@@ -8,6 +9,7 @@ pub trait Constraint: super::common::Constraint {
     const MIN: Option<u64> = None;
     const MAX: Option<u64> = None;
     const EXTENSIBLE: bool = false;
+    const PERMITTED_ALPHABET: Option<&'static [(char, char)]> = None;
 }
 
 #[derive(Default)]
@@ -0,0 +1,1543 @@
+use crate::io::per::aligned::Aligned;
+use crate::io::per::err::Error;
+use crate::io::per::err::ErrorKind;
+use crate::io::per::unaligned::buffer::BitBuffer;
+use crate::io::per::unaligned::buffer::Bits;
+use crate::io::per::unaligned::BitWrite;
+use crate::io::per::unaligned::ScopedBitRead;
+use crate::io::per::unaligned::BYTE_LEN;
+use crate::io::per::EffectiveAlphabet;
+use crate::io::per::PackedRead;
+use crate::io::per::PackedWrite;
+use crate::model::{Charset, PresenceConstraint};
+use crate::syn::io::Scope;
+use crate::syn::*;
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 30.5.3, in the ALIGNED variant the number of bits
+/// per character is rounded up to the next power of two
+const BITS_PER_VISIBLE_CHAR: usize = 8;
+const BITS_PER_NUMERIC_CHAR: usize = 4;
+const BITS_PER_BMP_CHAR: usize = 16;
+const BITS_PER_UNIVERSAL_CHAR: usize = 32;
+
+/// The same as [`UperWriter`], but inserts padding bits to octet-align fields according to the
+/// ALIGNED variant of ITU-T X.691 | ISO/IEC 8825-2:2015
+#[derive(Default)]
+pub struct AperWriter {
+    bits: Aligned<BitBuffer>,
+    scope: Option<Scope>,
+}
+
+impl AperWriter {
+    pub fn with_capacity(capacity_bytes: usize) -> Self {
+        Self {
+            bits: Aligned::new(BitBuffer::with_capacity(capacity_bytes)),
+            ..Default::default()
+        }
+    }
+
+    pub fn byte_content(&self) -> &[u8] {
+        self.bits.0.content()
+    }
+
+    pub const fn bit_len(&self) -> usize {
+        self.bits.0.bit_len()
+    }
+
+    pub fn into_bytes_vec(self) -> Vec<u8> {
+        debug_assert_eq!(self.bit_len().div_ceil(BYTE_LEN), self.bits.0.buffer.len());
+        self.bits.into_inner().into()
+    }
+
+    pub fn as_reader(&self) -> AperReader<Bits<'_>> {
+        AperReader::from(Bits::from((self.byte_content(), self.bit_len())))
+    }
+
+    #[inline]
+    pub fn scope_pushed<T, E, F: FnOnce(&mut Self) -> Result<T, E>>(
+        &mut self,
+        scope: Scope,
+        f: F,
+    ) -> Result<T, E> {
+        let original = self.scope.replace(scope);
+        let result = f(self);
+        if cfg!(debug_assertions) && result.is_ok() {
+            let scope = core::mem::replace(&mut self.scope, original);
+            // call to .unwrap() is save because this is supposed to be the original from above
+            debug_assert!(
+                scope.clone().unwrap().exhausted(),
+                "Not exhausted: {:?}",
+                scope.unwrap()
+            );
+        } else {
+            self.scope = original;
+        }
+        result
+    }
+
+    #[inline]
+    pub fn scope_stashed<R, F: FnOnce(&mut Self) -> R>(&mut self, f: F) -> R {
+        let scope = self.scope.take();
+        let result = f(self);
+        self.scope = scope;
+        result
+    }
+
+    #[inline]
+    pub fn write_bit_field_entry(&mut self, is_opt: bool, is_present: bool) -> Result<(), Error> {
+        if let Some(scope) = &mut self.scope {
+            scope.write_into_field(&mut self.bits.0, is_opt, is_present)
+        } else if is_opt {
+            self.bits.0.write_bit(is_present)
+        } else {
+            Ok(())
+        }
+    }
+
+    #[inline]
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    pub fn with_buffer<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<T, Error> {
+        if const_map_or!(self.scope, Scope::encode_as_open_type_field, false) {
+            let mut writer = AperWriter::with_capacity(512);
+            let result = f(&mut writer)?;
+            self.write_open_type_field(writer)?;
+            Ok(result)
+        } else {
+            f(self)
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.2, the complete encoding of the given writer
+    /// is added as octet-aligned octet string with an unconstrained length determinant
+    #[inline]
+    fn write_open_type_field(&mut self, writer: AperWriter) -> Result<(), Error> {
+        if writer.bit_len() == 0 {
+            // 11.1.3: an empty encoding is replaced by a single zero octet
+            self.bits.write_octetstring(None, None, false, &[0x00])
+        } else {
+            self.bits
+                .write_octetstring(None, None, false, writer.byte_content())
+        }
+    }
+
+    #[inline]
+    pub fn write_extensible_bit_and_length_or_err(
+        &mut self,
+        extensible: bool,
+        min: Option<u64>,
+        max: Option<u64>,
+        upper_limit: u64,
+        len: u64,
+    ) -> Result<bool, Error> {
+        let unwrapped_min = const_unwrap_or!(min, 0);
+        let unwrapped_max = const_unwrap_or!(max, upper_limit);
+        let out_of_range = len < unwrapped_min || len > unwrapped_max;
+
+        if extensible {
+            self.bits.0.write_bit(out_of_range)?;
+        }
+
+        if out_of_range {
+            if !extensible {
+                return Err(ErrorKind::SizeNotInRange(len, unwrapped_min, unwrapped_max).into());
+            } else {
+                self.bits.write_length_determinant(None, None, len)?;
+            }
+        } else {
+            self.bits.write_length_determinant(min, max, len)?;
+        }
+
+        Ok(out_of_range)
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 30.5.7, the characters are octet-aligned unless
+    /// the string is empty or has a known maximum size of no more than 16 bits
+    #[inline]
+    fn write_known_multiplier_string<T: Into<u32>, I: Iterator<Item = T>>(
+        &mut self,
+        extensible: bool,
+        min: Option<u64>,
+        max: Option<u64>,
+        len: u64,
+        chars: I,
+        bits_per_char: usize,
+    ) -> Result<(), Error> {
+        let out_of_range =
+            self.write_extensible_bit_and_length_or_err(extensible, min, max, u64::MAX, len)?;
+
+        let up_to_16_bits =
+            matches!(max, Some(max) if max.saturating_mul(bits_per_char as u64) <= 16);
+        if len > 0 && (out_of_range || !up_to_16_bits) {
+            self.bits.align()?;
+        }
+
+        for char in chars {
+            self.bits.0.write_bits_with_offset(
+                &char.into().to_be_bytes(),
+                BITS_PER_UNIVERSAL_CHAR - bits_per_char,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// See [`UperWriter::write_charset_string`]
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    fn write_charset_string(
+        &mut self,
+        charset: Charset,
+        extensible: bool,
+        min: Option<u64>,
+        max: Option<u64>,
+        value: &str,
+    ) -> Result<(), Error> {
+        Error::ensure_string_valid(charset, value)?;
+        let chars = value.chars().count() as u64;
+        match charset {
+            Charset::Bmp => self.write_known_multiplier_string(
+                extensible,
+                min,
+                max,
+                chars,
+                value.chars(),
+                BITS_PER_BMP_CHAR,
+            ),
+            Charset::Universal => self.write_known_multiplier_string(
+                extensible,
+                min,
+                max,
+                chars,
+                value.chars(),
+                BITS_PER_UNIVERSAL_CHAR,
+            ),
+            _ => {
+                if !extensible {
+                    let min = const_unwrap_or!(min, 0);
+                    let max = const_unwrap_or!(max, u64::MAX);
+                    if chars < min || chars > max {
+                        return Err(ErrorKind::SizeNotInRange(chars, min, max).into());
+                    }
+                }
+                let mut content = Vec::new();
+                crate::io::ber::write_string(&mut content, charset, value);
+                self.bits.write_octetstring(None, None, false, &content)
+            }
+        }
+    }
+
+    /// See [`UperWriter::write_permitted_alphabet_string`]
+    fn write_permitted_alphabet_string(
+        &mut self,
+        charset: Charset,
+        alphabet: &'static [(char, char)],
+        extensible: bool,
+        min: Option<u64>,
+        max: Option<u64>,
+        value: &str,
+    ) -> Result<(), Error> {
+        let alphabet = EffectiveAlphabet::new(alphabet, true);
+        let codes = value
+            .chars()
+            .enumerate()
+            .map(|(index, char)| alphabet.encode(charset, index, char))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.write_known_multiplier_string(
+            extensible,
+            min,
+            max,
+            codes.len() as u64,
+            codes.into_iter(),
+            alphabet.bits_per_char(),
+        )
+    }
+}
+
+impl Writer for AperWriter {
+    type Error = Error;
+
+    #[inline]
+    fn write_sequence<C: sequence::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            let extension = if let Some(extension_after) = C::EXTENDED_AFTER_FIELD {
+                let bit_pos = w.bits.0.write_position;
+                // if no extension field is present, none will call into overwriting this
+                w.bits.0.write_bit(false)?;
+                Some((extension_after, bit_pos))
+            } else {
+                None
+            };
+
+            // As in UPER, the values for all OPTIONAL flags are written before any field
+            // value is written. This remembers their position, so a later call of `write_opt`
+            // can write them to the buffer
+            let write_pos = w.bits.0.write_position;
+            let range = write_pos..write_pos + C::STD_OPTIONAL_FIELDS as usize;
+            for _ in 0..C::STD_OPTIONAL_FIELDS {
+                if let Err(e) = w.bits.0.write_bit(false) {
+                    w.bits.0.write_position = write_pos; // undo write_bits
+                    return Err(e);
+                }
+            }
+
+            if let Some((extension_after, bit_pos)) = extension {
+                w.scope_pushed(
+                    Scope::ExtensibleSequence {
+                        name: C::NAME,
+                        bit_pos,
+                        opt_bit_field: Some(range),
+                        calls_until_ext_bitfield: (extension_after + 1) as usize,
+                        number_of_ext_fields: (C::FIELD_COUNT - (extension_after + 1)) as usize,
+                        absent_ext_fields: 0,
+                        unknown_ext_fields: UnknownExtensions::default(),
+                    },
+                    f,
+                )
+            } else {
+                w.scope_pushed(Scope::OptBitField(range), f)
+            }
+        })
+    }
+
+    #[inline]
+    fn write_sequence_of<C: sequenceof::Constraint, T: WritableType>(
+        &mut self,
+        slice: &[T::Type],
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.scope_stashed(|w| {
+            w.write_extensible_bit_and_length_or_err(
+                C::EXTENSIBLE,
+                C::MIN,
+                C::MAX,
+                i64::MAX as u64,
+                slice.len() as u64,
+            )?;
+
+            w.scope_stashed(|w| {
+                for value in slice {
+                    T::write_value(w, value)?;
+                }
+                Ok(())
+            })
+        })
+    }
+
+    #[inline]
+    fn write_set<C: set::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        self.write_sequence::<C, F>(f)
+    }
+
+    #[inline]
+    fn write_set_of<C: setof::Constraint, T: WritableType>(
+        &mut self,
+        slice: &[<T as WritableType>::Type],
+    ) -> Result<(), Self::Error> {
+        self.write_sequence_of::<C, T>(slice)
+    }
+
+    #[inline]
+    fn write_enumerated<C: enumerated::Constraint>(
+        &mut self,
+        enumerated: &C,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            w.bits.write_enumeration_index(
+                C::STD_VARIANT_COUNT,
+                C::EXTENSIBLE,
+                enumerated.to_choice_index(),
+            )
+        })
+    }
+
+    #[inline]
+    fn write_choice<C: choice::Constraint>(&mut self, choice: &C) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.scope_stashed(|w| {
+            let index = choice.to_choice_index();
+
+            // this fails if the index is out of range
+            w.bits
+                .write_choice_index(C::STD_VARIANT_COUNT, C::EXTENSIBLE, index)?;
+
+            if index >= C::STD_VARIANT_COUNT {
+                let mut writer = AperWriter::with_capacity(512);
+                choice.write_content(&mut writer)?;
+                w.write_open_type_field(writer)
+            } else {
+                choice.write_content(w)
+            }
+        })
+    }
+
+    #[inline]
+    fn write_open_type<C: opentype::Constraint>(
+        &mut self,
+        opentype: &C,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| match opentype.unknown_bytes() {
+            Some(bytes) => w.bits.write_octetstring(None, None, false, bytes),
+            None => {
+                let mut writer = AperWriter::with_capacity(512);
+                opentype.write_content(&mut writer)?;
+                w.write_open_type_field(writer)
+            }
+        })
+    }
+
+    #[inline]
+    fn check_presence(
+        &mut self,
+        field: &'static str,
+        constraint: PresenceConstraint,
+        present: bool,
+    ) -> Result<(), Self::Error> {
+        match ViolationKind::of_presence(constraint, present) {
+            Some(kind) => Err(ErrorKind::ComponentConstraintViolated(field, kind).into()),
+            None => Ok(()),
+        }
+    }
+
+    #[inline]
+    fn check_value(&mut self, field: &'static str, permitted: bool) -> Result<(), Self::Error> {
+        if permitted {
+            Ok(())
+        } else {
+            Err(
+                ErrorKind::ComponentConstraintViolated(field, ViolationKind::ValueNotPermitted)
+                    .into(),
+            )
+        }
+    }
+
+    #[inline]
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    fn write_opt<T: WritableType>(
+        &mut self,
+        value: Option<&<T as WritableType>::Type>,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(true, const_is_some!(value))?;
+        if let Some(value) = value {
+            self.with_buffer(|w| w.scope_stashed(|w| T::write_value(w, value)))
+        } else {
+            Ok(())
+        }
+    }
+
+    #[inline]
+    fn write_default<C: default::Constraint<Owned = T::Type>, T: WritableType>(
+        &mut self,
+        value: &T::Type,
+    ) -> Result<(), Self::Error> {
+        let present = C::DEFAULT_VALUE.ne(value);
+        self.write_bit_field_entry(true, present)?;
+        if present {
+            self.scope_stashed(|w| T::write_value(w, value))
+        } else {
+            Ok(())
+        }
+    }
+
+    #[inline]
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    fn write_number<T: numbers::Number, C: numbers::Constraint<T>>(
+        &mut self,
+        value: T,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        let value = value.to_i64();
+
+        let max_fn = if C::EXTENSIBLE {
+            let min = const_unwrap_or!(C::MIN, 0);
+            let max = const_unwrap_or!(C::MAX, i64::MAX);
+            value < min || value > max
+        } else {
+            const_is_none!(C::MIN) && const_is_none!(C::MAX)
+        };
+
+        self.with_buffer(|w| {
+            if C::EXTENSIBLE {
+                w.bits.0.write_bit(max_fn)?;
+            }
+            if max_fn {
+                w.bits.write_unconstrained_whole_number(value)
+            } else if const_is_none!(C::MAX) {
+                // ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 13.2.6
+                w.bits
+                    .write_semi_constrained_whole_number(const_unwrap_or!(C::MIN, 0), value)
+            } else {
+                w.bits.write_constrained_whole_number(
+                    const_unwrap_or!(C::MIN, 0),
+                    const_unwrap_or!(C::MAX, i64::MAX),
+                    value,
+                )
+            }
+        })
+    }
+
+    #[inline]
+    fn write_real<C: real::Constraint>(&mut self, value: f64) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        // ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 15
+        // The contents octets of the CER/DER encoding with an unconstrained length determinant
+        let mut content = Vec::new();
+        crate::io::ber::write_real(&mut content, value);
+        self.with_buffer(|w| w.bits.write_octetstring(None, None, false, &content))
+    }
+
+    #[inline]
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    fn write_utf8string<C: utf8string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            if !C::EXTENSIBLE {
+                let chars = value.chars().count() as u64;
+                let min = const_unwrap_or!(C::MIN, 0);
+                let max = const_unwrap_or!(C::MAX, u64::MAX);
+                if chars < min || chars > max {
+                    return Err(ErrorKind::SizeNotInRange(chars, min, max).into());
+                }
+            }
+
+            // ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 30.3
+            // For 'known-multiplier character string types' there is no min/max in the encoding
+            w.bits
+                .write_octetstring(None, None, false, value.as_bytes())
+        })
+    }
+
+    #[inline]
+    fn write_ia5string<C: ia5string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            if let Some(alphabet) = C::PERMITTED_ALPHABET {
+                Error::ensure_string_valid(Charset::Ia5, value)?;
+                return w.write_permitted_alphabet_string(
+                    Charset::Ia5,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                    value,
+                );
+            }
+
+            Error::ensure_string_valid(Charset::Ia5, value)?;
+            w.write_known_multiplier_string(
+                C::EXTENSIBLE,
+                C::MIN,
+                C::MAX,
+                value.chars().count() as u64,
+                value.chars().map(|c| c as u8),
+                BITS_PER_VISIBLE_CHAR,
+            )
+        })
+    }
+
+    #[inline]
+    fn write_numeric_string<C: numericstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            if let Some(alphabet) = C::PERMITTED_ALPHABET {
+                Error::ensure_string_valid(Charset::Numeric, value)?;
+                return w.write_permitted_alphabet_string(
+                    Charset::Numeric,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                    value,
+                );
+            }
+
+            Error::ensure_string_valid(Charset::Numeric, value)?;
+            w.write_known_multiplier_string(
+                C::EXTENSIBLE,
+                C::MIN,
+                C::MAX,
+                value.chars().count() as u64,
+                value.chars().map(|c| match c as u8 - 32 {
+                    0 => 0,
+                    c => c - 15,
+                }),
+                BITS_PER_NUMERIC_CHAR,
+            )
+        })
+    }
+
+    #[inline]
+    fn write_printable_string<C: printablestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            if let Some(alphabet) = C::PERMITTED_ALPHABET {
+                Error::ensure_string_valid(Charset::Printable, value)?;
+                return w.write_permitted_alphabet_string(
+                    Charset::Printable,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                    value,
+                );
+            }
+
+            Error::ensure_string_valid(Charset::Printable, value)?;
+            w.write_known_multiplier_string(
+                C::EXTENSIBLE,
+                C::MIN,
+                C::MAX,
+                value.chars().count() as u64,
+                value.chars().map(|c| c as u8),
+                BITS_PER_VISIBLE_CHAR,
+            )
+        })
+    }
+
+    #[inline]
+    fn write_visible_string<C: visiblestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            if let Some(alphabet) = C::PERMITTED_ALPHABET {
+                Error::ensure_string_valid(Charset::Visible, value)?;
+                return w.write_permitted_alphabet_string(
+                    Charset::Visible,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                    value,
+                );
+            }
+
+            Error::ensure_string_valid(Charset::Visible, value)?;
+            w.write_known_multiplier_string(
+                C::EXTENSIBLE,
+                C::MIN,
+                C::MAX,
+                value.chars().count() as u64,
+                value.chars().map(|c| c as u8),
+                BITS_PER_VISIBLE_CHAR,
+            )
+        })
+    }
+
+    #[inline]
+    fn write_teletex_string<C: teletexstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            w.write_charset_string(Charset::Teletex, C::EXTENSIBLE, C::MIN, C::MAX, value)
+        })
+    }
+
+    #[inline]
+    fn write_graphic_string<C: graphicstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            w.write_charset_string(Charset::Graphic, C::EXTENSIBLE, C::MIN, C::MAX, value)
+        })
+    }
+
+    #[inline]
+    fn write_general_string<C: generalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            w.write_charset_string(Charset::General, C::EXTENSIBLE, C::MIN, C::MAX, value)
+        })
+    }
+
+    #[inline]
+    fn write_universal_string<C: universalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            if let Some(alphabet) = C::PERMITTED_ALPHABET {
+                Error::ensure_string_valid(Charset::Universal, value)?;
+                return w.write_permitted_alphabet_string(
+                    Charset::Universal,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                    value,
+                );
+            }
+
+            w.write_charset_string(Charset::Universal, C::EXTENSIBLE, C::MIN, C::MAX, value)
+        })
+    }
+
+    #[inline]
+    fn write_bmp_string<C: bmpstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            if let Some(alphabet) = C::PERMITTED_ALPHABET {
+                Error::ensure_string_valid(Charset::Bmp, value)?;
+                return w.write_permitted_alphabet_string(
+                    Charset::Bmp,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                    value,
+                );
+            }
+
+            w.write_charset_string(Charset::Bmp, C::EXTENSIBLE, C::MIN, C::MAX, value)
+        })
+    }
+
+    #[inline]
+    fn write_octet_string<C: octetstring::Constraint>(
+        &mut self,
+        value: &[u8],
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            w.bits
+                .write_octetstring(C::MIN, C::MAX, C::EXTENSIBLE, value)
+        })
+    }
+
+    #[inline]
+    fn write_bit_string<C: bitstring::Constraint>(
+        &mut self,
+        value: &[u8],
+        bit_len: u64,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            w.bits
+                .write_bitstring(C::MIN, C::MAX, C::EXTENSIBLE, value, 0, bit_len)
+        })
+    }
+
+    #[inline]
+    fn write_boolean<C: boolean::Constraint>(&mut self, value: bool) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| w.bits.write_boolean(value))
+    }
+
+    #[inline]
+    fn write_null<C: null::Constraint>(&mut self, _value: &Null) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    #[inline]
+    fn write_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+        value: &ObjectIdentifier,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        // ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 24
+        let mut content = Vec::new();
+        crate::io::ber::write_object_identifier(&mut content, C::TAG, value.arcs())
+            .map_err(Error::invalid_object_identifier)?;
+        self.with_buffer(|w| w.bits.write_octetstring(None, None, false, &content))
+    }
+
+    #[inline]
+    fn write_relative_oid<C: relativeoid::Constraint>(
+        &mut self,
+        value: &RelativeOid,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        // ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 25
+        let mut content = Vec::new();
+        crate::io::ber::write_relative_oid(&mut content, value.arcs());
+        self.with_buffer(|w| w.bits.write_octetstring(None, None, false, &content))
+    }
+
+    #[inline]
+    fn write_time<C: time::Constraint, T: time::TimeType>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        // ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 32 and 33
+        let value = value.to_string();
+        self.with_buffer(|w| {
+            Error::ensure_string_valid(Charset::Visible, &value)?;
+            w.write_known_multiplier_string(
+                false,
+                None,
+                None,
+                value.len() as u64,
+                value.bytes(),
+                BITS_PER_VISIBLE_CHAR,
+            )
+        })
+    }
+}
+
+/// The same as [`UperReader`], but expects fields to be octet-aligned according to the
+/// ALIGNED variant of ITU-T X.691 | ISO/IEC 8825-2:2015
+pub struct AperReader<B: ScopedBitRead> {
+    bits: Aligned<B>,
+    scope: Option<Scope>,
+}
+
+impl<'a, I: Into<Bits<'a>>> From<I> for AperReader<Bits<'a>> {
+    fn from(bits: I) -> Self {
+        Self {
+            bits: Aligned::new(bits.into()),
+            scope: None,
+        }
+    }
+}
+
+impl<B: ScopedBitRead> AperReader<B> {
+    #[inline]
+    pub fn bits_remaining(&self) -> usize {
+        self.bits.0.remaining()
+    }
+
+    #[inline]
+    pub fn scope_pushed<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        scope: Scope,
+        f: F,
+    ) -> Result<T, Error> {
+        let original = self.scope.replace(scope);
+        let result = f(self);
+        if cfg!(debug_assertions) && result.is_ok() {
+            let scope = core::mem::replace(&mut self.scope, original);
+            // call to .unwrap() is save because this is supposed to be the original from above
+            debug_assert!(
+                scope.clone().unwrap().exhausted(),
+                "Not exhausted: {:?}",
+                scope.unwrap()
+            );
+        } else {
+            self.scope = original;
+        }
+        result
+    }
+
+    #[inline]
+    pub fn scope_stashed<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<T, Error> {
+        let scope = self.scope.take();
+        let result = f(self);
+        self.scope = scope;
+        result
+    }
+
+    #[inline]
+    pub fn read_whole_sub_slice<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        length_bytes: usize,
+        f: F,
+    ) -> Result<T, Error> {
+        let end_position = self.bits.0.pos() + (length_bytes * BYTE_LEN);
+        let len_original = self.bits.0.len();
+        self.bits.0.set_len(end_position);
+        let result = f(self);
+        // extend to original position
+        let len = self.bits.0.set_len(len_original);
+        debug_assert_eq!(len_original, len);
+        if result.is_ok() {
+            // on successful read, skip the slice
+            self.bits.0.set_pos(end_position);
+        }
+        result
+    }
+
+    /// Skips the extended fields that are unknown to the schema, after all known fields are read
+    fn skip_unknown_extension_fields(&mut self) -> Result<(), Error> {
+        if let Some(
+            scope @ Scope::ExtensibleSequence {
+                calls_until_ext_bitfield: 0,
+                ..
+            },
+        ) = &mut self.scope
+        {
+            scope.read_extension_header(
+                #[cfg(feature = "descriptive-deserialize-errors")]
+                &mut Vec::new(),
+                &mut self.bits.0,
+            )?;
+        }
+        if let Some(Scope::AllBitField(range)) = &mut self.scope {
+            let flags = core::mem::replace(range, range.end..range.end);
+            for pos in flags {
+                if self.bits.0.with_read_position_at(pos, |b| b.read_bit())? {
+                    let _ = self.bits.read_octetstring(None, None, false)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[inline]
+    pub fn read_bit_field_entry(&mut self, is_opt: bool) -> Result<Option<bool>, Error> {
+        if let Some(scope) = &mut self.scope {
+            scope.read_from_field(
+                #[cfg(feature = "descriptive-deserialize-errors")]
+                &mut Vec::new(),
+                &mut self.bits.0,
+                is_opt,
+            )
+        } else if is_opt {
+            Some(self.bits.0.read_bit()).transpose()
+        } else {
+            Ok(None)
+        }
+    }
+
+    #[inline]
+    pub fn with_buffer<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<T, Error> {
+        if self
+            .scope
+            .as_ref()
+            .map(Scope::encode_as_open_type_field)
+            .unwrap_or(false)
+        {
+            self.read_open_type_field(f)
+        } else {
+            f(self)
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.2, the complete encoding is nested into an
+    /// octet-aligned octet string with an unconstrained length determinant
+    #[inline]
+    fn read_open_type_field<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<T, Error> {
+        let len = self.bits.read_length_determinant(None, None)?;
+        self.read_whole_sub_slice(len as usize, f)
+    }
+
+    #[inline]
+    fn read_known_multiplier_string(
+        &mut self,
+        extensible: bool,
+        min: Option<u64>,
+        max: Option<u64>,
+        bits_per_char: usize,
+    ) -> Result<Vec<u8>, Error> {
+        let len = self.read_known_multiplier_length(extensible, min, max, bits_per_char)?;
+        let mut buffer = vec![0u8; len as usize];
+        buffer.chunks_exact_mut(1).try_for_each(|chunk| {
+            self.bits
+                .0
+                .read_bits_with_offset(chunk, BYTE_LEN - bits_per_char)
+        })?;
+        Ok(buffer)
+    }
+
+    /// Reads the characters of a BMPString or UniversalString as their BER contents octets
+    fn read_wide_known_multiplier_string(
+        &mut self,
+        extensible: bool,
+        min: Option<u64>,
+        max: Option<u64>,
+        bits_per_char: usize,
+    ) -> Result<Vec<u8>, Error> {
+        let len = self.read_known_multiplier_length(extensible, min, max, bits_per_char)?;
+        let mut buffer = vec![0u8; len as usize * bits_per_char / BYTE_LEN];
+        self.bits.0.read_bits(&mut buffer)?;
+        Ok(buffer)
+    }
+
+    /// Reads the length and the padding bits that precede the characters
+    fn read_known_multiplier_length(
+        &mut self,
+        extensible: bool,
+        min: Option<u64>,
+        max: Option<u64>,
+        bits_per_char: usize,
+    ) -> Result<u64, Error> {
+        let out_of_range = extensible && self.bits.0.read_bit()?;
+        let len = if out_of_range {
+            self.bits.read_length_determinant(None, None)?
+        } else {
+            self.bits.read_length_determinant(min, max)?
+        };
+
+        let up_to_16_bits =
+            matches!(max, Some(max) if max.saturating_mul(bits_per_char as u64) <= 16);
+        if len > 0 && (out_of_range || !up_to_16_bits) {
+            self.bits.align()?;
+        }
+        Ok(len)
+    }
+
+    /// See [`AperWriter::write_charset_string`]
+    fn read_charset_string(
+        &mut self,
+        charset: Charset,
+        extensible: bool,
+        min: Option<u64>,
+        max: Option<u64>,
+    ) -> Result<String, Error> {
+        let content = match charset {
+            Charset::Bmp => {
+                self.read_wide_known_multiplier_string(extensible, min, max, BITS_PER_BMP_CHAR)?
+            }
+            Charset::Universal => self.read_wide_known_multiplier_string(
+                extensible,
+                min,
+                max,
+                BITS_PER_UNIVERSAL_CHAR,
+            )?,
+            _ => self.bits.read_octetstring(None, None, false)?,
+        };
+        let string = crate::io::per::decode_chars(charset, &content)?;
+        Error::ensure_string_valid(charset, &string)?;
+        Ok(string)
+    }
+
+    /// See [`AperWriter::write_permitted_alphabet_string`]
+    fn read_permitted_alphabet_string(
+        &mut self,
+        charset: Charset,
+        alphabet: &'static [(char, char)],
+        extensible: bool,
+        min: Option<u64>,
+        max: Option<u64>,
+    ) -> Result<String, Error> {
+        let alphabet = EffectiveAlphabet::new(alphabet, true);
+        let len =
+            self.read_known_multiplier_length(extensible, min, max, alphabet.bits_per_char())?;
+
+        (0..len as usize)
+            .map(|index| {
+                let mut code = [0u8; 4];
+                self.bits.0.read_bits_with_offset(
+                    &mut code,
+                    BITS_PER_UNIVERSAL_CHAR - alphabet.bits_per_char(),
+                )?;
+                alphabet.decode(charset, index, u32::from_be_bytes(code))
+            })
+            .collect()
+    }
+}
+
+impl<B: ScopedBitRead> Reader for AperReader<B> {
+    type Error = Error;
+
+    #[inline]
+    fn read_sequence<
+        C: sequence::Constraint,
+        S: Sized,
+        F: Fn(&mut Self) -> Result<S, Self::Error>,
+    >(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| {
+            let extension_after = if let Some(extension_after) = C::EXTENDED_AFTER_FIELD {
+                let bit_pos = r.bits.0.pos();
+                if r.bits.0.read_bit()? {
+                    Some((extension_after, bit_pos))
+                } else {
+                    None
+                }
+            } else {
+                None
+            };
+
+            // As in UPER, the values for all OPTIONAL flags are written before any field
+            // value is written. This remembers their position, so a later call of `read_opt`
+            // can retrieve them from the buffer
+            if r.bits.0.remaining() < C::STD_OPTIONAL_FIELDS as usize {
+                return Err(ErrorKind::EndOfStream.into());
+            }
+
+            let range = r.bits.0.pos()..r.bits.0.pos() + C::STD_OPTIONAL_FIELDS as usize;
+            r.bits.0.set_pos(range.end); // skip optional
+
+            if let Some((extension_after, bit_pos)) = extension_after {
+                r.scope_pushed(
+                    Scope::ExtensibleSequence {
+                        name: C::NAME,
+                        bit_pos,
+                        opt_bit_field: Some(range),
+                        calls_until_ext_bitfield: (extension_after + 1) as usize,
+                        number_of_ext_fields: (C::FIELD_COUNT - (extension_after + 1)) as usize,
+                        absent_ext_fields: 0,
+                        unknown_ext_fields: UnknownExtensions::default(),
+                    },
+                    |r| {
+                        let value = f(r)?;
+                        r.skip_unknown_extension_fields()?;
+                        Ok(value)
+                    },
+                )
+            } else {
+                r.scope_pushed(Scope::OptBitField(range), f)
+            }
+        })
+    }
+
+    #[inline]
+    fn read_sequence_of<C: sequenceof::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<Vec<T::Type>, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| {
+            let len = if C::EXTENSIBLE && r.bits.0.read_bit()? {
+                r.bits.read_length_determinant(None, None)?
+            } else {
+                r.bits.read_length_determinant(C::MIN, C::MAX)?
+            };
+
+            if len > 0 {
+                r.scope_stashed(|r| {
+                    let mut vec = Vec::with_capacity(len as usize);
+                    for _ in 0..len {
+                        vec.push(T::read_value(r)?);
+                    }
+                    Ok(vec)
+                })
+            } else {
+                Ok(Vec::new())
+            }
+        })
+    }
+
+    #[inline]
+    fn read_set<C: set::Constraint, S: Sized, F: Fn(&mut Self) -> Result<S, Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        self.read_sequence::<C, S, F>(f)
+    }
+
+    #[inline]
+    fn read_set_of<C: setof::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<Vec<<T as ReadableType>::Type>, Self::Error> {
+        self.read_sequence_of::<C, T>()
+    }
+
+    #[inline]
+    fn read_enumerated<C: enumerated::Constraint>(&mut self) -> Result<C, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| {
+            r.bits
+                .read_enumeration_index(C::STD_VARIANT_COUNT, C::EXTENSIBLE)
+        })
+        .and_then(|index| {
+            C::from_choice_index(index)
+                .ok_or_else(|| ErrorKind::InvalidChoiceIndex(index, C::VARIANT_COUNT).into())
+        })
+    }
+
+    #[inline]
+    fn read_choice<C: choice::Constraint>(&mut self) -> Result<C, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.scope_stashed(|r| {
+            let index = r
+                .bits
+                .read_choice_index(C::STD_VARIANT_COUNT, C::EXTENSIBLE)?;
+            if index >= C::STD_VARIANT_COUNT {
+                r.read_open_type_field(|r| Ok((index, C::read_content(index, r)?)))
+            } else {
+                Ok((index, C::read_content(index, r)?))
+            }
+            .and_then(|(index, content)| {
+                content.ok_or_else(|| ErrorKind::InvalidChoiceIndex(index, C::VARIANT_COUNT).into())
+            })
+        })
+    }
+
+    #[inline]
+    fn read_open_type<C: opentype::Constraint>(
+        &mut self,
+        key: Option<usize>,
+    ) -> Result<C, Self::Error> {
+        let key = key.ok_or(ErrorKind::InvalidOpenTypeKey(C::NAME))?;
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| {
+            if !C::KEYS.contains(&key) {
+                // keep the content of the open type field for a key unknown to the object set
+                let bytes = r.bits.read_octetstring(None, None, false)?;
+                return C::from_unknown_key(key, bytes).ok_or_else(|| {
+                    ErrorKind::InvalidChoiceIndex(key as u64, C::VARIANT_COUNT).into()
+                });
+            }
+            r.read_open_type_field(|r| C::read_content(key, r))
+                .and_then(|content| {
+                    content.ok_or_else(|| {
+                        ErrorKind::InvalidChoiceIndex(key as u64, C::VARIANT_COUNT).into()
+                    })
+                })
+        })
+    }
+
+    #[inline]
+    fn read_present<T: ReadableType>(
+        &mut self,
+        field: &'static str,
+    ) -> Result<T::Type, Self::Error> {
+        self.read_opt::<T>()?.ok_or_else(|| {
+            ErrorKind::ComponentConstraintViolated(field, ViolationKind::ComponentNotPresent).into()
+        })
+    }
+
+    #[inline]
+    fn read_opt<T: ReadableType>(
+        &mut self,
+    ) -> Result<Option<<T as ReadableType>::Type>, Self::Error> {
+        // unwrap: as opt-field this must and will return some value
+        if self.read_bit_field_entry(true)?.unwrap() {
+            self.with_buffer(|w| w.scope_stashed(T::read_value))
+                .map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    #[inline]
+    fn read_default<C: default::Constraint<Owned = T::Type>, T: ReadableType>(
+        &mut self,
+    ) -> Result<T::Type, Self::Error> {
+        // unwrap: as opt-field this must and will return some value
+        if self.read_bit_field_entry(true)?.unwrap() {
+            self.scope_stashed(T::read_value)
+        } else {
+            Ok(C::DEFAULT_VALUE.to_owned())
+        }
+    }
+
+    #[inline]
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    fn read_number<T: numbers::Number, C: numbers::Constraint<T>>(
+        &mut self,
+    ) -> Result<T, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| {
+            let unconstrained = if C::EXTENSIBLE {
+                r.bits.0.read_bit()?
+            } else {
+                const_is_none!(C::MIN) && const_is_none!(C::MAX)
+            };
+
+            if unconstrained {
+                r.bits.read_unconstrained_whole_number()
+            } else if const_is_none!(C::MAX) {
+                // ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 13.2.6
+                r.bits
+                    .read_semi_constrained_whole_number(const_unwrap_or!(C::MIN, 0))
+            } else {
+                r.bits.read_constrained_whole_number(
+                    const_unwrap_or!(C::MIN, 0),
+                    const_unwrap_or!(C::MAX, i64::MAX),
+                )
+            }
+            .map(T::from_i64)
+        })
+    }
+
+    #[inline]
+    fn read_real<C: real::Constraint>(&mut self) -> Result<f64, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| {
+            let content = r.bits.read_octetstring(None, None, false)?;
+            crate::io::ber::read_real(C::TAG, &content).map_err(Error::invalid_real)
+        })
+    }
+
+    #[inline]
+    fn read_utf8string<C: utf8string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| {
+            // ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 30.3
+            // For 'known-multiplier character string types' there is no min/max in the encoding
+            let octets = r.bits.read_octetstring(None, None, false)?;
+            String::from_utf8(octets).map_err(|e| ErrorKind::FromUtf8Error(e).into())
+        })
+    }
+
+    #[inline]
+    fn read_ia5string<C: ia5string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| {
+            if let Some(alphabet) = C::PERMITTED_ALPHABET {
+                return r.read_permitted_alphabet_string(
+                    Charset::Ia5,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                );
+            }
+
+            let buffer = r.read_known_multiplier_string(
+                C::EXTENSIBLE,
+                C::MIN,
+                C::MAX,
+                BITS_PER_VISIBLE_CHAR,
+            )?;
+            String::from_utf8(buffer).map_err(|e| ErrorKind::FromUtf8Error(e).into())
+        })
+    }
+
+    #[inline]
+    fn read_numeric_string<C: numericstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| {
+            if let Some(alphabet) = C::PERMITTED_ALPHABET {
+                return r.read_permitted_alphabet_string(
+                    Charset::Numeric,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                );
+            }
+
+            let mut buffer = r.read_known_multiplier_string(
+                C::EXTENSIBLE,
+                C::MIN,
+                C::MAX,
+                BITS_PER_NUMERIC_CHAR,
+            )?;
+            for char in buffer.iter_mut() {
+                *char = match *char {
+                    0_u8 => 32_u8,
+                    c => 32_u8 + 15 + c,
+                };
+            }
+            String::from_utf8(buffer).map_err(|e| ErrorKind::FromUtf8Error(e).into())
+        })
+    }
+
+    #[inline]
+    fn read_printable_string<C: printablestring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| {
+            if let Some(alphabet) = C::PERMITTED_ALPHABET {
+                return r.read_permitted_alphabet_string(
+                    Charset::Printable,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                );
+            }
+
+            let buffer = r.read_known_multiplier_string(
+                C::EXTENSIBLE,
+                C::MIN,
+                C::MAX,
+                BITS_PER_VISIBLE_CHAR,
+            )?;
+            String::from_utf8(buffer).map_err(|e| ErrorKind::FromUtf8Error(e).into())
+        })
+    }
+
+    #[inline]
+    fn read_visible_string<C: visiblestring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| {
+            if let Some(alphabet) = C::PERMITTED_ALPHABET {
+                return r.read_permitted_alphabet_string(
+                    Charset::Visible,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                );
+            }
+
+            let buffer = r.read_known_multiplier_string(
+                C::EXTENSIBLE,
+                C::MIN,
+                C::MAX,
+                BITS_PER_VISIBLE_CHAR,
+            )?;
+            String::from_utf8(buffer).map_err(|e| ErrorKind::FromUtf8Error(e).into())
+        })
+    }
+
+    #[inline]
+    fn read_teletex_string<C: teletexstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| r.read_charset_string(Charset::Teletex, C::EXTENSIBLE, C::MIN, C::MAX))
+    }
+
+    #[inline]
+    fn read_graphic_string<C: graphicstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| r.read_charset_string(Charset::Graphic, C::EXTENSIBLE, C::MIN, C::MAX))
+    }
+
+    #[inline]
+    fn read_general_string<C: generalstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| r.read_charset_string(Charset::General, C::EXTENSIBLE, C::MIN, C::MAX))
+    }
+
+    #[inline]
+    fn read_universal_string<C: universalstring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| {
+            if let Some(alphabet) = C::PERMITTED_ALPHABET {
+                return r.read_permitted_alphabet_string(
+                    Charset::Universal,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                );
+            }
+
+            r.read_charset_string(Charset::Universal, C::EXTENSIBLE, C::MIN, C::MAX)
+        })
+    }
+
+    #[inline]
+    fn read_bmp_string<C: bmpstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| {
+            if let Some(alphabet) = C::PERMITTED_ALPHABET {
+                return r.read_permitted_alphabet_string(
+                    Charset::Bmp,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                );
+            }
+
+            r.read_charset_string(Charset::Bmp, C::EXTENSIBLE, C::MIN, C::MAX)
+        })
+    }
+
+    #[inline]
+    fn read_octet_string<C: octetstring::Constraint>(&mut self) -> Result<Vec<u8>, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| r.bits.read_octetstring(C::MIN, C::MAX, C::EXTENSIBLE))
+    }
+
+    #[inline]
+    fn read_bit_string<C: bitstring::Constraint>(&mut self) -> Result<(Vec<u8>, u64), Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| r.bits.read_bitstring(C::MIN, C::MAX, C::EXTENSIBLE))
+    }
+
+    #[inline]
+    fn read_boolean<C: boolean::Constraint>(&mut self) -> Result<bool, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| r.bits.read_boolean())
+    }
+
+    #[inline]
+    fn read_null<C: null::Constraint>(&mut self) -> Result<Null, Self::Error> {
+        Ok(Null)
+    }
+
+    #[inline]
+    fn read_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+    ) -> Result<ObjectIdentifier, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| {
+            let content = r.bits.read_octetstring(None, None, false)?;
+            crate::io::ber::read_object_identifier(C::TAG, &content)
+                .map(ObjectIdentifier::new)
+                .map_err(Error::invalid_object_identifier)
+        })
+    }
+
+    #[inline]
+    fn read_relative_oid<C: relativeoid::Constraint>(
+        &mut self,
+    ) -> Result<RelativeOid, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| {
+            let content = r.bits.read_octetstring(None, None, false)?;
+            crate::io::ber::read_relative_oid(C::TAG, &content)
+                .map(RelativeOid::new)
+                .map_err(Error::invalid_object_identifier)
+        })
+    }
+
+    #[inline]
+    fn read_time<C: time::Constraint, T: time::TimeType>(&mut self) -> Result<T, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| {
+            let buffer =
+                r.read_known_multiplier_string(false, None, None, BITS_PER_VISIBLE_CHAR)?;
+            String::from_utf8(buffer)
+                .map_err(|e| Error::from(ErrorKind::FromUtf8Error(e)))?
+                .parse::<T>()
+                .map_err(|e| ErrorKind::InvalidTime(e).into())
+        })
+    }
+}
+
+pub trait AperDecodable<'a, I: Into<Bits<'a>> + 'a> {
+    fn decode_from_aper(bits: I) -> Result<Self, Error>
+    where
+        Self: Sized;
+}
+
+impl<'a, R: Readable, I: Into<Bits<'a>> + 'a> AperDecodable<'a, I> for R {
+    fn decode_from_aper(bits: I) -> Result<Self, Error>
+    where
+        Self: Sized,
+    {
+        let mut reader = AperReader::from(bits);
+        Self::read(&mut reader)
+    }
+}
@@ -0,0 +1,1227 @@
+use crate::io::ber::Error;
+use crate::io::ber::ErrorKind;
+use crate::io::ber::Length;
+use crate::io::ber::END_OF_CONTENTS;
+use crate::io::ber::{self, Identifier};
+use crate::model::{Charset, PresenceConstraint, Tag};
+use crate::syn::*;
+use alloc::borrow::Cow;
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::ops::Range;
+
+/// Writes values according to the Basic Encoding Rules of ITU-T X.690 | ISO/IEC 8825-1, always
+/// using the definite form of the length octets. If created through [`BerWriter::der`], the
+/// values are written according to the Distinguished Encoding Rules instead.
+///
+/// The identifier of a value is the `TAG` of its constraint. The tag of a field that refers to
+/// another type replaces the tag of the referenced type (implicit tagging), except for CHOICE
+/// and open type values and for fields with an explicit tag, which are wrapped in a constructed
+/// element with the tag of the field (explicit tagging). Fields that have their DEFAULT value are
+/// not written.
+#[derive(Default)]
+pub struct BerWriter {
+    buffer: Vec<u8>,
+    distinguished: bool,
+    tag: Option<Tag>,
+}
+
+impl BerWriter {
+    /// A writer for the Distinguished Encoding Rules, which - in addition to what [`BerWriter`]
+    /// does anyway - sorts the components of SET OF values by their encoding
+    /// (ITU-T X.690 | ISO/IEC 8825-1, 11.6)
+    pub fn der() -> Self {
+        Self {
+            distinguished: true,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_capacity(capacity_bytes: usize) -> Self {
+        Self {
+            buffer: Vec::with_capacity(capacity_bytes),
+            ..Default::default()
+        }
+    }
+
+    /// See [`BerWriter::der`]
+    pub fn der_with_capacity(capacity_bytes: usize) -> Self {
+        Self {
+            distinguished: true,
+            ..Self::with_capacity(capacity_bytes)
+        }
+    }
+
+    pub const fn is_der(&self) -> bool {
+        self.distinguished
+    }
+
+    pub fn byte_content(&self) -> &[u8] {
+        &self.buffer[..]
+    }
+
+    pub fn into_bytes_vec(self) -> Vec<u8> {
+        self.buffer
+    }
+
+    pub fn as_reader(&self) -> BerReader<'_> {
+        BerReader::from(self.byte_content())
+    }
+
+    /// Returns the tag of the referencing field or - if there is none - the given tag
+    #[inline]
+    fn tag_or(&mut self, tag: Tag) -> Tag {
+        self.tag.take().unwrap_or(tag)
+    }
+
+    #[inline]
+    fn write_primitive(&mut self, tag: Tag, content: &[u8]) -> Result<(), Error> {
+        ber::write_identifier(&mut self.buffer, tag, false);
+        ber::write_length(&mut self.buffer, content.len());
+        self.buffer.extend_from_slice(content);
+        Ok(())
+    }
+
+    fn write_constructed<F: FnOnce(&mut Self) -> Result<(), Error>>(
+        &mut self,
+        tag: Tag,
+        f: F,
+    ) -> Result<(), Error> {
+        let outer = core::mem::take(&mut self.buffer);
+        let result = f(self);
+        let content = core::mem::replace(&mut self.buffer, outer);
+        result?;
+        ber::write_identifier(&mut self.buffer, tag, true);
+        ber::write_length(&mut self.buffer, content.len());
+        self.buffer.extend_from_slice(&content);
+        Ok(())
+    }
+
+    /// CHOICE and open type values have no tag on their own, the tag of a referencing field
+    /// is therefore always explicit (ITU-T X.680 | ISO/IEC 8824-1, 31.2.7)
+    fn write_explicitly_tagged<F: FnOnce(&mut Self) -> Result<(), Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Error> {
+        match self.tag.take() {
+            Some(tag) => self.write_constructed(tag, f),
+            None => f(self),
+        }
+    }
+
+    fn write_string(
+        &mut self,
+        tag: Tag,
+        value: &str,
+        min: Option<u64>,
+        max: Option<u64>,
+        extensible: bool,
+    ) -> Result<(), Error> {
+        check_size(value.chars().count() as u64, min, max, extensible)?;
+        let tag = self.tag_or(tag);
+        self.write_primitive(tag, value.as_bytes())
+    }
+
+    /// Writes a character string whose contents octets differ from its UTF-8 representation
+    fn write_charset_string(
+        &mut self,
+        tag: Tag,
+        charset: Charset,
+        value: &str,
+        min: Option<u64>,
+        max: Option<u64>,
+        extensible: bool,
+    ) -> Result<(), Error> {
+        Error::ensure_string_valid(charset, value)?;
+        check_size(value.chars().count() as u64, min, max, extensible)?;
+        let tag = self.tag_or(tag);
+        let mut content = Vec::new();
+        ber::write_string(&mut content, charset, value);
+        self.write_primitive(tag, &content)
+    }
+}
+
+impl Writer for BerWriter {
+    type Error = Error;
+
+    #[inline]
+    fn write_sequence<C: sequence::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        if C::TRANSPARENT {
+            f(self)
+        } else {
+            let tag = self.tag_or(C::TAG);
+            self.write_constructed(tag, f)
+        }
+    }
+
+    #[inline]
+    fn write_sequence_of<C: sequenceof::Constraint, T: WritableType>(
+        &mut self,
+        slice: &[T::Type],
+    ) -> Result<(), Self::Error> {
+        check_size(slice.len() as u64, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        let tag = self.tag_or(C::TAG);
+        self.write_constructed(tag, |w| {
+            for value in slice {
+                T::write_value(w, value)?;
+            }
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_set<C: set::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        // the fields of a SET are already in the canonical order of their tags
+        self.write_sequence::<C, F>(f)
+    }
+
+    #[inline]
+    fn write_set_of<C: setof::Constraint, T: WritableType>(
+        &mut self,
+        slice: &[T::Type],
+    ) -> Result<(), Self::Error> {
+        if !self.distinguished {
+            return self.write_sequence_of::<C, T>(slice);
+        }
+
+        check_size(slice.len() as u64, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        let tag = self.tag_or(C::TAG);
+        self.write_constructed(tag, |w| {
+            // ITU-T X.690 | ISO/IEC 8825-1, 11.6
+            let mut components = slice
+                .iter()
+                .map(|value| {
+                    let mut writer = BerWriter::der();
+                    T::write_value(&mut writer, value).map(|_| writer.buffer)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            components.sort_unstable();
+            for component in components {
+                w.buffer.extend_from_slice(&component);
+            }
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_enumerated<C: enumerated::Constraint>(
+        &mut self,
+        enumerated: &C,
+    ) -> Result<(), Self::Error> {
+        let tag = self.tag_or(C::TAG);
+        let mut content = Vec::with_capacity(core::mem::size_of::<i64>());
+        ber::write_integer(&mut content, enumerated.to_choice_index() as i64);
+        self.write_primitive(tag, &content)
+    }
+
+    #[inline]
+    fn write_complex<C: complex::Constraint, F: FnOnce(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        if C::TAGGED {
+            // the outermost tag wins if the referenced type is just another reference
+            self.tag.get_or_insert(C::TAG);
+        }
+        let result = f(self);
+        self.tag = None;
+        result
+    }
+
+    #[inline]
+    fn write_explicit<C: explicit::Constraint, F: FnOnce(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        // ITU-T X.690 | ISO/IEC 8825-1, 8.14.3
+        let tag = self.tag_or(C::TAG);
+        self.write_constructed(tag, f)
+    }
+
+    #[inline]
+    fn write_choice<C: choice::Constraint>(&mut self, choice: &C) -> Result<(), Self::Error> {
+        self.write_explicitly_tagged(|w| choice.write_content(w))
+    }
+
+    #[inline]
+    fn write_open_type<C: opentype::Constraint>(
+        &mut self,
+        opentype: &C,
+    ) -> Result<(), Self::Error> {
+        if opentype.unknown_bytes().is_some() {
+            // the preserved content is not encoded according to these encoding rules
+            let key = opentype.to_choice_index() as u64;
+            return Err(ErrorKind::InvalidChoiceIndex(key, C::VARIANT_COUNT).into());
+        }
+        self.write_explicitly_tagged(|w| opentype.write_content(w))
+    }
+
+    #[inline]
+    fn check_presence(
+        &mut self,
+        field: &'static str,
+        constraint: PresenceConstraint,
+        present: bool,
+    ) -> Result<(), Self::Error> {
+        match ViolationKind::of_presence(constraint, present) {
+            Some(kind) => Err(ErrorKind::ComponentConstraintViolated(field, kind).into()),
+            None => Ok(()),
+        }
+    }
+
+    #[inline]
+    fn check_value(&mut self, field: &'static str, permitted: bool) -> Result<(), Self::Error> {
+        if permitted {
+            Ok(())
+        } else {
+            Err(
+                ErrorKind::ComponentConstraintViolated(field, ViolationKind::ValueNotPermitted)
+                    .into(),
+            )
+        }
+    }
+
+    #[inline]
+    fn write_opt<T: WritableType>(&mut self, value: Option<&T::Type>) -> Result<(), Self::Error> {
+        match value {
+            Some(value) => T::write_value(self, value),
+            None => Ok(()),
+        }
+    }
+
+    #[inline]
+    fn write_default<C: default::Constraint<Owned = T::Type>, T: WritableType>(
+        &mut self,
+        value: &T::Type,
+    ) -> Result<(), Self::Error> {
+        // ITU-T X.690 | ISO/IEC 8825-1, 11.5
+        if C::DEFAULT_VALUE.ne(value) {
+            T::write_value(self, value)
+        } else {
+            Ok(())
+        }
+    }
+
+    #[inline]
+    fn write_number<T: numbers::Number, C: numbers::Constraint<T>>(
+        &mut self,
+        value: T,
+    ) -> Result<(), Self::Error> {
+        let value = value.to_i64();
+        check_range(value, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        let tag = self.tag_or(C::TAG);
+        let mut content = Vec::with_capacity(core::mem::size_of::<i64>());
+        ber::write_integer(&mut content, value);
+        self.write_primitive(tag, &content)
+    }
+
+    #[inline]
+    fn write_real<C: real::Constraint>(&mut self, value: f64) -> Result<(), Self::Error> {
+        let tag = self.tag_or(C::TAG);
+        let mut content = Vec::new();
+        ber::write_real(&mut content, value);
+        self.write_primitive(tag, &content)
+    }
+
+    #[inline]
+    fn write_utf8string<C: utf8string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_string(C::TAG, value, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn write_ia5string<C: ia5string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        Error::ensure_string_valid(Charset::Ia5, value)?;
+        self.write_string(C::TAG, value, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn write_numeric_string<C: numericstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        Error::ensure_string_valid(Charset::Numeric, value)?;
+        self.write_string(C::TAG, value, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn write_visible_string<C: visiblestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        Error::ensure_string_valid(Charset::Visible, value)?;
+        self.write_string(C::TAG, value, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn write_printable_string<C: printablestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        Error::ensure_string_valid(Charset::Printable, value)?;
+        self.write_string(C::TAG, value, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn write_teletex_string<C: teletexstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_charset_string(
+            C::TAG,
+            Charset::Teletex,
+            value,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn write_graphic_string<C: graphicstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_charset_string(
+            C::TAG,
+            Charset::Graphic,
+            value,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn write_general_string<C: generalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_charset_string(
+            C::TAG,
+            Charset::General,
+            value,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn write_universal_string<C: universalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_charset_string(
+            C::TAG,
+            Charset::Universal,
+            value,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn write_bmp_string<C: bmpstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_charset_string(C::TAG, Charset::Bmp, value, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn write_octet_string<C: octetstring::Constraint>(
+        &mut self,
+        value: &[u8],
+    ) -> Result<(), Self::Error> {
+        check_size(value.len() as u64, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        let tag = self.tag_or(C::TAG);
+        self.write_primitive(tag, value)
+    }
+
+    #[inline]
+    fn write_bit_string<C: bitstring::Constraint>(
+        &mut self,
+        value: &[u8],
+        bit_len: u64,
+    ) -> Result<(), Self::Error> {
+        check_size(bit_len, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        let tag = self.tag_or(C::TAG);
+        let byte_len = bit_len.div_ceil(8) as usize;
+        let unused_bits = (byte_len * 8) as u64 - bit_len;
+        let value = value.get(..byte_len).ok_or(ErrorKind::InvalidContent(
+            tag,
+            "the bit length exceeds the given octets",
+        ))?;
+
+        // ITU-T X.690 | ISO/IEC 8825-1, 8.6.2 and 11.2.1
+        let mut content = Vec::with_capacity(byte_len + 1);
+        content.push(unused_bits as u8);
+        content.extend_from_slice(value);
+        if let Some(last) = content.last_mut().filter(|_| byte_len > 0) {
+            *last &= 0xFF << unused_bits;
+        }
+        self.write_primitive(tag, &content)
+    }
+
+    #[inline]
+    fn write_boolean<C: boolean::Constraint>(&mut self, value: bool) -> Result<(), Self::Error> {
+        // ITU-T X.690 | ISO/IEC 8825-1, 11.1
+        let tag = self.tag_or(C::TAG);
+        self.write_primitive(tag, &[if value { 0xFF } else { 0x00 }])
+    }
+
+    #[inline]
+    fn write_null<C: null::Constraint>(&mut self, _value: &Null) -> Result<(), Self::Error> {
+        let tag = self.tag_or(C::TAG);
+        self.write_primitive(tag, &[])
+    }
+
+    #[inline]
+    fn write_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+        value: &ObjectIdentifier,
+    ) -> Result<(), Self::Error> {
+        let tag = self.tag_or(C::TAG);
+        let mut content = Vec::new();
+        ber::write_object_identifier(&mut content, tag, value.arcs())?;
+        self.write_primitive(tag, &content)
+    }
+
+    #[inline]
+    fn write_relative_oid<C: relativeoid::Constraint>(
+        &mut self,
+        value: &RelativeOid,
+    ) -> Result<(), Self::Error> {
+        let tag = self.tag_or(C::TAG);
+        let mut content = Vec::new();
+        ber::write_relative_oid(&mut content, value.arcs());
+        self.write_primitive(tag, &content)
+    }
+
+    #[inline]
+    fn write_time<C: time::Constraint, T: time::TimeType>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.write_string(C::TAG, &value.to_string(), None, None, false)
+    }
+}
+
+/// Reads values that are encoded according to the Basic Encoding Rules (and therefore also
+/// values that are encoded according to the Distinguished Encoding Rules) of
+/// ITU-T X.690 | ISO/IEC 8825-1, including the indefinite form of the length octets and
+/// constructed strings. See [`BerWriter`] on how the tags are determined.
+pub struct BerReader<'a> {
+    bytes: Cow<'a, [u8]>,
+    position: usize,
+    /// The end of the current contents or `None` if they are of indefinite length
+    end: Option<usize>,
+    tag: Option<Tag>,
+}
+
+impl<'a> From<&'a [u8]> for BerReader<'a> {
+    fn from(bytes: &'a [u8]) -> Self {
+        Self {
+            end: Some(bytes.len()),
+            bytes: Cow::Borrowed(bytes),
+            position: 0,
+            tag: None,
+        }
+    }
+}
+
+impl BerReader<'_> {
+    #[inline]
+    pub fn bytes_remaining(&self) -> usize {
+        self.bytes.len() - self.position
+    }
+
+    /// Returns the tag of the referencing field or - if there is none - the given tag
+    #[inline]
+    fn tag_or(&mut self, tag: Tag) -> Tag {
+        self.tag.take().unwrap_or(tag)
+    }
+
+    #[inline]
+    fn limit(&self) -> usize {
+        self.end.unwrap_or(self.bytes.len())
+    }
+
+    /// Whether the end of the current contents is reached
+    #[inline]
+    fn at_end(&self) -> bool {
+        match self.end {
+            Some(end) => self.position >= end,
+            None => self.bytes[self.position..].starts_with(&END_OF_CONTENTS),
+        }
+    }
+
+    fn peek_identifier(&self) -> Result<Identifier, Error> {
+        ber::read_identifier(&self.bytes[self.position..self.limit()]).map(|(id, _)| id)
+    }
+
+    fn read_header(&mut self, expected: Tag) -> Result<(bool, Length), Error> {
+        let offset = self.position;
+        if self.at_end() {
+            return Err(ErrorKind::UnexpectedEndOfContents { expected, offset }.into());
+        }
+
+        let (identifier, identifier_len) = ber::read_identifier(&self.bytes[offset..self.limit()])?;
+        if identifier.tag != expected {
+            return Err(ErrorKind::UnexpectedTag {
+                expected,
+                found: identifier.tag,
+                offset,
+            }
+            .into());
+        }
+
+        let (length, length_len) =
+            ber::read_length(&self.bytes[offset + identifier_len..self.limit()])?;
+        let position = offset + identifier_len + length_len;
+        if let Length::Definite(length) = length {
+            if length > self.limit() - position {
+                return Err(Error::insufficient_data_in_source_buffer());
+            }
+        }
+
+        self.position = position;
+        Ok((identifier.constructed, length))
+    }
+
+    fn read_contents<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        tag: Tag,
+        length: Length,
+        f: F,
+    ) -> Result<T, Error> {
+        let outer = self.end;
+        self.end = match length {
+            Length::Definite(length) => Some(self.position + length),
+            Length::Indefinite => None,
+        };
+        let result = f(self).and_then(|value| {
+            if !self.at_end() {
+                Err(ErrorKind::ContentNotFullyConsumed(tag, self.limit() - self.position).into())
+            } else {
+                if length == Length::Indefinite {
+                    self.position += END_OF_CONTENTS.len();
+                }
+                Ok(value)
+            }
+        });
+        self.end = outer;
+        result
+    }
+
+    fn read_constructed<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        tag: Tag,
+        f: F,
+    ) -> Result<T, Error> {
+        match self.read_header(tag)? {
+            (true, length) => self.read_contents(tag, length, f),
+            (false, _) => Err(ErrorKind::UnexpectedConstructedFlag(tag, false).into()),
+        }
+    }
+
+    fn read_primitive<T, F: FnOnce(&[u8]) -> Result<T, Error>>(
+        &mut self,
+        tag: Tag,
+        f: F,
+    ) -> Result<T, Error> {
+        match self.read_header(tag)? {
+            (false, Length::Definite(length)) => {
+                let range = self.position..self.position + length;
+                self.position = range.end;
+                f(&self.bytes[range])
+            }
+            (false, Length::Indefinite) => Err(ErrorKind::UnexpectedIndefiniteLength(tag).into()),
+            (true, _) => Err(ErrorKind::UnexpectedConstructedFlag(tag, true).into()),
+        }
+    }
+
+    /// ITU-T X.690 | ISO/IEC 8825-1, 8.6.3 and 8.7.3, strings might be split into segments of
+    /// a constructed element, each being tagged with the universal tag of the string type
+    fn read_segments(
+        &mut self,
+        tag: Tag,
+        segment_tag: Tag,
+        segments: &mut Vec<Range<usize>>,
+    ) -> Result<(), Error> {
+        match self.read_header(tag)? {
+            (false, Length::Definite(length)) => {
+                segments.push(self.position..self.position + length);
+                self.position += length;
+                Ok(())
+            }
+            (false, Length::Indefinite) => Err(ErrorKind::UnexpectedIndefiniteLength(tag).into()),
+            (true, length) => self.read_contents(tag, length, |r| {
+                while !r.at_end() {
+                    r.read_segments(segment_tag, segment_tag, segments)?;
+                }
+                Ok(())
+            }),
+        }
+    }
+
+    fn read_string_contents(&mut self, tag: Tag, segment_tag: Tag) -> Result<Vec<u8>, Error> {
+        let tag = self.tag_or(tag);
+        let mut segments = Vec::new();
+        self.read_segments(tag, segment_tag, &mut segments)?;
+        Ok(segments
+            .into_iter()
+            .flat_map(|range| self.bytes[range].iter().copied())
+            .collect())
+    }
+
+    fn read_string(
+        &mut self,
+        tag: Tag,
+        segment_tag: Tag,
+        min: Option<u64>,
+        max: Option<u64>,
+        extensible: bool,
+    ) -> Result<String, Error> {
+        let string = String::from_utf8(self.read_string_contents(tag, segment_tag)?)
+            .map_err(ErrorKind::FromUtf8Error)?;
+        check_size(string.chars().count() as u64, min, max, extensible)?;
+        Ok(string)
+    }
+
+    /// Reads a character string whose contents octets differ from its UTF-8 representation
+    fn read_charset_string(
+        &mut self,
+        tag: Tag,
+        charset: Charset,
+        min: Option<u64>,
+        max: Option<u64>,
+        extensible: bool,
+    ) -> Result<String, Error> {
+        let content = self.read_string_contents(tag, charset.default_tag())?;
+        let string = ber::read_string(tag, charset, &content)?;
+        Error::ensure_string_valid(charset, &string)?;
+        check_size(string.chars().count() as u64, min, max, extensible)?;
+        Ok(string)
+    }
+
+    fn skip_element(&mut self) -> Result<(), Error> {
+        let identifier = self.peek_identifier()?;
+        match self.read_header(identifier.tag)? {
+            (_, Length::Definite(length)) => {
+                self.position += length;
+                Ok(())
+            }
+            (false, Length::Indefinite) => {
+                Err(ErrorKind::UnexpectedIndefiniteLength(identifier.tag).into())
+            }
+            (true, Length::Indefinite) => {
+                self.read_contents(identifier.tag, Length::Indefinite, Self::skip_remaining)
+            }
+        }
+    }
+
+    fn skip_remaining(&mut self) -> Result<(), Error> {
+        while !self.at_end() {
+            self.skip_element()?;
+        }
+        Ok(())
+    }
+
+    /// Returns `None` if the value to read is not present because the element at the current
+    /// position has another tag or there is no further element in the current contents
+    fn try_read<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<Option<T>, Error> {
+        let (position, end, tag) = (self.position, self.end, self.tag);
+        match f(self) {
+            Ok(value) => Ok(Some(value)),
+            Err(e) if e.is_unexpected_tag_at(position) => {
+                self.position = position;
+                self.end = end;
+                self.tag = tag;
+                Ok(None)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// The tag of a referencing field is explicit for CHOICE and open type values
+    fn read_explicitly_tagged<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<T, Error> {
+        match self.tag.take() {
+            Some(tag) => self.read_constructed(tag, f),
+            None => f(self),
+        }
+    }
+
+    /// The alternative is determined by the first variant that accepts the tag of the element
+    /// at the current position
+    fn read_choice_content<C: choice::Constraint>(&mut self) -> Result<C, Error> {
+        let offset = self.position;
+        for index in 0..C::VARIANT_COUNT {
+            if let Some(Some(choice)) = self.try_read(|r| C::read_content(index, r))? {
+                return Ok(choice);
+            }
+        }
+        if self.at_end() {
+            Err(ErrorKind::UnexpectedEndOfContents {
+                expected: C::TAG,
+                offset,
+            }
+            .into())
+        } else {
+            Err(ErrorKind::UnexpectedTag {
+                expected: C::TAG,
+                found: self.peek_identifier()?.tag,
+                offset,
+            }
+            .into())
+        }
+    }
+
+    /// ITU-T X.690 | ISO/IEC 8825-1, 8.11.1, the components of a SET might be in any order,
+    /// but are decoded in the canonical order of their tags - just as they are written
+    fn read_canonically_ordered<S, F: FnOnce(&mut Self) -> Result<S, Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<S, Error> {
+        let mut components = Vec::new();
+        while !self.at_end() {
+            let start = self.position;
+            let tag = self.peek_identifier()?.tag;
+            self.skip_element()?;
+            components.push((tag, start..self.position));
+        }
+        components.sort_by_key(|(tag, _)| *tag);
+
+        let ordered = components
+            .into_iter()
+            .flat_map(|(_, range)| self.bytes[range].iter().copied())
+            .collect::<Vec<u8>>();
+        let (position, end) = (self.position, Some(ordered.len()));
+        let bytes = core::mem::replace(&mut self.bytes, Cow::Owned(ordered));
+        let outer_end = core::mem::replace(&mut self.end, end);
+        self.position = 0;
+
+        let result = f(self);
+
+        self.bytes = bytes;
+        self.position = position;
+        self.end = outer_end;
+        result
+    }
+}
+
+impl Reader for BerReader<'_> {
+    type Error = Error;
+
+    #[inline]
+    fn read_sequence<
+        C: sequence::Constraint,
+        S: Sized,
+        F: Fn(&mut Self) -> Result<S, Self::Error>,
+    >(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        if C::TRANSPARENT {
+            return f(self);
+        }
+        let tag = self.tag_or(C::TAG);
+        self.read_constructed(tag, |r| {
+            let value = f(r)?;
+            if C::EXTENDED_AFTER_FIELD.is_some() {
+                // skip unknown extension additions
+                r.skip_remaining()?;
+            }
+            Ok(value)
+        })
+    }
+
+    #[inline]
+    fn read_sequence_of<C: sequenceof::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<Vec<T::Type>, Self::Error> {
+        let tag = self.tag_or(C::TAG);
+        let values = self.read_constructed(tag, |r| {
+            let mut values = Vec::new();
+            while !r.at_end() {
+                values.push(T::read_value(r)?);
+            }
+            Ok(values)
+        })?;
+        check_size(values.len() as u64, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        Ok(values)
+    }
+
+    #[inline]
+    fn read_set<C: set::Constraint, S: Sized, F: Fn(&mut Self) -> Result<S, Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        if C::TRANSPARENT {
+            return f(self);
+        }
+        let tag = self.tag_or(C::TAG);
+        self.read_constructed(tag, |r| {
+            r.read_canonically_ordered(|r| {
+                let value = f(r)?;
+                if C::EXTENDED_AFTER_FIELD.is_some() {
+                    // skip unknown extension additions
+                    r.skip_remaining()?;
+                }
+                if r.at_end() {
+                    Ok(value)
+                } else {
+                    Err(ErrorKind::ContentNotFullyConsumed(tag, r.limit() - r.position).into())
+                }
+            })
+        })
+    }
+
+    #[inline]
+    fn read_set_of<C: setof::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<Vec<T::Type>, Self::Error> {
+        self.read_sequence_of::<C, T>()
+    }
+
+    #[inline]
+    fn read_enumerated<C: enumerated::Constraint>(&mut self) -> Result<C, Self::Error> {
+        let tag = self.tag_or(C::TAG);
+        let index = self.read_primitive(tag, |content| ber::read_integer(tag, content))?;
+        u64::try_from(index)
+            .ok()
+            .and_then(C::from_choice_index)
+            .ok_or_else(|| ErrorKind::InvalidChoiceIndex(index as u64, C::VARIANT_COUNT).into())
+    }
+
+    #[inline]
+    fn read_complex<C: complex::Constraint, T, F: FnOnce(&mut Self) -> Result<T, Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<T, Self::Error> {
+        if C::TAGGED {
+            // the outermost tag wins if the referenced type is just another reference
+            self.tag.get_or_insert(C::TAG);
+        }
+        let result = f(self);
+        self.tag = None;
+        result
+    }
+
+    #[inline]
+    fn read_explicit<C: explicit::Constraint, T, F: FnOnce(&mut Self) -> Result<T, Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<T, Self::Error> {
+        let tag = self.tag_or(C::TAG);
+        self.read_constructed(tag, f)
+    }
+
+    #[inline]
+    fn read_choice<C: choice::Constraint>(&mut self) -> Result<C, Self::Error> {
+        self.read_explicitly_tagged(Self::read_choice_content::<C>)
+    }
+
+    #[inline]
+    fn read_open_type<C: opentype::Constraint>(
+        &mut self,
+        key: Option<usize>,
+    ) -> Result<C, Self::Error> {
+        let key = key.ok_or(ErrorKind::InvalidOpenTypeKey(C::NAME))?;
+        self.read_explicitly_tagged(|r| {
+            C::read_content(key, r)?
+                .ok_or_else(|| ErrorKind::InvalidChoiceIndex(key as u64, C::VARIANT_COUNT).into())
+        })
+    }
+
+    #[inline]
+    fn read_present<T: ReadableType>(
+        &mut self,
+        field: &'static str,
+    ) -> Result<T::Type, Self::Error> {
+        self.read_opt::<T>()?.ok_or_else(|| {
+            ErrorKind::ComponentConstraintViolated(field, ViolationKind::ComponentNotPresent).into()
+        })
+    }
+
+    #[inline]
+    fn read_opt<T: ReadableType>(&mut self) -> Result<Option<T::Type>, Self::Error> {
+        if self.at_end() {
+            Ok(None)
+        } else {
+            self.try_read(T::read_value)
+        }
+    }
+
+    #[inline]
+    fn read_default<C: default::Constraint<Owned = T::Type>, T: ReadableType>(
+        &mut self,
+    ) -> Result<T::Type, Self::Error> {
+        Ok(self
+            .read_opt::<T>()?
+            .unwrap_or_else(|| C::DEFAULT_VALUE.to_owned()))
+    }
+
+    #[inline]
+    fn read_number<T: numbers::Number, C: numbers::Constraint<T>>(
+        &mut self,
+    ) -> Result<T, Self::Error> {
+        let tag = self.tag_or(C::TAG);
+        let value = self.read_primitive(tag, |content| ber::read_integer(tag, content))?;
+        check_range(value, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        Ok(T::from_i64(value))
+    }
+
+    #[inline]
+    fn read_real<C: real::Constraint>(&mut self) -> Result<f64, Self::Error> {
+        let tag = self.tag_or(C::TAG);
+        self.read_primitive(tag, |content| ber::read_real(tag, content))
+    }
+
+    #[inline]
+    fn read_utf8string<C: utf8string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_string(
+            C::TAG,
+            Tag::DEFAULT_UTF8_STRING,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn read_ia5string<C: ia5string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let string = self.read_string(
+            C::TAG,
+            Tag::DEFAULT_IA5_STRING,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )?;
+        Error::ensure_string_valid(Charset::Ia5, &string)?;
+        Ok(string)
+    }
+
+    #[inline]
+    fn read_numeric_string<C: numericstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let string = self.read_string(
+            C::TAG,
+            Tag::DEFAULT_NUMERIC_STRING,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )?;
+        Error::ensure_string_valid(Charset::Numeric, &string)?;
+        Ok(string)
+    }
+
+    #[inline]
+    fn read_visible_string<C: visiblestring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let string = self.read_string(
+            C::TAG,
+            Tag::DEFAULT_VISIBLE_STRING,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )?;
+        Error::ensure_string_valid(Charset::Visible, &string)?;
+        Ok(string)
+    }
+
+    #[inline]
+    fn read_printable_string<C: printablestring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        let string = self.read_string(
+            C::TAG,
+            Tag::DEFAULT_PRINTABLE_STRING,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )?;
+        Error::ensure_string_valid(Charset::Printable, &string)?;
+        Ok(string)
+    }
+
+    #[inline]
+    fn read_teletex_string<C: teletexstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_charset_string(C::TAG, Charset::Teletex, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn read_graphic_string<C: graphicstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_charset_string(C::TAG, Charset::Graphic, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn read_general_string<C: generalstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_charset_string(C::TAG, Charset::General, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn read_universal_string<C: universalstring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        self.read_charset_string(C::TAG, Charset::Universal, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn read_bmp_string<C: bmpstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_charset_string(C::TAG, Charset::Bmp, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn read_octet_string<C: octetstring::Constraint>(&mut self) -> Result<Vec<u8>, Self::Error> {
+        let octets = self.read_string_contents(C::TAG, Tag::DEFAULT_OCTET_STRING)?;
+        check_size(octets.len() as u64, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        Ok(octets)
+    }
+
+    #[inline]
+    fn read_bit_string<C: bitstring::Constraint>(&mut self) -> Result<(Vec<u8>, u64), Self::Error> {
+        let tag = self.tag_or(C::TAG);
+        let mut segments = Vec::new();
+        self.read_segments(tag, Tag::DEFAULT_BIT_STRING, &mut segments)?;
+
+        // ITU-T X.690 | ISO/IEC 8825-1, 8.6.2 and 8.6.4, each segment starts with the number of
+        // unused bits in its final octet, which must be zero for all but the last segment
+        let mut bits = Vec::new();
+        let mut unused_bits = 0_u8;
+        for (index, range) in segments.iter().enumerate() {
+            let segment = &self.bytes[range.clone()];
+            let (unused, octets) = segment.split_first().ok_or(ErrorKind::InvalidContent(
+                tag,
+                "a bit string consists of at least one octet",
+            ))?;
+            if *unused > 7 || (*unused > 0 && (octets.is_empty() || index + 1 < segments.len())) {
+                return Err(ErrorKind::InvalidContent(tag, "invalid number of unused bits").into());
+            }
+            bits.extend_from_slice(octets);
+            unused_bits = *unused;
+        }
+
+        let bit_len = bits.len() as u64 * 8 - u64::from(unused_bits);
+        check_size(bit_len, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        Ok((bits, bit_len))
+    }
+
+    #[inline]
+    fn read_boolean<C: boolean::Constraint>(&mut self) -> Result<bool, Self::Error> {
+        let tag = self.tag_or(C::TAG);
+        self.read_primitive(tag, |content| match content {
+            [value] => Ok(*value != 0x00),
+            _ => Err(ErrorKind::InvalidContent(tag, "a boolean consists of a single octet").into()),
+        })
+    }
+
+    #[inline]
+    fn read_null<C: null::Constraint>(&mut self) -> Result<Null, Self::Error> {
+        let tag = self.tag_or(C::TAG);
+        self.read_primitive(tag, |content| {
+            if content.is_empty() {
+                Ok(Null)
+            } else {
+                Err(ErrorKind::InvalidContent(tag, "null has no contents").into())
+            }
+        })
+    }
+
+    #[inline]
+    fn read_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+    ) -> Result<ObjectIdentifier, Self::Error> {
+        let tag = self.tag_or(C::TAG);
+        self.read_primitive(tag, |content| {
+            ber::read_object_identifier(tag, content).map(ObjectIdentifier::new)
+        })
+    }
+
+    #[inline]
+    fn read_relative_oid<C: relativeoid::Constraint>(
+        &mut self,
+    ) -> Result<RelativeOid, Self::Error> {
+        let tag = self.tag_or(C::TAG);
+        self.read_primitive(tag, |content| {
+            ber::read_relative_oid(tag, content).map(RelativeOid::new)
+        })
+    }
+
+    #[inline]
+    fn read_time<C: time::Constraint, T: time::TimeType>(&mut self) -> Result<T, Self::Error> {
+        let tag = self.tag_or(C::TAG);
+        // the time types are defined as tagged VisibleStrings and thereby inherit its segments
+        let string = self.read_string(tag, Tag::DEFAULT_VISIBLE_STRING, None, None, false)?;
+        string
+            .parse::<T>()
+            .map_err(|e| ErrorKind::InvalidContent(tag, e.reason()).into())
+    }
+}
+
+#[inline]
+fn check_size(
+    size: u64,
+    min: Option<u64>,
+    max: Option<u64>,
+    extensible: bool,
+) -> Result<(), Error> {
+    if !extensible && (min.is_some_and(|min| size < min) || max.is_some_and(|max| size > max)) {
+        Err(ErrorKind::SizeNotInRange(size, min.unwrap_or(0), max.unwrap_or(u64::MAX)).into())
+    } else {
+        Ok(())
+    }
+}
+
+#[inline]
+fn check_range(
+    value: i64,
+    min: Option<i64>,
+    max: Option<i64>,
+    extensible: bool,
+) -> Result<(), Error> {
+    if !extensible && (min.is_some_and(|min| value < min) || max.is_some_and(|max| value > max)) {
+        Err(
+            ErrorKind::ValueNotInRange(value, min.unwrap_or(i64::MIN), max.unwrap_or(i64::MAX))
+                .into(),
+        )
+    } else {
+        Ok(())
+    }
+}
+
+pub trait BerDecodable<'a> {
+    fn decode_from_ber(bytes: &'a [u8]) -> Result<Self, Error>
+    where
+        Self: Sized;
+}
+
+impl<'a, R: Readable> BerDecodable<'a> for R {
+    fn decode_from_ber(bytes: &'a [u8]) -> Result<Self, Error>
+    where
+        Self: Sized,
+    {
+        let mut reader = BerReader::from(bytes);
+        Self::read(&mut reader)
+    }
+}
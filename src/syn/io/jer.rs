@@ -0,0 +1,969 @@
+use crate::io::jer::{self, Value};
+use crate::io::jer::{Error, ErrorKind};
+use crate::model::{Charset, PresenceConstraint};
+use crate::syn::*;
+use alloc::borrow::ToOwned;
+use alloc::format;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+/// Writes values according to the JSON Encoding Rules of ITU-T X.697 | ISO/IEC 8825-8, without
+/// any insignificant whitespace.
+///
+/// SEQUENCE, SET and CHOICE values are written as objects, whose members are named by the
+/// `IDENTIFIER` of the constraints of the fields. Fields that are absent or that have their
+/// DEFAULT value are not written. ENUMERATED values are written as their identifier, OCTET
+/// STRING values and BIT STRING values of fixed size as string of hexadecimal digits and all
+/// other BIT STRING values as object with the members `value` and `length`.
+#[derive(Default)]
+pub struct JerWriter {
+    buffer: String,
+    containers: Vec<WriteContainer>,
+    identifier: Option<&'static str>,
+}
+
+struct WriteContainer {
+    object: bool,
+    empty: bool,
+}
+
+impl JerWriter {
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buffer: String::with_capacity(capacity),
+            ..Default::default()
+        }
+    }
+
+    pub fn text_content(&self) -> &str {
+        &self.buffer
+    }
+
+    pub fn into_string(self) -> String {
+        self.buffer
+    }
+
+    pub fn as_reader(&self) -> JerReader<'_> {
+        JerReader::from(self.text_content())
+    }
+
+    /// Writes the separator and - within an object - the name of the member of the next value.
+    /// The identifier of the referencing field takes precedence over the given identifier.
+    fn begin_value(&mut self, identifier: Option<&'static str>) -> Result<(), Error> {
+        let identifier = self.identifier.take().or(identifier);
+        if let Some(container) = self.containers.last_mut() {
+            if !core::mem::replace(&mut container.empty, false) {
+                self.buffer.push(',');
+            }
+            if container.object {
+                let identifier = identifier.ok_or(ErrorKind::MissingIdentifier)?;
+                jer::write_string(&mut self.buffer, identifier);
+                self.buffer.push(':');
+            }
+        }
+        Ok(())
+    }
+
+    fn write_container<F: FnOnce(&mut Self) -> Result<(), Error>>(
+        &mut self,
+        identifier: Option<&'static str>,
+        object: bool,
+        f: F,
+    ) -> Result<(), Error> {
+        self.begin_value(identifier)?;
+        self.buffer.push(if object { '{' } else { '[' });
+        self.containers.push(WriteContainer {
+            object,
+            empty: true,
+        });
+        let result = f(self);
+        self.containers.pop();
+        self.buffer.push(if object { '}' } else { ']' });
+        result
+    }
+
+    fn write_string(
+        &mut self,
+        identifier: Option<&'static str>,
+        value: &str,
+        min: Option<u64>,
+        max: Option<u64>,
+        extensible: bool,
+    ) -> Result<(), Error> {
+        check_size(value.chars().count() as u64, min, max, extensible)?;
+        self.begin_value(identifier)?;
+        jer::write_string(&mut self.buffer, value);
+        Ok(())
+    }
+}
+
+impl Writer for JerWriter {
+    type Error = Error;
+
+    #[inline]
+    fn write_sequence<C: sequence::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        if C::TRANSPARENT {
+            f(self)
+        } else {
+            self.write_container(C::IDENTIFIER, true, f)
+        }
+    }
+
+    #[inline]
+    fn write_sequence_of<C: sequenceof::Constraint, T: WritableType>(
+        &mut self,
+        slice: &[T::Type],
+    ) -> Result<(), Self::Error> {
+        check_size(slice.len() as u64, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        self.write_container(C::IDENTIFIER, false, |w| {
+            for value in slice {
+                T::write_value(w, value)?;
+            }
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_set<C: set::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        self.write_sequence::<C, F>(f)
+    }
+
+    #[inline]
+    fn write_set_of<C: setof::Constraint, T: WritableType>(
+        &mut self,
+        slice: &[T::Type],
+    ) -> Result<(), Self::Error> {
+        self.write_sequence_of::<C, T>(slice)
+    }
+
+    #[inline]
+    fn write_enumerated<C: enumerated::Constraint>(
+        &mut self,
+        enumerated: &C,
+    ) -> Result<(), Self::Error> {
+        let index = enumerated.to_choice_index();
+        let identifier = C::IDENTIFIERS
+            .get(index as usize)
+            .ok_or(ErrorKind::InvalidChoiceIndex(index, C::VARIANT_COUNT))?;
+        self.begin_value(C::IDENTIFIER)?;
+        jer::write_string(&mut self.buffer, identifier);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_complex<C: complex::Constraint, F: FnOnce(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        if let Some(identifier) = C::IDENTIFIER {
+            // the outermost identifier wins if the referenced type is just another reference
+            self.identifier.get_or_insert(identifier);
+        }
+        let result = f(self);
+        self.identifier = None;
+        result
+    }
+
+    #[inline]
+    fn write_choice<C: choice::Constraint>(&mut self, choice: &C) -> Result<(), Self::Error> {
+        self.write_container(C::IDENTIFIER, true, |w| choice.write_content(w))
+    }
+
+    #[inline]
+    fn write_open_type<C: opentype::Constraint>(
+        &mut self,
+        opentype: &C,
+    ) -> Result<(), Self::Error> {
+        if opentype.unknown_bytes().is_some() {
+            // the preserved content is not encoded according to these encoding rules
+            let key = opentype.to_choice_index() as u64;
+            return Err(ErrorKind::InvalidChoiceIndex(key, C::VARIANT_COUNT).into());
+        }
+        // ITU-T X.697 | ISO/IEC 8825-8, 41: the value of the actual type is written as is
+        opentype.write_content(self)
+    }
+
+    #[inline]
+    fn check_presence(
+        &mut self,
+        field: &'static str,
+        constraint: PresenceConstraint,
+        present: bool,
+    ) -> Result<(), Self::Error> {
+        match ViolationKind::of_presence(constraint, present) {
+            Some(kind) => Err(ErrorKind::ComponentConstraintViolated(field, kind).into()),
+            None => Ok(()),
+        }
+    }
+
+    #[inline]
+    fn check_value(&mut self, field: &'static str, permitted: bool) -> Result<(), Self::Error> {
+        if permitted {
+            Ok(())
+        } else {
+            Err(
+                ErrorKind::ComponentConstraintViolated(field, ViolationKind::ValueNotPermitted)
+                    .into(),
+            )
+        }
+    }
+
+    #[inline]
+    fn write_opt<T: WritableType>(&mut self, value: Option<&T::Type>) -> Result<(), Self::Error> {
+        match value {
+            Some(value) => T::write_value(self, value),
+            None => {
+                self.identifier = None;
+                Ok(())
+            }
+        }
+    }
+
+    #[inline]
+    fn write_default<C: default::Constraint<Owned = T::Type>, T: WritableType>(
+        &mut self,
+        value: &T::Type,
+    ) -> Result<(), Self::Error> {
+        if C::DEFAULT_VALUE.ne(value) {
+            T::write_value(self, value)
+        } else {
+            self.identifier = None;
+            Ok(())
+        }
+    }
+
+    #[inline]
+    fn write_number<T: numbers::Number, C: numbers::Constraint<T>>(
+        &mut self,
+        value: T,
+    ) -> Result<(), Self::Error> {
+        let value = value.to_i64();
+        check_range(value, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        self.begin_value(C::IDENTIFIER)?;
+        self.buffer.push_str(&value.to_string());
+        Ok(())
+    }
+
+    #[inline]
+    fn write_real<C: real::Constraint>(&mut self, value: f64) -> Result<(), Self::Error> {
+        self.begin_value(C::IDENTIFIER)?;
+        // ITU-T X.697 | ISO/IEC 8825-8, 23: the special values are encoded as strings
+        if value.is_nan() {
+            jer::write_string(&mut self.buffer, "NaN");
+        } else if value.is_infinite() {
+            jer::write_string(&mut self.buffer, if value > 0.0 { "INF" } else { "-INF" });
+        } else if value == 0.0 && value.is_sign_negative() {
+            jer::write_string(&mut self.buffer, "-0");
+        } else {
+            self.buffer.push_str(&format!("{:?}", value));
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn write_utf8string<C: utf8string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_string(C::IDENTIFIER, value, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn write_ia5string<C: ia5string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_string(C::IDENTIFIER, value, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn write_numeric_string<C: numericstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_string(C::IDENTIFIER, value, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn write_visible_string<C: visiblestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_string(C::IDENTIFIER, value, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn write_printable_string<C: printablestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_string(C::IDENTIFIER, value, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn write_teletex_string<C: teletexstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_string(C::IDENTIFIER, value, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn write_graphic_string<C: graphicstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_string(C::IDENTIFIER, value, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn write_general_string<C: generalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_string(C::IDENTIFIER, value, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn write_universal_string<C: universalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_string(C::IDENTIFIER, value, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn write_bmp_string<C: bmpstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_string(C::IDENTIFIER, value, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn write_octet_string<C: octetstring::Constraint>(
+        &mut self,
+        value: &[u8],
+    ) -> Result<(), Self::Error> {
+        check_size(value.len() as u64, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        self.begin_value(C::IDENTIFIER)?;
+        jer::write_hex(&mut self.buffer, value);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_bit_string<C: bitstring::Constraint>(
+        &mut self,
+        value: &[u8],
+        bit_len: u64,
+    ) -> Result<(), Self::Error> {
+        check_size(bit_len, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        let mut octets = value
+            .get(..(bit_len.div_ceil(8)) as usize)
+            .ok_or(ErrorKind::InvalidContent(
+                "the bit length exceeds the given octets",
+            ))?
+            .to_vec();
+        // ITU-T X.697 | ISO/IEC 8825-8, 22.3, unused bits of the final octet are zero
+        if let Some(last) = octets.last_mut() {
+            *last &= 0xFF_u8 << ((8 - bit_len % 8) % 8);
+        }
+
+        self.begin_value(C::IDENTIFIER)?;
+        if is_fixed_size(C::MIN, C::MAX, C::EXTENSIBLE) {
+            jer::write_hex(&mut self.buffer, &octets);
+        } else {
+            self.buffer.push_str("{\"value\":");
+            jer::write_hex(&mut self.buffer, &octets);
+            self.buffer.push_str(",\"length\":");
+            self.buffer.push_str(&bit_len.to_string());
+            self.buffer.push('}');
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn write_boolean<C: boolean::Constraint>(&mut self, value: bool) -> Result<(), Self::Error> {
+        self.begin_value(C::IDENTIFIER)?;
+        self.buffer.push_str(if value { "true" } else { "false" });
+        Ok(())
+    }
+
+    #[inline]
+    fn write_null<C: null::Constraint>(&mut self, _value: &Null) -> Result<(), Self::Error> {
+        self.begin_value(C::IDENTIFIER)?;
+        self.buffer.push_str("null");
+        Ok(())
+    }
+
+    #[inline]
+    fn write_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+        value: &ObjectIdentifier,
+    ) -> Result<(), Self::Error> {
+        // ITU-T X.697 | ISO/IEC 8825-8, 31: the dot notation as a string
+        self.begin_value(C::IDENTIFIER)?;
+        jer::write_string(&mut self.buffer, &value.to_string());
+        Ok(())
+    }
+
+    #[inline]
+    fn write_relative_oid<C: relativeoid::Constraint>(
+        &mut self,
+        value: &RelativeOid,
+    ) -> Result<(), Self::Error> {
+        // ITU-T X.697 | ISO/IEC 8825-8, 32: the dot notation as a string
+        self.begin_value(C::IDENTIFIER)?;
+        jer::write_string(&mut self.buffer, &value.to_string());
+        Ok(())
+    }
+
+    #[inline]
+    fn write_time<C: time::Constraint, T: time::TimeType>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        // ITU-T X.697 | ISO/IEC 8825-8, 35: the character string of the value
+        self.begin_value(C::IDENTIFIER)?;
+        jer::write_string(&mut self.buffer, &value.to_string());
+        Ok(())
+    }
+}
+
+/// Reads values according to the JSON Encoding Rules of ITU-T X.697 | ISO/IEC 8825-8, see
+/// [`JerWriter`]. Members of objects are accepted in any order, unknown members are ignored if
+/// the SEQUENCE or SET is extensible.
+pub struct JerReader<'a> {
+    text: &'a str,
+    root_read: bool,
+    containers: Vec<ReadContainer>,
+    identifier: Option<&'static str>,
+    /// Whether the next value to take may be absent
+    optional: bool,
+    /// Whether the last value to take was optional and absent
+    absent: bool,
+}
+
+enum ReadContainer {
+    Object(Vec<(String, Value)>),
+    Array(alloc::vec::IntoIter<Value>),
+}
+
+impl<'a> From<&'a str> for JerReader<'a> {
+    fn from(text: &'a str) -> Self {
+        Self {
+            text,
+            root_read: false,
+            containers: Vec::default(),
+            identifier: None,
+            optional: false,
+            absent: false,
+        }
+    }
+}
+
+impl JerReader<'_> {
+    /// Takes the next value: the member with the given identifier in objects, the next value in
+    /// arrays or the JSON text itself. The identifier of the referencing field takes precedence
+    /// over the given identifier.
+    fn take(&mut self, identifier: Option<&'static str>) -> Result<Value, Error> {
+        let optional = core::mem::take(&mut self.optional);
+        let identifier = self.identifier.take().or(identifier);
+        match self.containers.last_mut() {
+            Some(ReadContainer::Object(members)) => {
+                let identifier = identifier.ok_or(ErrorKind::MissingIdentifier)?;
+                match members.iter().position(|(name, _)| name == identifier) {
+                    Some(index) => Ok(members.remove(index).1),
+                    None => {
+                        self.absent = optional;
+                        Err(ErrorKind::MissingMember(identifier).into())
+                    }
+                }
+            }
+            Some(ReadContainer::Array(values)) => {
+                values.next().ok_or_else(|| ErrorKind::NoMoreValues.into())
+            }
+            None if !self.root_read => {
+                self.root_read = true;
+                jer::parse(self.text)
+            }
+            None => Err(ErrorKind::NoMoreValues.into()),
+        }
+    }
+
+    fn take_object(
+        &mut self,
+        identifier: Option<&'static str>,
+    ) -> Result<Vec<(String, Value)>, Error> {
+        match self.take(identifier)? {
+            Value::Object(members) => Ok(members),
+            value => Err(unexpected("object", &value)),
+        }
+    }
+
+    fn take_array(&mut self, identifier: Option<&'static str>) -> Result<Vec<Value>, Error> {
+        match self.take(identifier)? {
+            Value::Array(values) => Ok(values),
+            value => Err(unexpected("array", &value)),
+        }
+    }
+
+    fn take_string(&mut self, identifier: Option<&'static str>) -> Result<String, Error> {
+        match self.take(identifier)? {
+            Value::String(string) => Ok(string),
+            value => Err(unexpected("string", &value)),
+        }
+    }
+
+    fn read_container<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        container: ReadContainer,
+        f: F,
+    ) -> Result<(T, ReadContainer), Error> {
+        self.containers.push(container);
+        let result = f(self);
+        let container = self.containers.pop().unwrap();
+        Ok((result?, container))
+    }
+
+    fn read_string(
+        &mut self,
+        identifier: Option<&'static str>,
+        min: Option<u64>,
+        max: Option<u64>,
+        extensible: bool,
+    ) -> Result<String, Error> {
+        let string = self.take_string(identifier)?;
+        check_size(string.chars().count() as u64, min, max, extensible)?;
+        Ok(string)
+    }
+}
+
+impl Reader for JerReader<'_> {
+    type Error = Error;
+
+    #[inline]
+    fn read_sequence<
+        C: sequence::Constraint,
+        S: Sized,
+        F: Fn(&mut Self) -> Result<S, Self::Error>,
+    >(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        if C::TRANSPARENT {
+            return f(self);
+        }
+        let members = self.take_object(C::IDENTIFIER)?;
+        let (value, container) = self.read_container(ReadContainer::Object(members), f)?;
+        match container {
+            ReadContainer::Object(members) if C::EXTENDED_AFTER_FIELD.is_none() => {
+                match members.into_iter().next() {
+                    Some((name, _)) => Err(ErrorKind::UnknownIdentifier(name).into()),
+                    None => Ok(value),
+                }
+            }
+            _ => Ok(value),
+        }
+    }
+
+    #[inline]
+    fn read_sequence_of<C: sequenceof::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<Vec<T::Type>, Self::Error> {
+        let values = self.take_array(C::IDENTIFIER)?;
+        let len = values.len();
+        check_size(len as u64, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        self.read_container(ReadContainer::Array(values.into_iter()), |r| {
+            (0..len).map(|_| T::read_value(r)).collect()
+        })
+        .map(|(values, _)| values)
+    }
+
+    #[inline]
+    fn read_set<C: set::Constraint, S: Sized, F: Fn(&mut Self) -> Result<S, Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        self.read_sequence::<C, S, F>(f)
+    }
+
+    #[inline]
+    fn read_set_of<C: setof::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<Vec<T::Type>, Self::Error> {
+        self.read_sequence_of::<C, T>()
+    }
+
+    #[inline]
+    fn read_enumerated<C: enumerated::Constraint>(&mut self) -> Result<C, Self::Error> {
+        let identifier = self.take_string(C::IDENTIFIER)?;
+        let index = C::IDENTIFIERS
+            .iter()
+            .position(|i| *i == identifier)
+            .ok_or(ErrorKind::UnknownIdentifier(identifier))?;
+        C::from_choice_index(index as u64)
+            .ok_or_else(|| ErrorKind::InvalidChoiceIndex(index as u64, C::VARIANT_COUNT).into())
+    }
+
+    #[inline]
+    fn read_complex<C: complex::Constraint, T, F: FnOnce(&mut Self) -> Result<T, Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<T, Self::Error> {
+        if let Some(identifier) = C::IDENTIFIER {
+            // the outermost identifier wins if the referenced type is just another reference
+            self.identifier.get_or_insert(identifier);
+        }
+        let result = f(self);
+        self.identifier = None;
+        result
+    }
+
+    #[inline]
+    fn read_choice<C: choice::Constraint>(&mut self) -> Result<C, Self::Error> {
+        let members = self.take_object(C::IDENTIFIER)?;
+        let name = match &members[..] {
+            [(name, _)] => name.clone(),
+            _ => {
+                return Err(ErrorKind::InvalidContent(
+                    "a choice value is an object with exactly one member",
+                )
+                .into())
+            }
+        };
+        // the variant is the one whose identifier names the member
+        self.read_container(ReadContainer::Object(members), |r| {
+            for index in 0..C::VARIANT_COUNT {
+                r.optional = true;
+                r.absent = false;
+                match C::read_content(index, r) {
+                    Ok(Some(value)) => return Ok(value),
+                    Ok(None) => break,
+                    Err(_) if core::mem::take(&mut r.absent) => continue,
+                    Err(e) => return Err(e),
+                }
+            }
+            r.optional = false;
+            Err(ErrorKind::UnknownIdentifier(name).into())
+        })
+        .map(|(value, _)| value)
+    }
+
+    #[inline]
+    fn read_open_type<C: opentype::Constraint>(
+        &mut self,
+        key: Option<usize>,
+    ) -> Result<C, Self::Error> {
+        let key = key.ok_or(ErrorKind::InvalidOpenTypeKey(C::NAME))?;
+        C::read_content(key, self)?
+            .ok_or_else(|| ErrorKind::InvalidChoiceIndex(key as u64, C::VARIANT_COUNT).into())
+    }
+
+    #[inline]
+    fn read_present<T: ReadableType>(
+        &mut self,
+        field: &'static str,
+    ) -> Result<T::Type, Self::Error> {
+        self.read_opt::<T>()?.ok_or_else(|| {
+            ErrorKind::ComponentConstraintViolated(field, ViolationKind::ComponentNotPresent).into()
+        })
+    }
+
+    #[inline]
+    fn read_opt<T: ReadableType>(&mut self) -> Result<Option<T::Type>, Self::Error> {
+        self.optional = true;
+        self.absent = false;
+        let result = T::read_value(self);
+        self.optional = false;
+        match result {
+            Ok(value) => Ok(Some(value)),
+            Err(_) if core::mem::take(&mut self.absent) => {
+                self.identifier = None;
+                Ok(None)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    #[inline]
+    fn read_default<C: default::Constraint<Owned = T::Type>, T: ReadableType>(
+        &mut self,
+    ) -> Result<T::Type, Self::Error> {
+        Ok(self
+            .read_opt::<T>()?
+            .unwrap_or_else(|| C::DEFAULT_VALUE.to_owned()))
+    }
+
+    #[inline]
+    fn read_number<T: numbers::Number, C: numbers::Constraint<T>>(
+        &mut self,
+    ) -> Result<T, Self::Error> {
+        let value = match self.take(C::IDENTIFIER)? {
+            Value::Number(number) if number.contains(['.', 'e', 'E']) => {
+                return Err(
+                    ErrorKind::InvalidContent("an integer has no fraction or exponent").into(),
+                )
+            }
+            Value::Number(number) => number
+                .parse::<i64>()
+                .map_err(|_| ErrorKind::ValueExceedsMaxInt)?,
+            value => return Err(unexpected("number", &value)),
+        };
+        check_range(value, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        Ok(T::from_i64(value))
+    }
+
+    #[inline]
+    fn read_real<C: real::Constraint>(&mut self) -> Result<f64, Self::Error> {
+        match self.take(C::IDENTIFIER)? {
+            Value::Number(number) => number
+                .parse::<f64>()
+                .map_err(|_| ErrorKind::InvalidContent("invalid number for a real").into()),
+            Value::String(string) => match string.as_str() {
+                "NaN" => Ok(f64::NAN),
+                "INF" => Ok(f64::INFINITY),
+                "-INF" => Ok(f64::NEG_INFINITY),
+                "-0" => Ok(-0.0),
+                _ => Err(ErrorKind::InvalidContent("unknown special real value").into()),
+            },
+            value => Err(unexpected("number", &value)),
+        }
+    }
+
+    #[inline]
+    fn read_utf8string<C: utf8string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_string(C::IDENTIFIER, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn read_ia5string<C: ia5string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let string = self.read_string(C::IDENTIFIER, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        Error::ensure_string_valid(Charset::Ia5, &string)?;
+        Ok(string)
+    }
+
+    #[inline]
+    fn read_numeric_string<C: numericstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let string = self.read_string(C::IDENTIFIER, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        Error::ensure_string_valid(Charset::Numeric, &string)?;
+        Ok(string)
+    }
+
+    #[inline]
+    fn read_visible_string<C: visiblestring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let string = self.read_string(C::IDENTIFIER, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        Error::ensure_string_valid(Charset::Visible, &string)?;
+        Ok(string)
+    }
+
+    #[inline]
+    fn read_printable_string<C: printablestring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        let string = self.read_string(C::IDENTIFIER, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        Error::ensure_string_valid(Charset::Printable, &string)?;
+        Ok(string)
+    }
+
+    #[inline]
+    fn read_teletex_string<C: teletexstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let string = self.read_string(C::IDENTIFIER, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        Error::ensure_string_valid(Charset::Teletex, &string)?;
+        Ok(string)
+    }
+
+    #[inline]
+    fn read_graphic_string<C: graphicstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let string = self.read_string(C::IDENTIFIER, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        Error::ensure_string_valid(Charset::Graphic, &string)?;
+        Ok(string)
+    }
+
+    #[inline]
+    fn read_general_string<C: generalstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let string = self.read_string(C::IDENTIFIER, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        Error::ensure_string_valid(Charset::General, &string)?;
+        Ok(string)
+    }
+
+    #[inline]
+    fn read_universal_string<C: universalstring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        let string = self.read_string(C::IDENTIFIER, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        Error::ensure_string_valid(Charset::Universal, &string)?;
+        Ok(string)
+    }
+
+    #[inline]
+    fn read_bmp_string<C: bmpstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let string = self.read_string(C::IDENTIFIER, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        Error::ensure_string_valid(Charset::Bmp, &string)?;
+        Ok(string)
+    }
+
+    #[inline]
+    fn read_octet_string<C: octetstring::Constraint>(&mut self) -> Result<Vec<u8>, Self::Error> {
+        let octets = jer::read_hex(&self.take_string(C::IDENTIFIER)?)?;
+        check_size(octets.len() as u64, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        Ok(octets)
+    }
+
+    #[inline]
+    fn read_bit_string<C: bitstring::Constraint>(&mut self) -> Result<(Vec<u8>, u64), Self::Error> {
+        let (octets, bit_len) = if is_fixed_size(C::MIN, C::MAX, C::EXTENSIBLE) {
+            let octets = jer::read_hex(&self.take_string(C::IDENTIFIER)?)?;
+            (octets, C::MIN.unwrap_or_default())
+        } else {
+            let mut members = self.take_object(C::IDENTIFIER)?;
+            let mut member = |name: &'static str| {
+                members
+                    .iter()
+                    .position(|(n, _)| n == name)
+                    .map(|index| members.remove(index).1)
+                    .ok_or(ErrorKind::MissingMember(name))
+            };
+            let octets = match member("value")? {
+                Value::String(hex) => jer::read_hex(&hex)?,
+                value => return Err(unexpected("string", &value)),
+            };
+            let bit_len = match member("length")? {
+                Value::Number(number) => number
+                    .parse::<u64>()
+                    .map_err(|_| ErrorKind::InvalidContent("invalid length of bit string"))?,
+                value => return Err(unexpected("number", &value)),
+            };
+            (octets, bit_len)
+        };
+
+        if octets.len() as u64 != bit_len.div_ceil(8) {
+            return Err(ErrorKind::InvalidContent(
+                "the number of octets does not match the length of the bit string",
+            )
+            .into());
+        }
+        check_size(bit_len, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        Ok((octets, bit_len))
+    }
+
+    #[inline]
+    fn read_boolean<C: boolean::Constraint>(&mut self) -> Result<bool, Self::Error> {
+        match self.take(C::IDENTIFIER)? {
+            Value::Bool(value) => Ok(value),
+            value => Err(unexpected("boolean", &value)),
+        }
+    }
+
+    #[inline]
+    fn read_null<C: null::Constraint>(&mut self) -> Result<Null, Self::Error> {
+        match self.take(C::IDENTIFIER)? {
+            Value::Null => Ok(Null),
+            value => Err(unexpected("null", &value)),
+        }
+    }
+
+    #[inline]
+    fn read_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+    ) -> Result<ObjectIdentifier, Self::Error> {
+        match self.take(C::IDENTIFIER)? {
+            Value::String(string) => string.parse().map_err(|_| {
+                ErrorKind::InvalidContent("invalid dot notation of an object identifier").into()
+            }),
+            value => Err(unexpected("string", &value)),
+        }
+    }
+
+    #[inline]
+    fn read_relative_oid<C: relativeoid::Constraint>(
+        &mut self,
+    ) -> Result<RelativeOid, Self::Error> {
+        match self.take(C::IDENTIFIER)? {
+            Value::String(string) => string.parse().map_err(|_| {
+                ErrorKind::InvalidContent("invalid dot notation of a relative object identifier")
+                    .into()
+            }),
+            value => Err(unexpected("string", &value)),
+        }
+    }
+
+    #[inline]
+    fn read_time<C: time::Constraint, T: time::TimeType>(&mut self) -> Result<T, Self::Error> {
+        match self.take(C::IDENTIFIER)? {
+            Value::String(string) => string
+                .parse()
+                .map_err(|e: time::TimeError| ErrorKind::InvalidContent(e.reason()).into()),
+            value => Err(unexpected("string", &value)),
+        }
+    }
+}
+
+#[cold]
+fn unexpected(expected: &'static str, found: &Value) -> Error {
+    ErrorKind::UnexpectedValue {
+        expected,
+        found: found.kind(),
+    }
+    .into()
+}
+
+/// According to ITU-T X.697 | ISO/IEC 8825-8, 22.3, a BIT STRING of fixed size has no length
+#[inline]
+fn is_fixed_size(min: Option<u64>, max: Option<u64>, extensible: bool) -> bool {
+    !extensible && min.is_some() && min == max
+}
+
+#[inline]
+fn check_size(
+    size: u64,
+    min: Option<u64>,
+    max: Option<u64>,
+    extensible: bool,
+) -> Result<(), Error> {
+    if !extensible && (min.is_some_and(|min| size < min) || max.is_some_and(|max| size > max)) {
+        Err(ErrorKind::SizeNotInRange(size, min.unwrap_or(0), max.unwrap_or(u64::MAX)).into())
+    } else {
+        Ok(())
+    }
+}
+
+#[inline]
+fn check_range(
+    value: i64,
+    min: Option<i64>,
+    max: Option<i64>,
+    extensible: bool,
+) -> Result<(), Error> {
+    if !extensible && (min.is_some_and(|min| value < min) || max.is_some_and(|max| value > max)) {
+        Err(
+            ErrorKind::ValueNotInRange(value, min.unwrap_or(i64::MIN), max.unwrap_or(i64::MAX))
+                .into(),
+        )
+    } else {
+        Ok(())
+    }
+}
+
+pub trait JerDecodable {
+    fn decode_from_jer(text: &str) -> Result<Self, Error>
+    where
+        Self: Sized;
+}
+
+impl<R: Readable> JerDecodable for R {
+    fn decode_from_jer(text: &str) -> Result<Self, Error>
+    where
+        Self: Sized,
+    {
+        let mut reader = JerReader::from(text);
+        Self::read(&mut reader)
+    }
+}
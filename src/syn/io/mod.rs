@@ -1,9 +1,29 @@
+mod aper;
+mod ber;
+mod jer;
+mod oer;
+#[cfg(feature = "std")]
 mod println;
+#[cfg(feature = "std")]
 mod proto_read;
+#[cfg(feature = "std")]
 mod proto_write;
+mod trace;
 mod uper;
+mod validate;
+mod xer;
 
+pub use aper::*;
+pub use ber::*;
+pub use jer::*;
+pub use oer::*;
+#[cfg(feature = "std")]
 pub use println::*;
+#[cfg(feature = "std")]
 pub use proto_read::*;
+#[cfg(feature = "std")]
 pub use proto_write::*;
+pub use trace::*;
 pub use uper::*;
+pub use validate::*;
+pub use xer::*;
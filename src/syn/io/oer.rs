@@ -0,0 +1,1415 @@
+use crate::io::ber;
+use crate::io::oer;
+use crate::io::oer::{Error, ErrorKind};
+use crate::model::{Charset, PresenceConstraint, Tag};
+use crate::syn::*;
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+
+/// Writes values according to the canonical Octet Encoding Rules (COER) of ITU-T X.696 |
+/// ISO/IEC 8825-7, which are also valid basic OER encodings.
+///
+/// Integers with a lower and an upper bound are written in a fixed number of octets, all other
+/// integers with a length determinant. Strings and SEQUENCE OF values of a fixed size are written
+/// without a length determinant. Components of SEQUENCE and SET values that have their DEFAULT
+/// value are not written, BOOLEAN `true` is always written as `0xFF`.
+#[derive(Default)]
+pub struct OerWriter {
+    buffer: Vec<u8>,
+    scope: Option<WriteScope>,
+}
+
+/// The constructed value the next value is a component of
+enum WriteScope {
+    Sequence(SequenceWriteScope),
+    /// The tag of the chosen alternative has not been written yet
+    Choice {
+        extension: bool,
+    },
+}
+
+struct SequenceWriteScope {
+    field: u64,
+    extended_after: Option<u64>,
+    /// The presence bits of the OPTIONAL and DEFAULT components of the root
+    presence: Vec<bool>,
+    /// The encodings of the extension additions, if present
+    extensions: Vec<Option<Vec<u8>>>,
+}
+
+impl OerWriter {
+    pub fn with_capacity(capacity_bytes: usize) -> Self {
+        Self {
+            buffer: Vec::with_capacity(capacity_bytes),
+            ..Default::default()
+        }
+    }
+
+    pub fn byte_content(&self) -> &[u8] {
+        &self.buffer[..]
+    }
+
+    pub fn into_bytes_vec(self) -> Vec<u8> {
+        self.buffer
+    }
+
+    pub fn as_reader(&self) -> OerReader<'_> {
+        OerReader::from(self.byte_content())
+    }
+
+    /// Writes the next value - if present - as component of the current scope. The tag is the
+    /// tag of the value if it is the chosen alternative of a CHOICE value or `None` if the value
+    /// has no tag of its own.
+    fn write_component<F: FnOnce(&mut Self) -> Result<(), Error>>(
+        &mut self,
+        tag: Option<Tag>,
+        is_opt: bool,
+        is_present: bool,
+        f: F,
+    ) -> Result<(), Error> {
+        match self.scope.take() {
+            None if is_present => f(self),
+            None => Ok(()),
+            Some(WriteScope::Choice { extension }) => match tag {
+                // the referenced value writes the tag instead
+                None => {
+                    self.scope = Some(WriteScope::Choice { extension });
+                    f(self)
+                }
+                Some(tag) => {
+                    oer::write_tag(&mut self.buffer, tag);
+                    if extension {
+                        self.write_open_type_encoding(f)
+                    } else {
+                        f(self)
+                    }
+                }
+            },
+            Some(WriteScope::Sequence(mut sequence)) => {
+                let index = sequence.field;
+                sequence.field += 1;
+                let result = if sequence.extended_after.is_some_and(|after| index > after) {
+                    if is_present {
+                        let outer = core::mem::take(&mut self.buffer);
+                        let result = f(self);
+                        let encoding = core::mem::replace(&mut self.buffer, outer);
+                        sequence.extensions.push(Some(encoding));
+                        result
+                    } else {
+                        sequence.extensions.push(None);
+                        Ok(())
+                    }
+                } else {
+                    if is_opt {
+                        sequence.presence.push(is_present);
+                    }
+                    if is_present {
+                        f(self)
+                    } else {
+                        Ok(())
+                    }
+                };
+                self.scope = Some(WriteScope::Sequence(sequence));
+                result
+            }
+        }
+    }
+
+    /// According to ITU-T X.696 | ISO/IEC 8825-7, 30, writes the encoding with a length
+    /// determinant in front of it
+    fn write_open_type_encoding<F: FnOnce(&mut Self) -> Result<(), Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Error> {
+        let outer = core::mem::take(&mut self.buffer);
+        let result = f(self);
+        let encoding = core::mem::replace(&mut self.buffer, outer);
+        result?;
+        oer::write_length(&mut self.buffer, encoding.len());
+        self.buffer.extend_from_slice(&encoding);
+        Ok(())
+    }
+
+    /// According to ITU-T X.696 | ISO/IEC 8825-7, 16, writes the preamble, the components of
+    /// the root and the extension additions of a SEQUENCE or SET value
+    fn write_sequence_encoding(&mut self, sequence: SequenceWriteScope, content: &[u8]) {
+        let extension = sequence.extensions.iter().any(Option::is_some);
+        let extension_bit = sequence.extended_after.map(|_| extension);
+        oer::write_bitmap(
+            &mut self.buffer,
+            extension_bit.into_iter().chain(sequence.presence),
+        );
+        self.buffer.extend_from_slice(content);
+
+        if extension {
+            let bits = sequence.extensions.len();
+            let octets = bits.div_ceil(8);
+            oer::write_length(&mut self.buffer, 1 + octets);
+            self.buffer.push((octets * 8 - bits) as u8);
+            oer::write_bitmap(
+                &mut self.buffer,
+                sequence.extensions.iter().map(Option::is_some),
+            );
+            for encoding in sequence.extensions.into_iter().flatten() {
+                oer::write_length(&mut self.buffer, encoding.len());
+                self.buffer.extend_from_slice(&encoding);
+            }
+        }
+    }
+
+    fn write_quantity(&mut self, quantity: usize) {
+        let mut octets = Vec::with_capacity(core::mem::size_of::<usize>());
+        oer::write_variable_integer(&mut octets, quantity as i64, false);
+        oer::write_length(&mut self.buffer, octets.len());
+        self.buffer.extend_from_slice(&octets);
+    }
+
+    fn write_string(
+        &mut self,
+        tag: Tag,
+        value: &[u8],
+        size: u64,
+        min: Option<u64>,
+        max: Option<u64>,
+        extensible: bool,
+    ) -> Result<(), Error> {
+        check_size(size, min, max, extensible)?;
+        self.write_component(Some(tag), false, true, |w| {
+            if fixed_size(min, max, extensible).is_none() {
+                oer::write_length(&mut w.buffer, value.len());
+            }
+            w.buffer.extend_from_slice(value);
+            Ok(())
+        })
+    }
+
+    /// ITU-T X.696 | ISO/IEC 8825-7, 27: the contents octets of the BER encoding, where only
+    /// the known-multiplier character string types omit the length determinant for a fixed size
+    fn write_charset_string(
+        &mut self,
+        tag: Tag,
+        charset: Charset,
+        value: &str,
+        min: Option<u64>,
+        max: Option<u64>,
+        extensible: bool,
+    ) -> Result<(), Error> {
+        Error::ensure_string_valid(charset, value)?;
+        let size = value.chars().count() as u64;
+        let mut content = Vec::new();
+        ber::write_string(&mut content, charset, value);
+        if is_known_multiplier(charset) {
+            self.write_string(tag, &content, size, min, max, extensible)
+        } else {
+            check_size(size, min, max, extensible)?;
+            self.write_component(Some(tag), false, true, |w| {
+                oer::write_length(&mut w.buffer, content.len());
+                w.buffer.extend_from_slice(&content);
+                Ok(())
+            })
+        }
+    }
+}
+
+impl Writer for OerWriter {
+    type Error = Error;
+
+    #[inline]
+    fn write_sequence<C: sequence::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        if C::TRANSPARENT {
+            return f(self);
+        }
+        self.write_component(Some(C::TAG), false, true, |w| {
+            let outer = core::mem::take(&mut w.buffer);
+            w.scope = Some(WriteScope::Sequence(SequenceWriteScope {
+                field: 0,
+                extended_after: C::EXTENDED_AFTER_FIELD,
+                presence: Vec::with_capacity(C::STD_OPTIONAL_FIELDS as usize),
+                extensions: Vec::new(),
+            }));
+            let result = f(w);
+            let scope = w.scope.take();
+            let content = core::mem::replace(&mut w.buffer, outer);
+            result?;
+            if let Some(WriteScope::Sequence(sequence)) = scope {
+                w.write_sequence_encoding(sequence, &content);
+            }
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_sequence_of<C: sequenceof::Constraint, T: WritableType>(
+        &mut self,
+        slice: &[T::Type],
+    ) -> Result<(), Self::Error> {
+        check_size(slice.len() as u64, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        self.write_component(Some(C::TAG), false, true, |w| {
+            w.write_quantity(slice.len());
+            for value in slice {
+                T::write_value(w, value)?;
+            }
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_set<C: set::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        self.write_sequence::<C, F>(f)
+    }
+
+    #[inline]
+    fn write_set_of<C: setof::Constraint, T: WritableType>(
+        &mut self,
+        slice: &[T::Type],
+    ) -> Result<(), Self::Error> {
+        check_size(slice.len() as u64, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        self.write_component(Some(C::TAG), false, true, |w| {
+            w.write_quantity(slice.len());
+            for value in slice {
+                T::write_value(w, value)?;
+            }
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_enumerated<C: enumerated::Constraint>(
+        &mut self,
+        enumerated: &C,
+    ) -> Result<(), Self::Error> {
+        let index = enumerated.to_choice_index();
+        if index >= C::VARIANT_COUNT {
+            return Err(ErrorKind::InvalidChoiceIndex(index, C::VARIANT_COUNT).into());
+        }
+        self.write_component(Some(C::TAG), false, true, |w| {
+            // ITU-T X.696 | ISO/IEC 8825-7, 11: the short form for values up to 127
+            if index < 0x80 {
+                w.buffer.push(index as u8);
+            } else {
+                let mut octets = Vec::with_capacity(core::mem::size_of::<u64>());
+                oer::write_variable_integer(&mut octets, index as i64, true);
+                w.buffer.push(0x80 | octets.len() as u8);
+                w.buffer.extend_from_slice(&octets);
+            }
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_complex<C: complex::Constraint, F: FnOnce(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        self.write_component(C::TAGGED.then_some(C::TAG), false, true, f)
+    }
+
+    #[inline]
+    fn write_explicit<C: explicit::Constraint, F: FnOnce(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        // only the outermost tag is written for an alternative of a CHOICE
+        self.write_component(Some(C::TAG), false, true, f)
+    }
+
+    #[inline]
+    fn write_choice<C: choice::Constraint>(&mut self, choice: &C) -> Result<(), Self::Error> {
+        let index = choice.to_choice_index();
+        if index >= C::VARIANT_COUNT {
+            return Err(ErrorKind::InvalidChoiceIndex(index, C::VARIANT_COUNT).into());
+        }
+        self.write_component(Some(C::TAG), false, true, |w| {
+            w.scope = Some(WriteScope::Choice {
+                extension: C::EXTENSIBLE && index >= C::STD_VARIANT_COUNT,
+            });
+            let result = choice.write_content(w);
+            w.scope = None;
+            result
+        })
+    }
+
+    #[inline]
+    fn write_open_type<C: opentype::Constraint>(
+        &mut self,
+        opentype: &C,
+    ) -> Result<(), Self::Error> {
+        self.write_component(Some(C::TAG), false, true, |w| {
+            w.write_open_type_encoding(|w| match opentype.unknown_bytes() {
+                Some(bytes) => {
+                    w.buffer.extend_from_slice(bytes);
+                    Ok(())
+                }
+                None => opentype.write_content(w),
+            })
+        })
+    }
+
+    #[inline]
+    fn check_presence(
+        &mut self,
+        field: &'static str,
+        constraint: PresenceConstraint,
+        present: bool,
+    ) -> Result<(), Self::Error> {
+        match ViolationKind::of_presence(constraint, present) {
+            Some(kind) => Err(ErrorKind::ComponentConstraintViolated(field, kind).into()),
+            None => Ok(()),
+        }
+    }
+
+    #[inline]
+    fn check_value(&mut self, field: &'static str, permitted: bool) -> Result<(), Self::Error> {
+        if permitted {
+            Ok(())
+        } else {
+            Err(
+                ErrorKind::ComponentConstraintViolated(field, ViolationKind::ValueNotPermitted)
+                    .into(),
+            )
+        }
+    }
+
+    #[inline]
+    fn write_opt<T: WritableType>(&mut self, value: Option<&T::Type>) -> Result<(), Self::Error> {
+        self.write_component(None, true, value.is_some(), |w| match value {
+            Some(value) => T::write_value(w, value),
+            None => Ok(()),
+        })
+    }
+
+    #[inline]
+    fn write_default<C: default::Constraint<Owned = T::Type>, T: WritableType>(
+        &mut self,
+        value: &T::Type,
+    ) -> Result<(), Self::Error> {
+        let is_present = C::DEFAULT_VALUE.ne(value);
+        self.write_component(None, true, is_present, |w| T::write_value(w, value))
+    }
+
+    #[inline]
+    fn write_number<T: numbers::Number, C: numbers::Constraint<T>>(
+        &mut self,
+        value: T,
+    ) -> Result<(), Self::Error> {
+        let value = value.to_i64();
+        check_range(value, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        self.write_component(Some(C::TAG), false, true, |w| {
+            match fixed_integer_size(C::MIN, C::MAX, C::EXTENSIBLE) {
+                Some((size, _)) => oer::write_fixed_integer(&mut w.buffer, value, size),
+                None => {
+                    let mut octets = Vec::with_capacity(core::mem::size_of::<i64>());
+                    let signed = is_signed(C::MIN, C::EXTENSIBLE);
+                    oer::write_variable_integer(&mut octets, value, signed);
+                    oer::write_length(&mut w.buffer, octets.len());
+                    w.buffer.extend_from_slice(&octets);
+                }
+            }
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_real<C: real::Constraint>(&mut self, value: f64) -> Result<(), Self::Error> {
+        // ITU-T X.696 | ISO/IEC 8825-7, 12.4: a length determinant followed by the contents
+        // octets of the CER/DER encoding
+        self.write_component(Some(C::TAG), false, true, |w| {
+            let mut content = Vec::new();
+            ber::write_real(&mut content, value);
+            oer::write_length(&mut w.buffer, content.len());
+            w.buffer.extend_from_slice(&content);
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_utf8string<C: utf8string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        check_size(value.chars().count() as u64, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        // the number of octets of a UTF8String is not known by its size
+        self.write_component(Some(C::TAG), false, true, |w| {
+            oer::write_length(&mut w.buffer, value.len());
+            w.buffer.extend_from_slice(value.as_bytes());
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_ia5string<C: ia5string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        Error::ensure_string_valid(Charset::Ia5, value)?;
+        self.write_string(
+            C::TAG,
+            value.as_bytes(),
+            value.len() as u64,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn write_numeric_string<C: numericstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        Error::ensure_string_valid(Charset::Numeric, value)?;
+        self.write_string(
+            C::TAG,
+            value.as_bytes(),
+            value.len() as u64,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn write_visible_string<C: visiblestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        Error::ensure_string_valid(Charset::Visible, value)?;
+        self.write_string(
+            C::TAG,
+            value.as_bytes(),
+            value.len() as u64,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn write_printable_string<C: printablestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        Error::ensure_string_valid(Charset::Printable, value)?;
+        self.write_string(
+            C::TAG,
+            value.as_bytes(),
+            value.len() as u64,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn write_teletex_string<C: teletexstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_charset_string(
+            C::TAG,
+            Charset::Teletex,
+            value,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn write_graphic_string<C: graphicstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_charset_string(
+            C::TAG,
+            Charset::Graphic,
+            value,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn write_general_string<C: generalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_charset_string(
+            C::TAG,
+            Charset::General,
+            value,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn write_universal_string<C: universalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_charset_string(
+            C::TAG,
+            Charset::Universal,
+            value,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn write_bmp_string<C: bmpstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_charset_string(C::TAG, Charset::Bmp, value, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn write_octet_string<C: octetstring::Constraint>(
+        &mut self,
+        value: &[u8],
+    ) -> Result<(), Self::Error> {
+        self.write_string(
+            C::TAG,
+            value,
+            value.len() as u64,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn write_bit_string<C: bitstring::Constraint>(
+        &mut self,
+        value: &[u8],
+        bit_len: u64,
+    ) -> Result<(), Self::Error> {
+        check_size(bit_len, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        let octets = bit_len.div_ceil(8) as usize;
+        if value.len() < octets {
+            return Err(
+                ErrorKind::InvalidContent("the bit length exceeds the given octets").into(),
+            );
+        }
+        self.write_component(Some(C::TAG), false, true, |w| {
+            let unused_bits = (octets * 8) as u64 - bit_len;
+            if fixed_size(C::MIN, C::MAX, C::EXTENSIBLE).is_none() {
+                oer::write_length(&mut w.buffer, 1 + octets);
+                w.buffer.push(unused_bits as u8);
+            }
+            w.buffer.extend_from_slice(&value[..octets]);
+            if let Some(last) = w.buffer.last_mut().filter(|_| octets > 0) {
+                // unused bits are zero (ITU-T X.696 | ISO/IEC 8825-7, 15.3)
+                *last &= 0xFF << unused_bits;
+            }
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_boolean<C: boolean::Constraint>(&mut self, value: bool) -> Result<(), Self::Error> {
+        self.write_component(Some(C::TAG), false, true, |w| {
+            w.buffer.push(if value { 0xFF } else { 0x00 });
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_null<C: null::Constraint>(&mut self, _value: &Null) -> Result<(), Self::Error> {
+        self.write_component(Some(C::TAG), false, true, |_| Ok(()))
+    }
+
+    #[inline]
+    fn write_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+        value: &ObjectIdentifier,
+    ) -> Result<(), Self::Error> {
+        // ITU-T X.696 | ISO/IEC 8825-7, 24: a length determinant followed by the contents
+        // octets of the BER encoding
+        let mut content = Vec::new();
+        ber::write_object_identifier(&mut content, C::TAG, value.arcs())
+            .map_err(invalid_ber_content)?;
+        self.write_component(Some(C::TAG), false, true, |w| {
+            oer::write_length(&mut w.buffer, content.len());
+            w.buffer.extend_from_slice(&content);
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_relative_oid<C: relativeoid::Constraint>(
+        &mut self,
+        value: &RelativeOid,
+    ) -> Result<(), Self::Error> {
+        // ITU-T X.696 | ISO/IEC 8825-7, 25
+        self.write_component(Some(C::TAG), false, true, |w| {
+            let mut content = Vec::new();
+            ber::write_relative_oid(&mut content, value.arcs());
+            oer::write_length(&mut w.buffer, content.len());
+            w.buffer.extend_from_slice(&content);
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_time<C: time::Constraint, T: time::TimeType>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        // ITU-T X.696 | ISO/IEC 8825-7, 29: as an unconstrained VisibleString
+        let value = value.to_string();
+        self.write_string(
+            C::TAG,
+            value.as_bytes(),
+            value.len() as u64,
+            None,
+            None,
+            false,
+        )
+    }
+}
+
+/// Reads values according to the basic Octet Encoding Rules of ITU-T X.696 | ISO/IEC 8825-7,
+/// see [`OerWriter`]. Unknown extension additions of SEQUENCE and SET values are skipped.
+pub struct OerReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    scope: Option<ReadScope>,
+    /// Whether the last value was rejected because its tag is not the tag of the chosen
+    /// alternative of the CHOICE value
+    mismatch: bool,
+}
+
+/// The constructed value the next value is a component of
+enum ReadScope {
+    Sequence(SequenceReadScope),
+    /// The tag of the chosen alternative has been read already
+    Choice {
+        tag: Tag,
+        extension: bool,
+    },
+}
+
+struct SequenceReadScope {
+    name: &'static str,
+    field: u64,
+    extended_after: Option<u64>,
+    /// The presence bits of the OPTIONAL and DEFAULT components of the root
+    presence: alloc::vec::IntoIter<bool>,
+    /// Whether there is an extension addition presence bitmap
+    extension: bool,
+    /// The presence bits of the extension additions, once the bitmap has been read
+    extensions: Option<alloc::vec::IntoIter<bool>>,
+}
+
+impl<'a> From<&'a [u8]> for OerReader<'a> {
+    fn from(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            position: 0,
+            scope: None,
+            mismatch: false,
+        }
+    }
+}
+
+impl<'a> OerReader<'a> {
+    #[inline]
+    pub fn bytes_remaining(&self) -> usize {
+        self.bytes.len() - self.position
+    }
+
+    fn read_octets(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        let octets = self
+            .bytes
+            .get(self.position..self.position.saturating_add(len))
+            .ok_or_else(|| Error::insufficient_data_in_source_buffer(self.position))?;
+        self.position += len;
+        Ok(octets)
+    }
+
+    /// Offsets of errors of the building blocks are relative to the current position
+    fn at_position(&self, error: Error) -> Error {
+        match error.kind() {
+            ErrorKind::InsufficientDataInSourceBuffer { offset } => {
+                Error::insufficient_data_in_source_buffer(self.position + offset)
+            }
+            _ => error,
+        }
+    }
+
+    fn read_length(&mut self) -> Result<usize, Error> {
+        let (length, consumed) =
+            oer::read_length(&self.bytes[self.position..]).map_err(|e| self.at_position(e))?;
+        self.position += consumed;
+        Ok(length)
+    }
+
+    fn read_quantity(&mut self) -> Result<usize, Error> {
+        let length = self.read_length()?;
+        let quantity = oer::read_fixed_integer(self.read_octets(length)?, false)?;
+        usize::try_from(quantity).map_err(|_| ErrorKind::ValueExceedsMaxInt.into())
+    }
+
+    /// Reads the next value - if present - as component of the current scope, see
+    /// [`OerWriter::write_component`]. Only OPTIONAL and DEFAULT components are read as `None`.
+    fn read_component<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        tag: Option<Tag>,
+        is_opt: bool,
+        f: F,
+    ) -> Result<Option<T>, Error> {
+        match self.scope.take() {
+            None => f(self).map(Some),
+            Some(ReadScope::Choice {
+                tag: found,
+                extension,
+            }) => match tag {
+                // the referenced value checks the tag instead
+                None => {
+                    self.scope = Some(ReadScope::Choice {
+                        tag: found,
+                        extension,
+                    });
+                    f(self).map(Some)
+                }
+                Some(tag) if tag != found => {
+                    self.scope = Some(ReadScope::Choice {
+                        tag: found,
+                        extension,
+                    });
+                    self.mismatch = true;
+                    Err(ErrorKind::UnexpectedChoiceTag {
+                        found,
+                        offset: self.position,
+                    }
+                    .into())
+                }
+                Some(_) if extension => self.read_open_type_encoding(f).map(Some),
+                Some(_) => f(self).map(Some),
+            },
+            Some(ReadScope::Sequence(mut sequence)) => {
+                let result = self.read_sequence_component(&mut sequence, is_opt, f);
+                self.scope = Some(ReadScope::Sequence(sequence));
+                result
+            }
+        }
+    }
+
+    fn read_sequence_component<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        sequence: &mut SequenceReadScope,
+        is_opt: bool,
+        f: F,
+    ) -> Result<Option<T>, Error> {
+        let index = sequence.field;
+        sequence.field += 1;
+        if sequence.extended_after.is_some_and(|after| index > after) {
+            let is_present = if sequence.extension {
+                if sequence.extensions.is_none() {
+                    sequence.extensions = Some(self.read_extension_bitmap()?.into_iter());
+                }
+                sequence
+                    .extensions
+                    .as_mut()
+                    .and_then(Iterator::next)
+                    .unwrap_or(false)
+            } else {
+                false
+            };
+            if is_present {
+                self.read_open_type_encoding(f).map(Some)
+            } else if is_opt {
+                Ok(None)
+            } else {
+                Err(ErrorKind::MissingExtensionAddition(sequence.name).into())
+            }
+        } else if !is_opt || sequence.presence.next().unwrap_or(false) {
+            f(self).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Reads a component that is neither OPTIONAL nor DEFAULT
+    fn read_value<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        tag: Option<Tag>,
+        f: F,
+    ) -> Result<T, Error> {
+        match self.read_component(tag, false, f)? {
+            Some(value) => Ok(value),
+            None => unreachable!("mandatory components are never read as absent"),
+        }
+    }
+
+    /// According to ITU-T X.696 | ISO/IEC 8825-7, 30, reads an encoding with a length
+    /// determinant in front of it, which must be fully consumed
+    fn read_open_type_encoding<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<T, Error> {
+        let length = self.read_length()?;
+        let end = self.position.saturating_add(length);
+        if end > self.bytes.len() {
+            return Err(Error::insufficient_data_in_source_buffer(self.position));
+        }
+        let value = f(self)?;
+        if self.position != end {
+            return Err(
+                ErrorKind::ContentNotFullyConsumed(end.saturating_sub(self.position)).into(),
+            );
+        }
+        Ok(value)
+    }
+
+    /// According to ITU-T X.696 | ISO/IEC 8825-7, 16.4, reads the extension addition presence
+    /// bitmap
+    fn read_extension_bitmap(&mut self) -> Result<Vec<bool>, Error> {
+        let length = self.read_length()?;
+        let octets = self.read_octets(length)?;
+        match octets.split_first() {
+            Some((unused_bits, bitmap)) if *unused_bits < 8 && !bitmap.is_empty() => Ok(
+                oer::read_bitmap(bitmap, bitmap.len() * 8 - usize::from(*unused_bits)),
+            ),
+            _ => {
+                Err(ErrorKind::InvalidContent("invalid extension addition presence bitmap").into())
+            }
+        }
+    }
+
+    fn read_string(
+        &mut self,
+        tag: Tag,
+        min: Option<u64>,
+        max: Option<u64>,
+        extensible: bool,
+    ) -> Result<&'a [u8], Error> {
+        let octets = self.read_value(Some(tag), |r| {
+            let length = match fixed_size(min, max, extensible) {
+                Some(size) => size as usize,
+                None => r.read_length()?,
+            };
+            r.read_octets(length)
+        })?;
+        check_size(octets.len() as u64, min, max, extensible)?;
+        Ok(octets)
+    }
+
+    fn read_ascii_string(
+        &mut self,
+        tag: Tag,
+        charset: Charset,
+        min: Option<u64>,
+        max: Option<u64>,
+        extensible: bool,
+    ) -> Result<String, Error> {
+        let octets = self.read_string(tag, min, max, extensible)?;
+        let string = String::from_utf8(octets.to_vec()).map_err(ErrorKind::from)?;
+        Error::ensure_string_valid(charset, &string)?;
+        Ok(string)
+    }
+
+    /// See [`OerWriter::write_charset_string`]
+    fn read_charset_string(
+        &mut self,
+        tag: Tag,
+        charset: Charset,
+        min: Option<u64>,
+        max: Option<u64>,
+        extensible: bool,
+    ) -> Result<String, Error> {
+        let octets = self.read_value(Some(tag), |r| {
+            let length = match fixed_size(min, max, extensible) {
+                Some(size) if is_known_multiplier(charset) => {
+                    size as usize * octets_per_char(charset)
+                }
+                _ => r.read_length()?,
+            };
+            r.read_octets(length)
+        })?;
+        let string = ber::read_string(tag, charset, octets).map_err(invalid_ber_content)?;
+        Error::ensure_string_valid(charset, &string)?;
+        check_size(string.chars().count() as u64, min, max, extensible)?;
+        Ok(string)
+    }
+}
+
+impl Reader for OerReader<'_> {
+    type Error = Error;
+
+    #[inline]
+    fn read_sequence<
+        C: sequence::Constraint,
+        S: Sized,
+        F: Fn(&mut Self) -> Result<S, Self::Error>,
+    >(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        if C::TRANSPARENT {
+            return f(self);
+        }
+        self.read_value(Some(C::TAG), |r| {
+            let extensible = C::EXTENDED_AFTER_FIELD.is_some();
+            let bits = usize::from(extensible) + C::STD_OPTIONAL_FIELDS as usize;
+            let mut preamble = oer::read_bitmap(r.read_octets(bits.div_ceil(8))?, bits);
+            let extension = extensible && preamble.remove(0);
+            r.scope = Some(ReadScope::Sequence(SequenceReadScope {
+                name: C::NAME,
+                field: 0,
+                extended_after: C::EXTENDED_AFTER_FIELD,
+                presence: preamble.into_iter(),
+                extension,
+                extensions: None,
+            }));
+            let result = f(r);
+            let scope = r.scope.take();
+            let value = result?;
+
+            if let Some(ReadScope::Sequence(sequence)) = scope.filter(|_| extension) {
+                // skip the extension additions that are unknown to this version of the type
+                let unknown = match sequence.extensions {
+                    Some(remaining) => remaining.collect(),
+                    None => r.read_extension_bitmap()?,
+                };
+                for _ in unknown.into_iter().filter(|is_present| *is_present) {
+                    let length = r.read_length()?;
+                    r.read_octets(length)?;
+                }
+            }
+            Ok(value)
+        })
+    }
+
+    #[inline]
+    fn read_sequence_of<C: sequenceof::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<Vec<T::Type>, Self::Error> {
+        self.read_value(Some(C::TAG), |r| {
+            let quantity = r.read_quantity()?;
+            check_size(quantity as u64, C::MIN, C::MAX, C::EXTENSIBLE)?;
+            // every component is encoded in at least one octet - except for NULL values
+            let mut values = Vec::with_capacity(quantity.min(r.bytes_remaining()));
+            for _ in 0..quantity {
+                values.push(T::read_value(r)?);
+            }
+            Ok(values)
+        })
+    }
+
+    #[inline]
+    fn read_set<C: set::Constraint, S: Sized, F: Fn(&mut Self) -> Result<S, Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        self.read_sequence::<C, S, F>(f)
+    }
+
+    #[inline]
+    fn read_set_of<C: setof::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<Vec<T::Type>, Self::Error> {
+        self.read_value(Some(C::TAG), |r| {
+            let quantity = r.read_quantity()?;
+            check_size(quantity as u64, C::MIN, C::MAX, C::EXTENSIBLE)?;
+            let mut values = Vec::with_capacity(quantity.min(r.bytes_remaining()));
+            for _ in 0..quantity {
+                values.push(T::read_value(r)?);
+            }
+            Ok(values)
+        })
+    }
+
+    #[inline]
+    fn read_enumerated<C: enumerated::Constraint>(&mut self) -> Result<C, Self::Error> {
+        let index = self.read_value(Some(C::TAG), |r| {
+            let first = r.read_octets(1)?[0];
+            if first < 0x80 {
+                Ok(i64::from(first))
+            } else {
+                let octets = r.read_octets(usize::from(first & 0x7F))?;
+                oer::read_fixed_integer(octets, true)
+            }
+        })?;
+        u64::try_from(index)
+            .ok()
+            .and_then(C::from_choice_index)
+            .ok_or_else(|| ErrorKind::InvalidChoiceIndex(index as u64, C::VARIANT_COUNT).into())
+    }
+
+    #[inline]
+    fn read_complex<C: complex::Constraint, T, F: FnOnce(&mut Self) -> Result<T, Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<T, Self::Error> {
+        self.read_value(C::TAGGED.then_some(C::TAG), f)
+    }
+
+    #[inline]
+    fn read_explicit<C: explicit::Constraint, T, F: FnOnce(&mut Self) -> Result<T, Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<T, Self::Error> {
+        self.read_value(Some(C::TAG), f)
+    }
+
+    #[inline]
+    fn read_choice<C: choice::Constraint>(&mut self) -> Result<C, Self::Error> {
+        self.read_value(Some(C::TAG), |r| {
+            let offset = r.position;
+            let (tag, consumed) =
+                oer::read_tag(&r.bytes[r.position..]).map_err(|e| r.at_position(e))?;
+            r.position += consumed;
+            // the chosen alternative is the one with the tag that has been read
+            for index in 0..C::VARIANT_COUNT {
+                r.scope = Some(ReadScope::Choice {
+                    tag,
+                    extension: C::EXTENSIBLE && index >= C::STD_VARIANT_COUNT,
+                });
+                r.mismatch = false;
+                let result = C::read_content(index, r);
+                r.scope = None;
+                match result {
+                    Ok(Some(value)) => return Ok(value),
+                    Ok(None) => break,
+                    Err(_) if core::mem::take(&mut r.mismatch) => continue,
+                    Err(e) => return Err(e),
+                }
+            }
+            Err(ErrorKind::UnexpectedChoiceTag { found: tag, offset }.into())
+        })
+    }
+
+    #[inline]
+    fn read_open_type<C: opentype::Constraint>(
+        &mut self,
+        key: Option<usize>,
+    ) -> Result<C, Self::Error> {
+        let key = key.ok_or(ErrorKind::InvalidOpenTypeKey(C::NAME))?;
+        self.read_value(Some(C::TAG), |r| {
+            if !C::KEYS.contains(&key) {
+                // keep the encoding of the open type for a key unknown to the object set
+                let length = r.read_length()?;
+                let bytes = r.read_octets(length)?.to_vec();
+                return C::from_unknown_key(key, bytes).ok_or_else(|| {
+                    ErrorKind::InvalidChoiceIndex(key as u64, C::VARIANT_COUNT).into()
+                });
+            }
+            r.read_open_type_encoding(|r| {
+                C::read_content(key, r)?.ok_or_else(|| {
+                    ErrorKind::InvalidChoiceIndex(key as u64, C::VARIANT_COUNT).into()
+                })
+            })
+        })
+    }
+
+    #[inline]
+    fn read_present<T: ReadableType>(
+        &mut self,
+        field: &'static str,
+    ) -> Result<T::Type, Self::Error> {
+        self.read_opt::<T>()?.ok_or_else(|| {
+            ErrorKind::ComponentConstraintViolated(field, ViolationKind::ComponentNotPresent).into()
+        })
+    }
+
+    #[inline]
+    fn read_opt<T: ReadableType>(&mut self) -> Result<Option<T::Type>, Self::Error> {
+        self.read_component(None, true, T::read_value)
+    }
+
+    #[inline]
+    fn read_default<C: default::Constraint<Owned = T::Type>, T: ReadableType>(
+        &mut self,
+    ) -> Result<T::Type, Self::Error> {
+        Ok(self
+            .read_opt::<T>()?
+            .unwrap_or_else(|| C::DEFAULT_VALUE.to_owned()))
+    }
+
+    #[inline]
+    fn read_number<T: numbers::Number, C: numbers::Constraint<T>>(
+        &mut self,
+    ) -> Result<T, Self::Error> {
+        let value = self.read_value(Some(C::TAG), |r| {
+            match fixed_integer_size(C::MIN, C::MAX, C::EXTENSIBLE) {
+                Some((size, signed)) => oer::read_fixed_integer(r.read_octets(size)?, signed),
+                None => {
+                    let length = r.read_length()?;
+                    if length == 0 {
+                        return Err(ErrorKind::InvalidContent(
+                            "an integer is encoded in at least one octet",
+                        )
+                        .into());
+                    }
+                    let octets = r.read_octets(length)?;
+                    oer::read_fixed_integer(octets, is_signed(C::MIN, C::EXTENSIBLE))
+                }
+            }
+        })?;
+        check_range(value, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        Ok(T::from_i64(value))
+    }
+
+    #[inline]
+    fn read_real<C: real::Constraint>(&mut self) -> Result<f64, Self::Error> {
+        self.read_value(Some(C::TAG), |r| {
+            let length = r.read_length()?;
+            let content = r.read_octets(length)?;
+            ber::read_real(C::TAG, content).map_err(invalid_ber_content)
+        })
+    }
+
+    #[inline]
+    fn read_utf8string<C: utf8string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let octets = self.read_value(Some(C::TAG), |r| {
+            let length = r.read_length()?;
+            r.read_octets(length)
+        })?;
+        let string = String::from_utf8(octets.to_vec()).map_err(ErrorKind::from)?;
+        check_size(string.chars().count() as u64, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        Ok(string)
+    }
+
+    #[inline]
+    fn read_ia5string<C: ia5string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_ascii_string(C::TAG, Charset::Ia5, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn read_numeric_string<C: numericstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_ascii_string(C::TAG, Charset::Numeric, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn read_visible_string<C: visiblestring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_ascii_string(C::TAG, Charset::Visible, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn read_printable_string<C: printablestring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        self.read_ascii_string(C::TAG, Charset::Printable, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn read_teletex_string<C: teletexstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_charset_string(C::TAG, Charset::Teletex, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn read_graphic_string<C: graphicstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_charset_string(C::TAG, Charset::Graphic, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn read_general_string<C: generalstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_charset_string(C::TAG, Charset::General, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn read_universal_string<C: universalstring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        self.read_charset_string(C::TAG, Charset::Universal, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn read_bmp_string<C: bmpstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_charset_string(C::TAG, Charset::Bmp, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn read_octet_string<C: octetstring::Constraint>(&mut self) -> Result<Vec<u8>, Self::Error> {
+        self.read_string(C::TAG, C::MIN, C::MAX, C::EXTENSIBLE)
+            .map(<[u8]>::to_vec)
+    }
+
+    #[inline]
+    fn read_bit_string<C: bitstring::Constraint>(&mut self) -> Result<(Vec<u8>, u64), Self::Error> {
+        let (octets, bit_len) = self.read_value(Some(C::TAG), |r| {
+            match fixed_size(C::MIN, C::MAX, C::EXTENSIBLE) {
+                Some(bit_len) => Ok((r.read_octets(bit_len.div_ceil(8) as usize)?, bit_len)),
+                None => {
+                    let length = r.read_length()?;
+                    match r.read_octets(length)?.split_first() {
+                        Some((unused_bits, octets))
+                            if *unused_bits < 8 && (*unused_bits == 0 || !octets.is_empty()) =>
+                        {
+                            let bit_len = octets.len() as u64 * 8 - u64::from(*unused_bits);
+                            Ok((octets, bit_len))
+                        }
+                        _ => Err(ErrorKind::InvalidContent("invalid amount of unused bits").into()),
+                    }
+                }
+            }
+        })?;
+        check_size(bit_len, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        Ok((octets.to_vec(), bit_len))
+    }
+
+    #[inline]
+    fn read_boolean<C: boolean::Constraint>(&mut self) -> Result<bool, Self::Error> {
+        self.read_value(Some(C::TAG), |r| match r.read_octets(1)?[0] {
+            0x00 => Ok(false),
+            0xFF => Ok(true),
+            _ => Err(ErrorKind::InvalidContent("a boolean is encoded as 0x00 or 0xFF").into()),
+        })
+    }
+
+    #[inline]
+    fn read_null<C: null::Constraint>(&mut self) -> Result<Null, Self::Error> {
+        self.read_value(Some(C::TAG), |_| Ok(Null))
+    }
+
+    #[inline]
+    fn read_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+    ) -> Result<ObjectIdentifier, Self::Error> {
+        self.read_value(Some(C::TAG), |r| {
+            let length = r.read_length()?;
+            let content = r.read_octets(length)?;
+            ber::read_object_identifier(C::TAG, content)
+                .map(ObjectIdentifier::new)
+                .map_err(invalid_ber_content)
+        })
+    }
+
+    #[inline]
+    fn read_relative_oid<C: relativeoid::Constraint>(
+        &mut self,
+    ) -> Result<RelativeOid, Self::Error> {
+        self.read_value(Some(C::TAG), |r| {
+            let length = r.read_length()?;
+            let content = r.read_octets(length)?;
+            ber::read_relative_oid(C::TAG, content)
+                .map(RelativeOid::new)
+                .map_err(invalid_ber_content)
+        })
+    }
+
+    #[inline]
+    fn read_time<C: time::Constraint, T: time::TimeType>(&mut self) -> Result<T, Self::Error> {
+        self.read_ascii_string(C::TAG, Charset::Visible, None, None, false)?
+            .parse::<T>()
+            .map_err(|e| ErrorKind::InvalidContent(e.reason()).into())
+    }
+}
+
+/// Maps an error of the contents octets that are shared with BER
+#[cold]
+#[inline(never)]
+fn invalid_ber_content(e: ber::Error) -> Error {
+    match e.kind() {
+        ber::ErrorKind::InvalidContent(_, reason) => ErrorKind::InvalidContent(reason).into(),
+        _ => ErrorKind::ValueExceedsMaxInt.into(),
+    }
+}
+
+/// ITU-T X.696 | ISO/IEC 8825-7, 27.1
+#[inline]
+fn is_known_multiplier(charset: Charset) -> bool {
+    !matches!(
+        charset,
+        Charset::Utf8 | Charset::Teletex | Charset::Graphic | Charset::General
+    )
+}
+
+#[inline]
+fn octets_per_char(charset: Charset) -> usize {
+    match charset {
+        Charset::Bmp => 2,
+        Charset::Universal => 4,
+        _ => 1,
+    }
+}
+
+/// The size of strings that are encoded without a length determinant
+#[inline]
+fn fixed_size(min: Option<u64>, max: Option<u64>, extensible: bool) -> Option<u64> {
+    min.filter(|min| !extensible && Some(*min) == max)
+}
+
+/// The number of octets of integers that are encoded without a length determinant and whether
+/// they are signed
+#[inline]
+fn fixed_integer_size(
+    min: Option<i64>,
+    max: Option<i64>,
+    extensible: bool,
+) -> Option<(usize, bool)> {
+    if extensible {
+        None
+    } else {
+        oer::fixed_integer_size(min, max)
+    }
+}
+
+/// Whether an integer that is encoded with a length determinant is signed
+#[inline]
+fn is_signed(min: Option<i64>, extensible: bool) -> bool {
+    extensible || min.is_none_or(|min| min < 0)
+}
+
+#[inline]
+fn check_size(
+    size: u64,
+    min: Option<u64>,
+    max: Option<u64>,
+    extensible: bool,
+) -> Result<(), Error> {
+    if !extensible && (min.is_some_and(|min| size < min) || max.is_some_and(|max| size > max)) {
+        Err(ErrorKind::SizeNotInRange(size, min.unwrap_or(0), max.unwrap_or(u64::MAX)).into())
+    } else {
+        Ok(())
+    }
+}
+
+#[inline]
+fn check_range(
+    value: i64,
+    min: Option<i64>,
+    max: Option<i64>,
+    extensible: bool,
+) -> Result<(), Error> {
+    if !extensible && (min.is_some_and(|min| value < min) || max.is_some_and(|max| value > max)) {
+        Err(
+            ErrorKind::ValueNotInRange(value, min.unwrap_or(i64::MIN), max.unwrap_or(i64::MAX))
+                .into(),
+        )
+    } else {
+        Ok(())
+    }
+}
+
+pub trait OerDecodable<'a> {
+    fn decode_from_oer(bytes: &'a [u8]) -> Result<Self, Error>
+    where
+        Self: Sized;
+}
+
+impl<'a, R: Readable> OerDecodable<'a> for R {
+    fn decode_from_oer(bytes: &'a [u8]) -> Result<Self, Error>
+    where
+        Self: Sized,
+    {
+        let mut reader = OerReader::from(bytes);
+        Self::read(&mut reader)
+    }
+}
@@ -1,5 +1,5 @@
-use crate::syn::*;
 use crate::syn::opentype::Constraint;
+use crate::syn::*;
 
 #[derive(Default)]
 pub struct PrintlnWriter(usize);
@@ -102,7 +102,6 @@ impl Writer for PrintlnWriter {
         })
     }
 
-
     fn write_choice<C: choice::Constraint>(&mut self, choice: &C) -> Result<(), Self::Error> {
         self.indented_println(&format!("Write choice {}, tag={:?}", C::NAME, C::TAG));
         self.with_increased_indentation(|w| {
@@ -191,6 +190,12 @@ impl Writer for PrintlnWriter {
         Ok(())
     }
 
+    fn write_real<C: real::Constraint>(&mut self, value: f64) -> Result<(), Self::Error> {
+        self.indented_println(format!("WRITING Real, tag={:?}", C::TAG));
+        self.with_increased_indentation(|w| w.indented_println(value.to_string()));
+        Ok(())
+    }
+
     fn write_utf8string<C: utf8string::Constraint>(
         &mut self,
         value: &str,
@@ -281,6 +286,96 @@ impl Writer for PrintlnWriter {
         Ok(())
     }
 
+    fn write_teletex_string<C: teletexstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.indented_println(format!(
+            "Writing TeletexString({}..{}), tag={:?}",
+            C::MIN
+                .map(|v| format!("{}", v))
+                .unwrap_or_else(|| String::from("MIN")),
+            C::MAX
+                .map(|v| format!("{}", v))
+                .unwrap_or_else(|| String::from("MAX")),
+            C::TAG
+        ));
+        self.with_increased_indentation(|w| w.indented_println(format!("{:?}", value)));
+        Ok(())
+    }
+
+    fn write_graphic_string<C: graphicstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.indented_println(format!(
+            "Writing GraphicString({}..{}), tag={:?}",
+            C::MIN
+                .map(|v| format!("{}", v))
+                .unwrap_or_else(|| String::from("MIN")),
+            C::MAX
+                .map(|v| format!("{}", v))
+                .unwrap_or_else(|| String::from("MAX")),
+            C::TAG
+        ));
+        self.with_increased_indentation(|w| w.indented_println(format!("{:?}", value)));
+        Ok(())
+    }
+
+    fn write_general_string<C: generalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.indented_println(format!(
+            "Writing GeneralString({}..{}), tag={:?}",
+            C::MIN
+                .map(|v| format!("{}", v))
+                .unwrap_or_else(|| String::from("MIN")),
+            C::MAX
+                .map(|v| format!("{}", v))
+                .unwrap_or_else(|| String::from("MAX")),
+            C::TAG
+        ));
+        self.with_increased_indentation(|w| w.indented_println(format!("{:?}", value)));
+        Ok(())
+    }
+
+    fn write_universal_string<C: universalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.indented_println(format!(
+            "Writing UniversalString({}..{}), tag={:?}",
+            C::MIN
+                .map(|v| format!("{}", v))
+                .unwrap_or_else(|| String::from("MIN")),
+            C::MAX
+                .map(|v| format!("{}", v))
+                .unwrap_or_else(|| String::from("MAX")),
+            C::TAG
+        ));
+        self.with_increased_indentation(|w| w.indented_println(format!("{:?}", value)));
+        Ok(())
+    }
+
+    fn write_bmp_string<C: bmpstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.indented_println(format!(
+            "Writing BMPString({}..{}), tag={:?}",
+            C::MIN
+                .map(|v| format!("{}", v))
+                .unwrap_or_else(|| String::from("MIN")),
+            C::MAX
+                .map(|v| format!("{}", v))
+                .unwrap_or_else(|| String::from("MAX")),
+            C::TAG
+        ));
+        self.with_increased_indentation(|w| w.indented_println(format!("{:?}", value)));
+        Ok(())
+    }
+
     fn write_octet_string<C: octetstring::Constraint>(
         &mut self,
         value: &[u8],
@@ -329,4 +424,31 @@ impl Writer for PrintlnWriter {
         self.indented_println(format!("WRITING NULL, tag={:?}", C::TAG));
         Ok(())
     }
+
+    fn write_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+        value: &ObjectIdentifier,
+    ) -> Result<(), Self::Error> {
+        self.indented_println(format!("WRITING ObjectIdentifier, tag={:?}", C::TAG));
+        self.with_increased_indentation(|w| w.indented_println(value.to_string()));
+        Ok(())
+    }
+
+    fn write_relative_oid<C: relativeoid::Constraint>(
+        &mut self,
+        value: &RelativeOid,
+    ) -> Result<(), Self::Error> {
+        self.indented_println(format!("WRITING RelativeOid, tag={:?}", C::TAG));
+        self.with_increased_indentation(|w| w.indented_println(value.to_string()));
+        Ok(())
+    }
+
+    fn write_time<C: time::Constraint, T: time::TimeType>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.indented_println(format!("WRITING {}, tag={:?}", T::NAME, C::TAG));
+        self.with_increased_indentation(|w| w.indented_println(value.to_string()));
+        Ok(())
+    }
 }
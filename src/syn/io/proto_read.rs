@@ -1,10 +1,10 @@
 use crate::io::protobuf::ProtoRead as _;
 use crate::io::protobuf::{Error, Format};
+use crate::syn::opentype::Constraint;
 use crate::syn::*;
 use std::borrow::Cow;
 use std::collections::VecDeque;
 use std::ops::Range;
-use crate::syn::opentype::Constraint;
 
 #[derive(Debug, Clone)]
 enum State {
@@ -186,6 +186,28 @@ impl<'a> ProtobufReader<'a> {
         result
     }
 
+    /// Reads the key in the first field and the payload in the oneof field of the variant
+    fn read_open_type_content<C: Constraint>(
+        &mut self,
+        key: usize,
+        index: usize,
+    ) -> Result<C, <Self as Reader>::Error> {
+        let encoded_key = match self.next_tag_range_filter_format::<true>(Format::VarInt) {
+            Some(range) => (&mut &self.source[range]).read_varint()?,
+            None => 0,
+        };
+
+        if encoded_key != key as u64 {
+            return Err(Error::invalid_variant(encoded_key));
+        }
+
+        if let State::Enclosed { tag_counter, .. } = &mut self.state {
+            *tag_counter = index as u32 + 2;
+        }
+
+        C::read_content(key, self)?.ok_or_else(|| Error::invalid_variant(encoded_key))
+    }
+
     #[inline]
     fn read_set_or_sequence_of<T: ReadableType>(
         &mut self,
@@ -291,9 +313,37 @@ impl<'a> Reader for ProtobufReader<'a> {
         }
     }
     #[inline]
-    fn read_open_type<C: Constraint>(&mut self,_key:usize) -> Result<C, Self::Error> {
-        panic!("not support open type")
+    fn read_open_type<C: Constraint>(&mut self, key: Option<usize>) -> Result<C, Self::Error> {
+        let key = key.ok_or(Error::InvalidOpenTypeKey(C::NAME))?;
+        let index = C::KEYS
+            .iter()
+            .position(|k| *k == key)
+            .ok_or_else(|| Error::invalid_variant(key as u64))?;
+
+        match self.next_tag_range::<true>() {
+            None => Err(Error::MissingRequiredField(C::NAME)),
+            Some(range) => {
+                let mut state = self.index_enclosed(range)?;
+                core::mem::swap(&mut self.state, &mut state);
+                let result = self.read_open_type_content::<C>(key, index);
+                self.state = state;
+                result
+            }
+        }
     }
+
+    #[inline]
+    fn read_present<T: ReadableType>(
+        &mut self,
+        field: &'static str,
+    ) -> Result<T::Type, Self::Error> {
+        self.read_opt::<T>()?
+            .ok_or(Error::ComponentConstraintViolated(
+                field,
+                ViolationKind::ComponentNotPresent,
+            ))
+    }
+
     #[inline]
     fn read_opt<T: ReadableType>(
         &mut self,
@@ -343,6 +393,18 @@ impl<'a> Reader for ProtobufReader<'a> {
         }
     }
 
+    #[inline]
+    fn read_real<C: real::Constraint>(&mut self) -> Result<f64, Self::Error> {
+        let mut reader = self.next_range_format_reader(Format::Fixed64);
+
+        // protobuf does not serialize null or 0-ish values
+        if reader.is_empty() {
+            return Ok(0.0);
+        }
+
+        reader.read_double()
+    }
+
     #[inline]
     fn read_utf8string<C: utf8string::Constraint>(&mut self) -> Result<String, Self::Error> {
         let mut reader = self.next_range_format_reader(Format::LengthDelimited);
@@ -375,6 +437,38 @@ impl<'a> Reader for ProtobufReader<'a> {
         reader.read_string()
     }
 
+    #[inline]
+    fn read_teletex_string<C: teletexstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let mut reader = self.next_range_format_reader(Format::LengthDelimited);
+        reader.read_string()
+    }
+
+    #[inline]
+    fn read_graphic_string<C: graphicstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let mut reader = self.next_range_format_reader(Format::LengthDelimited);
+        reader.read_string()
+    }
+
+    #[inline]
+    fn read_general_string<C: generalstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let mut reader = self.next_range_format_reader(Format::LengthDelimited);
+        reader.read_string()
+    }
+
+    #[inline]
+    fn read_universal_string<C: universalstring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        let mut reader = self.next_range_format_reader(Format::LengthDelimited);
+        reader.read_string()
+    }
+
+    #[inline]
+    fn read_bmp_string<C: bmpstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let mut reader = self.next_range_format_reader(Format::LengthDelimited);
+        reader.read_string()
+    }
+
     #[inline]
     fn read_octet_string<C: octetstring::Constraint>(&mut self) -> Result<Vec<u8>, Self::Error> {
         let mut reader = self.next_range_format_reader(Format::LengthDelimited); // TODO Format::VarInt ??
@@ -405,4 +499,33 @@ impl<'a> Reader for ProtobufReader<'a> {
     fn read_null<C: null::Constraint>(&mut self) -> Result<Null, Self::Error> {
         Ok(Null)
     }
+
+    #[inline]
+    fn read_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+    ) -> Result<ObjectIdentifier, Self::Error> {
+        let mut reader = self.next_range_format_reader(Format::LengthDelimited);
+        let string = reader.read_string()?;
+        string
+            .parse()
+            .map_err(|_| Error::invalid_object_identifier(string))
+    }
+
+    #[inline]
+    fn read_relative_oid<C: relativeoid::Constraint>(
+        &mut self,
+    ) -> Result<RelativeOid, Self::Error> {
+        let mut reader = self.next_range_format_reader(Format::LengthDelimited);
+        let string = reader.read_string()?;
+        string
+            .parse()
+            .map_err(|_| Error::invalid_object_identifier(string))
+    }
+
+    #[inline]
+    fn read_time<C: time::Constraint, T: time::TimeType>(&mut self) -> Result<T, Self::Error> {
+        let mut reader = self.next_range_format_reader(Format::LengthDelimited);
+        let string = reader.read_string()?;
+        string.parse().map_err(|_| Error::invalid_time(string))
+    }
 }
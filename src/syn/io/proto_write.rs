@@ -1,9 +1,10 @@
 use crate::io::protobuf::ProtoWrite as _;
 use crate::io::protobuf::{Error, Format};
+use crate::model::PresenceConstraint;
 use crate::prelude::ProtobufReader;
+use crate::syn::opentype::Constraint;
 use crate::syn::*;
 use std::io::Write;
-use crate::syn::opentype::Constraint;
 
 #[derive(Debug, Default, Copy, Clone)]
 struct State {
@@ -265,8 +266,75 @@ impl Writer for ProtobufWriter<'_> {
     }
 
     #[inline]
-    fn write_open_type<C: Constraint>(&mut self, _opentype: &C) -> Result<(), Self::Error> {
-        panic!("not support open type")
+    fn write_open_type<C: Constraint>(&mut self, opentype: &C) -> Result<(), Self::Error> {
+        let key = opentype.to_choice_index();
+        let index = C::KEYS
+            .iter()
+            .position(|k| *k == key)
+            .ok_or_else(|| Error::invalid_variant(key as u64))?;
+        let root = core::mem::take(&mut self.is_root);
+
+        // the key is the first field, followed by the oneof of the variant payloads
+        let result = if !root {
+            let mut state = core::mem::take(&mut self.state);
+            let mut buffer = core::mem::take(&mut self.buffer);
+
+            // writing to the new buffer
+            let result = self
+                .buffer
+                .write_tagged_uint64(1, key as u64)
+                .and_then(|_| {
+                    self.state.tag_counter = index as u32 + 1;
+                    opentype.write_content(self)
+                });
+
+            // restore the original self attributes
+            core::mem::swap(&mut buffer, &mut self.buffer);
+            core::mem::swap(&mut state, &mut self.state);
+
+            if result.is_ok() {
+                let buffer = buffer.into_inner_vec().unwrap(); // fine because take creates a vec
+                let format = Format::LengthDelimited;
+                let tag = self.state.tag_counter + 1;
+                self.buffer.write_tag(tag, format)?;
+                self.buffer.write_bytes(&buffer[..])?;
+                self.state.tag_counter = tag;
+            }
+
+            result
+        } else {
+            self.buffer.write_tagged_uint64(1, key as u64)?;
+            self.state.tag_counter = index as u32 + 1;
+            opentype.write_content(self)
+        };
+
+        self.state.format = Some(Format::LengthDelimited);
+        result
+    }
+
+    #[inline]
+    fn check_presence(
+        &mut self,
+        field: &'static str,
+        constraint: PresenceConstraint,
+        present: bool,
+    ) -> Result<(), Self::Error> {
+        match ViolationKind::of_presence(constraint, present) {
+            Some(kind) => Err(Error::ComponentConstraintViolated(field, kind)),
+            None => Ok(()),
+        }
+    }
+
+    #[inline]
+    fn check_value(&mut self, field: &'static str, permitted: bool) -> Result<(), Self::Error> {
+        if permitted {
+            Ok(())
+        } else {
+            Err(Error::ComponentConstraintViolated(
+                field,
+                ViolationKind::ValueNotPermitted,
+            ))
+        }
     }
 
     #[inline]
@@ -324,6 +392,15 @@ impl Writer for ProtobufWriter<'_> {
         Ok(())
     }
 
+    #[inline]
+    fn write_real<C: real::Constraint>(&mut self, value: f64) -> Result<(), Self::Error> {
+        let tag = self.state.tag_counter + 1;
+        self.buffer.write_tagged_double(tag, value)?;
+        self.state.format = Some(Format::Fixed64);
+        self.state.tag_counter = tag;
+        Ok(())
+    }
+
     #[inline]
     fn write_utf8string<C: utf8string::Constraint>(
         &mut self,
@@ -384,6 +461,66 @@ impl Writer for ProtobufWriter<'_> {
         Ok(())
     }
 
+    #[inline]
+    fn write_teletex_string<C: teletexstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        let tag = self.state.tag_counter + 1;
+        self.buffer.write_tagged_string(tag, value)?;
+        self.state.tag_counter = tag;
+        self.state.format = Some(Format::LengthDelimited);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_graphic_string<C: graphicstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        let tag = self.state.tag_counter + 1;
+        self.buffer.write_tagged_string(tag, value)?;
+        self.state.tag_counter = tag;
+        self.state.format = Some(Format::LengthDelimited);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_general_string<C: generalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        let tag = self.state.tag_counter + 1;
+        self.buffer.write_tagged_string(tag, value)?;
+        self.state.tag_counter = tag;
+        self.state.format = Some(Format::LengthDelimited);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_universal_string<C: universalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        let tag = self.state.tag_counter + 1;
+        self.buffer.write_tagged_string(tag, value)?;
+        self.state.tag_counter = tag;
+        self.state.format = Some(Format::LengthDelimited);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_bmp_string<C: bmpstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        let tag = self.state.tag_counter + 1;
+        self.buffer.write_tagged_string(tag, value)?;
+        self.state.tag_counter = tag;
+        self.state.format = Some(Format::LengthDelimited);
+        Ok(())
+    }
+
     #[inline]
     fn write_octet_string<C: octetstring::Constraint>(
         &mut self,
@@ -425,4 +562,40 @@ impl Writer for ProtobufWriter<'_> {
     fn write_null<C: null::Constraint>(&mut self, _value: &Null) -> Result<(), Self::Error> {
         Ok(())
     }
+
+    #[inline]
+    fn write_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+        value: &ObjectIdentifier,
+    ) -> Result<(), Self::Error> {
+        let tag = self.state.tag_counter + 1;
+        self.buffer.write_tagged_string(tag, &value.to_string())?;
+        self.state.tag_counter = tag;
+        self.state.format = Some(Format::LengthDelimited);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_relative_oid<C: relativeoid::Constraint>(
+        &mut self,
+        value: &RelativeOid,
+    ) -> Result<(), Self::Error> {
+        let tag = self.state.tag_counter + 1;
+        self.buffer.write_tagged_string(tag, &value.to_string())?;
+        self.state.tag_counter = tag;
+        self.state.format = Some(Format::LengthDelimited);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_time<C: time::Constraint, T: time::TimeType>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        let tag = self.state.tag_counter + 1;
+        self.buffer.write_tagged_string(tag, &value.to_string())?;
+        self.state.tag_counter = tag;
+        self.state.format = Some(Format::LengthDelimited);
+        Ok(())
+    }
 }
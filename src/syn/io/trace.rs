@@ -0,0 +1,603 @@
+use crate::io::per::err::{Error, ErrorKind};
+use crate::io::per::unaligned::BYTE_LEN;
+use crate::syn::io::{Bits, ScopedBitRead, UperRead, UperReader};
+use crate::syn::*;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cell::Cell;
+use core::fmt::Debug;
+
+/// Reads UPER like the wrapped [`UperReader`], but records every primitive it reads as
+/// [`TraceEntry`], similar to the output of a PER viewer. The entries read before a failure
+/// remain available, which helps to find the value that cannot be decoded.
+///
+/// ```rust
+/// use asn1rs::prelude::*;
+///
+/// asn_to_rust!(
+///     r"Basic DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+///     Pair ::= SEQUENCE { key INTEGER (0..255), value UTF8String OPTIONAL }
+///     END"
+/// );
+///
+/// let mut reader = TracingReader::from(&[0x15, 0x00][..]);
+/// let pair = reader.read::<Pair>().unwrap();
+/// assert_eq!(42, pair.key);
+///
+/// let trace = reader.into_trace();
+/// assert_eq!("Pair.key", trace.0[1].path);
+/// assert_eq!("00101010", trace.0[1].bits);
+/// println!("{}", trace);
+/// ```
+pub struct TracingReader<B: ScopedBitRead> {
+    reader: UperReader<B>,
+    tracer: Tracer,
+}
+
+impl<B: ScopedBitRead> From<UperReader<B>> for TracingReader<B> {
+    fn from(reader: UperReader<B>) -> Self {
+        Self {
+            reader,
+            tracer: Tracer::default(),
+        }
+    }
+}
+
+impl<'a, I: Into<Bits<'a>>> From<I> for TracingReader<Bits<'a>> {
+    fn from(bits: I) -> Self {
+        Self::from(UperReader::from(bits))
+    }
+}
+
+impl<B: ScopedBitRead> TracingReader<B> {
+    #[inline]
+    pub fn bits_remaining(&self) -> usize {
+        self.reader.bits_remaining()
+    }
+
+    /// The entries recorded so far, in the order they were read
+    pub fn entries(&self) -> &[TraceEntry] {
+        &self.tracer.entries[..]
+    }
+
+    pub fn into_trace(self) -> Trace {
+        Trace(self.tracer.entries)
+    }
+
+    /// Lets `f` read a primitive value with the wrapped [`UperReader`] and records the bits it
+    /// read
+    fn traced<T, D: FnOnce(&T) -> String, F: FnOnce(&mut UperReader<B>) -> Result<T, Error>>(
+        &mut self,
+        identifier: Option<&'static str>,
+        r#type: &'static str,
+        describe: D,
+        f: F,
+    ) -> Result<T, Error> {
+        let segment = self.tracer.take_identifier(identifier);
+        let offset = self.reader.bits.pos();
+        let result = f(&mut self.reader);
+        let value = match &result {
+            Ok(value) => describe(value),
+            Err(e) => e.kind().to_string(),
+        };
+        self.record(segment, offset, r#type, value);
+        result
+    }
+
+    /// Records the bits from `offset` to the current position. The dump stops at the first bit
+    /// that cannot be read.
+    fn record(
+        &mut self,
+        segment: Option<&str>,
+        offset: usize,
+        r#type: &'static str,
+        value: String,
+    ) {
+        let bits = &mut self.reader.bits;
+        let bits = (offset..bits.pos())
+            .map_while(|pos| bits.with_read_position_at(pos, |b| b.read_bit()).ok())
+            .map(|bit| if bit { '1' } else { '0' })
+            .collect();
+        self.tracer.record(segment, offset, bits, r#type, value);
+    }
+
+    /// Appends the identifier of a constructed value to the path of the trace and returns the
+    /// length of the path before. The outermost value is named by its type.
+    fn enter(&mut self, identifier: Option<&'static str>, name: &'static str) -> usize {
+        let segment = self
+            .tracer
+            .take_identifier(identifier)
+            .or_else(|| Some(name).filter(|_| self.tracer.is_at_root()));
+        self.tracer.enter(segment)
+    }
+}
+
+impl<B: ScopedBitRead> UperRead<B> for TracingReader<B> {
+    #[inline]
+    fn uper(&mut self) -> &mut UperReader<B> {
+        &mut self.reader
+    }
+}
+
+impl<B: ScopedBitRead> Reader for TracingReader<B> {
+    type Error = Error;
+
+    #[inline]
+    fn read<T: Readable>(&mut self) -> Result<T, Self::Error>
+    where
+        Self: Sized,
+    {
+        self.read_outermost()
+    }
+
+    fn read_sequence<
+        C: sequence::Constraint,
+        S: Sized,
+        F: Fn(&mut Self) -> Result<S, Self::Error>,
+    >(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        let offset = self.reader.bits.pos();
+        let len = self.enter(C::IDENTIFIER, C::NAME);
+        let result = self.read_sequence_with::<C, S, _>(|r| {
+            if r.reader.bits.pos() > offset {
+                r.record(None, offset, "SEQUENCE", String::from("preamble"));
+            }
+            f(r)
+        });
+        self.tracer.leave(len);
+        result
+    }
+
+    fn read_sequence_of<C: sequenceof::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<Vec<T::Type>, Self::Error> {
+        let offset = self.reader.bits.pos();
+        let len = self.enter(C::IDENTIFIER, "");
+        let index = Cell::new(0_u64);
+        let result = self.read_sequence_of_with::<C, _, _, _>(
+            |r, length| r.record(None, offset, "SEQUENCE OF", length.to_string()),
+            |r| {
+                let len = r.tracer.enter(Some(&format!("[{}]", index.get())));
+                let value = T::read_value(r);
+                r.tracer.leave(len);
+                index.set(index.get() + 1);
+                value
+            },
+        );
+        self.tracer.leave(len);
+        result
+    }
+
+    #[inline]
+    fn read_set<C: set::Constraint, S: Sized, F: Fn(&mut Self) -> Result<S, Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        self.read_sequence::<C, S, F>(f)
+    }
+
+    #[inline]
+    fn read_set_of<C: setof::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<Vec<T::Type>, Self::Error> {
+        self.read_sequence_of::<C, T>()
+    }
+
+    fn read_enumerated<C: enumerated::Constraint>(&mut self) -> Result<C, Self::Error> {
+        self.traced(
+            C::IDENTIFIER,
+            "ENUMERATED",
+            |value: &C| {
+                let index = value.to_choice_index();
+                C::IDENTIFIERS
+                    .get(index as usize)
+                    .map_or_else(|| index.to_string(), |identifier| identifier.to_string())
+            },
+            |r| r.read_enumerated::<C>(),
+        )
+    }
+
+    fn read_complex<C: complex::Constraint, T, F: FnOnce(&mut Self) -> Result<T, Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<T, Self::Error> {
+        self.tracer.refer(C::IDENTIFIER);
+        let result = f(self);
+        self.tracer.take_identifier(None);
+        result
+    }
+
+    #[inline]
+    fn read_unknown_extensions(&mut self) -> Result<UnknownExtensions, Self::Error> {
+        self.reader.read_unknown_extensions()
+    }
+
+    fn read_choice<C: choice::Constraint>(&mut self) -> Result<C, Self::Error> {
+        let offset = self.reader.bits.pos();
+        let len = self.enter(C::IDENTIFIER, C::NAME);
+        let recorded = Cell::new(false);
+        let result = self.read_choice_with(|index, r: &mut Self| {
+            r.record(None, offset, "CHOICE", index.to_string());
+            recorded.set(true);
+            C::read_content(index, r)
+        });
+        if !recorded.get() {
+            // the index of an unknown alternative or the error that occurred while reading it
+            let value = match &result {
+                Ok(value) => value.to_choice_index().to_string(),
+                Err(e) => e.kind().to_string(),
+            };
+            self.record(None, offset, "CHOICE", value);
+        }
+        self.tracer.leave(len);
+        result
+    }
+
+    fn read_open_type<C: opentype::Constraint>(
+        &mut self,
+        key: Option<usize>,
+    ) -> Result<C, Self::Error> {
+        let offset = self.reader.bits.pos();
+        let len = self.enter(C::IDENTIFIER, C::NAME);
+        let recorded = Cell::new(false);
+        let result = self.read_open_type_with(key, |r: &mut Self, key| {
+            let length = r.reader.bits_remaining() / BYTE_LEN;
+            r.record(None, offset, "open type", length.to_string());
+            recorded.set(true);
+            C::read_content(key, r)
+        });
+        if !recorded.get() {
+            // the content for a key that is unknown to the object set
+            let value = match &result {
+                Ok(value) => value.unknown_bytes().map(hex).unwrap_or_default(),
+                Err(e) => e.kind().to_string(),
+            };
+            self.record(None, offset, "open type", value);
+        }
+        self.tracer.leave(len);
+        result
+    }
+
+    #[inline]
+    fn read_opt<T: ReadableType>(&mut self) -> Result<Option<T::Type>, Self::Error> {
+        self.read_opt_with(T::read_value)
+    }
+
+    #[inline]
+    fn read_present<T: ReadableType>(
+        &mut self,
+        field: &'static str,
+    ) -> Result<T::Type, Self::Error> {
+        self.read_opt::<T>()?.ok_or_else(|| {
+            ErrorKind::ComponentConstraintViolated(field, ViolationKind::ComponentNotPresent).into()
+        })
+    }
+
+    #[inline]
+    fn read_default<C: default::Constraint<Owned = T::Type>, T: ReadableType>(
+        &mut self,
+    ) -> Result<T::Type, Self::Error> {
+        self.read_default_with::<C, T>()
+    }
+
+    fn read_number<T: numbers::Number, C: numbers::Constraint<T>>(
+        &mut self,
+    ) -> Result<T, Self::Error> {
+        self.traced(
+            C::IDENTIFIER,
+            "INTEGER",
+            |value: &T| value.to_i64().to_string(),
+            |r| r.read_number::<T, C>(),
+        )
+    }
+
+    fn read_real<C: real::Constraint>(&mut self) -> Result<f64, Self::Error> {
+        self.traced(C::IDENTIFIER, "REAL", ToString::to_string, |r| {
+            r.read_real::<C>()
+        })
+    }
+
+    fn read_utf8string<C: utf8string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.traced(C::IDENTIFIER, "UTF8String", debug, |r| {
+            r.read_utf8string::<C>()
+        })
+    }
+
+    fn read_ia5string<C: ia5string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.traced(C::IDENTIFIER, "IA5String", debug, |r| {
+            r.read_ia5string::<C>()
+        })
+    }
+
+    fn read_numeric_string<C: numericstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.traced(C::IDENTIFIER, "NumericString", debug, |r| {
+            r.read_numeric_string::<C>()
+        })
+    }
+
+    fn read_printable_string<C: printablestring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        self.traced(C::IDENTIFIER, "PrintableString", debug, |r| {
+            r.read_printable_string::<C>()
+        })
+    }
+
+    fn read_visible_string<C: visiblestring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.traced(C::IDENTIFIER, "VisibleString", debug, |r| {
+            r.read_visible_string::<C>()
+        })
+    }
+
+    fn read_teletex_string<C: teletexstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.traced(C::IDENTIFIER, "TeletexString", debug, |r| {
+            r.read_teletex_string::<C>()
+        })
+    }
+
+    fn read_graphic_string<C: graphicstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.traced(C::IDENTIFIER, "GraphicString", debug, |r| {
+            r.read_graphic_string::<C>()
+        })
+    }
+
+    fn read_general_string<C: generalstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.traced(C::IDENTIFIER, "GeneralString", debug, |r| {
+            r.read_general_string::<C>()
+        })
+    }
+
+    fn read_universal_string<C: universalstring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        self.traced(C::IDENTIFIER, "UniversalString", debug, |r| {
+            r.read_universal_string::<C>()
+        })
+    }
+
+    fn read_bmp_string<C: bmpstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.traced(C::IDENTIFIER, "BMPString", debug, |r| {
+            r.read_bmp_string::<C>()
+        })
+    }
+
+    fn read_octet_string<C: octetstring::Constraint>(&mut self) -> Result<Vec<u8>, Self::Error> {
+        self.traced(
+            C::IDENTIFIER,
+            "OCTET STRING",
+            |octets: &Vec<u8>| hex(octets),
+            |r| r.read_octet_string::<C>(),
+        )
+    }
+
+    fn read_bit_string<C: bitstring::Constraint>(&mut self) -> Result<(Vec<u8>, u64), Self::Error> {
+        self.traced(
+            C::IDENTIFIER,
+            "BIT STRING",
+            |(bits, len): &(Vec<u8>, u64)| format!("{} ({} bits)", hex(bits), len),
+            |r| r.read_bit_string::<C>(),
+        )
+    }
+
+    fn read_boolean<C: boolean::Constraint>(&mut self) -> Result<bool, Self::Error> {
+        self.traced(C::IDENTIFIER, "BOOLEAN", ToString::to_string, |r| {
+            r.read_boolean::<C>()
+        })
+    }
+
+    fn read_null<C: null::Constraint>(&mut self) -> Result<Null, Self::Error> {
+        self.traced(
+            C::IDENTIFIER,
+            "NULL",
+            |_| String::from("NULL"),
+            |r| r.read_null::<C>(),
+        )
+    }
+
+    fn read_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+    ) -> Result<ObjectIdentifier, Self::Error> {
+        self.traced(
+            C::IDENTIFIER,
+            "OBJECT IDENTIFIER",
+            ToString::to_string,
+            |r| r.read_object_identifier::<C>(),
+        )
+    }
+
+    fn read_relative_oid<C: relativeoid::Constraint>(
+        &mut self,
+    ) -> Result<RelativeOid, Self::Error> {
+        self.traced(C::IDENTIFIER, "RELATIVE-OID", ToString::to_string, |r| {
+            r.read_relative_oid::<C>()
+        })
+    }
+
+    fn read_time<C: time::Constraint, T: time::TimeType>(&mut self) -> Result<T, Self::Error> {
+        self.traced(C::IDENTIFIER, "TIME", ToString::to_string, |r| {
+            r.read_time::<C, T>()
+        })
+    }
+}
+
+fn debug<T: Debug + ?Sized>(value: &T) -> String {
+    format!("{:?}", value)
+}
+
+/// The octets as pairs of hexadecimal digits, separated by spaces
+fn hex(octets: &[u8]) -> String {
+    octets
+        .iter()
+        .map(|octet| format!("{:02x}", octet))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// The bits of a primitive value or of the preamble, length or index of a constructed value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceEntry {
+    /// The position of the first bit, counted from the start of the encoding
+    pub offset: usize,
+    /// The bits as `0` and `1`, in the order they were read
+    pub bits: String,
+    /// The identifiers of the components from the outermost to the read value, separated by
+    /// `.`, and the index of elements of `SEQUENCE OF` and `SET OF` values in brackets - for
+    /// example `Message.items[2].name`. The outermost value is named by its type.
+    pub path: String,
+    /// The ASN.1 type that was read, such as `INTEGER` or `SEQUENCE`
+    pub r#type: &'static str,
+    /// The decoded value or the error that occurred while decoding it
+    pub value: String,
+}
+
+impl TraceEntry {
+    pub fn bit_len(&self) -> usize {
+        self.bits.len()
+    }
+}
+
+/// All recorded entries of a [`TracingReader`]. The [`Display`](core::fmt::Display) impl
+/// renders these as text table, [`Trace::to_json`] as JSON array.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Trace(pub Vec<TraceEntry>);
+
+impl Trace {
+    pub fn iter(&self) -> impl Iterator<Item = &TraceEntry> {
+        self.0.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// An array with an object per entry, whose members are named like the fields of
+    /// [`TraceEntry`] plus `length` for the number of bits
+    pub fn to_json(&self) -> String {
+        let mut json = String::from("[");
+        for (index, entry) in self.0.iter().enumerate() {
+            if index > 0 {
+                json.push(',');
+            }
+            json.push_str(&format!(
+                "{{\"offset\":{},\"length\":{},\"bits\":",
+                entry.offset,
+                entry.bit_len()
+            ));
+            crate::io::jer::write_string(&mut json, &entry.bits);
+            json.push_str(",\"path\":");
+            crate::io::jer::write_string(&mut json, &entry.path);
+            json.push_str(",\"type\":");
+            crate::io::jer::write_string(&mut json, entry.r#type);
+            json.push_str(",\"value\":");
+            crate::io::jer::write_string(&mut json, &entry.value);
+            json.push('}');
+        }
+        json.push(']');
+        json
+    }
+}
+
+impl core::fmt::Display for Trace {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let path_width = self.0.iter().map(|e| e.path.len()).max().unwrap_or(0);
+        let type_width = self.0.iter().map(|e| e.r#type.len()).max().unwrap_or(0);
+        writeln!(
+            f,
+            "{:>6} {:>6}  {:path_width$}  {:type_width$}  value  bits",
+            "offset", "length", "path", "type",
+        )?;
+        for entry in &self.0 {
+            writeln!(
+                f,
+                "{:>6} {:>6}  {:path_width$}  {:type_width$}  {}  {}",
+                entry.offset,
+                entry.bit_len(),
+                entry.path,
+                entry.r#type,
+                entry.value,
+                entry.bits,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl IntoIterator for Trace {
+    type Item = TraceEntry;
+    type IntoIter = alloc::vec::IntoIter<TraceEntry>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+/// The path and the recorded entries of a [`TracingReader`]
+#[derive(Default)]
+struct Tracer {
+    entries: Vec<TraceEntry>,
+    path: String,
+    identifier: Option<&'static str>,
+}
+
+impl Tracer {
+    /// The identifier of the next value. The identifier of the referencing field takes
+    /// precedence over the given identifier.
+    fn take_identifier(&mut self, identifier: Option<&'static str>) -> Option<&'static str> {
+        self.identifier.take().or(identifier)
+    }
+
+    /// Remembers the identifier of the field that refers to the next value
+    fn refer(&mut self, identifier: Option<&'static str>) {
+        if let Some(identifier) = identifier {
+            // the outermost identifier wins if the referenced type is just another reference
+            self.identifier.get_or_insert(identifier);
+        }
+    }
+
+    /// Appends the segment to the path and returns the length of the path before
+    fn enter(&mut self, segment: Option<&str>) -> usize {
+        let len = self.path.len();
+        if let Some(segment) = segment {
+            if !self.path.is_empty() && !segment.starts_with('[') {
+                self.path.push('.');
+            }
+            self.path.push_str(segment);
+        }
+        len
+    }
+
+    fn leave(&mut self, len: usize) {
+        self.identifier = None;
+        self.path.truncate(len);
+    }
+
+    fn is_at_root(&self) -> bool {
+        self.path.is_empty()
+    }
+
+    fn record(
+        &mut self,
+        segment: Option<&str>,
+        offset: usize,
+        bits: String,
+        r#type: &'static str,
+        value: String,
+    ) {
+        let len = self.enter(segment);
+        self.entries.push(TraceEntry {
+            offset,
+            bits,
+            path: self.path.clone(),
+            r#type,
+            value,
+        });
+        self.leave(len);
+    }
+}
@@ -1,18 +1,27 @@
 use crate::io::per::err::Error;
 use crate::io::per::err::ErrorKind;
 use crate::io::per::unaligned::buffer::BitBuffer;
-use crate::io::per::unaligned::BitWrite;
+use crate::io::per::unaligned::ScopedBitWrite;
 use crate::io::per::unaligned::BYTE_LEN;
+use crate::io::per::EffectiveAlphabet;
 use crate::io::per::PackedRead;
 use crate::io::per::PackedWrite;
-use crate::model::Charset;
+use crate::model::{Charset, PresenceConstraint};
+use crate::syn::borrowed::{BorrowedReadableType, BorrowedReader};
 use crate::syn::*;
-use std::fmt::Debug;
-use std::ops::Range;
+use alloc::borrow::Cow;
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use core::fmt::Debug;
+use core::ops::Range;
 
 pub use crate::io::per::unaligned::buffer::Bits;
+pub use crate::io::per::unaligned::buffer::BitsMut;
 pub use crate::io::per::unaligned::ScopedBitRead;
 use crate::syn::opentype::Constraint;
+use alloc::string::ToString;
+use alloc::vec;
+use alloc::vec::Vec;
 
 #[derive(Debug, Clone)]
 pub enum Scope {
@@ -35,14 +44,24 @@ pub enum Scope {
     ///
     /// To find the beginning of part2 - and thus to be able to insert the secondary-header - one
     /// needs to keep track of the current field number. Also, the position of where to write
-    /// the presence flags to must be updated as well.
+    /// the presence flags to must be updated as well. Because the secondary-header is only
+    /// written if any extended field is present, the leading absent extended fields are counted
+    /// until then.
+    ///
+    /// Extended fields unknown to the schema follow the known ones, both in the presence flags
+    /// and in the field contents. When writing, these are announced before the first field.
     ExtensibleSequence {
         name: &'static str,
         bit_pos: usize,
         opt_bit_field: Option<Range<usize>>,
         calls_until_ext_bitfield: usize,
         number_of_ext_fields: usize,
+        absent_ext_fields: usize,
+        unknown_ext_fields: UnknownExtensions,
     },
+    /// Like [`Scope::AllBitField`] for the known extended fields, which are followed by the
+    /// unknown extended fields that are written after all known fields
+    AllBitFieldWithUnknown(Range<usize>, UnknownExtensions),
     /// Indicates that the extensible sequence has no extension body
     ExtensibleSequenceEmpty(&'static str),
 }
@@ -53,12 +72,15 @@ impl Scope {
         match self {
             Scope::OptBitField(range) => range.start == range.end,
             Scope::AllBitField(range) => range.start == range.end,
+            Scope::AllBitFieldWithUnknown(range, _) => range.start == range.end,
             Scope::ExtensibleSequence {
                 name: _,
                 bit_pos: _,
                 opt_bit_field,
                 calls_until_ext_bitfield: _,
                 number_of_ext_fields: _,
+                absent_ext_fields: _,
+                unknown_ext_fields: _,
             } => match opt_bit_field {
                 Some(range) => range.start == range.end,
                 None => true,
@@ -71,14 +93,16 @@ impl Scope {
     pub const fn encode_as_open_type_field(&self) -> bool {
         matches!(
             self,
-            Scope::AllBitField(_) | Scope::ExtensibleSequenceEmpty(_)
+            Scope::AllBitField(_)
+                | Scope::AllBitFieldWithUnknown(..)
+                | Scope::ExtensibleSequenceEmpty(_)
         )
     }
 
     #[inline]
-    pub fn write_into_field(
+    pub fn write_into_field<B: ScopedBitWrite>(
         &mut self,
-        buffer: &mut BitBuffer,
+        buffer: &mut B,
         is_opt: bool,
         is_present: bool,
     ) -> Result<(), Error> {
@@ -93,60 +117,75 @@ impl Scope {
                     Ok(())
                 }
             }
-            Scope::AllBitField(range) => {
+            Scope::AllBitField(range) | Scope::AllBitFieldWithUnknown(range, _) => {
                 let result =
                     buffer.with_write_position_at(range.start, |b| b.write_bit(is_present));
                 range.start += 1;
                 result
             }
             Scope::ExtensibleSequence {
-                name,
+                name: _,
                 bit_pos: ext_bit_pos,
-                opt_bit_field,
+                opt_bit_field: _,
                 calls_until_ext_bitfield,
                 number_of_ext_fields,
-            } => {
-                if *calls_until_ext_bitfield == 0 {
-                    buffer.with_write_position_at(*ext_bit_pos, |b| b.write_bit(is_present))?;
-                    if is_present {
-                        // when we reach this point, there is never zero numbers of ext-fields
-                        buffer.write_normally_small_non_negative_whole_number(
-                            *number_of_ext_fields as u64 - 1,
-                        )?;
-                        let pos = buffer.write_position;
-                        for _ in 0..*number_of_ext_fields {
-                            if let Err(e) = buffer.write_bit(true) {
-                                buffer.write_position = pos;
-                                return Err(e);
-                            }
-                        }
-
-                        // pos + 1 because the bit for the current call is already set
-                        // by the initializer loop above
-                        let range = pos + 1..buffer.write_position;
-                        *self = Scope::AllBitField(range);
-                    } else {
-                        *self = Scope::ExtensibleSequenceEmpty(name);
+                absent_ext_fields,
+                unknown_ext_fields,
+            } if *calls_until_ext_bitfield == 0 => {
+                if !is_present {
+                    // nothing to write until an extended field is present
+                    *absent_ext_fields += 1;
+                    return Ok(());
+                }
+
+                buffer.with_write_position_at(*ext_bit_pos, |b| b.write_bit(true))?;
+                // when we reach this point, there is never zero numbers of ext-fields
+                buffer.write_normally_small_non_negative_whole_number(
+                    (*number_of_ext_fields + unknown_ext_fields.len()) as u64 - 1,
+                )?;
+                let pos = buffer.bit_len();
+                let flags = (0..*number_of_ext_fields)
+                    // the flags of the absent fields before the current call are not set
+                    .map(|index| index >= *absent_ext_fields)
+                    .chain(unknown_ext_fields.iter().map(|field| field.is_some()));
+                for flag in flags {
+                    if let Err(e) = buffer.write_bit(flag) {
+                        buffer.set_bit_len(pos);
+                        return Err(e);
                     }
-                    // no need for this
-                    // if is_present is true, the bit is already set (initialize loop above)
-                    // if is_present is false, no bit will be written (empty)
-                    // self.write_into_field(buffer, is_opt, is_present)
-                    Ok(())
+                }
+
+                // + 1 because the bit for the current call is already set by the initializer
+                // loop above
+                let range = pos + *absent_ext_fields + 1..pos + *number_of_ext_fields;
+                *self = if unknown_ext_fields.is_empty() {
+                    Scope::AllBitField(range)
                 } else {
-                    *calls_until_ext_bitfield = calls_until_ext_bitfield.saturating_sub(1);
-                    if let Some(range) = opt_bit_field {
-                        if is_opt {
-                            let result = buffer
-                                .with_write_position_at(range.start, |b| b.write_bit(is_present));
-                            range.start += 1;
-                            result
-                        } else {
-                            Ok(())
-                        }
+                    Scope::AllBitFieldWithUnknown(range, core::mem::take(unknown_ext_fields))
+                };
+                Ok(())
+            }
+            Scope::ExtensibleSequence {
+                name: _,
+                bit_pos: _,
+                opt_bit_field,
+                calls_until_ext_bitfield,
+                number_of_ext_fields: _,
+                absent_ext_fields: _,
+                unknown_ext_fields: _,
+            } => {
+                *calls_until_ext_bitfield = calls_until_ext_bitfield.saturating_sub(1);
+                if let Some(range) = opt_bit_field {
+                    if is_opt {
+                        let result =
+                            buffer.with_write_position_at(range.start, |b| b.write_bit(is_present));
+                        range.start += 1;
+                        result
                     } else {
                         Ok(())
                     }
+                } else {
+                    Ok(())
                 }
             }
             Scope::ExtensibleSequenceEmpty(name) => {
@@ -179,7 +218,7 @@ impl Scope {
                     Ok(None)
                 }
             }
-            Scope::AllBitField(range) => {
+            Scope::AllBitField(range) | Scope::AllBitFieldWithUnknown(range, _) => {
                 if range.start < range.end {
                     let result =
                         bits.with_read_position_at(range.start, |buffer| buffer.read_bit());
@@ -191,34 +230,20 @@ impl Scope {
                 }
             }
             Scope::ExtensibleSequence {
-                name,
-                bit_pos: ext_bit_pos,
+                name: _,
+                bit_pos: _,
                 opt_bit_field,
                 calls_until_ext_bitfield,
-                number_of_ext_fields,
+                number_of_ext_fields: _,
+                absent_ext_fields: _,
+                unknown_ext_fields: _,
             } => {
                 if *calls_until_ext_bitfield == 0 {
-                    if bits.with_read_position_at(*ext_bit_pos, |b| b.read_bit())? {
-                        let read_number_of_ext_fields =
-                            bits.read_normally_small_length()? as usize + 1;
-                        if read_number_of_ext_fields > *number_of_ext_fields {
-                            #[cfg(feature = "descriptive-deserialize-errors")]
-                            descriptions.push(ScopeDescription::warning(
-                                format!("read_number_of_ext_fields({read_number_of_ext_fields}) > *number_of_ext_fields({number_of_ext_fields})")
-                            ));
-                            //     return Err(Error::UnsupportedOperation(format!(
-                            //         "Expected no more than {} extended field{} but got {}",
-                            //         number_of_ext_fields,
-                            //         if *number_of_ext_fields != 1 { "s" } else { "" },
-                            //         read_number_of_ext_fields
-                            //     )));
-                        }
-                        let range = bits.pos()..bits.pos() + *number_of_ext_fields;
-                        bits.set_pos(range.start + read_number_of_ext_fields); // skip bit-field
-                        *self = Scope::AllBitField(range);
-                    } else {
-                        *self = Scope::ExtensibleSequenceEmpty(name);
-                    }
+                    self.read_extension_header(
+                        #[cfg(feature = "descriptive-deserialize-errors")]
+                        descriptions,
+                        bits,
+                    )?;
                     self.read_from_field(
                         #[cfg(feature = "descriptive-deserialize-errors")]
                         descriptions,
@@ -242,14 +267,59 @@ impl Scope {
             Scope::ExtensibleSequenceEmpty(_) => Ok(Some(false)),
         }
     }
+
+    /// Reads the number of extended fields and their presence flags after the last field of the
+    /// root, if the extension bit is set. The presence flags of extended fields unknown to the
+    /// schema remain at the end of the [`Scope::AllBitField`].
+    pub fn read_extension_header(
+        &mut self,
+        #[cfg(feature = "descriptive-deserialize-errors")] descriptions: &mut Vec<ScopeDescription>,
+        bits: &mut impl ScopedBitRead,
+    ) -> Result<(), Error> {
+        if let Scope::ExtensibleSequence {
+            name,
+            bit_pos: ext_bit_pos,
+            #[cfg(feature = "descriptive-deserialize-errors")]
+            number_of_ext_fields,
+            ..
+        } = self
+        {
+            if bits.with_read_position_at(*ext_bit_pos, |b| b.read_bit())? {
+                let read_number_of_ext_fields = bits.read_normally_small_length()? as usize + 1;
+                #[cfg(feature = "descriptive-deserialize-errors")]
+                if read_number_of_ext_fields > *number_of_ext_fields {
+                    descriptions.push(ScopeDescription::warning(format!(
+                        "read_number_of_ext_fields({read_number_of_ext_fields}) > *number_of_ext_fields({number_of_ext_fields})"
+                    )));
+                }
+                let range = bits.pos()..bits.pos() + read_number_of_ext_fields;
+                bits.set_pos(range.end); // skip bit-field
+                *self = Scope::AllBitField(range);
+            } else {
+                *self = Scope::ExtensibleSequenceEmpty(name);
+            }
+        }
+        Ok(())
+    }
 }
 
-#[derive(Default)]
-pub struct UperWriter {
-    bits: BitBuffer,
+/// Writes into a growing [`BitBuffer`] by default. Created from a `&mut [u8]`, it writes into
+/// the given slice instead and fails with [`ErrorKind::InsufficientSpaceInDestinationBuffer`]
+/// if the value does not fit.
+pub struct UperWriter<B: ScopedBitWrite = BitBuffer> {
+    pub(crate) bits: B,
     scope: Option<Scope>,
 }
 
+impl Default for UperWriter {
+    fn default() -> Self {
+        Self {
+            bits: BitBuffer::default(),
+            scope: None,
+        }
+    }
+}
+
 impl UperWriter {
     pub fn with_capacity(capacity_bytes: usize) -> Self {
         Self {
@@ -258,10 +328,6 @@ impl UperWriter {
         }
     }
 
-    pub fn byte_content(&self) -> &[u8] {
-        self.bits.content()
-    }
-
     pub const fn bit_len(&self) -> usize {
         self.bits.bit_len()
     }
@@ -273,9 +339,30 @@ impl UperWriter {
         );
         self.bits.into()
     }
+}
+
+impl<'a, I: Into<BitsMut<'a>>> From<I> for UperWriter<BitsMut<'a>> {
+    fn from(bits: I) -> Self {
+        Self {
+            bits: bits.into(),
+            scope: None,
+        }
+    }
+}
+
+impl UperWriter<BitsMut<'_>> {
+    pub const fn bit_len(&self) -> usize {
+        self.bits.bit_len()
+    }
+}
+
+impl<B: ScopedBitWrite> UperWriter<B> {
+    pub fn byte_content(&self) -> &[u8] {
+        self.bits.content()
+    }
 
     pub fn as_reader(&self) -> UperReader<Bits> {
-        UperReader::from(Bits::from((self.byte_content(), self.bit_len())))
+        UperReader::from(Bits::from((self.byte_content(), self.bits.bit_len())))
     }
 
     #[inline]
@@ -326,16 +413,92 @@ impl UperWriter {
         f: F,
     ) -> Result<T, Error> {
         if const_map_or!(self.scope, Scope::encode_as_open_type_field, false) {
-            let mut writer = UperWriter::with_capacity(512);
-            let result = f(&mut writer)?;
-            self.bits
-                .write_octetstring(None, None, false, writer.bits.content())?;
-            Ok(result)
+            self.write_octets_with(true, f)
         } else {
             f(self)
         }
     }
 
+    /// Lets `f` write its content straight into the buffer and inserts the length determinant in
+    /// front of it afterwards, so no temporary buffer is needed for open type fields. The
+    /// content is padded to full octets. If `fragmented`, it is split into fragments like an
+    /// unconstrained OCTET STRING, otherwise only a single length determinant precedes it.
+    pub(crate) fn write_octets_with<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        fragmented: bool,
+        f: F,
+    ) -> Result<T, Error> {
+        let start = self.bits.bit_len();
+        // reserve the length determinant for up to 127 octets, which is the most common case
+        self.bits.write_bits(&[0x00])?;
+        let result = self.scope_stashed(f)?;
+        let padding = (BYTE_LEN - (self.bits.bit_len() - start) % BYTE_LEN) % BYTE_LEN;
+        self.bits.write_bits_with_len(&[0x00], padding)?;
+
+        let content = start + BYTE_LEN..self.bits.bit_len();
+        let octets = (content.len() / BYTE_LEN) as u64;
+        let header_len = length_determinants(octets, fragmented)
+            .map(|(_, len, _)| len)
+            .sum::<usize>();
+
+        // move the content behind all length determinants, then move each fragment back to
+        // the front, right behind its length determinant
+        let mut source = content.start + header_len - BYTE_LEN;
+        if source != content.start {
+            self.bits.copy_octets_within(content.clone(), source)?;
+        }
+        let mut position = start;
+        for (header, len, fragment_octets) in length_determinants(octets, fragmented) {
+            let fragment_len = fragment_octets as usize * BYTE_LEN;
+            if source != position + len && fragment_len > 0 {
+                self.bits
+                    .copy_octets_within(source..source + fragment_len, position + len)?;
+            }
+            self.bits
+                .with_write_position_at(position, |b| b.write_bits_with_len(&header, len))?;
+            position += len + fragment_len;
+            source += fragment_len;
+        }
+        self.bits.set_bit_len(position);
+        Ok(result)
+    }
+
+    /// Writes the unknown extended fields of the current scope after all known fields. If none
+    /// of the known extended fields is present, the secondary-header was not written yet.
+    pub fn write_unknown_extension_fields(&mut self) -> Result<(), Error> {
+        let unknown = match &mut self.scope {
+            Some(Scope::ExtensibleSequence {
+                bit_pos,
+                number_of_ext_fields,
+                unknown_ext_fields,
+                ..
+            }) if unknown_ext_fields.any_present() => {
+                let unknown = core::mem::take(unknown_ext_fields);
+                let (bit_pos, number_of_ext_fields) = (*bit_pos, *number_of_ext_fields);
+                self.bits
+                    .with_write_position_at(bit_pos, |b| b.write_bit(true))?;
+                self.bits.write_normally_small_non_negative_whole_number(
+                    (number_of_ext_fields + unknown.len()) as u64 - 1,
+                )?;
+                for _ in 0..number_of_ext_fields {
+                    self.bits.write_bit(false)?;
+                }
+                for field in unknown.iter() {
+                    self.bits.write_bit(field.is_some())?;
+                }
+                unknown
+            }
+            Some(Scope::AllBitFieldWithUnknown(_, unknown_ext_fields)) => {
+                core::mem::take(unknown_ext_fields)
+            }
+            _ => return Ok(()),
+        };
+        for content in unknown.iter().flatten() {
+            self.bits.write_octetstring(None, None, false, content)?;
+        }
+        Ok(())
+    }
+
     #[inline]
     pub fn write_extensible_bit_and_length_or_err(
         &mut self,
@@ -365,9 +528,132 @@ impl UperWriter {
 
         Ok(out_of_range)
     }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 30: BMPString and UniversalString are
+    /// known-multiplier character string types with 16 and 32 bits per character, the other
+    /// character string types are written as their BER contents octets without PER-visible
+    /// constraints
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    pub(crate) fn write_charset_string(
+        &mut self,
+        charset: Charset,
+        extensible: bool,
+        min: Option<u64>,
+        max: Option<u64>,
+        value: &str,
+    ) -> Result<(), Error> {
+        Error::ensure_string_valid(charset, value)?;
+        let chars = value.chars().count() as u64;
+
+        if let Charset::Bmp | Charset::Universal = charset {
+            self.write_extensible_bit_and_length_or_err(extensible, min, max, u64::MAX, chars)?;
+            Octets::write_with(&mut self.bits, |octets| {
+                crate::io::ber::write_string(octets, charset, value)
+            })
+        } else {
+            if !extensible {
+                let min = const_unwrap_or!(min, 0);
+                let max = const_unwrap_or!(max, u64::MAX);
+                if chars < min || chars > max {
+                    return Err(ErrorKind::SizeNotInRange(chars, min, max).into());
+                }
+            }
+            self.write_octets_with(true, |w| {
+                Octets::write_with(&mut w.bits, |octets| {
+                    crate::io::ber::write_string(octets, charset, value)
+                })
+            })
+        }
+    }
+
+    /// Writes a known-multiplier character string with a PER-visible `FROM` constraint, see
+    /// [`EffectiveAlphabet`]
+    pub(crate) fn write_permitted_alphabet_string(
+        &mut self,
+        charset: Charset,
+        alphabet: &[(char, char)],
+        extensible: bool,
+        min: Option<u64>,
+        max: Option<u64>,
+        value: &str,
+    ) -> Result<(), Error> {
+        let alphabet = EffectiveAlphabet::new(alphabet, false);
+        let codes = value
+            .chars()
+            .enumerate()
+            .map(|(index, char)| alphabet.encode(charset, index, char))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        self.write_extensible_bit_and_length_or_err(
+            extensible,
+            min,
+            max,
+            u64::MAX,
+            codes.len() as u64,
+        )?;
+
+        for code in codes {
+            self.bits
+                .write_bits_with_offset(&code.to_be_bytes(), 32 - alphabet.bits_per_char())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The length determinants of an unconstrained OCTET STRING with the given number of octets
+/// (ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.9.3), each with its length in bits and the
+/// number of octets following it. Without `fragmented`, only the first length determinant is
+/// returned and followed by all octets.
+/// Writes the octets it is extended with straight into the bits, so that the BER contents octets
+/// of REAL, OBJECT IDENTIFIER and character string values need no temporary buffer. Writing
+/// stops at the first error, which is kept in `result`.
+struct Octets<'a, B: ScopedBitWrite> {
+    bits: &'a mut B,
+    result: Result<(), Error>,
+}
+
+impl<B: ScopedBitWrite> Octets<'_, B> {
+    fn write_with<F: FnOnce(&mut Octets<B>)>(bits: &mut B, f: F) -> Result<(), Error> {
+        let mut octets = Octets {
+            bits,
+            result: Ok(()),
+        };
+        f(&mut octets);
+        octets.result
+    }
 }
 
-impl Writer for UperWriter {
+impl<B: ScopedBitWrite> Extend<u8> for Octets<'_, B> {
+    fn extend<I: IntoIterator<Item = u8>>(&mut self, iter: I) {
+        for octet in iter {
+            if self.result.is_err() {
+                break;
+            }
+            self.result = self.bits.write_bits(&[octet]);
+        }
+    }
+}
+
+fn length_determinants(
+    octets: u64,
+    fragmented: bool,
+) -> impl Iterator<Item = ([u8; 2], usize, u64)> {
+    let mut remaining = Some(octets);
+    core::iter::from_fn(move || {
+        let length = remaining.take()?;
+        let mut header = [0u8; 2];
+        let mut header_len = 0;
+        let fragment = (&mut header[..], &mut header_len)
+            .write_length_determinant(None, None, length)
+            .ok()?
+            .filter(|_| fragmented);
+        remaining = fragment.map(|fragment| length - fragment);
+        Some((header, header_len, fragment.unwrap_or(length)))
+    })
+}
+
+impl<B: ScopedBitWrite> Writer for UperWriter<B> {
     type Error = Error;
 
     #[inline]
@@ -378,7 +664,7 @@ impl Writer for UperWriter {
         self.write_bit_field_entry(false, true)?;
         self.with_buffer(|w| {
             let extension = if let Some(extension_after) = C::EXTENDED_AFTER_FIELD {
-                let bit_pos = w.bits.write_position;
+                let bit_pos = w.bits.bit_len();
                 // if no extension field is present, none will call into overwriting this
                 w.bits.write_bit(false)?;
                 Some((extension_after, bit_pos))
@@ -389,13 +675,13 @@ impl Writer for UperWriter {
             // In UPER the values for all OPTIONAL flags are written before any field
             // value is written. This remembers their position, so a later call of `write_opt`
             // can write them to the buffer
-            let write_pos = w.bits.write_position;
+            let write_pos = w.bits.bit_len();
             let range = write_pos..write_pos + C::STD_OPTIONAL_FIELDS as usize;
             for _ in 0..C::STD_OPTIONAL_FIELDS {
                 // insert in reverse order so that a simple pop() in `write_opt` retrieves
                 // the relevant position
                 if let Err(e) = w.bits.write_bit(false) {
-                    w.bits.write_position = write_pos; // undo write_bits
+                    w.bits.set_bit_len(write_pos); // undo write_bits
                     return Err(e);
                 }
             }
@@ -408,8 +694,13 @@ impl Writer for UperWriter {
                         opt_bit_field: Some(range),
                         calls_until_ext_bitfield: (extension_after + 1) as usize,
                         number_of_ext_fields: (C::FIELD_COUNT - (extension_after + 1)) as usize,
+                        absent_ext_fields: 0,
+                        unknown_ext_fields: UnknownExtensions::default(),
+                    },
+                    |w| {
+                        f(w)?;
+                        w.write_unknown_extension_fields()
                     },
-                    f,
                 )
             } else {
                 w.scope_pushed(Scope::OptBitField(range), f)
@@ -483,12 +774,11 @@ impl Writer for UperWriter {
             w.bits
                 .write_choice_index(C::STD_VARIANT_COUNT, C::EXTENSIBLE, index)?;
 
-            if index >= C::STD_VARIANT_COUNT {
-                // TODO performance
-                let mut writer = UperWriter::with_capacity(512);
-                choice.write_content(&mut writer)?;
+            if let Some(unknown) = choice.unknown_extension() {
                 w.bits
-                    .write_octetstring(None, None, false, writer.byte_content())
+                    .write_octetstring(None, None, false, unknown.content())
+            } else if index >= C::STD_VARIANT_COUNT {
+                w.write_octets_with(true, |w| choice.write_content(w))
             } else {
                 choice.write_content(w)
             }
@@ -496,18 +786,53 @@ impl Writer for UperWriter {
     }
 
     #[inline]
-    fn write_open_type<C: opentype::Constraint>(&mut self, opentype: &C) -> Result<(), Self::Error> {
+    fn write_unknown_extensions(&mut self, unknown: &UnknownExtensions) -> Result<(), Self::Error> {
+        if let Some(Scope::ExtensibleSequence {
+            unknown_ext_fields, ..
+        }) = &mut self.scope
+        {
+            *unknown_ext_fields = unknown.clone();
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn write_open_type<C: opentype::Constraint>(
+        &mut self,
+        opentype: &C,
+    ) -> Result<(), Self::Error> {
         self.write_bit_field_entry(false, true)?;
-        self.with_buffer(|w| {
-            let mut writer = UperWriter::with_capacity(512);
-            opentype.write_content(&mut writer)?;
-            let bytes=writer.byte_content();
-            w.bits.write_length_determinant(None,None,bytes.len() as u64)?;
-            w.bits.write_bits(bytes)
-            // w.bits.write_octetstring(None, None, false, writer.byte_content())
+        self.with_buffer(|w| match opentype.unknown_bytes() {
+            Some(bytes) => w.bits.write_octetstring(None, None, false, bytes),
+            None => w.write_octets_with(false, |w| opentype.write_content(w)),
         })
     }
 
+    #[inline]
+    fn check_presence(
+        &mut self,
+        field: &'static str,
+        constraint: PresenceConstraint,
+        present: bool,
+    ) -> Result<(), Self::Error> {
+        match ViolationKind::of_presence(constraint, present) {
+            Some(kind) => Err(ErrorKind::ComponentConstraintViolated(field, kind).into()),
+            None => Ok(()),
+        }
+    }
+
+    #[inline]
+    fn check_value(&mut self, field: &'static str, permitted: bool) -> Result<(), Self::Error> {
+        if permitted {
+            Ok(())
+        } else {
+            Err(
+                ErrorKind::ComponentConstraintViolated(field, ViolationKind::ValueNotPermitted)
+                    .into(),
+            )
+        }
+    }
+
     #[inline]
     #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
     fn write_opt<T: WritableType>(
@@ -574,6 +899,20 @@ impl Writer for UperWriter {
         }
     }
 
+    #[inline]
+    fn write_real<C: real::Constraint>(&mut self, value: f64) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        // ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 15
+        // The contents octets of the CER/DER encoding with an unconstrained length determinant
+        self.with_buffer(|w| {
+            w.write_octets_with(true, |w| {
+                Octets::write_with(&mut w.bits, |octets| {
+                    crate::io::ber::write_real(octets, value)
+                })
+            })
+        })
+    }
+
     #[inline]
     #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
     fn write_utf8string<C: utf8string::Constraint>(
@@ -605,6 +944,18 @@ impl Writer for UperWriter {
     ) -> Result<(), Self::Error> {
         self.write_bit_field_entry(false, true)?;
         self.with_buffer(|w| {
+            if let Some(alphabet) = C::PERMITTED_ALPHABET {
+                Error::ensure_string_valid(Charset::Ia5, value)?;
+                return w.write_permitted_alphabet_string(
+                    Charset::Ia5,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                    value,
+                );
+            }
+
             Error::ensure_string_valid(Charset::Ia5, value)?;
 
             w.write_extensible_bit_and_length_or_err(
@@ -631,6 +982,18 @@ impl Writer for UperWriter {
     ) -> Result<(), Self::Error> {
         self.write_bit_field_entry(false, true)?;
         self.with_buffer(|w| {
+            if let Some(alphabet) = C::PERMITTED_ALPHABET {
+                Error::ensure_string_valid(Charset::Numeric, value)?;
+                return w.write_permitted_alphabet_string(
+                    Charset::Numeric,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                    value,
+                );
+            }
+
             Error::ensure_string_valid(Charset::Numeric, value)?;
 
             w.write_extensible_bit_and_length_or_err(
@@ -660,6 +1023,18 @@ impl Writer for UperWriter {
     ) -> Result<(), Self::Error> {
         self.write_bit_field_entry(false, true)?;
         self.with_buffer(|w| {
+            if let Some(alphabet) = C::PERMITTED_ALPHABET {
+                Error::ensure_string_valid(Charset::Printable, value)?;
+                return w.write_permitted_alphabet_string(
+                    Charset::Printable,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                    value,
+                );
+            }
+
             Error::ensure_string_valid(Charset::Printable, value)?;
 
             w.write_extensible_bit_and_length_or_err(
@@ -685,6 +1060,18 @@ impl Writer for UperWriter {
     ) -> Result<(), Self::Error> {
         self.write_bit_field_entry(false, true)?;
         self.with_buffer(|w| {
+            if let Some(alphabet) = C::PERMITTED_ALPHABET {
+                Error::ensure_string_valid(Charset::Visible, value)?;
+                return w.write_permitted_alphabet_string(
+                    Charset::Visible,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                    value,
+                );
+            }
+
             Error::ensure_string_valid(Charset::Visible, value)?;
 
             w.write_extensible_bit_and_length_or_err(
@@ -703,6 +1090,85 @@ impl Writer for UperWriter {
         })
     }
 
+    #[inline]
+    fn write_teletex_string<C: teletexstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            w.write_charset_string(Charset::Teletex, C::EXTENSIBLE, C::MIN, C::MAX, value)
+        })
+    }
+
+    #[inline]
+    fn write_graphic_string<C: graphicstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            w.write_charset_string(Charset::Graphic, C::EXTENSIBLE, C::MIN, C::MAX, value)
+        })
+    }
+
+    #[inline]
+    fn write_general_string<C: generalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            w.write_charset_string(Charset::General, C::EXTENSIBLE, C::MIN, C::MAX, value)
+        })
+    }
+
+    #[inline]
+    fn write_universal_string<C: universalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            if let Some(alphabet) = C::PERMITTED_ALPHABET {
+                Error::ensure_string_valid(Charset::Universal, value)?;
+                return w.write_permitted_alphabet_string(
+                    Charset::Universal,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                    value,
+                );
+            }
+
+            w.write_charset_string(Charset::Universal, C::EXTENSIBLE, C::MIN, C::MAX, value)
+        })
+    }
+
+    #[inline]
+    fn write_bmp_string<C: bmpstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            if let Some(alphabet) = C::PERMITTED_ALPHABET {
+                Error::ensure_string_valid(Charset::Bmp, value)?;
+                return w.write_permitted_alphabet_string(
+                    Charset::Bmp,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                    value,
+                );
+            }
+
+            w.write_charset_string(Charset::Bmp, C::EXTENSIBLE, C::MIN, C::MAX, value)
+        })
+    }
+
     #[inline]
     fn write_octet_string<C: octetstring::Constraint>(
         &mut self,
@@ -738,21 +1204,83 @@ impl Writer for UperWriter {
     fn write_null<C: null::Constraint>(&mut self, _value: &Null) -> Result<(), Self::Error> {
         Ok(())
     }
-}
-
-pub struct UperReader<B: ScopedBitRead> {
-    bits: B,
-    scope: Option<Scope>,
-    #[cfg(feature = "descriptive-deserialize-errors")]
-    scope_description: Vec<ScopeDescription>,
-}
 
-/*
-impl<B: ScopedBitRead> From<B> for UperReader<B> {
-    fn from(bits: B) -> Self {
-        UperReader { bits, scope: None }
+    #[inline]
+    fn write_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+        value: &ObjectIdentifier,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        // ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 24
+        // The contents octets of the BER encoding with an unconstrained length determinant
+        self.with_buffer(|w| {
+            w.write_octets_with(true, |w| {
+                let mut valid = Ok(());
+                Octets::write_with(&mut w.bits, |octets| {
+                    valid = crate::io::ber::write_object_identifier(octets, C::TAG, value.arcs())
+                })?;
+                valid.map_err(Error::invalid_object_identifier)
+            })
+        })
     }
-}*/
+
+    #[inline]
+    fn write_relative_oid<C: relativeoid::Constraint>(
+        &mut self,
+        value: &RelativeOid,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        // ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 25
+        self.with_buffer(|w| {
+            w.write_octets_with(true, |w| {
+                Octets::write_with(&mut w.bits, |octets| {
+                    crate::io::ber::write_relative_oid(octets, value.arcs())
+                })
+            })
+        })
+    }
+
+    #[inline]
+    fn write_time<C: time::Constraint, T: time::TimeType>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        // ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 32 and 33
+        // The time types are encoded as if they were an unconstrained VisibleString
+        let value = value.to_string();
+        self.with_buffer(|w| {
+            Error::ensure_string_valid(Charset::Visible, &value)?;
+            w.write_extensible_bit_and_length_or_err(
+                false,
+                None,
+                None,
+                u64::MAX,
+                value.len() as u64,
+            )?;
+
+            for char in value.bytes() {
+                w.bits.write_bits_with_offset(&[char], 1)?;
+            }
+
+            Ok(())
+        })
+    }
+}
+
+pub struct UperReader<B: ScopedBitRead> {
+    pub(crate) bits: B,
+    scope: Option<Scope>,
+    #[cfg(feature = "descriptive-deserialize-errors")]
+    scope_description: Vec<ScopeDescription>,
+}
+
+/*
+impl<B: ScopedBitRead> From<B> for UperReader<B> {
+    fn from(bits: B) -> Self {
+        UperReader { bits, scope: None }
+    }
+}*/
 
 impl<'a, I: Into<Bits<'a>>> From<I> for UperReader<Bits<'a>> {
     fn from(bits: I) -> Self {
@@ -767,7 +1295,7 @@ impl<'a, I: Into<Bits<'a>>> From<I> for UperReader<Bits<'a>> {
 
 impl<B: ScopedBitRead> UperReader<B> {
     #[inline]
-    fn read_length_determinant(
+    pub(crate) fn read_length_determinant(
         &mut self,
         lower_bound: Option<u64>,
         upper_bound: Option<u64>,
@@ -784,8 +1312,60 @@ impl<B: ScopedBitRead> UperReader<B> {
         result
     }
 
+    /// See [`UperWriter::write_charset_string`]
+    pub(crate) fn read_charset_string(
+        &mut self,
+        charset: Charset,
+        extensible: bool,
+        min: Option<u64>,
+        max: Option<u64>,
+    ) -> Result<String, Error> {
+        let content = if let Charset::Bmp | Charset::Universal = charset {
+            let len = if extensible && self.bits.read_bit()? {
+                self.read_length_determinant(None, None)?
+            } else {
+                self.read_length_determinant(min, max)?
+            };
+            let octets_per_char = if charset == Charset::Bmp { 2 } else { 4 };
+            let mut buffer = vec![0u8; len as usize * octets_per_char];
+            self.bits.read_bits(&mut buffer)?;
+            buffer
+        } else {
+            self.bits.read_octetstring(None, None, false)?
+        };
+        let string = crate::io::per::decode_chars(charset, &content)?;
+        Error::ensure_string_valid(charset, &string)?;
+        Ok(string)
+    }
+
+    /// See [`UperWriter::write_permitted_alphabet_string`]
+    pub(crate) fn read_permitted_alphabet_string(
+        &mut self,
+        charset: Charset,
+        alphabet: &[(char, char)],
+        extensible: bool,
+        min: Option<u64>,
+        max: Option<u64>,
+    ) -> Result<String, Error> {
+        let alphabet = EffectiveAlphabet::new(alphabet, false);
+        let len = if extensible && self.bits.read_bit()? {
+            self.read_length_determinant(None, None)?
+        } else {
+            self.read_length_determinant(min, max)?
+        };
+
+        (0..len as usize)
+            .map(|index| {
+                let mut code = [0u8; 4];
+                self.bits
+                    .read_bits_with_offset(&mut code, 32 - alphabet.bits_per_char())?;
+                alphabet.decode(charset, index, u32::from_be_bytes(code))
+            })
+            .collect()
+    }
+
     #[inline]
-    fn read_enumeration_index(
+    pub(crate) fn read_enumeration_index(
         &mut self,
         std_variants: u64,
         extensible: bool,
@@ -813,10 +1393,75 @@ impl<B: ScopedBitRead> UperReader<B> {
         scope: Scope,
         f: F,
     ) -> Result<T, Error> {
-        let original = core::mem::replace(&mut self.scope, Some(scope));
+        UperRead::scope_pushed(self, scope, f)
+    }
+
+    #[inline]
+    pub fn scope_stashed<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<T, Error> {
+        UperRead::scope_stashed(self, f)
+    }
+
+    #[inline]
+    pub fn read_whole_sub_slice<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        length_bytes: usize,
+        f: F,
+    ) -> Result<T, Error> {
+        UperRead::read_whole_sub_slice(self, length_bytes, f)
+    }
+
+    #[inline]
+    pub fn read_bit_field_entry(&mut self, is_opt: bool) -> Result<Option<bool>, Error> {
+        #[allow(clippy::let_and_return)]
+        let result = if let Some(scope) = &mut self.scope {
+            scope.read_from_field(
+                #[cfg(feature = "descriptive-deserialize-errors")]
+                &mut self.scope_description,
+                &mut self.bits,
+                is_opt,
+            )
+        } else if is_opt {
+            Some(self.bits.read_bit()).transpose()
+        } else {
+            Ok(None)
+        };
+
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description
+            .push(ScopeDescription::read_bit_field_entry(is_opt, &result));
+
+        result
+    }
+
+    #[inline]
+    pub fn with_buffer<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<T, Error> {
+        UperRead::with_buffer(self, f)
+    }
+}
+
+/// Reads the constructed values for the [`UperReader`] and for the readers that wrap it, like
+/// the [`TracingReader`]. The components are read through the outermost reader, which is
+/// therefore passed to the closures instead of the [`UperReader`].
+pub(crate) trait UperRead<B: ScopedBitRead>: Reader<Error = Error> + Sized {
+    /// The [`UperReader`] that reads the bits
+    fn uper(&mut self) -> &mut UperReader<B>;
+
+    #[inline]
+    fn scope_pushed<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        scope: Scope,
+        f: F,
+    ) -> Result<T, Error> {
+        let original = core::mem::replace(&mut self.uper().scope, Some(scope));
         let result = f(self);
         if cfg!(debug_assertions) && result.is_ok() {
-            let scope = core::mem::replace(&mut self.scope, original);
+            let scope = core::mem::replace(&mut self.uper().scope, original);
             // call to .unwrap() is save because this is supposed to be the original from above
             debug_assert!(
                 scope.clone().unwrap().exhausted(),
@@ -824,35 +1469,37 @@ impl<B: ScopedBitRead> UperReader<B> {
                 scope.unwrap()
             );
         } else {
-            self.scope = original;
+            self.uper().scope = original;
         }
         result
     }
 
     #[inline]
-    pub fn scope_stashed<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+    fn scope_stashed<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
         &mut self,
         f: F,
     ) -> Result<T, Error> {
-        let scope = self.scope.take();
+        let scope = self.uper().scope.take();
         let result = f(self);
-        self.scope = scope;
+        self.uper().scope = scope;
         result
     }
 
     #[inline]
-    pub fn read_whole_sub_slice<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+    fn read_whole_sub_slice<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
         &mut self,
         length_bytes: usize,
         f: F,
     ) -> Result<T, Error> {
-        let write_position = self.bits.pos() + (length_bytes * BYTE_LEN);
-        let write_original = core::mem::replace(&mut self.bits.len(), write_position);
+        let write_position = self.uper().bits.pos() + (length_bytes * BYTE_LEN);
+        let write_original = core::mem::replace(&mut self.uper().bits.len(), write_position);
         let result = f(self);
+        let reader = self.uper();
         // extend to original position
-        let len = self.bits.set_len(write_original);
+        let len = reader.bits.set_len(write_original);
         #[cfg(feature = "descriptive-deserialize-errors")]
-        self.scope_description
+        reader
+            .scope_description
             .push(ScopeDescription::read_whole_sub_slice(
                 length_bytes,
                 write_position,
@@ -863,90 +1510,65 @@ impl<B: ScopedBitRead> UperReader<B> {
         debug_assert_eq!(write_original, len);
         if result.is_ok() {
             // on successful read, skip the slice
-            self.bits.set_pos(write_position);
+            reader.bits.set_pos(write_position);
         }
         result
     }
 
     #[inline]
-    pub fn read_bit_field_entry(&mut self, is_opt: bool) -> Result<Option<bool>, Error> {
-        #[allow(clippy::let_and_return)]
-        let result = if let Some(scope) = &mut self.scope {
-            scope.read_from_field(
-                #[cfg(feature = "descriptive-deserialize-errors")]
-                &mut self.scope_description,
-                &mut self.bits,
-                is_opt,
-            )
-        } else if is_opt {
-            Some(self.bits.read_bit()).transpose()
-        } else {
-            Ok(None)
-        };
-
-        #[cfg(feature = "descriptive-deserialize-errors")]
-        self.scope_description
-            .push(ScopeDescription::read_bit_field_entry(is_opt, &result));
-
-        result
-    }
-
-    #[inline]
-    pub fn with_buffer<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+    fn with_buffer<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
         &mut self,
         f: F,
     ) -> Result<T, Error> {
         if self
+            .uper()
             .scope
             .as_ref()
             .map(Scope::encode_as_open_type_field)
             .unwrap_or(false)
         {
-            let len = self.read_length_determinant(None, None)?;
+            let len = self.uper().read_length_determinant(None, None)?;
             self.read_whole_sub_slice(len as usize, f)
         } else {
             f(self)
         }
     }
-}
-
-impl<B: ScopedBitRead> Reader for UperReader<B> {
-    type Error = Error;
 
+    /// Reads the outermost value, see [`Reader::read`]
     #[inline]
-    fn read<T: Readable>(&mut self) -> Result<T, Self::Error>
-    where
-        Self: Sized,
-    {
+    fn read_outermost<T: Readable>(&mut self) -> Result<T, Error> {
         #[allow(clippy::let_and_return)]
         let value = T::read(self);
         #[cfg(feature = "descriptive-deserialize-errors")]
         let value = value.map_err(|mut e| {
-            e.0.description = core::mem::take(&mut self.scope_description);
+            e.0.description = core::mem::take(&mut self.uper().scope_description);
             e
         });
         value
     }
 
+    /// Reads the preamble of a SEQUENCE and lets `f` read its fields
     #[inline]
-    fn read_sequence<
+    fn read_sequence_with<
         C: sequence::Constraint,
         S: Sized,
-        F: Fn(&mut Self) -> Result<S, Self::Error>,
+        F: Fn(&mut Self) -> Result<S, Error>,
     >(
         &mut self,
         f: F,
-    ) -> Result<S, Self::Error> {
+    ) -> Result<S, Error> {
         #[cfg(feature = "descriptive-deserialize-errors")]
-        self.scope_description
+        self.uper()
+            .scope_description
             .push(ScopeDescription::sequence::<C>());
 
-        let _ = self.read_bit_field_entry(false);
+        let _ = self.uper().read_bit_field_entry(false);
         #[allow(clippy::let_and_return)]
         let result = self.with_buffer(|r| {
+            let bits = &mut r.uper().bits;
             let extension_after = if let Some(extension_after) = C::EXTENDED_AFTER_FIELD {
-                let bit_pos = r.bits.pos();
-                if r.bits.read_bit()? {
+                let bit_pos = bits.pos();
+                if bits.read_bit()? {
                     Some((extension_after, bit_pos))
                 } else {
                     None
@@ -958,12 +1580,12 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
             // In UPER the values for all OPTIONAL flags are written before any field
             // value is written. This remembers their position, so a later call of `read_opt`
             // can retrieve them from the buffer
-            if r.bits.remaining() < C::STD_OPTIONAL_FIELDS as usize {
+            if bits.remaining() < C::STD_OPTIONAL_FIELDS as usize {
                 return Err(ErrorKind::EndOfStream.into());
             }
 
-            let range = r.bits.pos()..r.bits.pos() + C::STD_OPTIONAL_FIELDS as usize;
-            r.bits.set_pos(range.end); // skip optional
+            let range = bits.pos()..bits.pos() + C::STD_OPTIONAL_FIELDS as usize;
+            bits.set_pos(range.end); // skip optional
 
             if let Some((extension_after, bit_pos)) = extension_after {
                 r.scope_pushed(
@@ -973,8 +1595,15 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
                         opt_bit_field: Some(range),
                         calls_until_ext_bitfield: (extension_after + 1) as usize,
                         number_of_ext_fields: (C::FIELD_COUNT - (extension_after + 1)) as usize,
+                        absent_ext_fields: 0,
+                        unknown_ext_fields: UnknownExtensions::default(),
+                    },
+                    |r| {
+                        let value = f(r)?;
+                        // skip the unknown extended fields, unless already read by `f`
+                        r.read_unknown_extensions()?;
+                        Ok(value)
                     },
-                    f,
                 )
             } else {
                 r.scope_pushed(Scope::OptBitField(range), f)
@@ -982,38 +1611,50 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
         });
 
         #[cfg(feature = "descriptive-deserialize-errors")]
-        self.scope_description.push(ScopeDescription::End(C::NAME));
+        self.uper()
+            .scope_description
+            .push(ScopeDescription::End(C::NAME));
 
         result
     }
 
+    /// Reads the length of a SEQUENCE OF, passes it to `len` and lets `f` read each of its values
     #[inline]
-    fn read_sequence_of<C: sequenceof::Constraint, T: ReadableType>(
+    fn read_sequence_of_with<
+        C: sequenceof::Constraint,
+        T,
+        L: FnOnce(&mut Self, u64),
+        F: Fn(&mut Self) -> Result<T, Error>,
+    >(
         &mut self,
-    ) -> Result<Vec<T::Type>, Self::Error> {
+        len: L,
+        f: F,
+    ) -> Result<Vec<T>, Error> {
         #[cfg(feature = "descriptive-deserialize-errors")]
-        self.scope_description
+        self.uper()
+            .scope_description
             .push(ScopeDescription::sequence_of::<C>());
 
-        let _ = self.read_bit_field_entry(false)?;
-        #[allow(clippy::let_and_return)]
+        let _ = self.uper().read_bit_field_entry(false)?;
         self.with_buffer(|r| {
-            let len = if C::EXTENSIBLE {
-                let extensible = r.bits.read_bit()?;
+            let reader = r.uper();
+            let length = if C::EXTENSIBLE {
+                let extensible = reader.bits.read_bit()?;
                 if extensible {
-                    r.read_length_determinant(None, None)?
+                    reader.read_length_determinant(None, None)?
                 } else {
-                    r.read_length_determinant(C::MIN, C::MAX)?
+                    reader.read_length_determinant(C::MIN, C::MAX)?
                 }
             } else {
-                r.read_length_determinant(C::MIN, C::MAX)?
+                reader.read_length_determinant(C::MIN, C::MAX)?
             };
+            len(r, length);
 
-            if len > 0 {
+            if length > 0 {
                 r.scope_stashed(|r| {
-                    let mut vec = Vec::with_capacity(len as usize);
-                    for _ in 0..len {
-                        vec.push(T::read_value(r)?);
+                    let mut vec = Vec::with_capacity(length as usize);
+                    for _ in 0..length {
+                        vec.push(f(r)?);
                     }
                     Ok(vec)
                 })
@@ -1023,6 +1664,158 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
         })
     }
 
+    /// Reads the index of a CHOICE and lets `f` read the content of the known alternatives
+    #[inline]
+    fn read_choice_with<
+        C: choice::Constraint,
+        F: FnOnce(u64, &mut Self) -> Result<Option<C>, Error>,
+    >(
+        &mut self,
+        f: F,
+    ) -> Result<C, Error> {
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.uper()
+            .scope_description
+            .push(ScopeDescription::choice::<C>());
+
+        let _ = self.uper().read_bit_field_entry(false)?;
+        #[allow(clippy::let_and_return)]
+        let result = self.scope_stashed(|r| {
+            let reader = r.uper();
+            let index = reader
+                .bits
+                .read_choice_index(C::STD_VARIANT_COUNT, C::EXTENSIBLE)?;
+            if index >= C::VARIANT_COUNT {
+                let content = reader.bits.read_octetstring(None, None, false)?;
+                Ok((
+                    index,
+                    C::from_unknown_extension(UnknownExtension::new(index, content)),
+                ))
+            } else if index >= C::STD_VARIANT_COUNT {
+                let length = reader.read_length_determinant(None, None)?;
+                r.read_whole_sub_slice(length as usize, |r| Ok((index, f(index, r)?)))
+            } else {
+                Ok((index, f(index, r)?))
+            }
+            .and_then(|(index, content)| {
+                content.ok_or_else(|| ErrorKind::InvalidChoiceIndex(index, C::VARIANT_COUNT).into())
+            })
+        });
+
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.uper()
+            .scope_description
+            .push(ScopeDescription::End(C::NAME));
+
+        result
+    }
+
+    /// Reads the length of the open type field and lets `f` read its content for the key, unless
+    /// the key is unknown to the object set
+    #[inline]
+    fn read_open_type_with<
+        C: Constraint,
+        F: FnOnce(&mut Self, usize) -> Result<Option<C>, Error>,
+    >(
+        &mut self,
+        key: Option<usize>,
+        f: F,
+    ) -> Result<C, Error> {
+        let key = key.ok_or(ErrorKind::InvalidOpenTypeKey(C::NAME))?;
+        let _ = self.uper().read_bit_field_entry(false)?;
+        self.scope_stashed(|r| {
+            if !C::KEYS.contains(&key) {
+                // keep the content of the open type field for a key unknown to the object set
+                let bytes = r.uper().bits.read_octetstring(None, None, false)?;
+                return C::from_unknown_key(key, bytes).ok_or_else(|| {
+                    ErrorKind::InvalidChoiceIndex(key as u64, C::VARIANT_COUNT).into()
+                });
+            }
+            let length = r.uper().read_length_determinant(None, None)?;
+            // the content is padded to whole octets
+            r.read_whole_sub_slice(length as usize, |r| {
+                f(r, key)?.ok_or_else(|| {
+                    ErrorKind::InvalidChoiceIndex(key as u64, C::VARIANT_COUNT).into()
+                })
+            })
+        })
+    }
+
+    /// Reads the presence flag of an OPTIONAL value and lets `f` read the value, if present
+    #[inline]
+    fn read_opt_with<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<Option<T>, Error> {
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.uper()
+            .scope_description
+            .push(ScopeDescription::optional());
+
+        // unwrap: as opt-field this must and will return some value
+        if self.uper().read_bit_field_entry(true)?.unwrap() {
+            self.with_buffer(|w| w.scope_stashed(f)).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Reads the presence flag of a value with a DEFAULT value and the value, if present
+    #[inline]
+    fn read_default_with<C: default::Constraint<Owned = T::Type>, T: ReadableType>(
+        &mut self,
+    ) -> Result<T::Type, Error> {
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.uper()
+            .scope_description
+            .push(ScopeDescription::default_type());
+
+        // unwrap: as opt-field this must and will return some value
+        if self.uper().read_bit_field_entry(true)?.unwrap() {
+            self.scope_stashed(T::read_value)
+        } else {
+            Ok(C::DEFAULT_VALUE.to_owned())
+        }
+    }
+}
+
+impl<B: ScopedBitRead> UperRead<B> for UperReader<B> {
+    #[inline]
+    fn uper(&mut self) -> &mut UperReader<B> {
+        self
+    }
+}
+
+impl<B: ScopedBitRead> Reader for UperReader<B> {
+    type Error = Error;
+
+    #[inline]
+    fn read<T: Readable>(&mut self) -> Result<T, Self::Error>
+    where
+        Self: Sized,
+    {
+        self.read_outermost()
+    }
+
+    #[inline]
+    fn read_sequence<
+        C: sequence::Constraint,
+        S: Sized,
+        F: Fn(&mut Self) -> Result<S, Self::Error>,
+    >(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        self.read_sequence_with::<C, S, F>(f)
+    }
+
+    #[inline]
+    fn read_sequence_of<C: sequenceof::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<Vec<T::Type>, Self::Error> {
+        self.read_sequence_of_with::<C, _, _, _>(|_, _| {}, T::read_value)
+    }
+
     #[inline]
     fn read_set<C: set::Constraint, S: Sized, F: Fn(&mut Self) -> Result<S, Self::Error>>(
         &mut self,
@@ -1074,84 +1867,74 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
 
     #[inline]
     fn read_choice<C: choice::Constraint>(&mut self) -> Result<C, Self::Error> {
-        #[cfg(feature = "descriptive-deserialize-errors")]
-        self.scope_description.push(ScopeDescription::choice::<C>());
+        self.read_choice_with(C::read_content)
+    }
 
-        let _ = self.read_bit_field_entry(false)?;
-        #[allow(clippy::let_and_return)]
-        let result = self.scope_stashed(|r| {
-            let index = r
-                .bits
-                .read_choice_index(C::STD_VARIANT_COUNT, C::EXTENSIBLE)?;
-            if index >= C::STD_VARIANT_COUNT {
-                let length = r.read_length_determinant(None, None)?;
-                r.read_whole_sub_slice(length as usize, |r| Ok((index, C::read_content(index, r)?)))
-            } else {
-                Ok((index, C::read_content(index, r)?))
-            }
-            .and_then(|(index, content)| {
-                content.ok_or_else(|| ErrorKind::InvalidChoiceIndex(index, C::VARIANT_COUNT).into())
-            })
-        });
+    #[inline]
+    fn read_unknown_extensions(&mut self) -> Result<UnknownExtensions, Self::Error> {
+        if let Some(
+            scope @ Scope::ExtensibleSequence {
+                calls_until_ext_bitfield: 0,
+                ..
+            },
+        ) = &mut self.scope
+        {
+            // none of the known extended fields caused the secondary-header to be read
+            scope.read_extension_header(
+                #[cfg(feature = "descriptive-deserialize-errors")]
+                &mut self.scope_description,
+                &mut self.bits,
+            )?;
+        }
 
-        #[cfg(feature = "descriptive-deserialize-errors")]
-        self.scope_description.push(ScopeDescription::End(C::NAME));
+        let flags = match &mut self.scope {
+            Some(Scope::AllBitField(range)) => core::mem::replace(range, range.end..range.end),
+            _ => return Ok(UnknownExtensions::default()),
+        };
 
-        result
-    }
+        let mut present = Vec::with_capacity(flags.len());
+        for pos in flags {
+            present.push(self.bits.with_read_position_at(pos, |b| b.read_bit())?);
+        }
 
+        let mut unknown = UnknownExtensions::default();
+        for present in present {
+            unknown.push(if present {
+                Some(self.bits.read_octetstring(None, None, false)?)
+            } else {
+                None
+            });
+        }
+        Ok(unknown)
+    }
 
     #[inline]
-    fn read_open_type<C: Constraint>(&mut self,key:usize) -> Result<C, Self::Error> {
-        // #[cfg(feature = "descriptive-deserialize-errors")]
-        // self.scope_description.push(ScopeDescription::choice::<C>());
-
-        let _ = self.read_bit_field_entry(false)?;
-        #[allow(clippy::let_and_return)]
-        let result = self.scope_stashed(|r| {
-            let _length = r.read_length_determinant(None, None)?;
-            let x=C::read_content(key, r);
-            x.and_then(|x|{
-                x.ok_or_else(|| ErrorKind::InvalidChoiceIndex(key as u64, C::VARIANT_COUNT).into())
-            })
-        });
-
-        // #[cfg(feature = "descriptive-deserialize-errors")]
-        // self.scope_description.push(ScopeDescription::End(C::NAME));
+    fn read_open_type<C: Constraint>(&mut self, key: Option<usize>) -> Result<C, Self::Error> {
+        self.read_open_type_with(key, |r, key| C::read_content(key, r))
+    }
 
-        result
+    #[inline]
+    fn read_present<T: ReadableType>(
+        &mut self,
+        field: &'static str,
+    ) -> Result<T::Type, Self::Error> {
+        self.read_opt::<T>()?.ok_or_else(|| {
+            ErrorKind::ComponentConstraintViolated(field, ViolationKind::ComponentNotPresent).into()
+        })
     }
 
     #[inline]
     fn read_opt<T: ReadableType>(
         &mut self,
     ) -> Result<Option<<T as ReadableType>::Type>, Self::Error> {
-        #[cfg(feature = "descriptive-deserialize-errors")]
-        self.scope_description.push(ScopeDescription::optional());
-
-        // unwrap: as opt-field this must and will return some value
-        if self.read_bit_field_entry(true)?.unwrap() {
-            self.with_buffer(|w| w.scope_stashed(T::read_value))
-                .map(Some)
-        } else {
-            Ok(None)
-        }
+        self.read_opt_with(T::read_value)
     }
 
     #[inline]
     fn read_default<C: default::Constraint<Owned = T::Type>, T: ReadableType>(
         &mut self,
     ) -> Result<T::Type, Self::Error> {
-        #[cfg(feature = "descriptive-deserialize-errors")]
-        self.scope_description
-            .push(ScopeDescription::default_type());
-
-        // unwrap: as opt-field this must and will return some value
-        if self.read_bit_field_entry(true)?.unwrap() {
-            self.scope_stashed(T::read_value)
-        } else {
-            Ok(C::DEFAULT_VALUE.to_owned())
-        }
+        self.read_default_with::<C, T>()
     }
 
     #[inline]
@@ -1192,6 +1975,29 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
         })
     }
 
+    #[inline]
+    fn read_real<C: real::Constraint>(&mut self) -> Result<f64, Self::Error> {
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description.push(ScopeDescription::real::<C>());
+
+        let _ = self.read_bit_field_entry(false)?;
+        #[allow(clippy::let_and_return)]
+        let result = self.with_buffer(|r| {
+            let content = r.bits.read_octetstring(None, None, false)?;
+            crate::io::ber::read_real(C::TAG, &content).map_err(Error::invalid_real)
+        });
+
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description.push(ScopeDescription::Result(
+            result
+                .as_ref()
+                .map(ToString::to_string)
+                .map_err(|e| e.clone()),
+        ));
+
+        result
+    }
+
     #[inline]
     fn read_utf8string<C: utf8string::Constraint>(&mut self) -> Result<String, Self::Error> {
         #[cfg(feature = "descriptive-deserialize-errors")]
@@ -1223,6 +2029,16 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
         let _ = self.read_bit_field_entry(false)?;
         #[allow(clippy::let_and_return)]
         let result = self.with_buffer(|r| {
+            if let Some(alphabet) = C::PERMITTED_ALPHABET {
+                return r.read_permitted_alphabet_string(
+                    Charset::Ia5,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                );
+            }
+
             let len = if C::EXTENSIBLE && r.bits.read_bit()? {
                 r.read_length_determinant(None, None)?
             } else {
@@ -1253,6 +2069,16 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
         let _ = self.read_bit_field_entry(false)?;
         #[allow(clippy::let_and_return)]
         let result = self.with_buffer(|r| {
+            if let Some(alphabet) = C::PERMITTED_ALPHABET {
+                return r.read_permitted_alphabet_string(
+                    Charset::Numeric,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                );
+            }
+
             let len = if C::EXTENSIBLE && r.bits.read_bit()? {
                 r.read_length_determinant(None, None)?
             } else {
@@ -1279,28 +2105,166 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
     }
 
     #[inline]
-    fn read_printable_string<C: printablestring::Constraint>(
+    fn read_printable_string<C: printablestring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description
+            .push(ScopeDescription::printable_string::<C>());
+
+        let _ = self.read_bit_field_entry(false)?;
+        #[allow(clippy::let_and_return)]
+        let result = self.with_buffer(|r| {
+            if let Some(alphabet) = C::PERMITTED_ALPHABET {
+                return r.read_permitted_alphabet_string(
+                    Charset::Printable,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                );
+            }
+
+            let len = if C::EXTENSIBLE && r.bits.read_bit()? {
+                r.read_length_determinant(None, None)?
+            } else {
+                r.read_length_determinant(C::MIN, C::MAX)?
+            };
+
+            let mut buffer = vec![0u8; len as usize];
+            buffer
+                .chunks_exact_mut(1)
+                .try_for_each(|chunk| r.bits.read_bits_with_offset(chunk, 1))?;
+
+            String::from_utf8(buffer).map_err(|e| ErrorKind::FromUtf8Error(e).into())
+        });
+
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description
+            .push(ScopeDescription::Result(result.clone()));
+
+        result
+    }
+
+    #[inline]
+    fn read_visible_string<C: visiblestring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description
+            .push(ScopeDescription::visible_string::<C>());
+
+        let _ = self.read_bit_field_entry(false)?;
+        #[allow(clippy::let_and_return)]
+        let result = self.with_buffer(|r| {
+            if let Some(alphabet) = C::PERMITTED_ALPHABET {
+                return r.read_permitted_alphabet_string(
+                    Charset::Visible,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                );
+            }
+
+            let len = if C::EXTENSIBLE && r.bits.read_bit()? {
+                r.read_length_determinant(None, None)?
+            } else {
+                r.read_length_determinant(C::MIN, C::MAX)?
+            };
+
+            let mut buffer = vec![0u8; len as usize];
+            buffer
+                .chunks_exact_mut(1)
+                .try_for_each(|chunk| r.bits.read_bits_with_offset(chunk, 1))?;
+
+            String::from_utf8(buffer).map_err(|e| ErrorKind::FromUtf8Error(e).into())
+        });
+
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description
+            .push(ScopeDescription::Result(result.clone()));
+
+        result
+    }
+
+    #[inline]
+    fn read_teletex_string<C: teletexstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description
+            .push(ScopeDescription::teletex_string::<C>());
+
+        let _ = self.read_bit_field_entry(false)?;
+        #[allow(clippy::let_and_return)]
+        let result = self.with_buffer(|r| {
+            r.read_charset_string(Charset::Teletex, C::EXTENSIBLE, C::MIN, C::MAX)
+        });
+
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description
+            .push(ScopeDescription::Result(result.clone()));
+
+        result
+    }
+
+    #[inline]
+    fn read_graphic_string<C: graphicstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description
+            .push(ScopeDescription::graphic_string::<C>());
+
+        let _ = self.read_bit_field_entry(false)?;
+        #[allow(clippy::let_and_return)]
+        let result = self.with_buffer(|r| {
+            r.read_charset_string(Charset::Graphic, C::EXTENSIBLE, C::MIN, C::MAX)
+        });
+
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description
+            .push(ScopeDescription::Result(result.clone()));
+
+        result
+    }
+
+    #[inline]
+    fn read_general_string<C: generalstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description
+            .push(ScopeDescription::general_string::<C>());
+
+        let _ = self.read_bit_field_entry(false)?;
+        #[allow(clippy::let_and_return)]
+        let result = self.with_buffer(|r| {
+            r.read_charset_string(Charset::General, C::EXTENSIBLE, C::MIN, C::MAX)
+        });
+
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description
+            .push(ScopeDescription::Result(result.clone()));
+
+        result
+    }
+
+    #[inline]
+    fn read_universal_string<C: universalstring::Constraint>(
         &mut self,
     ) -> Result<String, Self::Error> {
         #[cfg(feature = "descriptive-deserialize-errors")]
         self.scope_description
-            .push(ScopeDescription::printable_string::<C>());
+            .push(ScopeDescription::universal_string::<C>());
 
         let _ = self.read_bit_field_entry(false)?;
         #[allow(clippy::let_and_return)]
         let result = self.with_buffer(|r| {
-            let len = if C::EXTENSIBLE && r.bits.read_bit()? {
-                r.read_length_determinant(None, None)?
-            } else {
-                r.read_length_determinant(C::MIN, C::MAX)?
-            };
-
-            let mut buffer = vec![0u8; len as usize];
-            buffer
-                .chunks_exact_mut(1)
-                .try_for_each(|chunk| r.bits.read_bits_with_offset(chunk, 1))?;
+            if let Some(alphabet) = C::PERMITTED_ALPHABET {
+                return r.read_permitted_alphabet_string(
+                    Charset::Universal,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                );
+            }
 
-            String::from_utf8(buffer).map_err(|e| ErrorKind::FromUtf8Error(e).into())
+            r.read_charset_string(Charset::Universal, C::EXTENSIBLE, C::MIN, C::MAX)
         });
 
         #[cfg(feature = "descriptive-deserialize-errors")]
@@ -1311,26 +2275,25 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
     }
 
     #[inline]
-    fn read_visible_string<C: visiblestring::Constraint>(&mut self) -> Result<String, Self::Error> {
+    fn read_bmp_string<C: bmpstring::Constraint>(&mut self) -> Result<String, Self::Error> {
         #[cfg(feature = "descriptive-deserialize-errors")]
         self.scope_description
-            .push(ScopeDescription::visible_string::<C>());
+            .push(ScopeDescription::bmp_string::<C>());
 
         let _ = self.read_bit_field_entry(false)?;
         #[allow(clippy::let_and_return)]
         let result = self.with_buffer(|r| {
-            let len = if C::EXTENSIBLE && r.bits.read_bit()? {
-                r.read_length_determinant(None, None)?
-            } else {
-                r.read_length_determinant(C::MIN, C::MAX)?
-            };
-
-            let mut buffer = vec![0u8; len as usize];
-            buffer
-                .chunks_exact_mut(1)
-                .try_for_each(|chunk| r.bits.read_bits_with_offset(chunk, 1))?;
+            if let Some(alphabet) = C::PERMITTED_ALPHABET {
+                return r.read_permitted_alphabet_string(
+                    Charset::Bmp,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                );
+            }
 
-            String::from_utf8(buffer).map_err(|e| ErrorKind::FromUtf8Error(e).into())
+            r.read_charset_string(Charset::Bmp, C::EXTENSIBLE, C::MIN, C::MAX)
         });
 
         #[cfg(feature = "descriptive-deserialize-errors")]
@@ -1420,6 +2383,202 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
     fn read_null<C: null::Constraint>(&mut self) -> Result<Null, Self::Error> {
         Ok(Null)
     }
+
+    #[inline]
+    fn read_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+    ) -> Result<ObjectIdentifier, Self::Error> {
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description
+            .push(ScopeDescription::object_identifier::<C>());
+
+        let _ = self.read_bit_field_entry(false)?;
+        #[allow(clippy::let_and_return)]
+        let result = self.with_buffer(|r| {
+            let content = r.bits.read_octetstring(None, None, false)?;
+            crate::io::ber::read_object_identifier(C::TAG, &content)
+                .map(ObjectIdentifier::new)
+                .map_err(Error::invalid_object_identifier)
+        });
+
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description.push(ScopeDescription::Result(
+            result
+                .as_ref()
+                .map(ToString::to_string)
+                .map_err(|e| e.clone()),
+        ));
+
+        result
+    }
+
+    #[inline]
+    fn read_relative_oid<C: relativeoid::Constraint>(
+        &mut self,
+    ) -> Result<RelativeOid, Self::Error> {
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description
+            .push(ScopeDescription::relative_oid::<C>());
+
+        let _ = self.read_bit_field_entry(false)?;
+        #[allow(clippy::let_and_return)]
+        let result = self.with_buffer(|r| {
+            let content = r.bits.read_octetstring(None, None, false)?;
+            crate::io::ber::read_relative_oid(C::TAG, &content)
+                .map(RelativeOid::new)
+                .map_err(Error::invalid_object_identifier)
+        });
+
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description.push(ScopeDescription::Result(
+            result
+                .as_ref()
+                .map(ToString::to_string)
+                .map_err(|e| e.clone()),
+        ));
+
+        result
+    }
+
+    #[inline]
+    fn read_time<C: time::Constraint, T: time::TimeType>(&mut self) -> Result<T, Self::Error> {
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description.push(ScopeDescription::time::<C>());
+
+        let _ = self.read_bit_field_entry(false)?;
+        #[allow(clippy::let_and_return)]
+        let result = self.with_buffer(|r| {
+            let len = r.read_length_determinant(None, None)?;
+            let mut buffer = vec![0u8; len as usize];
+            buffer
+                .chunks_exact_mut(1)
+                .try_for_each(|chunk| r.bits.read_bits_with_offset(chunk, 1))?;
+
+            String::from_utf8(buffer)
+                .map_err(|e| Error::from(ErrorKind::FromUtf8Error(e)))?
+                .parse::<T>()
+                .map_err(|e| ErrorKind::InvalidTime(e).into())
+        });
+
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description.push(ScopeDescription::Result(
+            result
+                .as_ref()
+                .map(ToString::to_string)
+                .map_err(|e| e.clone()),
+        ));
+
+        result
+    }
+}
+
+impl<'a> BorrowedReader<'a> for UperReader<Bits<'a>> {
+    #[inline]
+    fn read_sequence_of_borrowed<C: sequenceof::Constraint, T: BorrowedReadableType<'a>>(
+        &mut self,
+    ) -> Result<Vec<T::Type>, Self::Error> {
+        self.read_sequence_of_with::<C, _, _, _>(|_, _| {}, T::read_value_borrowed)
+    }
+
+    #[inline]
+    fn read_set_of_borrowed<C: setof::Constraint, T: BorrowedReadableType<'a>>(
+        &mut self,
+    ) -> Result<Vec<T::Type>, Self::Error> {
+        self.read_sequence_of_borrowed::<C, T>()
+    }
+
+    #[inline]
+    fn read_choice_borrowed<
+        C: choice::Constraint,
+        F: FnOnce(u64, &mut Self) -> Result<Option<C>, Self::Error>,
+    >(
+        &mut self,
+        f: F,
+    ) -> Result<C, Self::Error> {
+        self.read_choice_with(f)
+    }
+
+    #[inline]
+    fn read_opt_borrowed<T: BorrowedReadableType<'a>>(
+        &mut self,
+    ) -> Result<Option<T::Type>, Self::Error> {
+        self.read_opt_with(T::read_value_borrowed)
+    }
+
+    #[inline]
+    fn read_present_borrowed<T: BorrowedReadableType<'a>>(
+        &mut self,
+        field: &'static str,
+    ) -> Result<T::Type, Self::Error> {
+        self.read_opt_borrowed::<T>()?.ok_or_else(|| {
+            ErrorKind::ComponentConstraintViolated(field, ViolationKind::ComponentNotPresent).into()
+        })
+    }
+
+    #[inline]
+    fn read_utf8string_borrowed<C: utf8string::Constraint>(
+        &mut self,
+    ) -> Result<Cow<'a, str>, Self::Error> {
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description
+            .push(ScopeDescription::utf8string::<C>());
+
+        let _ = self.read_bit_field_entry(false)?;
+        #[allow(clippy::let_and_return)]
+        let result = self.with_buffer(|r| {
+            // ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 30.3
+            // For 'known-multiplier character string types' there is no min/max in the encoding
+            match r.bits.read_octetstring_borrowed(None, None, false)? {
+                Cow::Borrowed(octets) => core::str::from_utf8(octets)
+                    .map(Cow::Borrowed)
+                    .map_err(|e| ErrorKind::Utf8Error(e).into()),
+                Cow::Owned(octets) => String::from_utf8(octets)
+                    .map(Cow::Owned)
+                    .map_err(|e| ErrorKind::FromUtf8Error(e).into()),
+            }
+        });
+
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description.push(ScopeDescription::Result(
+            result
+                .as_ref()
+                .map(ToString::to_string)
+                .map_err(Error::clone),
+        ));
+
+        result
+    }
+
+    #[inline]
+    fn read_octet_string_borrowed<C: octetstring::Constraint>(
+        &mut self,
+    ) -> Result<Cow<'a, [u8]>, Self::Error> {
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description
+            .push(ScopeDescription::octet_string::<C>());
+
+        let _ = self.read_bit_field_entry(false)?;
+        #[allow(clippy::let_and_return)]
+        let result = self.with_buffer(|r| {
+            r.bits
+                .read_octetstring_borrowed(C::MIN, C::MAX, C::EXTENSIBLE)
+        });
+
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description.push(ScopeDescription::Result(
+            result
+                .as_ref()
+                .map(|s| {
+                    s.iter()
+                        .map(|v| format!("{v:02x}"))
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                })
+                .map_err(|e| e.clone()),
+        ));
+
+        result
+    }
 }
 
 pub trait UperDecodable<'a, I: Into<Bits<'a>> + 'a> {
@@ -1480,6 +2639,18 @@ pub enum ScopeDescription {
         max: Option<i64>,
         extensible: bool,
     },
+    Real {
+        tag: asn1rs_model::model::Tag,
+    },
+    ObjectIdentifier {
+        tag: asn1rs_model::model::Tag,
+    },
+    RelativeOid {
+        tag: asn1rs_model::model::Tag,
+    },
+    Time {
+        tag: asn1rs_model::model::Tag,
+    },
     Utf8String {
         tag: asn1rs_model::model::Tag,
         min: Option<u64>,
@@ -1510,6 +2681,36 @@ pub enum ScopeDescription {
         max: Option<u64>,
         extensible: bool,
     },
+    TeletexString {
+        tag: asn1rs_model::model::Tag,
+        min: Option<u64>,
+        max: Option<u64>,
+        extensible: bool,
+    },
+    GraphicString {
+        tag: asn1rs_model::model::Tag,
+        min: Option<u64>,
+        max: Option<u64>,
+        extensible: bool,
+    },
+    GeneralString {
+        tag: asn1rs_model::model::Tag,
+        min: Option<u64>,
+        max: Option<u64>,
+        extensible: bool,
+    },
+    UniversalString {
+        tag: asn1rs_model::model::Tag,
+        min: Option<u64>,
+        max: Option<u64>,
+        extensible: bool,
+    },
+    BmpString {
+        tag: asn1rs_model::model::Tag,
+        min: Option<u64>,
+        max: Option<u64>,
+        extensible: bool,
+    },
     OctetString {
         tag: asn1rs_model::model::Tag,
         min: Option<u64>,
@@ -1621,6 +2822,26 @@ mod scope_description_impl {
             }
         }
 
+        #[inline]
+        pub fn real<C: real::Constraint>() -> Self {
+            Self::Real { tag: C::TAG }
+        }
+
+        #[inline]
+        pub fn object_identifier<C: objectidentifier::Constraint>() -> Self {
+            Self::ObjectIdentifier { tag: C::TAG }
+        }
+
+        #[inline]
+        pub fn relative_oid<C: relativeoid::Constraint>() -> Self {
+            Self::RelativeOid { tag: C::TAG }
+        }
+
+        #[inline]
+        pub fn time<C: time::Constraint>() -> Self {
+            Self::Time { tag: C::TAG }
+        }
+
         #[inline]
         pub fn utf8string<C: utf8string::Constraint>() -> Self {
             Self::Utf8String {
@@ -1671,6 +2892,56 @@ mod scope_description_impl {
             }
         }
 
+        #[inline]
+        pub fn teletex_string<C: teletexstring::Constraint>() -> Self {
+            Self::TeletexString {
+                tag: C::TAG,
+                min: C::MIN,
+                max: C::MAX,
+                extensible: C::EXTENSIBLE,
+            }
+        }
+
+        #[inline]
+        pub fn graphic_string<C: graphicstring::Constraint>() -> Self {
+            Self::GraphicString {
+                tag: C::TAG,
+                min: C::MIN,
+                max: C::MAX,
+                extensible: C::EXTENSIBLE,
+            }
+        }
+
+        #[inline]
+        pub fn general_string<C: generalstring::Constraint>() -> Self {
+            Self::GeneralString {
+                tag: C::TAG,
+                min: C::MIN,
+                max: C::MAX,
+                extensible: C::EXTENSIBLE,
+            }
+        }
+
+        #[inline]
+        pub fn universal_string<C: universalstring::Constraint>() -> Self {
+            Self::UniversalString {
+                tag: C::TAG,
+                min: C::MIN,
+                max: C::MAX,
+                extensible: C::EXTENSIBLE,
+            }
+        }
+
+        #[inline]
+        pub fn bmp_string<C: bmpstring::Constraint>() -> Self {
+            Self::BmpString {
+                tag: C::TAG,
+                min: C::MIN,
+                max: C::MAX,
+                extensible: C::EXTENSIBLE,
+            }
+        }
+
         #[inline]
         pub fn octet_string<C: octetstring::Constraint>() -> Self {
             Self::OctetString {
@@ -0,0 +1,531 @@
+use crate::model::{Charset, PresenceConstraint};
+use crate::syn::validate::{Violation, ViolationKind, Violations};
+use crate::syn::*;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Checks the constraints of the written values instead of encoding them and collects all
+/// violations, see [`Validate`].
+///
+/// Checked are the ranges of INTEGER values, the sizes of strings, OCTET STRING, BIT STRING,
+/// SEQUENCE OF and SET OF values, the characters of strings against their character set and
+/// permitted alphabet, as well as the `WITH COMPONENTS` presence constraints of components.
+/// Extensible ranges and sizes are not checked, because every value is permitted for them.
+/// Components are identified by the `IDENTIFIER` of their constraints.
+#[derive(Default)]
+pub struct ValidationWriter {
+    path: String,
+    identifier: Option<&'static str>,
+    violations: Vec<Violation>,
+}
+
+impl ValidationWriter {
+    /// Validates the given value and all of its components
+    pub fn validate<T: Writable>(value: &T) -> Result<(), Violations> {
+        let mut writer = Self::default();
+        match value.write(&mut writer) {
+            Ok(()) => writer.into_result(),
+            Err(infallible) => match infallible {},
+        }
+    }
+
+    pub fn violations(&self) -> &[Violation] {
+        &self.violations[..]
+    }
+
+    pub fn into_result(self) -> Result<(), Violations> {
+        if self.violations.is_empty() {
+            Ok(())
+        } else {
+            Err(Violations(self.violations))
+        }
+    }
+
+    /// The identifier of the next value. The identifier of the referencing field takes
+    /// precedence over the given identifier.
+    fn take_identifier(&mut self, identifier: Option<&'static str>) -> Option<&'static str> {
+        self.identifier.take().or(identifier)
+    }
+
+    fn with_segment<R, F: FnOnce(&mut Self) -> R>(&mut self, segment: Option<&str>, f: F) -> R {
+        let len = self.path.len();
+        if let Some(segment) = segment {
+            if !self.path.is_empty() && !segment.starts_with('[') {
+                self.path.push('.');
+            }
+            self.path.push_str(segment);
+        }
+        let result = f(self);
+        self.path.truncate(len);
+        result
+    }
+
+    fn add_violation(&mut self, identifier: Option<&str>, kind: ViolationKind) {
+        let violation = self.with_segment(identifier, |w| Violation {
+            path: w.path.clone(),
+            kind,
+        });
+        self.violations.push(violation);
+    }
+
+    fn check_size(
+        &mut self,
+        identifier: Option<&str>,
+        size: u64,
+        min: Option<u64>,
+        max: Option<u64>,
+        extensible: bool,
+    ) {
+        if !extensible && (min.is_some_and(|min| size < min) || max.is_some_and(|max| size > max)) {
+            self.add_violation(
+                identifier,
+                ViolationKind::SizeNotInRange(size, min.unwrap_or(0), max.unwrap_or(u64::MAX)),
+            );
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn check_string(
+        &mut self,
+        identifier: Option<&'static str>,
+        charset: Charset,
+        value: &str,
+        min: Option<u64>,
+        max: Option<u64>,
+        extensible: bool,
+        alphabet: Option<&'static [(char, char)]>,
+    ) {
+        let identifier = self.take_identifier(identifier);
+        self.check_size(
+            identifier,
+            value.chars().count() as u64,
+            min,
+            max,
+            extensible,
+        );
+
+        if let Some((index, char)) = charset.find_invalid(value) {
+            self.add_violation(
+                identifier,
+                ViolationKind::InvalidString(charset, char, index),
+            );
+        } else if let Some(alphabet) = alphabet {
+            if let Some((index, char)) = value.chars().enumerate().find(|(_, char)| {
+                !alphabet
+                    .iter()
+                    .any(|(start, end)| start <= char && char <= end)
+            }) {
+                self.add_violation(
+                    identifier,
+                    ViolationKind::CharacterNotPermitted(char, index),
+                );
+            }
+        }
+    }
+}
+
+impl Writer for ValidationWriter {
+    type Error = core::convert::Infallible;
+
+    #[inline]
+    fn write_sequence<C: sequence::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        let identifier = self.take_identifier(C::IDENTIFIER);
+        self.with_segment(identifier, f)
+    }
+
+    #[inline]
+    fn write_sequence_of<C: sequenceof::Constraint, T: WritableType>(
+        &mut self,
+        slice: &[T::Type],
+    ) -> Result<(), Self::Error> {
+        let identifier = self.take_identifier(C::IDENTIFIER);
+        self.check_size(
+            identifier,
+            slice.len() as u64,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        );
+        self.with_segment(identifier, |w| {
+            for (index, value) in slice.iter().enumerate() {
+                w.with_segment(Some(&format!("[{}]", index)), |w| T::write_value(w, value))?;
+            }
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_set<C: set::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        let identifier = self.take_identifier(C::IDENTIFIER);
+        self.with_segment(identifier, f)
+    }
+
+    #[inline]
+    fn write_set_of<C: setof::Constraint, T: WritableType>(
+        &mut self,
+        slice: &[T::Type],
+    ) -> Result<(), Self::Error> {
+        let identifier = self.take_identifier(C::IDENTIFIER);
+        self.check_size(
+            identifier,
+            slice.len() as u64,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        );
+        self.with_segment(identifier, |w| {
+            for (index, value) in slice.iter().enumerate() {
+                w.with_segment(Some(&format!("[{}]", index)), |w| T::write_value(w, value))?;
+            }
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_enumerated<C: enumerated::Constraint>(
+        &mut self,
+        _enumerated: &C,
+    ) -> Result<(), Self::Error> {
+        self.identifier = None;
+        Ok(())
+    }
+
+    #[inline]
+    fn write_complex<C: complex::Constraint, F: FnOnce(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        if let Some(identifier) = C::IDENTIFIER {
+            // the outermost identifier wins if the referenced type is just another reference
+            self.identifier.get_or_insert(identifier);
+        }
+        let result = f(self);
+        self.identifier = None;
+        result
+    }
+
+    #[inline]
+    fn check_presence(
+        &mut self,
+        field: &'static str,
+        constraint: PresenceConstraint,
+        present: bool,
+    ) -> Result<(), Self::Error> {
+        if let Some(kind) = ViolationKind::of_presence(constraint, present) {
+            self.add_violation(Some(field), kind);
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn check_value(&mut self, field: &'static str, permitted: bool) -> Result<(), Self::Error> {
+        if !permitted {
+            self.add_violation(Some(field), ViolationKind::ValueNotPermitted);
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn write_choice<C: choice::Constraint>(&mut self, choice: &C) -> Result<(), Self::Error> {
+        let identifier = self.take_identifier(C::IDENTIFIER);
+        self.with_segment(identifier, |w| choice.write_content(w))
+    }
+
+    #[inline]
+    fn write_open_type<C: opentype::Constraint>(
+        &mut self,
+        opentype: &C,
+    ) -> Result<(), Self::Error> {
+        opentype.write_content(self)
+    }
+
+    #[inline]
+    fn write_opt<T: WritableType>(&mut self, value: Option<&T::Type>) -> Result<(), Self::Error> {
+        match value {
+            Some(value) => T::write_value(self, value),
+            None => {
+                self.identifier = None;
+                Ok(())
+            }
+        }
+    }
+
+    #[inline]
+    fn write_default<C: default::Constraint<Owned = T::Type>, T: WritableType>(
+        &mut self,
+        value: &T::Type,
+    ) -> Result<(), Self::Error> {
+        T::write_value(self, value)
+    }
+
+    #[inline]
+    fn write_number<T: numbers::Number, C: numbers::Constraint<T>>(
+        &mut self,
+        value: T,
+    ) -> Result<(), Self::Error> {
+        let identifier = self.take_identifier(C::IDENTIFIER);
+        let value = value.to_i64();
+        if !C::EXTENSIBLE
+            && (C::MIN.is_some_and(|min| value < min) || C::MAX.is_some_and(|max| value > max))
+        {
+            self.add_violation(
+                identifier,
+                ViolationKind::ValueNotInRange(
+                    value,
+                    C::MIN.unwrap_or(i64::MIN),
+                    C::MAX.unwrap_or(i64::MAX),
+                ),
+            );
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn write_real<C: real::Constraint>(&mut self, _value: f64) -> Result<(), Self::Error> {
+        self.identifier = None;
+        Ok(())
+    }
+
+    #[inline]
+    fn write_utf8string<C: utf8string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.check_string(
+            C::IDENTIFIER,
+            Charset::Utf8,
+            value,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            C::PERMITTED_ALPHABET,
+        );
+        Ok(())
+    }
+
+    #[inline]
+    fn write_ia5string<C: ia5string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.check_string(
+            C::IDENTIFIER,
+            Charset::Ia5,
+            value,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            C::PERMITTED_ALPHABET,
+        );
+        Ok(())
+    }
+
+    #[inline]
+    fn write_numeric_string<C: numericstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.check_string(
+            C::IDENTIFIER,
+            Charset::Numeric,
+            value,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            C::PERMITTED_ALPHABET,
+        );
+        Ok(())
+    }
+
+    #[inline]
+    fn write_visible_string<C: visiblestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.check_string(
+            C::IDENTIFIER,
+            Charset::Visible,
+            value,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            C::PERMITTED_ALPHABET,
+        );
+        Ok(())
+    }
+
+    #[inline]
+    fn write_printable_string<C: printablestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.check_string(
+            C::IDENTIFIER,
+            Charset::Printable,
+            value,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            C::PERMITTED_ALPHABET,
+        );
+        Ok(())
+    }
+
+    #[inline]
+    fn write_teletex_string<C: teletexstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.check_string(
+            C::IDENTIFIER,
+            Charset::Teletex,
+            value,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            C::PERMITTED_ALPHABET,
+        );
+        Ok(())
+    }
+
+    #[inline]
+    fn write_graphic_string<C: graphicstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.check_string(
+            C::IDENTIFIER,
+            Charset::Graphic,
+            value,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            C::PERMITTED_ALPHABET,
+        );
+        Ok(())
+    }
+
+    #[inline]
+    fn write_general_string<C: generalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.check_string(
+            C::IDENTIFIER,
+            Charset::General,
+            value,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            C::PERMITTED_ALPHABET,
+        );
+        Ok(())
+    }
+
+    #[inline]
+    fn write_universal_string<C: universalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.check_string(
+            C::IDENTIFIER,
+            Charset::Universal,
+            value,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            C::PERMITTED_ALPHABET,
+        );
+        Ok(())
+    }
+
+    #[inline]
+    fn write_bmp_string<C: bmpstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.check_string(
+            C::IDENTIFIER,
+            Charset::Bmp,
+            value,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            C::PERMITTED_ALPHABET,
+        );
+        Ok(())
+    }
+
+    #[inline]
+    fn write_octet_string<C: octetstring::Constraint>(
+        &mut self,
+        value: &[u8],
+    ) -> Result<(), Self::Error> {
+        let identifier = self.take_identifier(C::IDENTIFIER);
+        self.check_size(
+            identifier,
+            value.len() as u64,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        );
+        Ok(())
+    }
+
+    #[inline]
+    fn write_bit_string<C: bitstring::Constraint>(
+        &mut self,
+        _value: &[u8],
+        bit_len: u64,
+    ) -> Result<(), Self::Error> {
+        let identifier = self.take_identifier(C::IDENTIFIER);
+        self.check_size(identifier, bit_len, C::MIN, C::MAX, C::EXTENSIBLE);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_boolean<C: boolean::Constraint>(&mut self, _value: bool) -> Result<(), Self::Error> {
+        self.identifier = None;
+        Ok(())
+    }
+
+    #[inline]
+    fn write_null<C: null::Constraint>(&mut self, _value: &Null) -> Result<(), Self::Error> {
+        self.identifier = None;
+        Ok(())
+    }
+
+    #[inline]
+    fn write_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+        _value: &ObjectIdentifier,
+    ) -> Result<(), Self::Error> {
+        self.identifier = None;
+        Ok(())
+    }
+
+    #[inline]
+    fn write_relative_oid<C: relativeoid::Constraint>(
+        &mut self,
+        _value: &RelativeOid,
+    ) -> Result<(), Self::Error> {
+        self.identifier = None;
+        Ok(())
+    }
+
+    #[inline]
+    fn write_time<C: time::Constraint, T: time::TimeType>(
+        &mut self,
+        _value: &T,
+    ) -> Result<(), Self::Error> {
+        self.identifier = None;
+        Ok(())
+    }
+}
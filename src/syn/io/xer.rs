@@ -0,0 +1,1142 @@
+use crate::io::xer::{self, Content, Element};
+use crate::io::xer::{Error, ErrorKind};
+use crate::model::{Charset, PresenceConstraint};
+use crate::syn::*;
+use alloc::borrow::ToOwned;
+use alloc::format;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+/// Writes values according to the basic XML Encoding Rules of ITU-T X.693 | ISO/IEC 8825-4,
+/// without an XML declaration and without any insignificant whitespace.
+///
+/// Every value is written as element, which is named by the `IDENTIFIER` of the constraint of
+/// the component, by the name of the type for the root value and for the components of SEQUENCE
+/// OF and SET OF values or by the name of the built-in type (for example `INTEGER`). BOOLEAN and
+/// ENUMERATED components of SEQUENCE OF and SET OF values are written as list of empty elements
+/// (`<true/><false/>`). Components that are absent or that have their DEFAULT value are not
+/// written.
+#[derive(Default)]
+pub struct XerWriter {
+    buffer: String,
+    depth: usize,
+    identifier: Option<&'static str>,
+    type_name: Option<&'static str>,
+}
+
+impl XerWriter {
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buffer: String::with_capacity(capacity),
+            ..Default::default()
+        }
+    }
+
+    pub fn text_content(&self) -> &str {
+        &self.buffer
+    }
+
+    pub fn into_string(self) -> String {
+        self.buffer
+    }
+
+    pub fn as_reader(&self) -> XerReader<'_> {
+        XerReader::from(self.text_content())
+    }
+
+    /// The name of the element of the next value. The identifier of the referencing component
+    /// takes precedence over the given identifier, which takes precedence over the name of the
+    /// referencing type.
+    fn element_name(&mut self, identifier: Option<&'static str>) -> Option<&'static str> {
+        let type_name = self.type_name.take();
+        self.identifier.take().or(identifier).or(type_name)
+    }
+
+    /// The name of the element of the next BOOLEAN or ENUMERATED value, which is not written
+    /// within an element of its own if it is a component of a SEQUENCE OF or SET OF value
+    fn value_list_element_name(
+        &mut self,
+        identifier: Option<&'static str>,
+        root_name: &'static str,
+    ) -> Option<&'static str> {
+        let type_name = self.type_name.take();
+        self.identifier
+            .take()
+            .or(identifier)
+            .or_else(|| Some(type_name.unwrap_or(root_name)).filter(|_| self.depth == 0))
+    }
+
+    fn write_element<F: FnOnce(&mut Self) -> Result<(), Error>>(
+        &mut self,
+        name: &str,
+        f: F,
+    ) -> Result<(), Error> {
+        self.buffer.push('<');
+        self.buffer.push_str(name);
+        self.buffer.push('>');
+        self.depth += 1;
+        let result = f(self);
+        self.depth -= 1;
+        self.buffer.push_str("</");
+        self.buffer.push_str(name);
+        self.buffer.push('>');
+        result
+    }
+
+    fn write_empty_element(&mut self, name: &str) {
+        self.buffer.push('<');
+        self.buffer.push_str(name);
+        self.buffer.push_str("/>");
+    }
+
+    fn write_value_list_element(&mut self, name: Option<&str>, value: &str) -> Result<(), Error> {
+        match name {
+            Some(name) => self.write_element(name, |w| {
+                w.write_empty_element(value);
+                Ok(())
+            }),
+            None => {
+                self.write_empty_element(value);
+                Ok(())
+            }
+        }
+    }
+
+    fn write_string(
+        &mut self,
+        identifier: Option<&'static str>,
+        builtin: &'static str,
+        value: &str,
+        min: Option<u64>,
+        max: Option<u64>,
+        extensible: bool,
+    ) -> Result<(), Error> {
+        check_size(value.chars().count() as u64, min, max, extensible)?;
+        let name = self.element_name(identifier).unwrap_or(builtin);
+        self.write_element(name, |w| {
+            xer::write_text(&mut w.buffer, value);
+            Ok(())
+        })
+    }
+}
+
+impl Writer for XerWriter {
+    type Error = Error;
+
+    #[inline]
+    fn write_sequence<C: sequence::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        if C::TRANSPARENT {
+            // the outermost type name wins if the type is just another reference
+            self.type_name.get_or_insert(C::NAME);
+            f(self)
+        } else {
+            let name = self.element_name(C::IDENTIFIER).unwrap_or(C::NAME);
+            self.write_element(name, f)
+        }
+    }
+
+    #[inline]
+    fn write_sequence_of<C: sequenceof::Constraint, T: WritableType>(
+        &mut self,
+        slice: &[T::Type],
+    ) -> Result<(), Self::Error> {
+        check_size(slice.len() as u64, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        let name = self.element_name(C::IDENTIFIER).unwrap_or("SEQUENCE_OF");
+        self.write_element(name, |w| {
+            for value in slice {
+                T::write_value(w, value)?;
+            }
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_set<C: set::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        self.write_sequence::<C, F>(f)
+    }
+
+    #[inline]
+    fn write_set_of<C: setof::Constraint, T: WritableType>(
+        &mut self,
+        slice: &[T::Type],
+    ) -> Result<(), Self::Error> {
+        check_size(slice.len() as u64, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        let name = self.element_name(C::IDENTIFIER).unwrap_or("SET_OF");
+        self.write_element(name, |w| {
+            for value in slice {
+                T::write_value(w, value)?;
+            }
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_enumerated<C: enumerated::Constraint>(
+        &mut self,
+        enumerated: &C,
+    ) -> Result<(), Self::Error> {
+        let index = enumerated.to_choice_index();
+        let identifier = C::IDENTIFIERS
+            .get(index as usize)
+            .ok_or(ErrorKind::InvalidChoiceIndex(index, C::VARIANT_COUNT))?;
+        let name = self.value_list_element_name(C::IDENTIFIER, C::NAME);
+        self.write_value_list_element(name, identifier)
+    }
+
+    #[inline]
+    fn write_complex<C: complex::Constraint, F: FnOnce(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        if let Some(identifier) = C::IDENTIFIER {
+            // the outermost identifier wins if the referenced type is just another reference
+            self.identifier.get_or_insert(identifier);
+        }
+        let result = f(self);
+        self.identifier = None;
+        result
+    }
+
+    #[inline]
+    fn write_choice<C: choice::Constraint>(&mut self, choice: &C) -> Result<(), Self::Error> {
+        let name = self.element_name(C::IDENTIFIER).unwrap_or(C::NAME);
+        self.write_element(name, |w| choice.write_content(w))
+    }
+
+    #[inline]
+    fn write_open_type<C: opentype::Constraint>(
+        &mut self,
+        opentype: &C,
+    ) -> Result<(), Self::Error> {
+        if opentype.unknown_bytes().is_some() {
+            // the preserved content is not encoded according to these encoding rules
+            let key = opentype.to_choice_index() as u64;
+            return Err(ErrorKind::InvalidChoiceIndex(key, C::VARIANT_COUNT).into());
+        }
+        // the value of the actual type is written within the element of its identifier
+        let name = self.element_name(C::IDENTIFIER).unwrap_or(C::NAME);
+        self.write_element(name, |w| opentype.write_content(w))
+    }
+
+    #[inline]
+    fn check_presence(
+        &mut self,
+        field: &'static str,
+        constraint: PresenceConstraint,
+        present: bool,
+    ) -> Result<(), Self::Error> {
+        match ViolationKind::of_presence(constraint, present) {
+            Some(kind) => Err(ErrorKind::ComponentConstraintViolated(field, kind).into()),
+            None => Ok(()),
+        }
+    }
+
+    #[inline]
+    fn check_value(&mut self, field: &'static str, permitted: bool) -> Result<(), Self::Error> {
+        if permitted {
+            Ok(())
+        } else {
+            Err(
+                ErrorKind::ComponentConstraintViolated(field, ViolationKind::ValueNotPermitted)
+                    .into(),
+            )
+        }
+    }
+
+    #[inline]
+    fn write_opt<T: WritableType>(&mut self, value: Option<&T::Type>) -> Result<(), Self::Error> {
+        match value {
+            Some(value) => T::write_value(self, value),
+            None => {
+                self.identifier = None;
+                Ok(())
+            }
+        }
+    }
+
+    #[inline]
+    fn write_default<C: default::Constraint<Owned = T::Type>, T: WritableType>(
+        &mut self,
+        value: &T::Type,
+    ) -> Result<(), Self::Error> {
+        if C::DEFAULT_VALUE.ne(value) {
+            T::write_value(self, value)
+        } else {
+            self.identifier = None;
+            Ok(())
+        }
+    }
+
+    #[inline]
+    fn write_number<T: numbers::Number, C: numbers::Constraint<T>>(
+        &mut self,
+        value: T,
+    ) -> Result<(), Self::Error> {
+        let value = value.to_i64();
+        check_range(value, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        let name = self.element_name(C::IDENTIFIER).unwrap_or("INTEGER");
+        self.write_element(name, |w| {
+            w.buffer.push_str(&value.to_string());
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_real<C: real::Constraint>(&mut self, value: f64) -> Result<(), Self::Error> {
+        let name = self.element_name(C::IDENTIFIER).unwrap_or("REAL");
+        self.write_element(name, |w| {
+            // ITU-T X.680 | ISO/IEC 8824-1, 21.6: the special values are empty elements
+            if value.is_nan() {
+                w.write_empty_element("NOT-A-NUMBER");
+            } else if value.is_infinite() {
+                w.write_empty_element(if value > 0.0 {
+                    "PLUS-INFINITY"
+                } else {
+                    "MINUS-INFINITY"
+                });
+            } else if value == 0.0 {
+                w.buffer
+                    .push_str(if value.is_sign_negative() { "-0" } else { "0" });
+            } else {
+                w.buffer.push_str(&format!("{:?}", value));
+            }
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_utf8string<C: utf8string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_string(
+            C::IDENTIFIER,
+            "UTF8String",
+            value,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn write_ia5string<C: ia5string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_string(
+            C::IDENTIFIER,
+            "IA5String",
+            value,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn write_numeric_string<C: numericstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_string(
+            C::IDENTIFIER,
+            "NumericString",
+            value,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn write_visible_string<C: visiblestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_string(
+            C::IDENTIFIER,
+            "VisibleString",
+            value,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn write_printable_string<C: printablestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_string(
+            C::IDENTIFIER,
+            "PrintableString",
+            value,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn write_teletex_string<C: teletexstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_string(
+            C::IDENTIFIER,
+            "TeletexString",
+            value,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn write_graphic_string<C: graphicstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_string(
+            C::IDENTIFIER,
+            "GraphicString",
+            value,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn write_general_string<C: generalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_string(
+            C::IDENTIFIER,
+            "GeneralString",
+            value,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn write_universal_string<C: universalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_string(
+            C::IDENTIFIER,
+            "UniversalString",
+            value,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn write_bmp_string<C: bmpstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_string(
+            C::IDENTIFIER,
+            "BMPString",
+            value,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn write_octet_string<C: octetstring::Constraint>(
+        &mut self,
+        value: &[u8],
+    ) -> Result<(), Self::Error> {
+        check_size(value.len() as u64, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        let name = self.element_name(C::IDENTIFIER).unwrap_or("OCTET_STRING");
+        self.write_element(name, |w| {
+            xer::write_hex(&mut w.buffer, value);
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_bit_string<C: bitstring::Constraint>(
+        &mut self,
+        value: &[u8],
+        bit_len: u64,
+    ) -> Result<(), Self::Error> {
+        check_size(bit_len, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        if (value.len() as u64) < bit_len.div_ceil(8) {
+            return Err(
+                ErrorKind::InvalidContent("the bit length exceeds the given octets").into(),
+            );
+        }
+        let name = self.element_name(C::IDENTIFIER).unwrap_or("BIT_STRING");
+        self.write_element(name, |w| {
+            xer::write_bits(&mut w.buffer, value, bit_len);
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_boolean<C: boolean::Constraint>(&mut self, value: bool) -> Result<(), Self::Error> {
+        let name = self.value_list_element_name(C::IDENTIFIER, "BOOLEAN");
+        self.write_value_list_element(name, if value { "true" } else { "false" })
+    }
+
+    #[inline]
+    fn write_null<C: null::Constraint>(&mut self, _value: &Null) -> Result<(), Self::Error> {
+        let name = self.element_name(C::IDENTIFIER).unwrap_or("NULL");
+        self.write_empty_element(name);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+        value: &ObjectIdentifier,
+    ) -> Result<(), Self::Error> {
+        let name = self
+            .element_name(C::IDENTIFIER)
+            .unwrap_or("OBJECT_IDENTIFIER");
+        self.write_element(name, |w| {
+            w.buffer.push_str(&value.to_string());
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_relative_oid<C: relativeoid::Constraint>(
+        &mut self,
+        value: &RelativeOid,
+    ) -> Result<(), Self::Error> {
+        let name = self.element_name(C::IDENTIFIER).unwrap_or("RELATIVE_OID");
+        self.write_element(name, |w| {
+            w.buffer.push_str(&value.to_string());
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_time<C: time::Constraint, T: time::TimeType>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        let name = self.element_name(C::IDENTIFIER).unwrap_or(T::NAME);
+        self.write_element(name, |w| {
+            w.buffer.push_str(&value.to_string());
+            Ok(())
+        })
+    }
+}
+
+/// Reads values according to the basic XML Encoding Rules of ITU-T X.693 | ISO/IEC 8825-4, see
+/// [`XerWriter`]. The name of the root element is not checked, because the name of the Rust
+/// type might differ from the ASN.1 type reference. Components of SEQUENCE and SET values are
+/// accepted in any order, unknown components are ignored if the SEQUENCE or SET is extensible.
+pub struct XerReader<'a> {
+    text: &'a str,
+    root_read: bool,
+    containers: Vec<ReadContainer>,
+    identifier: Option<&'static str>,
+    type_name: Option<&'static str>,
+    /// Whether the next element to take may be absent
+    optional: bool,
+    /// Whether the last element to take was optional and absent
+    absent: bool,
+}
+
+enum ReadContainer {
+    Components(Vec<Element>),
+    List(alloc::vec::IntoIter<Element>),
+}
+
+impl<'a> From<&'a str> for XerReader<'a> {
+    fn from(text: &'a str) -> Self {
+        Self {
+            text,
+            root_read: false,
+            containers: Vec::default(),
+            identifier: None,
+            type_name: None,
+            optional: false,
+            absent: false,
+        }
+    }
+}
+
+impl XerReader<'_> {
+    /// See [`XerWriter::element_name`]
+    fn element_name(&mut self, identifier: Option<&'static str>) -> Option<&'static str> {
+        let type_name = self.type_name.take();
+        self.identifier.take().or(identifier).or(type_name)
+    }
+
+    /// See [`XerWriter::value_list_element_name`]
+    fn value_list_element_name(
+        &mut self,
+        identifier: Option<&'static str>,
+        root_name: &'static str,
+    ) -> Option<&'static str> {
+        let type_name = self.type_name.take();
+        self.identifier
+            .take()
+            .or(identifier)
+            .or_else(|| Some(type_name.unwrap_or(root_name)).filter(|_| self.containers.is_empty()))
+    }
+
+    /// Takes the next element: the element with the given name within SEQUENCE, SET and CHOICE
+    /// values, the next element within SEQUENCE OF and SET OF values or the root element
+    fn take(&mut self, name: Option<&'static str>) -> Result<Element, Error> {
+        let optional = core::mem::take(&mut self.optional);
+        match self.containers.last_mut() {
+            Some(ReadContainer::Components(elements)) => {
+                let name = name.ok_or(ErrorKind::MissingIdentifier)?;
+                match elements.iter().position(|element| element.name == name) {
+                    Some(index) => Ok(elements.remove(index)),
+                    None => {
+                        self.absent = optional;
+                        Err(ErrorKind::MissingElement(name).into())
+                    }
+                }
+            }
+            Some(ReadContainer::List(elements)) => elements
+                .next()
+                .ok_or_else(|| ErrorKind::NoMoreElements.into()),
+            None if !self.root_read => {
+                self.root_read = true;
+                xer::parse(self.text)
+            }
+            None => Err(ErrorKind::NoMoreElements.into()),
+        }
+    }
+
+    fn take_text(
+        &mut self,
+        identifier: Option<&'static str>,
+        builtin: &'static str,
+    ) -> Result<String, Error> {
+        let name = self.element_name(identifier).unwrap_or(builtin);
+        self.take(Some(name))?.into_text()
+    }
+
+    /// Takes the name of the empty element that represents a BOOLEAN or ENUMERATED value
+    fn take_value_list_element(
+        &mut self,
+        identifier: Option<&'static str>,
+        root_name: &'static str,
+    ) -> Result<String, Error> {
+        let name = self.value_list_element_name(identifier, root_name);
+        let mut element = self.take(name)?;
+        if name.is_some() {
+            let mut elements = element.into_elements()?;
+            if elements.len() != 1 {
+                return Err(ErrorKind::InvalidContent("expected exactly one empty element").into());
+            }
+            element = elements.remove(0);
+        }
+        if element.content.is_empty() {
+            Ok(element.name)
+        } else {
+            Err(ErrorKind::InvalidContent("expected an empty element").into())
+        }
+    }
+
+    fn read_container<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        container: ReadContainer,
+        f: F,
+    ) -> Result<(T, ReadContainer), Error> {
+        self.containers.push(container);
+        let result = f(self);
+        let container = self.containers.pop().unwrap();
+        Ok((result?, container))
+    }
+
+    fn read_list<T: ReadableType>(
+        &mut self,
+        identifier: Option<&'static str>,
+        builtin: &'static str,
+        min: Option<u64>,
+        max: Option<u64>,
+        extensible: bool,
+    ) -> Result<Vec<T::Type>, Error> {
+        let name = self.element_name(identifier).unwrap_or(builtin);
+        let elements = self.take(Some(name))?.into_elements()?;
+        let len = elements.len();
+        check_size(len as u64, min, max, extensible)?;
+        self.read_container(ReadContainer::List(elements.into_iter()), |r| {
+            (0..len).map(|_| T::read_value(r)).collect()
+        })
+        .map(|(values, _)| values)
+    }
+
+    fn read_string(
+        &mut self,
+        identifier: Option<&'static str>,
+        builtin: &'static str,
+        min: Option<u64>,
+        max: Option<u64>,
+        extensible: bool,
+    ) -> Result<String, Error> {
+        let string = self.take_text(identifier, builtin)?;
+        check_size(string.chars().count() as u64, min, max, extensible)?;
+        Ok(string)
+    }
+}
+
+impl Reader for XerReader<'_> {
+    type Error = Error;
+
+    #[inline]
+    fn read_sequence<
+        C: sequence::Constraint,
+        S: Sized,
+        F: Fn(&mut Self) -> Result<S, Self::Error>,
+    >(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        if C::TRANSPARENT {
+            // the outermost type name wins if the type is just another reference
+            self.type_name.get_or_insert(C::NAME);
+            return f(self);
+        }
+        let name = self.element_name(C::IDENTIFIER).unwrap_or(C::NAME);
+        let elements = self.take(Some(name))?.into_elements()?;
+        let (value, container) = self.read_container(ReadContainer::Components(elements), f)?;
+        match container {
+            ReadContainer::Components(elements) if C::EXTENDED_AFTER_FIELD.is_none() => {
+                match elements.into_iter().next() {
+                    Some(element) => Err(ErrorKind::UnknownElement(element.name).into()),
+                    None => Ok(value),
+                }
+            }
+            _ => Ok(value),
+        }
+    }
+
+    #[inline]
+    fn read_sequence_of<C: sequenceof::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<Vec<T::Type>, Self::Error> {
+        self.read_list::<T>(C::IDENTIFIER, "SEQUENCE_OF", C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn read_set<C: set::Constraint, S: Sized, F: Fn(&mut Self) -> Result<S, Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        self.read_sequence::<C, S, F>(f)
+    }
+
+    #[inline]
+    fn read_set_of<C: setof::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<Vec<T::Type>, Self::Error> {
+        self.read_list::<T>(C::IDENTIFIER, "SET_OF", C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn read_enumerated<C: enumerated::Constraint>(&mut self) -> Result<C, Self::Error> {
+        let identifier = self.take_value_list_element(C::IDENTIFIER, C::NAME)?;
+        let index = C::IDENTIFIERS
+            .iter()
+            .position(|i| *i == identifier)
+            .ok_or(ErrorKind::UnknownElement(identifier))?;
+        C::from_choice_index(index as u64)
+            .ok_or_else(|| ErrorKind::InvalidChoiceIndex(index as u64, C::VARIANT_COUNT).into())
+    }
+
+    #[inline]
+    fn read_complex<C: complex::Constraint, T, F: FnOnce(&mut Self) -> Result<T, Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<T, Self::Error> {
+        if let Some(identifier) = C::IDENTIFIER {
+            // the outermost identifier wins if the referenced type is just another reference
+            self.identifier.get_or_insert(identifier);
+        }
+        let result = f(self);
+        self.identifier = None;
+        result
+    }
+
+    #[inline]
+    fn read_choice<C: choice::Constraint>(&mut self) -> Result<C, Self::Error> {
+        let name = self.element_name(C::IDENTIFIER).unwrap_or(C::NAME);
+        let elements = self.take(Some(name))?.into_elements()?;
+        let name = match &elements[..] {
+            [element] => element.name.clone(),
+            _ => {
+                return Err(ErrorKind::InvalidContent(
+                    "a choice value consists of exactly one element",
+                )
+                .into())
+            }
+        };
+        // the variant is the one whose identifier names the element
+        self.read_container(ReadContainer::Components(elements), |r| {
+            for index in 0..C::VARIANT_COUNT {
+                r.optional = true;
+                r.absent = false;
+                match C::read_content(index, r) {
+                    Ok(Some(value)) => return Ok(value),
+                    Ok(None) => break,
+                    Err(_) if core::mem::take(&mut r.absent) => continue,
+                    Err(e) => return Err(e),
+                }
+            }
+            r.optional = false;
+            Err(ErrorKind::UnknownElement(name).into())
+        })
+        .map(|(value, _)| value)
+    }
+
+    #[inline]
+    fn read_open_type<C: opentype::Constraint>(
+        &mut self,
+        key: Option<usize>,
+    ) -> Result<C, Self::Error> {
+        let key = key.ok_or(ErrorKind::InvalidOpenTypeKey(C::NAME))?;
+        let name = self.element_name(C::IDENTIFIER).unwrap_or(C::NAME);
+        let elements = self.take(Some(name))?.into_elements()?;
+        self.read_container(ReadContainer::Components(elements), |r| {
+            C::read_content(key, r)?
+                .ok_or_else(|| ErrorKind::InvalidChoiceIndex(key as u64, C::VARIANT_COUNT).into())
+        })
+        .map(|(value, _)| value)
+    }
+
+    #[inline]
+    fn read_present<T: ReadableType>(
+        &mut self,
+        field: &'static str,
+    ) -> Result<T::Type, Self::Error> {
+        self.read_opt::<T>()?.ok_or_else(|| {
+            ErrorKind::ComponentConstraintViolated(field, ViolationKind::ComponentNotPresent).into()
+        })
+    }
+
+    #[inline]
+    fn read_opt<T: ReadableType>(&mut self) -> Result<Option<T::Type>, Self::Error> {
+        self.optional = true;
+        self.absent = false;
+        let result = T::read_value(self);
+        self.optional = false;
+        match result {
+            Ok(value) => Ok(Some(value)),
+            Err(_) if core::mem::take(&mut self.absent) => {
+                self.identifier = None;
+                Ok(None)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    #[inline]
+    fn read_default<C: default::Constraint<Owned = T::Type>, T: ReadableType>(
+        &mut self,
+    ) -> Result<T::Type, Self::Error> {
+        Ok(self
+            .read_opt::<T>()?
+            .unwrap_or_else(|| C::DEFAULT_VALUE.to_owned()))
+    }
+
+    #[inline]
+    fn read_number<T: numbers::Number, C: numbers::Constraint<T>>(
+        &mut self,
+    ) -> Result<T, Self::Error> {
+        let text = self.take_text(C::IDENTIFIER, "INTEGER")?;
+        let text = text.trim();
+        let digits = text.strip_prefix(['-', '+']).unwrap_or(text);
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ErrorKind::InvalidContent("an integer consists of decimal digits").into());
+        }
+        let value = text
+            .parse::<i64>()
+            .map_err(|_| ErrorKind::ValueExceedsMaxInt)?;
+        check_range(value, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        Ok(T::from_i64(value))
+    }
+
+    #[inline]
+    fn read_real<C: real::Constraint>(&mut self) -> Result<f64, Self::Error> {
+        let name = self.element_name(C::IDENTIFIER).unwrap_or("REAL");
+        let element = self.take(Some(name))?;
+        if element
+            .content
+            .iter()
+            .any(|content| matches!(content, Content::Element(_)))
+        {
+            let mut elements = element.into_elements()?;
+            if elements.len() != 1 || !elements[0].content.is_empty() {
+                return Err(ErrorKind::InvalidContent("expected exactly one empty element").into());
+            }
+            return match elements.remove(0).name.as_str() {
+                "PLUS-INFINITY" => Ok(f64::INFINITY),
+                "MINUS-INFINITY" => Ok(f64::NEG_INFINITY),
+                "NOT-A-NUMBER" => Ok(f64::NAN),
+                _ => Err(ErrorKind::InvalidContent("unknown special real value").into()),
+            };
+        }
+
+        let text = element.into_text()?;
+        let text = text.trim();
+        if text.is_empty()
+            || !text
+                .bytes()
+                .all(|b| b.is_ascii_digit() || matches!(b, b'-' | b'+' | b'.' | b'e' | b'E'))
+        {
+            return Err(ErrorKind::InvalidContent("a real consists of a decimal number").into());
+        }
+        text.parse::<f64>()
+            .map_err(|_| ErrorKind::InvalidContent("a real consists of a decimal number").into())
+    }
+
+    #[inline]
+    fn read_utf8string<C: utf8string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_string(C::IDENTIFIER, "UTF8String", C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn read_ia5string<C: ia5string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let string = self.read_string(C::IDENTIFIER, "IA5String", C::MIN, C::MAX, C::EXTENSIBLE)?;
+        Error::ensure_string_valid(Charset::Ia5, &string)?;
+        Ok(string)
+    }
+
+    #[inline]
+    fn read_numeric_string<C: numericstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let string = self.read_string(
+            C::IDENTIFIER,
+            "NumericString",
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )?;
+        Error::ensure_string_valid(Charset::Numeric, &string)?;
+        Ok(string)
+    }
+
+    #[inline]
+    fn read_visible_string<C: visiblestring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let string = self.read_string(
+            C::IDENTIFIER,
+            "VisibleString",
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )?;
+        Error::ensure_string_valid(Charset::Visible, &string)?;
+        Ok(string)
+    }
+
+    #[inline]
+    fn read_printable_string<C: printablestring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        let string = self.read_string(
+            C::IDENTIFIER,
+            "PrintableString",
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )?;
+        Error::ensure_string_valid(Charset::Printable, &string)?;
+        Ok(string)
+    }
+
+    #[inline]
+    fn read_teletex_string<C: teletexstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let string = self.read_string(
+            C::IDENTIFIER,
+            "TeletexString",
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )?;
+        Error::ensure_string_valid(Charset::Teletex, &string)?;
+        Ok(string)
+    }
+
+    #[inline]
+    fn read_graphic_string<C: graphicstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let string = self.read_string(
+            C::IDENTIFIER,
+            "GraphicString",
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )?;
+        Error::ensure_string_valid(Charset::Graphic, &string)?;
+        Ok(string)
+    }
+
+    #[inline]
+    fn read_general_string<C: generalstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let string = self.read_string(
+            C::IDENTIFIER,
+            "GeneralString",
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )?;
+        Error::ensure_string_valid(Charset::General, &string)?;
+        Ok(string)
+    }
+
+    #[inline]
+    fn read_universal_string<C: universalstring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        let string = self.read_string(
+            C::IDENTIFIER,
+            "UniversalString",
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )?;
+        Error::ensure_string_valid(Charset::Universal, &string)?;
+        Ok(string)
+    }
+
+    #[inline]
+    fn read_bmp_string<C: bmpstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let string = self.read_string(C::IDENTIFIER, "BMPString", C::MIN, C::MAX, C::EXTENSIBLE)?;
+        Error::ensure_string_valid(Charset::Bmp, &string)?;
+        Ok(string)
+    }
+
+    #[inline]
+    fn read_octet_string<C: octetstring::Constraint>(&mut self) -> Result<Vec<u8>, Self::Error> {
+        let octets = xer::read_hex(&self.take_text(C::IDENTIFIER, "OCTET_STRING")?)?;
+        check_size(octets.len() as u64, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        Ok(octets)
+    }
+
+    #[inline]
+    fn read_bit_string<C: bitstring::Constraint>(&mut self) -> Result<(Vec<u8>, u64), Self::Error> {
+        let (octets, bit_len) = xer::read_bits(&self.take_text(C::IDENTIFIER, "BIT_STRING")?)?;
+        check_size(bit_len, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        Ok((octets, bit_len))
+    }
+
+    #[inline]
+    fn read_boolean<C: boolean::Constraint>(&mut self) -> Result<bool, Self::Error> {
+        match self
+            .take_value_list_element(C::IDENTIFIER, "BOOLEAN")?
+            .as_str()
+        {
+            "true" => Ok(true),
+            "false" => Ok(false),
+            _ => Err(ErrorKind::InvalidContent("expected <true/> or <false/>").into()),
+        }
+    }
+
+    #[inline]
+    fn read_null<C: null::Constraint>(&mut self) -> Result<Null, Self::Error> {
+        let name = self.element_name(C::IDENTIFIER).unwrap_or("NULL");
+        if self.take(Some(name))?.into_elements()?.is_empty() {
+            Ok(Null)
+        } else {
+            Err(ErrorKind::InvalidContent("a null value has no content").into())
+        }
+    }
+
+    #[inline]
+    fn read_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+    ) -> Result<ObjectIdentifier, Self::Error> {
+        let name = self
+            .element_name(C::IDENTIFIER)
+            .unwrap_or("OBJECT_IDENTIFIER");
+        let text = self.take(Some(name))?.into_text()?;
+        text.trim().parse().map_err(|_| {
+            ErrorKind::InvalidContent("invalid dot notation of an object identifier").into()
+        })
+    }
+
+    #[inline]
+    fn read_relative_oid<C: relativeoid::Constraint>(
+        &mut self,
+    ) -> Result<RelativeOid, Self::Error> {
+        let name = self.element_name(C::IDENTIFIER).unwrap_or("RELATIVE_OID");
+        let text = self.take(Some(name))?.into_text()?;
+        text.trim().parse().map_err(|_| {
+            ErrorKind::InvalidContent("invalid dot notation of a relative object identifier").into()
+        })
+    }
+
+    #[inline]
+    fn read_time<C: time::Constraint, T: time::TimeType>(&mut self) -> Result<T, Self::Error> {
+        let name = self.element_name(C::IDENTIFIER).unwrap_or(T::NAME);
+        let text = self.take(Some(name))?.into_text()?;
+        text.trim()
+            .parse()
+            .map_err(|e: time::TimeError| ErrorKind::InvalidContent(e.reason()).into())
+    }
+}
+
+#[inline]
+fn check_size(
+    size: u64,
+    min: Option<u64>,
+    max: Option<u64>,
+    extensible: bool,
+) -> Result<(), Error> {
+    if !extensible && (min.is_some_and(|min| size < min) || max.is_some_and(|max| size > max)) {
+        Err(ErrorKind::SizeNotInRange(size, min.unwrap_or(0), max.unwrap_or(u64::MAX)).into())
+    } else {
+        Ok(())
+    }
+}
+
+#[inline]
+fn check_range(
+    value: i64,
+    min: Option<i64>,
+    max: Option<i64>,
+    extensible: bool,
+) -> Result<(), Error> {
+    if !extensible && (min.is_some_and(|min| value < min) || max.is_some_and(|max| value > max)) {
+        Err(
+            ErrorKind::ValueNotInRange(value, min.unwrap_or(i64::MIN), max.unwrap_or(i64::MAX))
+                .into(),
+        )
+    } else {
+        Ok(())
+    }
+}
+
+pub trait XerDecodable {
+    fn decode_from_xer(text: &str) -> Result<Self, Error>
+    where
+        Self: Sized;
+}
+
+impl<R: Readable> XerDecodable for R {
+    fn decode_from_xer(text: &str) -> Result<Self, Error>
+    where
+        Self: Sized,
+    {
+        let mut reader = XerReader::from(text);
+        Self::read(&mut reader)
+    }
+}
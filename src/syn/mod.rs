@@ -1,54 +1,99 @@
 pub mod bitstring;
+pub mod bmpstring;
 pub mod boolean;
+pub mod borrowed;
 pub mod choice;
 pub mod common;
 pub mod complex;
 pub mod default;
 pub mod enumerated;
+pub mod explicit;
+pub mod generalstring;
+pub mod graphicstring;
 pub mod ia5string;
 pub mod io;
 pub mod null;
 pub mod numbers;
 pub mod numericstring;
+pub mod objectidentifier;
 pub mod octetstring;
+pub mod opentype;
 pub mod optional;
 pub mod printablestring;
+pub mod real;
+pub mod relativeoid;
 pub mod sequence;
 pub mod sequenceof;
 pub mod set;
 pub mod setof;
+pub mod teletexstring;
+pub mod time;
+pub mod universalstring;
+pub mod unknown;
 pub mod utf8string;
+pub mod validate;
 pub mod visiblestring;
-pub mod opentype;
+
+use crate::model::PresenceConstraint;
+use alloc::string::String;
+use alloc::vec::Vec;
 
 pub use crate::syn::null::Null;
 pub use bitstring::BitString;
 pub use bitstring::BitVec;
+pub use bmpstring::BmpString;
 pub use boolean::Boolean;
 pub use choice::Choice;
-pub use opentype::OpenType;
 pub use complex::Complex;
 pub use default::DefaultValue;
 pub use enumerated::Enumerated;
+pub use explicit::ExplicitTag;
+pub use generalstring::GeneralString;
+pub use graphicstring::GraphicString;
 pub use ia5string::Ia5String;
 pub use null::NullT;
 pub use numbers::Integer;
 pub use numericstring::NumericString;
+pub use objectidentifier::ObjectIdentifier;
+pub use objectidentifier::ObjectIdentifierT;
 pub use octetstring::OctetString;
+pub use opentype::OpenType;
 pub use printablestring::PrintableString;
+pub use real::Real;
+pub use relativeoid::RelativeOid;
+pub use relativeoid::RelativeOidT;
 pub use sequence::Sequence;
 pub use sequenceof::SequenceOf;
 pub use set::Set;
 pub use setof::SetOf;
+pub use teletexstring::TeletexString;
+pub use time::{
+    Date, DateT, Duration, DurationT, GeneralizedTime, GeneralizedTimeT, TimeOfDay, TimeOfDayT,
+    UtcTime, UtcTimeT,
+};
+pub use universalstring::UniversalString;
+pub use unknown::{UnknownExtension, UnknownExtensions};
 pub use utf8string::Utf8String;
+pub use validate::{Validate, Violation, ViolationKind, Violations};
 pub use visiblestring::VisibleString;
 
 pub mod prelude {
     pub use super::bitstring::BitVec;
+    pub use super::borrowed::{BorrowedReadable, BorrowedReadableType, BorrowedReader};
+    pub use super::Date;
+    pub use super::Duration;
+    pub use super::GeneralizedTime;
     pub use super::Null;
+    pub use super::ObjectIdentifier;
     pub use super::Readable;
     pub use super::ReadableType;
     pub use super::Reader;
+    pub use super::RelativeOid;
+    pub use super::TimeOfDay;
+    pub use super::UnknownExtension;
+    pub use super::UnknownExtensions;
+    pub use super::UtcTime;
+    pub use super::Validate;
     pub use super::Writable;
     pub use super::WritableType;
     pub use super::Writer;
@@ -89,11 +134,51 @@ pub trait Reader {
 
     fn read_enumerated<C: enumerated::Constraint>(&mut self) -> Result<C, Self::Error>;
 
+    /// Reads the value of a field that refers to another type, where `C::TAG` is the tag of
+    /// the field. Only tag based encodings need to override the default implementation.
+    #[inline]
+    fn read_complex<C: complex::Constraint, T, F: FnOnce(&mut Self) -> Result<T, Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<T, Self::Error> {
+        f(self)
+    }
+
+    /// Reads a value with an explicit tag, where `C::TAG` is the tag around the encoding of the
+    /// value. Only tag based encodings need to override the default implementation.
+    #[inline]
+    fn read_explicit<C: explicit::Constraint, T, F: FnOnce(&mut Self) -> Result<T, Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<T, Self::Error> {
+        f(self)
+    }
+
+    /// Reads the extension additions of the current SEQUENCE or SET that are unknown to its
+    /// schema, after all known fields have been read. Only encodings that can carry these
+    /// need to override the default implementation.
+    #[inline]
+    fn read_unknown_extensions(&mut self) -> Result<UnknownExtensions, Self::Error> {
+        Ok(UnknownExtensions::default())
+    }
+
     fn read_choice<C: choice::Constraint>(&mut self) -> Result<C, Self::Error>;
-    fn read_open_type<C: opentype::Constraint>(&mut self,key:usize) -> Result<C, Self::Error>;
+    /// Reads the open type `C` for the key of its object set, which is `None` if the variant is
+    /// not selected by such a key (see [`opentype::Key`])
+    fn read_open_type<C: opentype::Constraint>(
+        &mut self,
+        key: Option<usize>,
+    ) -> Result<C, Self::Error>;
 
     fn read_opt<T: ReadableType>(&mut self) -> Result<Option<T::Type>, Self::Error>;
 
+    /// Reads an OPTIONAL component, that is constrained to be PRESENT and stored in the field
+    /// `field`, and fails if it is absent.
+    fn read_present<T: ReadableType>(
+        &mut self,
+        field: &'static str,
+    ) -> Result<T::Type, Self::Error>;
+
     fn read_default<C: default::Constraint<Owned = T::Type>, T: ReadableType>(
         &mut self,
     ) -> Result<T::Type, Self::Error>;
@@ -102,6 +187,8 @@ pub trait Reader {
         &mut self,
     ) -> Result<T, Self::Error>;
 
+    fn read_real<C: real::Constraint>(&mut self) -> Result<f64, Self::Error>;
+
     fn read_utf8string<C: utf8string::Constraint>(&mut self) -> Result<String, Self::Error>;
 
     fn read_ia5string<C: ia5string::Constraint>(&mut self) -> Result<String, Self::Error>;
@@ -114,6 +201,18 @@ pub trait Reader {
         &mut self,
     ) -> Result<String, Self::Error>;
 
+    fn read_teletex_string<C: teletexstring::Constraint>(&mut self) -> Result<String, Self::Error>;
+
+    fn read_graphic_string<C: graphicstring::Constraint>(&mut self) -> Result<String, Self::Error>;
+
+    fn read_general_string<C: generalstring::Constraint>(&mut self) -> Result<String, Self::Error>;
+
+    fn read_universal_string<C: universalstring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error>;
+
+    fn read_bmp_string<C: bmpstring::Constraint>(&mut self) -> Result<String, Self::Error>;
+
     fn read_octet_string<C: octetstring::Constraint>(&mut self) -> Result<Vec<u8>, Self::Error>;
 
     fn read_bit_string<C: bitstring::Constraint>(&mut self) -> Result<(Vec<u8>, u64), Self::Error>;
@@ -121,13 +220,25 @@ pub trait Reader {
     fn read_boolean<C: boolean::Constraint>(&mut self) -> Result<bool, Self::Error>;
 
     fn read_null<C: null::Constraint>(&mut self) -> Result<Null, Self::Error>;
+
+    fn read_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+    ) -> Result<ObjectIdentifier, Self::Error>;
+
+    fn read_relative_oid<C: relativeoid::Constraint>(&mut self)
+        -> Result<RelativeOid, Self::Error>;
+
+    fn read_time<C: time::Constraint, T: time::TimeType>(&mut self) -> Result<T, Self::Error>;
 }
 
 pub trait Readable: Sized {
     fn read<R: Reader>(reader: &mut R) -> Result<Self, R::Error>;
 
-    fn read_by_key<R: Reader>(_reader: &mut R,_key:usize) -> Result<Self, R::Error>{
-        panic!("not support----Readable&trait&root")
+    /// Reads the value of an open type selected by the given key. Types that are not keyed
+    /// ignore it.
+    #[inline]
+    fn read_by_key<R: Reader>(reader: &mut R, _key: usize) -> Result<Self, R::Error> {
+        Self::read(reader)
     }
 }
 
@@ -135,8 +246,12 @@ pub trait ReadableType {
     type Type: Sized;
 
     fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, R::Error>;
-    fn read_value_by_key<R: Reader>(_reader: &mut R,_key:usize) -> Result<Self::Type, R::Error>{
-        panic!("not support----ReadableType&trait&root")
+
+    /// Like [`ReadableType::read_value`], but for the open type selected by the given key. Types
+    /// that are not keyed ignore it.
+    #[inline]
+    fn read_value_by_key<R: Reader>(reader: &mut R, _key: usize) -> Result<Self::Type, R::Error> {
+        Self::read_value(reader)
     }
 }
 
@@ -147,6 +262,11 @@ impl<T: Readable> ReadableType for T {
     fn read_value<R: Reader>(reader: &mut R) -> Result<T, R::Error> {
         T::read(reader)
     }
+
+    #[inline]
+    fn read_value_by_key<R: Reader>(reader: &mut R, key: usize) -> Result<T, R::Error> {
+        T::read_by_key(reader, key)
+    }
 }
 
 pub trait Writer {
@@ -185,8 +305,61 @@ pub trait Writer {
         enumerated: &C,
     ) -> Result<(), Self::Error>;
 
+    /// Writes the value of a field that refers to another type, where `C::TAG` is the tag of
+    /// the field. Only tag based encodings need to override the default implementation.
+    #[inline]
+    fn write_complex<C: complex::Constraint, F: FnOnce(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        f(self)
+    }
+
+    /// Writes a value with an explicit tag, where `C::TAG` is the tag around the encoding of the
+    /// value. Only tag based encodings need to override the default implementation.
+    #[inline]
+    fn write_explicit<C: explicit::Constraint, F: FnOnce(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        f(self)
+    }
+
+    /// Announces the extension additions of the current SEQUENCE or SET that are unknown to its
+    /// schema, before any field is written. Only encodings that can carry these need to override
+    /// the default implementation.
+    #[inline]
+    fn write_unknown_extensions(
+        &mut self,
+        _unknown: &UnknownExtensions,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Checks the `WITH COMPONENTS` presence constraint on the component of the current SEQUENCE
+    /// or SET, which is stored in the field `field`, before any field is written. Only validating
+    /// writers need to override the default implementation.
+    #[inline]
+    fn check_presence(
+        &mut self,
+        _field: &'static str,
+        _constraint: PresenceConstraint,
+        _present: bool,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Is called with the result of checking the value constraint of a component of a SEQUENCE or
+    /// SET, which is stored in the field `field`, before any field is written. Only validating
+    /// writers need to override the default implementation.
+    #[inline]
+    fn check_value(&mut self, _field: &'static str, _permitted: bool) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
     fn write_choice<C: choice::Constraint>(&mut self, choice: &C) -> Result<(), Self::Error>;
-    fn write_open_type<C: opentype::Constraint>(&mut self, opentype: &C) -> Result<(), Self::Error>;
+    fn write_open_type<C: opentype::Constraint>(&mut self, opentype: &C)
+        -> Result<(), Self::Error>;
 
     fn write_opt<T: WritableType>(&mut self, value: Option<&T::Type>) -> Result<(), Self::Error>;
 
@@ -200,6 +373,8 @@ pub trait Writer {
         value: T,
     ) -> Result<(), Self::Error>;
 
+    fn write_real<C: real::Constraint>(&mut self, value: f64) -> Result<(), Self::Error>;
+
     fn write_utf8string<C: utf8string::Constraint>(
         &mut self,
         value: &str,
@@ -223,6 +398,31 @@ pub trait Writer {
         value: &str,
     ) -> Result<(), Self::Error>;
 
+    fn write_teletex_string<C: teletexstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error>;
+
+    fn write_graphic_string<C: graphicstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error>;
+
+    fn write_general_string<C: generalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error>;
+
+    fn write_universal_string<C: universalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error>;
+
+    fn write_bmp_string<C: bmpstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error>;
+
     fn write_octet_string<C: octetstring::Constraint>(
         &mut self,
         value: &[u8],
@@ -237,6 +437,21 @@ pub trait Writer {
     fn write_boolean<C: boolean::Constraint>(&mut self, value: bool) -> Result<(), Self::Error>;
 
     fn write_null<C: null::Constraint>(&mut self, value: &Null) -> Result<(), Self::Error>;
+
+    fn write_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+        value: &ObjectIdentifier,
+    ) -> Result<(), Self::Error>;
+
+    fn write_relative_oid<C: relativeoid::Constraint>(
+        &mut self,
+        value: &RelativeOid,
+    ) -> Result<(), Self::Error>;
+
+    fn write_time<C: time::Constraint, T: time::TimeType>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Self::Error>;
 }
 
 pub trait Writable {
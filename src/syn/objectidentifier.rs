@@ -0,0 +1,104 @@
+use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use asn1rs_model::model::Tag;
+use core::fmt::{Display, Formatter};
+use core::marker::PhantomData;
+use core::num::ParseIntError;
+use core::str::FromStr;
+
+pub struct ObjectIdentifierT<C: Constraint = NoConstraint>(PhantomData<C>);
+
+pub trait Constraint: super::common::Constraint {}
+
+#[derive(Default)]
+pub struct NoConstraint;
+impl super::common::Constraint for NoConstraint {
+    const TAG: Tag = Tag::DEFAULT_OBJECT_IDENTIFIER;
+}
+impl Constraint for NoConstraint {}
+
+impl<C: Constraint> WritableType for ObjectIdentifierT<C> {
+    type Type = ObjectIdentifier;
+
+    #[inline]
+    fn write_value<W: Writer>(
+        writer: &mut W,
+        value: &Self::Type,
+    ) -> Result<(), <W as Writer>::Error> {
+        writer.write_object_identifier::<C>(value)
+    }
+}
+
+impl<C: Constraint> ReadableType for ObjectIdentifierT<C> {
+    type Type = ObjectIdentifier;
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        reader.read_object_identifier::<C>()
+    }
+}
+
+/// The value of an `OBJECT IDENTIFIER`, represented by its arcs (ITU-T X.680, 32). The
+/// arcs of constants are borrowed, so that these can be declared as `const`.
+#[derive(Default, Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct ObjectIdentifier(Cow<'static, [u64]>);
+
+impl ObjectIdentifier {
+    pub const fn from_static(arcs: &'static [u64]) -> Self {
+        Self(Cow::Borrowed(arcs))
+    }
+
+    pub fn new(arcs: Vec<u64>) -> Self {
+        Self(Cow::Owned(arcs))
+    }
+
+    pub fn arcs(&self) -> &[u64] {
+        &self.0
+    }
+}
+
+impl From<Vec<u64>> for ObjectIdentifier {
+    fn from(arcs: Vec<u64>) -> Self {
+        Self::new(arcs)
+    }
+}
+
+impl From<ObjectIdentifier> for Vec<u64> {
+    fn from(value: ObjectIdentifier) -> Self {
+        value.0.into_owned()
+    }
+}
+
+/// Dot notation, as in `1.2.840.113549`
+impl Display for ObjectIdentifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write_arcs(f, self.arcs())
+    }
+}
+
+impl FromStr for ObjectIdentifier {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_arcs(s).map(Self::new)
+    }
+}
+
+pub(crate) fn write_arcs(f: &mut Formatter<'_>, arcs: &[u64]) -> core::fmt::Result {
+    for (index, arc) in arcs.iter().enumerate() {
+        if index > 0 {
+            write!(f, ".")?;
+        }
+        write!(f, "{}", arc)?;
+    }
+    Ok(())
+}
+
+pub(crate) fn parse_arcs(s: &str) -> Result<Vec<u64>, ParseIntError> {
+    if s.is_empty() {
+        Ok(Vec::new())
+    } else {
+        s.split('.').map(u64::from_str).collect()
+    }
+}
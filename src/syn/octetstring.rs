@@ -1,4 +1,5 @@
 use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use alloc::vec::Vec;
 use asn1rs_model::model::Tag;
 use core::marker::PhantomData;
 
@@ -1,4 +1,6 @@
-use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use crate::syn::{enumerated, ObjectIdentifier, ReadableType, Reader, WritableType, Writer};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
 use core::marker::PhantomData;
 
 pub struct OpenType<C: Constraint>(PhantomData<C>);
@@ -8,12 +10,31 @@ pub trait Constraint: super::common::Constraint + Sized {
     const VARIANT_COUNT: u64;
     const STD_VARIANT_COUNT: u64;
     const EXTENSIBLE: bool = false;
+    /// The keys of the variants in the order of their definition
+    const KEYS: &'static [usize];
+    /// The arcs of the OBJECT IDENTIFIER values that stand for the [`Constraint::KEYS`] at the
+    /// same positions, if the variants are keyed by OBJECT IDENTIFIER values
+    const OBJECT_IDENTIFIERS: &'static [&'static [u64]] = &[];
 
     fn to_choice_index(&self) -> usize;
 
     fn write_content<W: Writer>(&self, writer: &mut W) -> Result<(), W::Error>;
 
     fn read_content<R: Reader>(index: usize, reader: &mut R) -> Result<Option<Self>, R::Error>;
+
+    /// The value for a key that is unknown to the object set, consisting of the key and the raw
+    /// content of the open type field, if this type preserves these
+    #[inline]
+    fn from_unknown_key(_key: usize, _bytes: Vec<u8>) -> Option<Self> {
+        None
+    }
+
+    /// The raw content of the open type field, if the key of this value is unknown to the
+    /// object set
+    #[inline]
+    fn unknown_bytes(&self) -> Option<&[u8]> {
+        None
+    }
 }
 
 impl<C: Constraint> WritableType for OpenType<C> {
@@ -31,12 +52,62 @@ impl<C: Constraint> WritableType for OpenType<C> {
 impl<C: Constraint> ReadableType for OpenType<C> {
     type Type = C;
 
-    fn read_value<R: Reader>(_reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
-        panic!("not support----OpenType&ReadableType")
+    /// Without a key, no variant is selected and the reader fails
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        reader.read_open_type::<Self::Type>(None)
+    }
+
+    #[inline]
+    fn read_value_by_key<R: Reader>(
+        reader: &mut R,
+        key: usize,
+    ) -> Result<Self::Type, <R as Reader>::Error> {
+        reader.read_open_type::<Self::Type>(Some(key))
+    }
+}
+
+/// The value of the component that selects the variant of an open type through a component
+/// relation constraint (ITU-T X.682 | ISO/IEC 8824-3, 10.7)
+pub trait Key {
+    /// The key of the variant of the open type `C` that is selected by this value, if this value
+    /// can be a key of its object set
+    fn to_key<C: Constraint>(&self) -> Option<usize>;
+}
+
+/// INTEGER values select the variant keyed by the same value, negative values and values beyond
+/// [`usize::MAX`] cannot be keys and select no variant
+macro_rules! impl_key_for_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Key for $t {
+                #[inline]
+                fn to_key<C: Constraint>(&self) -> Option<usize> {
+                    usize::try_from(*self).ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_key_for_integer!(u8, u16, u32, u64, i8, i16, i32, i64, usize);
+
+/// ENUMERATED values select the variant keyed by their index
+impl<T: enumerated::Constraint> Key for T {
+    #[inline]
+    fn to_key<C: Constraint>(&self) -> Option<usize> {
+        Some(self.to_choice_index() as usize)
     }
+}
 
+/// OBJECT IDENTIFIER values select the variant keyed by the same value, values unknown to the
+/// object set select no variant
+impl Key for ObjectIdentifier {
     #[inline]
-    fn read_value_by_key<R: Reader>(reader: &mut R, key: usize) -> Result<Self::Type, <R as Reader>::Error> {
-        reader.read_open_type::<Self::Type>(key)
+    fn to_key<C: Constraint>(&self) -> Option<usize> {
+        C::OBJECT_IDENTIFIERS
+            .iter()
+            .position(|arcs| *arcs == self.arcs())
+            .and_then(|index| C::KEYS.get(index).copied())
     }
 }
@@ -0,0 +1,87 @@
+use crate::syn::objectidentifier::{parse_arcs, write_arcs};
+use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use asn1rs_model::model::Tag;
+use core::fmt::{Display, Formatter};
+use core::marker::PhantomData;
+use core::num::ParseIntError;
+use core::str::FromStr;
+
+pub struct RelativeOidT<C: Constraint = NoConstraint>(PhantomData<C>);
+
+pub trait Constraint: super::common::Constraint {}
+
+#[derive(Default)]
+pub struct NoConstraint;
+impl super::common::Constraint for NoConstraint {
+    const TAG: Tag = Tag::DEFAULT_RELATIVE_OID;
+}
+impl Constraint for NoConstraint {}
+
+impl<C: Constraint> WritableType for RelativeOidT<C> {
+    type Type = RelativeOid;
+
+    #[inline]
+    fn write_value<W: Writer>(
+        writer: &mut W,
+        value: &Self::Type,
+    ) -> Result<(), <W as Writer>::Error> {
+        writer.write_relative_oid::<C>(value)
+    }
+}
+
+impl<C: Constraint> ReadableType for RelativeOidT<C> {
+    type Type = RelativeOid;
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        reader.read_relative_oid::<C>()
+    }
+}
+
+/// The value of a `RELATIVE-OID`, the arcs relative to some known object identifier
+/// (ITU-T X.680, 33).
+#[derive(Default, Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct RelativeOid(Cow<'static, [u64]>);
+
+impl RelativeOid {
+    pub const fn from_static(arcs: &'static [u64]) -> Self {
+        Self(Cow::Borrowed(arcs))
+    }
+
+    pub fn new(arcs: Vec<u64>) -> Self {
+        Self(Cow::Owned(arcs))
+    }
+
+    pub fn arcs(&self) -> &[u64] {
+        &self.0
+    }
+}
+
+impl From<Vec<u64>> for RelativeOid {
+    fn from(arcs: Vec<u64>) -> Self {
+        Self::new(arcs)
+    }
+}
+
+impl From<RelativeOid> for Vec<u64> {
+    fn from(value: RelativeOid) -> Self {
+        value.0.into_owned()
+    }
+}
+
+/// Dot notation, as in `8571.3.2`
+impl Display for RelativeOid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write_arcs(f, self.arcs())
+    }
+}
+
+impl FromStr for RelativeOid {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_arcs(s).map(Self::new)
+    }
+}
@@ -8,6 +8,9 @@ pub trait Constraint: super::common::Constraint {
     const STD_OPTIONAL_FIELDS: u64;
     const FIELD_COUNT: u64;
     const EXTENDED_AFTER_FIELD: Option<u64>;
+    /// Whether this is not an actual SEQUENCE but a named wrapper around a single type
+    /// (`Name ::= Type`), which tag based encodings must not encode as SEQUENCE
+    const TRANSPARENT: bool = false;
 
     fn read_seq<R: Reader>(reader: &mut R) -> Result<Self, R::Error>
     where
@@ -1,5 +1,6 @@
 use crate::model::Tag;
 use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use alloc::vec::Vec;
 use core::marker::PhantomData;
 
 pub struct SequenceOf<T, C: Constraint = NoConstraint>(PhantomData<T>, PhantomData<C>);
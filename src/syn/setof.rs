@@ -3,6 +3,7 @@ use core::marker::PhantomData;
 
 pub use crate::syn::sequenceof::Constraint;
 pub use crate::syn::sequenceof::NoConstraint;
+use alloc::vec::Vec;
 
 pub struct SetOf<T, C: Constraint = NoConstraint>(PhantomData<T>, PhantomData<C>);
 
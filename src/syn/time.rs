@@ -0,0 +1,915 @@
+use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use alloc::format;
+use asn1rs_model::model::Tag;
+use core::convert::TryFrom;
+use core::fmt::{Display, Formatter};
+use core::marker::PhantomData;
+use core::str::FromStr;
+#[cfg(feature = "std")]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const NANOS_PER_SECOND: u32 = 1_000_000_000;
+const SECONDS_PER_DAY: i64 = 86_400;
+
+pub struct TimeT<T: TimeType, C: Constraint = NoConstraint<T>>(PhantomData<T>, PhantomData<C>);
+
+pub type GeneralizedTimeT<C = NoConstraint<GeneralizedTime>> = TimeT<GeneralizedTime, C>;
+pub type UtcTimeT<C = NoConstraint<UtcTime>> = TimeT<UtcTime, C>;
+pub type DateT<C = NoConstraint<Date>> = TimeT<Date, C>;
+pub type TimeOfDayT<C = NoConstraint<TimeOfDay>> = TimeT<TimeOfDay, C>;
+pub type DurationT<C = NoConstraint<Duration>> = TimeT<Duration, C>;
+
+pub trait Constraint: super::common::Constraint {}
+
+pub struct NoConstraint<T>(PhantomData<T>);
+impl<T: TimeType> super::common::Constraint for NoConstraint<T> {
+    const TAG: Tag = T::TAG;
+}
+impl<T: TimeType> Constraint for NoConstraint<T> {}
+
+/// The value of one of the time types, which all encoding rules transfer as the ISO 8601
+/// based notation of ITU-T X.680 | ISO/IEC 8824-1
+pub trait TimeType: Display + FromStr<Err = TimeError> {
+    const TAG: Tag;
+    /// The name of the type as in ITU-T X.680 | ISO/IEC 8824-1, 12.38
+    const NAME: &'static str;
+}
+
+impl<T: TimeType, C: Constraint> WritableType for TimeT<T, C> {
+    type Type = T;
+
+    #[inline]
+    fn write_value<W: Writer>(
+        writer: &mut W,
+        value: &Self::Type,
+    ) -> Result<(), <W as Writer>::Error> {
+        writer.write_time::<C, T>(value)
+    }
+}
+
+impl<T: TimeType, C: Constraint> ReadableType for TimeT<T, C> {
+    type Type = T;
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        reader.read_time::<C, T>()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeError(&'static str);
+
+impl TimeError {
+    pub fn reason(&self) -> &'static str {
+        self.0
+    }
+}
+
+impl Display for TimeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Invalid time value: {}", self.0)
+    }
+}
+
+impl core::error::Error for TimeError {}
+
+/// A calendar date and time of day in UTC with a precision of up to nanoseconds
+/// (ITU-T X.680 | ISO/IEC 8824-1, 46). Values that are read with a local time
+/// difference are converted to UTC, values without any time difference are taken as UTC.
+#[derive(
+    Debug, Default, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash, Serialize, Deserialize,
+)]
+pub struct GeneralizedTime {
+    seconds: i64,
+    nanos: u32,
+}
+
+impl GeneralizedTime {
+    /// The given nanoseconds must be less than one second
+    pub fn from_unix(seconds: i64, nanos: u32) -> Result<Self, TimeError> {
+        if nanos < NANOS_PER_SECOND {
+            Ok(Self { seconds, nanos })
+        } else {
+            Err(TimeError("nanoseconds exceed one second"))
+        }
+    }
+
+    pub fn unix_seconds(&self) -> i64 {
+        self.seconds
+    }
+
+    pub fn subsec_nanos(&self) -> u32 {
+        self.nanos
+    }
+}
+
+impl TimeType for GeneralizedTime {
+    const TAG: Tag = Tag::DEFAULT_GENERALIZED_TIME;
+    const NAME: &'static str = "GeneralizedTime";
+}
+
+#[cfg(feature = "std")]
+impl From<SystemTime> for GeneralizedTime {
+    fn from(time: SystemTime) -> Self {
+        let (seconds, nanos) = unix_from_system_time(time);
+        Self { seconds, nanos }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<GeneralizedTime> for SystemTime {
+    fn from(time: GeneralizedTime) -> Self {
+        system_time_from_unix(time.seconds, time.nanos)
+    }
+}
+
+/// The canonical form of ITU-T X.690 | ISO/IEC 8825-1, 11.7: `YYYYMMDDHHMMSS[.f]Z`
+impl Display for GeneralizedTime {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let (year, month, day) = civil_from_days(self.seconds.div_euclid(SECONDS_PER_DAY));
+        let (hour, minute, second) = time_from_seconds(self.seconds);
+        write!(
+            f,
+            "{:04}{:02}{:02}{:02}{:02}{:02}",
+            year, month, day, hour, minute, second
+        )?;
+        if self.nanos > 0 {
+            let fraction = format!("{:09}", self.nanos);
+            write!(f, ".{}", fraction.trim_end_matches('0'))?;
+        }
+        write!(f, "Z")
+    }
+}
+
+impl FromStr for GeneralizedTime {
+    type Err = TimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut text = Text(s.as_bytes());
+        let days = text.date(4)?;
+        let hour = text.number(2, 23)?;
+        let minute = if text.peek_digit() {
+            text.number(2, 59)?
+        } else {
+            0
+        };
+        let second = if text.peek_digit() {
+            Some(text.number(2, 59)?)
+        } else {
+            None
+        };
+        let nanos = match (second, text.starts_with_any(b".,")) {
+            (Some(_), true) => text.fraction()?,
+            (None, true) => return Err(TimeError("only seconds may have a fraction")),
+            (_, false) => 0,
+        };
+        let second = second.unwrap_or(0);
+        let difference = text.time_difference(false)?;
+        text.end()?;
+        Ok(Self {
+            seconds: days * SECONDS_PER_DAY + seconds_of_day(hour, minute, second) - difference,
+            nanos,
+        })
+    }
+}
+
+/// A calendar date and time of day in UTC with a precision of seconds and with a two-digit
+/// year, that covers the years 1950 to 2049 (ITU-T X.680 | ISO/IEC 8824-1, 47)
+#[derive(
+    Debug, Default, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash, Serialize, Deserialize,
+)]
+pub struct UtcTime {
+    seconds: i64,
+}
+
+impl UtcTime {
+    /// The seconds since `1970-01-01T00:00:00Z` of `1950-01-01T00:00:00Z`
+    pub const MIN_UNIX_SECONDS: i64 = -631_152_000;
+    /// The seconds since `1970-01-01T00:00:00Z` of `2049-12-31T23:59:59Z`
+    pub const MAX_UNIX_SECONDS: i64 = 2_524_607_999;
+
+    pub fn from_unix(seconds: i64) -> Result<Self, TimeError> {
+        if (Self::MIN_UNIX_SECONDS..=Self::MAX_UNIX_SECONDS).contains(&seconds) {
+            Ok(Self { seconds })
+        } else {
+            Err(TimeError("the year is not within 1950 and 2049"))
+        }
+    }
+
+    pub fn unix_seconds(&self) -> i64 {
+        self.seconds
+    }
+}
+
+impl TimeType for UtcTime {
+    const TAG: Tag = Tag::DEFAULT_UTC_TIME;
+    const NAME: &'static str = "UTCTime";
+}
+
+/// Fractions of a second are truncated
+#[cfg(feature = "std")]
+impl TryFrom<SystemTime> for UtcTime {
+    type Error = TimeError;
+
+    fn try_from(time: SystemTime) -> Result<Self, Self::Error> {
+        Self::from_unix(unix_from_system_time(time).0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<UtcTime> for SystemTime {
+    fn from(time: UtcTime) -> Self {
+        system_time_from_unix(time.seconds, 0)
+    }
+}
+
+/// The canonical form of ITU-T X.690 | ISO/IEC 8825-1, 11.8: `YYMMDDHHMMSSZ`
+impl Display for UtcTime {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let (year, month, day) = civil_from_days(self.seconds.div_euclid(SECONDS_PER_DAY));
+        let (hour, minute, second) = time_from_seconds(self.seconds);
+        write!(
+            f,
+            "{:02}{:02}{:02}{:02}{:02}{:02}Z",
+            year.rem_euclid(100),
+            month,
+            day,
+            hour,
+            minute,
+            second
+        )
+    }
+}
+
+impl FromStr for UtcTime {
+    type Err = TimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut text = Text(s.as_bytes());
+        let days = text.date(2)?;
+        let hour = text.number(2, 23)?;
+        let minute = text.number(2, 59)?;
+        let second = if text.peek_digit() {
+            text.number(2, 59)?
+        } else {
+            0
+        };
+        let difference = text.time_difference(true)?;
+        text.end()?;
+        Self::from_unix(days * SECONDS_PER_DAY + seconds_of_day(hour, minute, second) - difference)
+    }
+}
+
+/// A calendar date with a four-digit year (ITU-T X.680 | ISO/IEC 8824-1, 38.4.1)
+#[derive(
+    Debug, Default, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash, Serialize, Deserialize,
+)]
+pub struct Date {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    pub fn new(year: u16, month: u8, day: u8) -> Result<Self, TimeError> {
+        if year > 9999 {
+            Err(TimeError("the year exceeds four digits"))
+        } else if !(1..=12).contains(&month) {
+            Err(TimeError("invalid month"))
+        } else if day < 1 || u32::from(day) > days_in_month(i64::from(year), u32::from(month)) {
+            Err(TimeError("invalid day of the month"))
+        } else {
+            Ok(Self { year, month, day })
+        }
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+}
+
+impl TimeType for Date {
+    const TAG: Tag = Tag::DEFAULT_DATE;
+    const NAME: &'static str = "DATE";
+}
+
+/// The date of the given point in time in UTC
+#[cfg(feature = "std")]
+impl TryFrom<SystemTime> for Date {
+    type Error = TimeError;
+
+    fn try_from(time: SystemTime) -> Result<Self, Self::Error> {
+        let (seconds, _) = unix_from_system_time(time);
+        let (year, month, day) = civil_from_days(seconds.div_euclid(SECONDS_PER_DAY));
+        let year = u16::try_from(year).map_err(|_| TimeError("the year exceeds four digits"))?;
+        Self::new(year, month as u8, day as u8)
+    }
+}
+
+/// The start of the day in UTC
+#[cfg(feature = "std")]
+impl From<Date> for SystemTime {
+    fn from(date: Date) -> Self {
+        let days = days_from_civil(
+            i64::from(date.year),
+            u32::from(date.month),
+            u32::from(date.day),
+        );
+        system_time_from_unix(days * SECONDS_PER_DAY, 0)
+    }
+}
+
+/// `YYYY-MM-DD`
+impl Display for Date {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl FromStr for Date {
+    type Err = TimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut text = Text(s.as_bytes());
+        let year = text.number(4, 9999)?;
+        text.expect(b'-')?;
+        let month = text.number(2, 12)?;
+        text.expect(b'-')?;
+        let day = text.number(2, 31)?;
+        text.end()?;
+        Self::new(year as u16, month as u8, day as u8)
+    }
+}
+
+/// A local time of day with a precision of seconds (ITU-T X.680 | ISO/IEC 8824-1, 38.4.2)
+#[derive(
+    Debug, Default, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash, Serialize, Deserialize,
+)]
+pub struct TimeOfDay {
+    hour: u8,
+    minute: u8,
+    second: u8,
+}
+
+impl TimeOfDay {
+    pub fn new(hour: u8, minute: u8, second: u8) -> Result<Self, TimeError> {
+        if hour > 23 || minute > 59 || second > 59 {
+            Err(TimeError("invalid time of day"))
+        } else {
+            Ok(Self {
+                hour,
+                minute,
+                second,
+            })
+        }
+    }
+
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    pub fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    pub fn second(&self) -> u8 {
+        self.second
+    }
+}
+
+impl TimeType for TimeOfDay {
+    const TAG: Tag = Tag::DEFAULT_TIME_OF_DAY;
+    const NAME: &'static str = "TIME-OF-DAY";
+}
+
+/// The time of day of the given point in time in UTC, fractions of a second are truncated
+#[cfg(feature = "std")]
+impl From<SystemTime> for TimeOfDay {
+    fn from(time: SystemTime) -> Self {
+        let (hour, minute, second) = time_from_seconds(unix_from_system_time(time).0);
+        Self {
+            hour: hour as u8,
+            minute: minute as u8,
+            second: second as u8,
+        }
+    }
+}
+
+/// The time since midnight
+impl From<TimeOfDay> for core::time::Duration {
+    fn from(time: TimeOfDay) -> Self {
+        let (hour, minute, second) = (time.hour, time.minute, time.second);
+        let seconds = seconds_of_day(u32::from(hour), u32::from(minute), u32::from(second));
+        core::time::Duration::from_secs(seconds as u64)
+    }
+}
+
+/// `HH:MM:SS`
+impl Display for TimeOfDay {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second)
+    }
+}
+
+impl FromStr for TimeOfDay {
+    type Err = TimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut text = Text(s.as_bytes());
+        let hour = text.number(2, 23)?;
+        text.expect(b':')?;
+        let minute = text.number(2, 59)?;
+        text.expect(b':')?;
+        let second = text.number(2, 59)?;
+        text.end()?;
+        Self::new(hour as u8, minute as u8, second as u8)
+    }
+}
+
+/// A duration in the notation `PnYnMnDTnHnMnS` (ITU-T X.680 | ISO/IEC 8824-1, 38.4.4), where
+/// weeks (`PnW`) are read as days. Only the seconds have a fraction.
+#[derive(
+    Debug, Default, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash, Serialize, Deserialize,
+)]
+pub struct Duration {
+    pub years: u32,
+    pub months: u32,
+    pub days: u32,
+    pub hours: u32,
+    pub minutes: u32,
+    pub seconds: u32,
+    /// The fraction of the seconds, less than one second
+    pub nanos: u32,
+}
+
+impl TimeType for Duration {
+    const TAG: Tag = Tag::DEFAULT_DURATION;
+    const NAME: &'static str = "DURATION";
+}
+
+/// Splits the given duration into days, hours, minutes and seconds
+impl From<core::time::Duration> for Duration {
+    fn from(duration: core::time::Duration) -> Self {
+        let seconds = duration.as_secs();
+        Self {
+            years: 0,
+            months: 0,
+            days: (seconds / SECONDS_PER_DAY as u64) as u32,
+            hours: (seconds / 3600 % 24) as u32,
+            minutes: (seconds / 60 % 60) as u32,
+            seconds: (seconds % 60) as u32,
+            nanos: duration.subsec_nanos(),
+        }
+    }
+}
+
+/// Fails for durations in years or months, because these are of no fixed length
+impl TryFrom<Duration> for core::time::Duration {
+    type Error = TimeError;
+
+    fn try_from(duration: Duration) -> Result<Self, Self::Error> {
+        if duration.years != 0 || duration.months != 0 {
+            Err(TimeError("years and months are of no fixed length"))
+        } else {
+            let seconds = u64::from(duration.days) * SECONDS_PER_DAY as u64
+                + u64::from(duration.hours) * 3600
+                + u64::from(duration.minutes) * 60
+                + u64::from(duration.seconds);
+            Ok(core::time::Duration::from_secs(seconds)
+                + core::time::Duration::from_nanos(u64::from(duration.nanos)))
+        }
+    }
+}
+
+impl Display for Duration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "P")?;
+        for (value, designator) in &[(self.years, 'Y'), (self.months, 'M'), (self.days, 'D')] {
+            if *value > 0 {
+                write!(f, "{}{}", value, designator)?;
+            }
+        }
+        let date_written = self.years > 0 || self.months > 0 || self.days > 0;
+        if self.hours > 0 || self.minutes > 0 || self.seconds > 0 || self.nanos > 0 {
+            write!(f, "T")?;
+            if self.hours > 0 {
+                write!(f, "{}H", self.hours)?;
+            }
+            if self.minutes > 0 {
+                write!(f, "{}M", self.minutes)?;
+            }
+            if self.seconds > 0 || self.nanos > 0 {
+                write!(f, "{}", self.seconds)?;
+                if self.nanos > 0 {
+                    let fraction = format!("{:09}", self.nanos);
+                    write!(f, ".{}", fraction.trim_end_matches('0'))?;
+                }
+                write!(f, "S")?;
+            }
+        } else if !date_written {
+            write!(f, "T0S")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Duration {
+    type Err = TimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut text = Text(s.as_bytes());
+        text.expect(b'P')?;
+        let mut duration = Duration::default();
+        let mut time = false;
+        let mut components = 0;
+        // the designators in the order they must appear, split by the time designator 'T'
+        let mut expected: &[u8] = b"YMWD";
+        while !text.0.is_empty() {
+            if !time && text.starts_with_any(b"T") {
+                text.0 = &text.0[1..];
+                time = true;
+                expected = b"HMS";
+                continue;
+            }
+            let value = text.unbounded_number()?;
+            let nanos = if text.starts_with_any(b".,") {
+                text.fraction()?
+            } else {
+                0
+            };
+            let designator = text
+                .0
+                .first()
+                .copied()
+                .ok_or(TimeError("missing designator"))?;
+            text.0 = &text.0[1..];
+            let position = expected
+                .iter()
+                .position(|d| *d == designator)
+                .ok_or(TimeError("unexpected designator"))?;
+            expected = &expected[position + 1..];
+            if nanos > 0 && designator != b'S' {
+                return Err(TimeError("only seconds may have a fraction"));
+            }
+            match (time, designator) {
+                (false, b'Y') => duration.years = value,
+                (false, b'M') => duration.months = value,
+                (false, b'W') => duration.days = value.saturating_mul(7),
+                (false, b'D') => duration.days = duration.days.saturating_add(value),
+                (true, b'H') => duration.hours = value,
+                (true, b'M') => duration.minutes = value,
+                (_, _) => {
+                    duration.seconds = value;
+                    duration.nanos = nanos;
+                }
+            }
+            components += 1;
+        }
+        if components == 0 {
+            Err(TimeError("a duration consists of at least one component"))
+        } else {
+            Ok(duration)
+        }
+    }
+}
+
+/// The remaining characters of a time value that is being parsed
+struct Text<'a>(&'a [u8]);
+
+impl Text<'_> {
+    fn peek_digit(&self) -> bool {
+        self.0.first().is_some_and(u8::is_ascii_digit)
+    }
+
+    fn starts_with_any(&self, candidates: &[u8]) -> bool {
+        self.0.first().is_some_and(|c| candidates.contains(c))
+    }
+
+    fn expect(&mut self, character: u8) -> Result<(), TimeError> {
+        if self.0.first() == Some(&character) {
+            self.0 = &self.0[1..];
+            Ok(())
+        } else {
+            Err(TimeError("unexpected character"))
+        }
+    }
+
+    fn end(&self) -> Result<(), TimeError> {
+        if self.0.is_empty() {
+            Ok(())
+        } else {
+            Err(TimeError("unexpected trailing characters"))
+        }
+    }
+
+    /// Reads exactly `digits` digits of a number that must not exceed `max`
+    fn number(&mut self, digits: usize, max: u32) -> Result<u32, TimeError> {
+        match self.0.get(..digits) {
+            Some(slice) if slice.iter().all(u8::is_ascii_digit) => {
+                self.0 = &self.0[digits..];
+                let value = slice
+                    .iter()
+                    .fold(0, |value, digit| value * 10 + u32::from(digit - b'0'));
+                if value <= max {
+                    Ok(value)
+                } else {
+                    Err(TimeError("a component exceeds its range"))
+                }
+            }
+            _ => Err(TimeError("expected digits")),
+        }
+    }
+
+    fn unbounded_number(&mut self) -> Result<u32, TimeError> {
+        let digits = self.0.iter().take_while(|c| c.is_ascii_digit()).count();
+        if digits == 0 {
+            return Err(TimeError("expected digits"));
+        }
+        let (number, remaining) = self.0.split_at(digits);
+        self.0 = remaining;
+        number.iter().try_fold(0_u32, |value, digit| {
+            value
+                .checked_mul(10)
+                .and_then(|value| value.checked_add(u32::from(digit - b'0')))
+                .ok_or(TimeError("a component exceeds its range"))
+        })
+    }
+
+    /// Reads the decimal sign and the digits of a fraction of a second into nanoseconds,
+    /// where digits beyond nanoseconds are truncated
+    fn fraction(&mut self) -> Result<u32, TimeError> {
+        self.0 = &self.0[1..];
+        let digits = self.0.iter().take_while(|c| c.is_ascii_digit()).count();
+        if digits == 0 {
+            return Err(TimeError("expected digits of the fraction"));
+        }
+        let nanos = (0..9).fold(0, |nanos, index| {
+            nanos * 10
+                + self
+                    .0
+                    .get(index)
+                    .filter(|_| index < digits)
+                    .map_or(0, |d| u32::from(d - b'0'))
+        });
+        self.0 = &self.0[digits..];
+        Ok(nanos)
+    }
+
+    /// Reads the date in the basic format `YYYYMMDD` or `YYMMDD` into the days since
+    /// `1970-01-01`, where a two-digit year is within 1950 and 2049
+    fn date(&mut self, year_digits: usize) -> Result<i64, TimeError> {
+        let year = i64::from(self.number(year_digits, 9999)?);
+        let year = match (year_digits, year) {
+            (2, year) if year >= 50 => 1900 + year,
+            (2, year) => 2000 + year,
+            (_, year) => year,
+        };
+        let month = self.number(2, 12)?;
+        let day = self.number(2, 31)?;
+        if month < 1 || day < 1 || day > days_in_month(year, month) {
+            Err(TimeError("invalid date"))
+        } else {
+            Ok(days_from_civil(year, month, day))
+        }
+    }
+
+    /// Reads the optional `Z` or the local time difference `+HHMM` / `-HHMM` in seconds
+    fn time_difference(&mut self, required: bool) -> Result<i64, TimeError> {
+        match self.0.first() {
+            Some(b'Z') => {
+                self.0 = &self.0[1..];
+                Ok(0)
+            }
+            Some(sign @ b'+') | Some(sign @ b'-') => {
+                let sign = if *sign == b'-' { -1 } else { 1 };
+                self.0 = &self.0[1..];
+                let hours = self.number(2, 23)?;
+                let minutes = if self.peek_digit() {
+                    self.number(2, 59)?
+                } else {
+                    0
+                };
+                Ok(sign * seconds_of_day(hours, minutes, 0))
+            }
+            _ if required => Err(TimeError("missing time difference")),
+            _ => Ok(0),
+        }
+    }
+}
+
+#[inline]
+fn seconds_of_day(hour: u32, minute: u32, second: u32) -> i64 {
+    i64::from(hour) * 3600 + i64::from(minute) * 60 + i64::from(second)
+}
+
+#[inline]
+fn time_from_seconds(seconds: i64) -> (i64, i64, i64) {
+    let seconds = seconds.rem_euclid(SECONDS_PER_DAY);
+    (seconds / 3600, seconds / 60 % 60, seconds % 60)
+}
+
+fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// The days since `1970-01-01` of the given date in the proleptic Gregorian calendar
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * i64::from((month + 9) % 12) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// The date in the proleptic Gregorian calendar of the given days since `1970-01-01`
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_from_march + 2) / 5 + 1) as u32;
+    let month = if month_from_march < 10 {
+        month_from_march + 3
+    } else {
+        month_from_march - 9
+    } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(feature = "std")]
+fn unix_from_system_time(time: SystemTime) -> (i64, u32) {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(duration) => (duration.as_secs() as i64, duration.subsec_nanos()),
+        Err(e) => {
+            let duration = e.duration();
+            let seconds = -(duration.as_secs() as i64);
+            match duration.subsec_nanos() {
+                0 => (seconds, 0),
+                nanos => (seconds - 1, NANOS_PER_SECOND - nanos),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+fn system_time_from_unix(seconds: i64, nanos: u32) -> SystemTime {
+    let nanos = core::time::Duration::from_nanos(u64::from(nanos));
+    if seconds >= 0 {
+        UNIX_EPOCH + core::time::Duration::from_secs(seconds as u64) + nanos
+    } else {
+        UNIX_EPOCH - core::time::Duration::from_secs(seconds.unsigned_abs()) + nanos
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn test_civil_days() {
+        assert_eq!(0, days_from_civil(1970, 1, 1));
+        assert_eq!((1970, 1, 1), civil_from_days(0));
+        assert_eq!(-719_468, days_from_civil(0, 3, 1));
+        for days in (-800_000..800_000).step_by(97) {
+            let (year, month, day) = civil_from_days(days);
+            assert_eq!(days, days_from_civil(year, month, day));
+        }
+    }
+
+    #[test]
+    fn test_generalized_time() {
+        let time = "19851106210627.3Z".parse::<GeneralizedTime>().unwrap();
+        assert_eq!(500_159_187, time.unix_seconds());
+        assert_eq!(300_000_000, time.subsec_nanos());
+        assert_eq!("19851106210627.3Z", time.to_string());
+        assert_eq!(
+            time,
+            "19851106160627,3-0500".parse::<GeneralizedTime>().unwrap()
+        );
+        assert_eq!(
+            GeneralizedTime::from_unix(500_159_160, 0).unwrap(),
+            "198511062106".parse::<GeneralizedTime>().unwrap()
+        );
+        assert_eq!(
+            "19691231235959Z",
+            GeneralizedTime::from_unix(-1, 0).unwrap().to_string()
+        );
+        assert!("1985110621.5Z".parse::<GeneralizedTime>().is_err());
+        assert!("19850229000000Z".parse::<GeneralizedTime>().is_err());
+        assert!("19851106246000Z".parse::<GeneralizedTime>().is_err());
+        assert!("19851106210627Zx".parse::<GeneralizedTime>().is_err());
+    }
+
+    #[test]
+    fn test_generalized_time_system_time() {
+        for (seconds, nanos) in &[
+            (0, 0),
+            (1_600_000_000, 123),
+            (-1, 999_999_999),
+            (-86_401, 5),
+        ] {
+            let time = GeneralizedTime::from_unix(*seconds, *nanos).unwrap();
+            assert_eq!(time, GeneralizedTime::from(SystemTime::from(time)));
+        }
+    }
+
+    #[test]
+    fn test_utc_time() {
+        let time = "8511062106Z".parse::<UtcTime>().unwrap();
+        assert_eq!(500_159_160, time.unix_seconds());
+        assert_eq!("851106210600Z", time.to_string());
+        assert_eq!(time, "851106230600+0200".parse::<UtcTime>().unwrap());
+        assert_eq!(
+            "491231235959Z",
+            UtcTime::from_unix(UtcTime::MAX_UNIX_SECONDS)
+                .unwrap()
+                .to_string()
+        );
+        assert_eq!(
+            "500101000000Z",
+            UtcTime::from_unix(UtcTime::MIN_UNIX_SECONDS)
+                .unwrap()
+                .to_string()
+        );
+        assert!(UtcTime::from_unix(UtcTime::MAX_UNIX_SECONDS + 1).is_err());
+        assert!("851106210600".parse::<UtcTime>().is_err());
+    }
+
+    #[test]
+    fn test_date_and_time_of_day() {
+        let date = "2020-02-29".parse::<Date>().unwrap();
+        assert_eq!((2020, 2, 29), (date.year(), date.month(), date.day()));
+        assert_eq!("2020-02-29", date.to_string());
+        assert_eq!(date, Date::try_from(SystemTime::from(date)).unwrap());
+        assert!("2021-02-29".parse::<Date>().is_err());
+        assert!("2021-2-28".parse::<Date>().is_err());
+
+        let time = "23:05:09".parse::<TimeOfDay>().unwrap();
+        assert_eq!("23:05:09", time.to_string());
+        assert_eq!(
+            core::time::Duration::from_secs(83_109),
+            core::time::Duration::from(time)
+        );
+        assert_eq!(
+            time,
+            TimeOfDay::from(UNIX_EPOCH + core::time::Duration::from_secs(83_109 + 86_400))
+        );
+        assert!("24:00:00".parse::<TimeOfDay>().is_err());
+    }
+
+    #[test]
+    fn test_duration() {
+        let duration = "P1Y2M3DT4H5M6.5S".parse::<Duration>().unwrap();
+        assert_eq!(
+            Duration {
+                years: 1,
+                months: 2,
+                days: 3,
+                hours: 4,
+                minutes: 5,
+                seconds: 6,
+                nanos: 500_000_000,
+            },
+            duration
+        );
+        assert_eq!("P1Y2M3DT4H5M6.5S", duration.to_string());
+        assert!(core::time::Duration::try_from(duration).is_err());
+
+        let duration = Duration::from(core::time::Duration::from_millis(90_061_250));
+        assert_eq!("P1DT1H1M1.25S", duration.to_string());
+        assert_eq!(
+            core::time::Duration::from_millis(90_061_250),
+            core::time::Duration::try_from(duration).unwrap()
+        );
+
+        assert_eq!(14, "P2W".parse::<Duration>().unwrap().days);
+        assert_eq!("PT0S", Duration::default().to_string());
+        assert_eq!("PT0S", "PT0S".parse::<Duration>().unwrap().to_string());
+        assert!("P".parse::<Duration>().is_err());
+        assert!("PT1D".parse::<Duration>().is_err());
+        assert!("P1M1Y".parse::<Duration>().is_err());
+        assert!("P1.5D".parse::<Duration>().is_err());
+    }
+}
@@ -0,0 +1,74 @@
+use alloc::vec::Vec;
+/// The extension additions of an extensible SEQUENCE or SET that are unknown to the schema the
+/// type was generated from - as found by the reader after all known extension additions. Each
+/// entry corresponds to one presence bit of the extension additions and holds the raw content of
+/// the open type field, if present (ITU-T X.691 | ISO/IEC 8825-2:2015, 19.7 - 19.9).
+///
+/// Only [`UperReader`] and [`UperWriter`] fill and re-emit these, all other encodings skip them.
+///
+/// [`UperReader`]: crate::syn::io::UperReader
+/// [`UperWriter`]: crate::syn::io::UperWriter
+#[derive(Default, Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct UnknownExtensions(Vec<Option<Vec<u8>>>);
+
+impl UnknownExtensions {
+    pub fn new(fields: Vec<Option<Vec<u8>>>) -> Self {
+        Self(fields)
+    }
+
+    /// The number of unknown extension additions, including the absent ones
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Whether any of the unknown extension additions is present
+    pub fn any_present(&self) -> bool {
+        self.0.iter().any(Option::is_some)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Option<&[u8]>> {
+        self.0.iter().map(Option::as_deref)
+    }
+
+    pub fn push(&mut self, field: Option<Vec<u8>>) {
+        self.0.push(field)
+    }
+}
+
+impl From<Vec<Option<Vec<u8>>>> for UnknownExtensions {
+    fn from(fields: Vec<Option<Vec<u8>>>) -> Self {
+        Self::new(fields)
+    }
+}
+
+/// An alternative of an extensible CHOICE that is unknown to the schema the type was generated
+/// from, consisting of its choice index and the raw content of the open type field
+/// (ITU-T X.691 | ISO/IEC 8825-2:2015, 23.8).
+///
+/// Only [`UperReader`] and [`UperWriter`] read and re-emit these.
+///
+/// [`UperReader`]: crate::syn::io::UperReader
+/// [`UperWriter`]: crate::syn::io::UperWriter
+#[derive(Default, Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct UnknownExtension {
+    index: u64,
+    content: Vec<u8>,
+}
+
+impl UnknownExtension {
+    pub fn new(index: u64, content: Vec<u8>) -> Self {
+        Self { index, content }
+    }
+
+    pub const fn index(&self) -> u64 {
+        self.index
+    }
+
+    pub fn content(&self) -> &[u8] {
+        &self.content
+    }
+}
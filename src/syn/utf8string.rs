@@ -1,5 +1,6 @@
 use crate::model::Tag;
 use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use alloc::string::String;
 use core::marker::PhantomData;
 
 pub struct Utf8String<C: Constraint = NoConstraint>(PhantomData<C>);
@@ -8,6 +9,7 @@ pub trait Constraint: super::common::Constraint {
     const MIN: Option<u64> = None;
     const MAX: Option<u64> = None;
     const EXTENSIBLE: bool = false;
+    const PERMITTED_ALPHABET: Option<&'static [(char, char)]> = None;
 }
 
 #[derive(Default)]
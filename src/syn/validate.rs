@@ -0,0 +1,225 @@
+use crate::model::{Charset, PresenceConstraint};
+use crate::syn::enumerated;
+use crate::syn::numbers::Number;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Checks the constraints of a value - and of all its components - independent of any encoding,
+/// for example before storing it to SQL or after reading it from protobuf, which both ignore most
+/// constraints. See [`ValidationWriter`] for what is checked.
+///
+/// [`ValidationWriter`]: crate::syn::io::ValidationWriter
+pub trait Validate {
+    /// Returns all violated constraints, or `Ok(())` if there are none
+    fn validate(&self) -> Result<(), Violations>;
+}
+
+/// A violated constraint, where `path` consists of the identifiers of the components that lead to
+/// the offending value, separated by `.`, and the index of elements of `SEQUENCE OF` and `SET OF`
+/// values in brackets - for example `items[2].name`. The path is empty for the validated value
+/// itself.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    pub path: String,
+    pub kind: ViolationKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ViolationKind {
+    /// The value, the lower and the upper bound
+    ValueNotInRange(i64, i64, i64),
+    /// The size, the lower and the upper bound
+    SizeNotInRange(u64, u64, u64),
+    /// The invalid character and its index
+    InvalidString(Charset, char, usize),
+    /// The character that is not in the permitted alphabet and its index
+    CharacterNotPermitted(char, usize),
+    /// The component is absent, although it is constrained to be present
+    ComponentNotPresent,
+    /// The component is present, although it is constrained to be absent
+    ComponentNotAbsent,
+    /// The value of the component is not permitted by its inner type constraint
+    ValueNotPermitted,
+}
+
+impl ViolationKind {
+    /// The violation of the given presence constraint of a component, if any
+    pub fn of_presence(constraint: PresenceConstraint, present: bool) -> Option<Self> {
+        match constraint {
+            PresenceConstraint::Present if !present => Some(Self::ComponentNotPresent),
+            PresenceConstraint::Absent if present => Some(Self::ComponentNotAbsent),
+            _ => None,
+        }
+    }
+}
+
+impl core::fmt::Display for ViolationKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::ValueNotInRange(value, min, max) => write!(
+                f,
+                "The value {} is not within the inclusive range of {} and {}",
+                value, min, max
+            ),
+            Self::SizeNotInRange(size, min, max) => write!(
+                f,
+                "The size {} is not within the inclusive range of {} and {}",
+                size, min, max
+            ),
+            Self::InvalidString(charset, char, index) => write!(
+                f,
+                "The character {:?} at index {} is not valid for {:?}",
+                char, index, charset
+            ),
+            Self::CharacterNotPermitted(char, index) => write!(
+                f,
+                "The character {:?} at index {} is not in the permitted alphabet",
+                char, index
+            ),
+            Self::ComponentNotPresent => write!(f, "The component has to be present"),
+            Self::ComponentNotAbsent => write!(f, "The component has to be absent"),
+            Self::ValueNotPermitted => write!(
+                f,
+                "The value is not permitted by the constraint of the component"
+            ),
+        }
+    }
+}
+
+impl core::fmt::Display for Violation {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.kind)
+        } else {
+            write!(f, "{}: {}", self.path, self.kind)
+        }
+    }
+}
+
+/// All violated constraints of a value, in the order of its components
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violations(pub Vec<Violation>);
+
+impl Violations {
+    pub fn iter(&self) -> impl Iterator<Item = &Violation> {
+        self.0.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The violation at the given path, if any
+    pub fn at(&self, path: &str) -> Option<&ViolationKind> {
+        self.0.iter().find(|v| v.path == path).map(|v| &v.kind)
+    }
+}
+
+impl core::fmt::Display for Violations {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (index, violation) in self.0.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", violation)?;
+        }
+        Ok(())
+    }
+}
+
+impl core::error::Error for Violations {}
+
+impl IntoIterator for Violations {
+    type Item = Violation;
+    type IntoIter = alloc::vec::IntoIter<Violation>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+/// The integer value of a - possibly `OPTIONAL` - component, see [`is_in_range`]
+pub trait IntegerComponent {
+    fn integer_value(&self) -> Option<i64>;
+}
+
+impl<T: Number> IntegerComponent for T {
+    #[inline]
+    fn integer_value(&self) -> Option<i64> {
+        Some(self.to_i64())
+    }
+}
+
+impl<T: Number> IntegerComponent for Option<T> {
+    #[inline]
+    fn integer_value(&self) -> Option<i64> {
+        self.map(Number::to_i64)
+    }
+}
+
+/// The boolean value of a - possibly `OPTIONAL` - component, see [`is_boolean`]
+pub trait BooleanComponent {
+    fn boolean_value(&self) -> Option<bool>;
+}
+
+impl BooleanComponent for bool {
+    #[inline]
+    fn boolean_value(&self) -> Option<bool> {
+        Some(*self)
+    }
+}
+
+impl BooleanComponent for Option<bool> {
+    #[inline]
+    fn boolean_value(&self) -> Option<bool> {
+        *self
+    }
+}
+
+/// The identifier of the variant of a - possibly `OPTIONAL` - `ENUMERATED` component, see
+/// [`is_variant`]
+pub trait EnumeratedComponent {
+    /// `None` if the component is absent, `Some(None)` for unknown extension variants
+    fn variant_identifier(&self) -> Option<Option<&'static str>>;
+}
+
+impl<C: enumerated::Constraint> EnumeratedComponent for C {
+    #[inline]
+    fn variant_identifier(&self) -> Option<Option<&'static str>> {
+        Some(C::IDENTIFIERS.get(self.to_choice_index() as usize).copied())
+    }
+}
+
+impl<C: enumerated::Constraint> EnumeratedComponent for Option<C> {
+    #[inline]
+    fn variant_identifier(&self) -> Option<Option<&'static str>> {
+        self.as_ref()
+            .and_then(EnumeratedComponent::variant_identifier)
+    }
+}
+
+/// Whether the component is absent or its value within the inclusive range of `min` and `max`
+#[inline]
+pub fn is_in_range<T: IntegerComponent>(value: &T, min: i64, max: i64) -> bool {
+    value
+        .integer_value()
+        .is_none_or(|value| min <= value && value <= max)
+}
+
+/// Whether the component is absent or has the given value
+#[inline]
+pub fn is_boolean<T: BooleanComponent>(value: &T, expected: bool) -> bool {
+    value.boolean_value().is_none_or(|value| value == expected)
+}
+
+/// Whether the component is absent or is the variant with the given identifier
+#[inline]
+pub fn is_variant<T: EnumeratedComponent>(value: &T, identifier: &str) -> bool {
+    value
+        .variant_identifier()
+        .is_none_or(|variant| variant == Some(identifier))
+}
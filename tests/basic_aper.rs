@@ -0,0 +1,86 @@
+mod test_utils;
+
+use test_utils::*;
+
+asn_to_rust!(
+    r"BasicAper DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Pdu ::= SEQUENCE {
+        flag BOOLEAN,
+        id INTEGER (0..65535),
+        name IA5String (SIZE(1..16)),
+        payload OCTET STRING,
+        opt INTEGER (0..7) OPTIONAL
+    }
+
+    Extensible ::= SEQUENCE {
+        abc INTEGER (0..3),
+        ...,
+        def BOOLEAN OPTIONAL
+    }
+
+    Selection ::= CHOICE {
+        abc INTEGER (0..255),
+        def BOOLEAN
+    }
+
+    Code ::= NumericString (SIZE(3))
+
+    END"
+);
+
+#[test]
+fn test_pdu() {
+    serialize_and_deserialize_aper(
+        75,
+        &[0xC0, 0x01, 0x02, 0x10, 0x61, 0x62, 0x02, 0xDE, 0xAD, 0xA0],
+        &Pdu {
+            flag: true,
+            id: 0x0102,
+            name: "ab".to_string(),
+            payload: vec![0xDE, 0xAD],
+            opt: Some(5),
+        },
+    );
+}
+
+#[test]
+fn test_pdu_differs_from_uper() {
+    let pdu = Pdu {
+        flag: false,
+        id: 1,
+        name: "a".to_string(),
+        payload: Vec::new(),
+        opt: None,
+    };
+    assert_ne!(serialize_uper(&pdu), serialize_aper(&pdu));
+}
+
+#[test]
+fn test_extensible_with_extension() {
+    serialize_and_deserialize_aper(
+        32,
+        &[0xC0, 0x20, 0x01, 0x80],
+        &Extensible {
+            abc: 2,
+            def: Some(true),
+        },
+    );
+}
+
+#[test]
+fn test_extensible_without_extension() {
+    serialize_and_deserialize_aper(3, &[0x40], &Extensible { abc: 2, def: None });
+}
+
+#[test]
+fn test_choice() {
+    serialize_and_deserialize_aper(16, &[0x00, 0x42], &Selection::Abc(0x42));
+    serialize_and_deserialize_aper(2, &[0xC0], &Selection::Def(true));
+}
+
+#[test]
+fn test_numeric_string_fixed_size_is_not_aligned() {
+    serialize_and_deserialize_aper(12, &[0x23, 0x40], &Code("123".to_string()));
+}
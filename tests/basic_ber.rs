@@ -0,0 +1,222 @@
+mod test_utils;
+
+use asn1rs::io::ber::ErrorKind;
+use asn1rs::model::Tag;
+use test_utils::*;
+
+asn_to_rust!(
+    r"BasicBer DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Pdu ::= SEQUENCE {
+        flag BOOLEAN,
+        id INTEGER (0..65535),
+        name IA5String (SIZE(1..16)),
+        payload OCTET STRING,
+        opt INTEGER (0..7) OPTIONAL
+    }
+
+    Extensible ::= SEQUENCE {
+        abc INTEGER (0..3),
+        ...,
+        def BOOLEAN OPTIONAL
+    }
+
+    Selection ::= CHOICE {
+        abc INTEGER (0..255),
+        def BOOLEAN
+    }
+
+    Wrapper ::= SEQUENCE {
+        selection Selection,
+        count INTEGER
+    }
+
+    Unordered ::= SET {
+        b BOOLEAN,
+        a INTEGER
+    }
+
+    Numbers ::= SET OF INTEGER
+
+    WithDefault ::= SEQUENCE {
+        a INTEGER DEFAULT 5,
+        b BOOLEAN
+    }
+
+    Code ::= NumericString (SIZE(3))
+
+    Blob ::= OCTET STRING
+
+    Flags ::= BIT STRING
+
+    END"
+);
+
+fn pdu() -> Pdu {
+    Pdu {
+        flag: true,
+        id: 0x0102,
+        name: "ab".to_string(),
+        payload: vec![0xDE, 0xAD],
+        opt: Some(5),
+    }
+}
+
+#[test]
+fn test_pdu() {
+    serialize_and_deserialize_der(
+        &[
+            0x30, 0x12, 0x80, 0x01, 0xFF, 0x81, 0x02, 0x01, 0x02, 0x82, 0x02, 0x61, 0x62, 0x83,
+            0x02, 0xDE, 0xAD, 0x84, 0x01, 0x05,
+        ],
+        &pdu(),
+    );
+}
+
+#[test]
+fn test_pdu_without_optional() {
+    serialize_and_deserialize_der(
+        &[
+            0x30, 0x0F, 0x80, 0x01, 0x00, 0x81, 0x02, 0x01, 0x02, 0x82, 0x02, 0x61, 0x62, 0x83,
+            0x02, 0xDE, 0xAD,
+        ],
+        &Pdu {
+            flag: false,
+            opt: None,
+            ..pdu()
+        },
+    );
+}
+
+#[test]
+fn test_pdu_indefinite_length_and_constructed_string() {
+    assert_eq!(
+        pdu(),
+        deserialize_ber::<Pdu>(&[
+            0x30, 0x80, 0x80, 0x01, 0xFF, 0x81, 0x02, 0x01, 0x02, 0x82, 0x02, 0x61, 0x62, 0xA3,
+            0x80, 0x04, 0x01, 0xDE, 0x04, 0x01, 0xAD, 0x00, 0x00, 0x84, 0x01, 0x05, 0x00, 0x00,
+        ])
+    );
+}
+
+#[test]
+fn test_choice() {
+    serialize_and_deserialize_der(&[0x80, 0x01, 0x42], &Selection::Abc(0x42));
+    serialize_and_deserialize_der(&[0x81, 0x01, 0xFF], &Selection::Def(true));
+}
+
+#[test]
+fn test_choice_unexpected_tag() {
+    let error = Selection::decode_from_ber(&[0x82, 0x01, 0x00]).unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        ErrorKind::UnexpectedTag {
+            found: Tag::ContextSpecific(2),
+            offset: 0,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn test_choice_is_explicitly_tagged_in_sequence() {
+    serialize_and_deserialize_der(
+        &[0x30, 0x08, 0xA0, 0x03, 0x80, 0x01, 0x42, 0x81, 0x01, 0x03],
+        &Wrapper {
+            selection: Selection::Abc(0x42),
+            count: 3,
+        },
+    );
+}
+
+#[test]
+fn test_extensible_skips_unknown_extension() {
+    serialize_and_deserialize_der(
+        &[0x30, 0x06, 0x80, 0x01, 0x02, 0x81, 0x01, 0xFF],
+        &Extensible {
+            abc: 2,
+            def: Some(true),
+        },
+    );
+    assert_eq!(
+        Extensible {
+            abc: 2,
+            def: Some(true),
+        },
+        deserialize_ber::<Extensible>(&[
+            0x30, 0x09, 0x80, 0x01, 0x02, 0x81, 0x01, 0xFF, 0x82, 0x01, 0x00
+        ])
+    );
+}
+
+#[test]
+fn test_set_in_any_order() {
+    let value = Unordered { b: true, a: 5 };
+    serialize_and_deserialize_der(&[0x31, 0x06, 0x80, 0x01, 0xFF, 0x81, 0x01, 0x05], &value);
+    assert_eq!(
+        value,
+        deserialize_ber::<Unordered>(&[0x31, 0x06, 0x81, 0x01, 0x05, 0x80, 0x01, 0xFF])
+    );
+}
+
+#[test]
+fn test_set_of_sorted_for_der_only() {
+    let numbers = Numbers(vec![3, 1, 256, 2]);
+    assert_eq!(
+        &[
+            0x31, 0x0D, 0x02, 0x01, 0x03, 0x02, 0x01, 0x01, 0x02, 0x02, 0x01, 0x00, 0x02, 0x01,
+            0x02
+        ][..],
+        &serialize_ber(&numbers)[..]
+    );
+    serialize_and_deserialize_der(
+        &[
+            0x31, 0x0D, 0x02, 0x01, 0x01, 0x02, 0x01, 0x02, 0x02, 0x01, 0x03, 0x02, 0x02, 0x01,
+            0x00,
+        ],
+        &Numbers(vec![1, 2, 3, 256]),
+    );
+    assert_eq!(
+        Numbers(vec![1, 2, 3, 256]),
+        deserialize_ber::<Numbers>(&serialize_der(&numbers))
+    );
+}
+
+#[test]
+fn test_der_with_capacity() {
+    let numbers = Numbers(vec![3, 1, 256, 2]);
+    let mut writer = BerWriter::der_with_capacity(64);
+    assert!(writer.is_der());
+    writer.write(&numbers).unwrap();
+    assert_eq!(serialize_der(&numbers), writer.into_bytes_vec());
+}
+
+#[test]
+fn test_default_is_omitted() {
+    serialize_and_deserialize_der(
+        &[0x30, 0x03, 0x81, 0x01, 0xFF],
+        &WithDefault { a: 5, b: true },
+    );
+    serialize_and_deserialize_der(
+        &[0x30, 0x06, 0x80, 0x01, 0x06, 0x81, 0x01, 0xFF],
+        &WithDefault { a: 6, b: true },
+    );
+}
+
+#[test]
+fn test_transparent_types() {
+    serialize_and_deserialize_der(&[0x12, 0x03, 0x31, 0x32, 0x33], &Code("123".to_string()));
+    serialize_and_deserialize_der(
+        &[0x03, 0x02, 0x05, 0xA0],
+        &Flags(BitVec::from_bytes(vec![0xA0], 3)),
+    );
+}
+
+#[test]
+fn test_long_form_length() {
+    let blob = Blob(vec![0x42; 200]);
+    let mut expected = vec![0x04, 0x81, 0xC8];
+    expected.extend_from_slice(&blob.0);
+    serialize_and_deserialize_der(&expected, &blob);
+}
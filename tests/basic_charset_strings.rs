@@ -0,0 +1,165 @@
+mod test_utils;
+
+use asn1rs::model::Charset;
+use test_utils::*;
+
+asn_to_rust!(
+    r"BasicCharsetStrings DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Bmp ::= BMPString
+
+    BmpFixed ::= BMPString (SIZE(2))
+
+    Universal ::= UniversalString
+
+    Teletex ::= TeletexString
+
+    Graphic ::= GraphicString
+
+    General ::= GeneralString
+
+    Names ::= SEQUENCE {
+        bmp BMPString,
+        teletex T61String,
+        general GeneralString OPTIONAL
+    }
+
+    END"
+);
+
+fn names() -> Names {
+    Names {
+        bmp: "A€".to_string(),
+        teletex: "Grüße".to_string(),
+        general: None,
+    }
+}
+
+#[test]
+fn test_uper() {
+    serialize_and_deserialize_uper(
+        56,
+        &[0x03, 0x00, 0x41, 0x00, 0xE9, 0x20, 0xAC],
+        &Bmp("Aé€".to_string()),
+    );
+    serialize_and_deserialize_uper(32, &[0x00, 0x41, 0x20, 0xAC], &BmpFixed("A€".to_string()));
+    serialize_and_deserialize_uper(
+        72,
+        &[0x02, 0x00, 0x00, 0x00, 0x41, 0x00, 0x01, 0xF6, 0x00],
+        &Universal("A😀".to_string()),
+    );
+    serialize_and_deserialize_uper(
+        48,
+        &[0x05, 0x47, 0x72, 0xFC, 0xDF, 0x65],
+        &Teletex("Grüße".to_string()),
+    );
+    serialize_and_deserialize_uper(24, &[0x02, 0x41, 0xA0], &Graphic("A\u{A0}".to_string()));
+}
+
+#[test]
+fn test_uper_fragmented() {
+    // 16K characters and one more are written as a fragment of 16K octets and the remainder
+    let value = "a".repeat(16385);
+    let mut expected = vec![0xC1];
+    expected.extend_from_slice(&value.as_bytes()[..16384]);
+    expected.extend_from_slice(&[0x01, b'a']);
+    serialize_and_deserialize_uper(expected.len() * 8, &expected, &Teletex(value));
+}
+
+#[test]
+fn test_aper() {
+    serialize_and_deserialize_aper(
+        56,
+        &[0x03, 0x00, 0x41, 0x00, 0xE9, 0x20, 0xAC],
+        &Bmp("Aé€".to_string()),
+    );
+    serialize_and_deserialize_aper(
+        72,
+        &[0x02, 0x00, 0x00, 0x00, 0x41, 0x00, 0x01, 0xF6, 0x00],
+        &Universal("A😀".to_string()),
+    );
+    serialize_and_deserialize_aper(24, &[0x02, 0x41, 0x42], &General("AB".to_string()));
+}
+
+#[test]
+fn test_der() {
+    serialize_and_deserialize_der(
+        &[0x1E, 0x06, 0x00, 0x41, 0x00, 0xE9, 0x20, 0xAC],
+        &Bmp("Aé€".to_string()),
+    );
+    serialize_and_deserialize_der(
+        &[0x1C, 0x08, 0x00, 0x00, 0x00, 0x41, 0x00, 0x01, 0xF6, 0x00],
+        &Universal("A😀".to_string()),
+    );
+    serialize_and_deserialize_der(
+        &[0x14, 0x05, 0x47, 0x72, 0xFC, 0xDF, 0x65],
+        &Teletex("Grüße".to_string()),
+    );
+    serialize_and_deserialize_der(&[0x19, 0x01, 0x41], &Graphic("A".to_string()));
+    serialize_and_deserialize_der(&[0x1B, 0x01, 0x41], &General("A".to_string()));
+    serialize_and_deserialize_der(
+        &[
+            0x30, 0x0D, 0x80, 0x04, 0x00, 0x41, 0x20, 0xAC, 0x81, 0x05, 0x47, 0x72, 0xFC, 0xDF,
+            0x65,
+        ],
+        &names(),
+    );
+}
+
+#[test]
+fn test_oer() {
+    serialize_and_deserialize_oer(
+        &[0x06, 0x00, 0x41, 0x00, 0xE9, 0x20, 0xAC],
+        &Bmp("Aé€".to_string()),
+    );
+    serialize_and_deserialize_oer(&[0x00, 0x41, 0x20, 0xAC], &BmpFixed("A€".to_string()));
+    serialize_and_deserialize_oer(
+        &[0x05, 0x47, 0x72, 0xFC, 0xDF, 0x65],
+        &Teletex("Grüße".to_string()),
+    );
+}
+
+#[test]
+fn test_jer() {
+    serialize_and_deserialize_jer(r#"{"bmp":"A€","teletex":"Grüße"}"#, &names());
+}
+
+#[test]
+fn test_xer() {
+    serialize_and_deserialize_xer(
+        "<Names><bmp>A€</bmp><teletex>Grüße</teletex></Names>",
+        &names(),
+    );
+}
+
+#[test]
+#[cfg(feature = "protobuf")]
+fn test_protobuf() {
+    let mut bytes = vec![0x0A, 0x04];
+    bytes.extend_from_slice("A€".as_bytes());
+    bytes.extend_from_slice(&[0x12, 0x07]);
+    bytes.extend_from_slice("Grüße".as_bytes());
+    serialize_and_deserialize_protobuf(&bytes, &names());
+}
+
+#[test]
+fn test_invalid_characters() {
+    let mut writer = asn1rs::syn::io::UperWriter::default();
+    assert_eq!(
+        Err(asn1rs::io::per::ErrorKind::InvalidString(Charset::Bmp, '😀', 1).into()),
+        Bmp("A😀".to_string()).write(&mut writer)
+    );
+
+    let mut writer = asn1rs::syn::io::UperWriter::default();
+    assert_eq!(
+        Err(asn1rs::io::per::ErrorKind::InvalidString(Charset::Teletex, '€', 0).into()),
+        Teletex("€".to_string()).write(&mut writer)
+    );
+
+    let mut writer = asn1rs::syn::io::UperWriter::default();
+    assert_eq!(
+        Err(asn1rs::io::per::ErrorKind::InvalidString(Charset::Graphic, '\u{7}', 0).into()),
+        Graphic("\u{7}".to_string()).write(&mut writer)
+    );
+}
@@ -0,0 +1,206 @@
+mod test_utils;
+
+use asn1rs::io::jer::ErrorKind;
+use test_utils::*;
+
+asn_to_rust!(
+    r"BasicJer DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Pdu ::= SEQUENCE {
+        flag BOOLEAN,
+        secret-code INTEGER (0..65535),
+        name UTF8String (SIZE(1..16)),
+        payload OCTET STRING,
+        opt INTEGER (0..7) OPTIONAL,
+        color Color DEFAULT light-blue
+    }
+
+    Color ::= ENUMERATED {
+        red,
+        light-blue,
+        green
+    }
+
+    Selection ::= CHOICE {
+        abc INTEGER (0..255),
+        def-ghi BOOLEAN,
+        nested Nested
+    }
+
+    Nested ::= SEQUENCE {
+        empty NULL,
+        values SEQUENCE OF INTEGER (-10..10)
+    }
+
+    Extensible ::= SEQUENCE {
+        abc INTEGER (0..3),
+        ...,
+        def BOOLEAN OPTIONAL
+    }
+
+    Strict ::= SEQUENCE {
+        abc INTEGER (0..3)
+    }
+
+    Bits ::= SEQUENCE {
+        fixed BIT STRING (SIZE(12)),
+        variable BIT STRING
+    }
+
+    Code ::= IA5String (SIZE(3))
+
+    END"
+);
+
+fn pdu() -> Pdu {
+    Pdu {
+        flag: true,
+        secret_code: 0x0102,
+        name: "a\"b".to_string(),
+        payload: vec![0xDE, 0xAD],
+        opt: Some(5),
+        color: Color::Red,
+    }
+}
+
+#[test]
+fn test_pdu() {
+    serialize_and_deserialize_jer(
+        r#"{"flag":true,"secret-code":258,"name":"a\"b","payload":"DEAD","opt":5,"color":"red"}"#,
+        &pdu(),
+    );
+}
+
+#[test]
+fn test_pdu_without_optional_and_default() {
+    serialize_and_deserialize_jer(
+        r#"{"flag":false,"secret-code":258,"name":"a\"b","payload":"DEAD"}"#,
+        &Pdu {
+            flag: false,
+            opt: None,
+            color: Color::LightBlue,
+            ..pdu()
+        },
+    );
+}
+
+#[test]
+fn test_pdu_members_in_any_order_and_whitespace() {
+    assert_eq!(
+        pdu(),
+        deserialize_jer::<Pdu>(
+            r#" { "color" : "red", "opt": 5, "payload": "dead",
+                "name": "a\"b", "secret-code": 258, "flag": true } "#
+        )
+    );
+}
+
+#[test]
+fn test_choice() {
+    serialize_and_deserialize_jer(r#"{"abc":66}"#, &Selection::Abc(66));
+    serialize_and_deserialize_jer(r#"{"def-ghi":true}"#, &Selection::DefGhi(true));
+    serialize_and_deserialize_jer(
+        r#"{"nested":{"empty":null,"values":[1,-2,3]}}"#,
+        &Selection::Nested(Nested {
+            empty: Null,
+            values: vec![1, -2, 3],
+        }),
+    );
+}
+
+#[test]
+fn test_choice_unknown_identifier() {
+    let error = Selection::decode_from_jer(r#"{"xyz":1}"#).unwrap_err();
+    assert_eq!(
+        &ErrorKind::UnknownIdentifier("xyz".to_string()),
+        error.kind()
+    );
+}
+
+#[test]
+fn test_choice_invalid_value_of_known_identifier() {
+    let error = Selection::decode_from_jer(r#"{"abc":true}"#).unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        ErrorKind::UnexpectedValue {
+            expected: "number",
+            found: "boolean"
+        }
+    ));
+}
+
+#[test]
+fn test_extensible_ignores_unknown_member() {
+    serialize_and_deserialize_jer(
+        r#"{"abc":2,"def":true}"#,
+        &Extensible {
+            abc: 2,
+            def: Some(true),
+        },
+    );
+    assert_eq!(
+        Extensible { abc: 2, def: None },
+        deserialize_jer::<Extensible>(r#"{"abc":2,"xyz":[1,2]}"#)
+    );
+    assert_eq!(
+        &ErrorKind::UnknownIdentifier("xyz".to_string()),
+        Strict::decode_from_jer(r#"{"abc":2,"xyz":[1,2]}"#)
+            .unwrap_err()
+            .kind()
+    );
+}
+
+#[test]
+fn test_missing_member() {
+    assert_eq!(
+        &ErrorKind::MissingMember("abc"),
+        Strict::decode_from_jer("{}").unwrap_err().kind()
+    );
+}
+
+#[test]
+fn test_bit_strings() {
+    serialize_and_deserialize_jer(
+        r#"{"fixed":"ABC0","variable":{"value":"A0","length":3}}"#,
+        &Bits {
+            fixed: BitVec::from_bytes(vec![0xAB, 0xC0], 12),
+            variable: BitVec::from_bytes(vec![0xA0], 3),
+        },
+    );
+    assert_eq!(
+        "{\"fixed\":\"ABC0\",\"variable\":{\"value\":\"\",\"length\":0}}",
+        serialize_jer(&Bits {
+            fixed: BitVec::from_bytes(vec![0xAB, 0xCF], 12),
+            variable: BitVec::default(),
+        })
+    );
+}
+
+#[test]
+fn test_transparent_types() {
+    serialize_and_deserialize_jer(r#""abc""#, &Code("abc".to_string()));
+    serialize_and_deserialize_jer(r#""light-blue""#, &Color::LightBlue);
+}
+
+#[test]
+fn test_constraints_are_checked() {
+    assert!(matches!(
+        Code::decode_from_jer(r#""abcd""#).unwrap_err().kind(),
+        ErrorKind::SizeNotInRange(4, 3, 3)
+    ));
+    assert!(matches!(
+        Strict::decode_from_jer(r#"{"abc":4}"#).unwrap_err().kind(),
+        ErrorKind::ValueNotInRange(4, 0, 3)
+    ));
+    assert!(matches!(
+        Strict::decode_from_jer(r#"{"abc":1.0}"#)
+            .unwrap_err()
+            .kind(),
+        ErrorKind::InvalidContent(_)
+    ));
+    assert!(matches!(
+        Color::decode_from_jer(r#""blue""#).unwrap_err().kind(),
+        ErrorKind::UnknownIdentifier(_)
+    ));
+}
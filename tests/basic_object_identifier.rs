@@ -0,0 +1,135 @@
+mod test_utils;
+
+use test_utils::*;
+
+asn_to_rust!(
+    r"BasicObjectIdentifier DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    id-base OBJECT IDENTIFIER ::= { iso(1) member-body(2) 840 }
+    id-rsa OBJECT IDENTIFIER ::= { id-base 113549 }
+    id-path RELATIVE-OID ::= { 3 2 }
+
+    Value ::= OBJECT IDENTIFIER
+
+    Relative ::= RELATIVE-OID
+
+    Info ::= SEQUENCE {
+        algorithm OBJECT IDENTIFIER DEFAULT id-rsa,
+        parent OBJECT IDENTIFIER DEFAULT { joint-iso-itu-t 999 },
+        path RELATIVE-OID OPTIONAL
+    }
+
+    END"
+);
+
+fn rsa() -> Value {
+    Value(ObjectIdentifier::new(vec![1, 2, 840, 113_549]))
+}
+
+fn info() -> Info {
+    Info {
+        algorithm: ObjectIdentifier::new(vec![2, 5, 4]),
+        parent: ObjectIdentifier::new(vec![2, 999]),
+        path: Some(ID_PATH),
+    }
+}
+
+#[test]
+fn test_value_references() {
+    assert_eq!(&[1, 2, 840], ID_BASE.arcs());
+    assert_eq!(&[1, 2, 840, 113_549], ID_RSA.arcs());
+    assert_eq!(&[3, 2], ID_PATH.arcs());
+    assert_eq!("1.2.840.113549", ID_RSA.to_string());
+    assert_eq!(Ok(ID_RSA), "1.2.840.113549".parse());
+}
+
+#[test]
+fn test_uper() {
+    serialize_and_deserialize_uper(56, &[0x06, 0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D], &rsa());
+    serialize_and_deserialize_uper(
+        40,
+        &[0x04, 0xC2, 0x7B, 0x03, 0x02],
+        &Relative(RelativeOid::new(vec![8571, 3, 2])),
+    );
+    serialize_and_deserialize_uper(51, &[0xA0, 0x4A, 0xA0, 0x80, 0x40, 0x60, 0x40], &info());
+}
+
+#[test]
+fn test_uper_defaults() {
+    serialize_and_deserialize_uper(
+        3,
+        &[0x00],
+        &Info {
+            algorithm: ID_RSA,
+            parent: ObjectIdentifier::new(vec![2, 999]),
+            path: None,
+        },
+    );
+}
+
+#[test]
+fn test_aper() {
+    serialize_and_deserialize_aper(56, &[0x06, 0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D], &rsa());
+}
+
+#[test]
+fn test_der() {
+    serialize_and_deserialize_der(&[0x06, 0x06, 0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D], &rsa());
+    serialize_and_deserialize_der(
+        &[0x0D, 0x04, 0xC2, 0x7B, 0x03, 0x02],
+        &Relative(RelativeOid::new(vec![8571, 3, 2])),
+    );
+    serialize_and_deserialize_der(
+        &[
+            0x30, 0x0B, 0x80, 0x02, 0x55, 0x04, 0x81, 0x01, 0x01, 0x82, 0x02, 0x03, 0x02,
+        ][..],
+        &Info {
+            parent: ObjectIdentifier::new(vec![0, 1]),
+            ..info()
+        },
+    );
+}
+
+#[test]
+fn test_oer() {
+    serialize_and_deserialize_oer(&[0x06, 0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D], &rsa());
+    serialize_and_deserialize_oer(
+        &[0x04, 0xC2, 0x7B, 0x03, 0x02],
+        &Relative(RelativeOid::new(vec![8571, 3, 2])),
+    );
+}
+
+#[test]
+fn test_jer() {
+    serialize_and_deserialize_jer("\"1.2.840.113549\"", &rsa());
+    serialize_and_deserialize_jer(
+        r#"{"algorithm":"2.5.4","path":"3.2"}"#,
+        &Info {
+            algorithm: ObjectIdentifier::new(vec![2, 5, 4]),
+            parent: ObjectIdentifier::new(vec![2, 999]),
+            path: Some(ID_PATH),
+        },
+    );
+}
+
+#[test]
+fn test_xer() {
+    serialize_and_deserialize_xer("<Value>1.2.840.113549</Value>", &rsa());
+    serialize_and_deserialize_xer(
+        "<Relative>8571.3.2</Relative>",
+        &Relative(RelativeOid::new(vec![8571, 3, 2])),
+    );
+}
+
+#[test]
+#[cfg(feature = "protobuf")]
+fn test_protobuf() {
+    serialize_and_deserialize_protobuf(
+        &[
+            0x0A, 0x05, b'2', b'.', b'5', b'.', b'4', 0x12, 0x05, b'2', b'.', b'9', b'9', b'9',
+            0x1A, 0x03, b'3', b'.', b'2',
+        ],
+        &info(),
+    );
+}
@@ -0,0 +1,199 @@
+mod test_utils;
+
+use asn1rs::io::oer::ErrorKind;
+use asn1rs::model::Tag;
+use test_utils::*;
+
+asn_to_rust!(
+    r"BasicOer DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Pdu ::= SEQUENCE {
+        flag BOOLEAN,
+        small INTEGER (0..255),
+        signed INTEGER (-1000..1000),
+        big INTEGER,
+        name UTF8String,
+        opt INTEGER (0..7) OPTIONAL,
+        color Color DEFAULT red
+    }
+
+    Color ::= ENUMERATED {
+        red,
+        blue,
+        green
+    }
+
+    Selection ::= CHOICE {
+        abc INTEGER (0..255),
+        def BOOLEAN,
+        nested Nested,
+        ...,
+        ext INTEGER (0..65535)
+    }
+
+    Nested ::= SEQUENCE {
+        values SEQUENCE OF INTEGER (0..255),
+        code IA5String (SIZE(2)),
+        bits BIT STRING (SIZE(4)),
+        vbits BIT STRING,
+        data OCTET STRING (SIZE(3))
+    }
+
+    Extensible ::= SEQUENCE {
+        abc INTEGER (0..3),
+        ...,
+        def BOOLEAN OPTIONAL,
+        ghi INTEGER (0..255) OPTIONAL
+    }
+
+    Strict ::= SEQUENCE {
+        abc INTEGER (0..3),
+        ...
+    }
+
+    Payload ::= OCTET STRING
+
+    Big ::= INTEGER (0..4294967295)
+
+    END"
+);
+
+fn pdu() -> Pdu {
+    Pdu {
+        flag: true,
+        small: 5,
+        signed: -2,
+        big: 300,
+        name: "ab".to_string(),
+        opt: Some(3),
+        color: Color::Green,
+    }
+}
+
+#[test]
+fn test_pdu() {
+    serialize_and_deserialize_oer(
+        &[
+            0xC0, 0xFF, 0x05, 0xFF, 0xFE, 0x02, 0x01, 0x2C, 0x02, 0x61, 0x62, 0x03, 0x02,
+        ],
+        &pdu(),
+    );
+}
+
+#[test]
+fn test_pdu_without_optional_and_default() {
+    serialize_and_deserialize_oer(
+        &[
+            0x00, 0x00, 0x05, 0xFF, 0xFE, 0x02, 0x01, 0x2C, 0x02, 0x61, 0x62,
+        ],
+        &Pdu {
+            flag: false,
+            opt: None,
+            color: Color::Red,
+            ..pdu()
+        },
+    );
+}
+
+#[test]
+fn test_choice() {
+    serialize_and_deserialize_oer(&[0x80, 0x42], &Selection::Abc(0x42));
+    serialize_and_deserialize_oer(&[0x81, 0xFF], &Selection::Def(true));
+}
+
+#[test]
+fn test_choice_extension_is_open_type() {
+    serialize_and_deserialize_oer(&[0x83, 0x02, 0x01, 0x02], &Selection::Ext(0x0102));
+}
+
+#[test]
+fn test_fixed_and_variable_sizes() {
+    serialize_and_deserialize_oer(
+        &[
+            0x82, 0x01, 0x02, 0x01, 0x02, 0x61, 0x62, 0xA0, 0x02, 0x05, 0xA0, 0xDE, 0xAD, 0xBE,
+        ],
+        &Selection::Nested(Nested {
+            values: vec![1, 2],
+            code: "ab".to_string(),
+            bits: BitVec::from_bytes(vec![0xA0], 4),
+            vbits: BitVec::from_bytes(vec![0xA0], 3),
+            data: vec![0xDE, 0xAD, 0xBE],
+        }),
+    );
+}
+
+#[test]
+fn test_long_length_determinant() {
+    let mut expected = vec![0x81, 0xC8];
+    expected.extend_from_slice(&[0x11; 200]);
+    serialize_and_deserialize_oer(&expected, &Payload(vec![0x11; 200]));
+}
+
+#[test]
+fn test_fixed_size_integer() {
+    serialize_and_deserialize_oer(&[0xFF, 0xFF, 0xFF, 0xFE], &Big(0xFFFF_FFFE));
+}
+
+#[test]
+fn test_extension_additions() {
+    serialize_and_deserialize_oer(
+        &[0x00, 0x02],
+        &Extensible {
+            abc: 2,
+            def: None,
+            ghi: None,
+        },
+    );
+    serialize_and_deserialize_oer(
+        &[0x80, 0x02, 0x02, 0x06, 0x80, 0x01, 0xFF],
+        &Extensible {
+            abc: 2,
+            def: Some(true),
+            ghi: None,
+        },
+    );
+    serialize_and_deserialize_oer(
+        &[0x80, 0x02, 0x02, 0x06, 0x40, 0x01, 0x07],
+        &Extensible {
+            abc: 2,
+            def: None,
+            ghi: Some(7),
+        },
+    );
+}
+
+#[test]
+fn test_unknown_extension_additions_are_skipped() {
+    assert_eq!(
+        Strict { abc: 2 },
+        deserialize_oer::<Strict>(&[0x80, 0x02, 0x02, 0x06, 0xC0, 0x01, 0xFF, 0x01, 0x07])
+    );
+}
+
+#[test]
+fn test_errors() {
+    assert_eq!(
+        &ErrorKind::ValueNotInRange(4, 0, 3),
+        Strict::decode_from_oer(&[0x00, 0x04]).unwrap_err().kind()
+    );
+    assert_eq!(
+        &ErrorKind::InsufficientDataInSourceBuffer { offset: 2 },
+        Pdu::decode_from_oer(&[0xC0, 0xFF]).unwrap_err().kind()
+    );
+    assert_eq!(
+        &ErrorKind::UnexpectedChoiceTag {
+            found: Tag::ContextSpecific(5),
+            offset: 0
+        },
+        Selection::decode_from_oer(&[0x85, 0x00])
+            .unwrap_err()
+            .kind()
+    );
+    assert!(matches!(
+        Selection::decode_from_oer(&[0x81, 0x01])
+            .unwrap_err()
+            .kind(),
+        ErrorKind::InvalidContent(_)
+    ));
+}
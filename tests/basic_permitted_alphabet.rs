@@ -0,0 +1,86 @@
+mod test_utils;
+
+use asn1rs::model::Charset;
+use test_utils::*;
+
+asn_to_rust!(
+    r#"BasicPermittedAlphabet DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Upper ::= IA5String (FROM ("A".."Z"))
+
+    Digits ::= NumericString (SIZE(4) ^ FROM ("0".."9"))
+
+    Hex ::= PrintableString (FROM ("0".."9" | "A".."F"))
+
+    Code ::= VisibleString (SIZE(1..8)) (FROM ("ABC"))
+
+    Greek ::= BMPString (FROM ("α".."ω"))
+
+    Extensible ::= IA5String (FROM ("A".."Z", ...))
+
+    END"#
+);
+
+#[test]
+fn test_uper_indexed() {
+    // 5 bits per character as index of the sorted alphabet
+    serialize_and_deserialize_uper(
+        33,
+        &[0x05, 0x39, 0x16, 0xB7, 0x00],
+        &Upper("HELLO".to_string()),
+    );
+    serialize_and_deserialize_uper(16, &[0x12, 0x34], &Digits("1234".to_string()));
+    serialize_and_deserialize_uper(24, &[0x04, 0xCA, 0xFE], &Hex("CAFE".to_string()));
+    serialize_and_deserialize_uper(9, &[0x50, 0x80], &Code("CAB".to_string()));
+    serialize_and_deserialize_uper(23, &[0x03, 0x00, 0x44], &Greek("αβγ".to_string()));
+}
+
+#[test]
+fn test_uper_extensible_alphabet_is_not_per_visible() {
+    serialize_and_deserialize_uper(22, &[0x02, 0x83, 0x08], &Extensible("AB".to_string()));
+}
+
+#[test]
+fn test_aper_value() {
+    // 8 bits per character, the values fit
+    serialize_and_deserialize_aper(
+        48,
+        &[0x05, 0x48, 0x45, 0x4C, 0x4C, 0x4F],
+        &Upper("HELLO".to_string()),
+    );
+    serialize_and_deserialize_aper(16, &[0x12, 0x34], &Digits("1234".to_string()));
+}
+
+#[test]
+fn test_der_is_not_affected() {
+    serialize_and_deserialize_der(
+        &[0x16, 0x05, 0x48, 0x45, 0x4C, 0x4C, 0x4F],
+        &Upper("HELLO".to_string()),
+    );
+}
+
+#[test]
+fn test_character_not_permitted() {
+    let mut writer = asn1rs::syn::io::UperWriter::default();
+    assert_eq!(
+        Err(asn1rs::io::per::ErrorKind::InvalidString(Charset::Ia5, 'i', 1).into()),
+        Upper("Hi".to_string()).write(&mut writer)
+    );
+
+    let mut writer = asn1rs::syn::io::AperWriter::default();
+    assert_eq!(
+        Err(asn1rs::io::per::ErrorKind::InvalidString(Charset::Printable, 'G', 0).into()),
+        Hex("G".to_string()).write(&mut writer)
+    );
+}
+
+#[test]
+fn test_decode_index_out_of_alphabet() {
+    // index 31 exceeds the 26 characters of the alphabet
+    let mut reader = asn1rs::syn::io::UperReader::from((&[0x01, 0xF8][..], 13));
+    assert_eq!(
+        &asn1rs::io::per::ErrorKind::InvalidString(Charset::Ia5, '\u{1f}', 0),
+        reader.read::<Upper>().unwrap_err().kind()
+    );
+}
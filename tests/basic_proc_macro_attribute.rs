@@ -676,7 +676,7 @@ fn test_nested_extensible_struct() {
 }
 
 #[test]
-fn test_extensible_struct_fail_inconsistent() {
+fn test_extensible_struct_first_extension_absent() {
     let mut uper = UperWriter::default();
     let v = ExtensibleStruct {
         range: 145,
@@ -697,13 +697,20 @@ fn test_extensible_struct_fail_inconsistent() {
         value15: Some(146),
         value16: Some(146),
     };
+    uper.write(&v).unwrap();
     assert_eq!(
-        Err(asn1rs::io::per::ErrorKind::ExtensionFieldsInconsistent(
-            "ExtensibleStruct".to_string()
-        )
-        .into()),
-        uper.write(&v)
+        &[
+            0xC8, 0x8F, 0x7F, 0xFF, 0x01, 0x92, 0x01, 0x92, 0x01, 0x92, 0x01, 0x92, 0x01, 0x92,
+            0x01, 0x92, 0x01, 0x92, 0x01, 0x92, 0x01, 0x92, 0x01, 0x92, 0x01, 0x92, 0x01, 0x92,
+            0x01, 0x92, 0x01, 0x92, 0x01, 0x92
+        ][..],
+        uper.byte_content()
     );
+    assert_eq!(34 * 8, uper.bit_len());
+
+    let mut uper = uper.as_reader();
+    assert_eq!(v, uper.read::<ExtensibleStruct>().unwrap());
+    assert_eq!(0, uper.bits_remaining());
 }
 
 /// ```asn
@@ -0,0 +1,107 @@
+mod test_utils;
+
+use test_utils::*;
+
+asn_to_rust!(
+    r"BasicReal DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Value ::= REAL
+
+    Measurement ::= SEQUENCE {
+        factor REAL,
+        samples SEQUENCE OF REAL,
+        offset REAL OPTIONAL
+    }
+
+    END"
+);
+
+fn measurement() -> Measurement {
+    Measurement {
+        factor: 0.5,
+        samples: vec![3.0, -1024.0],
+        offset: None,
+    }
+}
+
+#[test]
+fn test_uper() {
+    serialize_and_deserialize_uper(32, &[0x03, 0x80, 0x00, 0x01], &Value(1.0));
+    serialize_and_deserialize_uper(8, &[0x00], &Value(0.0));
+    serialize_and_deserialize_uper(16, &[0x01, 0x43], &Value(-0.0));
+    serialize_and_deserialize_uper(16, &[0x01, 0x40], &Value(f64::INFINITY));
+    serialize_and_deserialize_uper(
+        105,
+        &[
+            0x01, 0xC0, 0x7F, 0x80, 0x81, 0x01, 0xC0, 0x00, 0x01, 0x81, 0xE0, 0x05, 0x00, 0x80,
+        ],
+        &measurement(),
+    );
+}
+
+#[test]
+fn test_aper() {
+    serialize_and_deserialize_aper(32, &[0x03, 0x80, 0x00, 0x01], &Value(1.0));
+    serialize_and_deserialize_aper(16, &[0x01, 0x41], &Value(f64::NEG_INFINITY));
+}
+
+#[test]
+fn test_der() {
+    serialize_and_deserialize_der(&[0x09, 0x03, 0x80, 0x00, 0x01], &Value(1.0));
+    serialize_and_deserialize_der(&[0x09, 0x00], &Value(0.0));
+    serialize_and_deserialize_der(
+        &[
+            0x30, 0x11, 0x80, 0x03, 0x80, 0xFF, 0x01, 0xA1, 0x0A, 0x09, 0x03, 0x80, 0x00, 0x03,
+            0x09, 0x03, 0xC0, 0x0A, 0x01,
+        ][..],
+        &measurement(),
+    );
+}
+
+#[test]
+fn test_ber_decimal_form() {
+    assert_eq!(
+        Value(-1.5),
+        deserialize_ber::<Value>(&[0x09, 0x07, 0x03, b'-', b'1', b'5', b'E', b'-', b'1'])
+    );
+}
+
+#[test]
+fn test_oer() {
+    serialize_and_deserialize_oer(&[0x03, 0x80, 0x00, 0x01], &Value(1.0));
+    assert_eq!(vec![0x01, 0x42], serialize_oer(&Value(f64::NAN)));
+    assert!(deserialize_oer::<Value>(&[0x01, 0x42]).0.is_nan());
+}
+
+#[test]
+fn test_jer() {
+    serialize_and_deserialize_jer("1.0", &Value(1.0));
+    serialize_and_deserialize_jer("\"-INF\"", &Value(f64::NEG_INFINITY));
+    serialize_and_deserialize_jer("\"-0\"", &Value(-0.0));
+    serialize_and_deserialize_jer(r#"{"factor":0.5,"samples":[3.0,-1024.0]}"#, &measurement());
+    assert_eq!(Value(1.25e-3), deserialize_jer::<Value>("125E-5"));
+}
+
+#[test]
+fn test_xer() {
+    serialize_and_deserialize_xer("<Value>-2.5</Value>", &Value(-2.5));
+    serialize_and_deserialize_xer("<Value><PLUS-INFINITY/></Value>", &Value(f64::INFINITY));
+    serialize_and_deserialize_xer(
+        "<Measurement><factor>0.5</factor><samples><REAL>3.0</REAL><REAL>-1024.0</REAL></samples></Measurement>",
+        &measurement(),
+    );
+}
+
+#[test]
+#[cfg(feature = "protobuf")]
+fn test_protobuf() {
+    serialize_and_deserialize_protobuf(
+        &[0x09, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF8, 0x3F],
+        &Measurement {
+            factor: 1.5,
+            samples: Vec::new(),
+            offset: None,
+        },
+    );
+}
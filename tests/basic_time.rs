@@ -0,0 +1,154 @@
+mod test_utils;
+
+use std::convert::TryFrom;
+use std::time::{SystemTime, UNIX_EPOCH};
+use test_utils::*;
+
+asn_to_rust!(
+    r"BasicTime DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Moment ::= GeneralizedTime
+
+    Legacy ::= UTCTime
+
+    Event ::= SEQUENCE {
+        day DATE,
+        at TIME-OF-DAY,
+        lasts DURATION,
+        stamp GeneralizedTime OPTIONAL
+    }
+
+    END"
+);
+
+fn moment() -> Moment {
+    Moment(GeneralizedTime::from_unix(500_159_187, 300_000_000).unwrap())
+}
+
+fn event() -> Event {
+    Event {
+        day: Date::new(2020, 2, 29).unwrap(),
+        at: TimeOfDay::new(23, 5, 9).unwrap(),
+        lasts: Duration {
+            days: 1,
+            hours: 2,
+            ..Duration::default()
+        },
+        stamp: None,
+    }
+}
+
+#[test]
+fn test_system_time() {
+    let time = UNIX_EPOCH + std::time::Duration::new(500_159_187, 300_000_000);
+    assert_eq!(moment().0, GeneralizedTime::from(time));
+    assert_eq!(time, SystemTime::from(moment().0));
+
+    let legacy = UtcTime::try_from(time).unwrap();
+    assert_eq!("851106210627Z", legacy.to_string());
+    assert_eq!(
+        UNIX_EPOCH + std::time::Duration::from_secs(500_159_187),
+        SystemTime::from(legacy)
+    );
+    assert!(UtcTime::try_from(UNIX_EPOCH + std::time::Duration::from_secs(2_600_000_000)).is_err());
+
+    assert_eq!("1985-11-06", Date::try_from(time).unwrap().to_string());
+    assert_eq!("21:06:27", TimeOfDay::from(time).to_string());
+}
+
+#[test]
+fn test_uper() {
+    serialize_and_deserialize_uper(
+        127,
+        &[
+            0x11, 0x62, 0xE5, 0xC3, 0x56, 0x2C, 0x58, 0x36, 0x64, 0xC5, 0x83, 0x66, 0x4D, 0xD7,
+            0x33, 0xB4,
+        ],
+        &moment(),
+    );
+    serialize_and_deserialize_uper(
+        99,
+        &[
+            0x0D, 0x70, 0xD5, 0x8B, 0x16, 0x0D, 0x99, 0x31, 0x60, 0xD9, 0x83, 0x0B, 0x40,
+        ],
+        &Legacy("8511062106Z".parse().unwrap()),
+    );
+    serialize_and_deserialize_uper(
+        193,
+        &[
+            0x05, 0x32, 0x60, 0xC9, 0x82, 0xD6, 0x0C, 0x96, 0xB2, 0x72, 0x10, 0xC9, 0x9B, 0xA6,
+            0x0D, 0x5D, 0x30, 0x72, 0x0D, 0x41, 0x8C, 0x4A, 0x8C, 0xA4, 0x00,
+        ],
+        &event(),
+    );
+}
+
+#[test]
+fn test_aper() {
+    let mut bytes = vec![0x11];
+    bytes.extend_from_slice(b"19851106210627.3Z");
+    serialize_and_deserialize_aper(144, &bytes, &moment());
+}
+
+#[test]
+fn test_der() {
+    let mut bytes = vec![0x18, 0x11];
+    bytes.extend_from_slice(b"19851106210627.3Z");
+    serialize_and_deserialize_der(&bytes, &moment());
+
+    let mut bytes = vec![0x30, 0x1E, 0x80, 0x0A];
+    bytes.extend_from_slice(b"2020-02-29");
+    bytes.extend_from_slice(&[0x81, 0x08]);
+    bytes.extend_from_slice(b"23:05:09");
+    bytes.extend_from_slice(&[0x82, 0x06]);
+    bytes.extend_from_slice(b"P1DT2H");
+    serialize_and_deserialize_der(&bytes, &event());
+}
+
+#[test]
+fn test_ber_time_difference() {
+    let mut bytes = vec![0x17, 0x11];
+    bytes.extend_from_slice(b"851106230600+0200");
+    assert_eq!(
+        Legacy("851106210600Z".parse().unwrap()),
+        deserialize_ber::<Legacy>(&bytes)
+    );
+}
+
+#[test]
+fn test_oer() {
+    let mut bytes = vec![0x11];
+    bytes.extend_from_slice(b"19851106210627.3Z");
+    serialize_and_deserialize_oer(&bytes, &moment());
+}
+
+#[test]
+fn test_jer() {
+    serialize_and_deserialize_jer("\"19851106210627.3Z\"", &moment());
+    serialize_and_deserialize_jer(
+        r#"{"day":"2020-02-29","at":"23:05:09","lasts":"P1DT2H"}"#,
+        &event(),
+    );
+}
+
+#[test]
+fn test_xer() {
+    serialize_and_deserialize_xer("<Moment>19851106210627.3Z</Moment>", &moment());
+    serialize_and_deserialize_xer(
+        "<Event><day>2020-02-29</day><at>23:05:09</at><lasts>P1DT2H</lasts></Event>",
+        &event(),
+    );
+}
+
+#[test]
+#[cfg(feature = "protobuf")]
+fn test_protobuf() {
+    let mut bytes = vec![0x0A, 0x0A];
+    bytes.extend_from_slice(b"2020-02-29");
+    bytes.extend_from_slice(&[0x12, 0x08]);
+    bytes.extend_from_slice(b"23:05:09");
+    bytes.extend_from_slice(&[0x1A, 0x06]);
+    bytes.extend_from_slice(b"P1DT2H");
+    serialize_and_deserialize_protobuf(&bytes, &event());
+}
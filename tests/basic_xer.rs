@@ -0,0 +1,208 @@
+mod test_utils;
+
+use asn1rs::io::xer::ErrorKind;
+use test_utils::*;
+
+asn_to_rust!(
+    r"BasicXer DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Pdu ::= SEQUENCE {
+        flag BOOLEAN,
+        secret-code INTEGER (0..65535),
+        name UTF8String (SIZE(1..16)),
+        payload OCTET STRING,
+        opt INTEGER (0..7) OPTIONAL,
+        color Color DEFAULT light-blue
+    }
+
+    Color ::= ENUMERATED {
+        red,
+        light-blue,
+        green
+    }
+
+    Selection ::= CHOICE {
+        abc INTEGER (0..255),
+        def-ghi BOOLEAN,
+        nested Nested
+    }
+
+    Nested ::= SEQUENCE {
+        empty NULL,
+        values SEQUENCE OF INTEGER (-10..10),
+        flags SEQUENCE OF BOOLEAN,
+        colors SEQUENCE OF Color,
+        codes SEQUENCE OF Code
+    }
+
+    Extensible ::= SEQUENCE {
+        abc INTEGER (0..3),
+        ...,
+        def BOOLEAN OPTIONAL
+    }
+
+    Strict ::= SEQUENCE {
+        abc INTEGER (0..3)
+    }
+
+    Bits ::= BIT STRING
+
+    Code ::= IA5String (SIZE(1..3))
+
+    Flag ::= BOOLEAN
+
+    END"
+);
+
+fn pdu() -> Pdu {
+    Pdu {
+        flag: true,
+        secret_code: 0x0102,
+        name: "a<b&".to_string(),
+        payload: vec![0xDE, 0xAD],
+        opt: Some(5),
+        color: Color::Red,
+    }
+}
+
+#[test]
+fn test_pdu() {
+    serialize_and_deserialize_xer(
+        "<Pdu><flag><true/></flag><secret-code>258</secret-code><name>a&lt;b&amp;</name><payload>DEAD</payload><opt>5</opt><color><red/></color></Pdu>",
+        &pdu(),
+    );
+}
+
+#[test]
+fn test_pdu_without_optional_and_default() {
+    serialize_and_deserialize_xer(
+        "<Pdu><flag><false/></flag><secret-code>258</secret-code><name>a&lt;b&amp;</name><payload>DEAD</payload></Pdu>",
+        &Pdu {
+            flag: false,
+            opt: None,
+            color: Color::LightBlue,
+            ..pdu()
+        },
+    );
+}
+
+#[test]
+fn test_pdu_with_declaration_and_whitespace() {
+    assert_eq!(
+        pdu(),
+        deserialize_xer::<Pdu>(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+            <Pdu>
+                <flag><true /></flag>
+                <secret-code> 258 </secret-code>
+                <name>a&lt;b&amp;</name>
+                <payload>de ad</payload>
+                <opt>5</opt>
+                <color><red/></color>
+            </Pdu>"#
+        )
+    );
+}
+
+#[test]
+fn test_choice() {
+    serialize_and_deserialize_xer("<Selection><abc>66</abc></Selection>", &Selection::Abc(66));
+    serialize_and_deserialize_xer(
+        "<Selection><def-ghi><true/></def-ghi></Selection>",
+        &Selection::DefGhi(true),
+    );
+}
+
+#[test]
+fn test_sequence_of_components() {
+    serialize_and_deserialize_xer(
+        "<Selection><nested><empty/><values><INTEGER>1</INTEGER><INTEGER>-2</INTEGER></values><flags><true/><false/></flags><colors><green/><red/></colors><codes><Code>ab</Code></codes></nested></Selection>",
+        &Selection::Nested(Nested {
+            empty: Null,
+            values: vec![1, -2],
+            flags: vec![true, false],
+            colors: vec![Color::Green, Color::Red],
+            codes: vec![Code("ab".to_string())],
+        }),
+    );
+}
+
+#[test]
+fn test_choice_unknown_element() {
+    let error = Selection::decode_from_xer("<Selection><xyz>1</xyz></Selection>").unwrap_err();
+    assert_eq!(&ErrorKind::UnknownElement("xyz".to_string()), error.kind());
+}
+
+#[test]
+fn test_extensible_ignores_unknown_element() {
+    serialize_and_deserialize_xer(
+        "<Extensible><abc>2</abc><def><true/></def></Extensible>",
+        &Extensible {
+            abc: 2,
+            def: Some(true),
+        },
+    );
+    assert_eq!(
+        Extensible { abc: 2, def: None },
+        deserialize_xer::<Extensible>("<Extensible><abc>2</abc><xyz><a/></xyz></Extensible>")
+    );
+    assert_eq!(
+        &ErrorKind::UnknownElement("xyz".to_string()),
+        Strict::decode_from_xer("<Strict><abc>2</abc><xyz/></Strict>")
+            .unwrap_err()
+            .kind()
+    );
+}
+
+#[test]
+fn test_missing_element() {
+    assert_eq!(
+        &ErrorKind::MissingElement("abc"),
+        Strict::decode_from_xer("<Strict/>").unwrap_err().kind()
+    );
+}
+
+#[test]
+fn test_transparent_types() {
+    serialize_and_deserialize_xer("<Code>a\tb</Code>", &Code("a\tb".to_string()));
+    serialize_and_deserialize_xer("<Flag><false/></Flag>", &Flag(false));
+    serialize_and_deserialize_xer("<Color><light-blue/></Color>", &Color::LightBlue);
+    serialize_and_deserialize_xer(
+        "<Bits>1010</Bits>",
+        &Bits(BitVec::from_bytes(vec![0xA0], 4)),
+    );
+}
+
+#[test]
+fn test_control_characters() {
+    serialize_and_deserialize_xer("<Code>a<bel/>b</Code>", &Code("a\u{07}b".to_string()));
+}
+
+#[test]
+fn test_constraints_are_checked() {
+    assert!(matches!(
+        Code::decode_from_xer("<Code>abcd</Code>")
+            .unwrap_err()
+            .kind(),
+        ErrorKind::SizeNotInRange(4, 1, 3)
+    ));
+    assert!(matches!(
+        Strict::decode_from_xer("<Strict><abc>4</abc></Strict>")
+            .unwrap_err()
+            .kind(),
+        ErrorKind::ValueNotInRange(4, 0, 3)
+    ));
+    assert!(matches!(
+        Strict::decode_from_xer("<Strict><abc>1.0</abc></Strict>")
+            .unwrap_err()
+            .kind(),
+        ErrorKind::InvalidContent(_)
+    ));
+    assert!(matches!(
+        Color::decode_from_xer("<Color><blue/></Color>")
+            .unwrap_err()
+            .kind(),
+        ErrorKind::UnknownElement(_)
+    ));
+}
@@ -0,0 +1,160 @@
+use asn1rs::prelude::*;
+use std::borrow::Cow;
+
+mod test_utils;
+use test_utils::*;
+
+#[asn(sequence)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Header<'a> {
+    #[asn(utf8string)]
+    name: Cow<'a, str>,
+    #[asn(octet_string)]
+    payload: Cow<'a, [u8]>,
+}
+
+#[asn(sequence)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Message<'a> {
+    #[asn(boolean)]
+    urgent: bool,
+    #[asn(complex(Header<'a>, tag(UNIVERSAL(16))))]
+    header: Header<'a>,
+    #[asn(optional(utf8string))]
+    comment: Option<Cow<'a, str>>,
+    #[asn(sequence_of(octet_string))]
+    chunks: Vec<Cow<'a, [u8]>>,
+    #[asn(integer(0..255))]
+    id: u8,
+}
+
+#[asn(choice)]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Content<'a> {
+    #[asn(integer(0..255))]
+    Number(u8),
+    #[asn(utf8string)]
+    Text(Cow<'a, str>),
+    #[asn(complex(Header<'a>, tag(UNIVERSAL(16))))]
+    Header(Header<'a>),
+}
+
+#[asn(transparent)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Blob<'a>(#[asn(octet_string)] Cow<'a, [u8]>);
+
+fn header() -> Header<'static> {
+    Header {
+        name: Cow::Borrowed("first"),
+        payload: Cow::Borrowed(&[0xDE, 0xAD, 0xBE, 0xEF]),
+    }
+}
+
+fn read_borrowed<'a, T: BorrowedReadable<'a>>(bytes: &'a [u8], bits: usize) -> T {
+    let mut reader = UperReader::from((bytes, bits));
+    let result = reader.read_borrowed::<T>().unwrap();
+    assert_eq!(0, reader.bits_remaining());
+    result
+}
+
+fn is_borrowed<T: ?Sized + ToOwned>(value: &Cow<T>) -> bool {
+    matches!(value, Cow::Borrowed(_))
+}
+
+#[test]
+fn test_octet_aligned_values_are_borrowed() {
+    let (bits, bytes) = serialize_uper(&header());
+    let decoded = read_borrowed::<Header>(&bytes, bits);
+    assert_eq!(header(), decoded);
+    assert!(is_borrowed(&decoded.name));
+    assert!(is_borrowed(&decoded.payload));
+    assert_eq!(&bytes[1..6], decoded.name.as_bytes());
+}
+
+#[test]
+fn test_unaligned_values_are_copied() {
+    let message = Message {
+        urgent: true,
+        header: header(),
+        comment: Some(Cow::Borrowed("second")),
+        chunks: vec![Cow::Borrowed(&[1, 2, 3]), Cow::Borrowed(&[])],
+        id: 42,
+    };
+    let (bits, bytes) = serialize_uper(&message);
+    let decoded = read_borrowed::<Message>(&bytes, bits);
+    assert_eq!(message, decoded);
+
+    // the leading boolean and OPTIONAL flag shift everything that follows by two bits
+    assert!(!is_borrowed(&decoded.header.name));
+    assert!(!is_borrowed(&decoded.header.payload));
+    assert!(!is_borrowed(decoded.comment.as_ref().unwrap()));
+    assert!(decoded.chunks.iter().all(|chunk| !is_borrowed(chunk)));
+}
+
+#[test]
+fn test_owned_reader_reads_borrowed_types() {
+    let message = Message {
+        urgent: false,
+        header: header(),
+        comment: None,
+        chunks: vec![Cow::Owned(vec![7; 20])],
+        id: 1,
+    };
+    let (bits, bytes) = serialize_uper(&message);
+    let decoded = deserialize_uper::<Message>(&bytes, bits);
+    assert_eq!(message, decoded);
+    assert!(!is_borrowed(&decoded.header.name));
+    assert_eq!(
+        message,
+        deserialize_ber::<Message>(&serialize_der(&message))
+    );
+}
+
+#[test]
+fn test_choice_borrows_variant() {
+    let text = Content::Text(Cow::Borrowed("third"));
+    let (bits, bytes) = serialize_uper(&text);
+    assert_eq!(text, read_borrowed::<Content>(&bytes, bits));
+
+    let header = Content::Header(header());
+    let (bits, bytes) = serialize_uper(&header);
+    assert_eq!(header, read_borrowed::<Content>(&bytes, bits));
+
+    let number = Content::Number(5);
+    let (bits, bytes) = serialize_uper(&number);
+    assert_eq!(number, read_borrowed::<Content>(&bytes, bits));
+}
+
+#[test]
+fn test_transparent_borrows() {
+    let blob = Blob(Cow::Owned(vec![1, 2, 3, 4, 5]));
+    let (bits, bytes) = serialize_uper(&blob);
+    let decoded = read_borrowed::<Blob>(&bytes, bits);
+    assert_eq!(blob, decoded);
+    assert!(is_borrowed(&decoded.0));
+    assert_eq!(&bytes[1..], &decoded.0[..]);
+}
+
+#[test]
+fn test_fragmented_octet_string_is_copied() {
+    let blob = Blob(Cow::Owned(vec![0x55; 16 * 1024 + 1]));
+    let (bits, bytes) = serialize_uper(&blob);
+    let decoded = read_borrowed::<Blob>(&bytes, bits);
+    assert_eq!(blob, decoded);
+    assert!(!is_borrowed(&decoded.0));
+}
+
+#[test]
+fn test_invalid_utf8_is_rejected() {
+    let header = Header {
+        name: Cow::Borrowed("x"),
+        payload: Cow::Borrowed(&[]),
+    };
+    let (bits, mut bytes) = serialize_uper(&header);
+    bytes[1] = 0xFF;
+    let mut reader = UperReader::from((&bytes[..], bits));
+    assert!(matches!(
+        reader.read_borrowed::<Header>().unwrap_err().kind(),
+        asn1rs::io::per::ErrorKind::Utf8Error(_)
+    ));
+}
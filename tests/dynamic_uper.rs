@@ -0,0 +1,270 @@
+use asn1rs::dynamic::{DynamicValue, Interpreter};
+use asn1rs::io::per::err::ErrorKind;
+use asn1rs::model::Model;
+use asn1rs::parser::Tokenizer;
+use asn1rs::prelude::*;
+
+/// The bytes in the tests are the encodings of the types generated for this schema
+const SCHEMA: &str = r"DynamicUper DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Color ::= ENUMERATED { red, green, blue, ... }
+
+    Shape ::= CHOICE {
+        circle  INTEGER (0..255),
+        label   IA5String (SIZE(1..8)),
+        ...,
+        polygon SEQUENCE (SIZE(0..8)) OF INTEGER (0..15)
+    }
+
+    Drawing ::= SEQUENCE {
+        id      INTEGER,
+        visible BOOLEAN DEFAULT TRUE,
+        color   Color OPTIONAL,
+        shapes  SEQUENCE (SIZE(0..4)) OF Shape,
+        ...,
+        note    UTF8String OPTIONAL
+    }
+
+    ProcedureCode ::= ENUMERATED { setup, release, ... }
+
+    ELEMENTARY-PROCEDURE ::= CLASS {
+        &Request,
+        &procedureCode  ProcedureCode UNIQUE
+    }
+    WITH SYNTAX {
+        REQUEST         &Request
+        PROCEDURE CODE  &procedureCode
+    }
+
+    SetupRequest ::= SEQUENCE { ue INTEGER (0..255) }
+
+    ReleaseRequest ::= SEQUENCE { graceful BOOLEAN }
+
+    Procedures ELEMENTARY-PROCEDURE ::= {
+        { REQUEST SetupRequest PROCEDURE CODE setup } |
+        { REQUEST ReleaseRequest PROCEDURE CODE release },
+        ...
+    }
+
+    Message ::= SEQUENCE {
+        procedureCode   ELEMENTARY-PROCEDURE.&procedureCode ({Procedures}),
+        value           ELEMENTARY-PROCEDURE.&Request       ({Procedures}{@procedureCode})
+    }
+
+    MECHANISM ::= CLASS {
+        &id             OBJECT IDENTIFIER UNIQUE,
+        &Parameters
+    }
+    WITH SYNTAX {
+        ID              &id
+        PARAMETERS      &Parameters
+    }
+
+    Mechanisms MECHANISM ::= {
+        { ID { 1 2 3 } PARAMETERS SetupRequest } |
+        { ID { 1 2 4 } PARAMETERS ReleaseRequest },
+        ...
+    }
+
+    Algorithm ::= SEQUENCE {
+        id              MECHANISM.&id           ({Mechanisms}),
+        parameters      MECHANISM.&Parameters   ({Mechanisms}{@id})
+    }
+
+    END";
+
+fn model() -> Model<asn1rs::model::Asn> {
+    let tokens = Tokenizer::default().parse(SCHEMA);
+    Model::try_from(tokens).unwrap().try_resolve().unwrap()
+}
+
+fn sequence(components: Vec<(&str, DynamicValue)>) -> DynamicValue {
+    DynamicValue::Sequence(
+        components
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value))
+            .collect(),
+    )
+}
+
+fn choice(identifier: &str, value: DynamicValue) -> DynamicValue {
+    DynamicValue::Choice(identifier.to_string(), Box::new(value))
+}
+
+fn write_and_read_uper(type_name: &str, bits: usize, data: &[u8], value: &DynamicValue) {
+    let model = model();
+    let interpreter = Interpreter::new(&[&model]);
+
+    let mut writer = UperWriter::default();
+    interpreter
+        .write_uper(&mut writer, type_name, value)
+        .unwrap();
+    assert_eq!(
+        (bits, data),
+        (writer.bit_len(), writer.byte_content()),
+        "Serialized binary data does not match, bad-hex: {:02x?}",
+        writer.byte_content()
+    );
+
+    let mut reader = UperReader::from((data, bits));
+    assert_eq!(
+        value,
+        &interpreter.read_uper(&mut reader, type_name).unwrap()
+    );
+    assert_eq!(0, reader.bits_remaining());
+}
+
+#[test]
+fn test_sequence_with_choices_and_enumerated() {
+    write_and_read_uper(
+        "Drawing",
+        86,
+        &[32, 32, 169, 128, 233, 195, 138, 0, 8, 196, 140],
+        &sequence(vec![
+            ("id", DynamicValue::Integer(5)),
+            ("visible", DynamicValue::Boolean(true)),
+            ("color", DynamicValue::Enumerated("blue".to_string())),
+            (
+                "shapes",
+                DynamicValue::SequenceOf(vec![
+                    choice("circle", DynamicValue::Integer(7)),
+                    choice("label", DynamicValue::String("ab".to_string())),
+                    choice(
+                        "polygon",
+                        DynamicValue::SequenceOf(vec![
+                            DynamicValue::Integer(1),
+                            DynamicValue::Integer(2),
+                            DynamicValue::Integer(3),
+                        ]),
+                    ),
+                ]),
+            ),
+        ]),
+    );
+}
+
+#[test]
+fn test_sequence_with_extension_addition() {
+    write_and_read_uper(
+        "Drawing",
+        71,
+        &[192, 64, 37, 128, 2, 6, 4, 208, 210],
+        &sequence(vec![
+            ("id", DynamicValue::Integer(300)),
+            ("visible", DynamicValue::Boolean(false)),
+            ("shapes", DynamicValue::SequenceOf(Vec::new())),
+            ("note", DynamicValue::String("hi".to_string())),
+        ]),
+    );
+}
+
+#[test]
+fn test_sequence_with_fragmented_extension_addition() {
+    let model = model();
+    let interpreter = Interpreter::new(&[&model]);
+    let value = sequence(vec![
+        ("id", DynamicValue::Integer(300)),
+        ("visible", DynamicValue::Boolean(true)),
+        ("shapes", DynamicValue::SequenceOf(Vec::new())),
+        ("note", DynamicValue::String("a".repeat(20_000))),
+    ]);
+
+    let mut writer = UperWriter::default();
+    interpreter
+        .write_uper(&mut writer, "Drawing", &value)
+        .unwrap();
+    // the extension addition is larger than 16K octets and thus split into fragments
+    assert!(writer.byte_content().len() > 20_000);
+
+    let mut reader = UperReader::from((writer.byte_content(), writer.bit_len()));
+    assert_eq!(
+        value,
+        interpreter.read_uper(&mut reader, "Drawing").unwrap()
+    );
+    assert_eq!(0, reader.bits_remaining());
+}
+
+#[test]
+fn test_open_type_keyed_by_enumerated_sibling() {
+    write_and_read_uper(
+        "Message",
+        18,
+        &[0x40, 0x60, 0x00],
+        &sequence(vec![
+            (
+                "procedureCode",
+                DynamicValue::Enumerated("release".to_string()),
+            ),
+            (
+                "value",
+                choice(
+                    "ReleaseRequest",
+                    sequence(vec![("graceful", DynamicValue::Boolean(true))]),
+                ),
+            ),
+        ]),
+    );
+}
+
+#[test]
+fn test_open_type_keyed_by_object_identifier_sibling() {
+    write_and_read_uper(
+        "Algorithm",
+        40,
+        &[0x02, 0x2A, 0x04, 0x01, 0x80],
+        &sequence(vec![
+            (
+                "id",
+                DynamicValue::ObjectIdentifier(ObjectIdentifier::new(vec![1, 2, 4])),
+            ),
+            (
+                "parameters",
+                choice(
+                    "ReleaseRequest",
+                    sequence(vec![("graceful", DynamicValue::Boolean(true))]),
+                ),
+            ),
+        ]),
+    );
+}
+
+#[test]
+fn test_unknown_object_identifier_key_is_preserved() {
+    write_and_read_uper(
+        "Algorithm",
+        40,
+        &[0x02, 0x2A, 0x05, 0x01, 0x80],
+        &sequence(vec![
+            (
+                "id",
+                DynamicValue::ObjectIdentifier(ObjectIdentifier::new(vec![1, 2, 5])),
+            ),
+            ("parameters", DynamicValue::Unknown(vec![0x80])),
+        ]),
+    );
+}
+
+#[test]
+fn test_mismatching_value_is_rejected() {
+    let model = model();
+    let interpreter = Interpreter::new(&[&model]);
+    let mut writer = UperWriter::default();
+
+    let missing_shapes = sequence(vec![("id", DynamicValue::Integer(5))]);
+    assert!(matches!(
+        interpreter
+            .write_uper(&mut writer, "Drawing", &missing_shapes)
+            .unwrap_err()
+            .kind(),
+        ErrorKind::DynamicValueMismatch(_)
+    ));
+
+    assert_eq!(
+        &ErrorKind::UnknownType("Painting".to_string()),
+        interpreter
+            .write_uper(&mut writer, "Painting", &DynamicValue::Null)
+            .unwrap_err()
+            .kind()
+    );
+}
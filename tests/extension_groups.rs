@@ -0,0 +1,91 @@
+mod test_utils;
+
+use test_utils::*;
+
+asn_to_rust!(
+    r"ExtensionGroups DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Base ::= SEQUENCE {
+        a INTEGER (0..255),
+        b BOOLEAN,
+        ...,
+        ignored BOOLEAN
+    }
+
+    Extended ::= SEQUENCE {
+        COMPONENTS OF Base,
+        c BOOLEAN,
+        ...,
+        [[ 2:
+            d INTEGER (0..255),
+            e BOOLEAN OPTIONAL
+        ]],
+        f BOOLEAN OPTIONAL
+    }
+
+    END"
+);
+
+#[test]
+fn test_components_of_includes_the_root_components() {
+    let extended = Extended {
+        a: 5,
+        b: true,
+        c: false,
+        extension_group_1: None,
+        f: None,
+    };
+    // no field of Base after the extension marker is included
+    serialize_and_deserialize_uper(11, &[0x02, 0xC0], &extended);
+}
+
+#[test]
+fn test_extension_group_is_a_single_open_type() {
+    let extended = Extended {
+        a: 5,
+        b: true,
+        c: false,
+        extension_group_1: Some(ExtendedExtensionGroup1 { d: 7, e: None }),
+        f: None,
+    };
+    serialize_and_deserialize_uper(44, &[0x82, 0xC0, 0x60, 0x20, 0x38, 0x00], &extended);
+}
+
+#[test]
+fn test_extension_group_absent_and_later_addition_present() {
+    let extended = Extended {
+        a: 5,
+        b: true,
+        c: false,
+        extension_group_1: None,
+        f: Some(true),
+    };
+    serialize_and_deserialize_uper(36, &[0x82, 0xC0, 0x50, 0x18, 0x00], &extended);
+}
+
+mod extensibility_implied {
+    use super::*;
+
+    asn_to_rust!(
+        r"ExtensibilityImplied DEFINITIONS AUTOMATIC TAGS EXTENSIBILITY IMPLIED ::=
+        BEGIN
+
+        Record ::= SEQUENCE {
+            a BOOLEAN,
+            ...,
+            [[ b BOOLEAN ]]
+        }
+
+        END"
+    );
+}
+
+#[test]
+fn test_extension_group_is_not_extensible_itself() {
+    let record = extensibility_implied::Record {
+        a: true,
+        extension_group_1: Some(extensibility_implied::RecordExtensionGroup1 { b: true }),
+    };
+    serialize_and_deserialize_uper(26, &[0xC0, 0x40, 0x60, 0x00], &record);
+}
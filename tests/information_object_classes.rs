@@ -0,0 +1,306 @@
+mod test_utils;
+
+use asn1rs::io::per::err::ErrorKind;
+use test_utils::*;
+
+asn_to_rust!(
+    r#"InformationObjectClasses DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    IeId ::= INTEGER (0..65535)
+
+    Criticality ::= ENUMERATED { reject, ignore, notify }
+
+    PROTOCOL-IES ::= CLASS {
+        &id             IeId UNIQUE,
+        &criticality    Criticality,
+        &Value
+    }
+    WITH SYNTAX {
+        ID              &id
+        CRITICALITY     &criticality
+        TYPE            &Value
+    }
+
+    ProtocolIE-Field {PROTOCOL-IES : IEsSetParam} ::= SEQUENCE {
+        id              PROTOCOL-IES.&id            ({IEsSetParam}),
+        criticality     PROTOCOL-IES.&criticality   ({IEsSetParam}{@id}),
+        value           PROTOCOL-IES.&Value         ({IEsSetParam}{@id})
+    }
+
+    ProtocolIE-Container {PROTOCOL-IES : IEsSetParam} ::=
+        SEQUENCE (SIZE (0..8)) OF ProtocolIE-Field {{IEsSetParam}}
+
+    id-name INTEGER ::= 1
+    id-age  INTEGER ::= 2
+
+    Name ::= UTF8String
+
+    Age ::= INTEGER (0..255)
+
+    name-ie PROTOCOL-IES ::= { ID id-name CRITICALITY reject TYPE Name }
+
+    PersonIEs PROTOCOL-IES ::= {
+        name-ie |
+        { ID id-age CRITICALITY ignore TYPE Age },
+        ...
+    }
+
+    Person ::= SEQUENCE {
+        protocolIEs ProtocolIE-Container {{PersonIEs}}
+    }
+
+    END"#
+);
+
+mod keyed {
+    use super::*;
+
+    asn_to_rust!(
+        r#"KeyedOpenTypes DEFINITIONS AUTOMATIC TAGS ::=
+        BEGIN
+
+        ProcedureCode ::= ENUMERATED { setup, release, ... }
+
+        ELEMENTARY-PROCEDURE ::= CLASS {
+            &Request,
+            &procedureCode  ProcedureCode UNIQUE
+        }
+        WITH SYNTAX {
+            REQUEST         &Request
+            PROCEDURE CODE  &procedureCode
+        }
+
+        SetupRequest ::= SEQUENCE { ue INTEGER (0..255) }
+
+        ReleaseRequest ::= SEQUENCE { graceful BOOLEAN }
+
+        Procedures ELEMENTARY-PROCEDURE ::= {
+            { REQUEST SetupRequest PROCEDURE CODE setup } |
+            { REQUEST ReleaseRequest PROCEDURE CODE release },
+            ...
+        }
+
+        Message ::= SEQUENCE {
+            procedureCode   ELEMENTARY-PROCEDURE.&procedureCode ({Procedures}),
+            value           ELEMENTARY-PROCEDURE.&Request       ({Procedures}{@procedureCode})
+        }
+
+        MECHANISM ::= CLASS {
+            &id             OBJECT IDENTIFIER UNIQUE,
+            &Parameters
+        }
+        WITH SYNTAX {
+            ID              &id
+            PARAMETERS      &Parameters
+        }
+
+        id-mechanism-a OBJECT IDENTIFIER ::= { 1 2 3 }
+
+        Mechanisms MECHANISM ::= {
+            { ID id-mechanism-a PARAMETERS SetupRequest } |
+            { ID { 1 2 4 } PARAMETERS ReleaseRequest },
+            ...
+        }
+
+        Algorithm ::= SEQUENCE {
+            id              MECHANISM.&id           ({Mechanisms}),
+            parameters      MECHANISM.&Parameters   ({Mechanisms}{@id})
+        }
+
+        TRANSFER ::= CLASS {
+            &code           INTEGER (-100..100) UNIQUE,
+            &Payload
+        }
+        WITH SYNTAX {
+            CODE            &code
+            PAYLOAD         &Payload
+        }
+
+        Transfers TRANSFER ::= {
+            { CODE 1 PAYLOAD SetupRequest },
+            ...
+        }
+
+        Transfer ::= SEQUENCE {
+            code            TRANSFER.&code          ({Transfers}),
+            payload         TRANSFER.&Payload       ({Transfers}{@code})
+        }
+
+        END"#
+    );
+}
+
+#[test]
+fn test_open_type_variants_are_keyed_by_the_object_set() {
+    serialize_and_deserialize_uper(
+        88,
+        &[
+            0x20, 0x00, 0x10, 0x0C, 0x09, 0x85, 0x88, 0x00, 0x09, 0x01, 0x1E,
+        ],
+        &Person {
+            protocol_i_es: ProtocolIeContainerPersonIEs(vec![
+                ProtocolIeFieldPersonIEs {
+                    id: IeId(1),
+                    criticality: Criticality::Reject,
+                    value: ProtocolIeFieldPersonIEsValue::Name(Name("ab".to_string())),
+                },
+                ProtocolIeFieldPersonIEs {
+                    id: IeId(2),
+                    criticality: Criticality::Ignore,
+                    value: ProtocolIeFieldPersonIEsValue::Age(Age(30)),
+                },
+            ]),
+        },
+    );
+}
+
+#[test]
+fn test_unknown_key_is_preserved() {
+    // the id 3 is not defined in the object set
+    serialize_and_deserialize_uper(
+        38,
+        &[0x10, 0x00, 0x30, 0x04, 0x78],
+        &Person {
+            protocol_i_es: ProtocolIeContainerPersonIEs(vec![ProtocolIeFieldPersonIEs {
+                id: IeId(3),
+                criticality: Criticality::Reject,
+                value: ProtocolIeFieldPersonIEsValue::Unknown {
+                    key: 3,
+                    bytes: vec![0x1E],
+                },
+            }]),
+        },
+    );
+}
+
+#[test]
+fn test_unknown_key_is_preserved_aper() {
+    // the id 3 is not defined in the object set
+    serialize_and_deserialize_aper(
+        48,
+        &[0x10, 0x00, 0x03, 0x00, 0x01, 0x1E],
+        &Person {
+            protocol_i_es: ProtocolIeContainerPersonIEs(vec![ProtocolIeFieldPersonIEs {
+                id: IeId(3),
+                criticality: Criticality::Reject,
+                value: ProtocolIeFieldPersonIEsValue::Unknown {
+                    key: 3,
+                    bytes: vec![0x1E],
+                },
+            }]),
+        },
+    );
+}
+
+#[test]
+fn test_unknown_key_is_preserved_oer() {
+    // the id 3 is not defined in the object set
+    serialize_and_deserialize_oer(
+        &[0x01, 0x01, 0x00, 0x03, 0x00, 0x01, 0x1E],
+        &Person {
+            protocol_i_es: ProtocolIeContainerPersonIEs(vec![ProtocolIeFieldPersonIEs {
+                id: IeId(3),
+                criticality: Criticality::Reject,
+                value: ProtocolIeFieldPersonIEsValue::Unknown {
+                    key: 3,
+                    bytes: vec![0x1E],
+                },
+            }]),
+        },
+    );
+}
+
+#[test]
+fn test_unknown_key_is_rejected_by_der() {
+    let mut writer = BerWriter::der();
+    let result = writer.write(&Person {
+        protocol_i_es: ProtocolIeContainerPersonIEs(vec![ProtocolIeFieldPersonIEs {
+            id: IeId(3),
+            criticality: Criticality::Reject,
+            value: ProtocolIeFieldPersonIEsValue::Unknown {
+                key: 3,
+                bytes: vec![0x1E],
+            },
+        }]),
+    });
+    assert!(result.is_err());
+}
+
+#[test]
+#[cfg(feature = "protobuf")]
+fn test_open_type_protobuf_carries_key_and_payload() {
+    serialize_and_deserialize_protobuf(
+        &[
+            10, 34, 10, 16, 10, 2, 8, 1, 16, 0, 26, 8, 8, 1, 18, 4, 10, 2, 97, 98, 10, 14, 10, 2,
+            8, 2, 16, 1, 26, 6, 8, 2, 26, 2, 8, 30,
+        ],
+        &Person {
+            protocol_i_es: ProtocolIeContainerPersonIEs(vec![
+                ProtocolIeFieldPersonIEs {
+                    id: IeId(1),
+                    criticality: Criticality::Reject,
+                    value: ProtocolIeFieldPersonIEsValue::Name(Name("ab".to_string())),
+                },
+                ProtocolIeFieldPersonIEs {
+                    id: IeId(2),
+                    criticality: Criticality::Ignore,
+                    value: ProtocolIeFieldPersonIEsValue::Age(Age(30)),
+                },
+            ]),
+        },
+    );
+}
+
+#[test]
+fn test_open_type_keyed_by_enumerated_sibling() {
+    serialize_and_deserialize_uper(
+        18,
+        &[0x40, 0x60, 0x00],
+        &keyed::Message {
+            procedure_code: keyed::ProcedureCode::Release,
+            value: keyed::MessageValue::ReleaseRequest(keyed::ReleaseRequest { graceful: true }),
+        },
+    );
+}
+
+#[test]
+fn test_open_type_keyed_by_object_identifier_sibling() {
+    serialize_and_deserialize_uper(
+        40,
+        &[0x02, 0x2A, 0x04, 0x01, 0x80],
+        &keyed::Algorithm {
+            id: ObjectIdentifier::new(vec![1, 2, 4]),
+            parameters: keyed::AlgorithmParameters::ReleaseRequest(keyed::ReleaseRequest {
+                graceful: true,
+            }),
+        },
+    );
+}
+
+#[test]
+fn test_unknown_object_identifier_key_is_rejected() {
+    // the id 1.2.5 is not defined in the object set and is no key of it
+    let mut reader = UperReader::from((&[0x02, 0x2A, 0x05, 0x01, 0x80][..], 40));
+    assert_eq!(
+        &ErrorKind::InvalidOpenTypeKey("AlgorithmParameters"),
+        reader.read::<keyed::Algorithm>().unwrap_err().kind()
+    );
+}
+
+#[test]
+fn test_negative_integer_key_is_rejected() {
+    // negative values cannot be keys of the object set
+    let (bits, bytes) = serialize_uper(&keyed::Transfer {
+        code: -1,
+        payload: keyed::TransferPayload::Unknown {
+            key: 0,
+            bytes: vec![0x80],
+        },
+    });
+    let mut reader = UperReader::from((&bytes[..], bits));
+    assert_eq!(
+        &ErrorKind::InvalidOpenTypeKey("TransferPayload"),
+        reader.read::<keyed::Transfer>().unwrap_err().kind()
+    );
+}
@@ -0,0 +1,204 @@
+#![recursion_limit = "512"]
+
+mod test_utils;
+
+use asn1rs::io::per::err::ErrorKind;
+use asn1rs::syn::ViolationKind;
+use test_utils::*;
+
+asn_to_rust!(
+    r#"InnerTypeConstraints DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Level ::= ENUMERATED {
+        low,
+        medium,
+        high
+    }
+
+    Settings ::= SEQUENCE {
+        id INTEGER (0..255),
+        enabled BOOLEAN,
+        level Level,
+        label UTF8String OPTIONAL,
+        limit INTEGER (0..1000) OPTIONAL
+    }
+
+    ActiveSettings ::= Settings (WITH COMPONENTS {
+        ...,
+        id (1..10),
+        enabled (TRUE),
+        level (high)
+    })
+
+    LabeledSettings ::= Settings (WITH COMPONENTS {
+        ...,
+        label PRESENT,
+        limit (0..100)
+    })
+
+    END"#
+);
+
+#[asn(sequence, components(code(present), value(1..10)))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProcMacroLabeled {
+    #[asn(integer(0..255))]
+    pub value: u8,
+    #[asn(optional(utf8string))]
+    pub code: String,
+}
+
+#[asn(transparent, components(id(1..10), enabled(true)))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProcMacroActive(#[asn(complex(Settings, tag(UNIVERSAL(16))))] pub Settings);
+
+fn settings() -> Settings {
+    Settings {
+        id: 5,
+        enabled: true,
+        level: Level::High,
+        label: None,
+        limit: Some(50),
+    }
+}
+
+fn labeled() -> LabeledSettings {
+    LabeledSettings {
+        id: 5,
+        enabled: true,
+        level: Level::High,
+        label: "label".to_string(),
+        limit: Some(50),
+    }
+}
+
+fn uper_error(value: &impl Writable) -> ErrorKind {
+    let mut writer = UperWriter::default();
+    writer.write(value).unwrap_err().kind().clone()
+}
+
+#[test]
+fn test_permitted_values_are_encoded_like_the_parent() {
+    let (bits, bytes) = serialize_uper(&settings());
+    serialize_and_deserialize_uper(bits, &bytes, &ActiveSettings(settings()));
+    serialize_and_deserialize_uper(bits, &bytes, &ProcMacroActive(settings()));
+}
+
+#[test]
+fn test_value_not_in_range_is_rejected() {
+    let settings = Settings {
+        id: 11,
+        ..settings()
+    };
+    assert_eq!(
+        ErrorKind::ComponentConstraintViolated("id", ViolationKind::ValueNotPermitted),
+        uper_error(&ActiveSettings(settings.clone()))
+    );
+    assert_eq!(
+        ErrorKind::ComponentConstraintViolated("id", ViolationKind::ValueNotPermitted),
+        uper_error(&ProcMacroActive(settings))
+    );
+}
+
+#[test]
+fn test_boolean_and_enumerated_values_are_rejected() {
+    assert_eq!(
+        ErrorKind::ComponentConstraintViolated("enabled", ViolationKind::ValueNotPermitted),
+        uper_error(&ActiveSettings(Settings {
+            enabled: false,
+            ..settings()
+        }))
+    );
+    assert_eq!(
+        ErrorKind::ComponentConstraintViolated("level", ViolationKind::ValueNotPermitted),
+        uper_error(&ActiveSettings(Settings {
+            level: Level::Medium,
+            ..settings()
+        }))
+    );
+}
+
+#[test]
+fn test_other_encoders_reject_values() {
+    let active = ActiveSettings(Settings {
+        id: 0,
+        ..settings()
+    });
+    assert!(BerWriter::default().write(&active).is_err());
+    assert!(OerWriter::default().write(&active).is_err());
+    assert!(JerWriter::default().write(&active).is_err());
+    assert!(XerWriter::default().write(&active).is_err());
+    assert!(AperWriter::default().write(&active).is_err());
+}
+
+#[test]
+fn test_absent_optional_value_is_permitted() {
+    let without_limit = LabeledSettings {
+        limit: None,
+        ..labeled()
+    };
+    assert!(UperWriter::default().write(&without_limit).is_ok());
+    assert_eq!(
+        ErrorKind::ComponentConstraintViolated("limit", ViolationKind::ValueNotPermitted),
+        uper_error(&LabeledSettings {
+            limit: Some(101),
+            ..labeled()
+        })
+    );
+}
+
+#[test]
+fn test_present_field_is_encoded_as_optional() {
+    let settings = Settings {
+        label: Some("label".to_string()),
+        ..settings()
+    };
+    let (bits, bytes) = serialize_uper(&settings);
+    serialize_and_deserialize_uper(bits, &bytes, &labeled());
+    assert_eq!(labeled(), deserialize_ber(&serialize_ber(&labeled())));
+    assert_eq!(labeled(), deserialize_jer(&serialize_jer(&labeled())));
+}
+
+#[test]
+fn test_absent_present_field_is_rejected_by_decoders() {
+    let (bits, bytes) = serialize_uper(&settings());
+    let mut reader = UperReader::from((&bytes[..], bits));
+    assert_eq!(
+        &ErrorKind::ComponentConstraintViolated("label", ViolationKind::ComponentNotPresent),
+        reader.read::<LabeledSettings>().unwrap_err().kind()
+    );
+    assert!(OerReader::from(&serialize_oer(&settings())[..])
+        .read::<LabeledSettings>()
+        .is_err());
+}
+
+#[test]
+fn test_proc_macro_present_field() {
+    let value = ProcMacroLabeled {
+        value: 3,
+        code: "code".to_string(),
+    };
+    let (bits, bytes) = serialize_uper(&value);
+    serialize_and_deserialize_uper(bits, &bytes, &value);
+    assert_eq!(
+        ErrorKind::ComponentConstraintViolated("value", ViolationKind::ValueNotPermitted),
+        uper_error(&ProcMacroLabeled { value: 0, ..value })
+    );
+}
+
+#[test]
+fn test_validate_reports_value_violations() {
+    let active = ActiveSettings(Settings {
+        id: 0,
+        level: Level::Low,
+        ..settings()
+    });
+    let violations = active.validate().unwrap_err();
+    assert_eq!(2, violations.len());
+    assert_eq!(Some(&ViolationKind::ValueNotPermitted), violations.at("id"));
+    assert_eq!(
+        Some(&ViolationKind::ValueNotPermitted),
+        violations.at("level")
+    );
+}
@@ -0,0 +1,62 @@
+mod test_utils;
+
+use test_utils::*;
+
+asn_to_rust!(
+    r#"ParameterizedTypes DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Container{Payload, INTEGER : maxItems} ::= SEQUENCE {
+        header INTEGER (0..255),
+        items SEQUENCE (SIZE(1..maxItems)) OF Payload
+    }
+
+    Wrapper{Inner} ::= SEQUENCE {
+        container Container{Inner, 4}
+    }
+
+    Bounded{INTEGER : Bounds} ::= SEQUENCE {
+        value INTEGER (Bounds)
+    }
+
+    MyMessage ::= BOOLEAN
+
+    Message ::= Container{MyMessage, 2}
+
+    Wrapped ::= Wrapper{MyMessage}
+
+    Small ::= Bounded{{0..7}}
+
+    END"#
+);
+
+#[test]
+fn test_instantiation_with_type_and_value() {
+    serialize_and_deserialize_uper(
+        11,
+        &[0x05, 0xC0],
+        &Message(ContainerMyMessage2 {
+            header: 5,
+            items: vec![MyMessage(true), MyMessage(false)],
+        }),
+    );
+}
+
+#[test]
+fn test_nested_instantiation() {
+    serialize_and_deserialize_uper(
+        12,
+        &[0x05, 0x50],
+        &Wrapped(WrapperMyMessage {
+            container: ContainerMyMessage4 {
+                header: 5,
+                items: vec![MyMessage(false), MyMessage(true)],
+            },
+        }),
+    );
+}
+
+#[test]
+fn test_instantiation_with_value_set() {
+    serialize_and_deserialize_uper(3, &[0xA0], &Small(Bounded07 { value: 5 }));
+}
@@ -74,8 +74,11 @@ pub fn emulate_macro_expansion_fallible(mut file: fs::File) {
     ) {
         for attr in attributes {
             if attr.path == *attribute_path {
-                let attribute_meta = attr.parse_meta().unwrap();
-                let attribute_meta = attribute_meta.into_token_stream().to_string();
+                let attribute_meta = match attr.parse_meta() {
+                    Ok(attribute_meta) => attribute_meta.into_token_stream().to_string(),
+                    // `components(id(1..10))` of WITH COMPONENTS constraints is no valid meta
+                    Err(_) => attr.tokens.to_string(),
+                };
 
                 let item = item();
                 // skip 'asn (' and ')'
@@ -0,0 +1,111 @@
+mod test_utils;
+
+use test_utils::*;
+
+mod explicit_tags {
+    use super::*;
+
+    asn_to_rust!(
+        r"ExplicitTags DEFINITIONS EXPLICIT TAGS ::=
+        BEGIN
+
+        Record ::= SEQUENCE {
+            id [0] INTEGER,
+            flag [1] IMPLICIT BOOLEAN,
+            name UTF8String OPTIONAL
+        }
+
+        Selection ::= CHOICE {
+            text UTF8String,
+            number INTEGER
+        }
+
+        END"
+    );
+}
+
+mod implicit_tags {
+    use super::*;
+
+    asn_to_rust!(
+        r"ImplicitTags DEFINITIONS IMPLICIT TAGS EXTENSIBILITY IMPLIED ::=
+        BEGIN
+
+        Record ::= SEQUENCE {
+            id [0] INTEGER,
+            flag [1] EXPLICIT BOOLEAN
+        }
+
+        END"
+    );
+}
+
+#[test]
+fn test_explicit_tags_wrap_the_value() {
+    let record = explicit_tags::Record {
+        id: 5,
+        flag: true,
+        name: None,
+    };
+    serialize_and_deserialize_der(
+        &[0x30, 0x08, 0xA0, 0x03, 0x02, 0x01, 0x05, 0x81, 0x01, 0xFF],
+        &record,
+    );
+}
+
+#[test]
+fn test_explicit_tags_are_not_assigned_automatically() {
+    let record = explicit_tags::Record {
+        id: 5,
+        flag: false,
+        name: Some("a".to_string()),
+    };
+    serialize_and_deserialize_der(
+        &[
+            0x30, 0x0B, 0xA0, 0x03, 0x02, 0x01, 0x05, 0x81, 0x01, 0x00, 0x0C, 0x01, 0x61,
+        ],
+        &record,
+    );
+}
+
+#[test]
+fn test_explicit_tags_oer_is_unaffected() {
+    let record = explicit_tags::Record {
+        id: 5,
+        flag: true,
+        name: Some("a".to_string()),
+    };
+    serialize_and_deserialize_oer(&[0x80, 0x01, 0x05, 0xFF, 0x01, 0x61], &record);
+}
+
+#[test]
+fn test_choice_index_in_canonical_order_of_tags() {
+    // INTEGER (UNIVERSAL 2) precedes UTF8String (UNIVERSAL 12)
+    serialize_and_deserialize_uper(
+        17,
+        &[0x00, 0x82, 0x80],
+        &explicit_tags::Selection::Number(5),
+    );
+    serialize_and_deserialize_uper(
+        17,
+        &[0x80, 0xB0, 0x80],
+        &explicit_tags::Selection::Text("a".to_string()),
+    );
+    serialize_and_deserialize_der(&[0x02, 0x01, 0x05], &explicit_tags::Selection::Number(5));
+}
+
+#[test]
+fn test_implicit_tags_replace_the_tag() {
+    let record = implicit_tags::Record { id: 5, flag: true };
+    serialize_and_deserialize_der(
+        &[0x30, 0x08, 0x80, 0x01, 0x05, 0xA1, 0x03, 0x01, 0x01, 0xFF],
+        &record,
+    );
+}
+
+#[test]
+fn test_extensibility_implied() {
+    // the leading bit is the extension bit
+    let record = implicit_tags::Record { id: 5, flag: true };
+    serialize_and_deserialize_uper(18, &[0x00, 0x82, 0xC0], &record);
+}
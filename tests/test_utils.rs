@@ -39,6 +39,167 @@ pub fn serialize_and_deserialize_uper<T: Readable + Writable + std::fmt::Debug +
     );
 }
 
+pub fn serialize_aper(to_aper: &impl Writable) -> (usize, Vec<u8>) {
+    let mut writer = AperWriter::default();
+    writer.write(to_aper).unwrap();
+    let bits = writer.bit_len();
+    (bits, writer.into_bytes_vec())
+}
+
+pub fn deserialize_aper<T: Readable>(data: &[u8], bits: usize) -> T {
+    let mut reader = AperReader::from((data, bits));
+    let result = reader.read::<T>().unwrap();
+    assert_eq!(
+        0,
+        reader.bits_remaining(),
+        "After reading, there are still bits remaining!"
+    );
+    result
+}
+
+pub fn serialize_and_deserialize_aper<T: Readable + Writable + std::fmt::Debug + PartialEq>(
+    bits: usize,
+    data: &[u8],
+    aper: &T,
+) {
+    let serialized = serialize_aper(aper);
+    assert_eq!(
+        (bits, data),
+        (serialized.0, &serialized.1[..]),
+        "Serialized binary data does not match, bad-hex: {:02x?}",
+        &serialized.1[..]
+    );
+    assert_eq!(
+        aper,
+        &deserialize_aper::<T>(data, bits),
+        "Deserialized data struct does not match"
+    );
+}
+
+pub fn serialize_ber(to_ber: &impl Writable) -> Vec<u8> {
+    let mut writer = BerWriter::default();
+    writer.write(to_ber).unwrap();
+    writer.into_bytes_vec()
+}
+
+pub fn serialize_der(to_der: &impl Writable) -> Vec<u8> {
+    let mut writer = BerWriter::der();
+    writer.write(to_der).unwrap();
+    writer.into_bytes_vec()
+}
+
+pub fn deserialize_ber<T: Readable>(data: &[u8]) -> T {
+    let mut reader = BerReader::from(data);
+    let result = reader.read::<T>().unwrap();
+    assert_eq!(
+        0,
+        reader.bytes_remaining(),
+        "After reading, there are still bytes remaining!"
+    );
+    result
+}
+
+pub fn serialize_and_deserialize_der<T: Readable + Writable + std::fmt::Debug + PartialEq>(
+    data: &[u8],
+    der: &T,
+) {
+    let serialized = serialize_der(der);
+    assert_eq!(
+        data,
+        &serialized[..],
+        "Serialized binary data does not match, bad-hex: {:02x?}",
+        &serialized[..]
+    );
+    assert_eq!(
+        der,
+        &deserialize_ber::<T>(data),
+        "Deserialized data struct does not match"
+    );
+}
+
+pub fn serialize_jer(to_jer: &impl Writable) -> String {
+    let mut writer = JerWriter::default();
+    writer.write(to_jer).unwrap();
+    writer.into_string()
+}
+
+pub fn deserialize_jer<T: Readable>(text: &str) -> T {
+    let mut reader = JerReader::from(text);
+    reader.read::<T>().unwrap()
+}
+
+pub fn serialize_and_deserialize_jer<T: Readable + Writable + std::fmt::Debug + PartialEq>(
+    text: &str,
+    jer: &T,
+) {
+    let serialized = serialize_jer(jer);
+    assert_eq!(text, serialized, "Serialized JSON text does not match");
+    assert_eq!(
+        jer,
+        &deserialize_jer::<T>(text),
+        "Deserialized data struct does not match"
+    );
+}
+
+pub fn serialize_xer(to_xer: &impl Writable) -> String {
+    let mut writer = XerWriter::default();
+    writer.write(to_xer).unwrap();
+    writer.into_string()
+}
+
+pub fn deserialize_xer<T: Readable>(text: &str) -> T {
+    let mut reader = XerReader::from(text);
+    reader.read::<T>().unwrap()
+}
+
+pub fn serialize_and_deserialize_xer<T: Readable + Writable + std::fmt::Debug + PartialEq>(
+    text: &str,
+    xer: &T,
+) {
+    let serialized = serialize_xer(xer);
+    assert_eq!(text, serialized, "Serialized XML document does not match");
+    assert_eq!(
+        xer,
+        &deserialize_xer::<T>(text),
+        "Deserialized data struct does not match"
+    );
+}
+
+pub fn serialize_oer(to_oer: &impl Writable) -> Vec<u8> {
+    let mut writer = OerWriter::default();
+    writer.write(to_oer).unwrap();
+    writer.into_bytes_vec()
+}
+
+pub fn deserialize_oer<T: Readable>(data: &[u8]) -> T {
+    let mut reader = OerReader::from(data);
+    let result = reader.read::<T>().unwrap();
+    assert_eq!(
+        0,
+        reader.bytes_remaining(),
+        "After reading, there are still bytes remaining!"
+    );
+    result
+}
+
+pub fn serialize_and_deserialize_oer<T: Readable + Writable + std::fmt::Debug + PartialEq>(
+    data: &[u8],
+    oer: &T,
+) {
+    let serialized = serialize_oer(oer);
+    assert_eq!(
+        data,
+        &serialized[..],
+        "Serialized binary data does not match, bad-hex: {:02x?}",
+        &serialized[..]
+    );
+    assert_eq!(
+        oer,
+        &deserialize_oer::<T>(data),
+        "Deserialized data struct does not match"
+    );
+}
+
 #[cfg(feature = "protobuf")]
 pub fn serialize_protobuf(to_protobuf: &impl Writable) -> Vec<u8> {
     let mut writer = ProtobufWriter::default();
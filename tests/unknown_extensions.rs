@@ -0,0 +1,214 @@
+use asn1rs::prelude::*;
+
+mod test_utils;
+use test_utils::*;
+
+/// The newer revision of the schema, which is unknown to the relaying peer
+#[asn(sequence, extensible_after(b))]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct RecordV2 {
+    #[asn(integer(0..255))]
+    a: u8,
+    #[asn(boolean)]
+    b: bool,
+    #[asn(optional(integer(0..255)))]
+    c: Option<u8>,
+    #[asn(optional(boolean))]
+    d: Option<bool>,
+    #[asn(optional(utf8string))]
+    e: Option<String>,
+}
+
+#[asn(sequence, extensible_after(b), unknown_extensions)]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct RecordV1 {
+    #[asn(integer(0..255))]
+    a: u8,
+    #[asn(boolean)]
+    b: bool,
+    #[asn(optional(integer(0..255)))]
+    c: Option<u8>,
+    unknown_extensions: UnknownExtensions,
+}
+
+#[asn(sequence, extensible_after(b))]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct RecordV1Plain {
+    #[asn(integer(0..255))]
+    a: u8,
+    #[asn(boolean)]
+    b: bool,
+    #[asn(optional(integer(0..255)))]
+    c: Option<u8>,
+}
+
+#[asn(sequence)]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct EnvelopeV2 {
+    #[asn(complex(RecordV2, tag(UNIVERSAL(16))))]
+    record: RecordV2,
+    #[asn(boolean)]
+    trailer: bool,
+}
+
+#[asn(sequence)]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct EnvelopeV1Plain {
+    #[asn(complex(RecordV1Plain, tag(UNIVERSAL(16))))]
+    record: RecordV1Plain,
+    #[asn(boolean)]
+    trailer: bool,
+}
+
+#[asn(enumerated, extensible_after(Green))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ColorV2 {
+    Red,
+    Green,
+    Blue,
+    Yellow,
+}
+
+#[asn(enumerated, extensible_after(Green), unknown_extensions)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ColorV1 {
+    Red,
+    Green,
+    Blue,
+    UnknownExtension(u64),
+}
+
+#[asn(choice, extensible_after(Number))]
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValueV2 {
+    #[asn(integer(0..255))]
+    Number(u8),
+    #[asn(utf8string)]
+    Text(String),
+    #[asn(boolean)]
+    Flag(bool),
+}
+
+#[asn(choice, extensible_after(Number), unknown_extensions)]
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValueV1 {
+    #[asn(integer(0..255))]
+    Number(u8),
+    UnknownExtension(UnknownExtension),
+}
+
+fn relay<F: Writable, T: Readable + Writable>(value: &F) -> (T, (usize, Vec<u8>)) {
+    let (bits, bytes) = serialize_uper(value);
+    let relayed = deserialize_uper::<T>(&bytes, bits);
+    let reencoded = serialize_uper(&relayed);
+    assert_eq!((bits, bytes), reencoded, "Relayed message differs");
+    (relayed, reencoded)
+}
+
+fn v2() -> RecordV2 {
+    RecordV2 {
+        a: 5,
+        b: true,
+        c: Some(7),
+        d: None,
+        e: Some("hi".to_string()),
+    }
+}
+
+#[test]
+fn test_sequence_unknown_extensions_are_reencoded_verbatim() {
+    let (relayed, _) = relay::<_, RecordV1>(&v2());
+    assert_eq!(5, relayed.a);
+    assert!(relayed.b);
+    assert_eq!(Some(7), relayed.c);
+    assert_eq!(2, relayed.unknown_extensions.len());
+    assert_eq!(
+        vec![None, Some(&[0x02, b'h', b'i'][..])],
+        relayed.unknown_extensions.iter().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_sequence_unknown_extensions_without_known_extension() {
+    let record = RecordV2 {
+        c: None,
+        d: Some(true),
+        ..v2()
+    };
+    let (relayed, _) = relay::<_, RecordV1>(&record);
+    assert_eq!(None, relayed.c);
+    assert_eq!(
+        vec![Some(&[0x80][..]), Some(&[0x02, b'h', b'i'][..])],
+        relayed.unknown_extensions.iter().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_sequence_without_unknown_extensions() {
+    let record = RecordV2 {
+        d: None,
+        e: None,
+        ..v2()
+    };
+    let (relayed, _) = relay::<_, RecordV1>(&record);
+    assert_eq!(Some(7), relayed.c);
+    assert_eq!(2, relayed.unknown_extensions.len());
+    assert!(!relayed.unknown_extensions.any_present());
+}
+
+#[test]
+fn test_sequence_unknown_extensions_are_skipped_if_not_preserved() {
+    let envelope = EnvelopeV2 {
+        record: v2(),
+        trailer: true,
+    };
+    let (bits, bytes) = serialize_uper(&envelope);
+    assert_eq!(
+        EnvelopeV1Plain {
+            record: RecordV1Plain {
+                a: 5,
+                b: true,
+                c: Some(7),
+            },
+            trailer: true,
+        },
+        deserialize_uper::<EnvelopeV1Plain>(&bytes, bits)
+    );
+}
+
+#[test]
+fn test_sequence_older_sender_with_fewer_extensions() {
+    let record = RecordV1Plain {
+        a: 5,
+        b: true,
+        c: Some(7),
+    };
+    let (bits, bytes) = serialize_uper(&record);
+    assert_eq!(
+        RecordV2 {
+            d: None,
+            e: None,
+            ..v2()
+        },
+        deserialize_uper::<RecordV2>(&bytes, bits)
+    );
+}
+
+#[test]
+fn test_enumerated_unknown_extension() {
+    let (relayed, _) = relay::<_, ColorV1>(&ColorV2::Yellow);
+    assert_eq!(ColorV1::UnknownExtension(3), relayed);
+    let (relayed, _) = relay::<_, ColorV1>(&ColorV2::Blue);
+    assert_eq!(ColorV1::Blue, relayed);
+}
+
+#[test]
+fn test_choice_unknown_extension() {
+    let (relayed, _) = relay::<_, ValueV1>(&ValueV2::Flag(true));
+    assert_eq!(
+        ValueV1::UnknownExtension(UnknownExtension::new(2, vec![0x80])),
+        relayed
+    );
+    let (relayed, _) = relay::<_, ValueV1>(&ValueV2::Number(3));
+    assert_eq!(ValueV1::Number(3), relayed);
+}
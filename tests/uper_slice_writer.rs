@@ -0,0 +1,113 @@
+use asn1rs::io::per::unaligned::buffer::BitBuffer;
+use asn1rs::io::per::unaligned::BitWrite;
+use asn1rs::io::per::{Error, ErrorKind, PackedWrite};
+use asn1rs::prelude::*;
+
+mod test_utils;
+use test_utils::*;
+
+#[asn(sequence, extensible_after(flag))]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Frame {
+    #[asn(boolean)]
+    flag: bool,
+    #[asn(optional(octet_string))]
+    payload: Option<Vec<u8>>,
+}
+
+#[asn(choice, extensible_after(Number))]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    #[asn(integer(0..255))]
+    Number(u8),
+    #[asn(utf8string)]
+    Text(String),
+}
+
+/// The sizes around the boundaries of the length determinants and the fragments
+const SIZES: [usize; 9] = [0, 1, 126, 127, 128, 16_382, 16_383, 16_384, 70_000];
+
+fn frame(size: usize) -> Frame {
+    Frame {
+        flag: true,
+        payload: Some((0..size).map(|i| i as u8).collect()),
+    }
+}
+
+/// Encodes the extension addition of the [`Frame`] as open type by hand
+fn expected(frame: &Frame) -> Vec<u8> {
+    let payload = frame.payload.as_ref().unwrap();
+    let mut content = BitBuffer::default();
+    content
+        .write_octetstring(None, None, false, payload)
+        .unwrap();
+
+    let mut bits = BitBuffer::default();
+    bits.write_bit(true).unwrap();
+    bits.write_bit(frame.flag).unwrap();
+    bits.write_normally_small_non_negative_whole_number(0)
+        .unwrap();
+    bits.write_bit(true).unwrap();
+    bits.write_octetstring(None, None, false, content.content())
+        .unwrap();
+    bits.into()
+}
+
+fn serialize_into<'a>(buffer: &'a mut [u8], value: &impl Writable) -> Result<&'a [u8], Error> {
+    let mut writer = UperWriter::from(&mut *buffer);
+    writer.write(value)?;
+    let len = writer.byte_content().len();
+    drop(writer);
+    Ok(&buffer[..len])
+}
+
+#[test]
+fn test_slice_writer_matches_growing_writer() {
+    for size in SIZES {
+        let frame = frame(size);
+        let (bits, bytes) = serialize_uper(&frame);
+        assert_eq!(expected(&frame), bytes, "size {}", size);
+
+        // the remains of a previous message must not leak into the padding
+        let mut buffer = vec![0xA5; bytes.len() + 16];
+        let mut writer = UperWriter::from(&mut buffer[..]);
+        writer.write(&frame).unwrap();
+        assert_eq!(bits, writer.bit_len(), "size {}", size);
+        assert_eq!(&bytes[..], writer.byte_content(), "size {}", size);
+
+        // the UperReader does not reassemble fragmented open types
+        if size < 16_000 {
+            assert_eq!(frame, deserialize_uper::<Frame>(&bytes, bits));
+        }
+    }
+}
+
+#[test]
+fn test_slice_writer_fails_on_insufficient_space() {
+    for size in SIZES {
+        let frame = frame(size);
+        let (_, bytes) = serialize_uper(&frame);
+
+        let mut buffer = vec![0u8; bytes.len()];
+        assert_eq!(&bytes[..], serialize_into(&mut buffer, &frame).unwrap());
+
+        let mut buffer = vec![0u8; bytes.len() - 1];
+        assert!(matches!(
+            serialize_into(&mut buffer, &frame).unwrap_err().kind(),
+            ErrorKind::InsufficientSpaceInDestinationBuffer(_)
+        ));
+    }
+}
+
+#[test]
+fn test_slice_writer_extensible_choice() {
+    for size in SIZES {
+        let value = Value::Text("x".repeat(size));
+        let (bits, bytes) = serialize_uper(&value);
+        let mut buffer = vec![0xFF; bytes.len()];
+        assert_eq!(&bytes[..], serialize_into(&mut buffer, &value).unwrap());
+        if size < 16_000 {
+            assert_eq!(value, deserialize_uper::<Value>(&bytes, bits));
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use asn1rs::io::per::err::ErrorKind;
+use asn1rs::prelude::*;
+
+asn_to_rust!(
+    r"UperTrace DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Header ::= SEQUENCE {
+        station INTEGER (0..4294967295),
+        kind ENUMERATED { car, truck, bus }
+    }
+
+    Item ::= SEQUENCE {
+        name UTF8String,
+        flag BOOLEAN OPTIONAL
+    }
+
+    Payload ::= CHOICE {
+        speed INTEGER (0..16383),
+        text IA5String
+    }
+
+    Message ::= SEQUENCE {
+        header Header,
+        items SEQUENCE (SIZE(0..8)) OF Item,
+        payload Payload,
+        note UTF8String OPTIONAL
+    }
+
+    END"
+);
+
+fn message() -> Message {
+    Message {
+        header: Header {
+            station: 1234,
+            kind: HeaderKind::Truck,
+        },
+        items: vec![
+            Item {
+                name: "a".to_string(),
+                flag: Some(true),
+            },
+            Item {
+                name: "b".to_string(),
+                flag: None,
+            },
+        ],
+        payload: Payload::Speed(42),
+        note: None,
+    }
+}
+
+fn serialize(message: &Message) -> (Vec<u8>, usize) {
+    let mut writer = UperWriter::default();
+    writer.write(message).unwrap();
+    let bits = writer.bit_len();
+    (writer.into_bytes_vec(), bits)
+}
+
+#[test]
+fn test_trace_names_the_path_and_bits_of_every_primitive() {
+    let (bytes, bits) = serialize(&message());
+    let mut reader = TracingReader::from((&bytes[..], bits));
+    assert_eq!(message(), reader.read::<Message>().unwrap());
+    assert_eq!(0, reader.bits_remaining());
+
+    let trace = reader.into_trace();
+    let entries = trace
+        .iter()
+        .map(|e| (e.offset, e.path.as_str(), e.r#type, e.value.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            (0, "Message", "SEQUENCE", "preamble"),
+            (1, "Message.header.station", "INTEGER", "1234"),
+            (33, "Message.header.kind", "ENUMERATED", "truck"),
+            (35, "Message.items", "SEQUENCE OF", "2"),
+            (39, "Message.items[0]", "SEQUENCE", "preamble"),
+            (40, "Message.items[0].name", "UTF8String", "\"a\""),
+            (56, "Message.items[0].flag", "BOOLEAN", "true"),
+            (57, "Message.items[1]", "SEQUENCE", "preamble"),
+            (58, "Message.items[1].name", "UTF8String", "\"b\""),
+            (74, "Message.payload", "CHOICE", "0"),
+            (75, "Message.payload.speed", "INTEGER", "42"),
+        ],
+        entries
+    );
+    assert_eq!("00000000000000000000010011010010", trace.0[1].bits);
+    assert_eq!("01", trace.0[2].bits);
+
+    // the entries cover the encoding without gaps
+    let mut offset = 0;
+    for entry in trace.iter() {
+        assert_eq!(offset, entry.offset);
+        offset += entry.bit_len();
+    }
+    assert_eq!(bits, offset);
+}
+
+#[test]
+fn test_trace_keeps_the_entries_before_a_failure() {
+    let (bytes, _) = serialize(&message());
+    let mut reader = TracingReader::from((&bytes[..], 56));
+    assert!(reader.read::<Message>().is_err());
+
+    let last = reader.entries().last().unwrap();
+    assert_eq!("Message.items[0].flag", last.path);
+    assert_eq!(56, last.offset);
+    assert_eq!("BOOLEAN", last.r#type);
+    assert_eq!("", last.bits);
+    assert_eq!(ErrorKind::EndOfStream.to_string(), last.value);
+}
+
+#[test]
+fn test_trace_renders_text_and_json() {
+    let (bytes, bits) = serialize(&message());
+    let mut reader = TracingReader::from((&bytes[..], bits));
+    reader.read::<Message>().unwrap();
+    let trace = reader.into_trace();
+
+    let text = trace.to_string();
+    assert_eq!(trace.len() + 1, text.lines().count());
+    assert!(text
+        .lines()
+        .nth(3)
+        .unwrap()
+        .ends_with("Message.header.kind     ENUMERATED   truck  01"));
+
+    let json = trace.to_json();
+    assert!(json.starts_with(
+        r#"[{"offset":0,"length":1,"bits":"0","path":"Message","type":"SEQUENCE","value":"preamble"},"#
+    ));
+    assert!(json.contains(
+        r#"{"offset":40,"length":16,"bits":"0000000101100001","path":"Message.items[0].name","type":"UTF8String","value":"\"a\""}"#
+    ));
+    assert!(json.ends_with(r#""type":"INTEGER","value":"42"}]"#));
+}
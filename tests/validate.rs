@@ -0,0 +1,226 @@
+#![recursion_limit = "512"]
+
+mod test_utils;
+
+use asn1rs::model::Charset;
+use asn1rs::syn::{Violation, ViolationKind};
+use test_utils::*;
+
+asn_to_rust!(
+    r#"ValidateConstraints DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Item ::= SEQUENCE {
+        id INTEGER (1..100),
+        name IA5String (SIZE(1..8)),
+        code PrintableString (FROM ("A".."F")) OPTIONAL
+    }
+
+    Value ::= CHOICE {
+        number INTEGER (0..9),
+        text UTF8String (SIZE(0..4))
+    }
+
+    Order ::= SEQUENCE {
+        count INTEGER (0..10, ...),
+        items SEQUENCE (SIZE(1..3)) OF Item,
+        value Value,
+        data OCTET STRING (SIZE(2)),
+        note IA5String OPTIONAL,
+        digits NumericString OPTIONAL
+    }
+
+    NamedItem ::= Item (WITH COMPONENTS { ..., code PRESENT })
+
+    PlainItem ::= Item (WITH COMPONENTS { ..., code ABSENT })
+
+    END"#
+);
+
+#[asn(transparent, components(code(absent)))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProcMacroPlainItem(#[asn(complex(Item, tag(UNIVERSAL(16))))] pub Item);
+
+fn item() -> Item {
+    Item {
+        id: 1,
+        name: "first".to_string(),
+        code: Some("CAFE".to_string()),
+    }
+}
+
+fn order() -> Order {
+    Order {
+        count: 5,
+        items: vec![item()],
+        value: Value::Number(3),
+        data: vec![0x01, 0x02],
+        note: None,
+        digits: Some("0123".to_string()),
+    }
+}
+
+#[test]
+fn test_valid_values() {
+    assert_eq!(Ok(()), item().validate());
+    assert_eq!(Ok(()), order().validate());
+    assert_eq!(
+        Ok(()),
+        NamedItem {
+            id: 1,
+            name: "first".to_string(),
+            code: "CAFE".to_string(),
+        }
+        .validate()
+    );
+}
+
+#[test]
+fn test_value_not_in_range() {
+    let item = Item { id: 101, ..item() };
+    assert_eq!(
+        Some(&ViolationKind::ValueNotInRange(101, 1, 100)),
+        item.validate().unwrap_err().at("id")
+    );
+}
+
+#[test]
+fn test_extensible_range_is_not_checked() {
+    let order = Order {
+        count: 11,
+        ..order()
+    };
+    assert_eq!(Ok(()), order.validate());
+}
+
+#[test]
+fn test_size_not_in_range() {
+    let item = Item {
+        name: String::default(),
+        ..item()
+    };
+    assert_eq!(
+        Some(&ViolationKind::SizeNotInRange(0, 1, 8)),
+        item.validate().unwrap_err().at("name")
+    );
+
+    let order = Order {
+        data: vec![0x01],
+        ..order()
+    };
+    assert_eq!(
+        Some(&ViolationKind::SizeNotInRange(1, 2, 2)),
+        order.validate().unwrap_err().at("data")
+    );
+}
+
+#[test]
+fn test_invalid_charset_and_alphabet() {
+    let item = Item {
+        name: "fürst".to_string(),
+        code: Some("CAGE".to_string()),
+        ..item()
+    };
+    let violations = item.validate().unwrap_err();
+    assert_eq!(2, violations.len());
+    assert_eq!(
+        Some(&ViolationKind::InvalidString(Charset::Ia5, 'ü', 1)),
+        violations.at("name")
+    );
+    assert_eq!(
+        Some(&ViolationKind::CharacterNotPermitted('G', 2)),
+        violations.at("code")
+    );
+
+    let order = Order {
+        digits: Some("12a".to_string()),
+        ..order()
+    };
+    assert_eq!(
+        Some(&ViolationKind::InvalidString(Charset::Numeric, 'a', 2)),
+        order.validate().unwrap_err().at("digits")
+    );
+}
+
+#[test]
+fn test_all_violations_with_nested_paths() {
+    let order = Order {
+        items: vec![
+            item(),
+            Item {
+                id: 0,
+                name: "too long name".to_string(),
+                ..item()
+            },
+        ],
+        value: Value::Text("hello".to_string()),
+        ..order()
+    };
+    assert_eq!(
+        vec![
+            Violation {
+                path: "items[1].id".to_string(),
+                kind: ViolationKind::ValueNotInRange(0, 1, 100),
+            },
+            Violation {
+                path: "items[1].name".to_string(),
+                kind: ViolationKind::SizeNotInRange(13, 1, 8),
+            },
+            Violation {
+                path: "value.text".to_string(),
+                kind: ViolationKind::SizeNotInRange(5, 0, 4),
+            },
+        ],
+        order.validate().unwrap_err().0
+    );
+}
+
+#[test]
+fn test_sequence_of_size_and_display() {
+    let order = Order {
+        items: vec![item(); 4],
+        ..order()
+    };
+    let violations = order.validate().unwrap_err();
+    assert_eq!(
+        Some(&ViolationKind::SizeNotInRange(4, 1, 3)),
+        violations.at("items")
+    );
+    assert_eq!(
+        "items: The size 4 is not within the inclusive range of 1 and 3",
+        violations.to_string()
+    );
+}
+
+#[test]
+fn test_with_components_presence() {
+    let absent = Item {
+        code: None,
+        ..item()
+    };
+    assert_eq!(Ok(()), PlainItem(absent.clone()).validate());
+    assert_eq!(Ok(()), ProcMacroPlainItem(absent).validate());
+    assert_eq!(
+        Some(&ViolationKind::ComponentNotAbsent),
+        PlainItem(item()).validate().unwrap_err().at("code")
+    );
+    assert_eq!(
+        Some(&ViolationKind::ComponentNotAbsent),
+        ProcMacroPlainItem(item())
+            .validate()
+            .unwrap_err()
+            .at("code")
+    );
+}
+
+#[test]
+fn test_presence_is_checked_by_encoders() {
+    let mut writer = UperWriter::default();
+    assert_eq!(
+        &asn1rs::io::per::err::ErrorKind::ComponentConstraintViolated(
+            "code",
+            ViolationKind::ComponentNotAbsent
+        ),
+        writer.write(&PlainItem(item())).unwrap_err().kind()
+    );
+}